@@ -0,0 +1,553 @@
+//! Dense linear algebra backends.
+//!
+//! Solvers call through [`LinearAlgebraBackend`] so the factorization library can
+//! be swapped at runtime. The pure-Rust [`Native`] backend (nalgebra) is always
+//! available; `Faer` is compiled in with the `faer` feature.
+//!
+//! The `lapack` and `suitesparse` features add `Lapack` and `SuiteSparse`, which
+//! call a system LAPACK and UMFPACK. Both libraries are loaded at runtime, so the
+//! crate builds without them and [`backend`] only returns these backends where
+//! the library is found. Other libraries plug in by implementing the trait and
+//! handing the backend to
+//! [`FrameAnalysis::with_backend`](crate::frame::FrameAnalysis::with_backend) or
+//! [`NewtonSolver::with_backend`](crate::nonlinear::NewtonSolver::with_backend).
+
+use nalgebra::{DMatrix, DVector};
+
+/// Eigenvalues in ascending order with the matching eigenvectors column-wise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigen {
+    pub values: DVector<f64>,
+    pub vectors: DMatrix<f64>,
+}
+
+pub trait LinearAlgebraBackend: Send + Sync {
+    /// Name used for runtime selection, see [`backend`].
+    fn name(&self) -> &'static str;
+
+    /// Solve `a x = b`. Returns `None` when `a` is singular.
+    fn solve(&self, a: &DMatrix<f64>, b: &DMatrix<f64>) -> Option<DMatrix<f64>>;
+
+    /// Lower Cholesky factor `L` with `a = L Lᵀ`. Returns `None` when `a` is not
+    /// symmetric positive definite.
+    fn cholesky(&self, a: &DMatrix<f64>) -> Option<DMatrix<f64>>;
+
+    /// Eigen decomposition of a symmetric matrix.
+    fn symmetric_eigen(&self, a: &DMatrix<f64>) -> Option<Eigen>;
+
+    /// Generalized symmetric eigenproblem `K φ = λ M φ` with mass-normalized modes.
+    ///
+    /// The problem is reduced through the Cholesky factor of `M`. When `M` is only
+    /// semi-definite (massless DOFs) the solver falls back to factorizing `K` and
+    /// solving the inverse problem; modes without mass have infinite eigenvalues
+    /// and are dropped.
+    fn generalized_eigen(&self, k: &DMatrix<f64>, m: &DMatrix<f64>) -> Option<Eigen> {
+        assert_eq!(k.shape(), m.shape(), "K and M must have the same size");
+        if let Some(l) = self.cholesky(m) {
+            let reduced = reduce(self, &l, k)?;
+            let eigen = self.symmetric_eigen(&reduced)?;
+            let vectors = self.solve(&l.transpose(), &eigen.vectors)?;
+            return Some(Eigen { values: eigen.values, vectors });
+        }
+
+        inverse_eigen(self, k, m, |phi| {
+            let modal_mass = phi.dot(&(m * &phi));
+            phi / modal_mass.sqrt()
+        })
+    }
+}
+
+/// Eigenpairs of `A φ = λ B φ` with `A` positive definite but `B` possibly
+/// singular or indefinite, solved as `B φ = μ A φ` through the Cholesky
+/// factor of `A` with `λ = 1 / μ`, ascending. Only positive `μ` are kept: the
+/// others, below a round-off threshold relative to the largest, belong to
+/// infinite or negative `λ` (massless DOFs, unloaded buckling modes). Each
+/// mode is scaled by `normalize`.
+pub(crate) fn inverse_eigen<B: LinearAlgebraBackend + ?Sized>(
+    backend: &B,
+    a: &DMatrix<f64>,
+    b: &DMatrix<f64>,
+    normalize: impl Fn(DVector<f64>) -> DVector<f64>,
+) -> Option<Eigen> {
+    let l = backend.cholesky(a)?;
+    let reduced = reduce(backend, &l, b)?;
+    let eigen = backend.symmetric_eigen(&reduced)?;
+    let shapes = backend.solve(&l.transpose(), &eigen.vectors)?;
+    let scale = eigen.values.amax();
+    let mut pairs: Vec<(f64, DVector<f64>)> = eigen
+        .values
+        .iter()
+        .enumerate()
+        .filter(|&(_, &mu)| mu > 1e-12 * scale)
+        .map(|(i, &mu)| (1.0 / mu, normalize(shapes.column(i).into_owned())))
+        .collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let values = DVector::from_iterator(pairs.len(), pairs.iter().map(|(value, _)| *value));
+    let columns: Vec<DVector<f64>> = pairs.into_iter().map(|(_, phi)| phi).collect();
+    let vectors = if columns.is_empty() { DMatrix::zeros(a.nrows(), 0) } else { DMatrix::from_columns(&columns) };
+    Some(Eigen { values, vectors })
+}
+
+/// `L⁻¹ A L⁻ᵀ`, symmetrized against round-off.
+fn reduce<B: LinearAlgebraBackend + ?Sized>(backend: &B, l: &DMatrix<f64>, a: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+    let left = backend.solve(l, a)?;
+    let reduced = backend.solve(l, &left.transpose())?;
+    Some((&reduced + reduced.transpose()) * 0.5)
+}
+
+fn sorted(values: DVector<f64>, vectors: DMatrix<f64>) -> Eigen {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    Eigen {
+        values: DVector::from_iterator(order.len(), order.iter().map(|&i| values[i])),
+        vectors: vectors.select_columns(&order),
+    }
+}
+
+/// Pure-Rust backend built on nalgebra's dense decompositions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Native;
+
+impl LinearAlgebraBackend for Native {
+    fn name(&self) -> &'static str { "native" }
+
+    fn solve(&self, a: &DMatrix<f64>, b: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        a.clone().lu().solve(b)
+    }
+
+    fn cholesky(&self, a: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        a.clone().cholesky().map(|c| c.l())
+    }
+
+    fn symmetric_eigen(&self, a: &DMatrix<f64>) -> Option<Eigen> {
+        let eigen = a.clone().symmetric_eigen();
+        Some(sorted(eigen.eigenvalues, eigen.eigenvectors))
+    }
+}
+
+/// Backend built on the faer crate; faster for large dense systems.
+#[cfg(feature = "faer")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Faer;
+
+#[cfg(feature = "faer")]
+impl Faer {
+    fn to_faer(a: &DMatrix<f64>) -> faer::Mat<f64> {
+        faer::Mat::from_fn(a.nrows(), a.ncols(), |i, j| a[(i, j)])
+    }
+
+    fn from_faer(a: faer::MatRef<'_, f64>) -> DMatrix<f64> {
+        DMatrix::from_fn(a.nrows(), a.ncols(), |i, j| a[(i, j)])
+    }
+}
+
+#[cfg(feature = "faer")]
+impl LinearAlgebraBackend for Faer {
+    fn name(&self) -> &'static str { "faer" }
+
+    fn solve(&self, a: &DMatrix<f64>, b: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        use faer::linalg::solvers::Solve;
+        // Partial pivoting LU does not report singularity; reject non-finite results instead.
+        let x = Self::to_faer(a).partial_piv_lu().solve(Self::to_faer(b));
+        let x = Self::from_faer(x.as_ref());
+        x.iter().all(|v| v.is_finite()).then_some(x)
+    }
+
+    fn cholesky(&self, a: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        let llt = Self::to_faer(a).llt(faer::Side::Lower).ok()?;
+        Some(Self::from_faer(llt.L()))
+    }
+
+    fn symmetric_eigen(&self, a: &DMatrix<f64>) -> Option<Eigen> {
+        let eigen = Self::to_faer(a).self_adjoint_eigen(faer::Side::Lower).ok()?;
+        let s = eigen.S().column_vector();
+        let values = DVector::from_fn(s.nrows(), |i, _| s[i]);
+        Some(sorted(values, Self::from_faer(eigen.U())))
+    }
+}
+
+/// First library that loads: the path in the environment variable `variable`,
+/// then each of `names` on the system search path.
+#[cfg(any(feature = "lapack", feature = "suitesparse"))]
+fn load_library(variable: &str, names: &[&str]) -> Option<libloading::Library> {
+    let path = std::env::var(variable).ok();
+    path.iter().map(String::as_str).chain(names.iter().copied()).find_map(|name| {
+        // SAFETY: LAPACK and UMFPACK builds run no initialisation code with
+        // preconditions when loaded.
+        unsafe { libloading::Library::new(name) }.ok()
+    })
+}
+
+/// `*symbol` from `library` as a function pointer of type `T`.
+///
+/// # Safety
+///
+/// `T` must match the signature of the exported routine.
+#[cfg(any(feature = "lapack", feature = "suitesparse"))]
+unsafe fn routine<T: Copy>(library: &libloading::Library, symbol: &[u8]) -> Option<T> {
+    // SAFETY: upheld by the caller.
+    unsafe { library.get::<T>(symbol) }.ok().map(|routine| *routine)
+}
+
+#[cfg(feature = "lapack")]
+mod lapack_ffi {
+    use std::ffi::{c_char, c_int};
+
+    pub type Dgesv = unsafe extern "C" fn(
+        n: *const c_int,
+        nrhs: *const c_int,
+        a: *mut f64,
+        lda: *const c_int,
+        ipiv: *mut c_int,
+        b: *mut f64,
+        ldb: *const c_int,
+        info: *mut c_int,
+    );
+    pub type Dpotrf = unsafe extern "C" fn(uplo: *const c_char, n: *const c_int, a: *mut f64, lda: *const c_int, info: *mut c_int);
+    pub type Dsyev = unsafe extern "C" fn(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const c_int,
+        a: *mut f64,
+        lda: *const c_int,
+        w: *mut f64,
+        work: *mut f64,
+        lwork: *const c_int,
+        info: *mut c_int,
+    );
+}
+
+/// Backend calling a system LAPACK (reference LAPACK, OpenBLAS, MKL, ...)
+/// through its Fortran interface: `dgesv`, `dpotrf` and `dsyev`. The library
+/// is loaded from the path in `RUSTFEM_LAPACK`, else by its common names.
+#[cfg(feature = "lapack")]
+#[derive(Debug)]
+pub struct Lapack {
+    _library: libloading::Library,
+    dgesv: lapack_ffi::Dgesv,
+    dpotrf: lapack_ffi::Dpotrf,
+    dsyev: lapack_ffi::Dsyev,
+}
+
+#[cfg(feature = "lapack")]
+impl Lapack {
+    const NAMES: [&'static str; 8] = [
+        "liblapack.so.3",
+        "liblapack.so",
+        "libopenblas.so.0",
+        "libopenblas.so",
+        "liblapack.dylib",
+        "libopenblas.dylib",
+        "lapack.dll",
+        "libopenblas.dll",
+    ];
+
+    /// Loads the library; `None` when it is not installed or lacks a routine.
+    pub fn load() -> Option<Self> {
+        let library = load_library("RUSTFEM_LAPACK", &Self::NAMES)?;
+        // SAFETY: the types spell out the reference LAPACK signatures.
+        let (dgesv, dpotrf, dsyev) = unsafe {
+            (routine(&library, b"dgesv_\0")?, routine(&library, b"dpotrf_\0")?, routine(&library, b"dsyev_\0")?)
+        };
+        Some(Self { _library: library, dgesv, dpotrf, dsyev })
+    }
+
+    fn order(a: &DMatrix<f64>) -> std::ffi::c_int {
+        assert!(a.is_square(), "matrix must be square");
+        a.nrows().max(1).try_into().expect("matrix too large for LAPACK")
+    }
+}
+
+#[cfg(feature = "lapack")]
+impl LinearAlgebraBackend for Lapack {
+    fn name(&self) -> &'static str { "lapack" }
+
+    fn solve(&self, a: &DMatrix<f64>, b: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        assert_eq!(a.nrows(), b.nrows(), "A and b must have the same number of rows");
+        if a.is_empty() || b.is_empty() {
+            return Some(b.clone());
+        }
+        let (n, nrhs) = (Self::order(a), b.ncols().try_into().expect("too many right-hand sides"));
+        let (mut lu, mut x) = (a.clone(), b.clone());
+        let (mut pivots, mut info) = (vec![0; a.nrows()], 0);
+        // SAFETY: nalgebra stores column-major with leading dimension n, as
+        // LAPACK expects, and every buffer has the size passed.
+        unsafe { (self.dgesv)(&n, &nrhs, lu.as_mut_ptr(), &n, pivots.as_mut_ptr(), x.as_mut_ptr(), &n, &mut info) };
+        (info == 0).then_some(x)
+    }
+
+    fn cholesky(&self, a: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        if a.is_empty() {
+            return Some(a.clone());
+        }
+        let n = Self::order(a);
+        let (mut l, mut info) = (a.clone(), 0);
+        // SAFETY: as in `solve`.
+        unsafe { (self.dpotrf)(&(b'L' as std::ffi::c_char), &n, l.as_mut_ptr(), &n, &mut info) };
+        if info != 0 {
+            return None;
+        }
+        // dpotrf leaves the strict upper triangle untouched.
+        l.fill_upper_triangle(0.0, 1);
+        Some(l)
+    }
+
+    fn symmetric_eigen(&self, a: &DMatrix<f64>) -> Option<Eigen> {
+        if a.is_empty() {
+            return Some(Eigen { values: DVector::zeros(0), vectors: a.clone() });
+        }
+        let n = Self::order(a);
+        let (jobz, uplo) = (b'V' as std::ffi::c_char, b'L' as std::ffi::c_char);
+        let (mut vectors, mut values) = (a.clone(), DVector::zeros(a.nrows()));
+        let (mut work, mut info) = (vec![0.0], 0);
+        // SAFETY: as in `solve`; the first call only queries the workspace size.
+        unsafe {
+            (self.dsyev)(&jobz, &uplo, &n, vectors.as_mut_ptr(), &n, values.as_mut_ptr(), work.as_mut_ptr(), &-1, &mut info);
+            let size = work[0] as std::ffi::c_int;
+            work = vec![0.0; size.max(1) as usize];
+            (self.dsyev)(&jobz, &uplo, &n, vectors.as_mut_ptr(), &n, values.as_mut_ptr(), work.as_mut_ptr(), &size, &mut info);
+        }
+        // dsyev returns the eigenvalues ascending.
+        (info == 0).then_some(Eigen { values, vectors })
+    }
+}
+
+#[cfg(feature = "suitesparse")]
+mod umfpack_ffi {
+    use std::ffi::{c_int, c_void};
+
+    pub type Symbolic = unsafe extern "C" fn(
+        n_row: c_int,
+        n_col: c_int,
+        ap: *const c_int,
+        ai: *const c_int,
+        ax: *const f64,
+        symbolic: *mut *mut c_void,
+        control: *const f64,
+        info: *mut f64,
+    ) -> c_int;
+    pub type Numeric = unsafe extern "C" fn(
+        ap: *const c_int,
+        ai: *const c_int,
+        ax: *const f64,
+        symbolic: *mut c_void,
+        numeric: *mut *mut c_void,
+        control: *const f64,
+        info: *mut f64,
+    ) -> c_int;
+    pub type Solve = unsafe extern "C" fn(
+        sys: c_int,
+        ap: *const c_int,
+        ai: *const c_int,
+        ax: *const f64,
+        x: *mut f64,
+        b: *const f64,
+        numeric: *mut c_void,
+        control: *const f64,
+        info: *mut f64,
+    ) -> c_int;
+    pub type Free = unsafe extern "C" fn(object: *mut *mut c_void);
+
+    /// `sys` value solving `A x = b`.
+    pub const UMFPACK_A: c_int = 0;
+    pub const UMFPACK_OK: c_int = 0;
+}
+
+/// Backend solving with SuiteSparse's sparse LU, UMFPACK, which pays off for
+/// the mostly empty stiffness matrices of large models: matrices are passed on
+/// with their zero entries dropped. Cholesky and eigen decompositions use
+/// [`Native`]. The library is loaded from the path in `RUSTFEM_UMFPACK`, else
+/// by its common names.
+#[cfg(feature = "suitesparse")]
+#[derive(Debug)]
+pub struct SuiteSparse {
+    _library: libloading::Library,
+    symbolic: umfpack_ffi::Symbolic,
+    numeric: umfpack_ffi::Numeric,
+    solve: umfpack_ffi::Solve,
+    free_symbolic: umfpack_ffi::Free,
+    free_numeric: umfpack_ffi::Free,
+}
+
+#[cfg(feature = "suitesparse")]
+impl SuiteSparse {
+    const NAMES: [&'static str; 5] = ["libumfpack.so.6", "libumfpack.so.5", "libumfpack.so", "libumfpack.dylib", "umfpack.dll"];
+
+    /// Loads the library; `None` when it is not installed or lacks a routine.
+    pub fn load() -> Option<Self> {
+        let library = load_library("RUSTFEM_UMFPACK", &Self::NAMES)?;
+        // SAFETY: the types spell out the UMFPACK `di` signatures.
+        let (symbolic, numeric, solve, free_symbolic, free_numeric) = unsafe {
+            (
+                routine(&library, b"umfpack_di_symbolic\0")?,
+                routine(&library, b"umfpack_di_numeric\0")?,
+                routine(&library, b"umfpack_di_solve\0")?,
+                routine(&library, b"umfpack_di_free_symbolic\0")?,
+                routine(&library, b"umfpack_di_free_numeric\0")?,
+            )
+        };
+        Some(Self { _library: library, symbolic, numeric, solve, free_symbolic, free_numeric })
+    }
+}
+
+/// Nonzero entries of a dense matrix in compressed sparse column form:
+/// column pointers, row indices and values.
+#[cfg(feature = "suitesparse")]
+fn compressed_columns(a: &DMatrix<f64>) -> (Vec<std::ffi::c_int>, Vec<std::ffi::c_int>, Vec<f64>) {
+    let index = |i: usize| std::ffi::c_int::try_from(i).expect("matrix too large for UMFPACK");
+    let (mut pointers, mut rows, mut values) = (vec![0], Vec::new(), Vec::new());
+    for column in a.column_iter() {
+        for (i, &value) in column.iter().enumerate().filter(|&(_, &value)| value != 0.0) {
+            rows.push(index(i));
+            values.push(value);
+        }
+        pointers.push(index(rows.len()));
+    }
+    (pointers, rows, values)
+}
+
+#[cfg(feature = "suitesparse")]
+impl LinearAlgebraBackend for SuiteSparse {
+    fn name(&self) -> &'static str { "suitesparse" }
+
+    fn solve(&self, a: &DMatrix<f64>, b: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        use std::ptr::{null, null_mut};
+
+        assert!(a.is_square(), "matrix must be square");
+        assert_eq!(a.nrows(), b.nrows(), "A and b must have the same number of rows");
+        if a.is_empty() || b.is_empty() {
+            return Some(b.clone());
+        }
+        let n = std::ffi::c_int::try_from(a.nrows()).expect("matrix too large for UMFPACK");
+        let (pointers, rows, values) = compressed_columns(a);
+        let (ap, ai, ax) = (pointers.as_ptr(), rows.as_ptr(), values.as_ptr());
+        let mut x = DMatrix::zeros(b.nrows(), b.ncols());
+        // SAFETY: the arrays form a valid n x n compressed column matrix and
+        // outlive the factorization, which is freed on every path. Columns of
+        // `b` and `x` are contiguous with n entries each.
+        unsafe {
+            let (mut symbolic, mut numeric) = (null_mut(), null_mut());
+            if (self.symbolic)(n, n, ap, ai, ax, &mut symbolic, null(), null_mut()) != umfpack_ffi::UMFPACK_OK {
+                (self.free_symbolic)(&mut symbolic);
+                return None;
+            }
+            let status = (self.numeric)(ap, ai, ax, symbolic, &mut numeric, null(), null_mut());
+            (self.free_symbolic)(&mut symbolic);
+            // A singular matrix only raises a warning, which is not OK either.
+            let mut ok = status == umfpack_ffi::UMFPACK_OK;
+            for j in 0..b.ncols() {
+                if !ok {
+                    break;
+                }
+                let (column, solution) = (b.column(j).as_ptr(), x.column_mut(j).as_mut_ptr());
+                ok = (self.solve)(umfpack_ffi::UMFPACK_A, ap, ai, ax, solution, column, numeric, null(), null_mut())
+                    == umfpack_ffi::UMFPACK_OK;
+            }
+            (self.free_numeric)(&mut numeric);
+            ok.then_some(x)
+        }
+    }
+
+    fn cholesky(&self, a: &DMatrix<f64>) -> Option<DMatrix<f64>> { Native.cholesky(a) }
+
+    fn symmetric_eigen(&self, a: &DMatrix<f64>) -> Option<Eigen> { Native.symmetric_eigen(a) }
+}
+
+/// Names of the backends usable in this build: compiled in and, for those
+/// calling a system library, with the library found.
+pub fn available() -> Vec<&'static str> {
+    ["native", "faer", "lapack", "suitesparse"].into_iter().filter(|name| backend(name).is_some()).collect()
+}
+
+/// Backend by name (case-insensitive), or `None` if it is not compiled in or
+/// its system library is missing.
+pub fn backend(name: &str) -> Option<Box<dyn LinearAlgebraBackend>> {
+    match name.to_ascii_lowercase().as_str() {
+        "native" | "nalgebra" => Some(Box::new(Native)),
+        #[cfg(feature = "faer")]
+        "faer" => Some(Box::new(Faer)),
+        #[cfg(feature = "lapack")]
+        "lapack" => Lapack::load().map(|lapack| Box::new(lapack) as Box<dyn LinearAlgebraBackend>),
+        #[cfg(feature = "suitesparse")]
+        "suitesparse" | "umfpack" => SuiteSparse::load().map(|suitesparse| Box::new(suitesparse) as Box<dyn LinearAlgebraBackend>),
+        _ => None,
+    }
+}
+
+/// Backend selected by the `RUSTFEM_BACKEND` environment variable, falling back to
+/// [`Native`] when it is unset or names a backend that is not available.
+pub fn default_backend() -> Box<dyn LinearAlgebraBackend> {
+    std::env::var("RUSTFEM_BACKEND")
+        .ok()
+        .and_then(|name| backend(&name))
+        .unwrap_or_else(|| Box::new(Native))
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    #[test]
+    fn native_is_always_available() {
+        assert!(available().contains(&"native"));
+        assert_eq!(backend("Native").unwrap().name(), "native");
+        assert!(backend("unknown").is_none());
+    }
+
+    #[test]
+    fn generalized_eigen_falls_back_for_massless_dofs() {
+        // Two springs in series, mass only at the tip.
+        let k = DMatrix::from_row_slice(2, 2, &[2.0, -1.0, -1.0, 1.0]);
+        let m = DMatrix::from_row_slice(2, 2, &[0.0, 0.0, 0.0, 1.0]);
+        let eigen = Native.generalized_eigen(&k, &m).unwrap();
+        assert_eq!(eigen.values.len(), 1);
+        assert_almost_eq!(eigen.values[0], 0.5);
+        let phi = eigen.vectors.column(0);
+        assert_almost_eq!(phi[1].abs(), 1.0);
+        assert_almost_eq!(phi[0] / phi[1], 0.5);
+    }
+
+    #[cfg(feature = "faer")]
+    #[test]
+    fn faer_matches_native() {
+        let k = DMatrix::from_row_slice(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 1.0]);
+        let m = DMatrix::from_diagonal(&DVector::from_vec(vec![1.0, 2.0, 1.0]));
+        let native = Native.generalized_eigen(&k, &m).unwrap();
+        let faer = Faer.generalized_eigen(&k, &m).unwrap();
+        for i in 0..3 {
+            assert_almost_eq!(native.values[i], faer.values[i], 1e-10);
+        }
+        let x = Faer.solve(&k, &DMatrix::from_element(3, 1, 1.0)).unwrap();
+        assert_almost_eq!(x[2], 6.0, 1e-10);
+    }
+
+    /// Checks a backend calling a system library against [`Native`]; skipped
+    /// where the library is not installed.
+    #[cfg(any(feature = "lapack", feature = "suitesparse"))]
+    fn matches_native(name: &str) {
+        let Some(backend) = backend(name) else {
+            assert!(!available().contains(&name));
+            return;
+        };
+        let k = DMatrix::from_row_slice(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 1.0]);
+        let m = DMatrix::from_diagonal(&DVector::from_vec(vec![1.0, 2.0, 1.0]));
+        let native = Native.generalized_eigen(&k, &m).unwrap();
+        let other = backend.generalized_eigen(&k, &m).unwrap();
+        for i in 0..3 {
+            assert_almost_eq!(native.values[i], other.values[i], 1e-10);
+        }
+        let x = backend.solve(&k, &DMatrix::from_element(3, 1, 1.0)).unwrap();
+        assert_almost_eq!(x[2], 6.0, 1e-10);
+        assert!(backend.solve(&DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 4.0]), &DMatrix::from_element(2, 1, 1.0)).is_none());
+    }
+
+    #[cfg(feature = "lapack")]
+    #[test]
+    fn lapack_matches_native() { matches_native("lapack"); }
+
+    #[cfg(feature = "suitesparse")]
+    #[test]
+    fn suitesparse_matches_native() { matches_native("suitesparse"); }
+}
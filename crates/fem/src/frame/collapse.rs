@@ -0,0 +1,120 @@
+//! Alternate load path studies against progressive collapse: members are
+//! notionally removed, the loads near the removal are amplified for the dynamic
+//! effect of the sudden loss, and the remaining structure is checked with its
+//! design groups.
+
+use structure::{Load, MemberId, Model};
+
+use super::{
+    design::{check_design_groups, Demand},
+    erection::StageFailure,
+};
+
+/// Removal scenarios to run against a model. Ids refer to the complete model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlternatePathStudy {
+    scenarios: Vec<Vec<MemberId>>,
+    amplification: f64,
+}
+
+impl Default for AlternatePathStudy {
+    fn default() -> Self { Self { scenarios: Vec::new(), amplification: 2.0 } }
+}
+
+impl AlternatePathStudy {
+    pub fn new() -> Self { Self::default() }
+
+    /// One scenario per member, each removing that member alone.
+    pub fn single_removals<I: IntoIterator<Item = MemberId>>(members: I) -> Self {
+        Self { scenarios: members.into_iter().map(|member| vec![member]).collect(), ..Self::default() }
+    }
+
+    pub fn with_scenario(mut self, removed: Vec<MemberId>) -> Self {
+        self.add_scenario(removed);
+        self
+    }
+
+    /// Dynamic amplification of the loads next to a removal, two for a sudden
+    /// loss in a linear static analysis. Panics below one.
+    pub fn with_amplification(mut self, factor: f64) -> Self {
+        assert!(factor >= 1.0, "dynamic amplification factor must be at least one");
+        self.amplification = factor;
+        self
+    }
+
+    pub fn add_scenario(&mut self, removed: Vec<MemberId>) { self.scenarios.push(removed); }
+
+    pub fn scenarios(&self) -> &[Vec<MemberId>] { &self.scenarios }
+    pub fn amplification(&self) -> f64 { self.amplification }
+
+    /// Check every scenario, in order.
+    pub fn run(&self, model: &Model) -> Vec<RemovalCheck> {
+        self.scenarios.iter().map(|removed| self.check(model, removed)).collect()
+    }
+
+    /// Check the structure left after removing `removed`. The loads on members
+    /// framing into a removed member, and the nodal loads at its ends, are
+    /// amplified; everything further away keeps its static value.
+    pub fn check(&self, model: &Model, removed: &[MemberId]) -> RemovalCheck {
+        let mut stage = model.without_members(removed);
+        let affected_nodes: Vec<_> = removed
+            .iter()
+            .flat_map(|&member| model.member_nodes(member))
+            .filter_map(|node| stage.nodes.binary_search(&node).ok())
+            .collect();
+        let affected_members: Vec<_> = (0..stage.members.len())
+            .filter(|&member| stage.model.member_nodes(member).iter().any(|node| affected_nodes.contains(node)))
+            .collect();
+        for case in 0..stage.model.load_cases().len() {
+            for load in stage.model.load_case_mut(case).loads_mut() {
+                let affected = match *load {
+                    Load::Nodal { node, .. } => affected_nodes.contains(&node),
+                    Load::MemberUniform { member, .. } => affected_members.contains(&member),
+                };
+                if affected {
+                    *load = load.scaled(self.amplification);
+                }
+            }
+        }
+
+        let mut check = RemovalCheck { removed: removed.to_vec(), governing: None, failure: None };
+        let instabilities = stage.model.instabilities();
+        if !instabilities.is_empty() {
+            check.failure = Some(StageFailure::Unstable(instabilities));
+            return check;
+        }
+        let Some(report) = check_design_groups(&stage.model) else {
+            check.failure = Some(StageFailure::Singular);
+            return check;
+        };
+        for group in report.groups() {
+            if let Some((demand, utilization)) = group.governing
+                && check.governing.is_none_or(|(_, governing)| utilization > governing)
+            {
+                check.governing = Some((Demand { member: stage.members[demand.member], ..demand }, utilization));
+            }
+        }
+        if let Some((demand, utilization)) = check.governing
+            && utilization > 1.0
+        {
+            check.failure = Some(StageFailure::Overstressed { member: demand.member, utilization });
+        }
+        check
+    }
+}
+
+/// Outcome of one removal scenario. The governing demand is the one with the
+/// highest utilization over all checked design groups, its member by the id in
+/// the complete model; instabilities refer to the model without the removed
+/// members.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemovalCheck {
+    pub removed: Vec<MemberId>,
+    pub governing: Option<(Demand, f64)>,
+    pub failure: Option<StageFailure>,
+}
+
+impl RemovalCheck {
+    /// Whether an alternate load path carries the loads.
+    pub fn is_robust(&self) -> bool { self.failure.is_none() }
+}
@@ -0,0 +1,90 @@
+//! Enveloped checks of design groups: members sharing a section are checked
+//! together against the largest demand on any of them. The load cases of the
+//! model act as the design combinations.
+
+use structure::{MemberId, Model};
+
+use super::{erection::end_forces, FrameAnalysis};
+
+/// End forces of one member under one load case: the axial force and the
+/// larger resultant end moment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Demand {
+    pub member: MemberId,
+    pub load_case: usize,
+    pub axial: f64,
+    pub moment: f64,
+}
+
+/// Envelope of a design group over its members and all load cases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupCheck {
+    pub group: usize,
+    /// Demand with the largest axial force magnitude.
+    pub max_axial: Option<Demand>,
+    /// Demand with the largest end moment.
+    pub max_moment: Option<Demand>,
+    /// Demand with the highest utilization of the group capacity; `None` for
+    /// groups without a capacity or members.
+    pub governing: Option<(Demand, f64)>,
+}
+
+impl GroupCheck {
+    pub fn utilization(&self) -> Option<f64> { self.governing.map(|(_, utilization)| utilization) }
+
+    /// Whether the shared section carries the governing demand; unchecked
+    /// groups count as adequate.
+    pub fn is_adequate(&self) -> bool { self.utilization().is_none_or(|utilization| utilization <= 1.0) }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignReport {
+    groups: Vec<GroupCheck>,
+}
+
+impl DesignReport {
+    /// Checks in design group order.
+    pub fn groups(&self) -> &[GroupCheck] { &self.groups }
+    pub fn group(&self, index: usize) -> &GroupCheck { &self.groups[index] }
+
+    /// Groups whose section has to grow, the next candidates of a sizing loop.
+    pub fn overstressed(&self) -> impl Iterator<Item = &GroupCheck> { self.groups.iter().filter(|g| !g.is_adequate()) }
+
+    pub fn is_adequate(&self) -> bool { self.overstressed().next().is_none() }
+}
+
+/// Solve every load case of `model` and envelope the member end forces of each
+/// design group. Returns `None` when the structure is singular.
+pub fn check_design_groups(model: &Model) -> Option<DesignReport> {
+    let analysis = FrameAnalysis::new(model);
+    let results = model.load_cases().iter().map(|case| analysis.solve(case)).collect::<Option<Vec<_>>>()?;
+
+    let groups = model
+        .design_groups()
+        .iter()
+        .enumerate()
+        .map(|(index, group)| {
+            let mut check = GroupCheck { group: index, max_axial: None, max_moment: None, governing: None };
+            for (load_case, result) in results.iter().enumerate() {
+                for &member in group.members() {
+                    let (axial, moment) = end_forces(result, member);
+                    let demand = Demand { member, load_case, axial, moment };
+                    if check.max_axial.is_none_or(|max| axial.abs() > max.axial.abs()) {
+                        check.max_axial = Some(demand);
+                    }
+                    if check.max_moment.is_none_or(|max| moment > max.moment) {
+                        check.max_moment = Some(demand);
+                    }
+                    if let Some(capacity) = group.capacity() {
+                        let utilization = capacity.utilization(axial, moment);
+                        if check.governing.is_none_or(|(_, governing)| utilization > governing) {
+                            check.governing = Some((demand, utilization));
+                        }
+                    }
+                }
+            }
+            check
+        })
+        .collect();
+    Some(DesignReport { groups })
+}
@@ -0,0 +1,143 @@
+//! Local matrices of the two-node 3D Euler-Bernoulli beam element.
+//!
+//! Local DOFs are `[u1, v1, w1, θx1, θy1, θz1, u2, v2, w2, θx2, θy2, θz2]`. Bending
+//! in the local xy plane (v, θz) uses `Iz`, bending in the xz plane (w, θy) uses
+//! `Iy`; the latter follows the same pattern with the rotation terms negated
+//! because `θy = -dw/dx`.
+
+use nalgebra::{Matrix3, SMatrix, SVector, Vector3};
+
+pub type Matrix12 = SMatrix<f64, 12, 12>;
+pub type Vector12 = SVector<f64, 12>;
+
+/// Bending DOFs `[v1, θ1, v2, θ2]` and the sign of the rotations for both planes.
+const BENDING: [([usize; 4], f64); 2] = [([1, 5, 7, 11], 1.0), ([2, 4, 8, 10], -1.0)];
+
+fn add_axial(m: &mut Matrix12, dofs: [usize; 2], block: [[f64; 2]; 2]) {
+    for (i, &a) in dofs.iter().enumerate() {
+        for (j, &b) in dofs.iter().enumerate() {
+            m[(a, b)] += block[i][j];
+        }
+    }
+}
+
+/// Scatter a `[v1, θ1, v2, θ2]` block, flipping the sign of rotation couplings.
+fn add_bending(m: &mut Matrix12, (dofs, sign): ([usize; 4], f64), block: [[f64; 4]; 4]) {
+    for i in 0..4 {
+        for j in 0..4 {
+            let flip = if (i % 2 == 1) != (j % 2 == 1) { sign } else { 1.0 };
+            m[(dofs[i], dofs[j])] += flip * block[i][j];
+        }
+    }
+}
+
+/// Elastic stiffness for axial stiffness `ea`, torsional stiffness `gj` and bending
+/// stiffnesses `[eiy, eiz]`.
+pub fn stiffness(length: f64, ea: f64, gj: f64, ei: [f64; 2]) -> Matrix12 {
+    let l = length;
+    let mut k = Matrix12::zeros();
+    add_axial(&mut k, [0, 6], [[ea / l, -ea / l], [-ea / l, ea / l]]);
+    add_axial(&mut k, [3, 9], [[gj / l, -gj / l], [-gj / l, gj / l]]);
+    // Plane xy bends about z, plane xz about y.
+    for (plane, ei) in BENDING.into_iter().zip([ei[1], ei[0]]) {
+        let (a, b, c, d) = (12.0 * ei / l.powi(3), 6.0 * ei / l.powi(2), 4.0 * ei / l, 2.0 * ei / l);
+        add_bending(&mut k, plane, [[a, b, -a, b], [b, c, -b, d], [-a, -b, a, -b], [b, d, -b, c]]);
+    }
+    k
+}
+
+/// Consistent mass for mass per unit length `mass` and polar mass moment of inertia
+/// per unit length `polar`.
+pub fn mass(length: f64, mass: f64, polar: f64) -> Matrix12 {
+    let l = length;
+    let mut m = Matrix12::zeros();
+    let axial = mass * l / 6.0;
+    add_axial(&mut m, [0, 6], [[2.0 * axial, axial], [axial, 2.0 * axial]]);
+    let torsion = polar * l / 6.0;
+    add_axial(&mut m, [3, 9], [[2.0 * torsion, torsion], [torsion, 2.0 * torsion]]);
+    let s = mass * l / 420.0;
+    let block = [
+        [156.0 * s, 22.0 * l * s, 54.0 * s, -13.0 * l * s],
+        [22.0 * l * s, 4.0 * l * l * s, 13.0 * l * s, -3.0 * l * l * s],
+        [54.0 * s, 13.0 * l * s, 156.0 * s, -22.0 * l * s],
+        [-13.0 * l * s, -3.0 * l * l * s, -22.0 * l * s, 4.0 * l * l * s],
+    ];
+    for plane in BENDING {
+        add_bending(&mut m, plane, block);
+    }
+    m
+}
+
+/// Geometric stiffness for axial force `axial` (tension positive). Only the
+/// bending terms are included.
+pub fn geometric_stiffness(length: f64, axial: f64) -> Matrix12 {
+    let l = length;
+    let s = axial / l;
+    let mut k = Matrix12::zeros();
+    let block = [
+        [1.2 * s, 0.1 * l * s, -1.2 * s, 0.1 * l * s],
+        [0.1 * l * s, 2.0 * l * l * s / 15.0, -0.1 * l * s, -l * l * s / 30.0],
+        [-1.2 * s, -0.1 * l * s, 1.2 * s, -0.1 * l * s],
+        [0.1 * l * s, -l * l * s / 30.0, -0.1 * l * s, 2.0 * l * l * s / 15.0],
+    ];
+    for plane in BENDING {
+        add_bending(&mut k, plane, block);
+    }
+    k
+}
+
+/// Equivalent nodal loads of a uniform load `q` given in local coordinates.
+pub fn uniform_load(length: f64, q: Vector3<f64>) -> Vector12 {
+    let l = length;
+    let mut f = Vector12::zeros();
+    f[0] = q.x * l / 2.0;
+    f[6] = q.x * l / 2.0;
+    for ((dofs, sign), q) in BENDING.into_iter().zip([q.y, q.z]) {
+        f[dofs[0]] += q * l / 2.0;
+        f[dofs[1]] += sign * q * l * l / 12.0;
+        f[dofs[2]] += q * l / 2.0;
+        f[dofs[3]] -= sign * q * l * l / 12.0;
+    }
+    f
+}
+
+/// Transformation from global to local DOFs, `T = diag(Rᵀ, Rᵀ, Rᵀ, Rᵀ)` for a
+/// rotation matrix whose columns are the local axes.
+pub fn transformation(rotation: &Matrix3<f64>) -> Matrix12 {
+    let mut t = Matrix12::zeros();
+    for block in 0..4 {
+        t.fixed_view_mut::<3, 3>(3 * block, 3 * block).copy_from(&rotation.transpose());
+    }
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrices_are_symmetric_and_rigid_body_free() {
+        let k = stiffness(2.0, 3.0, 0.5, [4.0, 5.0]);
+        assert!((k - k.transpose()).amax() < 1e-12);
+        assert!((mass(2.0, 3.0, 0.1) - mass(2.0, 3.0, 0.1).transpose()).amax() < 1e-12);
+
+        // Rigid rotation about z by a small angle: v = x θ, θz = θ.
+        let mut rigid = Vector12::zeros();
+        rigid[5] = 1.0;
+        rigid[7] = 2.0;
+        rigid[11] = 1.0;
+        assert!((k * rigid).amax() < 1e-12);
+        // Same about y: w = -x θ.
+        let mut rigid = Vector12::zeros();
+        rigid[4] = 1.0;
+        rigid[8] = -2.0;
+        rigid[10] = 1.0;
+        assert!((k * rigid).amax() < 1e-12);
+
+        // Rigid translation.
+        let mut rigid = Vector12::zeros();
+        rigid[2] = 1.0;
+        rigid[8] = 1.0;
+        assert!((geometric_stiffness(2.0, 1.0) * rigid).amax() < 1e-12);
+    }
+}
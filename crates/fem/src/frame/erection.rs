@@ -0,0 +1,90 @@
+//! Feasibility of an erection sequence: every stage must be stable and keep the
+//! members within their temporary capacities.
+
+use structure::{ErectionSequence, Instability, MemberId, Model};
+
+use super::{FrameAnalysis, StaticResult};
+
+/// Why a stage is not feasible.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StageFailure {
+    /// The stage structure has mechanisms; ids refer to the stage model.
+    Unstable(Vec<Instability>),
+    /// The stiffness matrix is singular although no mechanism was detected.
+    Singular,
+    /// A member exceeds its temporary capacity.
+    Overstressed { member: MemberId, utilization: f64 },
+}
+
+/// Outcome of one stage. The governing member is the one with the highest
+/// utilization among members with a capacity, by its id in the complete model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageCheck {
+    pub stage: usize,
+    pub governing: Option<(MemberId, f64)>,
+    pub failure: Option<StageFailure>,
+}
+
+impl StageCheck {
+    pub fn is_feasible(&self) -> bool { self.failure.is_none() }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErectionReport {
+    stages: Vec<StageCheck>,
+}
+
+impl ErectionReport {
+    pub fn stages(&self) -> &[StageCheck] { &self.stages }
+
+    pub fn first_failure(&self) -> Option<&StageCheck> { self.stages.iter().find(|check| !check.is_feasible()) }
+
+    pub fn is_feasible(&self) -> bool { self.first_failure().is_none() }
+}
+
+/// Check every stage of `sequence` with a linear analysis of the partial
+/// structure under the stage loads. Member forces are checked at the member
+/// ends, using the resultant of the two bending moments.
+pub fn check_erection(model: &Model, sequence: &ErectionSequence) -> ErectionReport {
+    let stages = (0..sequence.stages().len()).map(|stage| check_stage(model, sequence, stage)).collect();
+    ErectionReport { stages }
+}
+
+fn check_stage(model: &Model, sequence: &ErectionSequence, stage: usize) -> StageCheck {
+    let staged = sequence.stage_model(model, stage);
+    let mut check = StageCheck { stage, governing: None, failure: None };
+    if staged.members.is_empty() {
+        return check;
+    }
+    let instabilities = staged.model.instabilities();
+    if !instabilities.is_empty() {
+        check.failure = Some(StageFailure::Unstable(instabilities));
+        return check;
+    }
+    let Some(result) = FrameAnalysis::new(&staged.model).solve(staged.model.load_case(0)) else {
+        check.failure = Some(StageFailure::Singular);
+        return check;
+    };
+
+    for (local, &member) in staged.members.iter().enumerate() {
+        let Some(capacity) = sequence.capacity(member) else { continue };
+        let (axial, moment) = end_forces(&result, local);
+        let utilization = capacity.utilization(axial, moment);
+        if check.governing.is_none_or(|(_, governing)| utilization > governing) {
+            check.governing = Some((member, utilization));
+        }
+    }
+    if let Some((member, utilization)) = check.governing
+        && utilization > 1.0
+    {
+        check.failure = Some(StageFailure::Overstressed { member, utilization });
+    }
+    check
+}
+
+/// Axial force and the larger resultant end moment of a member.
+pub(super) fn end_forces(result: &StaticResult, member: MemberId) -> (f64, f64) {
+    let forces = result.member_forces(member);
+    let moment = forces[4].hypot(forces[5]).max(forces[10].hypot(forces[11]));
+    (result.axial_force(member), moment)
+}
@@ -0,0 +1,114 @@
+//! Influence coefficients of unit nodal loads, for evaluating many load
+//! placements (vehicle positions, equipment layouts) without re-solving.
+
+use nalgebra::{DMatrix, DVector};
+use structure::{MemberId, NodeId};
+
+use super::{FrameAnalysis, StaticResult};
+use crate::results::{Location, Quantity};
+
+/// A result value an influence matrix row tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub quantity: Quantity,
+    pub location: Location,
+}
+
+impl Response {
+    pub fn displacement(node: NodeId, dof: usize) -> Self {
+        Self { quantity: Quantity::Displacement, location: Location::Dof { node, dof } }
+    }
+
+    pub fn reaction(node: NodeId, dof: usize) -> Self {
+        Self { quantity: Quantity::Reaction, location: Location::Dof { node, dof } }
+    }
+
+    /// Member end force in local axes, `entry` ordered like the local DOFs.
+    pub fn member_force(member: MemberId, entry: usize) -> Self {
+        Self { quantity: Quantity::MemberForce, location: Location::MemberEnd { member, entry } }
+    }
+
+    fn value(&self, result: &StaticResult) -> f64 {
+        match (self.quantity, self.location) {
+            (Quantity::Displacement, Location::Dof { node, dof }) => result.displacement(node, dof),
+            (Quantity::Reaction, Location::Dof { node, dof }) => result.reaction(node, dof),
+            (Quantity::MemberForce, Location::MemberEnd { member, entry }) => result.member_forces(member)[entry],
+            _ => panic!("{} is not available at {}", self.quantity, self.location),
+        }
+    }
+}
+
+/// Responses to unit loads: entry `(i, j)` is response `i` under a unit load
+/// in global DOF `loads[j]`. By linearity, any combination of loads at these
+/// DOFs follows from a matrix-vector product.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfluenceMatrix {
+    loads: Vec<(NodeId, usize)>,
+    responses: Vec<Response>,
+    coefficients: DMatrix<f64>,
+}
+
+impl InfluenceMatrix {
+    /// Load positions as node and global DOF, in column order.
+    pub fn loads(&self) -> &[(NodeId, usize)] { &self.loads }
+    pub fn responses(&self) -> &[Response] { &self.responses }
+    pub fn coefficients(&self) -> &DMatrix<f64> { &self.coefficients }
+
+    pub fn coefficient(&self, response: usize, load: usize) -> f64 { self.coefficients[(response, load)] }
+
+    /// Responses to load magnitudes given per load position.
+    pub fn evaluate(&self, magnitudes: &DVector<f64>) -> DVector<f64> {
+        assert_eq!(magnitudes.len(), self.loads.len(), "one magnitude is needed per load position");
+        &self.coefficients * magnitudes
+    }
+
+    /// Responses to a placement listing `(load position, magnitude)` pairs, e.g.
+    /// the axles of a vehicle at one position along a bridge.
+    pub fn evaluate_placement(&self, placement: &[(usize, f64)]) -> DVector<f64> {
+        let mut responses = DVector::zeros(self.responses.len());
+        for &(load, magnitude) in placement {
+            responses += self.coefficients.column(load) * magnitude;
+        }
+        responses
+    }
+
+    /// Smallest and largest value of every response over a set of placements.
+    /// Both are zero without placements.
+    pub fn envelope<'p, I: IntoIterator<Item = &'p [(usize, f64)]>>(&self, placements: I) -> (DVector<f64>, DVector<f64>) {
+        let mut envelope: Option<(DVector<f64>, DVector<f64>)> = None;
+        for placement in placements {
+            let responses = self.evaluate_placement(placement);
+            envelope = Some(match envelope {
+                Some((min, max)) => (min.inf(&responses), max.sup(&responses)),
+                None => (responses.clone(), responses),
+            });
+        }
+        envelope.unwrap_or_else(|| (DVector::zeros(self.responses.len()), DVector::zeros(self.responses.len())))
+    }
+}
+
+impl FrameAnalysis<'_> {
+    /// Influence matrix of `responses` for unit nodal loads at `loads`, given as
+    /// node and global DOF. The stiffness is factorized once for all load
+    /// positions. Returns `None` when the supported structure is a mechanism.
+    pub fn influence(&self, loads: &[(NodeId, usize)], responses: &[Response]) -> Option<InfluenceMatrix> {
+        assert!(loads.iter().all(|&(node, dof)| node < self.model.nodes().len() && dof < 6), "load position out of range");
+        let k = self.stiffness();
+        let free = self.free_dofs();
+        let k_ff = k.select_rows(&free).select_columns(&free);
+        let mut f = DMatrix::zeros(self.dof_count(), loads.len());
+        for (column, &(node, dof)) in loads.iter().enumerate() {
+            f[(6 * node + dof, column)] = 1.0;
+        }
+        let u_f = self.backend.solve(&k_ff, &f.select_rows(&free))?;
+
+        let mut coefficients = DMatrix::zeros(responses.len(), loads.len());
+        for column in 0..loads.len() {
+            let result = self.complete(&k, &f.column(column).into_owned(), &free, u_f.column(column).iter().copied(), &[]);
+            for (row, response) in responses.iter().enumerate() {
+                coefficients[(row, column)] = response.value(&result);
+            }
+        }
+        Some(InfluenceMatrix { loads: loads.to_vec(), responses: responses.to_vec(), coefficients })
+    }
+}
@@ -0,0 +1,89 @@
+//! Lifting analysis of a sub-assembly: the amplified self-weight is carried to
+//! the lifting points, giving the sling forces and the member forces during
+//! the lift.
+
+use nalgebra::Vector3;
+use structure::{Lift, MemberId, Model, NodeId};
+
+use super::{
+    erection::{end_forces, StageFailure},
+    FrameAnalysis,
+};
+
+/// Force in the sling at one lifting point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlingForce {
+    /// Lifting point, by its id in the complete model.
+    pub node: NodeId,
+    /// Support reaction at the lifting point, the force the sling has to apply.
+    pub reaction: Vector3<f64>,
+    /// Sling tension carrying the vertical reaction along the sling.
+    pub tension: f64,
+    /// Sling angle from the vertical, in radians.
+    pub angle: f64,
+}
+
+/// Outcome of a lift. The governing member is the one with the highest
+/// utilization among members with a capacity, by its id in the complete model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiftReport {
+    /// Self-weight of the assembly without the dynamic factor.
+    pub weight: f64,
+    /// Sling forces in lifting point order; empty when the lift failed to solve.
+    pub slings: Vec<SlingForce>,
+    pub governing: Option<(MemberId, f64)>,
+    pub failure: Option<StageFailure>,
+}
+
+impl LiftReport {
+    pub fn is_feasible(&self) -> bool { self.failure.is_none() }
+
+    /// Sum of the sling tensions; exceeds the hook load for inclined slings.
+    pub fn total_tension(&self) -> f64 { self.slings.iter().map(|sling| sling.tension).sum() }
+}
+
+/// Analyse a lift with a linear analysis of the lifted assembly. Inclined
+/// slings change the tension only; the horizontal sling components squeezing
+/// the assembly are left to the restraint at the lifting points.
+pub fn check_lift(model: &Model, lift: &Lift) -> LiftReport {
+    let lifted = lift.lift_model(model);
+    let mut report = LiftReport { weight: lift.weight(model), slings: Vec::new(), governing: None, failure: None };
+    let instabilities = lifted.model.instabilities();
+    if !instabilities.is_empty() {
+        report.failure = Some(StageFailure::Unstable(instabilities));
+        return report;
+    }
+    let Some(result) = FrameAnalysis::new(&lifted.model).solve(lifted.model.load_case(0)) else {
+        report.failure = Some(StageFailure::Singular);
+        return report;
+    };
+
+    for &(node, _) in lift.lifting_points() {
+        let index = lifted.nodes.binary_search(&node).expect("lifting point of the assembly");
+        let reaction = Vector3::from_fn(|dof, _| result.reaction(index, dof));
+        let (tension, angle) = match lift.hook() {
+            Some(hook) => {
+                let direction = (hook.0 - model.node(node).center().0).normalize();
+                assert!(direction.z > 0.0, "crane hook must be above the lifting points");
+                (reaction.z / direction.z, direction.z.acos())
+            }
+            None => (reaction.z, 0.0),
+        };
+        report.slings.push(SlingForce { node, reaction, tension, angle });
+    }
+
+    for (local, &member) in lifted.members.iter().enumerate() {
+        let Some(capacity) = lift.capacity(member) else { continue };
+        let (axial, moment) = end_forces(&result, local);
+        let utilization = capacity.utilization(axial, moment);
+        if report.governing.is_none_or(|(_, governing)| utilization > governing) {
+            report.governing = Some((member, utilization));
+        }
+    }
+    if let Some((member, utilization)) = report.governing
+        && utilization > 1.0
+    {
+        report.failure = Some(StageFailure::Overstressed { member, utilization });
+    }
+    report
+}
@@ -0,0 +1,289 @@
+//! Linear analysis of 3D frames built as a [`structure::Model`].
+//!
+//! Every node carries six global DOFs `[ux, uy, uz, rx, ry, rz]`, numbered
+//! `6 * node + dof`. Members are two-node Euler-Bernoulli beams oriented by their
+//! local axes; supports prescribe zero displacement on the restrained DOFs and
+//! support patches add translational springs to their nodes.
+
+use nalgebra::{DMatrix, DVector, Vector3};
+use structure::{Load, LoadCase, MemberId, Model, NodeId};
+
+use crate::backend::{self, Eigen, LinearAlgebraBackend};
+
+pub mod collapse;
+pub mod design;
+pub mod element;
+pub mod erection;
+pub mod influence;
+pub mod lifting;
+
+use element::{Matrix12, Vector12};
+
+/// Displacements, support reactions and member end forces of a load case.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticResult {
+    displacements: DVector<f64>,
+    reactions: DVector<f64>,
+    member_forces: Vec<Vector12>,
+}
+
+impl StaticResult {
+    /// Result assembled from stored values, e.g. a baseline read back for
+    /// [`crate::results::compare`].
+    pub fn new(displacements: DVector<f64>, reactions: DVector<f64>, member_forces: Vec<Vector12>) -> Self {
+        assert_eq!(displacements.len(), reactions.len(), "displacements and reactions must cover the same DOFs");
+        Self { displacements, reactions, member_forces }
+    }
+
+    /// Global displacement vector over all DOFs.
+    pub fn displacements(&self) -> &DVector<f64> { &self.displacements }
+    /// Global reaction vector; on patch-supported DOFs, the spring forces. Zero on
+    /// other unrestrained DOFs.
+    pub fn reactions(&self) -> &DVector<f64> { &self.reactions }
+
+    pub fn displacement(&self, node: NodeId, dof: usize) -> f64 { self.displacements[6 * node + dof] }
+    pub fn reaction(&self, node: NodeId, dof: usize) -> f64 { self.reactions[6 * node + dof] }
+
+    /// End forces of a member in its local axes, ordered like the local DOFs. The
+    /// axial force (tension positive) is the seventh entry.
+    pub fn member_forces(&self, member: MemberId) -> &Vector12 { &self.member_forces[member] }
+
+    pub fn axial_force(&self, member: MemberId) -> f64 { self.member_forces[member][6] }
+
+    pub fn member_count(&self) -> usize { self.member_forces.len() }
+}
+
+/// Eigenvalues and global mode shapes, lowest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigenResult {
+    values: DVector<f64>,
+    shapes: DMatrix<f64>,
+}
+
+impl EigenResult {
+    /// Squared circular frequencies for modal analysis, load factors for buckling.
+    pub fn values(&self) -> &DVector<f64> { &self.values }
+    /// Mode shapes column-wise over all global DOFs.
+    pub fn shapes(&self) -> &DMatrix<f64> { &self.shapes }
+
+    /// Circular natural frequency of a mode when the values are `ω²`.
+    pub fn circular_frequency(&self, mode: usize) -> f64 { self.values[mode].sqrt() }
+}
+
+pub struct FrameAnalysis<'a> {
+    model: &'a Model,
+    backend: Box<dyn LinearAlgebraBackend>,
+}
+
+impl<'a> FrameAnalysis<'a> {
+    pub fn new(model: &'a Model) -> Self {
+        Self { model, backend: backend::default_backend() }
+    }
+
+    pub fn with_backend(mut self, backend: Box<dyn LinearAlgebraBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    pub fn model(&self) -> &Model { self.model }
+
+    pub fn dof_count(&self) -> usize { 6 * self.model.nodes().len() }
+
+    /// Unrestrained global DOFs in ascending order.
+    pub fn free_dofs(&self) -> Vec<usize> {
+        (0..self.dof_count())
+            .filter(|&dof| !self.model.support(dof / 6).is_some_and(|fixity| fixity.is_restrained(dof % 6)))
+            .collect()
+    }
+
+    fn member_dofs(&self, member: MemberId) -> [usize; 12] {
+        let [start, end] = self.model.member_nodes(member);
+        std::array::from_fn(|i| if i < 6 { 6 * start + i } else { 6 * end + i - 6 })
+    }
+
+    fn transformation(&self, member: MemberId) -> Matrix12 {
+        element::transformation(&self.model.member(member).rotation_matrix())
+    }
+
+    fn assemble<F: Fn(MemberId) -> Matrix12>(&self, local: F) -> DMatrix<f64> {
+        let n = self.dof_count();
+        let mut global = DMatrix::zeros(n, n);
+        for member in 0..self.model.members().len() {
+            let t = self.transformation(member);
+            let matrix = t.transpose() * local(member) * t;
+            let dofs = self.member_dofs(member);
+            for (i, &a) in dofs.iter().enumerate() {
+                for (j, &b) in dofs.iter().enumerate() {
+                    global[(a, b)] += matrix[(i, j)];
+                }
+            }
+        }
+        global
+    }
+
+    fn local_stiffness(&self, member: MemberId) -> Matrix12 {
+        let member = self.model.member(member);
+        let section = member.get_section().expect("frame members need a section");
+        let material = section.material();
+        let e = material.young_modulus();
+        element::stiffness(
+            member.length(),
+            e * section.area(),
+            material.shear_modulus() * section.torsion_constant(),
+            [e * section.second_moment_of_area_y(), e * section.second_moment_of_area_z()],
+        )
+    }
+
+    /// Global elastic stiffness over all DOFs, support patch springs included.
+    pub fn stiffness(&self) -> DMatrix<f64> {
+        let mut k = self.assemble(|member| self.local_stiffness(member));
+        for (dof, spring) in self.patch_springs() {
+            k[(dof, dof)] += spring;
+        }
+        k
+    }
+
+    /// Spring stiffness of the support patches on each global DOF they act on.
+    fn patch_springs(&self) -> Vec<(usize, f64)> {
+        let mut springs = Vec::new();
+        for patch in self.model.support_patches() {
+            for (node, stiffness) in patch.node_stiffness() {
+                springs.extend((0..3).filter(|&i| stiffness[i] > 0.0).map(|i| (6 * node + i, stiffness[i])));
+            }
+        }
+        springs
+    }
+
+    /// Global consistent mass over all DOFs, from the material density.
+    pub fn mass(&self) -> DMatrix<f64> {
+        self.assemble(|member| {
+            let member = self.model.member(member);
+            let section = member.get_section().expect("frame members need a section");
+            let density = section.material().density();
+            let polar = section.second_moment_of_area_y() + section.second_moment_of_area_z();
+            element::mass(member.length(), density * section.area(), density * polar)
+        })
+    }
+
+    /// Global geometric stiffness for the member axial forces of `result`.
+    pub fn geometric_stiffness(&self, result: &StaticResult) -> DMatrix<f64> {
+        self.assemble(|member| element::geometric_stiffness(self.model.member(member).length(), result.axial_force(member)))
+    }
+
+    /// Equivalent nodal loads of a load case.
+    pub fn load_vector(&self, case: &LoadCase) -> DVector<f64> {
+        let mut f = DVector::zeros(self.dof_count());
+        for load in case.loads() {
+            match load {
+                Load::Nodal { node, force, moment } => {
+                    for i in 0..3 {
+                        f[6 * node + i] += force.0[i];
+                        f[6 * node + 3 + i] += moment.0[i];
+                    }
+                }
+                Load::MemberUniform { member, load } => {
+                    let f_local = self.fixed_end_forces(*member, load.0);
+                    let f_global = self.transformation(*member).transpose() * f_local;
+                    for (i, &dof) in self.member_dofs(*member).iter().enumerate() {
+                        f[dof] += f_global[i];
+                    }
+                }
+            }
+        }
+        f
+    }
+
+    fn fixed_end_forces(&self, member: MemberId, global_load: Vector3<f64>) -> Vector12 {
+        let member = self.model.member(member);
+        element::uniform_load(member.length(), member.rotation_matrix().transpose() * global_load)
+    }
+
+    /// Linear static solution of a load case. Returns `None` when the supported
+    /// structure is a mechanism; [`Model::instabilities`] reports which part.
+    pub fn solve(&self, case: &LoadCase) -> Option<StaticResult> {
+        let k = self.stiffness();
+        let f = self.load_vector(case);
+        let free = self.free_dofs();
+        let k_ff = k.select_rows(&free).select_columns(&free);
+        let f_f = DMatrix::from_iterator(free.len(), 1, free.iter().map(|&i| f[i]));
+        let u_f = self.backend.solve(&k_ff, &f_f)?;
+        Some(self.complete(&k, &f, &free, u_f.column(0).iter().copied(), case.loads()))
+    }
+
+    /// Static result from the displacements of the free DOFs: reactions from
+    /// the out-of-balance forces, member end forces less the fixed-end forces of
+    /// the member `loads`.
+    fn complete<I: IntoIterator<Item = f64>>(
+        &self,
+        k: &DMatrix<f64>,
+        f: &DVector<f64>,
+        free: &[usize],
+        free_displacements: I,
+        loads: &[Load],
+    ) -> StaticResult {
+        let mut displacements = DVector::zeros(self.dof_count());
+        for (&dof, value) in free.iter().zip(free_displacements) {
+            displacements[dof] = value;
+        }
+        let mut reactions = k * &displacements - f;
+        for &dof in free {
+            reactions[dof] = 0.0;
+        }
+        for (dof, spring) in self.patch_springs() {
+            if free.binary_search(&dof).is_ok() {
+                reactions[dof] -= spring * displacements[dof];
+            }
+        }
+
+        let mut member_forces = Vec::with_capacity(self.model.members().len());
+        for (member, _) in self.model.members().iter().enumerate() {
+            let dofs = self.member_dofs(member);
+            let u = Vector12::from_iterator(dofs.iter().map(|&dof| displacements[dof]));
+            let mut forces = self.local_stiffness(member) * self.transformation(member) * u;
+            for load in loads {
+                if let Load::MemberUniform { member: loaded, load } = load
+                    && *loaded == member
+                {
+                    forces -= self.fixed_end_forces(member, load.0);
+                }
+            }
+            member_forces.push(forces);
+        }
+        StaticResult { displacements, reactions, member_forces }
+    }
+
+    /// Lowest `count` natural modes; the values are `ω²` and shapes are
+    /// mass-normalized.
+    pub fn modes(&self, count: usize) -> Option<EigenResult> {
+        let free = self.free_dofs();
+        let k = self.stiffness().select_rows(&free).select_columns(&free);
+        let m = self.mass().select_rows(&free).select_columns(&free);
+        let eigen = self.backend.generalized_eigen(&k, &m)?;
+        Some(self.expand(eigen, &free, count))
+    }
+
+    /// Lowest `count` linear buckling load factors of a load case, solving
+    /// `(K + λ Kg) φ = 0` with `Kg` from the linear axial forces.
+    pub fn buckling(&self, case: &LoadCase, count: usize) -> Option<EigenResult> {
+        let result = self.solve(case)?;
+        let free = self.free_dofs();
+        let k = self.stiffness().select_rows(&free).select_columns(&free);
+        let kg = self.geometric_stiffness(&result).select_rows(&free).select_columns(&free);
+
+        // K is positive definite while -Kg is indefinite in general, so solve
+        // `-Kg φ = μ K φ` and keep the positive `μ = 1 / λ`.
+        let eigen = backend::inverse_eigen(self.backend.as_ref(), &k, &(-kg), |phi| phi.normalize())?;
+        Some(self.expand(eigen, &free, count))
+    }
+
+    fn expand(&self, eigen: Eigen, free: &[usize], count: usize) -> EigenResult {
+        let count = count.min(eigen.values.len());
+        let mut shapes = DMatrix::zeros(self.dof_count(), count);
+        for mode in 0..count {
+            for (i, &dof) in free.iter().enumerate() {
+                shapes[(dof, mode)] = eigen.vectors[(i, mode)];
+            }
+        }
+        EigenResult { values: eigen.values.rows(0, count).into_owned(), shapes }
+    }
+}
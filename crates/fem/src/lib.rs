@@ -1,3 +1,10 @@
+pub mod backend;
+pub mod frame;
+pub mod modal;
+pub mod nonlinear;
+pub mod results;
+pub mod statespace;
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
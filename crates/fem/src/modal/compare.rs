@@ -0,0 +1,152 @@
+use nalgebra::{DMatrix, DVector};
+
+/// Modal assurance criterion between two mode shape vectors.
+///
+/// Returns a value in `[0, 1]`: one for shapes that are scalar multiples of each
+/// other, zero for orthogonal shapes. Zero vectors yield zero.
+pub fn mac(a: &DVector<f64>, b: &DVector<f64>) -> f64 {
+    assert_eq!(a.len(), b.len(), "mode shapes must have the same number of DOFs");
+    let aa = a.dot(a);
+    let bb = b.dot(b);
+    if aa <= 0.0 || bb <= 0.0 {
+        return 0.0;
+    }
+    let ab = a.dot(b);
+    (ab * ab) / (aa * bb)
+}
+
+/// MAC matrix between two mode sets. Entry `(i, j)` compares reference mode `i`
+/// with candidate mode `j`.
+pub fn mac_matrix(reference: &DMatrix<f64>, candidate: &DMatrix<f64>) -> DMatrix<f64> {
+    assert_eq!(
+        reference.nrows(),
+        candidate.nrows(),
+        "mode sets must have the same number of DOFs"
+    );
+    DMatrix::from_fn(reference.ncols(), candidate.ncols(), |i, j| {
+        mac(&reference.column(i).into_owned(), &candidate.column(j).into_owned())
+    })
+}
+
+/// MAC matrix of a mode set against itself; off-diagonal terms reveal spatial aliasing.
+pub fn auto_mac(modes: &DMatrix<f64>) -> DMatrix<f64> {
+    mac_matrix(modes, modes)
+}
+
+/// Pairing of a reference mode with the candidate mode that best correlates with it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModeMatch {
+    pub reference: usize,
+    pub candidate: usize,
+    pub mac: f64,
+}
+
+/// Pair each reference mode with at most one candidate mode.
+///
+/// Pairs are assigned greedily by decreasing MAC so the strongest correlations win,
+/// and each candidate is used once. Reference modes without a candidate reaching
+/// `threshold` map to `None`.
+pub fn match_modes(reference: &DMatrix<f64>, candidate: &DMatrix<f64>, threshold: f64) -> Vec<Option<ModeMatch>> {
+    let macs = mac_matrix(reference, candidate);
+    let mut pairs: Vec<ModeMatch> = Vec::with_capacity(macs.len());
+    for i in 0..macs.nrows() {
+        for j in 0..macs.ncols() {
+            if macs[(i, j)] >= threshold {
+                pairs.push(ModeMatch { reference: i, candidate: j, mac: macs[(i, j)] });
+            }
+        }
+    }
+    pairs.sort_by(|a, b| b.mac.total_cmp(&a.mac));
+
+    let mut result: Vec<Option<ModeMatch>> = vec![None; reference.ncols()];
+    let mut used = vec![false; candidate.ncols()];
+    for pair in pairs {
+        if result[pair.reference].is_none() && !used[pair.candidate] {
+            result[pair.reference] = Some(pair);
+            used[pair.candidate] = true;
+        }
+    }
+    result
+}
+
+/// Follows a set of modes through a sequence of parameter changes.
+///
+/// After every step the tracked shapes are replaced by their matched candidates,
+/// so gradual changes (mode veering, crossing) are followed step by step rather
+/// than compared against the initial shapes only.
+#[derive(Debug, Clone)]
+pub struct ModeTracker {
+    modes: DMatrix<f64>,
+    threshold: f64,
+}
+
+impl ModeTracker {
+    pub fn new(reference: DMatrix<f64>, threshold: f64) -> Self {
+        Self { modes: reference, threshold }
+    }
+
+    /// Currently tracked mode shapes (one column per tracked mode).
+    pub fn modes(&self) -> &DMatrix<f64> { &self.modes }
+
+    pub fn threshold(&self) -> f64 { self.threshold }
+
+    /// Match the next candidate set against the tracked modes and update them.
+    ///
+    /// Tracked modes that lose their match keep their previous shape. Matched
+    /// candidates are sign-aligned with the mode they replace.
+    pub fn track(&mut self, candidate: &DMatrix<f64>) -> Vec<Option<ModeMatch>> {
+        let matches = match_modes(&self.modes, candidate, self.threshold);
+        for found in matches.iter().flatten() {
+            let previous = self.modes.column(found.reference).into_owned();
+            let mut next = candidate.column(found.candidate).into_owned();
+            if previous.dot(&next) < 0.0 {
+                next = -next;
+            }
+            self.modes.set_column(found.reference, &next);
+        }
+        matches
+    }
+
+    /// Reorder a candidate set so column `i` holds the match for tracked mode `i`.
+    /// Unmatched positions are `None`.
+    pub fn reorder(candidate: &DMatrix<f64>, matches: &[Option<ModeMatch>]) -> Vec<Option<DVector<f64>>> {
+        matches
+            .iter()
+            .map(|found| found.map(|m| candidate.column(m.candidate).into_owned()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    #[test]
+    fn mac_is_scale_invariant_and_zero_for_orthogonal_shapes() {
+        let a = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+        let b = DVector::from_vec(vec![-2.0, -4.0, -6.0]);
+        let c = DVector::from_vec(vec![3.0, 0.0, -1.0]);
+        assert_almost_eq!(mac(&a, &b), 1.0);
+        assert_almost_eq!(mac(&a, &c), 0.0);
+        assert_almost_eq!(mac(&a, &DVector::zeros(3)), 0.0);
+    }
+
+    #[test]
+    fn match_modes_resolves_swapped_order() {
+        let reference = DMatrix::from_columns(&[
+            DVector::from_vec(vec![1.0, 0.0, 0.0]),
+            DVector::from_vec(vec![0.0, 1.0, 0.0]),
+        ]);
+        let candidate = DMatrix::from_columns(&[
+            DVector::from_vec(vec![0.1, 1.0, 0.0]),
+            DVector::from_vec(vec![1.0, -0.1, 0.0]),
+            DVector::from_vec(vec![0.0, 0.0, 1.0]),
+        ]);
+        let matches = match_modes(&reference, &candidate, 0.9);
+        assert_eq!(matches[0].unwrap().candidate, 1);
+        assert_eq!(matches[1].unwrap().candidate, 0);
+        assert!(matches[0].unwrap().mac > 0.9);
+    }
+}
@@ -0,0 +1,139 @@
+use nalgebra::{DMatrix, DVector};
+
+/// Stiffness and mass matrices condensed onto a set of master (sensor) DOFs.
+///
+/// `transformation` maps master displacements to the full DOF vector, so the
+/// reduced matrices are `Tᵀ K T` and `Tᵀ M T`. Reduced DOFs follow the order of
+/// the `master` slice passed to the reduction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReducedModel {
+    master: Vec<usize>,
+    transformation: DMatrix<f64>,
+    stiffness: DMatrix<f64>,
+    mass: DMatrix<f64>,
+}
+
+impl ReducedModel {
+    pub fn master(&self) -> &[usize] { &self.master }
+    pub fn transformation(&self) -> &DMatrix<f64> { &self.transformation }
+    pub fn stiffness(&self) -> &DMatrix<f64> { &self.stiffness }
+    pub fn mass(&self) -> &DMatrix<f64> { &self.mass }
+
+    /// Expand a vector of master DOF values (e.g. a measured mode shape) to all DOFs.
+    pub fn expand(&self, reduced: &DVector<f64>) -> DVector<f64> {
+        &self.transformation * reduced
+    }
+
+    fn from_transformation(master: &[usize], transformation: DMatrix<f64>, k: &DMatrix<f64>, m: &DMatrix<f64>) -> Self {
+        let stiffness = transformation.transpose() * k * &transformation;
+        let mass = transformation.transpose() * m * &transformation;
+        Self { master: master.to_vec(), transformation, stiffness, mass }
+    }
+}
+
+/// Static (Guyan) condensation onto `master` DOFs.
+///
+/// Exact for static loads applied at master DOFs; modes are accurate as long as
+/// the slave DOFs carry little inertia. Returns `None` when the slave stiffness
+/// block is singular (e.g. a slave DOF without stiffness).
+pub fn guyan(k: &DMatrix<f64>, m: &DMatrix<f64>, master: &[usize]) -> Option<ReducedModel> {
+    dynamic(k, m, master, 0.0)
+}
+
+/// Dynamic condensation at a shift `omega_sq` (ω², rad²/s²).
+///
+/// Slave DOFs follow the master DOFs through `(Kss - ω² Mss)⁻¹ (Ksm - ω² Msm)`,
+/// which is exact for modes with eigenvalue equal to the shift. A zero shift is
+/// Guyan reduction.
+pub fn dynamic(k: &DMatrix<f64>, m: &DMatrix<f64>, master: &[usize], omega_sq: f64) -> Option<ReducedModel> {
+    let shifted = k - m * omega_sq;
+    let transformation = static_transformation(&shifted, master)?;
+    Some(ReducedModel::from_transformation(master, transformation, k, m))
+}
+
+/// Improved Reduced System (IRS) condensation.
+///
+/// Corrects the Guyan transformation with the slave inertia forces it neglects:
+/// `T = Tg + S M Tg Mg⁻¹ Kg` where `S` holds `Kss⁻¹` on the slave block.
+pub fn irs(k: &DMatrix<f64>, m: &DMatrix<f64>, master: &[usize]) -> Option<ReducedModel> {
+    let guyan = guyan(k, m, master)?;
+    let slave = slave_dofs(k.nrows(), master);
+    if slave.is_empty() {
+        return Some(guyan);
+    }
+    let kss = k.select_rows(&slave).select_columns(&slave);
+    let kss_inv = kss.try_inverse()?;
+    let mut s = DMatrix::zeros(k.nrows(), k.ncols());
+    for (a, &i) in slave.iter().enumerate() {
+        for (b, &j) in slave.iter().enumerate() {
+            s[(i, j)] = kss_inv[(a, b)];
+        }
+    }
+    let mg_inv = guyan.mass.clone().try_inverse()?;
+    let correction = s * m * &guyan.transformation * mg_inv * &guyan.stiffness;
+    let transformation = &guyan.transformation + correction;
+    Some(ReducedModel::from_transformation(master, transformation, k, m))
+}
+
+fn slave_dofs(size: usize, master: &[usize]) -> Vec<usize> {
+    (0..size).filter(|dof| !master.contains(dof)).collect()
+}
+
+/// `T` with identity rows at master DOFs and `-Ass⁻¹ Asm` rows at slave DOFs.
+fn static_transformation(a: &DMatrix<f64>, master: &[usize]) -> Option<DMatrix<f64>> {
+    let n = a.nrows();
+    assert_eq!(n, a.ncols(), "system matrices must be square");
+    assert!(master.iter().all(|&dof| dof < n), "master DOF out of range");
+    let slave = slave_dofs(n, master);
+
+    let mut t = DMatrix::zeros(n, master.len());
+    for (col, &dof) in master.iter().enumerate() {
+        t[(dof, col)] = 1.0;
+    }
+    if slave.is_empty() {
+        return Some(t);
+    }
+
+    let ass = a.select_rows(&slave).select_columns(&slave);
+    let asm = a.select_rows(&slave).select_columns(master);
+    let slave_rows = ass.lu().solve(&asm)?;
+    for (row, &dof) in slave.iter().enumerate() {
+        for col in 0..master.len() {
+            t[(dof, col)] = -slave_rows[(row, col)];
+        }
+    }
+    Some(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    fn spring_chain() -> (DMatrix<f64>, DMatrix<f64>) {
+        // Three unit masses connected by unit springs, fixed at the left end.
+        let k = DMatrix::from_row_slice(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 1.0]);
+        let m = DMatrix::identity(3, 3);
+        (k, m)
+    }
+
+    #[test]
+    fn guyan_preserves_static_flexibility_at_masters() {
+        let (k, m) = spring_chain();
+        let reduced = guyan(&k, &m, &[2]).unwrap();
+        // Three unit springs in series seen from the tip.
+        assert_almost_eq!(reduced.stiffness()[(0, 0)], 1.0 / 3.0);
+        let expanded = reduced.expand(&DVector::from_vec(vec![3.0]));
+        assert_almost_eq!(expanded[0], 1.0);
+        assert_almost_eq!(expanded[1], 2.0);
+    }
+
+    #[test]
+    fn keeping_every_dof_is_identity() {
+        let (k, m) = spring_chain();
+        let reduced = irs(&k, &m, &[0, 1, 2]).unwrap();
+        assert_eq!(reduced.stiffness(), &k);
+        assert_eq!(reduced.mass(), &m);
+    }
+}
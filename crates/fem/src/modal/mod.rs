@@ -0,0 +1,8 @@
+//! Post-processing utilities for modal (eigenvalue) results.
+//!
+//! Mode shapes are stored column-wise in a `DMatrix<f64>`: every column is one
+//! mode and every row one degree of freedom.
+
+pub mod compare;
+pub mod condensation;
+pub mod superposition;
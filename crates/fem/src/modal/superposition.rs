@@ -0,0 +1,203 @@
+use nalgebra::{DMatrix, DVector};
+
+use crate::frame::EigenResult;
+
+/// Transient response by modal superposition: the load is projected onto a set
+/// of mass-normalized modes and every modal coordinate is integrated as a
+/// damped single-DOF oscillator `q̈ + 2 ζ ω q̇ + ω² q = φᵀ f(t)`.
+///
+/// The oscillators are stepped with the exact solution for a load varying
+/// linearly within each step, so the only approximations are the truncation to
+/// the given modes and the sampling of the load. A fast alternative to direct
+/// time integration for linear models dominated by a few modes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalSuperposition {
+    omega_sq: DVector<f64>,
+    shapes: DMatrix<f64>,
+    damping: DVector<f64>,
+}
+
+impl ModalSuperposition {
+    /// Modes given by their `ω²` and mass-normalized shapes stored column-wise,
+    /// undamped. Panics unless every `ω²` is positive; rigid-body modes cannot be
+    /// superposed this way.
+    pub fn new(omega_sq: DVector<f64>, shapes: DMatrix<f64>) -> Self {
+        assert_eq!(omega_sq.len(), shapes.ncols(), "one ω² is needed per mode shape");
+        assert!(omega_sq.iter().all(|&value| value > 0.0), "modes must have positive ω²");
+        let damping = DVector::zeros(omega_sq.len());
+        Self { omega_sq, shapes, damping }
+    }
+
+    /// Modes from [`crate::frame::FrameAnalysis::modes`].
+    pub fn from_eigen(eigen: &EigenResult) -> Self { Self::new(eigen.values().clone(), eigen.shapes().clone()) }
+
+    /// The same damping ratio for every mode.
+    pub fn with_damping(self, ratio: f64) -> Self {
+        let ratios = DVector::from_element(self.mode_count(), ratio);
+        self.with_modal_damping(ratios)
+    }
+
+    /// Damping ratio per mode. Panics unless every ratio is in `[0, 1)`.
+    pub fn with_modal_damping(mut self, ratios: DVector<f64>) -> Self {
+        assert_eq!(ratios.len(), self.mode_count(), "one damping ratio is needed per mode");
+        assert!(ratios.iter().all(|ratio| (0.0..1.0).contains(ratio)), "damping ratios must be subcritical");
+        self.damping = ratios;
+        self
+    }
+
+    pub fn mode_count(&self) -> usize { self.omega_sq.len() }
+    pub fn shapes(&self) -> &DMatrix<f64> { &self.shapes }
+    pub fn damping(&self) -> &DVector<f64> { &self.damping }
+
+    /// Modal forces `Φᵀ f` of a load vector over all DOFs.
+    pub fn modal_forces(&self, force: &DVector<f64>) -> DVector<f64> {
+        assert_eq!(force.len(), self.shapes.nrows(), "load vector must cover the DOFs of the mode shapes");
+        self.shapes.tr_mul(force)
+    }
+
+    /// Response to a fixed load pattern scaled by `time_function`, sampled
+    /// `steps` times after the start at rest at `t = 0`.
+    pub fn respond<F: Fn(f64) -> f64>(
+        &self,
+        force: &DVector<f64>,
+        time_function: F,
+        time_step: f64,
+        steps: usize,
+    ) -> TransientResponse {
+        let modal = self.modal_forces(force);
+        let forces: Vec<DVector<f64>> = (0..=steps).map(|i| &modal * time_function(i as f64 * time_step)).collect();
+        self.integrate(&forces, time_step)
+    }
+
+    /// Response to load vectors sampled at `t = i * time_step`, starting at rest.
+    pub fn respond_sampled(&self, forces: &[DVector<f64>], time_step: f64) -> TransientResponse {
+        let modal: Vec<DVector<f64>> = forces.iter().map(|force| self.modal_forces(force)).collect();
+        self.integrate(&modal, time_step)
+    }
+
+    fn integrate(&self, modal_forces: &[DVector<f64>], time_step: f64) -> TransientResponse {
+        assert!(time_step > 0.0, "time step must be positive");
+        assert!(!modal_forces.is_empty(), "at least the initial load sample is needed");
+        let samples = modal_forces.len();
+        let modes = self.mode_count();
+        let mut q = DMatrix::zeros(modes, samples);
+        let mut q_dot = DMatrix::zeros(modes, samples);
+        let mut q_ddot = DMatrix::zeros(modes, samples);
+        for mode in 0..modes {
+            let omega = self.omega_sq[mode].sqrt();
+            let zeta = self.damping[mode];
+            let step = LinearLoadStep::new(omega, zeta, time_step);
+            q_ddot[(mode, 0)] = modal_forces[0][mode];
+            for i in 1..samples {
+                let (u, v) = (q[(mode, i - 1)], q_dot[(mode, i - 1)]);
+                let (p0, p1) = (modal_forces[i - 1][mode], modal_forces[i][mode]);
+                q[(mode, i)] = step.a * u + step.b * v + step.c * p0 + step.d * p1;
+                q_dot[(mode, i)] = step.a_dot * u + step.b_dot * v + step.c_dot * p0 + step.d_dot * p1;
+                q_ddot[(mode, i)] = p1 - 2.0 * zeta * omega * q_dot[(mode, i)] - self.omega_sq[mode] * q[(mode, i)];
+            }
+        }
+        TransientResponse {
+            times: (0..samples).map(|i| i as f64 * time_step).collect(),
+            displacements: &self.shapes * &q,
+            velocities: &self.shapes * &q_dot,
+            accelerations: &self.shapes * &q_ddot,
+            modal: q,
+        }
+    }
+}
+
+/// Recurrence coefficients of a unit-mass oscillator under a load varying
+/// linearly over a step (Chopra, Dynamics of Structures, table 5.2.1).
+struct LinearLoadStep {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    a_dot: f64,
+    b_dot: f64,
+    c_dot: f64,
+    d_dot: f64,
+}
+
+impl LinearLoadStep {
+    fn new(omega: f64, zeta: f64, dt: f64) -> Self {
+        let k = omega * omega;
+        let root = (1.0 - zeta * zeta).sqrt();
+        let omega_d = omega * root;
+        let decay = (-zeta * omega * dt).exp();
+        let (sin, cos) = (omega_d * dt).sin_cos();
+        let ratio = zeta / root;
+        Self {
+            a: decay * (ratio * sin + cos),
+            b: decay * sin / omega_d,
+            c: (2.0 * zeta / (omega * dt)
+                + decay
+                    * (((1.0 - 2.0 * zeta * zeta) / (omega_d * dt) - ratio) * sin
+                        - (1.0 + 2.0 * zeta / (omega * dt)) * cos))
+                / k,
+            d: (1.0 - 2.0 * zeta / (omega * dt)
+                + decay * ((2.0 * zeta * zeta - 1.0) / (omega_d * dt) * sin + 2.0 * zeta / (omega * dt) * cos))
+                / k,
+            a_dot: -decay * omega / root * sin,
+            b_dot: decay * (cos - ratio * sin),
+            c_dot: (-1.0 / dt + decay * ((omega / root + ratio / dt) * sin + cos / dt)) / k,
+            d_dot: (1.0 - decay * (ratio * sin + cos)) / (k * dt),
+        }
+    }
+}
+
+/// Sampled response of a [`ModalSuperposition`]. Physical quantities are stored
+/// column-wise per sample over all DOFs of the mode shapes, modal coordinates
+/// per sample over the modes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransientResponse {
+    times: Vec<f64>,
+    modal: DMatrix<f64>,
+    displacements: DMatrix<f64>,
+    velocities: DMatrix<f64>,
+    accelerations: DMatrix<f64>,
+}
+
+impl TransientResponse {
+    pub fn times(&self) -> &[f64] { &self.times }
+    pub fn modal(&self) -> &DMatrix<f64> { &self.modal }
+    pub fn displacements(&self) -> &DMatrix<f64> { &self.displacements }
+    pub fn velocities(&self) -> &DMatrix<f64> { &self.velocities }
+    pub fn accelerations(&self) -> &DMatrix<f64> { &self.accelerations }
+
+    /// Time history of the displacement of one DOF.
+    pub fn displacement_history(&self, dof: usize) -> Vec<f64> { self.displacements.row(dof).iter().copied().collect() }
+
+    /// Largest displacement magnitude of one DOF and the time it occurs.
+    pub fn peak_displacement(&self, dof: usize) -> (f64, f64) {
+        let row = self.displacements.row(dof);
+        let (sample, value) = row.iter().enumerate().fold((0, 0.0f64), |peak, (i, &value)| {
+            if value.abs() > peak.1.abs() { (i, value) } else { peak }
+        });
+        (self.times[sample], value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    #[test]
+    fn damped_oscillator_under_step_load() {
+        // ω = 2, ζ = 0.05, unit mass, unit step force.
+        let (omega, zeta) = (2.0, 0.05);
+        let modes = ModalSuperposition::new(DVector::from_element(1, omega * omega), DMatrix::identity(1, 1))
+            .with_damping(zeta);
+        let response = modes.respond(&DVector::from_element(1, 1.0), |_| 1.0, 0.1, 200);
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        for (i, &t) in response.times().iter().enumerate() {
+            let exact = (1.0
+                - (-zeta * omega * t).exp() * ((omega_d * t).cos() + zeta / (1.0 - zeta * zeta).sqrt() * (omega_d * t).sin()))
+                / (omega * omega);
+            assert_almost_eq!(response.displacements()[(0, i)], exact, 1e-12);
+        }
+        assert_almost_eq!(response.accelerations()[(0, 0)], 1.0);
+    }
+}
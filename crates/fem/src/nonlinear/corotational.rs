@@ -0,0 +1,117 @@
+//! Planar frames with large displacements.
+//!
+//! Each member is a linear beam in a frame that follows its chord (corotational
+//! formulation), so rigid-body rotations of any size carry no strain. The frame
+//! lies in the global XZ plane with in-plane DOFs `[ux, uz, ry]` per node; the
+//! other three DOFs of the model are ignored.
+
+use nalgebra::{DMatrix, DVector, Matrix3, Matrix3x6, Matrix6, Vector3, Vector6};
+use structure::{Model, NodeId};
+
+use super::NonlinearSystem;
+
+/// Model DOFs `[ux, uz, ry]` carried in the plane.
+const IN_PLANE: [usize; 3] = [0, 2, 4];
+
+/// Corotational [`NonlinearSystem`] of a planar frame built from a [`Model`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorotationalFrame {
+    points: Vec<[f64; 2]>,
+    /// End nodes, axial stiffness EA and bending stiffness EI of each member.
+    members: Vec<([NodeId; 2], f64, f64)>,
+    /// Row of in-plane DOF `3 * node + k` in the system, `None` where restrained.
+    rows: Vec<Option<usize>>,
+    size: usize,
+}
+
+impl CorotationalFrame {
+    /// Frame of the XZ-plane nodes and members of `model`; every member needs a section.
+    pub fn new(model: &Model) -> Self {
+        let points = model.nodes().iter().map(|node| [node.center().x(), node.center().z()]).collect();
+        let members = (0..model.members().len())
+            .map(|id| {
+                let section = model.member(id).get_section().expect("frame members need a section");
+                let e = section.material().young_modulus();
+                (model.member_nodes(id), e * section.area(), e * section.second_moment_of_area_y())
+            })
+            .collect();
+        let restrained = |node: NodeId, dof: usize| model.support(node).is_some_and(|fixity| fixity.is_restrained(dof));
+        let mut size = 0;
+        let rows = (0..3 * model.nodes().len())
+            .map(|i| {
+                (!restrained(i / 3, IN_PLANE[i % 3])).then(|| {
+                    size += 1;
+                    size - 1
+                })
+            })
+            .collect();
+        Self { points, members, rows, size }
+    }
+
+    /// Row of model DOF `dof` of `node` in the system; `None` when it is
+    /// restrained or out of plane.
+    pub fn free_dof(&self, node: NodeId, dof: usize) -> Option<usize> {
+        let k = IN_PLANE.iter().position(|&d| d == dof)?;
+        self.rows[3 * node + k]
+    }
+
+    /// Internal forces and tangent stiffness of every member with its in-plane DOFs.
+    fn members_at(&self, u: &DVector<f64>) -> Vec<([usize; 6], Vector6<f64>, Matrix6<f64>)> {
+        let full = |i: usize| self.rows[i].map_or(0.0, |row| u[row]);
+        self.members
+            .iter()
+            .map(|&([a, b], ea, ei)| {
+                let dofs = [3 * a, 3 * a + 1, 3 * a + 2, 3 * b, 3 * b + 1, 3 * b + 2];
+                let d = Vector6::from_fn(|i, _| full(dofs[i]));
+                let (x0, z0) = (self.points[b][0] - self.points[a][0], self.points[b][1] - self.points[a][1]);
+                let (dx, dz) = (x0 + d[3] - d[0], z0 + d[4] - d[1]);
+                let (l0, ln) = (x0.hypot(z0), dx.hypot(dz));
+                let (c, s) = (dx / ln, dz / ln);
+                // Chord rotation, then end rotations relative to the chord.
+                let alpha = (x0 * dz - z0 * dx).atan2(x0 * dx + z0 * dz);
+                let (t1, t2) = (d[2] - alpha, d[5] - alpha);
+                let stiffness = Matrix3::new(ea / l0, 0.0, 0.0, 0.0, 4.0 * ei / l0, 2.0 * ei / l0, 0.0, 2.0 * ei / l0, 4.0 * ei / l0);
+                let local = stiffness * Vector3::new(ln - l0, t1, t2);
+                let r = Vector6::new(-c, -s, 0.0, c, s, 0.0);
+                let z = Vector6::new(s, -c, 0.0, -s, c, 0.0);
+                let b_rows = [r, Vector6::new(0.0, 0.0, 1.0, 0.0, 0.0, 0.0) - z / ln, Vector6::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0) - z / ln];
+                let b = Matrix3x6::from_rows(&b_rows.map(|row| row.transpose()));
+                let force = b.transpose() * local;
+                let tangent = b.transpose() * stiffness * b
+                    + z * z.transpose() * (local[0] / ln)
+                    + (r * z.transpose() + z * r.transpose()) * ((local[1] + local[2]) / (ln * ln));
+                (dofs, force, tangent)
+            })
+            .collect()
+    }
+}
+
+impl NonlinearSystem for CorotationalFrame {
+    fn size(&self) -> usize { self.size }
+
+    fn internal_force(&self, u: &DVector<f64>) -> DVector<f64> {
+        let mut force = DVector::zeros(self.size);
+        for (dofs, member, _) in self.members_at(u) {
+            for (i, &dof) in dofs.iter().enumerate() {
+                if let Some(row) = self.rows[dof] {
+                    force[row] += member[i];
+                }
+            }
+        }
+        force
+    }
+
+    fn tangent_stiffness(&self, u: &DVector<f64>) -> DMatrix<f64> {
+        let mut tangent = DMatrix::zeros(self.size, self.size);
+        for (dofs, _, member) in self.members_at(u) {
+            for (i, &row) in dofs.iter().enumerate() {
+                for (j, &column) in dofs.iter().enumerate() {
+                    if let (Some(row), Some(column)) = (self.rows[row], self.rows[column]) {
+                        tangent[(row, column)] += member[(i, j)];
+                    }
+                }
+            }
+        }
+        tangent
+    }
+}
@@ -0,0 +1,130 @@
+use nalgebra::{DMatrix, DVector};
+
+use super::NonlinearSystem;
+use super::log::{ConvergenceLog, IterationRecord, StepRecord};
+use super::newton::{NewtonSolver, NonlinearResult};
+
+/// Drive one DOF to a target displacement in equal increments, with the load
+/// factor as an additional unknown. Unlike load control this follows the
+/// equilibrium path past limit points (pushover curves, snap-through).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplacementControl {
+    /// Index of the controlled DOF.
+    pub dof: usize,
+    /// Displacement of the controlled DOF at the last step.
+    pub target: f64,
+    pub steps: usize,
+}
+
+impl DisplacementControl {
+    pub fn new(dof: usize, target: f64, steps: usize) -> Self { Self { dof, target, steps } }
+}
+
+/// Value prescribed on the controlled DOF in one step.
+struct Increment {
+    dof: usize,
+    step: usize,
+    prescribed: f64,
+}
+
+impl NewtonSolver {
+    /// Solve `f_int(u) = λ f_ext` with `u[dof]` prescribed at every step and `λ`
+    /// unknown. The load stepping and line search settings are not used; the
+    /// residual is measured relative to the norm of `external`. The log records
+    /// the converged load factor of every step, so
+    /// [`ConvergenceLog::equilibrium_path`] gives the load-displacement curve.
+    pub fn solve_displacement_controlled<S: NonlinearSystem>(
+        &self,
+        system: &S,
+        external: &DVector<f64>,
+        control: &DisplacementControl,
+    ) -> NonlinearResult {
+        assert_eq!(external.len(), system.size(), "load vector does not match the system size");
+        assert!(control.dof < system.size(), "controlled DOF is out of range");
+        assert!(control.steps > 0, "at least one displacement step is required");
+
+        let mut log = ConvergenceLog::new();
+        let mut u = DVector::zeros(system.size());
+        let mut load_factor = 0.0;
+        for step in 1..=control.steps {
+            let increment = Increment { dof: control.dof, step, prescribed: control.target * step as f64 / control.steps as f64 };
+            let (record, state) = self.controlled_step(system, external, &increment, &u, load_factor);
+            let converged = record.converged;
+            let reached = record.load_factor;
+            log.push(record);
+            if !converged {
+                return NonlinearResult { displacement: u, load_factor, converged: false, log };
+            }
+            u = state;
+            load_factor = reached;
+        }
+        NonlinearResult { displacement: u, load_factor, converged: true, log }
+    }
+
+    /// One displacement increment from the converged state `(u, λ)`. Each
+    /// iteration solves the tangent system for the residual and the reference
+    /// load together and combines them so the controlled DOF hits its value.
+    fn controlled_step<S: NonlinearSystem>(
+        &self,
+        system: &S,
+        external: &DVector<f64>,
+        increment: &Increment,
+        start: &DVector<f64>,
+        mut load_factor: f64,
+    ) -> (StepRecord, DVector<f64>) {
+        let &Increment { dof, step, prescribed } = increment;
+        let settings = self.settings();
+        let reference = external.norm().max(f64::MIN_POSITIVE);
+        let mut u = start.clone();
+        let mut residual = external * load_factor - system.internal_force(&u);
+        let initial_residual = residual.norm();
+        let mut iterations = Vec::new();
+        let mut tangent: Option<DMatrix<f64>> = None;
+
+        let mut converged = false;
+        for iteration in 1..=settings.max_iterations {
+            let stiffness_updated = settings.stiffness_update.is_due(iteration, tangent.is_none());
+            if stiffness_updated {
+                tangent = Some(system.tangent_stiffness(&u));
+            }
+            let mut rhs = DMatrix::zeros(residual.len(), 2);
+            rhs.set_column(0, external);
+            rhs.set_column(1, &residual);
+            let Some(solution) = self.backend.solve(tangent.as_ref().unwrap(), &rhs) else {
+                break;
+            };
+            let (unit, correction) = (solution.column(0), solution.column(1));
+            if unit[dof].abs() <= f64::EPSILON * unit.amax() {
+                // The reference load does not move the controlled DOF.
+                break;
+            }
+            let d_lambda = (prescribed - u[dof] - correction[dof]) / unit[dof];
+            let du = correction + unit * d_lambda;
+
+            u += &du;
+            u[dof] = prescribed;
+            load_factor += d_lambda;
+            residual = external * load_factor - system.internal_force(&u);
+            let residual_norm = residual.norm();
+            iterations.push(IterationRecord {
+                iteration,
+                residual_norm,
+                relative_residual: residual_norm / reference,
+                increment_norm: du.norm(),
+                line_search_factor: 1.0,
+                stiffness_updated,
+            });
+
+            if !residual_norm.is_finite() || residual_norm > settings.divergence_ratio * initial_residual.max(reference) {
+                break;
+            }
+            converged = residual_norm / reference <= settings.tolerance;
+            if converged {
+                break;
+            }
+        }
+
+        let record = StepRecord { step, load_factor, initial_residual, iterations, converged, checkpoint: u.clone() };
+        (record, u)
+    }
+}
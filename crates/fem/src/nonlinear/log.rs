@@ -0,0 +1,111 @@
+use std::fmt::Write;
+
+use nalgebra::DVector;
+
+/// One Newton iteration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterationRecord {
+    /// Iteration number within the step, starting at one.
+    pub iteration: usize,
+    /// Out-of-balance force norm after the update.
+    pub residual_norm: f64,
+    /// Residual norm relative to the applied load norm.
+    pub relative_residual: f64,
+    /// Norm of the applied displacement correction (after line search scaling).
+    pub increment_norm: f64,
+    /// Factor the Newton correction was scaled by; one without line search.
+    pub line_search_factor: f64,
+    /// Whether the tangent stiffness was reassembled and factorized this iteration.
+    pub stiffness_updated: bool,
+}
+
+/// All iterations of one load increment plus the state it ended in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepRecord {
+    pub step: usize,
+    /// Load factor the step tried to reach; under displacement control, the one
+    /// it ended at.
+    pub load_factor: f64,
+    /// Residual norm before the first iteration.
+    pub initial_residual: f64,
+    pub iterations: Vec<IterationRecord>,
+    pub converged: bool,
+    /// Displacements at the end of the step (converged or not), so a run can be
+    /// inspected or restarted from any step.
+    pub checkpoint: DVector<f64>,
+}
+
+impl StepRecord {
+    pub fn final_residual(&self) -> f64 {
+        self.iterations.last().map_or(self.initial_residual, |it| it.residual_norm)
+    }
+
+    pub fn stiffness_updates(&self) -> usize {
+        self.iterations.iter().filter(|it| it.stiffness_updated).count()
+    }
+}
+
+/// Structured history of a nonlinear solve.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConvergenceLog {
+    steps: Vec<StepRecord>,
+}
+
+impl ConvergenceLog {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn push(&mut self, step: StepRecord) {
+        self.steps.push(step);
+    }
+
+    pub fn steps(&self) -> &[StepRecord] { &self.steps }
+
+    pub fn last_step(&self) -> Option<&StepRecord> { self.steps.last() }
+
+    pub fn total_iterations(&self) -> usize {
+        self.steps.iter().map(|s| s.iterations.len()).sum()
+    }
+
+    /// First step that failed to converge. With adaptive stepping this may be an
+    /// attempt that was retried with a smaller increment.
+    pub fn first_failure(&self) -> Option<&StepRecord> {
+        self.steps.iter().find(|s| !s.converged)
+    }
+
+    /// Last converged checkpoint, if any.
+    pub fn last_converged(&self) -> Option<&StepRecord> {
+        self.steps.iter().rev().find(|s| s.converged)
+    }
+
+    /// `(u[dof], λ)` of every converged step, starting from the unloaded state.
+    pub fn equilibrium_path(&self, dof: usize) -> Vec<(f64, f64)> {
+        std::iter::once((0.0, 0.0))
+            .chain(self.steps.iter().filter(|s| s.converged).map(|s| (s.checkpoint[dof], s.load_factor)))
+            .collect()
+    }
+
+    /// One row per iteration, suitable for spreadsheets or plotting.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "step,load_factor,iteration,residual_norm,relative_residual,increment_norm,line_search_factor,stiffness_updated,converged\n",
+        );
+        for step in &self.steps {
+            for it in &step.iterations {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{:e},{:e},{:e},{},{},{}",
+                    step.step,
+                    step.load_factor,
+                    it.iteration,
+                    it.residual_norm,
+                    it.relative_residual,
+                    it.increment_norm,
+                    it.line_search_factor,
+                    it.stiffness_updated,
+                    step.converged
+                );
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,33 @@
+//! Incremental-iterative solution of nonlinear static problems.
+//!
+//! A [`NonlinearSystem`] supplies the internal force vector and tangent stiffness
+//! for a displacement state; [`NewtonSolver`] drives it to equilibrium with an
+//! external load applied in increments, recording every iteration in a
+//! [`ConvergenceLog`]. [`DisplacementControl`] instead prescribes one DOF and
+//! solves for the load factor, tracing equilibrium paths past limit points.
+//! [`CorotationalFrame`] is the large-displacement system of a planar frame model.
+
+use nalgebra::{DMatrix, DVector};
+
+pub mod corotational;
+pub mod displacement;
+pub mod log;
+pub mod newton;
+
+pub use corotational::CorotationalFrame;
+pub use displacement::DisplacementControl;
+pub use log::{ConvergenceLog, IterationRecord, StepRecord};
+pub use newton::{AdaptiveStepping, LoadStepping, NewtonSettings, NewtonSolver, NonlinearResult, StiffnessUpdate};
+
+/// Equilibrium equations `f_int(u) = λ f_ext` of a structure with supports
+/// already eliminated.
+pub trait NonlinearSystem {
+    /// Number of free DOFs.
+    fn size(&self) -> usize;
+
+    /// Internal (resisting) force vector at displacement `u`.
+    fn internal_force(&self, u: &DVector<f64>) -> DVector<f64>;
+
+    /// Tangent stiffness `∂f_int/∂u` at displacement `u`.
+    fn tangent_stiffness(&self, u: &DVector<f64>) -> DMatrix<f64>;
+}
@@ -0,0 +1,263 @@
+use nalgebra::{DMatrix, DVector};
+
+use super::NonlinearSystem;
+use super::log::{ConvergenceLog, IterationRecord, StepRecord};
+use crate::backend::{self, LinearAlgebraBackend};
+
+/// When the tangent stiffness is reassembled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StiffnessUpdate {
+    /// Every iteration (full Newton-Raphson).
+    Full,
+    /// At the start of every load step only (modified Newton-Raphson).
+    PerStep,
+    /// At the start of every step and then every `n` iterations.
+    Every(usize),
+}
+
+impl StiffnessUpdate {
+    /// Whether to reassemble in `iteration` (starting at one) of a step.
+    pub(super) fn is_due(&self, iteration: usize, first: bool) -> bool {
+        match *self {
+            StiffnessUpdate::Full => true,
+            StiffnessUpdate::PerStep => first,
+            StiffnessUpdate::Every(n) => first || (iteration - 1).is_multiple_of(n.max(1)),
+        }
+    }
+}
+
+/// Load increments of the adaptive scheme, as fractions of the full load.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveStepping {
+    pub initial: f64,
+    /// Smallest increment tried before the solve is abandoned.
+    pub min: f64,
+    pub max: f64,
+    /// Increment multiplier after a failed step (below one).
+    pub cut_factor: f64,
+    /// Increment multiplier after an easy step (above one).
+    pub growth_factor: f64,
+    /// Steps converging within this many iterations count as easy.
+    pub easy_iterations: usize,
+    /// Upper bound on attempted steps, including cut ones.
+    pub max_steps: usize,
+}
+
+impl Default for AdaptiveStepping {
+    fn default() -> Self {
+        Self {
+            initial: 0.1,
+            min: 1e-4,
+            max: 0.5,
+            cut_factor: 0.5,
+            growth_factor: 1.5,
+            easy_iterations: 4,
+            max_steps: 1000,
+        }
+    }
+}
+
+/// How the external load is incremented.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadStepping {
+    /// This many equal increments; the solve stops at the first failure.
+    Fixed(usize),
+    /// Cut the increment when a step fails and grow it after easy convergence.
+    Adaptive(AdaptiveStepping),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NewtonSettings {
+    pub stepping: LoadStepping,
+    pub max_iterations: usize,
+    /// Convergence limit on the residual norm relative to the applied load norm.
+    pub tolerance: f64,
+    pub stiffness_update: StiffnessUpdate,
+    /// Maximum number of step halvings in the backtracking line search; zero
+    /// disables the line search.
+    pub max_line_search: usize,
+    /// A step is abandoned when the residual grows beyond this multiple of its
+    /// initial value.
+    pub divergence_ratio: f64,
+}
+
+impl Default for NewtonSettings {
+    fn default() -> Self {
+        Self {
+            stepping: LoadStepping::Adaptive(AdaptiveStepping::default()),
+            max_iterations: 25,
+            tolerance: 1e-8,
+            stiffness_update: StiffnessUpdate::Full,
+            max_line_search: 5,
+            divergence_ratio: 1e6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonlinearResult {
+    /// Displacements of the last converged step (zero if none converged).
+    pub displacement: DVector<f64>,
+    /// Load factor reached by the last converged step.
+    pub load_factor: f64,
+    pub converged: bool,
+    pub log: ConvergenceLog,
+}
+
+pub struct NewtonSolver {
+    settings: NewtonSettings,
+    pub(super) backend: Box<dyn LinearAlgebraBackend>,
+}
+
+impl NewtonSolver {
+    pub fn new(settings: NewtonSettings) -> Self {
+        Self { settings, backend: backend::default_backend() }
+    }
+
+    pub fn with_backend(mut self, backend: Box<dyn LinearAlgebraBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    pub fn settings(&self) -> &NewtonSettings { &self.settings }
+
+    /// Apply `external` incrementally according to the load stepping scheme,
+    /// iterating each increment to equilibrium. Every attempted step, including
+    /// ones that were cut back, is recorded in the log.
+    pub fn solve<S: NonlinearSystem>(&self, system: &S, external: &DVector<f64>) -> NonlinearResult {
+        assert_eq!(external.len(), system.size(), "load vector does not match the system size");
+        match self.settings.stepping {
+            LoadStepping::Fixed(steps) => self.solve_fixed(system, external, steps),
+            LoadStepping::Adaptive(adaptive) => self.solve_adaptive(system, external, &adaptive),
+        }
+    }
+
+    fn solve_fixed<S: NonlinearSystem>(&self, system: &S, external: &DVector<f64>, steps: usize) -> NonlinearResult {
+        assert!(steps > 0, "at least one load step is required");
+        let mut log = ConvergenceLog::new();
+        let mut u = DVector::zeros(system.size());
+        let mut reached = 0.0;
+        for step in 1..=steps {
+            let load_factor = step as f64 / steps as f64;
+            let (record, state) = self.step(system, external, step, load_factor, u.clone());
+            let converged = record.converged;
+            log.push(record);
+            if !converged {
+                return NonlinearResult { displacement: u, load_factor: reached, converged: false, log };
+            }
+            u = state;
+            reached = load_factor;
+        }
+        NonlinearResult { displacement: u, load_factor: reached, converged: true, log }
+    }
+
+    fn solve_adaptive<S: NonlinearSystem>(
+        &self,
+        system: &S,
+        external: &DVector<f64>,
+        adaptive: &AdaptiveStepping,
+    ) -> NonlinearResult {
+        assert!(
+            0.0 < adaptive.min && adaptive.min <= adaptive.initial && adaptive.initial <= adaptive.max,
+            "adaptive increments must satisfy 0 < min <= initial <= max"
+        );
+        assert!(adaptive.cut_factor > 0.0 && adaptive.cut_factor < 1.0, "cut factor must lie in (0, 1)");
+        assert!(adaptive.growth_factor >= 1.0, "growth factor must not be below one");
+
+        let mut log = ConvergenceLog::new();
+        let mut u = DVector::zeros(system.size());
+        let mut reached = 0.0;
+        let mut increment = adaptive.initial;
+        for step in 1..=adaptive.max_steps {
+            let load_factor = (reached + increment).min(1.0);
+            let (record, state) = self.step(system, external, step, load_factor, u.clone());
+            let converged = record.converged;
+            let iterations = record.iterations.len();
+            log.push(record);
+            if converged {
+                u = state;
+                reached = load_factor;
+                if reached >= 1.0 {
+                    return NonlinearResult { displacement: u, load_factor: reached, converged: true, log };
+                }
+                if iterations <= adaptive.easy_iterations {
+                    increment = (increment * adaptive.growth_factor).min(adaptive.max);
+                }
+            } else {
+                increment *= adaptive.cut_factor;
+                if increment < adaptive.min {
+                    break;
+                }
+            }
+        }
+        NonlinearResult { displacement: u, load_factor: reached, converged: false, log }
+    }
+
+    /// Iterate one load step from `u`. Returns the step record and the end state.
+    pub(crate) fn step<S: NonlinearSystem>(
+        &self,
+        system: &S,
+        external: &DVector<f64>,
+        step: usize,
+        load_factor: f64,
+        mut u: DVector<f64>,
+    ) -> (StepRecord, DVector<f64>) {
+        let settings = &self.settings;
+        let load = external * load_factor;
+        let reference = load.norm().max(f64::MIN_POSITIVE);
+        let mut residual = &load - system.internal_force(&u);
+        let initial_residual = residual.norm();
+        let mut iterations = Vec::new();
+        let mut tangent: Option<DMatrix<f64>> = None;
+
+        let mut converged = initial_residual / reference <= settings.tolerance;
+        for iteration in 1..=settings.max_iterations {
+            if converged {
+                break;
+            }
+            let stiffness_updated = settings.stiffness_update.is_due(iteration, tangent.is_none());
+            if stiffness_updated {
+                tangent = Some(system.tangent_stiffness(&u));
+            }
+            let rhs = DMatrix::from_column_slice(residual.len(), 1, residual.as_slice());
+            let Some(du) = self.backend.solve(tangent.as_ref().unwrap(), &rhs) else {
+                break;
+            };
+            let du = du.column(0).into_owned();
+
+            // Backtracking line search on the residual norm.
+            let current = residual.norm();
+            let mut factor = 1.0;
+            let mut trial = &u + &du;
+            let mut trial_residual = &load - system.internal_force(&trial);
+            for _ in 0..settings.max_line_search {
+                if trial_residual.norm() < current {
+                    break;
+                }
+                factor *= 0.5;
+                trial = &u + &du * factor;
+                trial_residual = &load - system.internal_force(&trial);
+            }
+
+            u = trial;
+            residual = trial_residual;
+            let residual_norm = residual.norm();
+            iterations.push(IterationRecord {
+                iteration,
+                residual_norm,
+                relative_residual: residual_norm / reference,
+                increment_norm: du.norm() * factor,
+                line_search_factor: factor,
+                stiffness_updated,
+            });
+
+            if !residual_norm.is_finite() || residual_norm > settings.divergence_ratio * initial_residual {
+                break;
+            }
+            converged = residual_norm / reference <= settings.tolerance;
+        }
+
+        let record = StepRecord { step, load_factor, initial_residual, iterations, converged, checkpoint: u.clone() };
+        (record, u)
+    }
+}
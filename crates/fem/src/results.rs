@@ -0,0 +1,231 @@
+//! Tolerance-aware comparison of static results, e.g. against a stored baseline
+//! when checking that a new crate version reproduces earlier answers.
+
+use std::fmt;
+
+use nalgebra::DVector;
+use structure::{MemberId, NodeId};
+
+use crate::frame::StaticResult;
+
+const DOF_NAMES: [&str; 6] = ["ux", "uy", "uz", "rx", "ry", "rz"];
+const FORCE_NAMES: [&str; 6] = ["Fx", "Fy", "Fz", "Mx", "My", "Mz"];
+
+/// Kind of result a difference was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    Displacement,
+    Reaction,
+    MemberForce,
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Quantity::Displacement => "displacement",
+            Quantity::Reaction => "reaction",
+            Quantity::MemberForce => "member force",
+        })
+    }
+}
+
+/// Allowed deviation `absolute + relative * max(|reference|, |candidate|)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+impl Tolerance {
+    pub fn new(absolute: f64, relative: f64) -> Self { Self { absolute, relative } }
+
+    pub fn limit(&self, reference: f64, candidate: f64) -> f64 {
+        self.absolute + self.relative * reference.abs().max(candidate.abs())
+    }
+
+    pub fn accepts(&self, reference: f64, candidate: f64) -> bool {
+        (reference - candidate).abs() <= self.limit(reference, candidate)
+    }
+}
+
+/// Tolerance per quantity. The defaults accept relative deviations of 1e-6
+/// above absolute floors of 1e-12 m for displacements and 1e-6 N for forces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerances {
+    pub displacement: Tolerance,
+    pub reaction: Tolerance,
+    pub member_force: Tolerance,
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            displacement: Tolerance::new(1e-12, 1e-6),
+            reaction: Tolerance::new(1e-6, 1e-6),
+            member_force: Tolerance::new(1e-6, 1e-6),
+        }
+    }
+}
+
+impl Tolerances {
+    pub fn with_displacement(mut self, tolerance: Tolerance) -> Self {
+        self.displacement = tolerance;
+        self
+    }
+
+    pub fn with_reaction(mut self, tolerance: Tolerance) -> Self {
+        self.reaction = tolerance;
+        self
+    }
+
+    pub fn with_member_force(mut self, tolerance: Tolerance) -> Self {
+        self.member_force = tolerance;
+        self
+    }
+}
+
+/// Where a value sits: a global DOF for displacements and reactions, a local
+/// end-force entry for member forces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Dof { node: NodeId, dof: usize },
+    MemberEnd { member: MemberId, entry: usize },
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Location::Dof { node, dof } => write!(f, "node {node} {}", DOF_NAMES[dof]),
+            Location::MemberEnd { member, entry } => {
+                let end = if entry < 6 { "start" } else { "end" };
+                write!(f, "member {member} {end} {}", FORCE_NAMES[entry % 6])
+            }
+        }
+    }
+}
+
+/// A value outside its tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Difference {
+    pub quantity: Quantity,
+    pub location: Location,
+    pub reference: f64,
+    pub candidate: f64,
+    /// Allowed deviation at these values.
+    pub limit: f64,
+}
+
+impl Difference {
+    pub fn deviation(&self) -> f64 { (self.reference - self.candidate).abs() }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}: reference {:e}, candidate {:e}, deviation {:e} exceeds {:e}",
+            self.quantity,
+            self.location,
+            self.reference,
+            self.candidate,
+            self.deviation(),
+            self.limit
+        )
+    }
+}
+
+/// Outcome of [`compare`]: differences in result order (displacements,
+/// reactions, member forces) and quantities whose sizes disagree, which are
+/// not compared value by value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonReport {
+    pub differences: Vec<Difference>,
+    /// `(quantity, reference size, candidate size)`.
+    pub size_mismatches: Vec<(Quantity, usize, usize)>,
+    /// Number of values compared.
+    pub compared: usize,
+}
+
+impl ComparisonReport {
+    pub fn is_match(&self) -> bool { self.differences.is_empty() && self.size_mismatches.is_empty() }
+
+    /// Difference with the largest deviation relative to its limit.
+    pub fn worst(&self) -> Option<&Difference> {
+        self.differences.iter().max_by(|a, b| (a.deviation() / a.limit).total_cmp(&(b.deviation() / b.limit)))
+    }
+}
+
+impl fmt::Display for ComparisonReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_match() {
+            return write!(f, "results match ({} values compared)", self.compared);
+        }
+        writeln!(
+            f,
+            "results differ: {} of {} values out of tolerance, {} size mismatches",
+            self.differences.len(),
+            self.compared,
+            self.size_mismatches.len()
+        )?;
+        for (quantity, reference, candidate) in &self.size_mismatches {
+            writeln!(f, "  {quantity}: reference has {reference} values, candidate {candidate}")?;
+        }
+        for difference in &self.differences {
+            writeln!(f, "  {difference}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare a candidate result with a reference value by value.
+pub fn compare(reference: &StaticResult, candidate: &StaticResult, tolerances: &Tolerances) -> ComparisonReport {
+    let mut report = ComparisonReport { differences: Vec::new(), size_mismatches: Vec::new(), compared: 0 };
+    let dof = |i: usize| Location::Dof { node: i / 6, dof: i % 6 };
+    let (a, b) = (reference.displacements(), candidate.displacements());
+    compare_values(&mut report, Quantity::Displacement, a, b, tolerances.displacement, dof);
+    let (a, b) = (reference.reactions(), candidate.reactions());
+    compare_values(&mut report, Quantity::Reaction, a, b, tolerances.reaction, dof);
+
+    let flatten = |result: &StaticResult| {
+        let members = result.member_count();
+        DVector::from_iterator(12 * members, (0..members).flat_map(|m| result.member_forces(m).iter().copied()))
+    };
+    let end = |i: usize| Location::MemberEnd { member: i / 12, entry: i % 12 };
+    let (a, b) = (flatten(reference), flatten(candidate));
+    compare_values(&mut report, Quantity::MemberForce, &a, &b, tolerances.member_force, end);
+    report
+}
+
+fn compare_values<F: Fn(usize) -> Location>(
+    report: &mut ComparisonReport,
+    quantity: Quantity,
+    reference: &DVector<f64>,
+    candidate: &DVector<f64>,
+    tolerance: Tolerance,
+    location: F,
+) {
+    if reference.len() != candidate.len() {
+        report.size_mismatches.push((quantity, reference.len(), candidate.len()));
+        return;
+    }
+    for (i, (&r, &c)) in reference.iter().zip(candidate.iter()).enumerate() {
+        report.compared += 1;
+        if !tolerance.accepts(r, c) {
+            let limit = tolerance.limit(r, c);
+            report.differences.push(Difference { quantity, location: location(i), reference: r, candidate: c, limit });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locations_are_named() {
+        assert_eq!(Location::Dof { node: 3, dof: 2 }.to_string(), "node 3 uz");
+        assert_eq!(Location::MemberEnd { member: 1, entry: 11 }.to_string(), "member 1 end Mz");
+        assert!(Tolerance::new(0.0, 0.01).accepts(100.0, 100.9));
+        assert!(!Tolerance::new(0.0, 0.01).accepts(100.0, 102.0));
+    }
+}
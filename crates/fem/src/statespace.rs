@@ -0,0 +1,202 @@
+//! First-order state-space form of the linearized equations of motion
+//! `M ẍ + C ẋ + K x = B₀ u`.
+//!
+//! The state vector is `[x; ẋ]`, so `A` and the output matrices are twice the
+//! size of the structural matrices. Matrices can be written as NumPy `.npy`
+//! files or as a MATLAB level 4 `.mat` file for use in control toolboxes.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use nalgebra::DMatrix;
+
+/// Quantity measured at an output DOF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    Displacement(usize),
+    Velocity(usize),
+    Acceleration(usize),
+}
+
+impl Output {
+    pub fn dof(&self) -> usize {
+        match *self {
+            Output::Displacement(dof) | Output::Velocity(dof) | Output::Acceleration(dof) => dof,
+        }
+    }
+}
+
+/// State-space matrices `ẋ = A x + B u`, `y = C x + D u`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateSpace {
+    a: DMatrix<f64>,
+    b: DMatrix<f64>,
+    c: DMatrix<f64>,
+    d: DMatrix<f64>,
+}
+
+impl StateSpace {
+    /// Build the state-space model from mass, damping and stiffness matrices.
+    ///
+    /// Every entry of `inputs` is a DOF receiving a unit force; `outputs` select
+    /// the measured quantities in the order of the rows of `C` and `D`. Returns
+    /// `None` when the mass matrix is singular (e.g. massless rotational DOFs,
+    /// which should be condensed out first).
+    pub fn from_structure(
+        m: &DMatrix<f64>,
+        c: &DMatrix<f64>,
+        k: &DMatrix<f64>,
+        inputs: &[usize],
+        outputs: &[Output],
+    ) -> Option<Self> {
+        let n = m.nrows();
+        assert!(
+            m.is_square() && c.shape() == (n, n) && k.shape() == (n, n),
+            "M, C and K must be square and of equal size"
+        );
+        assert!(inputs.iter().all(|&dof| dof < n), "input DOF out of range");
+        assert!(outputs.iter().all(|output| output.dof() < n), "output DOF out of range");
+
+        let lu = m.clone().lu();
+        let m_inv_k = lu.solve(k)?;
+        let m_inv_c = lu.solve(c)?;
+        let mut force = DMatrix::zeros(n, inputs.len());
+        for (col, &dof) in inputs.iter().enumerate() {
+            force[(dof, col)] = 1.0;
+        }
+        let m_inv_b = lu.solve(&force)?;
+
+        let mut a = DMatrix::zeros(2 * n, 2 * n);
+        a.view_mut((0, n), (n, n)).fill_with_identity();
+        a.view_mut((n, 0), (n, n)).copy_from(&-&m_inv_k);
+        a.view_mut((n, n), (n, n)).copy_from(&-&m_inv_c);
+
+        let mut b = DMatrix::zeros(2 * n, inputs.len());
+        b.view_mut((n, 0), (n, inputs.len())).copy_from(&m_inv_b);
+
+        let mut c_out = DMatrix::zeros(outputs.len(), 2 * n);
+        let mut d = DMatrix::zeros(outputs.len(), inputs.len());
+        for (row, output) in outputs.iter().enumerate() {
+            match *output {
+                Output::Displacement(dof) => c_out[(row, dof)] = 1.0,
+                Output::Velocity(dof) => c_out[(row, n + dof)] = 1.0,
+                Output::Acceleration(dof) => {
+                    c_out.row_mut(row).copy_from(&a.row(n + dof));
+                    d.row_mut(row).copy_from(&b.row(n + dof));
+                }
+            }
+        }
+
+        Some(Self { a, b, c: c_out, d })
+    }
+
+    pub fn a(&self) -> &DMatrix<f64> { &self.a }
+    pub fn b(&self) -> &DMatrix<f64> { &self.b }
+    pub fn c(&self) -> &DMatrix<f64> { &self.c }
+    pub fn d(&self) -> &DMatrix<f64> { &self.d }
+
+    pub fn states(&self) -> usize { self.a.nrows() }
+    pub fn inputs(&self) -> usize { self.b.ncols() }
+    pub fn outputs(&self) -> usize { self.c.nrows() }
+
+    fn named(&self) -> [(&'static str, &DMatrix<f64>); 4] {
+        [("A", &self.a), ("B", &self.b), ("C", &self.c), ("D", &self.d)]
+    }
+
+    /// Write `A.npy`, `B.npy`, `C.npy` and `D.npy` into `directory` (created if missing).
+    pub fn write_npy(&self, directory: impl AsRef<Path>) -> io::Result<()> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+        for (name, matrix) in self.named() {
+            let mut file = BufWriter::new(File::create(directory.join(format!("{name}.npy")))?);
+            write_npy(&mut file, matrix)?;
+            file.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write a MATLAB level 4 `.mat` file holding the variables `A`, `B`, `C` and `D`.
+    /// Level 4 files are read by `load` in MATLAB, Octave and `scipy.io.loadmat`.
+    pub fn write_mat(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        for (name, matrix) in self.named() {
+            write_mat_variable(&mut file, name, matrix)?;
+        }
+        file.flush()
+    }
+}
+
+/// Serialize a matrix in NumPy `.npy` format (version 1.0, little-endian `f8`, C order).
+pub fn write_npy<W: Write>(writer: &mut W, matrix: &DMatrix<f64>) -> io::Result<()> {
+    let mut header = format!(
+        "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+        matrix.nrows(),
+        matrix.ncols()
+    );
+    // Magic (6) + version (2) + header length (2) + header, padded to 64 bytes with a trailing newline.
+    let unpadded = 10 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+    header.push('\n');
+
+    writer.write_all(b"\x93NUMPY")?;
+    writer.write_all(&[1, 0])?;
+    writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    writer.write_all(header.as_bytes())?;
+    for row in matrix.row_iter() {
+        for value in row.iter() {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+/// Append one real double matrix to a MATLAB level 4 stream.
+pub fn write_mat_variable<W: Write>(writer: &mut W, name: &str, matrix: &DMatrix<f64>) -> io::Result<()> {
+    // Type 0000: little-endian IEEE, double precision, numeric full matrix.
+    let header = [0i32, matrix.nrows() as i32, matrix.ncols() as i32, 0, name.len() as i32 + 1];
+    for value in header {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    writer.write_all(name.as_bytes())?;
+    writer.write_all(&[0])?;
+    // nalgebra storage is column-major, as MATLAB expects.
+    for value in matrix.iter() {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    #[test]
+    fn single_oscillator() {
+        let m = DMatrix::from_element(1, 1, 2.0);
+        let c = DMatrix::from_element(1, 1, 0.4);
+        let k = DMatrix::from_element(1, 1, 8.0);
+        let outputs = [Output::Displacement(0), Output::Velocity(0), Output::Acceleration(0)];
+        let ss = StateSpace::from_structure(&m, &c, &k, &[0], &outputs).unwrap();
+        assert_eq!(ss.a(), &DMatrix::from_row_slice(2, 2, &[0.0, 1.0, -4.0, -0.2]));
+        assert_eq!(ss.b(), &DMatrix::from_row_slice(2, 1, &[0.0, 0.5]));
+        assert_eq!(ss.c(), &DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 1.0, -4.0, -0.2]));
+        assert_almost_eq!(ss.d()[(2, 0)], 0.5);
+        assert_almost_eq!(ss.d()[(0, 0)], 0.0);
+    }
+
+    #[test]
+    fn npy_header_is_aligned() {
+        let mut buffer = Vec::new();
+        write_npy(&mut buffer, &DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])).unwrap();
+        let header_len = u16::from_le_bytes([buffer[8], buffer[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        assert_eq!(buffer.len(), 10 + header_len + 6 * 8);
+        assert_eq!(buffer[10 + header_len - 1], b'\n');
+        // Row-major data: second value is the (0, 1) entry.
+        let second = f64::from_le_bytes(buffer[10 + header_len + 8..10 + header_len + 16].try_into().unwrap());
+        assert_almost_eq!(second, 2.0);
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use fem::backend::{Eigen, LinearAlgebraBackend, Native};
+use fem::frame::FrameAnalysis;
+use nalgebra::DMatrix;
+use structure::benchmarks;
+
+/// Stand-in for an external library binding: delegates to [`Native`] and
+/// counts the solves it is asked for.
+struct External {
+    solves: Arc<AtomicUsize>,
+}
+
+impl LinearAlgebraBackend for External {
+    fn name(&self) -> &'static str { "external" }
+
+    fn solve(&self, a: &DMatrix<f64>, b: &DMatrix<f64>) -> Option<DMatrix<f64>> {
+        self.solves.fetch_add(1, Ordering::Relaxed);
+        Native.solve(a, b)
+    }
+
+    fn cholesky(&self, a: &DMatrix<f64>) -> Option<DMatrix<f64>> { Native.cholesky(a) }
+
+    fn symmetric_eigen(&self, a: &DMatrix<f64>) -> Option<Eigen> { Native.symmetric_eigen(a) }
+}
+
+#[test]
+fn external_backend_plugs_into_the_analysis() {
+    let benchmark = benchmarks::portal_frame();
+    let solves = Arc::new(AtomicUsize::new(0));
+    let external = FrameAnalysis::new(benchmark.model()).with_backend(Box::new(External { solves: solves.clone() }));
+    let result = external.solve(benchmark.load_case()).unwrap();
+    assert_eq!(solves.load(Ordering::Relaxed), 1);
+
+    let native = FrameAnalysis::new(benchmark.model()).with_backend(Box::new(Native)).solve(benchmark.load_case()).unwrap();
+    assert_eq!(result.displacements(), native.displacements());
+    assert!(fem::backend::backend("external").is_none());
+}
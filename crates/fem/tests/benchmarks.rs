@@ -0,0 +1,92 @@
+use fem::frame::FrameAnalysis;
+use fem::nonlinear::{CorotationalFrame, DisplacementControl, LoadStepping, NewtonSettings, NewtonSolver, NonlinearSystem};
+use nalgebra::DVector;
+use structure::benchmarks::{self, Benchmark, Quantity};
+use structure::Load;
+
+/// Check every reference of a benchmark: linear quantities with the frame
+/// analysis, limit loads by displacement control of a [`CorotationalFrame`].
+fn verify(benchmark: &Benchmark) {
+    let analysis = FrameAnalysis::new(benchmark.model());
+    let case = benchmark.load_case();
+    for reference in benchmark.references() {
+        let value = match reference.quantity {
+            Quantity::Displacement { node, dof } => analysis.solve(case).unwrap().displacement(node, dof),
+            Quantity::Reaction { node, dof } => analysis.solve(case).unwrap().reaction(node, dof),
+            Quantity::NaturalFrequency { mode } => analysis.modes(mode + 1).unwrap().circular_frequency(mode),
+            Quantity::BucklingFactor { mode } => analysis.buckling(case, mode + 1).unwrap().values()[mode],
+            Quantity::LimitLoadFactor => limit_load_factor(benchmark),
+        };
+        let error = (value - reference.value).abs() / reference.value.abs();
+        assert!(
+            error <= reference.tolerance,
+            "{}: {:?} = {value}, expected {} (relative error {error:e})",
+            benchmark.name(),
+            reference.quantity,
+            reference.value
+        );
+    }
+}
+
+/// Load factor at the first limit point, traced past it by driving the loaded
+/// node along the single nodal load of the benchmark.
+fn limit_load_factor(benchmark: &Benchmark) -> f64 {
+    let model = benchmark.model();
+    let [Load::Nodal { node, force, .. }] = benchmark.load_case().loads() else { panic!("expected one nodal load") };
+    let frame = CorotationalFrame::new(model);
+    let k = if force.x().abs() >= force.z().abs() { 0 } else { 2 };
+    let dof = frame.free_dof(*node, k).expect("loaded DOF is restrained");
+    let mut external = DVector::zeros(frame.size());
+    external[dof] = force.0[k];
+
+    let extent = model.nodes().iter().map(|n| n.center().0.amax()).fold(0.0, f64::max);
+    let control = DisplacementControl::new(dof, 0.5 * extent * external[dof].signum(), 50);
+    let result = NewtonSolver::new(NewtonSettings::default()).solve_displacement_controlled(&frame, &external, &control);
+    let path = result.log.equilibrium_path(dof);
+    let peak = path.windows(2).position(|w| w[1].1 < w[0].1).expect("no limit point within the controlled range");
+    path[peak].1
+}
+
+#[test]
+fn simply_supported_beam() {
+    verify(&benchmarks::simply_supported_beam());
+}
+
+#[test]
+fn portal_frame() {
+    verify(&benchmarks::portal_frame());
+}
+
+#[test]
+fn cantilever_eigenvalues() {
+    verify(&benchmarks::cantilever_eigenvalues());
+}
+
+#[test]
+fn euler_column() {
+    verify(&benchmarks::euler_column());
+}
+
+#[test]
+fn lees_frame() {
+    verify(&benchmarks::lees_frame());
+}
+
+#[test]
+fn lees_frame_is_stable_under_small_loads() {
+    let benchmark = benchmarks::lees_frame();
+    let [Load::Nodal { node, force, .. }] = benchmark.load_case().loads() else { panic!("expected one nodal load") };
+    let frame = CorotationalFrame::new(benchmark.model());
+    let dof = frame.free_dof(*node, 2).unwrap();
+    let mut external = DVector::zeros(frame.size());
+    // Half the reference limit load.
+    external[dof] = 0.5 * benchmark.references()[0].value * force.z();
+
+    // Plain Newton: the first full step overshoots in the residual norm, which
+    // the line search would take for divergence.
+    let settings = NewtonSettings { stepping: LoadStepping::Fixed(5), max_line_search: 0, ..NewtonSettings::default() };
+    let result = NewtonSolver::new(settings).solve(&frame, &external);
+    assert!(result.converged);
+    assert!(result.displacement[dof] < 0.0);
+    assert!(frame.tangent_stiffness(&result.displacement).cholesky().is_some(), "tangent is not positive definite");
+}
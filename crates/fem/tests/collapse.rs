@@ -0,0 +1,64 @@
+use fem::frame::{collapse::AlternatePathStudy, erection::StageFailure};
+use geometry::Vector3d;
+use structure::{Capacity, DesignGroup, Fixity, LoadCase, Model};
+
+mod common;
+
+use common::ipe300;
+
+/// Two-bay frame in the XZ plane with fixed bases: members 0 to 2 are the
+/// columns from left to right, members 3 and 4 the beams under gravity.
+fn two_bays(beam_capacity: f64) -> Model {
+    let mut model = Model::new();
+    let bases: Vec<_> = (0..3).map(|i| model.add_node((6.0 * i as f64, 0.0, 0.0))).collect();
+    let tops: Vec<_> = (0..3).map(|i| model.add_node((6.0 * i as f64, 0.0, 4.0))).collect();
+    for i in 0..3 {
+        model.add_member(bases[i], tops[i], ipe300());
+        model.set_support(bases[i], Fixity::fixed());
+    }
+    for i in 0..2 {
+        model.add_member(tops[i], tops[i + 1], ipe300());
+    }
+    let mut gravity = LoadCase::new("gravity");
+    gravity.add_member_load(3, Vector3d::new(0.0, 0.0, -20e3));
+    gravity.add_member_load(4, Vector3d::new(0.0, 0.0, -20e3));
+    model.add_load_case(gravity);
+    model.add_design_group(DesignGroup::of("columns", [0, 1, 2]).with_capacity(Capacity::new(1e7, 1e7)));
+    model.add_design_group(DesignGroup::of("beams", [3, 4]).with_capacity(Capacity::new(1e7, beam_capacity)));
+    model
+}
+
+#[test]
+fn exterior_column_loss_overstresses_cantilevered_beam() {
+    // Amplified cantilever moment 2 w 6² / 2 = 720 kNm; the 12 m bridging span
+    // over a lost interior column stays below that.
+    let model = two_bays(600e3);
+    let checks = AlternatePathStudy::single_removals(0..3).run(&model);
+    assert_eq!(checks.len(), 3);
+    assert!(checks[1].is_robust());
+    for (check, beam) in [(&checks[0], 3), (&checks[2], 4)] {
+        match check.failure {
+            Some(StageFailure::Overstressed { member, utilization }) => {
+                assert_eq!(member, beam);
+                assert!((utilization - 720e3 / 600e3).abs() < 1e-6);
+            }
+            ref failure => panic!("expected the beam to be overstressed, got {failure:?}"),
+        }
+    }
+
+    // Amplification scales the loads next to the removal only.
+    let static_check = AlternatePathStudy::new().with_amplification(1.0).check(&model, &[1]);
+    let dynamic_check = AlternatePathStudy::new().check(&model, &[1]);
+    let (static_demand, _) = static_check.governing.unwrap();
+    let (dynamic_demand, _) = dynamic_check.governing.unwrap();
+    assert!((dynamic_demand.moment - 2.0 * static_demand.moment).abs() < 1e-6 * static_demand.moment);
+}
+
+#[test]
+fn losing_every_column_leaves_a_mechanism() {
+    let model = two_bays(1e7);
+    let check = AlternatePathStudy::new().with_scenario(vec![0, 1, 2]).run(&model).remove(0);
+    assert_eq!(check.removed, vec![0, 1, 2]);
+    assert!(matches!(check.failure, Some(StageFailure::Unstable(_))));
+    assert!(check.governing.is_none());
+}
@@ -0,0 +1,33 @@
+//! Fixtures shared by the frame analysis tests. Each test binary uses a subset.
+#![allow(dead_code)]
+
+use structure::{Fixity, Material, Model, Section};
+
+/// IPE 300 in steel, with stiffness properties scaled by `scale` to make a
+/// family of candidate sections.
+pub fn ipe300_scaled(scale: f64) -> Section {
+    let mut section = Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None);
+    section.set_area(53.8e-4 * scale);
+    section.set_second_moment_components(8356e-8 * scale, 603.8e-8 * scale, 0.0);
+    section.set_torsion_constant(20.1e-8 * scale);
+    section
+}
+
+pub fn ipe300() -> Section { ipe300_scaled(1.0) }
+
+/// Portal frame of IPE 300 in the XZ plane, 4 m high and 6 m wide, with `base`
+/// supports at nodes 0 and 3: members 0 and 2 are the columns, member 1 the
+/// beam between the heads at nodes 1 and 2. No load cases.
+pub fn portal(base: Fixity) -> Model {
+    let mut model = Model::new();
+    let nodes: Vec<_> = [(0.0, 0.0, 0.0), (0.0, 0.0, 4.0), (6.0, 0.0, 4.0), (6.0, 0.0, 0.0)]
+        .into_iter()
+        .map(|p| model.add_node(p))
+        .collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 3)] {
+        model.add_member(nodes[a], nodes[b], ipe300());
+    }
+    model.set_support(nodes[0], base.clone());
+    model.set_support(nodes[3], base);
+    model
+}
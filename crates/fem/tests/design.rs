@@ -0,0 +1,78 @@
+use fem::frame::{design::check_design_groups, FrameAnalysis};
+use geometry::Vector3d;
+use structure::{Capacity, DesignGroup, Fixity, LoadCase, Model, Section};
+
+mod common;
+
+use common::ipe300_scaled;
+
+/// The common portal with fixed bases. Load case 0 is gravity on the beam,
+/// case 1 adds wind at the left column head.
+fn portal() -> Model {
+    let mut model = common::portal(Fixity::fixed());
+    let mut gravity = LoadCase::new("gravity");
+    gravity.add_member_load(1, Vector3d::new(0.0, 0.0, -20e3));
+    model.add_load_case(gravity.clone());
+    let mut wind = gravity;
+    wind.add_nodal_force(1, Vector3d::new(30e3, 0.0, 0.0));
+    model.add_load_case(wind);
+    model
+}
+
+#[test]
+fn group_envelope_reports_governing_member_and_case() {
+    let mut model = portal();
+    let columns = model.add_design_group(DesignGroup::of("columns", [0, 2]).with_capacity(Capacity::new(1e6, 100e3)));
+    let beam = model.add_design_group(DesignGroup::of("beam", [1]));
+    assert_eq!(model.design_group_of(2), Some(columns));
+    assert_eq!(model.design_group_of(1), Some(beam));
+
+    let report = check_design_groups(&model).unwrap();
+    let check = report.group(columns);
+    let (demand, utilization) = check.governing.unwrap();
+    // Wind pushes towards the right column, which attracts the larger base moment.
+    assert_eq!(demand.load_case, 1);
+    assert_eq!(demand.member, 2);
+
+    let analysis = FrameAnalysis::new(&model);
+    let mut largest: f64 = 0.0;
+    for case in model.load_cases() {
+        let result = analysis.solve(case).unwrap();
+        for member in [0, 2] {
+            let forces = result.member_forces(member);
+            largest = largest.max(forces[4].hypot(forces[5])).max(forces[10].hypot(forces[11]));
+        }
+    }
+    assert!((check.max_moment.unwrap().moment - largest).abs() < 1e-6 * largest);
+    assert!(utilization >= check.max_moment.unwrap().moment / 100e3 - 1e-12);
+    assert!(check.max_axial.unwrap().axial.abs() > 60e3);
+
+    // Without a capacity the beam is enveloped but not checked.
+    assert!(report.group(beam).governing.is_none());
+    assert!(report.group(beam).max_moment.is_some());
+    assert!(report.group(beam).is_adequate());
+}
+
+#[test]
+fn sizing_loop_grows_group_until_adequate() {
+    let mut model = portal();
+    let columns = model.add_design_group(DesignGroup::of("columns", [0, 2]));
+    // Candidate sections with capacities growing faster than their stiffness.
+    let candidates: Vec<(Section, Capacity)> =
+        [1.0, 1.5, 2.0, 3.0].iter().map(|&s| (ipe300_scaled(s), Capacity::new(1e6 * s, 25e3 * s * s))).collect();
+
+    let mut chosen = 0;
+    model.resize_design_group(columns, candidates[0].0.clone(), candidates[0].1);
+    while !check_design_groups(&model).unwrap().is_adequate() {
+        chosen += 1;
+        assert!(chosen < candidates.len(), "no candidate section is adequate");
+        model.resize_design_group(columns, candidates[chosen].0.clone(), candidates[chosen].1);
+    }
+    assert!(chosen > 0);
+    let report = check_design_groups(&model).unwrap();
+    assert!(report.group(columns).utilization().unwrap() <= 1.0);
+    assert_eq!(report.overstressed().count(), 0);
+    for member in [0, 2] {
+        assert_eq!(model.member(member).get_section(), Some(&candidates[chosen].0));
+    }
+}
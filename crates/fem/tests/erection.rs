@@ -0,0 +1,69 @@
+use fem::frame::erection::{check_erection, StageFailure};
+use geometry::Vector3d;
+use structure::{Capacity, ErectionSequence, Fixity, Model, Stage};
+
+mod common;
+
+/// The common portal with bases pinned about the out-of-plane axis.
+fn portal() -> Model { common::portal(Fixity::new([true; 3], [true, false, true])) }
+
+/// Columns first, each held by a temporary prop at its head, then the beam
+/// under a horizontal construction load.
+fn sequence(propped: bool) -> ErectionSequence {
+    let prop = Fixity::new([true, false, false], [false; 3]);
+    let mut sequence = ErectionSequence::new();
+    let mut left = Stage::new("left column");
+    left.add_member(0);
+    if propped {
+        left.add_temporary_support(1, prop.clone());
+    }
+    sequence.add_stage(left);
+
+    let mut right = Stage::new("right column");
+    right.add_member(2);
+    right.add_temporary_support(1, prop.clone());
+    right.add_temporary_support(2, prop);
+    sequence.add_stage(right);
+
+    let mut beam = Stage::new("beam");
+    beam.add_member(1);
+    beam.load_case_mut().add_nodal_force(1, Vector3d::new(10e3, 0.0, 0.0));
+    sequence.add_stage(beam);
+    sequence
+}
+
+#[test]
+fn unpropped_column_is_a_mechanism() {
+    let report = check_erection(&portal(), &sequence(false));
+    let failure = report.first_failure().unwrap();
+    assert_eq!(failure.stage, 0);
+    assert!(matches!(failure.failure, Some(StageFailure::Unstable(ref found)) if !found.is_empty()));
+    assert!(report.stages()[1].is_feasible());
+}
+
+#[test]
+fn propped_sequence_is_feasible() {
+    let mut sequence = sequence(true);
+    for member in 0..3 {
+        sequence.set_capacity(member, Capacity::new(500e3, 50e3));
+    }
+    let report = check_erection(&portal(), &sequence);
+    assert!(report.is_feasible());
+    assert_eq!(report.stages()[0].governing, Some((0, 0.0)));
+    // Each column carries half the sway load: 5 kN × 4 m at its head.
+    let (_, utilization) = report.stages()[2].governing.unwrap();
+    assert!((utilization - 20e3 / 50e3).abs() < 0.01 * utilization);
+}
+
+#[test]
+fn overstressed_member_governs_the_failing_stage() {
+    let mut sequence = sequence(true);
+    sequence.set_capacity(1, Capacity::new(500e3, 50e3));
+    sequence.set_capacity(2, Capacity::new(500e3, 15e3));
+    let report = check_erection(&portal(), &sequence);
+    let failure = report.first_failure().unwrap();
+    assert_eq!(failure.stage, 2);
+    let Some(StageFailure::Overstressed { member, utilization }) = failure.failure else { panic!("expected overstress") };
+    assert_eq!(member, 2);
+    assert!((utilization - 20e3 / 15e3).abs() < 0.02 * utilization);
+}
@@ -0,0 +1,71 @@
+use fem::frame::{influence::Response, FrameAnalysis};
+use geometry::Vector3d;
+use nalgebra::DVector;
+use structure::{Fixity, LoadCase, Model};
+use utils::assert_almost_eq;
+
+mod common;
+
+use common::ipe300;
+
+/// Simply supported 8 m span along X in four members: pinned at node 0,
+/// sliding along X at node 4.
+fn span() -> Model {
+    let mut model = Model::new();
+    let nodes: Vec<_> = (0..5).map(|i| model.add_node((2.0 * i as f64, 0.0, 0.0))).collect();
+    for i in 0..4 {
+        model.add_member(nodes[i], nodes[i + 1], ipe300());
+    }
+    model.set_support(nodes[0], Fixity::new([true; 3], [true, false, false]));
+    model.set_support(nodes[4], Fixity::new([false, true, true], [false; 3]));
+    model
+}
+
+#[test]
+fn reaction_influence_line_is_linear() {
+    let model = span();
+    let analysis = FrameAnalysis::new(&model);
+    let loads: Vec<_> = (0..5).map(|node| (node, 2)).collect();
+    let responses = [Response::reaction(0, 2), Response::reaction(4, 2), Response::displacement(2, 2)];
+    let influence = analysis.influence(&loads, &responses).unwrap();
+
+    for (j, &(node, _)) in loads.iter().enumerate() {
+        let x = 2.0 * node as f64;
+        assert_almost_eq!(influence.coefficient(0, j) + influence.coefficient(1, j), -1.0, 1e-9);
+        assert_almost_eq!(influence.coefficient(0, j), -(8.0 - x) / 8.0, 1e-9);
+    }
+    // Maxwell: the midspan deflection under a load at node 1 equals the node 1
+    // deflection under a midspan load.
+    let reciprocal = analysis.influence(&[(2, 2)], &[Response::displacement(1, 2)]).unwrap();
+    assert_almost_eq!(influence.coefficient(2, 1), reciprocal.coefficient(0, 0), 1e-9);
+}
+
+#[test]
+fn placements_match_full_solves() {
+    let model = span();
+    let analysis = FrameAnalysis::new(&model);
+    let loads: Vec<_> = (1..4).map(|node| (node, 2)).collect();
+    let responses = [Response::reaction(0, 2), Response::member_force(1, 4), Response::displacement(2, 2)];
+    let influence = analysis.influence(&loads, &responses).unwrap();
+
+    // Two 100 kN axles 2 m apart at both positions on the span.
+    let placements: Vec<Vec<(usize, f64)>> = (0..2).map(|first| vec![(first, -100e3), (first + 1, -100e3)]).collect();
+    for placement in &placements {
+        let mut case = LoadCase::new("axles");
+        for &(load, magnitude) in placement {
+            case.add_nodal_force(loads[load].0, Vector3d::new(0.0, 0.0, magnitude));
+        }
+        let result = analysis.solve(&case).unwrap();
+        let values = influence.evaluate_placement(placement);
+        assert_almost_eq!(values[0], result.reaction(0, 2));
+        assert_almost_eq!(values[1], result.member_forces(1)[4]);
+        assert_almost_eq!(values[2], result.displacement(2, 2));
+    }
+
+    let (min, max) = influence.envelope(placements.iter().map(Vec::as_slice));
+    assert!(min[0] <= max[0]);
+    // By symmetry both placements deflect midspan equally.
+    assert_almost_eq!(min[2], max[2]);
+    let all = influence.evaluate(&DVector::from_element(3, -100e3));
+    assert!(all[0] > max[0]);
+}
@@ -0,0 +1,78 @@
+use fem::frame::{erection::StageFailure, lifting::check_lift};
+use structure::{Capacity, Fixity, Lift, Model};
+use utils::assert_almost_eq;
+
+mod common;
+
+use common::ipe300;
+
+/// Area and unit weight of [`ipe300`].
+const AREA: f64 = 53.8e-4;
+const UNIT_WEIGHT: f64 = 78.5e3;
+
+/// 8 m girder along X in four members, resting on bearings at both ends.
+fn girder() -> Model {
+    let mut model = Model::new();
+    let nodes: Vec<_> = (0..5).map(|i| model.add_node((2.0 * i as f64, 0.0, 0.0))).collect();
+    for i in 0..4 {
+        model.add_member(nodes[i], nodes[i + 1], ipe300());
+    }
+    model.set_support(nodes[0], Fixity::pinned());
+    model.set_support(nodes[4], Fixity::pinned());
+    model
+}
+
+/// Lifted at the quarter points; one lug also holds the roll about the girder axis.
+fn lift() -> Lift {
+    let mut lift = Lift::new(0..4).with_lifting_point(3).with_dynamic_factor(1.3);
+    lift.add_lifting_point(1, Fixity::new([true; 3], [true, false, false]));
+    lift
+}
+
+#[test]
+fn slings_share_amplified_self_weight() {
+    let model = girder();
+    let w = AREA * UNIT_WEIGHT;
+    let report = check_lift(&model, &lift());
+    assert!(report.is_feasible());
+    assert_almost_eq!(report.weight, 8.0 * w);
+    assert_eq!(report.slings.len(), 2);
+    for sling in &report.slings {
+        assert_almost_eq!(sling.reaction.z, 0.5 * 1.3 * 8.0 * w, 1e-9);
+        assert_almost_eq!(sling.tension, sling.reaction.z);
+        assert_almost_eq!(sling.angle, 0.0);
+    }
+
+    // Hook 3 m above the center: slings at atan(2 / 3) from the vertical.
+    let inclined = check_lift(&model, &lift().with_hook((4.0, 0.0, 3.0).into()));
+    for sling in &inclined.slings {
+        assert_almost_eq!(sling.angle, (2.0f64 / 3.0).atan());
+        assert_almost_eq!(sling.tension, sling.reaction.z * 13.0f64.sqrt() / 3.0, 1e-9);
+    }
+    assert!(inclined.total_tension() > 1.3 * inclined.weight);
+}
+
+#[test]
+fn member_checks_use_amplified_moments() {
+    let model = girder();
+    let w = AREA * UNIT_WEIGHT;
+    // Cantilever moment over the lugs: 1.3 w 2² / 2.
+    let support_moment = 1.3 * w * 2.0;
+
+    let mut lift = lift();
+    for member in 0..4 {
+        lift.set_capacity(member, Capacity::new(1e6, 2.0 * support_moment));
+    }
+    let report = check_lift(&model, &lift);
+    let (_, utilization) = report.governing.unwrap();
+    assert_almost_eq!(utilization, 0.5, 1e-9);
+
+    lift.set_capacity(2, Capacity::new(1e6, 0.8 * support_moment));
+    let report = check_lift(&model, &lift);
+    assert!(matches!(report.failure, Some(StageFailure::Overstressed { member: 2, .. })));
+
+    // A single lug leaves the girder free to rotate about it.
+    let report = check_lift(&model, &Lift::new(0..4).with_lifting_point(2));
+    assert!(matches!(report.failure, Some(StageFailure::Unstable(_))));
+    assert!(report.slings.is_empty());
+}
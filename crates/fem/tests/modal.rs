@@ -0,0 +1,129 @@
+use fem::modal::compare::{auto_mac, ModeTracker};
+use fem::{frame::FrameAnalysis, modal::superposition::ModalSuperposition};
+use nalgebra::{DMatrix, DVector};
+use utils::assert_almost_eq;
+
+fn modes(columns: &[[f64; 3]]) -> DMatrix<f64> {
+    let cols: Vec<DVector<f64>> = columns.iter().map(|c| DVector::from_row_slice(c)).collect();
+    DMatrix::from_columns(&cols)
+}
+
+#[test]
+fn auto_mac_has_unit_diagonal() {
+    let set = modes(&[[1.0, 1.0, 0.0], [1.0, -1.0, 0.5]]);
+    let macs = auto_mac(&set);
+    assert_almost_eq!(macs[(0, 0)], 1.0);
+    assert_almost_eq!(macs[(1, 1)], 1.0);
+    assert_almost_eq!(macs[(0, 1)], macs[(1, 0)]);
+}
+
+#[test]
+fn tracker_follows_modes_through_crossing() {
+    let mut tracker = ModeTracker::new(modes(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]), 0.8);
+
+    // Step 1: shapes rotate slightly, order preserved.
+    let step1 = modes(&[[1.0, 0.2, 0.0], [-0.2, 1.0, 0.0]]);
+    let matches = tracker.track(&step1);
+    assert_eq!(matches[0].unwrap().candidate, 0);
+    assert_eq!(matches[1].unwrap().candidate, 1);
+
+    // Step 2: frequencies cross so the solver reports the modes swapped and sign-flipped.
+    let step2 = modes(&[[0.4, -1.0, 0.0], [-1.0, -0.4, 0.0]]);
+    let matches = tracker.track(&step2);
+    assert_eq!(matches[0].unwrap().candidate, 1);
+    assert_eq!(matches[1].unwrap().candidate, 0);
+
+    // Tracked shapes are sign-aligned with the previous step.
+    assert!(tracker.modes()[(0, 0)] > 0.0);
+    let reordered = ModeTracker::reorder(&step2, &matches);
+    assert_almost_eq!(reordered[0].as_ref().unwrap()[0], -1.0);
+}
+
+#[test]
+fn tracker_reports_lost_modes() {
+    let mut tracker = ModeTracker::new(modes(&[[1.0, 0.0, 0.0]]), 0.9);
+    let matches = tracker.track(&modes(&[[0.0, 0.0, 1.0]]));
+    assert!(matches[0].is_none());
+    assert_almost_eq!(tracker.modes()[(0, 0)], 1.0);
+}
+
+fn lowest_eigenvalue(k: &DMatrix<f64>, m: &DMatrix<f64>) -> f64 {
+    let l_inv = m.clone().cholesky().unwrap().l().try_inverse().unwrap();
+    let a = &l_inv * k * l_inv.transpose();
+    a.symmetric_eigen().eigenvalues.min()
+}
+
+#[test]
+fn irs_improves_on_guyan_for_lowest_mode() {
+    use fem::modal::condensation::{guyan, irs};
+
+    let n = 6;
+    let mut k = DMatrix::zeros(n, n);
+    for i in 0..n {
+        k[(i, i)] = if i + 1 == n { 1.0 } else { 2.0 };
+        if i + 1 < n {
+            k[(i, i + 1)] = -1.0;
+            k[(i + 1, i)] = -1.0;
+        }
+    }
+    let m = DMatrix::identity(n, n);
+    let master = [2, 5];
+
+    let exact = lowest_eigenvalue(&k, &m);
+    let static_reduced = guyan(&k, &m, &master).unwrap();
+    let improved = irs(&k, &m, &master).unwrap();
+    let guyan_value = lowest_eigenvalue(static_reduced.stiffness(), static_reduced.mass());
+    let irs_value = lowest_eigenvalue(improved.stiffness(), improved.mass());
+
+    // Condensation can only stiffen the model; IRS recovers most of the error.
+    assert!(guyan_value >= exact);
+    assert!(irs_value >= exact - 1e-12);
+    assert!((irs_value - exact) < 0.1 * (guyan_value - exact));
+    assert_eq!(improved.master(), &master);
+}
+
+#[test]
+fn undamped_chain_matches_closed_form_step_response() {
+    let k = DMatrix::from_row_slice(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 1.0]);
+    let eigen = k.clone().symmetric_eigen();
+    let modes = ModalSuperposition::new(eigen.eigenvalues.clone(), eigen.eigenvectors.clone());
+    let force = DVector::from_vec(vec![0.0, 0.0, 1.0]);
+    let response = modes.respond(&force, |_| 1.0, 0.05, 400);
+
+    for (i, &t) in response.times().iter().enumerate() {
+        let mut exact = DVector::zeros(3);
+        for mode in 0..3 {
+            let shape = eigen.eigenvectors.column(mode);
+            let omega_sq = eigen.eigenvalues[mode];
+            exact += shape * (shape.dot(&force) / omega_sq * (1.0 - (omega_sq.sqrt() * t).cos()));
+        }
+        for dof in 0..3 {
+            assert!((response.displacements()[(dof, i)] - exact[dof]).abs() < 1e-10);
+        }
+    }
+
+    // Sampled load vectors give the same history as the scaled pattern.
+    let sampled: Vec<_> = (0..=400).map(|_| force.clone()).collect();
+    assert_eq!(modes.respond_sampled(&sampled, 0.05).displacements(), response.displacements());
+    // Suddenly applied loads overshoot the static deflection of 3 at the tip.
+    assert!(response.peak_displacement(2).1 > 3.0);
+}
+
+#[test]
+fn damped_cantilever_settles_at_static_deflection() {
+    let benchmark = structure::benchmarks::cantilever_eigenvalues();
+    let model = benchmark.model();
+    let analysis = FrameAnalysis::new(model);
+    let modes = ModalSuperposition::from_eigen(&analysis.modes(12).unwrap()).with_damping(0.05);
+
+    let mut case = structure::LoadCase::new("tip");
+    case.add_nodal_force(1, geometry::Vector3d::new(0.0, 0.0, -1e3));
+    let force = analysis.load_vector(&case);
+    let tip = 6 + 2;
+    let response = modes.respond(&force, |t| t.min(0.01) / 0.01, 1e-4, 3000);
+    let static_tip = analysis.solve(&case).unwrap().displacement(1, 2);
+    let settled = *response.displacement_history(tip).last().unwrap();
+    assert!((settled - static_tip).abs() < 0.01 * static_tip.abs());
+    let (time, peak) = response.peak_displacement(tip);
+    assert!(time > 0.01 && peak.abs() > static_tip.abs());
+}
@@ -0,0 +1,224 @@
+use fem::frame::FrameAnalysis;
+use fem::nonlinear::{
+    AdaptiveStepping, CorotationalFrame, DisplacementControl, LoadStepping, NewtonSettings, NewtonSolver, NonlinearSystem,
+    StiffnessUpdate,
+};
+use nalgebra::{DMatrix, DVector};
+use structure::{Load, benchmarks};
+use utils::assert_almost_eq;
+
+/// Single spring with internal force `k u + c u³` (hardening for `c > 0`).
+struct CubicSpring {
+    k: f64,
+    c: f64,
+}
+
+impl NonlinearSystem for CubicSpring {
+    fn size(&self) -> usize { 1 }
+
+    fn internal_force(&self, u: &DVector<f64>) -> DVector<f64> {
+        DVector::from_element(1, self.k * u[0] + self.c * u[0].powi(3))
+    }
+
+    fn tangent_stiffness(&self, u: &DVector<f64>) -> DMatrix<f64> {
+        DMatrix::from_element(1, 1, self.k + 3.0 * self.c * u[0] * u[0])
+    }
+}
+
+#[test]
+fn newton_log_records_every_iteration() {
+    let spring = CubicSpring { k: 1.0, c: 1.0 };
+    let settings = NewtonSettings { stepping: LoadStepping::Fixed(4), ..NewtonSettings::default() };
+    let result = NewtonSolver::new(settings).solve(&spring, &DVector::from_element(1, 10.0));
+
+    assert!(result.converged);
+    assert_almost_eq!(result.load_factor, 1.0);
+    assert_almost_eq!(result.displacement[0], 2.0, 1e-8);
+
+    let log = &result.log;
+    assert_eq!(log.steps().len(), 4);
+    assert!(log.first_failure().is_none());
+    for step in log.steps() {
+        assert!(step.converged);
+        assert!(!step.iterations.is_empty());
+        assert!(step.final_residual() <= step.initial_residual);
+        assert_eq!(step.stiffness_updates(), step.iterations.len());
+    }
+    assert_almost_eq!(log.last_step().unwrap().checkpoint[0], 2.0, 1e-8);
+
+    let csv = log.to_csv();
+    assert_eq!(csv.lines().count(), log.total_iterations() + 1);
+    assert!(csv.starts_with("step,load_factor,iteration,residual_norm"));
+}
+
+#[test]
+fn modified_newton_reuses_stiffness() {
+    let spring = CubicSpring { k: 1.0, c: 1.0 };
+    let settings = NewtonSettings {
+        stepping: LoadStepping::Fixed(4),
+        max_iterations: 200,
+        stiffness_update: StiffnessUpdate::PerStep,
+        ..NewtonSettings::default()
+    };
+    let result = NewtonSolver::new(settings).solve(&spring, &DVector::from_element(1, 10.0));
+    assert!(result.converged);
+    for step in result.log.steps() {
+        assert_eq!(step.stiffness_updates(), 1);
+        assert!(step.iterations[0].stiffness_updated);
+    }
+}
+
+#[test]
+fn failed_step_is_logged_with_last_converged_checkpoint() {
+    // Softening spring with a limit load of 2/(3√3) ≈ 0.385.
+    let spring = CubicSpring { k: 1.0, c: -1.0 };
+    let settings = NewtonSettings { stepping: LoadStepping::Fixed(5), max_iterations: 15, ..NewtonSettings::default() };
+    let result = NewtonSolver::new(settings).solve(&spring, &DVector::from_element(1, 0.5));
+
+    assert!(!result.converged);
+    assert_almost_eq!(result.load_factor, 0.6);
+    let failure = result.log.first_failure().unwrap();
+    assert_eq!(failure.step, 4);
+    assert!(failure.final_residual() > 0.0);
+    assert!(failure.iterations.iter().any(|it| it.line_search_factor < 1.0));
+    let last = result.log.last_converged().unwrap();
+    assert_eq!(last.step, 3);
+    assert_eq!(last.checkpoint, result.displacement);
+}
+
+#[test]
+fn adaptive_stepping_cuts_and_grows_increments() {
+    // Stiffening spring: a single full-load step needs many iterations.
+    let spring = CubicSpring { k: 1.0, c: 10.0 };
+    let adaptive = AdaptiveStepping { initial: 1.0, max: 1.0, ..AdaptiveStepping::default() };
+    let settings = NewtonSettings {
+        stepping: LoadStepping::Adaptive(adaptive),
+        max_iterations: 6,
+        max_line_search: 0,
+        ..NewtonSettings::default()
+    };
+    let result = NewtonSolver::new(settings).solve(&spring, &DVector::from_element(1, 1000.0));
+    assert!(result.converged);
+    assert_almost_eq!(result.load_factor, 1.0);
+    let u = result.displacement[0];
+    assert_almost_eq!(u + 10.0 * u.powi(3), 1000.0, 1e-8);
+
+    let steps = result.log.steps();
+    assert!(!steps[0].converged);
+    assert!(steps[1].load_factor < steps[0].load_factor);
+    let increments: Vec<f64> = steps
+        .iter()
+        .filter(|s| s.converged)
+        .map(|s| s.load_factor)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .collect();
+    assert!(increments.windows(2).any(|w| w[1] > w[0]));
+}
+
+/// Spring with internal force `atan(u)`, which never exceeds π/2.
+struct SaturatingSpring;
+
+impl NonlinearSystem for SaturatingSpring {
+    fn size(&self) -> usize { 1 }
+
+    fn internal_force(&self, u: &DVector<f64>) -> DVector<f64> {
+        DVector::from_element(1, u[0].atan())
+    }
+
+    fn tangent_stiffness(&self, u: &DVector<f64>) -> DMatrix<f64> {
+        DMatrix::from_element(1, 1, 1.0 / (1.0 + u[0] * u[0]))
+    }
+}
+
+#[test]
+fn adaptive_stepping_stops_at_minimum_increment() {
+    let result = NewtonSolver::new(NewtonSettings::default()).solve(&SaturatingSpring, &DVector::from_element(1, 2.0));
+    assert!(!result.converged);
+    assert!(!result.log.last_step().unwrap().converged);
+    // The resistance saturates at π/2, i.e. a load factor of π/4.
+    assert!(result.load_factor > 0.5 && result.load_factor < std::f64::consts::FRAC_PI_4);
+    assert_almost_eq!(result.displacement[0].atan(), 2.0 * result.load_factor, 1e-8);
+}
+
+#[test]
+fn displacement_control_traces_past_limit_point() {
+    // Softening spring: load control fails at λ ≈ 0.385, displacement control
+    // follows the descending branch.
+    let spring = CubicSpring { k: 1.0, c: -1.0 };
+    let control = DisplacementControl::new(0, 1.5, 15);
+    let result =
+        NewtonSolver::new(NewtonSettings::default()).solve_displacement_controlled(&spring, &DVector::from_element(1, 1.0), &control);
+
+    assert!(result.converged);
+    assert_almost_eq!(result.displacement[0], 1.5);
+    assert_almost_eq!(result.load_factor, 1.5 - 1.5f64.powi(3), 1e-8);
+
+    let path = result.log.equilibrium_path(0);
+    assert_eq!(path.len(), 16);
+    for &(u, lambda) in &path {
+        assert_almost_eq!(lambda, u - u.powi(3), 1e-8);
+    }
+    let peak = path.iter().map(|&(_, lambda)| lambda).fold(f64::MIN, f64::max);
+    assert!(peak > 0.38 && peak <= 2.0 / (3.0 * 3f64.sqrt()));
+}
+
+/// Two springs in series, the first softening: the controlled tip passes the
+/// limit point while the inner DOF snaps back.
+struct SofteningChain;
+
+impl NonlinearSystem for SofteningChain {
+    fn size(&self) -> usize { 2 }
+
+    fn internal_force(&self, u: &DVector<f64>) -> DVector<f64> {
+        let first = u[0] - u[0].powi(3);
+        let second = 10.0 * (u[1] - u[0]);
+        DVector::from_vec(vec![first - second, second])
+    }
+
+    fn tangent_stiffness(&self, u: &DVector<f64>) -> DMatrix<f64> {
+        let k1 = 1.0 - 3.0 * u[0] * u[0];
+        DMatrix::from_row_slice(2, 2, &[k1 + 10.0, -10.0, -10.0, 10.0])
+    }
+}
+
+#[test]
+fn displacement_control_of_multi_dof_system() {
+    let external = DVector::from_vec(vec![0.0, 1.0]);
+    let control = DisplacementControl::new(1, 1.2, 24);
+    let result = NewtonSolver::new(NewtonSettings::default()).solve_displacement_controlled(&SofteningChain, &external, &control);
+
+    assert!(result.converged);
+    let u = &result.displacement;
+    assert_almost_eq!(u[1], 1.2);
+    let residual = &external * result.load_factor - SofteningChain.internal_force(u);
+    assert!(residual.norm() < 1e-8);
+    assert!(result.log.equilibrium_path(1).windows(2).any(|w| w[1].1 < w[0].1));
+}
+
+#[test]
+fn pushover_of_portal_frame_model() {
+    let benchmark = benchmarks::portal_frame();
+    let model = benchmark.model();
+    let [Load::Nodal { node, force, .. }] = benchmark.load_case().loads() else { panic!("expected one nodal load") };
+    let sway = FrameAnalysis::new(model).solve(benchmark.load_case()).unwrap().displacement(*node, 0);
+
+    let frame = CorotationalFrame::new(model);
+    let dof = frame.free_dof(*node, 0).unwrap();
+    let mut external = DVector::zeros(frame.size());
+    external[dof] = force.x();
+    let control = DisplacementControl::new(dof, 20.0 * sway, 20);
+    let result = NewtonSolver::new(NewtonSettings::default()).solve_displacement_controlled(&frame, &external, &control);
+
+    assert!(result.converged);
+    let residual = &external * result.load_factor - frame.internal_force(&result.displacement);
+    assert!(residual.norm() <= 1e-8 * external.norm());
+    // Small sways follow the linear analysis; the whole curve stays close to it.
+    let path = result.log.equilibrium_path(dof);
+    assert_eq!(path.len(), 21);
+    assert_almost_eq!(path[1].1, 1.0, 1e-3);
+    for &(u, lambda) in &path[1..] {
+        assert_almost_eq!(lambda, u / sway, 1e-2);
+    }
+}
@@ -0,0 +1,78 @@
+use fem::frame::FrameAnalysis;
+use geometry::Vector3d;
+use structure::{Instability, LoadCase, Material, Model, Section, SupportPatch};
+use utils::assert_almost_eq;
+
+fn section() -> Section {
+    let mut section = Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None);
+    section.set_area(0.25);
+    section.set_second_moment_components(5.2e-3, 5.2e-3, 0.0);
+    section.set_torsion_constant(8.8e-3);
+    section
+}
+
+/// Square ring of stout beams in the XY plane, a pile cap resting on its four
+/// corners.
+fn cap() -> Model {
+    let mut model = Model::new();
+    let corners: Vec<_> = [(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0), (0.0, 2.0, 0.0)]
+        .into_iter()
+        .map(|p| model.add_node(p))
+        .collect();
+    for i in 0..4 {
+        model.add_member(corners[i], corners[(i + 1) % 4], section());
+    }
+    model
+}
+
+#[test]
+fn centered_load_settles_patch_uniformly() {
+    let mut model = cap();
+    let k = 1e8;
+    model.add_support_patch(SupportPatch::uniform([k, k, k], 0..4));
+    assert!(model.instabilities().is_empty());
+
+    let mut case = LoadCase::new("uniform");
+    for node in 0..4 {
+        case.add_nodal_force(node, Vector3d::new(0.0, 0.0, -250e3));
+    }
+    let result = FrameAnalysis::new(&model).solve(&case).unwrap();
+    for node in 0..4 {
+        // A rigid-body settlement: the beams stay unstrained.
+        assert_almost_eq!(result.displacement(node, 2), -1e6 / k, 1e-12);
+        assert_almost_eq!(result.reaction(node, 2), 250e3, 1e-3);
+    }
+}
+
+#[test]
+fn eccentric_load_is_in_equilibrium_with_spring_reactions() {
+    let mut model = cap();
+    let patch = SupportPatch::new([1e8, 1e8, 4e8]).with_node(0, 1.0).with_node(1, 2.0).with_node(2, 1.0).with_node(3, 2.0);
+    model.add_support_patch(patch);
+
+    let mut case = LoadCase::new("eccentric");
+    case.add_nodal_force(0, Vector3d::new(30e3, 0.0, -500e3));
+    let result = FrameAnalysis::new(&model).solve(&case).unwrap();
+
+    let mut force = Vector3d::new(0.0, 0.0, 0.0).0;
+    let mut moment = force;
+    for node in 0..4 {
+        let reaction = Vector3d::new(result.reaction(node, 0), result.reaction(node, 1), result.reaction(node, 2)).0;
+        force += reaction;
+        moment += model.node(node).center().0.cross(&reaction);
+    }
+    assert_almost_eq!(force.x, -30e3, 1e-4);
+    assert_almost_eq!(force.z, 500e3, 1e-4);
+    // The load acts at the origin, so the reactions carry no net moment.
+    assert!(moment.norm() < 1e-4);
+    // The loaded corner takes more than the opposite one of equal stiffness.
+    assert!(result.reaction(0, 2) > result.reaction(2, 2));
+}
+
+#[test]
+fn patch_without_horizontal_stiffness_is_a_mechanism() {
+    let mut model = cap();
+    model.add_support_patch(SupportPatch::uniform([0.0, 0.0, 1e8], 0..4));
+    let found = model.instabilities();
+    assert!(matches!(found[..], [Instability::RigidBodyMotion { modes: 3, .. }]));
+}
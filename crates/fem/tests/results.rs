@@ -0,0 +1,82 @@
+use fem::{
+    frame::{FrameAnalysis, StaticResult},
+    results::{Location, Quantity, Tolerance, Tolerances, compare},
+};
+use geometry::Vector3d;
+use structure::{Fixity, LoadCase, Material, Model, Section};
+
+/// Cantilever of two members with a tip load.
+fn cantilever() -> (Model, LoadCase) {
+    let mut model = Model::new();
+    let nodes: Vec<_> = (0..3).map(|i| model.add_node((i as f64 * 2.0, 0.0, 0.0))).collect();
+    let mut section = Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None);
+    section.set_area(0.01);
+    section.set_second_moment_components(8e-5, 8e-5, 0.0);
+    section.set_torsion_constant(1e-5);
+    model.add_member(nodes[0], nodes[1], section.clone());
+    model.add_member(nodes[1], nodes[2], section);
+    model.set_support(nodes[0], Fixity::fixed());
+    let mut case = LoadCase::new("tip");
+    case.add_nodal_force(nodes[2], Vector3d::new(0.0, 0.0, -10e3));
+    (model, case)
+}
+
+fn solve() -> StaticResult {
+    let (model, case) = cantilever();
+    FrameAnalysis::new(&model).solve(&case).unwrap()
+}
+
+#[test]
+fn identical_results_match() {
+    let result = solve();
+    let report = compare(&result, &result, &Tolerances::default());
+    assert!(report.is_match());
+    assert_eq!(report.compared, 18 + 18 + 24);
+    assert!(report.worst().is_none());
+    assert_eq!(report.to_string(), "results match (60 values compared)");
+}
+
+#[test]
+fn perturbed_values_are_reported() {
+    let reference = solve();
+    let mut displacements = reference.displacements().clone();
+    displacements[2 * 6 + 2] *= 1.01;
+    let mut member_forces: Vec<_> = (0..2).map(|m| *reference.member_forces(m)).collect();
+    member_forces[0][4] += 1.0;
+    let candidate = StaticResult::new(displacements, reference.reactions().clone(), member_forces);
+
+    let report = compare(&reference, &candidate, &Tolerances::default());
+    assert!(!report.is_match());
+    assert_eq!(report.differences.len(), 2);
+    assert_eq!(report.differences[0].quantity, Quantity::Displacement);
+    assert_eq!(report.differences[0].location, Location::Dof { node: 2, dof: 2 });
+    assert_eq!(report.differences[1].location, Location::MemberEnd { member: 0, entry: 4 });
+    assert_eq!(report.worst().unwrap().quantity, Quantity::Displacement);
+
+    let text = report.to_string();
+    assert!(text.starts_with("results differ: 2 of 60 values out of tolerance, 0 size mismatches"));
+    assert!(text.contains("displacement at node 2 uz"));
+    assert!(text.contains("member force at member 0 start My"));
+
+    let loose = Tolerances::default()
+        .with_displacement(Tolerance::new(0.0, 0.02))
+        .with_member_force(Tolerance::new(2.0, 0.0));
+    assert!(compare(&reference, &candidate, &loose).is_match());
+}
+
+#[test]
+fn size_mismatch_is_reported() {
+    let reference = solve();
+    let shorter = StaticResult::new(
+        reference.displacements().rows(0, 12).into_owned(),
+        reference.reactions().rows(0, 12).into_owned(),
+        vec![*reference.member_forces(0)],
+    );
+    let report = compare(&reference, &shorter, &Tolerances::default());
+    assert!(!report.is_match());
+    assert_eq!(
+        report.size_mismatches,
+        vec![(Quantity::Displacement, 18, 12), (Quantity::Reaction, 18, 12), (Quantity::MemberForce, 24, 12)]
+    );
+    assert!(report.to_string().contains("displacement: reference has 18 values, candidate 12"));
+}
@@ -1,37 +1,39 @@
-use std::f64::consts::PI;
+use nalgebra::{ComplexField, Matrix3, RealField, Vector3};
+use num_traits::{One, Zero};
 
-use nalgebra::Vector3;
-
-use crate::line::{Line, LineVector};
-use crate::{Vector2d, Vector3d};
-use utils::epsilon;
+use crate::line::{Line, LineVector, LocalAxis};
+use crate::{BoundingBox3d, GeometryError, Scalar, Vector2d, Vector3d};
 
 pub trait ArcVector: LineVector {
-    fn to_vec3(&self) -> Vector3<f64>;
-    fn from_vec3(vec: Vector3<f64>) -> Self;
+    fn to_vec3(&self) -> Vector3<Self::Scalar>;
+    fn from_vec3(vec: Vector3<Self::Scalar>) -> Self;
 }
 
-impl ArcVector for Vector2d {
-    fn to_vec3(&self) -> Vector3<f64> {
-        Vector3::new(self.x(), self.y(), 0.0)
+impl<T: Scalar> ArcVector for Vector2d<T> {
+    fn to_vec3(&self) -> Vector3<T> {
+        Vector3::new(self.x(), self.y(), T::zero())
     }
 
-    fn from_vec3(vec: Vector3<f64>) -> Self {
+    fn from_vec3(vec: Vector3<T>) -> Self {
         Vector2d::new(vec.x, vec.y)
     }
 }
 
-impl ArcVector for Vector3d {
-    fn to_vec3(&self) -> Vector3<f64> {
+impl<T: Scalar> ArcVector for Vector3d<T> {
+    fn to_vec3(&self) -> Vector3<T> {
         self.0
     }
 
-    fn from_vec3(vec: Vector3<f64>) -> Self {
+    fn from_vec3(vec: Vector3<T>) -> Self {
         Vector3d(vec)
     }
 }
 
+/// `point` as a double-precision 3D point, e.g. for a bounding box.
+pub(crate) fn widened<V: ArcVector>(point: &V) -> Vector3d { Vector3d(point.to_vec3().map(Scalar::as_f64)) }
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Arc<V>
 where
     V: ArcVector,
@@ -39,9 +41,9 @@ where
     center: V,
     start: V,
     end: V,
-    normal: Vector3<f64>,
-    sweep: f64,
-    radius: f64,
+    normal: Vector3<V::Scalar>,
+    sweep: V::Scalar,
+    radius: V::Scalar,
 }
 
 // Local 2D/3D aliases removed; crate root provides 3D public aliases instead.
@@ -56,6 +58,7 @@ where
         S: Into<V>,
         E: Into<V>,
     {
+        let (zero, one, eps, pi) = (V::Scalar::zero(), V::Scalar::one(), V::Scalar::epsilon(), V::Scalar::pi());
         let center: V = center.into();
         let start: V = start.into();
         let end: V = end.into();
@@ -68,47 +71,47 @@ where
 
         let start_len = start_vec.norm();
         let end_len = end_vec.norm();
-        let radius = (start_len + end_len) * 0.5;
+        let radius = (start_len + end_len) * V::Scalar::from_f64_lossy(0.5);
 
         let cross = start_vec3.cross(&end_vec3);
         let cross_norm = cross.norm();
-        let mut normal = if cross_norm <= epsilon() {
-            Vector3::new(0.0, 0.0, if clockwise { -1.0 } else { 1.0 })
+        let mut normal = if cross_norm <= eps {
+            Vector3::new(zero, zero, if clockwise { -one } else { one })
         } else {
             cross / cross_norm
         };
 
-        if cross_norm > epsilon() && clockwise {
+        if cross_norm > eps && clockwise {
             normal = -normal;
         }
 
-        let start_dir = if start_len <= epsilon() {
-            Vector3::new(1.0, 0.0, 0.0)
+        let start_dir = if start_len <= eps {
+            Vector3::x()
         } else {
             start_vec3 / start_len
         };
 
-        let end_dir = if end_len <= epsilon() {
+        let end_dir = if end_len <= eps {
             start_dir
         } else {
             end_vec3 / end_len
         };
 
         let cross_dir = start_dir.cross(&end_dir);
-        let dot = start_dir.dot(&end_dir).clamp(-1.0, 1.0);
+        let dot = start_dir.dot(&end_dir).clamp(-one, one);
         let mut sweep = cross_dir.norm().atan2(dot);
-        if cross_dir.dot(&normal) < 0.0 {
+        if cross_dir.dot(&normal) < zero {
             sweep = -sweep;
         }
 
-        if clockwise && sweep > 0.0 {
-            sweep -= 2.0 * PI;
-        } else if !clockwise && sweep < 0.0 {
-            sweep += 2.0 * PI;
+        if clockwise && sweep > zero {
+            sweep -= V::Scalar::two_pi();
+        } else if !clockwise && sweep < zero {
+            sweep += V::Scalar::two_pi();
         }
 
-        if sweep.abs() <= epsilon() {
-            sweep = if clockwise { -PI } else { PI };
+        if sweep.abs() <= eps {
+            sweep = if clockwise { -pi } else { pi };
         }
 
         normal = normal.normalize();
@@ -122,6 +125,7 @@ where
         P2: Into<V>,
         P3: Into<V>,
     {
+        let eps = V::Scalar::epsilon();
         let p1: V = p1.into();
         let p2: V = p2.into();
         let p3: V = p3.into();
@@ -131,13 +135,13 @@ where
 
         let v1 = p2_vec - p1_vec;
         let v2 = p3_vec - p1_vec;
-        if v1.norm() <= epsilon() || v2.norm() <= epsilon() {
+        if v1.norm() <= eps || v2.norm() <= eps {
             return None;
         }
 
         let normal = v1.cross(&v2);
         let normal_norm = normal.norm();
-        if normal_norm <= epsilon() {
+        if normal_norm <= eps {
             return None;
         }
         let normal_unit = normal / normal_norm;
@@ -150,19 +154,128 @@ where
         let x3 = diff3.dot(&u);
         let y3 = diff3.dot(&v);
 
-        if y3.abs() <= epsilon() {
+        if y3.abs() <= eps {
             return None;
         }
 
-        let cx = x2 * 0.5;
-        let cy = (x3 * x3 + y3 * y3 - x2 * x3) / (2.0 * y3);
+        let two = V::Scalar::from_f64_lossy(2.0);
+        let cx = x2 / two;
+        let cy = (x3 * x3 + y3 * y3 - x2 * x3) / (two * y3);
 
         let center_vec = p1_vec + u * cx + v * cy;
         let center = V::from_vec3(center_vec);
-        let clockwise = cy < 0.0;
+        let clockwise = cy < V::Scalar::zero();
         Some(Self::new(center, p1, p3, clockwise))
     }
 
+    /// [`Arc::from_three_points`] that tells coincident points from collinear ones.
+    pub fn try_from_three_points<P1, P2, P3>(p1: P1, p2: P2, p3: P3) -> Result<Self, GeometryError>
+    where
+        P1: Into<V>,
+        P2: Into<V>,
+        P3: Into<V>,
+    {
+        let (p1, p2, p3): (V, V, V) = (p1.into(), p2.into(), p3.into());
+        let [a, b, c] = [p1, p2, p3].map(|p| p.to_vec3());
+        let eps = V::Scalar::epsilon();
+        if (b - a).norm() <= eps || (c - b).norm() <= eps || (a - c).norm() <= eps {
+            return Err(GeometryError::CoincidentPoints);
+        }
+        Self::from_three_points(p1, p2, p3).ok_or(GeometryError::Collinear)
+    }
+
+    /// Fillet of radius `radius` between two lines meeting at a corner.
+    ///
+    /// The lines are extended to their intersection and the arc is placed in the
+    /// corner between the rays running from it towards each line's far end. The
+    /// arc starts at the tangent point on `first` and ends on `second`. Returns
+    /// `None` for parallel or skew lines, or when the tangent points would fall
+    /// outside the segments.
+    pub fn fillet(first: &Line<V>, second: &Line<V>, radius: V::Scalar) -> Option<Self> {
+        Self::fillet_with_ends(first, second, radius).map(|(_, arc, _)| arc)
+    }
+
+    /// [`Arc::fillet`] together with the far ends of both lines.
+    fn fillet_with_ends(first: &Line<V>, second: &Line<V>, radius: V::Scalar) -> Option<(V, Self, V)> {
+        let (one, eps) = (V::Scalar::one(), V::Scalar::epsilon());
+        if radius <= eps {
+            return None;
+        }
+        let (a0, a1) = (first.start().to_vec3(), first.end().to_vec3());
+        let (b0, b1) = (second.start().to_vec3(), second.end().to_vec3());
+        let (d1, d2) = (a1 - a0, b1 - b0);
+        let normal = d1.cross(&d2);
+        if normal.norm() <= eps * d1.norm() * d2.norm() {
+            return None;
+        }
+        // Intersection of the infinite lines: a0 + s d1 = b0 + t d2.
+        let r = b0 - a0;
+        let s = r.cross(&d2).dot(&normal) / normal.norm_squared();
+        let t = r.cross(&d1).dot(&normal) / normal.norm_squared();
+        let corner = a0 + d1 * s;
+        if (corner - (b0 + d2 * t)).norm() > eps * (corner.norm() + d1.norm() + d2.norm()) {
+            return None;
+        }
+
+        let far = |p: Vector3<V::Scalar>, q: Vector3<V::Scalar>| if (p - corner).norm() > (q - corner).norm() { p } else { q };
+        let (far1, far2) = (far(a0, a1), far(b0, b1));
+        let (reach1, reach2) = ((far1 - corner).norm(), (far2 - corner).norm());
+        let u = (far1 - corner) / reach1;
+        let w = (far2 - corner) / reach2;
+        let half = u.dot(&w).clamp(-one, one).acos() / V::Scalar::from_f64_lossy(2.0);
+        let setback = radius / half.tan();
+        if setback > reach1 + eps || setback > reach2 + eps {
+            return None;
+        }
+        let bisector = (u + w).normalize();
+        let center = corner + bisector * (radius / half.sin());
+        let start = corner + u * setback;
+        let end = corner + w * setback;
+        let arc = Self::new(V::from_vec3(center), V::from_vec3(start), V::from_vec3(end), false);
+        Some((V::from_vec3(far1), arc, V::from_vec3(far2)))
+    }
+
+    /// Lines tangent to both this arc and `other`, running from the tangent point
+    /// on `self` to the one on `other`.
+    ///
+    /// Both the outer and the crossing tangents of the two circles are considered
+    /// (in the plane of `self`); only those touching both arcs within their sweep
+    /// are returned.
+    pub fn tangent_lines(&self, other: &Self) -> Vec<Line<V>> {
+        let (zero, one, eps) = (V::Scalar::zero(), V::Scalar::one(), V::Scalar::epsilon());
+        let c1 = self.center.to_vec3();
+        let c2 = other.center.to_vec3();
+        let offset = c2 - c1;
+        let in_plane = offset - self.normal * offset.dot(&self.normal);
+        let distance = in_plane.norm();
+        if distance <= eps {
+            return Vec::new();
+        }
+        let ex = in_plane / distance;
+        let ey = self.normal.cross(&ex);
+        let (r1, r2) = (self.radius, other.radius);
+
+        let mut lines = Vec::new();
+        for sign in [one, -one] {
+            let r2_signed = sign * r2;
+            let h = (r2_signed - r1) / distance;
+            if h.abs() > one + eps {
+                continue;
+            }
+            let root = (one - h * h).max(zero).sqrt();
+            for side in [one, -one] {
+                let n = ex * h + ey * (side * root);
+                let p = V::from_vec3(c1 - n * r1);
+                let q = V::from_vec3(c1 + in_plane - n * r2_signed);
+                let duplicate = lines.iter().any(|l: &Line<V>| l.start().is_approx(&p, None) && l.end().is_approx(&q, None));
+                if !duplicate && !p.is_approx(&q, None) && self.contains(&p, None) && other.contains(&q, None) {
+                    lines.push(Line::new(p, q));
+                }
+            }
+        }
+        lines
+    }
+
     pub fn center(&self) -> V {
         self.center
     }
@@ -175,36 +288,36 @@ where
         self.end
     }
 
-    pub fn radius(&self) -> f64 {
+    pub fn radius(&self) -> V::Scalar {
         self.radius
     }
 
-    pub fn angle(&self) -> f64 {
+    pub fn angle(&self) -> V::Scalar {
         self.sweep
     }
 
-    pub fn length(&self) -> f64 {
+    pub fn length(&self) -> V::Scalar {
         self.radius * self.sweep.abs()
     }
 
-    pub fn point_at(&self, t: f64) -> V {
+    pub fn point_at(&self, t: V::Scalar) -> V {
         self.point_at_angle(self.sweep * t)
     }
 
-    pub fn angle_at(&self, t: f64) -> f64 {
+    /// Point at arc length `length` from the start, clamped to the arc.
+    pub fn point_at_length(&self, length: V::Scalar) -> V { self.point_at(self.parameter_at_length(length)) }
+
+    /// Parameter `t` of the point at arc length `length` from the start,
+    /// clamped to `[0, 1]`.
+    pub fn parameter_at_length(&self, length: V::Scalar) -> V::Scalar { crate::curve::parameter_at_length(length, self.length()) }
+
+    pub fn angle_at(&self, t: V::Scalar) -> V::Scalar {
         self.sweep * t
     }
 
-    pub fn point_at_angle(&self, angle: f64) -> V {
+    pub fn point_at_angle(&self, angle: V::Scalar) -> V {
         let center_vec = self.center.to_vec3();
-        let start_vec = self.start.to_vec3() - center_vec;
-        let start_dir = if start_vec.norm() <= epsilon() {
-            Vector3::new(1.0, 0.0, 0.0)
-        } else {
-            start_vec.normalize()
-        };
-
-        let perp = self.normal.cross(&start_dir);
+        let (start_dir, perp) = self.start_frame();
         let rotated = start_dir * angle.cos() + perp * angle.sin();
         V::from_vec3(center_vec + rotated * self.radius)
     }
@@ -214,51 +327,91 @@ where
         self.point_at_angle(angle)
     }
 
-    pub fn distance(&self, point: &V) -> f64 {
+    pub fn distance(&self, point: &V) -> V::Scalar {
         let closest = self.closest_point(point);
         point.sub(&closest).norm()
     }
 
-    pub fn contains(&self, point: &V) -> bool {
+    /// Whether `point` lies on the arc within `tolerance` of its circle,
+    /// [`Scalar::epsilon`] by default.
+    pub fn contains(&self, point: &V, tolerance: Option<f64>) -> bool {
         let angle = self.angle_from_point(point);
         if !self.angle_in_range(angle) {
             return false;
         }
         let radial = point.sub(&self.center).norm();
-        (radial - self.radius).abs() <= epsilon()
+        (radial - self.radius).abs() <= V::Scalar::tolerance(tolerance)
     }
 
-    pub fn break_at(&self, t: f64) -> Vec<Self> {
-        if t <= 0.0 || t >= 1.0 {
+    pub fn break_at(&self, t: V::Scalar) -> Vec<Self> {
+        if t <= V::Scalar::zero() || t >= V::Scalar::one() {
             return vec![*self];
         }
 
         let angle = self.sweep * t;
-        vec![self.segment(0.0, angle), self.segment(angle, self.sweep)]
+        vec![self.segment(V::Scalar::zero(), angle), self.segment(angle, self.sweep)]
     }
 
-    pub fn break_at_angle(&self, angle: f64) -> Vec<Self> {
-        if self.sweep.abs() <= epsilon() {
+    pub fn break_at_angle(&self, angle: V::Scalar) -> Vec<Self> {
+        if self.sweep.abs() <= V::Scalar::epsilon() {
             return vec![*self];
         }
         if !self.angle_in_range(angle) {
             return vec![*self];
         }
-        vec![self.segment(0.0, angle), self.segment(angle, self.sweep)]
+        vec![self.segment(V::Scalar::zero(), angle), self.segment(angle, self.sweep)]
     }
 
     pub fn break_at_point(&self, point: &V) -> Vec<Self> {
-        if !self.contains(point) {
+        if !self.contains(point, None) {
             return vec![*self];
         }
         let angle = self.angle_from_point(point);
         self.break_at_angle(angle)
     }
 
+    /// Split at every point lying on the arc, in order from the start; points
+    /// off the arc, at its ends or repeated within tolerance are ignored.
+    pub fn break_at_points(&self, points: &[V]) -> Vec<Self> {
+        let eps = V::Scalar::epsilon();
+        let mut angles: Vec<V::Scalar> = points
+            .iter()
+            .filter(|point| (point.sub(&self.center).norm() - self.radius).abs() <= eps)
+            .map(|point| self.unwrapped(self.angle_from_point(point)))
+            .filter(|&angle| angle.abs() * self.radius > eps && (self.sweep - angle).abs() * self.radius > eps)
+            .filter(|angle| self.angle_in_range(*angle))
+            .collect();
+        angles.sort_by(|a, b| a.abs().as_f64().total_cmp(&b.abs().as_f64()));
+        angles.dedup_by(|b, a| (*b - *a).abs() * self.radius <= eps);
+
+        let mut start = V::Scalar::zero();
+        let mut arcs = Vec::with_capacity(angles.len() + 1);
+        for angle in angles {
+            arcs.push(self.segment(start, angle));
+            start = angle;
+        }
+        arcs.push(self.segment(start, self.sweep));
+        arcs
+    }
+
+    /// Centroid of the arc as a curve, on the bisector at `r sin(θ/2) / (θ/2)`
+    /// from the center.
+    pub fn centroid(&self) -> V {
+        let half_turn = V::Scalar::from_f64_lossy(0.5);
+        let half = self.sweep.abs() * half_turn;
+        if half <= V::Scalar::epsilon() {
+            return self.point_at(half_turn);
+        }
+        let center = self.center.to_vec3();
+        let bisector = self.point_at(half_turn).to_vec3() - center;
+        V::from_vec3(center + bisector * (half.sin() / half))
+    }
+
+    /// Run the arc backwards over the same points. Only the sweep changes sign;
+    /// flipping the normal as well would turn the arc onto the other side.
     pub fn reverse(&mut self) {
         std::mem::swap(&mut self.start, &mut self.end);
         self.sweep = -self.sweep;
-        self.normal = -self.normal;
     }
 
     pub fn reversed(&self) -> Self {
@@ -268,26 +421,27 @@ where
     }
 
     pub fn start_tangent(&self) -> V {
-        self.tangent_at_angle(0.0)
+        self.tangent_at_angle(V::Scalar::zero())
     }
 
     pub fn end_tangent(&self) -> V {
         self.tangent_at_angle(self.sweep)
     }
 
-    pub fn angle_from_point(&self, point: &V) -> f64 {
-        let center_vec = self.center.to_vec3();
-        let start_vec = self.start.to_vec3() - center_vec;
-        let start_dir = if start_vec.norm() <= epsilon() {
-            Vector3::new(1.0, 0.0, 0.0)
-        } else {
-            start_vec.normalize()
-        };
+    /// Local frame at the start point: X along [`Arc::start_tangent`], Z along
+    /// the arc normal and Y = Z × X, pointing to the center for arcs turning
+    /// positively about the normal.
+    pub fn rotation_matrix(&self) -> Matrix3<V::Scalar> {
+        let ex = self.start_tangent().to_vec3().normalize();
+        let ez = self.normal;
+        Matrix3::from_columns(&[ex, ez.cross(&ex), ez])
+    }
 
-        let perp = self.normal.cross(&start_dir);
-        let vec = point.to_vec3() - center_vec;
-        if vec.norm() <= epsilon() {
-            return 0.0;
+    pub fn angle_from_point(&self, point: &V) -> V::Scalar {
+        let (start_dir, perp) = self.start_frame();
+        let vec = point.to_vec3() - self.center.to_vec3();
+        if vec.norm() <= V::Scalar::epsilon() {
+            return V::Scalar::zero();
         }
         let vec_dir = vec.normalize();
         let x = vec_dir.dot(&start_dir);
@@ -295,102 +449,95 @@ where
         y.atan2(x)
     }
 
-    pub fn length_at_angle(&self, angle: f64) -> f64 {
+    pub fn length_at_angle(&self, angle: V::Scalar) -> V::Scalar {
         self.radius * angle.abs()
     }
 
-    pub fn length_at_point(&self, point: &V) -> f64 {
+    pub fn length_at_point(&self, point: &V) -> V::Scalar {
         let angle = self.clamped_angle_from_point(point);
         self.length_at_angle(angle)
     }
 
-    pub fn intersection_with_line(&self, line: &Line<V>, treat_as_ray: bool) -> Vec<V> {
+    /// Points where a line segment crosses the arc, in order along the line.
+    pub fn intersection_with_line(&self, line: &Line<V>) -> Vec<V> {
+        let eps = V::Scalar::epsilon();
+        self.line_parameters(line)
+            .into_iter()
+            .filter(|&t| t >= -eps && t <= V::Scalar::one() + eps)
+            .map(|t| line.point_at(t))
+            .collect()
+    }
+
+    /// Parameters along the infinite line through `line` (0 at its start, 1 at
+    /// its end) of the points lying on the arc, in ascending order. A tangent
+    /// line touches once.
+    pub(crate) fn line_parameters(&self, line: &Line<V>) -> Vec<V::Scalar> {
+        let (zero, one, eps) = (V::Scalar::zero(), V::Scalar::one(), V::Scalar::epsilon());
+        let (two, four) = (V::Scalar::from_f64_lossy(2.0), V::Scalar::from_f64_lossy(4.0));
         let center_vec = self.center.to_vec3();
         let line_start = line.start().to_vec3();
         let line_dir = line.end().to_vec3() - line_start;
 
         let to_center = line_start - center_vec;
         let a = line_dir.dot(&line_dir);
-        let b = 2.0 * line_dir.dot(&to_center);
+        let b = two * line_dir.dot(&to_center);
         let c = to_center.dot(&to_center) - self.radius * self.radius;
 
-        let discriminant = b * b - 4.0 * a * c;
-        if discriminant < -epsilon() {
+        let discriminant = b * b - four * a * c;
+        if a <= eps || discriminant < -eps {
             return Vec::new();
         }
 
-        let mut result = Vec::new();
-        let disc_sqrt = discriminant.max(0.0).sqrt();
-        if disc_sqrt <= epsilon() {
-            // Tangent: single intersection
-            let t = -b / (2.0 * a);
-            let outside_segment = (!treat_as_ray && (t < -epsilon() || t > 1.0 + epsilon()))
-                || (treat_as_ray && t < -epsilon());
-            if !outside_segment {
-                let point_vec = line_start + line_dir * t;
-                let point = V::from_vec3(point_vec);
-                if self.contains(&point) || treat_as_ray {
-                    result.push(point);
-                }
-            }
-        } else {
-            for sign in [-1.0, 1.0] {
-                let t = (-b + sign * disc_sqrt) / (2.0 * a);
-                if !treat_as_ray && (t < -epsilon() || t > 1.0 + epsilon()) {
-                    continue;
-                }
-                if treat_as_ray && t < -epsilon() {
-                    continue;
-                }
-                let point_vec = line_start + line_dir * t;
-                let point = V::from_vec3(point_vec);
-                if self.contains(&point) || treat_as_ray {
-                    result.push(point);
-                }
-            }
-        }
-        result
+        let disc_sqrt = discriminant.max(zero).sqrt();
+        // Tangent: single intersection
+        let signs: &[V::Scalar] = if disc_sqrt <= eps { &[zero] } else { &[-one, one] };
+        signs
+            .iter()
+            .map(|&sign| (-b + sign * disc_sqrt) / (two * a))
+            .filter(|&t| self.contains(&V::from_vec3(line_start + line_dir * t), None))
+            .collect()
     }
 
     pub fn intersection_with_arc(&self, other: &Self) -> Vec<V> {
+        let (zero, one, eps, two) = (V::Scalar::zero(), V::Scalar::one(), V::Scalar::epsilon(), V::Scalar::from_f64_lossy(2.0));
         // Solve circle-circle intersection in plane assuming shared plane.
         let c1 = self.center.to_vec3();
         let c2 = other.center.to_vec3();
         let diff = c2 - c1;
         let d = diff.norm();
-        if d <= epsilon() {
+        if d <= eps {
             return Vec::new();
         }
 
         let r1 = self.radius;
         let r2 = other.radius;
-        if d > r1 + r2 + epsilon() || d < (r1 - r2).abs() - epsilon() {
+        if d > r1 + r2 + eps || d < (r1 - r2).abs() - eps {
             return Vec::new();
         }
 
-        let a = (r1 * r1 - r2 * r2 + d * d) / (2.0 * d);
+        let a = (r1 * r1 - r2 * r2 + d * d) / (two * d);
         let h_sq = r1 * r1 - a * a;
-        if h_sq < -epsilon() {
+        if h_sq < -eps {
             return Vec::new();
         }
-        let h = h_sq.max(0.0).sqrt();
+        let h = h_sq.max(zero).sqrt();
         let base = c1 + diff * (a / d);
 
         let diff_norm = diff / d;
         let perp = self.normal.cross(&diff_norm);
 
         let mut points = Vec::new();
-        if h <= epsilon() {
+        if h <= eps {
             let point_vec = base;
             let point = V::from_vec3(point_vec);
-            if self.contains(&point) && other.contains(&point) {
+            if self.contains(&point, None) && other.contains(&point, None) {
                 points.push(point);
             }
         } else {
-            for sign in [-1.0, 1.0] {
+            for sign in [-one, one] {
                 let point_vec = base + perp * (sign * h);
                 let point = V::from_vec3(point_vec);
-                if self.contains(&point) && other.contains(&point) {
+                if self.contains(&point, None) && other.contains(&point, None) {
                     points.push(point);
                 }
             }
@@ -398,12 +545,57 @@ where
         points
     }
 
+    /// Axis-aligned bounding box of the swept curve: the end points plus every
+    /// extreme in a coordinate direction that lies within the sweep.
+    pub fn bounding_box(&self) -> BoundingBox3d {
+        let mut bounds = BoundingBox3d::new(widened(&self.start), widened(&self.end));
+        let center = self.center.to_vec3();
+        let start_vec = self.start.to_vec3() - center;
+        if start_vec.norm() <= V::Scalar::epsilon() {
+            return bounds;
+        }
+        let u = start_vec.normalize();
+        let w = self.normal.cross(&u);
+        for i in 0..3 {
+            // Coordinate i varies as u_i cos(a) + w_i sin(a); extremes where its derivative vanishes.
+            if u[i].hypot(w[i]) <= V::Scalar::epsilon() {
+                continue;
+            }
+            let extreme = w[i].atan2(u[i]);
+            for angle in [extreme, extreme + V::Scalar::pi()] {
+                let angle = self.unwrapped(angle);
+                if self.angle_in_range(angle) {
+                    bounds.expand_with_point(widened(&self.point_at_angle(angle)));
+                }
+            }
+        }
+        bounds
+    }
+
+    /// Fewest equal chords whose sagitta, the gap `r (1 − cos(θ/2n))` between
+    /// each chord and the arc, stays within `max_sagitta`. Panics unless the
+    /// tolerance is positive.
+    pub fn segments_for_sagitta(&self, max_sagitta: V::Scalar) -> usize {
+        assert!(max_sagitta > V::Scalar::zero(), "sagitta tolerance must be positive");
+        if max_sagitta >= self.radius {
+            return 1;
+        }
+        let step = V::Scalar::from_f64_lossy(2.0) * (V::Scalar::one() - max_sagitta / self.radius).acos();
+        ((self.sweep.abs() / step).ceil().as_f64() as usize).max(1)
+    }
+
+    /// Equal chords within `max_sagitta` of the arc, see
+    /// [`Arc::segments_for_sagitta`].
+    pub fn linearized_by_tolerance(&self, max_sagitta: V::Scalar) -> Vec<Line<V>> {
+        self.linearized(self.segments_for_sagitta(max_sagitta))
+    }
+
     pub fn linearized(&self, segments: usize) -> Vec<Line<V>> {
         let segments = segments.max(1);
         let mut lines = Vec::with_capacity(segments);
         let mut prev = self.start;
         for i in 1..=segments {
-            let t = i as f64 / segments as f64;
+            let t = V::Scalar::from_f64_lossy(i as f64 / segments as f64);
             let next = self.point_at(t);
             lines.push(Line::new(prev, next));
             prev = next;
@@ -411,20 +603,25 @@ where
         lines
     }
 
-    fn tangent_at_angle(&self, angle: f64) -> V {
-        let center_vec = self.center.to_vec3();
-        let start_vec = self.start.to_vec3() - center_vec;
-        let start_dir = if start_vec.norm() <= epsilon() {
-            Vector3::new(1.0, 0.0, 0.0)
+    /// Unit vectors from the center towards the start and a quarter turn on
+    /// about the normal, spanning the plane of the arc.
+    fn start_frame(&self) -> (Vector3<V::Scalar>, Vector3<V::Scalar>) {
+        let start_vec = self.start.to_vec3() - self.center.to_vec3();
+        let start_dir = if start_vec.norm() <= V::Scalar::epsilon() {
+            Vector3::x()
         } else {
             start_vec.normalize()
         };
-        let perp = self.normal.cross(&start_dir);
+        (start_dir, self.normal.cross(&start_dir))
+    }
+
+    fn tangent_at_angle(&self, angle: V::Scalar) -> V {
+        let (start_dir, perp) = self.start_frame();
         let dir = start_dir * (-angle.sin()) + perp * angle.cos();
         V::from_vec3(dir)
     }
 
-    fn segment(&self, start_angle: f64, end_angle: f64) -> Self {
+    fn segment(&self, start_angle: V::Scalar, end_angle: V::Scalar) -> Self {
         let start = self.point_at_angle(start_angle);
         let end = self.point_at_angle(end_angle);
         Self {
@@ -437,31 +634,117 @@ where
         }
     }
 
-    fn angle_in_range(&self, angle: f64) -> bool {
-        if self.sweep >= 0.0 {
-            angle >= -epsilon() && angle <= self.sweep + epsilon()
+    /// `angle` turned by whole turns into `[0, 2π)` for a positive sweep, or
+    /// `(-2π, 0]` for a negative one.
+    fn unwrapped(&self, angle: V::Scalar) -> V::Scalar {
+        let turn = V::Scalar::two_pi();
+        let angle = angle - (angle / turn).floor() * turn;
+        if self.sweep >= V::Scalar::zero() { angle } else { angle - turn }
+    }
+
+    fn angle_in_range(&self, angle: V::Scalar) -> bool {
+        let (zero, eps) = (V::Scalar::zero(), V::Scalar::epsilon());
+        if self.sweep >= zero {
+            angle >= -eps && angle <= self.sweep + eps
         } else {
-            angle <= epsilon() && angle >= self.sweep - epsilon()
+            angle <= eps && angle >= self.sweep - eps
         }
     }
 
-    fn clamp_angle(&self, angle: f64) -> f64 {
-        if self.sweep >= 0.0 {
-            angle.clamp(0.0, self.sweep)
+    fn clamp_angle(&self, angle: V::Scalar) -> V::Scalar {
+        let zero = V::Scalar::zero();
+        if self.sweep >= zero {
+            angle.clamp(zero, self.sweep)
         } else {
-            angle.clamp(self.sweep, 0.0)
+            angle.clamp(self.sweep, zero)
         }
     }
 
-    fn clamped_angle_from_point(&self, point: &V) -> f64 {
+    fn clamped_angle_from_point(&self, point: &V) -> V::Scalar {
         let angle = self.angle_from_point(point);
         self.clamp_angle(angle)
     }
 }
 
+impl<V: ArcVector<Scalar = f64>> Arc<V> {
+    pub fn local_axis(&self) -> LocalAxis { LocalAxis::new(widened(&self.start), self.rotation_matrix()) }
+
+    pub fn to_local(self, point: Vector3d) -> Vector3d { self.local_axis().to_local(point) }
+
+    pub fn to_global(self, local: Vector3d) -> Vector3d { self.local_axis().to_global(local) }
+}
+
+impl<V: ArcVector> crate::Curve<V> for Arc<V> {
+    fn start(&self) -> V { self.start }
+    fn end(&self) -> V { self.end }
+    fn length(&self) -> V::Scalar { Arc::length(self) }
+    fn point_at(&self, t: V::Scalar) -> V { Arc::point_at(self, t) }
+    fn tangent_at(&self, t: V::Scalar) -> V { self.tangent_at_angle(self.angle_at(t)).scale(self.sweep.signum()) }
+    fn closest_point(&self, point: &V) -> V { Arc::closest_point(self, point) }
+    fn bounding_box(&self) -> BoundingBox3d { Arc::bounding_box(self) }
+    fn linearized(&self, segments: usize) -> Vec<Line<V>> { Arc::linearized(self, segments) }
+}
+
+impl<V: ArcVector> crate::DistanceTo<Line<V>, V> for Arc<V> {
+    /// Exact where the segment meets the arc; otherwise the arc is sampled and
+    /// the best sample refined by golden-section search.
+    fn distance_to(&self, line: &Line<V>) -> (V, V, V::Scalar) {
+        if let Some(&hit) = self.intersection_with_line(line).first() {
+            return (hit, hit, V::Scalar::zero());
+        }
+        const SAMPLES: usize = 32;
+        let (zero, one) = (V::Scalar::zero(), V::Scalar::one());
+        let gap = |t: V::Scalar| {
+            let point = Arc::point_at(self, t);
+            point.sub(&line.closest_point(&point)).norm()
+        };
+        let compare = |a: &V::Scalar, b: &V::Scalar| gap(*a).as_f64().total_cmp(&gap(*b).as_f64());
+        let best = (0..=SAMPLES).map(|i| V::Scalar::from_f64_lossy(i as f64 / SAMPLES as f64)).min_by(compare).unwrap_or(zero);
+        let step = V::Scalar::from_f64_lossy(1.0 / SAMPLES as f64);
+        let (mut low, mut high) = ((best - step).max(zero), (best + step).min(one));
+        let ratio = V::Scalar::from_f64_lossy((5.0_f64.sqrt() - 1.0) / 2.0);
+        for _ in 0..60 {
+            let (a, b) = (high - ratio * (high - low), low + ratio * (high - low));
+            if gap(a) < gap(b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+        let refined = (low + high) / V::Scalar::from_f64_lossy(2.0);
+        let t = if gap(refined) < gap(best) { refined } else { best };
+        let on_arc = Arc::point_at(self, t);
+        let on_line = line.closest_point(&on_arc);
+        (on_arc, on_line, on_arc.sub(&on_line).norm())
+    }
+}
+
+impl crate::Transformable for Arc<Vector3d> {
+    fn transform(&mut self, transform: &crate::Transform3d) {
+        self.center = transform.apply_point(&self.center);
+        self.start = transform.apply_point(&self.start);
+        self.end = transform.apply_point(&self.end);
+        self.radius *= transform.scale();
+        // The normal is an axial vector: it flips under mirroring so the sweep
+        // keeps its sign while the arc turns the other way in space.
+        let sign = if transform.is_mirroring() { -1.0 } else { 1.0 };
+        self.normal = (transform.linear() * self.normal).normalize() * sign;
+    }
+}
+
+/// Round the corner between two lines: both lines trimmed (or extended) to the
+/// tangent points of [`Arc::fillet`], with the arc between them. The result is a
+/// continuous path from the far end of `first` through the arc to the far end
+/// of `second`. Returns `None` where [`Arc::fillet`] does.
+pub fn fillet<V: ArcVector>(first: &Line<V>, second: &Line<V>, radius: V::Scalar) -> Option<(Line<V>, Arc<V>, Line<V>)> {
+    let (far1, arc, far2) = Arc::fillet_with_ends(first, second, radius)?;
+    Some((Line::new(far1, arc.start()), arc, Line::new(arc.end(), far2)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64::consts::PI;
     use utils::{assert_almost_eq, assert_vec3_almost_eq};
 
     #[test]
@@ -476,7 +759,7 @@ mod tests {
     fn arc_point_at_and_contains() {
     let arc = Arc::<Vector2d>::new(Vector2d::new(0.0, 0.0), Vector2d::new(1.0, 0.0), Vector2d::new(0.0, 1.0), false);
         let mid = arc.point_at(0.5);
-        assert!(arc.contains(&mid));
+        assert!(arc.contains(&mid, None));
         assert_almost_eq!(mid.x(), (2.0f64).sqrt() / 2.0);
         assert_almost_eq!(mid.y(), (2.0f64).sqrt() / 2.0);
     }
@@ -0,0 +1,77 @@
+use nalgebra::Vector3;
+use utils::epsilon;
+
+use crate::{Sphere, Vector3d};
+
+/// Axis-aligned box between the corners `min` and `max`, e.g. the extent of a
+/// member, a slab or a whole model. A single point gives a box of zero size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox3d {
+    min: Vector3d,
+    max: Vector3d,
+}
+
+impl BoundingBox3d {
+    /// Box spanned by two opposite corners, given in any order.
+    pub fn new<A: Into<Vector3d>, B: Into<Vector3d>>(a: A, b: B) -> Self {
+        let (a, b) = (a.into(), b.into());
+        Self { min: Vector3d(a.0.inf(&b.0)), max: Vector3d(a.0.sup(&b.0)) }
+    }
+
+    pub fn from_point(point: Vector3d) -> Self { Self { min: point, max: point } }
+
+    /// Smallest box holding all `points`; `None` without points.
+    pub fn from_points<I, P>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Vector3d>,
+    {
+        let mut points = points.into_iter().map(Into::into);
+        let mut bounds = Self::from_point(points.next()?);
+        points.for_each(|point| bounds.expand_with_point(point));
+        Some(bounds)
+    }
+
+    pub fn expand_with_point(&mut self, point: Vector3d) {
+        self.min = Vector3d(self.min.0.inf(&point.0));
+        self.max = Vector3d(self.max.0.sup(&point.0));
+    }
+
+    pub fn min(&self) -> Vector3d { self.min }
+    pub fn max(&self) -> Vector3d { self.max }
+    pub fn center(&self) -> Vector3d { self.min.midpoint(&self.max) }
+
+    /// Size along X, Y and Z.
+    pub fn extent(&self) -> Vector3d { self.max - self.min }
+
+    /// Whether `point` lies inside or within `tolerance` of the box,
+    /// [`epsilon`] by default.
+    pub fn contains(&self, point: &Vector3d, tolerance: Option<f64>) -> bool {
+        let tolerance = tolerance.unwrap_or_else(epsilon);
+        (0..3).all(|k| point[k] >= self.min[k] - tolerance && point[k] <= self.max[k] + tolerance)
+    }
+
+    /// Smallest box holding both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self { min: Vector3d(self.min.0.inf(&other.min.0)), max: Vector3d(self.max.0.sup(&other.max.0)) }
+    }
+
+    /// Common part of both boxes, flat where they only touch; `None` when
+    /// they are apart.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (min, max) = (self.min.0.sup(&other.min.0), self.max.0.inf(&other.max.0));
+        (0..3).all(|k| min[k] <= max[k]).then_some(Self { min: Vector3d(min), max: Vector3d(max) })
+    }
+
+    /// Grown by `margin` on every side, e.g. a clearance around a member. A
+    /// negative margin shrinks the box, down to its center at most.
+    pub fn inflate(&self, margin: f64) -> Self {
+        let center = self.center().0;
+        let half = (self.extent().0 / 2.0 + Vector3::repeat(margin)).sup(&Vector3::zeros());
+        Self { min: Vector3d(center - half), max: Vector3d(center + half) }
+    }
+
+    /// Sphere through the corners, centered on the box.
+    pub fn bounding_sphere(&self) -> Sphere { Sphere::new(self.center(), self.extent().norm() / 2.0) }
+}
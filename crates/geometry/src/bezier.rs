@@ -0,0 +1,224 @@
+use nalgebra::Vector3;
+
+use crate::{BoundingBox3d, Line3d, Vector3d};
+use utils::epsilon;
+
+/// Cubic Bézier curve defined by four control points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bezier3d {
+    points: [Vector3d; 4],
+}
+
+/// Abscissae and weights of the five-point Gauss-Legendre rule on [-1, 1].
+const GAUSS: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+impl Bezier3d {
+    pub fn new<P0, P1, P2, P3>(start: P0, control1: P1, control2: P2, end: P3) -> Self
+    where
+        P0: Into<Vector3d>,
+        P1: Into<Vector3d>,
+        P2: Into<Vector3d>,
+        P3: Into<Vector3d>,
+    {
+        Self { points: [start.into(), control1.into(), control2.into(), end.into()] }
+    }
+
+    pub fn control_points(&self) -> &[Vector3d; 4] { &self.points }
+    pub fn start(&self) -> Vector3d { self.points[0] }
+    pub fn end(&self) -> Vector3d { self.points[3] }
+
+    fn p(&self, i: usize) -> Vector3<f64> { self.points[i].0 }
+
+    /// Point at parameter `t` in [0, 1].
+    pub fn point_at(&self, t: f64) -> Vector3d {
+        let s = 1.0 - t;
+        Vector3d(
+            self.p(0) * (s * s * s) + self.p(1) * (3.0 * s * s * t) + self.p(2) * (3.0 * s * t * t) + self.p(3) * (t * t * t),
+        )
+    }
+
+    /// First derivative with respect to `t`.
+    pub fn derivative_at(&self, t: f64) -> Vector3d {
+        let s = 1.0 - t;
+        Vector3d(
+            (self.p(1) - self.p(0)) * (3.0 * s * s) + (self.p(2) - self.p(1)) * (6.0 * s * t) + (self.p(3) - self.p(2)) * (3.0 * t * t),
+        )
+    }
+
+    fn second_derivative_at(&self, t: f64) -> Vector3<f64> {
+        (self.p(2) - self.p(1) * 2.0 + self.p(0)) * (6.0 * (1.0 - t)) + (self.p(3) - self.p(2) * 2.0 + self.p(1)) * (6.0 * t)
+    }
+
+    /// Unit tangent at `t`. Falls back to the chord direction where the derivative
+    /// vanishes (coincident control points).
+    pub fn tangent_at(&self, t: f64) -> Vector3d {
+        let d = self.derivative_at(t).0;
+        if d.norm() > epsilon() {
+            return Vector3d(d.normalize());
+        }
+        let chord = self.p(3) - self.p(0);
+        Vector3d(if chord.norm() > epsilon() { chord.normalize() } else { Vector3::x() })
+    }
+
+    /// Arc length, integrated with Gauss-Legendre quadrature over 16 spans.
+    pub fn length(&self) -> f64 {
+        self.length_between(0.0, 1.0)
+    }
+
+    /// Arc length between parameters `t0` and `t1`.
+    pub fn length_between(&self, t0: f64, t1: f64) -> f64 {
+        const SPANS: usize = 16;
+        let h = (t1 - t0) / SPANS as f64;
+        (0..SPANS)
+            .map(|i| {
+                let mid = t0 + h * (i as f64 + 0.5);
+                GAUSS.iter().map(|(x, w)| w * self.derivative_at(mid + 0.5 * h * x).norm()).sum::<f64>() * 0.5 * h
+            })
+            .sum::<f64>()
+            .abs()
+    }
+
+    /// Parameter of the point on the curve closest to `point`: coarse sampling
+    /// followed by Newton refinement of `(B(t) - p) · B'(t) = 0`.
+    pub fn closest_parameter(&self, point: &Vector3d) -> f64 {
+        const SAMPLES: usize = 32;
+        let distance = |t: f64| (self.point_at(t).0 - point.0).norm_squared();
+        let mut best = (0..=SAMPLES)
+            .map(|i| i as f64 / SAMPLES as f64)
+            .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .unwrap_or(0.0);
+        for _ in 0..20 {
+            let diff = self.point_at(best).0 - point.0;
+            let d1 = self.derivative_at(best).0;
+            let f = diff.dot(&d1);
+            let df = d1.dot(&d1) + diff.dot(&self.second_derivative_at(best));
+            if df.abs() <= epsilon() * d1.norm_squared() {
+                break;
+            }
+            let next = (best - f / df).clamp(0.0, 1.0);
+            if (next - best).abs() <= epsilon() {
+                break;
+            }
+            if distance(next) > distance(best) {
+                break;
+            }
+            best = next;
+        }
+        best
+    }
+
+    pub fn closest_point(&self, point: &Vector3d) -> Vector3d {
+        self.point_at(self.closest_parameter(point))
+    }
+
+    pub fn distance(&self, point: &Vector3d) -> f64 {
+        (self.closest_point(point).0 - point.0).norm()
+    }
+
+    /// Axis-aligned bounding box: the end points plus the extremes where a
+    /// coordinate of the derivative vanishes inside the curve.
+    pub fn bounding_box(&self) -> BoundingBox3d {
+        let mut bounds = BoundingBox3d::new(Vector3d(self.p(0)), Vector3d(self.p(3)));
+        // B'(t) / 3 = a t² + b t + c per coordinate.
+        let (d0, d1, d2) = (self.p(1) - self.p(0), self.p(2) - self.p(1), self.p(3) - self.p(2));
+        let (a, b, c) = (d0 - d1 * 2.0 + d2, (d1 - d0) * 2.0, d0);
+        let tolerance = epsilon() * d0.norm().max(d1.norm()).max(d2.norm());
+        for i in 0..3 {
+            let roots = if a[i].abs() <= tolerance {
+                if b[i].abs() <= tolerance { vec![] } else { vec![-c[i] / b[i]] }
+            } else {
+                let discriminant = b[i] * b[i] - 4.0 * a[i] * c[i];
+                if discriminant < 0.0 {
+                    vec![]
+                } else {
+                    let root = discriminant.sqrt();
+                    vec![(-b[i] - root) / (2.0 * a[i]), (-b[i] + root) / (2.0 * a[i])]
+                }
+            };
+            for t in roots.into_iter().filter(|t| (0.0..=1.0).contains(t)) {
+                bounds.expand_with_point(self.point_at(t));
+            }
+        }
+        bounds
+    }
+
+    /// Split at `t` into two curves covering the same shape (de Casteljau).
+    pub fn split_at(&self, t: f64) -> (Self, Self) {
+        let lerp = |a: Vector3<f64>, b: Vector3<f64>| a + (b - a) * t;
+        let (p01, p12, p23) = (lerp(self.p(0), self.p(1)), lerp(self.p(1), self.p(2)), lerp(self.p(2), self.p(3)));
+        let (p012, p123) = (lerp(p01, p12), lerp(p12, p23));
+        let mid = lerp(p012, p123);
+        (
+            Self::new(self.points[0], Vector3d(p01), Vector3d(p012), Vector3d(mid)),
+            Self::new(Vector3d(mid), Vector3d(p123), Vector3d(p23), self.points[3]),
+        )
+    }
+
+    /// Straight segments at equal parameter steps.
+    pub fn linearized(&self, segments: usize) -> Vec<Line3d> {
+        let segments = segments.max(1);
+        (0..segments)
+            .map(|i| {
+                let t0 = i as f64 / segments as f64;
+                let t1 = (i + 1) as f64 / segments as f64;
+                Line3d::new(self.point_at(t0), self.point_at(t1))
+            })
+            .collect()
+    }
+
+    /// Straight segments whose midpoints deviate from the curve by at most
+    /// `tolerance` (chord height), refined by recursive bisection.
+    pub fn linearized_with_tolerance(&self, tolerance: f64) -> Vec<Line3d> {
+        let mut lines = Vec::new();
+        self.subdivide(0.0, 1.0, tolerance, 0, &mut lines);
+        lines
+    }
+
+    fn subdivide(&self, t0: f64, t1: f64, tolerance: f64, depth: usize, lines: &mut Vec<Line3d>) {
+        let (a, b) = (self.point_at(t0), self.point_at(t1));
+        let mid = 0.5 * (t0 + t1);
+        // Check both the midpoint and quarter points so inflections are not missed.
+        let deviation = [0.25, 0.5, 0.75]
+            .iter()
+            .map(|f| Line3d::new(a, b).distance(&self.point_at(t0 + f * (t1 - t0))))
+            .fold(0.0, f64::max);
+        if deviation <= tolerance || depth >= 16 {
+            lines.push(Line3d::new(a, b));
+        } else {
+            self.subdivide(t0, mid, tolerance, depth + 1, lines);
+            self.subdivide(mid, t1, tolerance, depth + 1, lines);
+        }
+    }
+}
+
+impl crate::Curve<Vector3d> for Bezier3d {
+    fn start(&self) -> Vector3d { self.points[0] }
+    fn end(&self) -> Vector3d { self.points[3] }
+    fn length(&self) -> f64 { Bezier3d::length(self) }
+    fn point_at(&self, t: f64) -> Vector3d { Bezier3d::point_at(self, t) }
+    fn tangent_at(&self, t: f64) -> Vector3d { Bezier3d::tangent_at(self, t) }
+    fn closest_point(&self, point: &Vector3d) -> Vector3d { Bezier3d::closest_point(self, point) }
+    fn bounding_box(&self) -> BoundingBox3d { Bezier3d::bounding_box(self) }
+    fn linearized(&self, segments: usize) -> Vec<Line3d> { Bezier3d::linearized(self, segments) }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    #[test]
+    fn straight_curve_has_chord_length() {
+        let curve = Bezier3d::new((0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (3.0, 0.0, 0.0));
+        assert_almost_eq!(curve.length(), 3.0);
+        assert_almost_eq!(curve.point_at(0.5).x(), 1.5);
+        assert_eq!(curve.linearized_with_tolerance(1e-6).len(), 1);
+    }
+}
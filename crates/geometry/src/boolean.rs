@@ -0,0 +1,437 @@
+//! Boolean operations on planar regions expressed in 2D local coordinates.
+//!
+//! A region is an outer ring followed by any number of hole rings. The edges of
+//! both regions are split at every mutual intersection (including collinear
+//! overlaps), each resulting piece is classified against the other region, and the
+//! pieces selected by the operation are chained back into closed rings. Working
+//! on split edges rather than tracing crossings keeps shared edges and touching
+//! vertices (common when assembling sections from rectangles) well defined.
+
+use crate::Vector2d;
+
+pub(crate) type Ring = Vec<Vector2d>;
+
+type Pieces = Vec<(Vector2d, Vector2d)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    SharedSame,
+    SharedOpposite,
+}
+
+/// Signed area of a ring (positive for counter-clockwise orientation).
+pub(crate) fn signed_area(ring: &[Vector2d]) -> f64 {
+    let n = ring.len();
+    (0..n).map(|i| ring[i].cross(&ring[(i + 1) % n])).sum::<f64>() * 0.5
+}
+
+/// Even-odd point-in-ring test (boundary points are not handled specially).
+pub(crate) fn ring_contains(ring: &[Vector2d], p: &Vector2d) -> bool {
+    edges_contain(&ring_edges(ring), p)
+}
+
+fn ring_edges(ring: &[Vector2d]) -> Pieces {
+    let n = ring.len();
+    (0..n).map(|i| (ring[i], ring[(i + 1) % n])).collect()
+}
+
+/// Even-odd test against a set of closed edges; holes toggle back to outside.
+fn edges_contain(edges: &[(Vector2d, Vector2d)], p: &Vector2d) -> bool {
+    let mut inside = false;
+    for (a, b) in edges {
+        if (a.y() > p.y()) != (b.y() > p.y()) {
+            let x = a.x() + (p.y() - a.y()) * (b.x() - a.x()) / (b.y() - a.y());
+            if x > p.x() {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Distance from `p` to the segment `a`–`b`.
+pub(crate) fn segment_distance(p: &Vector2d, a: &Vector2d, b: &Vector2d) -> f64 {
+    let ab = *b - *a;
+    let len_sq = ab.dot(&ab);
+    if len_sq == 0.0 {
+        return (*p - *a).norm();
+    }
+    let t = ((*p - *a).dot(&ab) / len_sq).clamp(0.0, 1.0);
+    (*p - (*a + ab * t)).norm()
+}
+
+/// Apply `op` to regions `a` and `b` (outer ring first, then holes). Returned
+/// rings are counter-clockwise for outer boundaries and clockwise for holes.
+pub(crate) fn boolean(a: &[Ring], b: &[Ring], op: BooleanOp, tol: f64) -> Vec<Ring> {
+    let a = normalized_region(a, tol);
+    let b = normalized_region(b, tol);
+    if a.is_empty() || b.is_empty() {
+        return match op {
+            BooleanOp::Union => a.into_iter().chain(b).collect(),
+            BooleanOp::Intersection => Vec::new(),
+            BooleanOp::Difference => a,
+        };
+    }
+
+    let edges_a: Pieces = a.iter().flat_map(|ring| ring_edges(ring)).collect();
+    let edges_b: Pieces = b.iter().flat_map(|ring| ring_edges(ring)).collect();
+    let (pieces_a, pieces_b) = split_edges(&edges_a, &edges_b, tol);
+
+    let mut selected: Pieces = Vec::new();
+    for (p, q) in pieces_a {
+        let keep = matches!(
+            (op, classify(&p, &q, &edges_b, tol)),
+            (BooleanOp::Union, Location::Outside | Location::SharedSame)
+                | (BooleanOp::Intersection, Location::Inside | Location::SharedSame)
+                | (BooleanOp::Difference, Location::Outside | Location::SharedOpposite)
+        );
+        if keep {
+            selected.push((p, q));
+        }
+    }
+    for (p, q) in pieces_b {
+        match (op, classify(&p, &q, &edges_a, tol)) {
+            (BooleanOp::Union, Location::Outside) => selected.push((p, q)),
+            (BooleanOp::Intersection, Location::Inside) => selected.push((p, q)),
+            (BooleanOp::Difference, Location::Inside) => selected.push((q, p)),
+            _ => {}
+        }
+    }
+
+    chain(selected, tol)
+        .into_iter()
+        .map(|ring| simplified(ring, tol))
+        .filter(|ring| ring.len() >= 3 && signed_area(ring).abs() > tol * tol)
+        .collect()
+}
+
+/// Clean every ring of a region and orient the outer ring counter-clockwise and
+/// the holes clockwise. An empty region is returned if the outer ring degenerates.
+pub(crate) fn normalized_region(region: &[Ring], tol: f64) -> Vec<Ring> {
+    let mut rings: Vec<Ring> = Vec::with_capacity(region.len());
+    for (index, ring) in region.iter().enumerate() {
+        let mut ring = normalized(ring, tol);
+        if ring.len() < 3 {
+            if index == 0 {
+                return Vec::new();
+            }
+            continue;
+        }
+        if index > 0 {
+            ring.reverse();
+        }
+        rings.push(ring);
+    }
+    rings
+}
+
+/// Remove repeated points and orient the ring counter-clockwise.
+fn normalized(ring: &[Vector2d], tol: f64) -> Ring {
+    let mut out: Ring = Vec::with_capacity(ring.len());
+    for p in ring {
+        if out.last().is_none_or(|last| (*p - *last).norm() > tol) {
+            out.push(*p);
+        }
+    }
+    while out.len() > 1 && (out[0] - out[out.len() - 1]).norm() <= tol {
+        out.pop();
+    }
+    if signed_area(&out) < 0.0 {
+        out.reverse();
+    }
+    out
+}
+
+/// Split every edge of both regions at their mutual intersections. Shared points
+/// are computed once and inserted into both edge sets so pieces meet exactly.
+fn split_edges(a: &[(Vector2d, Vector2d)], b: &[(Vector2d, Vector2d)], tol: f64) -> (Pieces, Pieces) {
+    let mut splits_a: Vec<Vec<(f64, Vector2d)>> = vec![Vec::new(); a.len()];
+    let mut splits_b: Vec<Vec<(f64, Vector2d)>> = vec![Vec::new(); b.len()];
+
+    for (i, &(a0, a1)) in a.iter().enumerate() {
+        let da = a1 - a0;
+        let len_a = da.norm();
+        for (j, &(b0, b1)) in b.iter().enumerate() {
+            let db = b1 - b0;
+            let len_b = db.norm();
+            let denom = da.cross(&db);
+
+            if denom.abs() <= tol * len_a.max(len_b).max(1.0) {
+                // Parallel: only collinear overlaps produce split points.
+                if segment_distance(&b0, &a0, &a1).min(segment_distance(&b1, &a0, &a1)) > tol
+                    && segment_distance(&a0, &b0, &b1).min(segment_distance(&a1, &b0, &b1)) > tol
+                {
+                    continue;
+                }
+                for p in [b0, b1] {
+                    if segment_distance(&p, &a0, &a1) <= tol {
+                        let t = (p - a0).dot(&da) / (len_a * len_a);
+                        if t * len_a > tol && (1.0 - t) * len_a > tol {
+                            splits_a[i].push((t, p));
+                        }
+                    }
+                }
+                for p in [a0, a1] {
+                    if segment_distance(&p, &b0, &b1) <= tol {
+                        let u = (p - b0).dot(&db) / (len_b * len_b);
+                        if u * len_b > tol && (1.0 - u) * len_b > tol {
+                            splits_b[j].push((u, p));
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let r = b0 - a0;
+            let t = r.cross(&db) / denom;
+            let u = r.cross(&da) / denom;
+            let t_tol = tol / len_a;
+            let u_tol = tol / len_b;
+            if t < -t_tol || t > 1.0 + t_tol || u < -u_tol || u > 1.0 + u_tol {
+                continue;
+            }
+
+            // Snap to existing vertices so both rings share identical points.
+            let t_at_end = t.abs() <= t_tol || (1.0 - t).abs() <= t_tol;
+            let u_at_end = u.abs() <= u_tol || (1.0 - u).abs() <= u_tol;
+            let point = if t.abs() <= t_tol {
+                a0
+            } else if (1.0 - t).abs() <= t_tol {
+                a1
+            } else if u.abs() <= u_tol {
+                b0
+            } else if (1.0 - u).abs() <= u_tol {
+                b1
+            } else {
+                a0 + da * t
+            };
+            if !t_at_end {
+                splits_a[i].push((t, point));
+            }
+            if !u_at_end {
+                splits_b[j].push((u, point));
+            }
+        }
+    }
+
+    (pieces(a, splits_a), pieces(b, splits_b))
+}
+
+fn pieces(edges: &[(Vector2d, Vector2d)], mut splits: Vec<Vec<(f64, Vector2d)>>) -> Pieces {
+    let mut out = Vec::new();
+    for (&(start, end), edge_splits) in edges.iter().zip(splits.iter_mut()) {
+        edge_splits.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let mut prev = start;
+        for (_, p) in edge_splits.iter() {
+            if *p != prev {
+                out.push((prev, *p));
+                prev = *p;
+            }
+        }
+        if end != prev {
+            out.push((prev, end));
+        }
+    }
+    out
+}
+
+fn classify(p: &Vector2d, q: &Vector2d, other: &[(Vector2d, Vector2d)], tol: f64) -> Location {
+    let mid = p.midpoint(q);
+    for (a, b) in other {
+        if segment_distance(&mid, a, b) <= tol
+            && segment_distance(p, a, b) <= tol
+            && segment_distance(q, a, b) <= tol
+        {
+            return if (*q - *p).dot(&(*b - *a)) >= 0.0 {
+                Location::SharedSame
+            } else {
+                Location::SharedOpposite
+            };
+        }
+    }
+    if edges_contain(other, &mid) {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Chain directed pieces into closed rings. At vertices with several candidate
+/// continuations the sharpest left turn is taken, which keeps rings that only
+/// touch at a point separate.
+fn chain(edges: Pieces, tol: f64) -> Vec<Ring> {
+    let mut vertices: Vec<Vector2d> = Vec::new();
+    let mut index_of = |p: &Vector2d| -> usize {
+        if let Some(i) = vertices.iter().position(|v| (*v - *p).norm() <= tol) {
+            i
+        } else {
+            vertices.push(*p);
+            vertices.len() - 1
+        }
+    };
+    let indexed: Vec<(usize, usize)> = edges
+        .iter()
+        .map(|(p, q)| (index_of(p), index_of(q)))
+        .filter(|(i, j)| i != j)
+        .collect();
+
+    let mut used = vec![false; indexed.len()];
+    let mut rings = Vec::new();
+    for first in 0..indexed.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let start = indexed[first].0;
+        let mut ring = vec![vertices[start]];
+        let mut current = first;
+        loop {
+            let (from, to) = indexed[current];
+            if to == start {
+                rings.push(ring);
+                break;
+            }
+            ring.push(vertices[to]);
+            let incoming = vertices[to] - vertices[from];
+            let next = (0..indexed.len())
+                .filter(|&k| !used[k] && indexed[k].0 == to)
+                .max_by(|&k, &l| {
+                    let turn = |e: usize| {
+                        let out = vertices[indexed[e].1] - vertices[to];
+                        incoming.cross(&out).atan2(incoming.dot(&out))
+                    };
+                    turn(k).total_cmp(&turn(l))
+                });
+            match next {
+                Some(k) => {
+                    used[k] = true;
+                    current = k;
+                }
+                None => break,
+            }
+        }
+    }
+    rings
+}
+
+/// Drop vertices lying on the straight line between their neighbours.
+fn simplified(mut ring: Ring, tol: f64) -> Ring {
+    let mut changed = true;
+    while changed && ring.len() >= 3 {
+        changed = false;
+        let n = ring.len();
+        for i in 0..n {
+            let prev = ring[(i + n - 1) % n];
+            let next = ring[(i + 1) % n];
+            if segment_distance(&ring[i], &prev, &next) <= tol {
+                ring.remove(i);
+                changed = true;
+                break;
+            }
+        }
+    }
+    ring
+}
+
+/// Group rings into outer boundaries (counter-clockwise) and the holes they enclose
+/// (clockwise). Each hole is assigned to the smallest outer ring containing it.
+pub(crate) fn group_rings(rings: Vec<Ring>) -> Vec<(Ring, Vec<Ring>)> {
+    let (outers, holes): (Vec<Ring>, Vec<Ring>) = rings.into_iter().partition(|r| signed_area(r) > 0.0);
+    let mut groups: Vec<(Ring, Vec<Ring>)> = outers.into_iter().map(|r| (r, Vec::new())).collect();
+    for hole in holes {
+        let probe = interior_probe(&hole);
+        let owner = groups
+            .iter()
+            .enumerate()
+            .filter(|(_, (outer, _))| ring_contains(outer, &probe))
+            .min_by(|(_, (x, _)), (_, (y, _))| signed_area(x).total_cmp(&signed_area(y)))
+            .map(|(i, _)| i);
+        if let Some(i) = owner {
+            groups[i].1.push(hole);
+        }
+    }
+    groups
+}
+
+/// A point just outside a clockwise hole, next to its first edge.
+fn interior_probe(hole: &[Vector2d]) -> Vector2d {
+    let a = hole[0];
+    let b = hole[1 % hole.len()];
+    let edge = b - a;
+    a.midpoint(&b) + edge.perp() * 1e-6
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_almost_eq;
+
+    use super::*;
+
+    fn square(x0: f64, y0: f64, size: f64) -> Ring {
+        vec![
+            Vector2d::new(x0, y0),
+            Vector2d::new(x0 + size, y0),
+            Vector2d::new(x0 + size, y0 + size),
+            Vector2d::new(x0, y0 + size),
+        ]
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let a = vec![square(0.0, 0.0, 2.0)];
+        let b = vec![square(1.0, 1.0, 2.0)];
+        let union = boolean(&a, &b, BooleanOp::Union, 1e-9);
+        assert_eq!(union.len(), 1);
+        assert_almost_eq!(signed_area(&union[0]), 7.0);
+        let inter = boolean(&a, &b, BooleanOp::Intersection, 1e-9);
+        assert_eq!(inter.len(), 1);
+        assert_almost_eq!(signed_area(&inter[0]), 1.0);
+        let diff = boolean(&a, &b, BooleanOp::Difference, 1e-9);
+        assert_eq!(diff.len(), 1);
+        assert_almost_eq!(signed_area(&diff[0]), 3.0);
+    }
+
+    #[test]
+    fn shared_edge_union_merges_into_single_ring() {
+        let a = vec![square(0.0, 0.0, 1.0)];
+        let b = vec![square(1.0, 0.0, 1.0)];
+        let union = boolean(&a, &b, BooleanOp::Union, 1e-9);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].len(), 4);
+        assert_almost_eq!(signed_area(&union[0]), 2.0);
+        assert!(boolean(&a, &b, BooleanOp::Intersection, 1e-9).is_empty());
+    }
+
+    #[test]
+    fn enclosed_difference_produces_hole() {
+        let a = vec![square(0.0, 0.0, 4.0)];
+        let b = vec![square(1.0, 1.0, 1.0)];
+        let diff = boolean(&a, &b, BooleanOp::Difference, 1e-9);
+        assert_eq!(diff.len(), 2);
+        let total: f64 = diff.iter().map(|r| signed_area(r)).sum();
+        assert_almost_eq!(total, 15.0);
+        let groups = group_rings(diff);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].1.len(), 1);
+    }
+
+    #[test]
+    fn union_with_holed_region_keeps_uncovered_hole_part() {
+        let mut hole = square(1.0, 1.0, 2.0);
+        hole.reverse();
+        let frame = vec![square(0.0, 0.0, 4.0), hole];
+        let patch = vec![square(2.0, 0.0, 2.0)];
+        let union = boolean(&frame, &patch, BooleanOp::Union, 1e-9);
+        let total: f64 = union.iter().map(|r| signed_area(r)).sum();
+        assert_almost_eq!(total, 16.0 - 4.0 + 1.0);
+    }
+}
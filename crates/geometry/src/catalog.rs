@@ -0,0 +1,179 @@
+//! Dimensions of standard hot-rolled profiles behind the `from_designation`
+//! constructors: European IPE, HEA and HEB beams, UPN channels and equal
+//! angles after EN 10365 / EN 10056, and a selection of American W shapes.
+
+use crate::shape::{ShapeC, ShapeI, ShapeL};
+
+const MM: f64 = 1e-3;
+const INCH: f64 = 0.0254;
+
+/// I sections: designation, then `[h, b, tw, tf, r]` in mm (in inches for W shapes).
+const I_SECTIONS: &[(&str, [f64; 5])] = &[
+    ("IPE 80", [80.0, 46.0, 3.8, 5.2, 5.0]),
+    ("IPE 100", [100.0, 55.0, 4.1, 5.7, 7.0]),
+    ("IPE 120", [120.0, 64.0, 4.4, 6.3, 7.0]),
+    ("IPE 140", [140.0, 73.0, 4.7, 6.9, 7.0]),
+    ("IPE 160", [160.0, 82.0, 5.0, 7.4, 9.0]),
+    ("IPE 180", [180.0, 91.0, 5.3, 8.0, 9.0]),
+    ("IPE 200", [200.0, 100.0, 5.6, 8.5, 12.0]),
+    ("IPE 220", [220.0, 110.0, 5.9, 9.2, 12.0]),
+    ("IPE 240", [240.0, 120.0, 6.2, 9.8, 15.0]),
+    ("IPE 270", [270.0, 135.0, 6.6, 10.2, 15.0]),
+    ("IPE 300", [300.0, 150.0, 7.1, 10.7, 15.0]),
+    ("IPE 330", [330.0, 160.0, 7.5, 11.5, 18.0]),
+    ("IPE 360", [360.0, 170.0, 8.0, 12.7, 18.0]),
+    ("IPE 400", [400.0, 180.0, 8.6, 13.5, 21.0]),
+    ("IPE 450", [450.0, 190.0, 9.4, 14.6, 21.0]),
+    ("IPE 500", [500.0, 200.0, 10.2, 16.0, 21.0]),
+    ("IPE 550", [550.0, 210.0, 11.1, 17.2, 24.0]),
+    ("IPE 600", [600.0, 220.0, 12.0, 19.0, 24.0]),
+    ("HEA 100", [96.0, 100.0, 5.0, 8.0, 12.0]),
+    ("HEA 120", [114.0, 120.0, 5.0, 8.0, 12.0]),
+    ("HEA 140", [133.0, 140.0, 5.5, 8.5, 12.0]),
+    ("HEA 160", [152.0, 160.0, 6.0, 9.0, 15.0]),
+    ("HEA 180", [171.0, 180.0, 6.0, 9.5, 15.0]),
+    ("HEA 200", [190.0, 200.0, 6.5, 10.0, 18.0]),
+    ("HEA 220", [210.0, 220.0, 7.0, 11.0, 18.0]),
+    ("HEA 240", [230.0, 240.0, 7.5, 12.0, 21.0]),
+    ("HEA 260", [250.0, 260.0, 7.5, 12.5, 24.0]),
+    ("HEA 280", [270.0, 280.0, 8.0, 13.0, 24.0]),
+    ("HEA 300", [290.0, 300.0, 8.5, 14.0, 27.0]),
+    ("HEA 320", [310.0, 300.0, 9.0, 15.5, 27.0]),
+    ("HEA 340", [330.0, 300.0, 9.5, 16.5, 27.0]),
+    ("HEA 360", [350.0, 300.0, 10.0, 17.5, 27.0]),
+    ("HEA 400", [390.0, 300.0, 11.0, 19.0, 27.0]),
+    ("HEA 450", [440.0, 300.0, 11.5, 21.0, 27.0]),
+    ("HEA 500", [490.0, 300.0, 12.0, 23.0, 27.0]),
+    ("HEA 550", [540.0, 300.0, 12.5, 24.0, 27.0]),
+    ("HEA 600", [590.0, 300.0, 13.0, 25.0, 27.0]),
+    ("HEB 100", [100.0, 100.0, 6.0, 10.0, 12.0]),
+    ("HEB 120", [120.0, 120.0, 6.5, 11.0, 12.0]),
+    ("HEB 140", [140.0, 140.0, 7.0, 12.0, 12.0]),
+    ("HEB 160", [160.0, 160.0, 8.0, 13.0, 15.0]),
+    ("HEB 180", [180.0, 180.0, 8.5, 14.0, 15.0]),
+    ("HEB 200", [200.0, 200.0, 9.0, 15.0, 18.0]),
+    ("HEB 220", [220.0, 220.0, 9.5, 16.0, 18.0]),
+    ("HEB 240", [240.0, 240.0, 10.0, 17.0, 21.0]),
+    ("HEB 260", [260.0, 260.0, 10.0, 17.5, 24.0]),
+    ("HEB 280", [280.0, 280.0, 10.5, 18.0, 24.0]),
+    ("HEB 300", [300.0, 300.0, 11.0, 19.0, 27.0]),
+    ("HEB 320", [320.0, 300.0, 11.5, 20.5, 27.0]),
+    ("HEB 340", [340.0, 300.0, 12.0, 21.5, 27.0]),
+    ("HEB 360", [360.0, 300.0, 12.5, 22.5, 27.0]),
+    ("HEB 400", [400.0, 300.0, 13.5, 24.0, 27.0]),
+    ("HEB 450", [450.0, 300.0, 14.0, 26.0, 27.0]),
+    ("HEB 500", [500.0, 300.0, 14.5, 28.0, 27.0]),
+    ("HEB 550", [550.0, 300.0, 15.0, 29.0, 27.0]),
+    ("HEB 600", [600.0, 300.0, 15.5, 30.0, 27.0]),
+    ("W8X31", [8.0, 7.995, 0.285, 0.435, 0.394]),
+    ("W10X49", [9.98, 10.0, 0.34, 0.56, 0.5]),
+    ("W12X26", [12.2, 6.49, 0.23, 0.38, 0.3]),
+    ("W12X50", [12.2, 8.08, 0.37, 0.64, 0.5]),
+    ("W14X22", [13.7, 5.0, 0.23, 0.335, 0.4]),
+    ("W14X90", [14.0, 14.5, 0.44, 0.71, 0.6]),
+    ("W16X26", [15.7, 5.5, 0.25, 0.345, 0.402]),
+    ("W18X35", [17.7, 6.0, 0.3, 0.425, 0.402]),
+    ("W21X44", [20.7, 6.5, 0.35, 0.45, 0.5]),
+    ("W24X55", [23.6, 7.01, 0.395, 0.505, 0.505]),
+];
+
+/// UPN channels: designation, then `[h, b, tw, tf, r1, r2]` in mm; flanges
+/// slope 8 % up to UPN 300 and 5 % above.
+const CHANNELS: &[(&str, [f64; 6])] = &[
+    ("UPN 80", [80.0, 45.0, 6.0, 8.0, 8.0, 4.0]),
+    ("UPN 100", [100.0, 50.0, 6.0, 8.5, 8.5, 4.5]),
+    ("UPN 120", [120.0, 55.0, 7.0, 9.0, 9.0, 4.5]),
+    ("UPN 140", [140.0, 60.0, 7.0, 10.0, 10.0, 5.0]),
+    ("UPN 160", [160.0, 65.0, 7.5, 10.5, 10.5, 5.5]),
+    ("UPN 180", [180.0, 70.0, 8.0, 11.0, 11.0, 5.5]),
+    ("UPN 200", [200.0, 75.0, 8.5, 11.5, 11.5, 6.0]),
+    ("UPN 220", [220.0, 80.0, 9.0, 12.5, 12.5, 6.5]),
+    ("UPN 240", [240.0, 85.0, 9.5, 13.0, 13.0, 6.5]),
+    ("UPN 260", [260.0, 90.0, 10.0, 14.0, 14.0, 7.0]),
+    ("UPN 280", [280.0, 95.0, 10.0, 15.0, 15.0, 7.5]),
+    ("UPN 300", [300.0, 100.0, 10.0, 16.0, 16.0, 8.0]),
+    ("UPN 320", [320.0, 100.0, 14.0, 17.5, 17.5, 8.75]),
+    ("UPN 350", [350.0, 100.0, 14.0, 16.0, 16.0, 8.0]),
+    ("UPN 380", [380.0, 102.0, 13.5, 16.0, 16.0, 8.0]),
+    ("UPN 400", [400.0, 110.0, 14.0, 18.0, 18.0, 9.0]),
+];
+
+/// Equal angles: designation, then `[b, t, r1, r2]` in mm.
+const ANGLES: &[(&str, [f64; 4])] = &[
+    ("L 20x3", [20.0, 3.0, 3.5, 2.0]),
+    ("L 25x3", [25.0, 3.0, 3.5, 2.0]),
+    ("L 30x3", [30.0, 3.0, 5.0, 2.5]),
+    ("L 40x4", [40.0, 4.0, 6.0, 3.0]),
+    ("L 45x4.5", [45.0, 4.5, 7.0, 3.5]),
+    ("L 50x5", [50.0, 5.0, 7.0, 3.5]),
+    ("L 60x6", [60.0, 6.0, 8.0, 4.0]),
+    ("L 70x7", [70.0, 7.0, 9.0, 4.5]),
+    ("L 80x8", [80.0, 8.0, 10.0, 5.0]),
+    ("L 90x9", [90.0, 9.0, 11.0, 5.5]),
+    ("L 100x8", [100.0, 8.0, 12.0, 6.0]),
+    ("L 100x10", [100.0, 10.0, 12.0, 6.0]),
+    ("L 120x12", [120.0, 12.0, 13.0, 6.5]),
+    ("L 150x15", [150.0, 15.0, 16.0, 8.0]),
+    ("L 200x20", [200.0, 20.0, 18.0, 9.0]),
+];
+
+/// Every designation in the catalog, in the spelling of the tables.
+pub fn designations() -> impl Iterator<Item = &'static str> {
+    let sections = I_SECTIONS.iter().map(|(name, _)| *name);
+    sections.chain(CHANNELS.iter().map(|(name, _)| *name)).chain(ANGLES.iter().map(|(name, _)| *name))
+}
+
+/// Upper-case designation without blanks, with `×` for `x` and the series
+/// letter of wide-flange beams in front: "HE 300 A" becomes "HEA300".
+fn normalize(designation: &str) -> String {
+    let mut name: String = designation.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase().replace('×', "X");
+    if let Some(rest) = name.strip_prefix("HE")
+        && let Some(series @ ('A' | 'B' | 'M')) = rest.chars().last()
+        && rest.len() > 1
+    {
+        name = format!("HE{series}{}", &rest[..rest.len() - 1]);
+    }
+    name
+}
+
+fn find<T: Copy>(table: &[(&str, T)], designation: &str) -> Option<T> {
+    let name = normalize(designation);
+    table.iter().find(|(entry, _)| normalize(entry) == name).map(|(_, dimensions)| *dimensions)
+}
+
+impl ShapeI {
+    /// Standard I section by designation, e.g. "IPE 300", "HE 200 B" or
+    /// "W12x26", with its root fillets; `None` for profiles not in the catalog.
+    pub fn from_designation(designation: &str) -> Option<Self> {
+        let [h, b, tw, tf, r] = find(I_SECTIONS, designation)?;
+        let unit = if normalize(designation).starts_with('W') { INCH } else { MM };
+        let [h, b, tw, tf, r] = [h, b, tw, tf, r].map(|value| value * unit);
+        Some(Self::new(b, b, h, tf, tf, tw, r, 0.0, 0.0, 0.0, 0.0))
+    }
+}
+
+impl ShapeC {
+    /// Standard UPN channel by designation, e.g. "UPN 200", with its fillets,
+    /// toe radii and flange slope; `None` for profiles not in the catalog.
+    pub fn from_designation(designation: &str) -> Option<Self> {
+        let [h, b, tw, tf, r1, r2] = find(CHANNELS, designation)?.map(|value| value * MM);
+        let slope: f64 = if h <= 0.3 { 0.08 } else { 0.05 };
+        let taper = slope.atan();
+        Some(Self::new(b, b, h, tf, tf, tw, r1, r2, r2, 0.0, 0.0, taper, taper))
+    }
+}
+
+impl ShapeL {
+    /// Standard equal angle by designation, e.g. "L 100x10" or "L100x100x10",
+    /// with its root fillet and toe radii; `None` for profiles not in the catalog.
+    pub fn from_designation(designation: &str) -> Option<Self> {
+        let name = normalize(designation);
+        // Equal legs may be given twice.
+        let name = match name.strip_prefix('L').map(|rest| rest.split('X').collect::<Vec<_>>()) {
+            Some(parts) if parts.len() == 3 && parts[0] == parts[1] => format!("L{}X{}", parts[0], parts[2]),
+            _ => name,
+        };
+        let [b, t, r1, r2] = find(ANGLES, &name)?.map(|value| value * MM);
+        Some(Self::new(b, b, t, t, r1, r2, 0.0, 0.0))
+    }
+}
@@ -0,0 +1,362 @@
+use std::f64::consts::FRAC_PI_3;
+
+use nalgebra::{Matrix3, Vector2};
+
+use crate::polygon::Polygon as RawPolygon;
+use crate::shape::{Disk, Rectangle, Shape, ShapeC, ShapeI, ShapeL, ShapeT};
+use crate::boolean::signed_area;
+use crate::{ShapeDescriptor, Vector2d, Vector3d};
+
+/// Parametric profile recognised from a polygon outline, see
+/// [`<dyn Shape>::classify`](Shape#method.classify).
+#[derive(Debug, Clone)]
+pub enum ClassifiedShape {
+    Rectangle(Rectangle),
+    /// Rectangular hollow section; the hole dimensions are set on the rectangle.
+    RectangularTube(Rectangle),
+    /// Solid round, approximated by a regular polygon in the outline.
+    Circle(Disk),
+    /// Circular hollow section.
+    Tube(Disk),
+    I(ShapeI),
+    C(ShapeC),
+    L(ShapeL),
+    T(ShapeT),
+}
+
+impl dyn Shape {
+    /// Detect which standard profile `polygon` is, within the absolute length
+    /// `tolerance`, e.g. for a section outline imported from DXF.
+    ///
+    /// Polygonal profiles are matched in any in-plane rotation and mirror image,
+    /// preferring the orientation closest to the polygon's local axes. Runs of
+    /// at least two equal chords are read as the fillets and toe radii of the
+    /// profile, and sloped inner flange faces as its taper; the fitted profile is
+    /// checked by rebuilding it and comparing its corners and radii. Circles are
+    /// recognised from vertices at a common distance from the centroid. Returns
+    /// `None` for any other outline.
+    pub fn classify(polygon: &RawPolygon<Vector3d>, tolerance: f64) -> Option<ClassifiedShape> {
+        let local = |ring: &[Vector3d]| -> Vec<Vector2d> {
+            ring.iter().map(|v| polygon.to_local(*v)).map(|p| Vector2d::new(p.x(), p.y())).collect()
+        };
+        let outer = simplify(&local(polygon.vertices()), tolerance);
+        match polygon.holes() {
+            [] => {
+                if let Some(radius) = circle_radius(&outer, tolerance) {
+                    return Some(ClassifiedShape::Circle(Disk::new(radius, 0.0)));
+                }
+                let (corners, radii) = sharpen(&outer, tolerance)?;
+                let (corners, _) = align(&corners)?;
+                (0..4).find_map(|quarter| fit_solid(&rotate(&corners, quarter), &radii, tolerance))
+            }
+            [hole] => {
+                let hole = simplify(&local(hole), tolerance);
+                if (center(&outer) - center(&hole)).norm() > tolerance {
+                    return None;
+                }
+                if let (Some(radius), Some(hole_radius)) = (circle_radius(&outer, tolerance), circle_radius(&hole, tolerance)) {
+                    return Some(ClassifiedShape::Tube(Disk::new(radius, hole_radius)));
+                }
+                let outer = align(&outer)?;
+                let hole = rotate_like(&hole, &outer.1);
+                let (outer, hole) = (outer.0, simplify(&hole, tolerance));
+                let (width, height) = rectangle(&outer, tolerance)?;
+                let (hole_width, hole_height) = rectangle(&hole, tolerance)?;
+                Rectangle::try_new(width, height, hole_width, hole_height).ok().map(ClassifiedShape::RectangularTube)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ClassifiedShape {
+    /// The recognised shape behind the common [`Shape`] interface.
+    pub fn shape(&self) -> &dyn Shape {
+        match self {
+            Self::Rectangle(s) | Self::RectangularTube(s) => s,
+            Self::Circle(s) | Self::Tube(s) => s,
+            Self::I(s) => s,
+            Self::C(s) => s,
+            Self::L(s) => s,
+            Self::T(s) => s,
+        }
+    }
+}
+
+impl Shape for ClassifiedShape {
+    fn area(&self) -> f64 { self.shape().area() }
+    fn perimeter(&self) -> f64 { self.shape().perimeter() }
+    fn centroid(&self) -> Vector3d { self.shape().centroid() }
+    fn second_moment_of_area(&self) -> Matrix3<f64> { self.shape().second_moment_of_area() }
+    fn linearized(&self, sides: usize) -> RawPolygon<Vector3d> { self.shape().linearized(sides) }
+    fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d> { self.shape().linearized_by_tolerance(max_sagitta) }
+    fn descriptor(&self) -> Option<ShapeDescriptor> { self.shape().descriptor() }
+}
+
+/// Drop repeated and collinear vertices and orient the ring counter-clockwise.
+fn simplify(ring: &[Vector2d], tolerance: f64) -> Vec<Vector2d> {
+    let mut points: Vec<Vector2d> = Vec::with_capacity(ring.len());
+    for &p in ring {
+        if points.last().is_none_or(|&last| (p - last).norm() > tolerance) {
+            points.push(p);
+        }
+    }
+    while points.len() > 1 && (points[0] - points[points.len() - 1]).norm() <= tolerance {
+        points.pop();
+    }
+    let mut changed = true;
+    while changed && points.len() > 3 {
+        changed = false;
+        for i in 0..points.len() {
+            let n = points.len();
+            let (a, b, c) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            let chord = c - a;
+            let offset = (b - a).cross(&chord).abs() / chord.norm().max(f64::MIN_POSITIVE);
+            if offset <= tolerance && (b - a).dot(&chord) >= 0.0 && (c - b).dot(&chord) >= 0.0 {
+                points.remove(i);
+                changed = true;
+                break;
+            }
+        }
+    }
+    if signed_area(&points) < 0.0 {
+        points.reverse();
+    }
+    points
+}
+
+/// Radius when all vertices (at least eight) lie on a common circle.
+fn circle_radius(ring: &[Vector2d], tolerance: f64) -> Option<f64> {
+    if ring.len() < 8 {
+        return None;
+    }
+    let center = center(ring);
+    let radii: Vec<f64> = ring.iter().map(|&p| (p - center).norm()).collect();
+    let radius = radii.iter().sum::<f64>() / radii.len() as f64;
+    radii.iter().all(|r| (r - radius).abs() <= tolerance).then_some(radius)
+}
+
+/// Mean of the vertices.
+fn center(ring: &[Vector2d]) -> Vector2d { ring.iter().fold(Vector2d::new(0.0, 0.0), |sum, &p| sum + p) / ring.len() as f64 }
+
+/// Rotate the ring by the smallest angle that makes its longest edge
+/// axis-parallel. Returns the rotated ring and the direction of the new x axis.
+fn align(ring: &[Vector2d]) -> Option<(Vec<Vector2d>, Vector2d)> {
+    let edge = |i: usize| ring[(i + 1) % ring.len()] - ring[i];
+    let longest = (0..ring.len()).map(edge).max_by(|a, b| a.norm().total_cmp(&b.norm()))?;
+    let quarter = std::f64::consts::FRAC_PI_2;
+    let angle = (longest.angle() + quarter / 2.0).rem_euclid(quarter) - quarter / 2.0;
+    let direction = Vector2d::new(angle.cos(), angle.sin());
+    Some((rotate_like(ring, &direction), direction))
+}
+
+/// Express points in a frame whose x axis is `direction`.
+fn rotate_like(ring: &[Vector2d], direction: &Vector2d) -> Vec<Vector2d> {
+    ring.iter().map(|p| Vector2d::new(p.dot(direction), direction.cross(p))).collect()
+}
+
+/// Rotate by `quarter` quarter turns counter-clockwise.
+fn rotate(ring: &[Vector2d], quarter: usize) -> Vec<Vector2d> {
+    ring.iter()
+        .map(|p| match quarter % 4 {
+            0 => *p,
+            1 => p.perp(),
+            2 => -*p,
+            _ => Vector2d::new(p.y(), -p.x()),
+        })
+        .collect()
+}
+
+/// Translate so the bounding box starts at the origin; returns the box size too.
+fn normalized(ring: &[Vector2d]) -> (Vec<Vector2d>, Vector2d) {
+    let min = Vector2d(ring.iter().fold(Vector2::repeat(f64::INFINITY), |m, p| m.inf(&p.0)));
+    let max = Vector2d(ring.iter().fold(Vector2::repeat(f64::NEG_INFINITY), |m, p| m.sup(&p.0)));
+    (ring.iter().map(|&p| p - min).collect(), max - min)
+}
+
+fn rectangle(ring: &[Vector2d], tolerance: f64) -> Option<(f64, f64)> {
+    let (points, size) = normalized(ring);
+    let corners = [Vector2d::new(0.0, 0.0), Vector2d::new(size.x(), 0.0), size, Vector2d::new(0.0, size.y())];
+    (points.len() == 4 && same_vertices(&points, &corners, tolerance)).then_some((size.x(), size.y()))
+}
+
+fn same_vertices(a: &[Vector2d], b: &[Vector2d], tolerance: f64) -> bool {
+    a.len() == b.len() && b.iter().all(|q| a.iter().any(|p| (*p - *q).norm() <= tolerance))
+}
+
+/// Collapse every run of at least two equal chords turning alike, as the
+/// fillets and toe radii of rolled profiles are drawn, into the corner of the
+/// straight edges it joins. Returns the corners and the radius of the arc
+/// rounding each, zero where the outline is sharp.
+fn sharpen(ring: &[Vector2d], tolerance: f64) -> Option<(Vec<Vector2d>, Vec<f64>)> {
+    let n = ring.len();
+    let edge = |i: usize| ring[(i + 1) % n] - ring[i % n];
+    let turn = |i: usize| {
+        let (a, b) = (edge(i + n - 1), edge(i));
+        a.cross(&b).atan2(a.dot(&b))
+    };
+    // Inside an arc, a vertex joins two equal chords at a shallow turn.
+    let inside = |i: usize| turn(i).abs() < FRAC_PI_3 && (edge(i + n - 1).norm() - edge(i).norm()).abs() <= tolerance;
+    // Start on a sharp corner or where an arc begins, never where one ends.
+    let start = (n..2 * n).find(|&i| !inside(i) && !inside(i - 1))?;
+    let (mut corners, mut radii) = (Vec::new(), Vec::new());
+    let mut i = start;
+    while i < start + n {
+        if !inside(i + 1) {
+            corners.push(ring[i % n]);
+            radii.push(0.0);
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        while inside(end) {
+            end += 1;
+        }
+        let chord = edge(i).norm();
+        if (i + 2..end).any(|j| (turn(j) - turn(i + 1)).abs() * chord > tolerance) {
+            return None;
+        }
+        let (before, after) = (edge(i + n - 1), edge(end));
+        let (from, to) = (ring[i % n], ring[end % n]);
+        let cross = before.cross(&after);
+        if cross.abs() <= f64::EPSILON * before.norm() * after.norm() {
+            return None;
+        }
+        let corner = from + before * ((to - from).cross(&after) / cross);
+        let deflection = cross.atan2(before.dot(&after)).abs();
+        corners.push(corner);
+        radii.push((corner - from).norm() / (deflection / 2.0).tan());
+        i = end + 1;
+    }
+    Some((corners, radii))
+}
+
+/// Translate so the bounding box starts at the origin and start the ring at its
+/// bottom left vertex, where the shape constructors start their outlines.
+/// Returns the ring and the index it now starts from.
+fn canonical(ring: &[Vector2d], tolerance: f64) -> (Vec<Vector2d>, usize) {
+    let (mut points, _) = normalized(ring);
+    let start = (0..points.len())
+        .filter(|&i| points[i].y() <= tolerance)
+        .min_by(|&a, &b| points[a].x().total_cmp(&points[b].x()))
+        .unwrap_or(0);
+    points.rotate_left(start);
+    (points, start)
+}
+
+/// Fit the profile in its canonical orientation (flanges horizontal, web or
+/// heel on the left/bottom as built by the shape constructors) from its sharp
+/// corners and their `radii`. Each candidate is rebuilt and must reproduce both;
+/// outlines whose measured dimensions make no valid shape are not recognised.
+fn fit_solid(ring: &[Vector2d], radii: &[f64], tolerance: f64) -> Option<ClassifiedShape> {
+    let (corners, start) = canonical(ring, tolerance);
+    let mut radii = radii.to_vec();
+    radii.rotate_left(start);
+    let candidates = match corners.len() {
+        4 => vec![fit_rectangle(&corners)],
+        6 => vec![fit_l(&corners, &radii)],
+        8 => vec![fit_c(&corners, &radii), fit_t(&corners, &radii)],
+        12 => vec![fit_i(&corners, &radii)],
+        _ => return None,
+    };
+    candidates.into_iter().flatten().find_map(|(candidate, expected_radii)| {
+        let outline: Vec<Vector2d> = candidate.linearized(0).vertices().iter().map(|v| Vector2d::new(v.x(), v.y())).collect();
+        let (expected, _) = canonical(&outline, tolerance);
+        let same_corners = expected.len() == corners.len() && expected.iter().zip(&corners).all(|(&a, &b)| (a - b).norm() <= tolerance);
+        let same_radii = expected_radii.iter().zip(&radii).all(|(a, b)| (a - b).abs() <= tolerance);
+        (same_corners && same_radii).then_some(candidate)
+    })
+}
+
+/// A fitted candidate and the radii it rounds its corners with, in outline order.
+type Fit = Option<(ClassifiedShape, Vec<f64>)>;
+
+/// Thickness and taper angle of a flange whose inner face rises from `toe` at
+/// the tip to `root` at the web over `outstand`, the thickness measured `from_toe`
+/// in from the tip.
+fn flange(toe: f64, root: f64, outstand: f64, from_toe: f64) -> (f64, f64) {
+    let slope = (root - toe) / outstand;
+    (toe + slope * from_toe, slope.atan())
+}
+
+fn fit_rectangle(p: &[Vector2d]) -> Fit {
+    let rectangle = Rectangle::try_new(p[1].x() - p[0].x(), p[2].y() - p[1].y(), 0.0, 0.0).ok()?;
+    Some((ClassifiedShape::Rectangle(rectangle), vec![0.0; 4]))
+}
+
+fn fit_i(p: &[Vector2d], r: &[f64]) -> Fit {
+    let (bottom_width, top_width, height, web) = (p[1].x() - p[0].x(), p[6].x() - p[7].x(), p[7].y() - p[0].y(), p[3].x() - p[10].x());
+    let (bottom, bottom_taper) = flange(p[2].y() - p[1].y(), p[3].y() - p[1].y(), (bottom_width - web) / 2.0, bottom_width / 4.0);
+    let (top, top_taper) = flange(p[6].y() - p[5].y(), p[6].y() - p[4].y(), (top_width - web) / 2.0, top_width / 4.0);
+    let (fillet, top_toe, bottom_toe) = (r[3], r[5], r[2]);
+    let shape =
+        ShapeI::try_new(bottom_width, top_width, height, bottom, top, web, fillet, top_toe, bottom_toe, top_taper, bottom_taper).ok()?;
+    let radii = vec![0.0, 0.0, bottom_toe, fillet, fillet, top_toe, 0.0, 0.0, top_toe, fillet, fillet, bottom_toe];
+    Some((ClassifiedShape::I(shape), radii))
+}
+
+fn fit_c(p: &[Vector2d], r: &[f64]) -> Fit {
+    let (bottom_width, top_width, height, web) = (p[1].x() - p[0].x(), p[6].x() - p[7].x(), p[7].y() - p[0].y(), p[3].x() - p[0].x());
+    let (bottom, bottom_taper) = flange(p[2].y() - p[1].y(), p[3].y() - p[0].y(), bottom_width - web, bottom_width / 2.0);
+    let (top, top_taper) = flange(p[6].y() - p[5].y(), p[7].y() - p[4].y(), top_width - web, top_width / 2.0);
+    let (fillet, top_toe, bottom_toe, top_back, bottom_back) = (r[3], r[5], r[2], r[7], r[0]);
+    let shape = ShapeC::try_new(
+        bottom_width,
+        top_width,
+        height,
+        bottom,
+        top,
+        web,
+        fillet,
+        top_toe,
+        bottom_toe,
+        top_back,
+        bottom_back,
+        top_taper,
+        bottom_taper,
+    )
+    .ok()?;
+    Some((ClassifiedShape::C(shape), vec![bottom_back, 0.0, bottom_toe, fillet, fillet, top_toe, 0.0, top_back]))
+}
+
+fn fit_t(p: &[Vector2d], r: &[f64]) -> Fit {
+    let (width, height, web) = (p[4].x() - p[5].x(), p[4].y() - p[1].y(), p[1].x() - p[0].x());
+    let outstand = (width - web) / 2.0;
+    let (thickness, taper) = flange(p[4].y() - p[3].y(), p[4].y() - p[2].y(), outstand, outstand / 2.0);
+    let (fillet, toe) = (r[2], r[3]);
+    let shape = ShapeT::try_new(width, height, thickness, web, fillet, toe, taper).ok()?;
+    Some((ClassifiedShape::T(shape), vec![0.0, 0.0, fillet, toe, 0.0, 0.0, toe, fillet]))
+}
+
+fn fit_l(p: &[Vector2d], r: &[f64]) -> Fit {
+    let (width, height) = (p[1].x() - p[0].x(), p[5].y() - p[0].y());
+    let (toe, root) = (p[2], p[3]);
+    let slope = (root.y() - toe.y()) / (toe.x() - root.x());
+    // The leg thicknesses are taken on the inner faces halfway along the legs,
+    // each point depending on the other leg's thickness.
+    let flange_at_heel = root.y() - slope * (width / 2.0 - root.x());
+    let web_at_heel = root.x() - slope * (height / 2.0 - root.y());
+    let det = 1.0 - slope * slope / 4.0;
+    let flange_thickness = (flange_at_heel - slope * web_at_heel / 2.0) / det;
+    let web_thickness = (web_at_heel - slope * flange_at_heel / 2.0) / det;
+    let (fillet, toe_radius, back_fillet) = (r[3], r[2], r[0]);
+    let shape = ShapeL::try_new(width, height, flange_thickness, web_thickness, fillet, toe_radius, back_fillet, slope.atan()).ok()?;
+    Some((ClassifiedShape::L(shape), vec![back_fillet, 0.0, toe_radius, fillet, toe_radius, 0.0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collinear_vertices_are_removed() {
+        let ring = [
+            Vector2d::new(0.0, 0.0),
+            Vector2d::new(1.0, 0.0),
+            Vector2d::new(2.0, 0.0),
+            Vector2d::new(2.0, 1.0),
+            Vector2d::new(0.0, 1.0),
+        ];
+        assert_eq!(simplify(&ring, 1e-9).len(), 4);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{BoundingBox3d, Scalar};
+use crate::line::{Line, LineVector};
+
+/// Common interface of the curve types, so member meshing or load positioning
+/// can take any of them. The parameter `t` runs from 0 at the start to 1 at the
+/// end; it is proportional to arc length for all curves but Bézier curves.
+pub trait Curve<V: LineVector> {
+    fn start(&self) -> V;
+    fn end(&self) -> V;
+    fn length(&self) -> V::Scalar;
+    fn point_at(&self, t: V::Scalar) -> V;
+    /// Unit tangent at `t` in the direction of travel.
+    fn tangent_at(&self, t: V::Scalar) -> V;
+    fn closest_point(&self, point: &V) -> V;
+    /// Axis-aligned bounding box, in `f64` whatever the precision of the
+    /// curve; planar curves lie at z = 0.
+    fn bounding_box(&self) -> BoundingBox3d;
+    /// Chords approximating the curve, `segments` for every curved piece;
+    /// straight pieces are returned as they are.
+    fn linearized(&self, segments: usize) -> Vec<Line<V>>;
+}
+
+/// Parameter of arc length `length` along a curve of length `total` whose
+/// parameter is proportional to arc length, clamped to `[0, 1]`.
+pub(crate) fn parameter_at_length<T: Scalar>(length: T, total: T) -> T {
+    if total <= T::epsilon() { T::zero() } else { (length / total).clamp(T::zero(), T::one()) }
+}
@@ -0,0 +1,176 @@
+use nalgebra::{Matrix2, Matrix3, Vector2};
+
+use crate::{Axis, LocalAxis, Polygon, Polyline, Segment, Vector3d};
+
+/// Planar section bounded by closed polylines of lines and arcs, e.g. a plate
+/// with filleted corners or a round tube. Area, centroid and second moments are
+/// exact: every arc adds or removes the circular segment between it and its
+/// chord instead of being linearized.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurvedPolygon {
+    outer: Polyline,
+    holes: Vec<Polyline>,
+    frame: LocalAxis,
+}
+
+/// Signed area integrals `[A, ∫x, ∫y, ∫x², ∫y², ∫xy]` in the local frame.
+type Moments = [f64; 6];
+
+impl CurvedPolygon {
+    /// Panics unless `outer` is closed.
+    pub fn new(outer: Polyline) -> Self {
+        assert!(outer.is_closed(), "section boundary must be closed");
+        // Frame of the polygon through the segment ends and arc midpoints, so a
+        // circle of two arcs still spans a plane.
+        let frame = Polygon::new(sample_points(&outer)).local_axis();
+        Self { outer, holes: Vec::new(), frame }
+    }
+
+    /// Panics unless `hole` is closed.
+    pub fn with_hole(mut self, hole: Polyline) -> Self {
+        assert!(hole.is_closed(), "hole boundary must be closed");
+        self.holes.push(hole);
+        self
+    }
+
+    pub fn outer(&self) -> &Polyline { &self.outer }
+    pub fn holes(&self) -> &[Polyline] { &self.holes }
+
+    /// Local frame at the centroid of the straight-sided outline, with the same
+    /// axes a [`Polygon`] through the outline points would have.
+    pub fn local_axis(&self) -> LocalAxis { self.frame }
+
+    pub fn area(&self) -> f64 { self.moments()[0] }
+
+    pub fn centroid(&self) -> Vector3d {
+        let [area, sx, sy, ..] = self.moments();
+        self.frame.to_global(Vector3d::new(sx / area, sy / area, 0.0))
+    }
+
+    /// Second moments `[Ixx Ixy; Ixy Iyy]` about the centroid along the local
+    /// X,Y axes, as [`Polygon::about_centroid_local`].
+    pub fn about_centroid_local(&self) -> Matrix2<f64> {
+        let [area, sx, sy, sxx, syy, sxy] = self.moments();
+        let (cx, cy) = (sx / area, sy / area);
+        let ixy = sxy - area * cx * cy;
+        Matrix2::new(syy - area * cy * cy, ixy, ixy, sxx - area * cx * cx)
+    }
+
+    /// Thin-plate inertia tensor about the centroid in global axes, as
+    /// [`Polygon::about_centroid_global`].
+    pub fn about_centroid_global(&self) -> Matrix3<f64> {
+        let local = self.about_centroid_local();
+        let (ixx, iyy, ixy) = (local[(0, 0)], local[(1, 1)], local[(0, 1)]);
+        let tensor = Matrix3::new(ixx, -ixy, 0.0, -ixy, iyy, 0.0, 0.0, 0.0, ixx + iyy);
+        let rotation = Matrix3::from_columns(&[Axis::AxisX, Axis::AxisY, Axis::AxisZ].map(|axis| self.frame.direction(axis).0));
+        rotation * tensor * rotation.transpose()
+    }
+
+    /// Polygon with every arc replaced by `segments_per_arc` chords, for
+    /// meshing or comparison with the exact properties.
+    pub fn linearized(&self, segments_per_arc: usize) -> Polygon {
+        let ring = |boundary: &Polyline| -> Vec<Vector3d> {
+            let mut points = Vec::new();
+            for segment in boundary.segments() {
+                match segment {
+                    Segment::Line(line) => points.push(line.start()),
+                    Segment::Arc(arc) => points.extend(arc.linearized(segments_per_arc).iter().map(|line| line.start())),
+                }
+            }
+            points
+        };
+        Polygon::with_holes(ring(&self.outer), self.holes.iter().map(ring).collect::<Vec<_>>())
+    }
+
+    /// Area integrals of the outer ring less those of the holes, each ring
+    /// taken counterclockwise.
+    fn moments(&self) -> Moments {
+        let mut total = self.ring_moments(&self.outer);
+        for hole in &self.holes {
+            for (sum, term) in total.iter_mut().zip(self.ring_moments(hole)) {
+                *sum -= term;
+            }
+        }
+        total
+    }
+
+    fn ring_moments(&self, ring: &Polyline) -> Moments {
+        let local = |point: Vector3d| {
+            let p = self.frame.to_local(point);
+            Vector2::new(p.x(), p.y())
+        };
+        let mut moments = [0.0; 6];
+        for segment in ring.segments() {
+            let (start, end) = (local(segment.start()), local(segment.end()));
+            add(&mut moments, &chord(start, end), 1.0);
+            if let Segment::Arc(arc) = segment {
+                let (center, middle) = (local(arc.center()), local(arc.point_at(0.5)));
+                // Bulging to the right of the chord the arc encloses more area.
+                let sign = if (end - start).perp(&(middle - start)) < 0.0 { 1.0 } else { -1.0 };
+                add(&mut moments, &circular_segment(center, start, end, middle), sign);
+            }
+        }
+        if moments[0] < 0.0 {
+            moments = moments.map(|m| -m);
+        }
+        moments
+    }
+}
+
+fn sample_points(ring: &Polyline) -> Vec<Vector3d> {
+    let mut points = Vec::new();
+    for segment in ring.segments() {
+        points.push(segment.start());
+        if let Segment::Arc(arc) = segment {
+            points.push(arc.point_at(0.5));
+        }
+    }
+    points
+}
+
+fn add(total: &mut Moments, terms: &Moments, sign: f64) {
+    for (sum, term) in total.iter_mut().zip(terms) {
+        *sum += sign * term;
+    }
+}
+
+/// Signed integrals of the triangle between the origin and the chord `a b`.
+fn chord(a: Vector2<f64>, b: Vector2<f64>) -> Moments {
+    let cross = a.perp(&b);
+    [
+        cross / 2.0,
+        (a.x + b.x) * cross / 6.0,
+        (a.y + b.y) * cross / 6.0,
+        (a.x * a.x + a.x * b.x + b.x * b.x) * cross / 12.0,
+        (a.y * a.y + a.y * b.y + b.y * b.y) * cross / 12.0,
+        (2.0 * a.x * a.y + a.x * b.y + b.x * a.y + 2.0 * b.x * b.y) * cross / 24.0,
+    ]
+}
+
+/// Integrals of the circular segment between the chord `start end` and the
+/// arc through `middle` about `center`: the sector less the triangle at the
+/// center, which turns into an addition for arcs longer than a semicircle.
+fn circular_segment(center: Vector2<f64>, start: Vector2<f64>, end: Vector2<f64>, middle: Vector2<f64>) -> Moments {
+    let radius = (middle - center).norm();
+    let u = (middle - center) / radius;
+    let v = Vector2::new(-u.y, u.x);
+    // Half chord and the signed distance of the chord from the center.
+    let half = (end - start).norm() / 2.0;
+    let offset = ((start + end) / 2.0 - center).dot(&u);
+    let alpha = half.atan2(offset);
+    let theta = 2.0 * alpha;
+
+    let area = radius * radius / 2.0 * (theta - theta.sin());
+    let first = 2.0 / 3.0 * radius.powi(3) * alpha.sin().powi(3);
+    let iuu = radius.powi(4) / 8.0 * (theta + theta.sin()) - half * offset.powi(3) / 2.0;
+    let ivv = radius.powi(4) / 8.0 * (theta - theta.sin()) - half.powi(3) * offset / 6.0;
+
+    // Shift from the center frame (u, v) to the local frame.
+    let moment = center * area + u * first;
+    let rotation = Matrix2::from_columns(&[u, v]);
+    let second = center * center.transpose() * area
+        + center * (u * first).transpose()
+        + u * first * center.transpose()
+        + rotation * Matrix2::new(iuu, 0.0, 0.0, ivv) * rotation.transpose();
+    [area, moment.x, moment.y, second[(0, 0)], second[(1, 1)], second[(0, 1)]]
+}
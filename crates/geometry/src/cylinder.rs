@@ -0,0 +1,139 @@
+use nalgebra::Vector3;
+use utils::epsilon;
+
+use crate::{Line3d, Vector3d};
+
+/// Solid circular cylinder with flat caps around the segment from `start` to
+/// `end`, e.g. the clearance envelope of a member.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cylinder {
+    start: Vector3d,
+    end: Vector3d,
+    radius: f64,
+}
+
+impl Cylinder {
+    /// Panics on a negative radius or coinciding ends.
+    pub fn new<S: Into<Vector3d>, E: Into<Vector3d>>(start: S, end: E, radius: f64) -> Self {
+        let (start, end) = (start.into(), end.into());
+        assert!(radius >= 0.0, "cylinder radius must not be negative");
+        assert!((end.0 - start.0).norm() > epsilon(), "cylinder axis must have a length");
+        Self { start, end, radius }
+    }
+
+    /// Cylinder around a member axis.
+    pub fn around(axis: &Line3d, radius: f64) -> Self { Self::new(axis.start(), axis.end(), radius) }
+
+    pub fn start(&self) -> Vector3d { self.start }
+    pub fn end(&self) -> Vector3d { self.end }
+    pub fn radius(&self) -> f64 { self.radius }
+    pub fn axis(&self) -> Line3d { Line3d::new(self.start, self.end) }
+    pub fn length(&self) -> f64 { (self.end.0 - self.start.0).norm() }
+
+    /// Whether `point` lies inside or within `tolerance` of the cylinder,
+    /// [`epsilon`] by default.
+    pub fn contains(&self, point: &Vector3d, tolerance: Option<f64>) -> bool {
+        self.signed_distance(point) <= tolerance.unwrap_or_else(epsilon)
+    }
+
+    /// Distance from `point` to the surface, negative inside.
+    pub fn signed_distance(&self, point: &Vector3d) -> f64 {
+        let (along, radial) = self.split(point);
+        let length = self.length();
+        let beyond_cap = (-along).max(along - length).max(0.0);
+        let beyond_side = (radial.norm() - self.radius).max(0.0);
+        if beyond_cap > 0.0 || beyond_side > 0.0 {
+            beyond_cap.hypot(beyond_side)
+        } else {
+            -(self.radius - radial.norm()).min(along).min(length - along)
+        }
+    }
+
+    /// Point of the surface nearest to `point`, on the side or a cap.
+    pub fn closest_point(&self, point: &Vector3d) -> Vector3d {
+        let (along, radial) = self.split(point);
+        let (length, distance) = (self.length(), radial.norm());
+        let outward = radial.try_normalize(epsilon()).unwrap_or_else(|| self.perpendicular());
+        let inside = (0.0..=length).contains(&along) && distance <= self.radius;
+        let (along, distance) = if !inside {
+            (along.clamp(0.0, length), distance.min(self.radius))
+        } else if self.radius - distance <= along.min(length - along) {
+            (along, self.radius)
+        } else if along <= length - along {
+            (0.0, distance)
+        } else {
+            (length, distance)
+        };
+        Vector3d(self.start.0 + self.direction() * along + outward * distance)
+    }
+
+    /// Points where the segment `line` pierces or touches the surface, ordered
+    /// from its start.
+    pub fn intersection_with_line(&self, line: &Line3d) -> Vec<Vector3d> {
+        self.line_parameters(line)
+            .into_iter()
+            .filter(|t| (-epsilon()..=1.0 + epsilon()).contains(t))
+            .map(|t| line.point_at(t))
+            .collect()
+    }
+
+    /// Parameters along `line` (0 at the start, 1 at the end) where the infinite
+    /// line through it meets the side or the caps, in increasing order.
+    pub(crate) fn line_parameters(&self, line: &Line3d) -> Vec<f64> {
+        let (axis, length) = (self.direction(), self.length());
+        let (offset, direction) = (line.start().0 - self.start.0, line.end().0 - line.start().0);
+        let slack = epsilon() * length.max(1.0);
+        let mut parameters = Vec::new();
+
+        // Side: |offset + t direction| measured across the axis equals the radius.
+        let across = |v: Vector3<f64>| v - axis * v.dot(&axis);
+        let (o, d) = (across(offset), across(direction));
+        let a = d.norm_squared();
+        if a > epsilon() * epsilon() {
+            let b = o.dot(&d);
+            let discriminant = b * b - a * (o.norm_squared() - self.radius * self.radius);
+            if discriminant >= 0.0 {
+                let root = discriminant.sqrt();
+                for t in [(-b - root) / a, (-b + root) / a] {
+                    let along = (offset + direction * t).dot(&axis);
+                    if (-slack..=length + slack).contains(&along) {
+                        parameters.push(t);
+                    }
+                }
+            }
+        }
+
+        // Caps: the planes at either end, within the radius.
+        let rate = direction.dot(&axis);
+        if rate.abs() > epsilon() * direction.norm() {
+            for cap in [0.0, length] {
+                let t = (cap - offset.dot(&axis)) / rate;
+                if across(offset + direction * t).norm() <= self.radius + slack {
+                    parameters.push(t);
+                }
+            }
+        }
+
+        parameters.sort_by(f64::total_cmp);
+        let tolerance = epsilon() * length.max(1.0) / direction.norm();
+        parameters.dedup_by(|a, b| (*a - *b).abs() <= tolerance);
+        parameters
+    }
+
+    fn direction(&self) -> Vector3<f64> { (self.end.0 - self.start.0).normalize() }
+
+    /// Distance of `point` along the axis from the start, and its offset from the axis.
+    fn split(&self, point: &Vector3d) -> (f64, Vector3<f64>) {
+        let (axis, offset) = (self.direction(), point.0 - self.start.0);
+        let along = offset.dot(&axis);
+        (along, offset - axis * along)
+    }
+
+    /// Some unit vector across the axis.
+    fn perpendicular(&self) -> Vector3<f64> {
+        let axis = self.direction();
+        let helper = if axis.x.abs() < 0.9 { Vector3::x() } else { Vector3::y() };
+        axis.cross(&helper).normalize()
+    }
+}
@@ -0,0 +1,445 @@
+use crate::shape::{
+    CompoundShape, Disk, GenericShape, Rectangle, Shape, ShapeBox, ShapeC, ShapeError, ShapeI, ShapeL, ShapeT, ShapeTube,
+};
+use crate::Vector2d;
+
+/// Parameters a shape is built from, with one variant per shape type. This is
+/// also the serialized form of every shape, tagged with its type name, e.g.
+/// `{"type": "Rectangle", "width": 0.2, "height": 0.1}`; radii, fillets, taper
+/// angles and holes may be left out for zero. Deserializing validates the
+/// parameters as the `try_new` constructors do.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "type"))]
+pub enum ShapeDescriptor {
+    Rectangle {
+        width: f64,
+        height: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        hole_width: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        hole_height: f64,
+    },
+    Disk {
+        radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        hole_radius: f64,
+    },
+    ShapeBox {
+        width: f64,
+        height: f64,
+        thickness: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        outer_radius: f64,
+    },
+    ShapeTube {
+        diameter: f64,
+        thickness: f64,
+    },
+    ShapeI {
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        bottom_thickness: f64,
+        top_thickness: f64,
+        web_thickness: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        top_toe_radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        bottom_toe_radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        top_taper_angle: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        bottom_taper_angle: f64,
+    },
+    ShapeC {
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        bottom_thickness: f64,
+        top_thickness: f64,
+        web_thickness: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        top_toe_radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        bottom_toe_radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        top_back_fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        bottom_back_fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        top_taper_angle: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        bottom_taper_angle: f64,
+    },
+    ShapeL {
+        width: f64,
+        height: f64,
+        flange_thickness: f64,
+        web_thickness: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        toe_radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        back_fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        taper_angle: f64,
+    },
+    ShapeT {
+        width: f64,
+        height: f64,
+        flange_thickness: f64,
+        web_thickness: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        fillet: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        toe_radius: f64,
+        #[cfg_attr(feature = "serde", serde(default))]
+        taper_angle: f64,
+    },
+    /// Outline points in the XY plane, cleaned as by [`GenericShape::from_points`].
+    GenericShape {
+        points: Vec<Vector2d>,
+    },
+    CompoundShape {
+        parts: Vec<CompoundPart>,
+    },
+}
+
+/// Part of a [`ShapeDescriptor::CompoundShape`], placed as by
+/// [`CompoundShape::add_part`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompoundPart {
+    pub shape: ShapeDescriptor,
+    pub offset: Vector2d,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub angle: f64,
+}
+
+const WRONG_TYPE: ShapeError = ShapeError { dimension: "type", reason: "must name the shape being built" };
+
+impl ShapeDescriptor {
+    /// The shape these parameters describe, whichever type it is.
+    pub fn build(&self) -> Result<Box<dyn Shape>, ShapeError> {
+        let descriptor = self.clone();
+        Ok(match self {
+            Self::Rectangle { .. } => Box::new(Rectangle::try_from(descriptor)?),
+            Self::Disk { .. } => Box::new(Disk::try_from(descriptor)?),
+            Self::ShapeBox { .. } => Box::new(ShapeBox::try_from(descriptor)?),
+            Self::ShapeTube { .. } => Box::new(ShapeTube::try_from(descriptor)?),
+            Self::ShapeI { .. } => Box::new(ShapeI::try_from(descriptor)?),
+            Self::ShapeC { .. } => Box::new(ShapeC::try_from(descriptor)?),
+            Self::ShapeL { .. } => Box::new(ShapeL::try_from(descriptor)?),
+            Self::ShapeT { .. } => Box::new(ShapeT::try_from(descriptor)?),
+            Self::GenericShape { .. } => Box::new(GenericShape::try_from(descriptor)?),
+            Self::CompoundShape { .. } => Box::new(CompoundShape::try_from(descriptor)?),
+        })
+    }
+}
+
+impl From<Rectangle> for ShapeDescriptor {
+    fn from(shape: Rectangle) -> Self {
+        let Rectangle { width, height, hole_width, hole_height, .. } = shape;
+        Self::Rectangle { width, height, hole_width, hole_height }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for Rectangle {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::Rectangle { width, height, hole_width, hole_height } => Self::try_new(width, height, hole_width, hole_height),
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<Disk> for ShapeDescriptor {
+    fn from(shape: Disk) -> Self { Self::Disk { radius: shape.radius, hole_radius: shape.hole_radius } }
+}
+
+impl TryFrom<ShapeDescriptor> for Disk {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::Disk { radius, hole_radius } => Self::try_new(radius, hole_radius),
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<ShapeBox> for ShapeDescriptor {
+    fn from(shape: ShapeBox) -> Self {
+        let ShapeBox { width, height, thickness, outer_radius } = shape;
+        Self::ShapeBox { width, height, thickness, outer_radius }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for ShapeBox {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::ShapeBox { width, height, thickness, outer_radius } => Self::try_new(width, height, thickness, outer_radius),
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<ShapeTube> for ShapeDescriptor {
+    fn from(shape: ShapeTube) -> Self { Self::ShapeTube { diameter: shape.diameter, thickness: shape.thickness } }
+}
+
+impl TryFrom<ShapeDescriptor> for ShapeTube {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::ShapeTube { diameter, thickness } => Self::try_new(diameter, thickness),
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<ShapeI> for ShapeDescriptor {
+    fn from(shape: ShapeI) -> Self {
+        let ShapeI {
+            bottom_width,
+            top_width,
+            height,
+            bottom_thickness,
+            top_thickness,
+            web_thickness,
+            fillet,
+            top_toe_radius,
+            bottom_toe_radius,
+            top_taper_angle,
+            bottom_taper_angle,
+            ..
+        } = shape;
+        Self::ShapeI {
+            bottom_width,
+            top_width,
+            height,
+            bottom_thickness,
+            top_thickness,
+            web_thickness,
+            fillet,
+            top_toe_radius,
+            bottom_toe_radius,
+            top_taper_angle,
+            bottom_taper_angle,
+        }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for ShapeI {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::ShapeI {
+                bottom_width,
+                top_width,
+                height,
+                bottom_thickness,
+                top_thickness,
+                web_thickness,
+                fillet,
+                top_toe_radius,
+                bottom_toe_radius,
+                top_taper_angle,
+                bottom_taper_angle,
+            } => Self::try_new(
+                bottom_width,
+                top_width,
+                height,
+                bottom_thickness,
+                top_thickness,
+                web_thickness,
+                fillet,
+                top_toe_radius,
+                bottom_toe_radius,
+                top_taper_angle,
+                bottom_taper_angle,
+            ),
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<ShapeC> for ShapeDescriptor {
+    fn from(shape: ShapeC) -> Self {
+        let ShapeC {
+            bottom_width,
+            top_width,
+            height,
+            bottom_thickness,
+            top_thickness,
+            web_thickness,
+            fillet,
+            top_toe_radius,
+            bottom_toe_radius,
+            top_back_fillet,
+            bottom_back_fillet,
+            top_taper_angle,
+            bottom_taper_angle,
+            ..
+        } = shape;
+        Self::ShapeC {
+            bottom_width,
+            top_width,
+            height,
+            bottom_thickness,
+            top_thickness,
+            web_thickness,
+            fillet,
+            top_toe_radius,
+            bottom_toe_radius,
+            top_back_fillet,
+            bottom_back_fillet,
+            top_taper_angle,
+            bottom_taper_angle,
+        }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for ShapeC {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::ShapeC {
+                bottom_width,
+                top_width,
+                height,
+                bottom_thickness,
+                top_thickness,
+                web_thickness,
+                fillet,
+                top_toe_radius,
+                bottom_toe_radius,
+                top_back_fillet,
+                bottom_back_fillet,
+                top_taper_angle,
+                bottom_taper_angle,
+            } => Self::try_new(
+                bottom_width,
+                top_width,
+                height,
+                bottom_thickness,
+                top_thickness,
+                web_thickness,
+                fillet,
+                top_toe_radius,
+                bottom_toe_radius,
+                top_back_fillet,
+                bottom_back_fillet,
+                top_taper_angle,
+                bottom_taper_angle,
+            ),
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<ShapeL> for ShapeDescriptor {
+    fn from(shape: ShapeL) -> Self {
+        let ShapeL { width, height, flange_thickness, web_thickness, fillet, toe_radius, back_fillet, taper_angle, .. } = shape;
+        Self::ShapeL { width, height, flange_thickness, web_thickness, fillet, toe_radius, back_fillet, taper_angle }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for ShapeL {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::ShapeL { width, height, flange_thickness, web_thickness, fillet, toe_radius, back_fillet, taper_angle } => {
+                Self::try_new(width, height, flange_thickness, web_thickness, fillet, toe_radius, back_fillet, taper_angle)
+            }
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<ShapeT> for ShapeDescriptor {
+    fn from(shape: ShapeT) -> Self {
+        let ShapeT { width, height, flange_thickness, web_thickness, fillet, toe_radius, taper_angle, .. } = shape;
+        Self::ShapeT { width, height, flange_thickness, web_thickness, fillet, toe_radius, taper_angle }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for ShapeT {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::ShapeT { width, height, flange_thickness, web_thickness, fillet, toe_radius, taper_angle } => {
+                Self::try_new(width, height, flange_thickness, web_thickness, fillet, toe_radius, taper_angle)
+            }
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl From<GenericShape> for ShapeDescriptor {
+    fn from(shape: GenericShape) -> Self {
+        Self::GenericShape { points: shape.to_polygon().vertices().iter().map(|v| Vector2d::new(v.x(), v.y())).collect() }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for GenericShape {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::GenericShape { points } => {
+                Self::from_points(points).ok_or(ShapeError { dimension: "points", reason: "must outline a simple polygon" })
+            }
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+impl TryFrom<ShapeDescriptor> for CompoundShape {
+    type Error = ShapeError;
+
+    fn try_from(descriptor: ShapeDescriptor) -> Result<Self, ShapeError> {
+        match descriptor {
+            ShapeDescriptor::CompoundShape { parts } => {
+                let mut compound = Self::new();
+                for part in parts {
+                    compound.add_boxed_part(part.shape.build()?, part.offset, part.angle);
+                }
+                Ok(compound)
+            }
+            _ => Err(WRONG_TYPE),
+        }
+    }
+}
+
+// Compound parts are trait objects, so the compound goes through its
+// descriptor by hand; it fails to serialize when a part has none.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CompoundShape {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let descriptor = self.descriptor().ok_or_else(|| serde::ser::Error::custom("compound part without a descriptor"))?;
+        serde::Serialize::serialize(&descriptor, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CompoundShape {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let descriptor: ShapeDescriptor = serde::Deserialize::deserialize(deserializer)?;
+        Self::try_from(descriptor).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,180 @@
+//! Human-readable formatting of the geometry types. Numbers follow the
+//! precision of the format string, so `{:.3}` prints every coordinate with
+//! three decimals while `{}` prints the shortest exact representation.
+
+use std::fmt;
+
+use nalgebra::{Dim, Matrix, RawStorage};
+
+use crate::arc::{Arc, ArcVector};
+use crate::line::{Line, LineVector, LocalAxis};
+use crate::polygon::Polygon;
+use crate::{Axis, Scalar, SignedAxis, Vector2d, Vector3d};
+
+fn number(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{value:.precision$}"),
+        None => write!(f, "{value}"),
+    }
+}
+
+fn tuple(f: &mut fmt::Formatter<'_>, values: &[f64]) -> fmt::Result {
+    f.write_str("(")?;
+    for (i, &value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        number(f, value)?;
+    }
+    f.write_str(")")
+}
+
+fn ring<V: fmt::Display>(f: &mut fmt::Formatter<'_>, points: &[V]) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, point) in points.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        point.fmt(f)?;
+    }
+    f.write_str("]")
+}
+
+impl<T: Scalar> fmt::Display for Vector2d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { tuple(f, &[self.x().as_f64(), self.y().as_f64()]) }
+}
+
+impl<T: Scalar> fmt::Display for Vector3d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        tuple(f, &[self.x().as_f64(), self.y().as_f64(), self.z().as_f64()])
+    }
+}
+
+impl<V: LineVector + fmt::Display> fmt::Display for Line<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.start().fmt(f)?;
+        f.write_str(" -> ")?;
+        self.end().fmt(f)
+    }
+}
+
+/// `start -> end` followed by the center, radius and sweep in degrees.
+impl<V: ArcVector + fmt::Display> fmt::Display for Arc<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.start().fmt(f)?;
+        f.write_str(" -> ")?;
+        self.end().fmt(f)?;
+        f.write_str(" about ")?;
+        self.center().fmt(f)?;
+        f.write_str(", radius ")?;
+        number(f, self.radius().as_f64())?;
+        f.write_str(", sweep ")?;
+        number(f, self.angle().as_f64().to_degrees())?;
+        f.write_str("°")
+    }
+}
+
+/// The outer ring, followed by every hole.
+impl<V: ArcVector + fmt::Display> fmt::Display for Polygon<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("polygon ")?;
+        ring(f, self.vertices())?;
+        for hole in self.holes() {
+            f.write_str(" hole ")?;
+            ring(f, hole)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Axis::AxisX => "x",
+            Axis::AxisY => "y",
+            Axis::AxisZ => "z",
+        })
+    }
+}
+
+impl fmt::Display for SignedAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_positive() {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.axis())
+    }
+}
+
+impl fmt::Display for LocalAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("origin ")?;
+        self.origin().fmt(f)?;
+        for axis in [Axis::AxisX, Axis::AxisY, Axis::AxisZ] {
+            write!(f, ", {axis} ")?;
+            self.direction(axis).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Matrix printed as a table with one bracketed line per row and columns
+/// aligned, e.g. `println!("{:.3}", MatrixTable(&rotation))`.
+pub struct MatrixTable<'a, R: Dim, C: Dim, S: RawStorage<f64, R, C>>(pub &'a Matrix<f64, R, C, S>);
+
+impl<R: Dim, C: Dim, S: RawStorage<f64, R, C>> fmt::Display for MatrixTable<'_, R, C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rows, columns) = self.0.shape();
+        let cells: Vec<String> = (0..rows * columns)
+            .map(|i| {
+                let value = self.0[(i / columns, i % columns)];
+                match f.precision() {
+                    Some(precision) => format!("{value:.precision$}"),
+                    None => format!("{value}"),
+                }
+            })
+            .collect();
+        let width = cells.iter().map(|cell| cell.chars().count()).max().unwrap_or(0);
+        for row in 0..rows {
+            if row > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str("[")?;
+            for cell in &cells[row * columns..(row + 1) * columns] {
+                write!(f, " {cell:>width$}")?;
+            }
+            f.write_str(" ]")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Matrix2;
+
+    use super::*;
+    use crate::{Line3d, Polygon};
+
+    #[test]
+    fn precision_applies_to_every_number() {
+        let v = Vector3d::new(1.0, -0.5, 2.0 / 3.0);
+        assert_eq!(format!("{v:.2}"), "(1.00, -0.50, 0.67)");
+        assert_eq!(format!("{}", Vector2d::new(1.5, 2.0)), "(1.5, 2)");
+
+        let line = Line3d::new((0.0, 0.0, 0.0), (1.0, 2.0, 0.0));
+        assert_eq!(format!("{line:.1}"), "(0.0, 0.0, 0.0) -> (1.0, 2.0, 0.0)");
+
+        let triangle = Polygon::new(vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)]);
+        assert_eq!(format!("{triangle}"), "polygon [(0, 0, 0), (1, 0, 0), (0, 1, 0)]");
+
+        let axis = LocalAxis::new(Vector3d::new(1.0, 0.0, 0.0), nalgebra::Matrix3::identity());
+        assert_eq!(format!("{axis:.0}"), "origin (1, 0, 0), x (1, 0, 0), y (0, 1, 0), z (0, 0, 1)");
+    }
+
+    #[test]
+    fn matrix_table_aligns_columns() {
+        let m = Matrix2::new(1.0, -20.5, 300.25, 0.0);
+        assert_eq!(format!("{:.1}", MatrixTable(&m)), "[   1.0 -20.5 ]\n[ 300.2   0.0 ]");
+    }
+}
@@ -0,0 +1,9 @@
+use crate::line::LineVector;
+
+/// Minimum distance between two entities with the points that realize it, as
+/// `(point on self, point on other, distance)` like [`crate::Line::closest_points`];
+/// e.g. the clearance between a duct and a brace. Entities that touch or
+/// overlap report zero and a common point.
+pub trait DistanceTo<T, V: LineVector> {
+    fn distance_to(&self, other: &T) -> (V, V, V::Scalar);
+}
@@ -1,15 +1,30 @@
-use crate::line::{Line, LineVector};
-use utils::epsilon;
+use crate::arc::{Arc, ArcVector};
+use crate::line::Line;
+use crate::polyline::Segment;
+use crate::ray::Ray;
+use num_traits::Zero;
+
+use crate::{BoundingBox3d, Curve, Scalar, Transformable, Vector3d};
 
 #[cfg(test)]
-use crate::{Vector2d, Vector3d};
+use crate::Vector2d;
 
+/// Straight or circular boundary piece, optionally carrying tangent hints at
+/// its ends.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "V: serde::Serialize, V::Scalar: serde::Serialize",
+        deserialize = "V: serde::Deserialize<'de>, V::Scalar: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Edge<V>
 where
-    V: LineVector,
+    V: ArcVector,
 {
-    line: Line<V>,
+    geometry: Segment<V>,
     start_tangent: Option<V>,
     end_tangent: Option<V>,
 }
@@ -19,18 +34,14 @@ where
 
 impl<V> Edge<V>
 where
-    V: LineVector,
+    V: ArcVector,
 {
     pub fn new<S, E>(start: S, end: E) -> Self
     where
         S: Into<V>,
         E: Into<V>,
     {
-        Self {
-            line: Line::new(start.into(), end.into()),
-            start_tangent: None,
-            end_tangent: None,
-        }
+        Self::from(Line::new(start.into(), end.into()))
     }
 
     pub fn with_tangents<S, E, T1, T2>(start: S, end: E, start_tangent: T1, end_tangent: T2) -> Self
@@ -41,89 +52,115 @@ where
         T2: Into<V>,
     {
         Self {
-            line: Line::new(start.into(), end.into()),
+            geometry: Segment::Line(Line::new(start.into(), end.into())),
             start_tangent: Some(start_tangent.into()),
             end_tangent: Some(end_tangent.into()),
         }
     }
 
+    /// Curved edge running along `arc`.
+    pub fn from_arc(arc: Arc<V>) -> Self { Self::from(arc) }
+
+    /// The line or arc the edge runs along.
+    pub fn geometry(&self) -> &Segment<V> { &self.geometry }
+
+    pub fn is_curved(&self) -> bool { matches!(self.geometry, Segment::Arc(_)) }
+
     pub fn start(&self) -> V {
-        self.line.start()
+        self.geometry.start()
     }
 
     pub fn end(&self) -> V {
-        self.line.end()
+        self.geometry.end()
     }
 
-    pub fn length(&self) -> f64 {
-        self.line.length()
+    pub fn length(&self) -> V::Scalar {
+        self.geometry.length()
     }
 
     pub fn centroid(&self) -> V {
-        self.line.midpoint()
+        match &self.geometry {
+            Segment::Line(line) => line.midpoint(),
+            Segment::Arc(arc) => arc.centroid(),
+        }
     }
 
-    pub fn point_at(&self, t: f64) -> V {
-        self.line.point_at(t)
+    pub fn point_at(&self, t: V::Scalar) -> V {
+        self.geometry.point_at(t)
+    }
+
+    /// Unit tangent at `t` in the direction of the edge, from its geometry;
+    /// the tangent hints are not consulted.
+    pub fn tangent_at(&self, t: V::Scalar) -> V {
+        self.geometry.curve().tangent_at(t)
     }
 
     pub fn closest_point(&self, point: &V) -> V {
-        self.line.closest_point(point)
+        self.geometry.closest_point(point)
     }
 
-    pub fn contains(&self, point: &V) -> bool {
-        self.line.contains(point)
+    pub fn contains(&self, point: &V, tolerance: Option<f64>) -> bool {
+        self.geometry.contains(point, tolerance)
     }
 
-    pub fn length_at_point(&self, point: &V) -> f64 {
-        self.line.length_at_point(point)
+    pub fn length_at_point(&self, point: &V) -> V::Scalar {
+        self.geometry.length_at_point(point)
     }
 
-    pub fn break_at(&self, parameter: f64) -> Vec<Self> {
-        self.line
+    pub fn break_at(&self, parameter: V::Scalar) -> Vec<Self> {
+        self.geometry
             .break_at(parameter)
             .into_iter()
-            .map(|segment| {
-                let mut edge = Self::new(segment.start(), segment.end());
-                if let Some(tangent) = self.start_tangent {
-                    edge.start_tangent = Some(tangent);
-                }
-                if let Some(tangent) = self.end_tangent {
-                    edge.end_tangent = Some(tangent);
-                }
-                edge
-            })
+            .map(|geometry| Self { geometry, start_tangent: self.start_tangent, end_tangent: self.end_tangent })
             .collect()
     }
 
     pub fn break_at_point(&self, point: &V) -> Vec<Self> {
-        self.line
-            .break_at_point(point)
-            .into_iter()
-            .map(|segment| Self::new(segment.start(), segment.end()))
-            .collect()
+        self.break_at_points(std::slice::from_ref(point))
+    }
+
+    /// Split at every point lying on the edge, see [`Line::break_at_points`]
+    /// and [`Arc::break_at_points`].
+    pub fn break_at_points(&self, points: &[V]) -> Vec<Self> {
+        self.geometry.break_at_points(points).into_iter().map(Self::from).collect()
+    }
+
+    /// First point, from the start of the edge, where it crosses the segment
+    /// `other`.
+    pub fn intersection_with_line(&self, other: &Line<V>) -> Option<V> {
+        self.intersections_with_line(other).into_iter().next()
     }
 
-    pub fn intersection_with_line(&self, other: &Line<V>, treat_as_ray: bool) -> Option<V> {
-        self.line.intersection(other, treat_as_ray)
+    /// Every point where the edge crosses the segment `other`; a curved edge
+    /// can be crossed twice. In order from the start of the edge.
+    pub fn intersections_with_line(&self, other: &Line<V>) -> Vec<V> {
+        let mut points = self.geometry.intersection_with_line(other, None);
+        points.sort_by(|a, b| self.length_at_point(a).as_f64().total_cmp(&self.length_at_point(b).as_f64()));
+        points
     }
 
+    /// Where the ray leaving the start of this edge along its start direction
+    /// meets `other`: the infinite line through a straight edge, or a point
+    /// within the sweep of a curved one, nearest first.
     pub fn ray_intersection_with_edge(&self, other: &Self) -> Option<V> {
-        self.line.ray_intersection(&other.line)
+        let ray = Ray::new(self.start(), self.tangent_at(V::Scalar::zero()));
+        match &other.geometry {
+            Segment::Line(line) => ray.intersection_with_infinite_line(line, None),
+            Segment::Arc(arc) => ray.intersection_with_arc(arc, None).into_iter().next(),
+        }
     }
 
     pub fn reverse(&mut self) {
-        self.line.reverse();
+        self.geometry = self.geometry.reversed();
         std::mem::swap(&mut self.start_tangent, &mut self.end_tangent);
     }
 
     pub fn reversed_edge(&self) -> Self {
-        let reversed = Self {
-            line: self.line.reversed(),
+        Self {
+            geometry: self.geometry.reversed(),
             start_tangent: self.end_tangent,
             end_tangent: self.start_tangent,
-        };
-        reversed
+        }
     }
 
     pub fn set_start_tangent(&mut self, tangent: V) {
@@ -143,7 +180,39 @@ where
     }
 
     pub fn is_degenerate(&self) -> bool {
-        self.length() <= epsilon()
+        self.length() <= V::Scalar::epsilon()
+    }
+}
+
+impl<V: ArcVector> From<Line<V>> for Edge<V> {
+    fn from(line: Line<V>) -> Self { Self { geometry: Segment::Line(line), start_tangent: None, end_tangent: None } }
+}
+
+impl<V: ArcVector> From<Arc<V>> for Edge<V> {
+    fn from(arc: Arc<V>) -> Self { Self { geometry: Segment::Arc(arc), start_tangent: None, end_tangent: None } }
+}
+
+impl<V: ArcVector> From<Segment<V>> for Edge<V> {
+    fn from(geometry: Segment<V>) -> Self { Self { geometry, start_tangent: None, end_tangent: None } }
+}
+
+impl<V: ArcVector> Curve<V> for Edge<V> {
+    fn start(&self) -> V { self.geometry.start() }
+    fn end(&self) -> V { self.geometry.end() }
+    fn length(&self) -> V::Scalar { self.geometry.length() }
+    fn point_at(&self, t: V::Scalar) -> V { self.geometry.point_at(t) }
+    fn tangent_at(&self, t: V::Scalar) -> V { Edge::tangent_at(self, t) }
+    fn closest_point(&self, point: &V) -> V { self.geometry.closest_point(point) }
+    fn bounding_box(&self) -> BoundingBox3d { self.geometry.curve().bounding_box() }
+    fn linearized(&self, segments: usize) -> Vec<Line<V>> { self.geometry.curve().linearized(segments) }
+}
+
+impl Transformable for Edge<Vector3d> {
+    fn transform(&mut self, transform: &crate::Transform3d) {
+        self.geometry.transform(transform);
+        for tangent in [&mut self.start_tangent, &mut self.end_tangent].into_iter().flatten() {
+            *tangent = transform.apply_vector(tangent).normalize();
+        }
     }
 }
 
@@ -181,7 +250,7 @@ mod tests {
     fn edge_intersection_with_line() {
     let edge = Edge::<Vector2d>::new(Vector2d::new(0.0, 0.0), Vector2d::new(4.0, 4.0));
         let line = Line::new(Vector2d::new(0.0, 4.0), Vector2d::new(4.0, 0.0));
-        let intersection = edge.intersection_with_line(&line, false).unwrap();
+        let intersection = edge.intersection_with_line(&line).unwrap();
         assert_almost_eq!(intersection.x(), 2.0);
     }
 
@@ -191,6 +260,6 @@ mod tests {
         let point = Vector2d::new(5.0, 2.0);
         let closest = edge.closest_point(&point);
         assert_almost_eq!(closest.x(), 5.0);
-        assert!(edge.contains(&Vector2d::new(5.0, 0.0)));
+        assert!(edge.contains(&Vector2d::new(5.0, 0.0), None));
     }
 }
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// Invalid input to one of the fallible geometry constructors, e.g.
+/// [`crate::Polygon::try_new`], for callers that must not panic on user data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    /// Fewer than three distinct points were left for a ring.
+    TooFewPoints { distinct: usize },
+    /// The points all lie on one line, so they do not span a plane.
+    Collinear,
+    /// Two points that must differ coincide.
+    CoincidentPoints,
+    /// Segment `index` of a chain does not start where the previous one ends.
+    Disconnected { index: usize },
+    /// A scalar argument out of range: its name and the condition it breaks.
+    InvalidArgument { argument: &'static str, reason: &'static str },
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewPoints { distinct } => write!(f, "need at least three distinct points, got {distinct}"),
+            Self::Collinear => write!(f, "points are collinear and do not span a plane"),
+            Self::CoincidentPoints => write!(f, "points coincide"),
+            Self::Disconnected { index } => write!(f, "segment {index} does not start where the previous one ends"),
+            Self::InvalidArgument { argument, reason } => write!(f, "{argument} {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
@@ -0,0 +1,104 @@
+//! Exact rational polygon properties, available with the `exact` feature.
+//!
+//! Every `f64` vertex coordinate converts to a rational number without
+//! rounding, so area, centroid and second moments follow exactly from the
+//! shoelace-type edge sums. Slow, and meant as a reference for validating the
+//! `f64` implementations on slivers and nearly degenerate polygons.
+
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::Polygon;
+
+/// Area, centroid and centroidal second moments of a planar region.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactProperties {
+    pub area: BigRational,
+    pub centroid: [BigRational; 2],
+    /// `[Ixx, Iyy, Ixy]` about the centroid with `Ixx = ∫y² dA`, `Iyy = ∫x² dA`
+    /// and `Ixy = ∫xy dA`, as in [`Polygon::about_centroid_local`].
+    pub second_moments: [BigRational; 3],
+}
+
+impl ExactProperties {
+    /// Properties of the region bounded by `rings`, the outer ring first and
+    /// holes after it, each in either orientation. `None` for non-finite
+    /// coordinates or a region without area.
+    pub fn of_rings(rings: &[Vec<[f64; 2]>]) -> Option<Self> {
+        let mut sums: [BigRational; 6] = Default::default();
+        for (index, ring) in rings.iter().enumerate() {
+            let points = ring
+                .iter()
+                .map(|&[x, y]| Some([BigRational::from_float(x)?, BigRational::from_float(y)?]))
+                .collect::<Option<Vec<_>>>()?;
+            let ring_sums = edge_sums(&points);
+            // Outer ring counts positive and holes negative, whatever their
+            // orientation.
+            let outward = ring_sums[0].is_positive() == (index == 0);
+            for (sum, ring_sum) in sums.iter_mut().zip(ring_sums) {
+                *sum = if outward { &*sum + ring_sum } else { &*sum - ring_sum };
+            }
+        }
+        let [area2, x_sum, y_sum, xx_sum, yy_sum, xy_sum] = sums;
+        if area2.is_zero() {
+            return None;
+        }
+        let area = area2 / BigRational::from_integer(2.into());
+        let cx = x_sum / (&area * BigRational::from_integer(6.into()));
+        let cy = y_sum / (&area * BigRational::from_integer(6.into()));
+        let twelve = BigRational::from_integer(12.into());
+        let ixx = yy_sum / &twelve - &area * &cy * &cy;
+        let iyy = xx_sum / &twelve - &area * &cx * &cx;
+        let ixy = xy_sum / (twelve * BigRational::from_integer(2.into())) - &area * &cx * &cy;
+        Some(Self { area, centroid: [cx, cy], second_moments: [ixx, iyy, ixy] })
+    }
+
+    /// Properties of a polygon in the global XY plane, holes included; the z
+    /// coordinates are ignored.
+    pub fn of_polygon(polygon: &Polygon) -> Option<Self> {
+        let rings: Vec<Vec<[f64; 2]>> = std::iter::once(polygon.vertices())
+            .chain(polygon.holes().iter())
+            .map(|ring| ring.iter().map(|v| [v.x(), v.y()]).collect())
+            .collect();
+        Self::of_rings(&rings)
+    }
+
+    pub fn area_f64(&self) -> f64 { to_f64(&self.area) }
+    pub fn centroid_f64(&self) -> [f64; 2] { self.centroid.each_ref().map(to_f64) }
+    pub fn second_moments_f64(&self) -> [f64; 3] { self.second_moments.each_ref().map(to_f64) }
+}
+
+/// `|approximate − exact| / |exact|`, or the absolute error for a zero exact
+/// value.
+pub fn relative_error(exact: &BigRational, approximate: f64) -> f64 {
+    let Some(approximate) = BigRational::from_float(approximate) else { return f64::INFINITY };
+    let error = (approximate - exact).abs();
+    if exact.is_zero() { to_f64(&error) } else { to_f64(&(error / exact.abs())) }
+}
+
+fn to_f64(value: &BigRational) -> f64 { value.to_f64().unwrap_or(f64::NAN) }
+
+/// Twice the signed area followed by the edge sums of the first and second
+/// moments: `Σ c`, `Σ (xᵢ + xⱼ) c`, `Σ (yᵢ + yⱼ) c`, `Σ (xᵢ² + xᵢxⱼ + xⱼ²) c`,
+/// `Σ (yᵢ² + yᵢyⱼ + yⱼ²) c` and `Σ (xᵢyⱼ + 2xᵢyᵢ + 2xⱼyⱼ + xⱼyᵢ) c`, with
+/// `c = xᵢyⱼ − xⱼyᵢ` over the edges `i → j`.
+fn edge_sums(points: &[[BigRational; 2]]) -> [BigRational; 6] {
+    let mut sums: [BigRational; 6] = Default::default();
+    for (i, [xi, yi]) in points.iter().enumerate() {
+        let [xj, yj] = &points[(i + 1) % points.len()];
+        let c = xi * yj - xj * yi;
+        let two = BigRational::from_integer(2.into());
+        let terms = [
+            BigRational::from_integer(1.into()),
+            xi + xj,
+            yi + yj,
+            xi * xi + xi * xj + xj * xj,
+            yi * yi + yi * yj + yj * yj,
+            xi * yj + &two * xi * yi + &two * xj * yj + xj * yi,
+        ];
+        for (sum, term) in sums.iter_mut().zip(terms) {
+            *sum += term * &c;
+        }
+    }
+    sums
+}
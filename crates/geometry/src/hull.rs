@@ -0,0 +1,117 @@
+use nalgebra::{Matrix3, Vector3};
+use utils::epsilon;
+
+use crate::{Polygon, Vector2d, Vector3d};
+
+/// Convex hull of a 2D point set (Andrew's monotone chain).
+///
+/// Vertices are returned counter-clockwise starting from the lowest-leftmost
+/// point; collinear and duplicate points are dropped. Fewer than three vertices
+/// are returned for degenerate (empty, single point or collinear) inputs.
+pub fn convex_hull_2d(points: &[Vector2d]) -> Vec<Vector2d> {
+    hull_indices(points).into_iter().map(|i| points[i]).collect()
+}
+
+/// Convex hull of a 3D point set lying in (or close to) a plane.
+///
+/// Points are projected onto their best-fit plane through the centroid before the
+/// hull is taken, so the returned polygon is planar even for slightly scattered
+/// input. Returns `None` when the points do not span an area.
+pub fn convex_hull_planar(points: &[Vector3d]) -> Option<Polygon> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let centroid = points.iter().fold(Vector3::zeros(), |acc, p| acc + p.0) / n;
+    let mut covariance = Matrix3::zeros();
+    for p in points {
+        let d = p.0 - centroid;
+        covariance += d * d.transpose();
+    }
+    let eigen = covariance.symmetric_eigen();
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| eigen.eigenvalues[b].total_cmp(&eigen.eigenvalues[a]));
+    if eigen.eigenvalues[order[1]] <= epsilon() * eigen.eigenvalues[order[0]].max(epsilon()) {
+        return None;
+    }
+    // In-plane axes along the two largest spreads; the smallest is the plane normal.
+    let ex: Vector3<f64> = eigen.eigenvectors.column(order[0]).into_owned();
+    let ey: Vector3<f64> = eigen.eigenvectors.column(order[1]).into_owned();
+
+    let local: Vec<Vector2d> = points
+        .iter()
+        .map(|p| {
+            let d = p.0 - centroid;
+            Vector2d::new(d.dot(&ex), d.dot(&ey))
+        })
+        .collect();
+    let hull = hull_indices(&local);
+    if hull.len() < 3 {
+        return None;
+    }
+    Some(Polygon::new(hull.into_iter().map(|i| {
+        let q = local[i];
+        Vector3d(centroid + ex * q.x() + ey * q.y())
+    })))
+}
+
+/// Indices of the hull vertices of `points`, counter-clockwise.
+fn hull_indices(points: &[Vector2d]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| {
+        points[a]
+            .x()
+            .total_cmp(&points[b].x())
+            .then(points[a].y().total_cmp(&points[b].y()))
+    });
+    order.dedup_by(|a, b| (points[*a] - points[*b]).norm() <= epsilon());
+    if order.len() < 3 {
+        return order;
+    }
+
+    let extent = (points[order[order.len() - 1]] - points[order[0]]).norm();
+    let tol = epsilon() * extent.max(1.0);
+    let turns_left = |hull: &[usize], p: usize| {
+        let a = points[hull[hull.len() - 2]];
+        let b = points[hull[hull.len() - 1]];
+        let c = points[p];
+        let ab = b - a;
+        let bc = c - b;
+        ab.cross(&bc) > tol * (ab.norm() + bc.norm())
+    };
+
+    let mut lower: Vec<usize> = Vec::new();
+    for &i in &order {
+        while lower.len() >= 2 && !turns_left(&lower, i) {
+            lower.pop();
+        }
+        lower.push(i);
+    }
+    let mut upper: Vec<usize> = Vec::new();
+    for &i in order.iter().rev() {
+        while upper.len() >= 2 && !turns_left(&upper, i) {
+            upper.pop();
+        }
+        upper.push(i);
+    }
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    if lower.len() < 3 {
+        lower.truncate(2);
+    }
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collinear_points_have_no_area() {
+        let points = [Vector2d::new(0.0, 0.0), Vector2d::new(1.0, 1.0), Vector2d::new(2.0, 2.0)];
+        assert_eq!(convex_hull_2d(&points).len(), 2);
+        let points3: Vec<Vector3d> = points.iter().map(|p| Vector3d::from(*p)).collect();
+        assert!(convex_hull_planar(&points3).is_none());
+    }
+}
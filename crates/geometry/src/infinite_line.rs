@@ -0,0 +1,76 @@
+use utils::epsilon;
+
+use crate::{Line3d, Plane, Polygon, Vector3d};
+
+/// Unbounded line through `point` with unit `direction`, e.g. a gridline that
+/// nodes are projected onto. Parameters along it are distances from `point`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InfiniteLine3d {
+    point: Vector3d,
+    direction: Vector3d,
+}
+
+impl InfiniteLine3d {
+    /// The direction is normalized. Panics on a zero direction.
+    pub fn new<P: Into<Vector3d>, D: Into<Vector3d>>(point: P, direction: D) -> Self {
+        let direction = direction.into().0;
+        assert!(direction.norm() > epsilon(), "line direction must be non-zero");
+        Self { point: point.into(), direction: Vector3d(direction.normalize()) }
+    }
+
+    /// Line through both ends of a segment. Panics on a zero-length segment.
+    pub fn from_line(line: &Line3d) -> Self { Self::new(line.start(), Vector3d(line.end().0 - line.start().0)) }
+
+    pub fn point(&self) -> Vector3d { self.point }
+    pub fn direction(&self) -> Vector3d { self.direction }
+
+    /// Point at signed distance `t` from [`InfiniteLine3d::point`].
+    pub fn point_at(&self, t: f64) -> Vector3d { Vector3d(self.point.0 + self.direction.0 * t) }
+
+    /// Signed distance from [`InfiniteLine3d::point`] to the projection of `point`.
+    pub fn parameter(&self, point: &Vector3d) -> f64 { (point.0 - self.point.0).dot(&self.direction.0) }
+
+    /// Orthogonal projection of `point`, anywhere along the line.
+    pub fn closest_point(&self, point: &Vector3d) -> Vector3d { self.point_at(self.parameter(point)) }
+
+    pub fn distance(&self, point: &Vector3d) -> f64 { (point.0 - self.closest_point(point).0).norm() }
+
+    /// Whether `point` lies within `tolerance` of the line, [`epsilon`] by default.
+    pub fn contains(&self, point: &Vector3d, tolerance: Option<f64>) -> bool { self.distance(point) <= tolerance.unwrap_or_else(epsilon) }
+
+    /// Point where the segment `line` crosses this line. A segment lying on the
+    /// line reports its start; parallel or skew segments give `None`.
+    pub fn intersection_with_line(&self, line: &Line3d) -> Option<Vector3d> {
+        let (start, along) = (line.start(), line.end().0 - line.start().0);
+        if self.contains(&start, None) {
+            return Some(start);
+        }
+        let normal = self.direction.0.cross(&along);
+        if normal.norm_squared() <= epsilon() * along.norm_squared() {
+            return None;
+        }
+        // Parameter of the segment point closest to this line.
+        let offset = self.point.0 - start.0;
+        let t = self.direction.0.cross(&offset).dot(&normal) / normal.norm_squared();
+        if !(-epsilon()..=1.0 + epsilon()).contains(&t) {
+            return None;
+        }
+        let point = line.point_at(t);
+        (self.distance(&point) <= 1e-9 * along.norm().max(1.0)).then_some(point)
+    }
+
+    /// Point where the line crosses `plane`; `None` when parallel to it.
+    pub fn intersection_with_plane(&self, plane: &Plane) -> Option<Vector3d> {
+        plane.line_parameter(&self.guide()).map(|t| self.point_at(t))
+    }
+
+    /// Points where the line meets the polygon, see [`Polygon::intersection_with_line`].
+    pub fn intersection_with_polygon(&self, polygon: &Polygon) -> Vec<Vector3d> { polygon.intersection_with_line(&self.guide(), None) }
+
+    /// Parts of the line inside the polygon, see [`Polygon::clip_line`].
+    pub fn clip_to_polygon(&self, polygon: &Polygon) -> Vec<Line3d> { polygon.clip_line(&self.guide(), None) }
+
+    /// Unit segment from the point along the direction.
+    fn guide(&self) -> Line3d { Line3d::new(self.point, self.point_at(1.0)) }
+}
@@ -1,17 +1,86 @@
+mod bbox;
+mod bezier;
+mod catalog;
+mod boolean;
+mod classify;
+mod curve;
+mod curved;
+mod cylinder;
+mod descriptor;
+mod display;
+mod distance;
 mod edge;
+mod error;
+mod hull;
+mod infinite_line;
 mod arc;
+mod offset;
+mod plane;
 mod polygon;
+mod polyline;
+mod ray;
+mod region;
+mod sampling;
+#[cfg(feature = "exact")]
+pub mod exact;
 pub mod line;
+pub mod mesh;
+pub mod predicates;
 mod shape;
+mod shear;
+pub mod snap;
+pub mod spatial;
+mod sphere;
+mod torsion;
+mod transform;
 mod vector;
 
 // Public API: expose 3D concrete type aliases as canonical names; 2D inputs
 // to public constructors will still be accepted but the canonical exported
 // types are the 3D-specialized aliases below.
 pub type Arc = arc::Arc<Vector3d>;
+pub use arc::fillet;
 pub type Edge = edge::Edge<Vector3d>;
 pub type Polygon = polygon::Polygon<Vector3d>;
-pub use shape::{Disk, Rectangle, Shape, ShapeC, ShapeI, ShapeL, ShapeT};
-pub use vector::{Vector2d, Vector3d};
-pub use line::{Axis, LocalAxis, Line3d};
+pub type BoundaryPoint = polygon::BoundaryPoint<Vector3d>;
+pub use polygon::{BoundaryFeature, EdgeLoopError};
+pub type Polyline = polyline::Polyline<Vector3d>;
+pub type Segment = polyline::Segment<Vector3d>;
+pub type Ray = ray::Ray<Vector3d>;
+pub use bbox::BoundingBox3d;
+pub use bezier::Bezier3d;
+pub use catalog::designations as profile_designations;
+pub use display::MatrixTable;
+pub use distance::DistanceTo;
+pub use error::GeometryError;
+pub use classify::ClassifiedShape;
+pub use curve::Curve;
+pub use curved::CurvedPolygon;
+pub use cylinder::Cylinder;
+pub use descriptor::{CompoundPart, ShapeDescriptor};
+pub use hull::{convex_hull_2d, convex_hull_planar};
+pub use infinite_line::InfiniteLine3d;
+pub use offset::OffsetJoin;
+pub use plane::Plane;
+pub use region::Region;
+pub use sampling::IntegrationPoint;
+pub use shear::ThinWalledSection;
+pub use shape::{
+    CompoundShape, Disk, GenericShape, Rectangle, Shape, ShapeBox, ShapeC, ShapeError, ShapeI, ShapeL, ShapeT, ShapeTube,
+};
+pub use sphere::{bounding_sphere, Sphere};
+pub use transform::{Transform3d, Transformable};
+pub use vector::{Scalar, Vector2d, Vector3d};
+pub use mesh::TriMesh;
+pub use line::{Axis, LocalAxis, Line3d, SignedAxis, TriadLine};
 pub use line::Line3d as Line;
+
+// Lines in the plane, e.g. section outlines.
+pub type Line2d = line::Line<Vector2d>;
+
+// Single-precision geometry, e.g. for visualization-scale models. It computes
+// in f32 with `Scalar::epsilon` of f32 as its default tolerance.
+pub type Vector3f = Vector3d<f32>;
+pub type Line3f = line::Line<Vector3f>;
+pub type Arc3f = arc::Arc<Vector3f>;
+pub type Polygon3f = polygon::Polygon<Vector3f>;
@@ -1,8 +1,12 @@
-use crate::{Vector2d, Vector3d};
+use nalgebra::{ComplexField, RealField};
+use num_traits::{One, Zero};
+
+use crate::{Scalar, Vector2d, Vector3d};
 use utils::epsilon;
 
 /// Canonical coordinate axes for 3D space.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Axis {
     AxisX,
     AxisY,
@@ -18,79 +22,138 @@ impl Axis {
             Axis::AxisZ => Vector3d::new(0.0, 0.0, 1.0),
         }
     }
+
+    /// RGB color of the axis in orientation triads: X red, Y green, Z blue.
+    pub fn color(&self) -> [u8; 3] {
+        match self {
+            Axis::AxisX => [255, 0, 0],
+            Axis::AxisY => [0, 255, 0],
+            Axis::AxisZ => [0, 0, 255],
+        }
+    }
 }
 
-/// Internal trait to abstract over 2D and 3D vector behaviour for lines.
-pub trait LineVector: Copy {
-    fn add(&self, other: &Self) -> Self;
-    fn sub(&self, other: &Self) -> Self;
-    fn scale(&self, factor: f64) -> Self;
-    fn dot(&self, other: &Self) -> f64;
-    fn norm(&self) -> f64;
-    fn normalize(&self) -> Self;
-    fn component_min(&self, other: &Self) -> Self;
-    fn component_max(&self, other: &Self) -> Self;
-    fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool;
+/// Coordinate axis with a direction, e.g. the negative local Z face of a
+/// member. Every method taking one also takes a plain [`Axis`], which is the
+/// positive direction, and `-Axis::AxisZ` gives [`SignedAxis::NegZ`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignedAxis {
+    PosX,
+    PosY,
+    PosZ,
+    NegX,
+    NegY,
+    NegZ,
 }
 
-impl LineVector for Vector2d {
-    fn add(&self, other: &Self) -> Self { Vector2d(self.0 + other.0) }
-    fn sub(&self, other: &Self) -> Self { Vector2d(self.0 - other.0) }
-    fn scale(&self, factor: f64) -> Self { Vector2d(self.0 * factor) }
-    fn dot(&self, other: &Self) -> f64 { self.0.dot(&other.0) }
-    fn norm(&self) -> f64 { self.0.norm() }
-    fn normalize(&self) -> Self { Vector2d(self.0.normalize()) }
-    fn component_min(&self, other: &Self) -> Self {
-        Vector2d(self.0.zip_map(&other.0, |a, b| a.min(b)))
-    }
-    fn component_max(&self, other: &Self) -> Self {
-        Vector2d(self.0.zip_map(&other.0, |a, b| a.max(b)))
+impl SignedAxis {
+    pub fn new(axis: Axis, positive: bool) -> Self {
+        match (axis, positive) {
+            (Axis::AxisX, true) => SignedAxis::PosX,
+            (Axis::AxisY, true) => SignedAxis::PosY,
+            (Axis::AxisZ, true) => SignedAxis::PosZ,
+            (Axis::AxisX, false) => SignedAxis::NegX,
+            (Axis::AxisY, false) => SignedAxis::NegY,
+            (Axis::AxisZ, false) => SignedAxis::NegZ,
+        }
     }
-    fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool {
-        self.is_approx(other, precision)
+
+    pub fn axis(&self) -> Axis {
+        match self {
+            SignedAxis::PosX | SignedAxis::NegX => Axis::AxisX,
+            SignedAxis::PosY | SignedAxis::NegY => Axis::AxisY,
+            SignedAxis::PosZ | SignedAxis::NegZ => Axis::AxisZ,
+        }
     }
-}
 
+    pub fn is_positive(&self) -> bool { matches!(self, SignedAxis::PosX | SignedAxis::PosY | SignedAxis::PosZ) }
 
-impl LineVector for Vector3d {
-    fn add(&self, other: &Self) -> Self {
-        Vector3d(self.0 + other.0)
-    }
+    /// `1.0` for the positive axes, `-1.0` for the negative ones.
+    pub fn sign(&self) -> f64 { if self.is_positive() { 1.0 } else { -1.0 } }
 
-    fn sub(&self, other: &Self) -> Self {
-        Vector3d(self.0 - other.0)
-    }
+    /// Unit vector along the axis in global coordinates.
+    pub fn to_vector3d(&self) -> Vector3d { Vector3d(self.axis().to_vector3d().0 * self.sign()) }
 
-    fn scale(&self, factor: f64) -> Self {
-        Vector3d(self.0 * factor)
+    /// Axis `vector` points along, if it is parallel to one. The length does
+    /// not matter; a zero vector gives `None`.
+    pub fn from_vector3d(vector: &Vector3d) -> Option<Self> {
+        let norm = vector.0.norm();
+        if norm <= epsilon() {
+            return None;
+        }
+        let (index, component) = vector.0.iter().enumerate().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+        let axis = [Axis::AxisX, Axis::AxisY, Axis::AxisZ][index];
+        ((norm - component.abs()) <= epsilon() * norm).then(|| SignedAxis::new(axis, *component > 0.0))
     }
+}
 
-    fn dot(&self, other: &Self) -> f64 {
-        self.0.dot(&other.0)
-    }
+impl From<Axis> for SignedAxis {
+    fn from(axis: Axis) -> Self { SignedAxis::new(axis, true) }
+}
 
-    fn norm(&self) -> f64 {
-        self.0.norm()
-    }
+impl From<SignedAxis> for Vector3d {
+    fn from(axis: SignedAxis) -> Self { axis.to_vector3d() }
+}
 
-    fn normalize(&self) -> Self {
-        Vector3d(self.0.normalize())
-    }
+impl std::ops::Neg for Axis {
+    type Output = SignedAxis;
+    fn neg(self) -> SignedAxis { SignedAxis::new(self, false) }
+}
 
-    fn component_min(&self, other: &Self) -> Self {
-        Vector3d(self.0.zip_map(&other.0, |a, b| a.min(b)))
-    }
+impl std::ops::Neg for SignedAxis {
+    type Output = SignedAxis;
+    fn neg(self) -> SignedAxis { SignedAxis::new(self.axis(), !self.is_positive()) }
+}
 
-    fn component_max(&self, other: &Self) -> Self {
-        Vector3d(self.0.zip_map(&other.0, |a, b| a.max(b)))
-    }
+/// One arm of an orientation triad: a segment from the frame origin along a
+/// local axis, in global coordinates, with the color of that axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriadLine {
+    pub axis: Axis,
+    pub line: Line3d,
+    pub color: [u8; 3],
+}
 
-    fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool {
-        self.is_approx(other, precision)
-    }
+/// Internal trait to abstract over 2D and 3D vector behaviour for lines.
+pub trait LineVector: Copy {
+    /// Precision the line computes in.
+    type Scalar: Scalar;
+
+    fn add(&self, other: &Self) -> Self;
+    fn sub(&self, other: &Self) -> Self;
+    fn scale(&self, factor: Self::Scalar) -> Self;
+    fn dot(&self, other: &Self) -> Self::Scalar;
+    fn norm(&self) -> Self::Scalar;
+    fn normalize(&self) -> Self;
+    fn component_min(&self, other: &Self) -> Self;
+    fn component_max(&self, other: &Self) -> Self;
+    fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool;
 }
 
+macro_rules! impl_line_vector {
+    ($vector:ident) => {
+        impl<T: Scalar> LineVector for $vector<T> {
+            type Scalar = T;
+
+            fn add(&self, other: &Self) -> Self { $vector(self.0 + other.0) }
+            fn sub(&self, other: &Self) -> Self { $vector(self.0 - other.0) }
+            fn scale(&self, factor: T) -> Self { $vector(self.0 * factor) }
+            fn dot(&self, other: &Self) -> T { self.0.dot(&other.0) }
+            fn norm(&self) -> T { self.0.norm() }
+            fn normalize(&self) -> Self { $vector(self.0.normalize()) }
+            fn component_min(&self, other: &Self) -> Self { $vector(self.0.zip_map(&other.0, |a, b| a.min(b))) }
+            fn component_max(&self, other: &Self) -> Self { $vector(self.0.zip_map(&other.0, |a, b| a.max(b))) }
+            fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool { self.is_approx(other, precision) }
+        }
+    };
+}
+
+impl_line_vector!(Vector2d);
+impl_line_vector!(Vector3d);
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line<V>
 where
     V: LineVector,
@@ -126,71 +189,84 @@ where
     pub fn direction(&self) -> Option<V> {
         let dir = self.end.sub(&self.start);
         let length = dir.norm();
-        if length <= epsilon() {
+        if length <= V::Scalar::epsilon() {
             None
         } else {
             Some(dir.normalize())
         }
     }
 
-    pub fn length(&self) -> f64 {
+    pub fn length(&self) -> V::Scalar {
         self.end.sub(&self.start).norm()
     }
 
     pub fn midpoint(&self) -> V {
-        self.start.add(&self.end).scale(0.5)
+        self.start.add(&self.end).scale(V::Scalar::from_f64_lossy(0.5))
     }
 
-    pub fn point_at(&self, t: f64) -> V {
+    pub fn point_at(&self, t: V::Scalar) -> V {
         let dir = self.end.sub(&self.start);
         self.start.add(&dir.scale(t))
     }
 
+    /// Point at arc length `length` from the start, clamped to the segment,
+    /// e.g. a station every 0.5 m along a member.
+    pub fn point_at_length(&self, length: V::Scalar) -> V { self.point_at(self.parameter_at_length(length)) }
+
+    /// Parameter `t` of the point at arc length `length` from the start,
+    /// clamped to `[0, 1]`; 0 on a degenerate segment.
+    pub fn parameter_at_length(&self, length: V::Scalar) -> V::Scalar { crate::curve::parameter_at_length(length, self.length()) }
+
     pub fn closest_point(&self, point: &V) -> V {
         let dir = self.end.sub(&self.start);
         let len_sq = dir.dot(&dir);
-        if len_sq <= epsilon() {
+        if len_sq <= V::Scalar::epsilon() {
             return self.start;
         }
 
         let to_point = point.sub(&self.start);
-        let mut t = dir.dot(&to_point) / len_sq;
-        t = t.clamp(0.0, 1.0);
-        self.point_at(t)
+        let t = dir.dot(&to_point) / len_sq;
+        self.point_at(t.clamp(V::Scalar::zero(), V::Scalar::one()))
     }
 
     pub fn projection(&self, point: &V) -> V {
         self.closest_point(point)
     }
 
-    pub fn distance(&self, point: &V) -> f64 {
+    pub fn distance(&self, point: &V) -> V::Scalar {
         let closest = self.closest_point(point);
         point.sub(&closest).norm()
     }
 
-    pub fn contains(&self, point: &V) -> bool {
+    /// Whether `point` lies within `tolerance` of the segment, [`Scalar::epsilon`] by default.
+    pub fn contains(&self, point: &V, tolerance: Option<f64>) -> bool {
+        let tolerance = V::Scalar::tolerance(tolerance);
         let closest = self.closest_point(point);
-        if !closest.is_approx(point, Some(epsilon())) {
+        if !closest.is_approx(point, Some(tolerance.as_f64())) {
             return false;
         }
 
         let dir = self.end.sub(&self.start);
         let len_sq = dir.dot(&dir);
-        if len_sq <= epsilon() {
-            return point.is_approx(&self.start, Some(epsilon()));
+        if len_sq <= tolerance * tolerance {
+            return point.is_approx(&self.start, Some(tolerance.as_f64()));
         }
 
         let to_point = point.sub(&self.start);
+        let slack = tolerance / len_sq.sqrt();
         let t = dir.dot(&to_point) / len_sq;
-        t >= -epsilon() && t <= 1.0 + epsilon()
+        t >= -slack && t <= V::Scalar::one() + slack
     }
 
-    pub fn bounding_box(&self) -> (V, V) {
-        (self.start.component_min(&self.end), self.start.component_max(&self.end))
+    pub fn bounding_box(&self) -> crate::BoundingBox3d
+    where
+        V: crate::arc::ArcVector,
+    {
+        crate::BoundingBox3d::new(crate::arc::widened(&self.start), crate::arc::widened(&self.end))
     }
 
-    pub fn break_at(&self, parameter: f64) -> Vec<Self> {
-        if parameter <= 0.0 || parameter >= 1.0 {
+    pub fn break_at(&self, parameter: V::Scalar) -> Vec<Self> {
+        if parameter <= V::Scalar::zero() || parameter >= V::Scalar::one() {
             return vec![*self];
         }
 
@@ -199,17 +275,40 @@ where
     }
 
     pub fn break_at_point(&self, point: &V) -> Vec<Self> {
-        if !self.contains(point) {
+        if !self.contains(point, None) {
             return vec![*self];
         }
 
-        if point.is_approx(&self.start, Some(epsilon())) || point.is_approx(&self.end, Some(epsilon())) {
+        if point.is_approx(&self.start, None) || point.is_approx(&self.end, None) {
             return vec![*self];
         }
 
         vec![Self::new(self.start, *point), Self::new(*point, self.end)]
     }
 
+    /// Split at every point lying on the line, e.g. where several members
+    /// cross it. Points are taken in order from the start; points off the line,
+    /// at its ends or repeated within tolerance are ignored.
+    pub fn break_at_points(&self, points: &[V]) -> Vec<Self> {
+        let (length, eps) = (self.length(), V::Scalar::epsilon());
+        let mut cuts: Vec<(V::Scalar, V)> = points
+            .iter()
+            .filter_map(|point| self.point_parameter(point).map(|t| (t, *point)))
+            .filter(|&(t, _)| t * length > eps && (V::Scalar::one() - t) * length > eps)
+            .collect();
+        cuts.sort_by(|a, b| a.0.as_f64().total_cmp(&b.0.as_f64()));
+        cuts.dedup_by(|b, a| (b.0 - a.0) * length <= eps);
+
+        let mut start = self.start;
+        let mut segments = Vec::with_capacity(cuts.len() + 1);
+        for (_, point) in cuts {
+            segments.push(Self::new(start, point));
+            start = point;
+        }
+        segments.push(Self::new(start, self.end));
+        segments
+    }
+
     pub fn move_start(&mut self, start: V) {
         self.start = start;
         self.orientation = None;
@@ -229,27 +328,40 @@ where
         Self::new(self.end, self.start)
     }
 
-    pub fn length_at_point(&self, point: &V) -> f64 {
+    /// Line lengthened by `start_by` beyond its start and `end_by` beyond its
+    /// end; negative distances shorten it. The orientation is kept. Zero-length
+    /// lines have no direction and are returned unchanged.
+    pub fn extended(&self, start_by: V::Scalar, end_by: V::Scalar) -> Self {
+        let Some(direction) = self.direction() else { return *self };
+        Self { start: self.start.sub(&direction.scale(start_by)), end: self.end.add(&direction.scale(end_by)), ..*self }
+    }
+
+    pub fn length_at_point(&self, point: &V) -> V::Scalar {
         let proj = self.closest_point(point);
         proj.sub(&self.start).norm()
     }
 
-    pub fn point_parameter(&self, point: &V) -> Option<f64> {
-        if !self.contains(point) {
+    pub fn point_parameter(&self, point: &V) -> Option<V::Scalar> {
+        if !self.contains(point, None) {
             return None;
         }
 
         let dir = self.end.sub(&self.start);
         let len_sq = dir.dot(&dir);
-        if len_sq <= epsilon() {
-            return Some(0.0);
+        if len_sq <= V::Scalar::epsilon() {
+            return Some(V::Scalar::zero());
         }
 
         let to_point = point.sub(&self.start);
-        Some((dir.dot(&to_point) / len_sq).clamp(0.0, 1.0))
+        Some((dir.dot(&to_point) / len_sq).clamp(V::Scalar::zero(), V::Scalar::one()))
     }
 
-    pub fn intersection(&self, other: &Self, treat_as_ray: bool) -> Option<V> {
+    /// Point where two segments cross, or `None` when they miss by more than
+    /// `tolerance` ([`Scalar::epsilon`] by default); collinear segments report the
+    /// start of `other` when it lies on `self`.
+    pub fn intersection(&self, other: &Self, tolerance: Option<f64>) -> Option<V> {
+        let tolerance = V::Scalar::tolerance(tolerance);
+        let (zero, one, eps) = (V::Scalar::zero(), V::Scalar::one(), V::Scalar::epsilon());
         let dir1 = self.end.sub(&self.start);
         let dir2 = other.end.sub(&other.start);
 
@@ -260,45 +372,171 @@ where
         let c = dir1.dot(&r);
         let f = dir2.dot(&r);
 
+        // a·e - b² = a·e·sin²θ, so compare against a·e to test the angle alone.
         let denom = a * e - b * b;
-        if denom.abs() <= epsilon() {
-            if self.contains(&other.start) {
+        if denom <= eps * eps * a * e {
+            if self.contains(&other.start, Some(tolerance.as_f64())) {
                 return Some(other.start);
             }
             return None;
         }
 
-    let mut s = (b * f - c * e) / denom;
-    let t = (a * f - b * c) / denom;
-
-        if !treat_as_ray {
-            if s < -epsilon() || s > 1.0 + epsilon() || t < -epsilon() || t > 1.0 + epsilon() {
-                return None;
-            }
-        } else {
-            // Self treated as ray; only require s >= 0 (allow tiny negative due to FP)
-            let tol = 1e-9_f64;
-            if s < -tol { return None; }
-            if s < 0.0 { s = 0.0; }
-            // Do not constrain t: treat the other as an infinite line
+        let s = (b * f - c * e) / denom;
+        let t = (a * f - b * c) / denom;
+        let (slack_s, slack_t) = (tolerance / a.sqrt(), tolerance / e.sqrt());
+        if s < zero - slack_s || s > one + slack_s || t < zero - slack_t || t > one + slack_t {
+            return None;
         }
 
         let point_on_self = self.start.add(&dir1.scale(s));
         let point_on_other = other.start.add(&dir2.scale(t));
 
-        if point_on_self.is_approx(&point_on_other, Some(epsilon())) {
+        if point_on_self.is_approx(&point_on_other, Some(tolerance.as_f64())) {
             Some(point_on_self)
         } else {
             None
         }
     }
 
-    pub fn ray_intersection(&self, other: &Self) -> Option<V> {
-        self.intersection(other, true)
+    /// Closest points between two segments and their distance, as
+    /// `(point on self, point on other, distance)`.
+    ///
+    /// Handles skew, intersecting (distance zero) and parallel segments; for
+    /// parallel overlapping segments one of the equally close pairs is returned.
+    /// Degenerate (zero-length) segments are treated as points.
+    pub fn closest_points(&self, other: &Self) -> (V, V, V::Scalar) {
+        let (zero, one, eps) = (V::Scalar::zero(), V::Scalar::one(), V::Scalar::epsilon());
+        let d1 = self.end.sub(&self.start);
+        let d2 = other.end.sub(&other.start);
+        let r = self.start.sub(&other.start);
+        let a = d1.dot(&d1);
+        let e = d2.dot(&d2);
+        let f = d2.dot(&r);
+
+        let (s, t) = if a <= eps && e <= eps {
+            (zero, zero)
+        } else if a <= eps {
+            (zero, (f / e).clamp(zero, one))
+        } else {
+            let c = d1.dot(&r);
+            if e <= eps {
+                ((-c / a).clamp(zero, one), zero)
+            } else {
+                let b = d1.dot(&d2);
+                let denom = a * e - b * b;
+                // Parallel segments: any s works, start from the beginning of self.
+                let mut s = if denom > eps * a * e { ((b * f - c * e) / denom).clamp(zero, one) } else { zero };
+                let mut t = (b * s + f) / e;
+                if t < zero {
+                    t = zero;
+                    s = (-c / a).clamp(zero, one);
+                } else if t > one {
+                    t = one;
+                    s = ((b - c) / a).clamp(zero, one);
+                }
+                (s, t)
+            }
+        };
+
+        let p = self.point_at(s);
+        let q = other.point_at(t);
+        (p, q, p.sub(&q).norm())
+    }
+
+    /// Minimum distance between two segments.
+    pub fn distance_to_line(&self, other: &Self) -> V::Scalar {
+        self.closest_points(other).2
+    }
+}
+
+impl<T: Scalar> Line<Vector2d<T>> {
+    /// Parallel segment shifted by `distance` to the left of the line; negative
+    /// distances shift to the right. `None` for a degenerate line.
+    pub fn offset(&self, distance: T) -> Option<Self> {
+        let shift = self.direction()?.perp() * distance;
+        Some(Self { start: self.start + shift, end: self.end + shift, ..*self })
     }
 }
 
 impl Line<Vector3d> {
+    /// Point where the segment crosses `plane`; `None` when it stays on one side
+    /// or runs parallel to the plane.
+    pub fn intersection_with_plane(&self, plane: &crate::Plane) -> Option<Vector3d> {
+        plane.intersection_with_line(self)
+    }
+
+    /// Parallel segment shifted by `distance` within `plane`, to the left of the
+    /// line seen from the side the normal points to; negative distances shift
+    /// to the right. `None` when the line is degenerate or normal to the plane.
+    pub fn offset_in_plane(&self, plane: &crate::Plane, distance: f64) -> Option<Self> {
+        let normal = plane.normal().0;
+        let along = self.end.0 - self.start.0;
+        let side = normal.cross(&(along - normal * normal.dot(&along)));
+        if side.norm() <= epsilon() * along.norm().max(1.0) {
+            return None;
+        }
+        let shift = Vector3d(side.normalize() * distance);
+        Some(Self { start: self.start + shift, end: self.end + shift, ..*self })
+    }
+
+    /// Line with its end nearer to `plane` moved onto it, extending the line to
+    /// a support face or trimming the part beyond it. `None` when the line runs
+    /// parallel to the plane.
+    pub fn trim_to_plane(&self, plane: &crate::Plane) -> Option<Self> {
+        let point = self.point_at(plane.line_parameter(self)?);
+        let mut trimmed = *self;
+        if plane.distance(&self.start) <= plane.distance(&self.end) {
+            trimmed.start = point;
+        } else {
+            trimmed.end = point;
+        }
+        Some(trimmed)
+    }
+
+    /// Parts of the line inside `polygon`, in order from the start, e.g. a beam
+    /// trimmed to the slab edge. Parts running along the boundary count as
+    /// inside. Empty when the line misses the polygon or leaves its plane.
+    pub fn trim_to_polygon(&self, polygon: &crate::Polygon) -> Vec<Self> {
+        let plane = polygon.plane();
+        if !plane.contains(&self.start, None) || !plane.contains(&self.end, None) {
+            return Vec::new();
+        }
+        let length_sq = self.length() * self.length();
+        if length_sq <= epsilon() {
+            return Vec::new();
+        }
+        let parameter = |point: Vector3d| (point.0 - self.start.0).dot(&(self.end.0 - self.start.0)) / length_sq;
+        let mut cuts = vec![0.0, 1.0];
+        for edge in polygon.boundary_lines() {
+            cuts.extend(self.intersection(&edge, None).map(parameter));
+            if self.contains(&edge.start(), None) {
+                cuts.push(parameter(edge.start()));
+            }
+        }
+        cuts.retain(|t| (0.0..=1.0).contains(t));
+        cuts.sort_by(f64::total_cmp);
+        cuts.dedup_by(|a, b| (*a - *b).abs() <= epsilon());
+
+        let mut parts: Vec<Self> = Vec::new();
+        for pair in cuts.windows(2) {
+            let middle = self.point_at(0.5 * (pair[0] + pair[1]));
+            if !polygon.contains(&middle, None) && !polygon.border_contains(&middle, None) {
+                continue;
+            }
+            let end = self.point_at(pair[1]);
+            match parts.last_mut() {
+                Some(last) if last.end.is_approx(&self.point_at(pair[0]), Some(epsilon())) => last.end = end,
+                _ => parts.push(Self { start: self.point_at(pair[0]), end, ..*self }),
+            }
+        }
+        parts
+    }
+
+    /// Smallest sphere enclosing the segment: centered at its midpoint.
+    pub fn bounding_sphere(&self) -> crate::Sphere {
+        crate::Sphere::new(Vector3d((self.start.0 + self.end.0) / 2.0), self.length() / 2.0)
+    }
+
     /// Builds a rotation matrix whose first column aligns with the line direction and
     /// whose remaining columns provide an orthonormal frame around the tangent.
     pub fn rotation_matrix(&self) -> Option<nalgebra::Matrix3<f64>> {
@@ -360,29 +598,12 @@ impl Line<Vector3d> {
     /// Rotate the local axis frame around a global-space axis vector that passes
     /// through the line start point.
     pub fn rotate(&mut self, angle: f64, axis: [f64; 3]) {
-        use nalgebra::{Matrix3, Rotation3, Unit, Vector3};
-
-        let base_mat: Matrix3<f64> = match self.rotation_matrix() {
-            Some(m) => m,
-            None => return,
-        };
-        let axis_vec = Vector3::new(axis[0], axis[1], axis[2]);
-        if axis_vec.norm_squared() <= epsilon() * epsilon() {
+        let Some(frame) = self.rotation_matrix() else { return };
+        if nalgebra::Vector3::from(axis).norm() <= epsilon() {
             return;
         }
-        let unit_axis = match Unit::try_new(axis_vec, epsilon()) {
-            Some(axis) => axis,
-            None => return,
-        };
-        let incremental = Rotation3::from_axis_angle(&unit_axis, angle);
-        let new_x = incremental * base_mat.column(0).into_owned();
-        let new_y = incremental * base_mat.column(1).into_owned();
-        let new_z = incremental * base_mat.column(2).into_owned();
-        let updated = Matrix3::from_columns(&[new_x, new_y, new_z]);
-
-        let mut stored = [0.0_f64; 9];
-        stored.copy_from_slice(updated.as_slice());
-        self.orientation = Some(stored);
+        let turned = crate::Transform3d::rotation(angle, axis).apply_frame(&frame);
+        self.orientation = Some(turned.as_slice().try_into().expect("3x3 matrix"));
     }
 
     /// Converts a global-space point into the line's local coordinate frame where the
@@ -414,19 +635,60 @@ impl Line<Vector3d> {
     ///
     /// Example: `line.axis(Axis::AxisX)` returns the unit vector pointing along
     /// the line tangent (the local X axis) expressed in global coordinates.
-    pub fn axis(&self, axis: Axis) -> Option<Vector3d> {
+    pub fn axis<A: Into<SignedAxis>>(&self, axis: A) -> Option<Vector3d> {
         let rotation = self.rotation_matrix()?;
         // rotation columns are the local basis expressed in global coordinates
-        match axis {
-            Axis::AxisX => Some(Vector3d::new(rotation.column(0)[0], rotation.column(0)[1], rotation.column(0)[2])),
-            Axis::AxisY => Some(Vector3d::new(rotation.column(1)[0], rotation.column(1)[1], rotation.column(1)[2])),
-            Axis::AxisZ => Some(Vector3d::new(rotation.column(2)[0], rotation.column(2)[1], rotation.column(2)[2])),
+        Some(Vector3d(rotation * axis.into().to_vector3d().0))
+    }
+}
+
+impl<V: crate::arc::ArcVector> crate::Curve<V> for Line<V> {
+    fn start(&self) -> V { self.start }
+    fn end(&self) -> V { self.end }
+    fn length(&self) -> V::Scalar { Line::length(self) }
+    fn point_at(&self, t: V::Scalar) -> V { Line::point_at(self, t) }
+    /// Zero for a degenerate line.
+    fn tangent_at(&self, _t: V::Scalar) -> V { self.direction().unwrap_or_else(|| self.start.sub(&self.start)) }
+    fn closest_point(&self, point: &V) -> V { Line::closest_point(self, point) }
+    fn bounding_box(&self) -> crate::BoundingBox3d { Line::bounding_box(self) }
+    fn linearized(&self, _segments: usize) -> Vec<Line<V>> { vec![*self] }
+}
+
+impl<V: LineVector> crate::DistanceTo<Line<V>, V> for Line<V> {
+    fn distance_to(&self, other: &Line<V>) -> (V, V, V::Scalar) { self.closest_points(other) }
+}
+
+impl<V: crate::arc::ArcVector> crate::DistanceTo<crate::arc::Arc<V>, V> for Line<V> {
+    fn distance_to(&self, arc: &crate::arc::Arc<V>) -> (V, V, V::Scalar) {
+        let (on_arc, on_line, distance) = crate::DistanceTo::distance_to(arc, self);
+        (on_line, on_arc, distance)
+    }
+}
+
+impl<V: crate::arc::ArcVector> crate::DistanceTo<crate::polygon::Polygon<V>, V> for Line<V> {
+    fn distance_to(&self, polygon: &crate::polygon::Polygon<V>) -> (V, V, V::Scalar) {
+        let (on_polygon, on_line, distance) = crate::DistanceTo::distance_to(polygon, self);
+        (on_line, on_polygon, distance)
+    }
+}
+
+impl crate::Transformable for Line<Vector3d> {
+    /// Moves both endpoints; a stored orientation is carried along with
+    /// [`Transform3d::apply_frame`](crate::Transform3d::apply_frame), so its
+    /// x-axis keeps following the line and it stays right-handed under mirroring.
+    fn transform(&mut self, transform: &crate::Transform3d) {
+        self.start = transform.apply_point(&self.start);
+        self.end = transform.apply_point(&self.end);
+        if let Some(stored) = self.orientation {
+            let frame = transform.apply_frame(&nalgebra::Matrix3::from_column_slice(&stored));
+            self.orientation = Some(frame.as_slice().try_into().expect("3x3 matrix"));
         }
     }
 }
 
 /// Represents a local coordinate frame (origin + orthonormal axes) in 3D.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocalAxis {
     origin: Vector3d,
     // Columns form an orthonormal basis expressed in global coordinates
@@ -438,17 +700,63 @@ impl LocalAxis {
         Self { origin, rotation }
     }
 
+    /// Frame with the given axes, which are normalized. `None` unless they are
+    /// mutually orthogonal and right-handed.
+    pub fn from_axes(origin: Vector3d, ex: Vector3d, ey: Vector3d, ez: Vector3d) -> Option<Self> {
+        let axes = [ex, ey, ez].map(|axis| axis.0.try_normalize(epsilon()));
+        let [Some(ex), Some(ey), Some(ez)] = axes else { return None };
+        let orthogonal = ex.dot(&ey).abs() <= 1e-9 && ey.dot(&ez).abs() <= 1e-9 && ez.dot(&ex).abs() <= 1e-9;
+        (orthogonal && ex.cross(&ey).dot(&ez) > 0.0).then(|| Self::new(origin, nalgebra::Matrix3::from_columns(&[ex, ey, ez])))
+    }
+
+    /// Frame with local Z along `z` and local X as close to `x_hint` as
+    /// possible, e.g. a section frame from the member axis and a web direction.
+    /// `None` when `z` is zero or parallel to the hint.
+    pub fn from_z_and_x_hint(origin: Vector3d, z: Vector3d, x_hint: Vector3d) -> Option<Self> {
+        let ez = z.0.try_normalize(epsilon())?;
+        let ex = (x_hint.0 - ez * x_hint.0.dot(&ez)).try_normalize(epsilon() * x_hint.0.norm().max(1.0))?;
+        Some(Self::new(origin, nalgebra::Matrix3::from_columns(&[ex, ez.cross(&ex), ez])))
+    }
+
+    /// Frame rotated by the intrinsic X-Y-Z angles of [`LocalAxis::euler_angles`].
+    pub fn from_euler_angles(origin: Vector3d, roll: f64, pitch: f64, yaw: f64) -> Self {
+        Self::new(origin, *nalgebra::Rotation3::from_euler_angles(roll, pitch, yaw).matrix())
+    }
+
     pub fn origin(&self) -> Vector3d { self.origin }
+    pub fn rotation(&self) -> nalgebra::Matrix3<f64> { self.rotation }
 
-    /// Return global-space unit vector for the requested local axis.
-    pub fn direction(&self, axis: Axis) -> Vector3d {
-        match axis {
-            Axis::AxisX => Vector3d::new(self.rotation.column(0)[0], self.rotation.column(0)[1], self.rotation.column(0)[2]),
-            Axis::AxisY => Vector3d::new(self.rotation.column(1)[0], self.rotation.column(1)[1], self.rotation.column(1)[2]),
-            Axis::AxisZ => Vector3d::new(self.rotation.column(2)[0], self.rotation.column(2)[1], self.rotation.column(2)[2]),
-        }
+    /// Global frame of `inner`, a frame given in the coordinates of this one;
+    /// e.g. a section rotation inside a member frame. Nested frames compose
+    /// from the outside in.
+    pub fn compose(&self, inner: &Self) -> Self { Self::new(self.to_global(inner.origin), self.rotation * inner.rotation) }
+
+    /// The global frame expressed in this frame's coordinates, so that
+    /// `frame.compose(&frame.inverse())` is the identity.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.transpose();
+        Self::new(Vector3d(-(rotation * self.origin.0)), rotation)
+    }
+
+    /// Frame a fraction `t` of the way to `other`: the origin moves linearly and
+    /// the axes turn about a fixed axis at constant rate, the short way round.
+    /// `t` = 0 gives this frame, 1 gives `other`.
+    pub fn interpolate(&self, other: &Self, t: f64) -> Self {
+        use nalgebra::{Rotation3, UnitQuaternion};
+        let from = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(self.rotation));
+        let to = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(other.rotation));
+        // Equal rotations leave nothing to interpolate.
+        let rotation = from.try_slerp(&to, t, epsilon()).unwrap_or(from);
+        Self::new(Vector3d(self.origin.0.lerp(&other.origin.0, t)), *rotation.to_rotation_matrix().matrix())
     }
 
+    /// Angles `(roll, pitch, yaw)` with rotation = Rz(yaw) · Ry(pitch) · Rx(roll),
+    /// i.e. rolled about local X first. Pitch lies in [-π/2, π/2].
+    pub fn euler_angles(&self) -> (f64, f64, f64) { nalgebra::Rotation3::from_matrix_unchecked(self.rotation).euler_angles() }
+
+    /// Return global-space unit vector for the requested local axis.
+    pub fn direction<A: Into<SignedAxis>>(&self, axis: A) -> Vector3d { Vector3d(self.rotation * axis.into().to_vector3d().0) }
+
     /// Transform a global-space point to this local frame.
     pub fn to_local(&self, point: Vector3d) -> Vector3d {
         let offset = point.0 - self.origin.0;
@@ -461,6 +769,40 @@ impl LocalAxis {
         let global = self.origin.0 + self.rotation * local.0;
         Vector3d::new(global.x, global.y, global.z)
     }
+
+    /// Segments of length `scale` from the origin along the X, Y and Z axes.
+    pub fn triad_geometry(&self, scale: f64) -> [TriadLine; 3] {
+        [Axis::AxisX, Axis::AxisY, Axis::AxisZ].map(|axis| TriadLine {
+            axis,
+            line: Line::new(self.origin, Vector3d(self.origin.0 + self.direction(axis).0 * scale)),
+            color: axis.color(),
+        })
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for LocalAxis {
+    type Epsilon = f64;
+    fn default_epsilon() -> f64 { f64::default_epsilon() }
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.origin.abs_diff_eq(&other.origin, epsilon) && self.rotation.abs_diff_eq(&other.rotation, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for LocalAxis {
+    fn default_max_relative() -> f64 { f64::default_max_relative() }
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.origin.relative_eq(&other.origin, epsilon, max_relative) && self.rotation.relative_eq(&other.rotation, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::UlpsEq for LocalAxis {
+    fn default_max_ulps() -> u32 { f64::default_max_ulps() }
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.origin.ulps_eq(&other.origin, epsilon, max_ulps) && self.rotation.ulps_eq(&other.rotation, epsilon, max_ulps)
+    }
 }
 
 #[cfg(test)]
@@ -508,7 +850,7 @@ mod tests {
     fn intersection_segments() {
     let a = Line::<Vector3d>::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 4.0, 0.0));
     let b = Line::<Vector3d>::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(4.0, 0.0, 0.0));
-        let intersection = a.intersection(&b, false).expect("segments intersect");
+        let intersection = a.intersection(&b, None).expect("segments intersect");
         assert_almost_eq!(intersection.x(), 2.0);
         assert_almost_eq!(intersection.y(), 2.0);
         assert_almost_eq!(intersection.z(), 0.0);
@@ -518,7 +860,7 @@ mod tests {
     fn intersection_parallel_returns_none() {
     let a = Line::<Vector3d>::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0));
     let b = Line::<Vector3d>::new(Vector3d::new(0.0, 1.0, 0.0), Vector3d::new(4.0, 1.0, 0.0));
-        assert!(a.intersection(&b, false).is_none());
+        assert!(a.intersection(&b, None).is_none());
     }
 
     #[test]
@@ -534,28 +876,16 @@ mod tests {
     fn contains_and_length_at_point() {
     let line = Line::<Vector3d>::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 10.0));
         let point = Vector3d::new(0.0, 0.0, 7.5);
-        assert!(line.contains(&point));
+        assert!(line.contains(&point, None));
         assert_almost_eq!(line.length_at_point(&point), 7.5);
     }
 
-    #[test]
-    fn ray_intersection_requires_forward_parameters() {
-    let a = Line::<Vector3d>::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(5.0, 0.0, 0.0));
-    let b = Line::<Vector3d>::new(Vector3d::new(10.0, 0.0, 0.0), Vector3d::new(15.0, 0.0, 0.0));
-        assert!(a.ray_intersection(&b).is_none());
-    let c = Line::<Vector3d>::new(Vector3d::new(5.0, -5.0, 0.0), Vector3d::new(5.0, 5.0, 0.0));
-        let intersection = a.ray_intersection(&c).expect("rays meet");
-        assert_almost_eq!(intersection.x(), 5.0);
-        assert_almost_eq!(intersection.y(), 0.0);
-        assert_almost_eq!(intersection.z(), 0.0);
-    }
-
     #[test]
     fn contains_handles_degenerate_line() {
         let point = Vector3d::new(1.0, 1.0, 0.0);
     let line = Line::<Vector3d>::new(point, point);
-        assert!(line.contains(&point));
-        assert!(line.contains(&Vector3d::new(1.0 + DEFAULT_EPSILON / 2.0, 1.0, 0.0)));
+        assert!(line.contains(&point, None));
+        assert!(line.contains(&Vector3d::new(1.0 + DEFAULT_EPSILON / 2.0, 1.0, 0.0), None));
     }
 
     #[test]
@@ -0,0 +1,572 @@
+//! Triangular meshing of planar polygons: constrained Delaunay triangulation of
+//! the boundary and holes, optional interior points, and refinement to a
+//! maximum edge length. The resulting [`TriMesh`] is the common container for
+//! triangulated surfaces, e.g. extruded solids.
+
+use std::collections::{HashMap, HashSet};
+
+use nalgebra::Vector2;
+use utils::epsilon;
+
+use crate::spatial::{Bounded, Bvh};
+use crate::{Polygon, Vector3d};
+
+/// Input to [`triangulate`] besides the polygon.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshOptions {
+    max_edge_length: Option<f64>,
+    points: Vec<Vector3d>,
+}
+
+impl MeshOptions {
+    pub fn new() -> Self { Self::default() }
+
+    /// Split boundary edges and refine the interior until no edge is longer
+    /// than `length`. Panics unless `length` is positive.
+    pub fn with_max_edge_length(mut self, length: f64) -> Self {
+        assert!(length > 0.0, "maximum edge length must be positive");
+        self.max_edge_length = Some(length);
+        self
+    }
+
+    /// Points the mesh must contain, e.g. column heads under a slab. They are
+    /// projected onto the polygon plane; points outside the polygon or on its
+    /// boundary are ignored.
+    pub fn with_points<I: IntoIterator<Item = Vector3d>>(mut self, points: I) -> Self {
+        self.points.extend(points);
+        self
+    }
+
+    pub fn max_edge_length(&self) -> Option<f64> { self.max_edge_length }
+    pub fn points(&self) -> &[Vector3d] { &self.points }
+}
+
+/// Triangles over a shared vertex list. Triangles run counterclockwise about
+/// their face normal; meshes from [`triangulate`] face along the normal of the
+/// polygon they were meshed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriMesh {
+    vertices: Vec<Vector3d>,
+    triangles: Vec<[usize; 3]>,
+}
+
+#[deprecated(note = "renamed to `TriMesh`")]
+pub type TriangleMesh = TriMesh;
+
+/// Nearest crossing of a ray with a [`TriMesh`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    pub triangle: usize,
+    /// Distance from the ray origin, in units of the ray direction.
+    pub distance: f64,
+    pub point: Vector3d,
+}
+
+impl TriMesh {
+    /// Mesh from a vertex buffer and triangles indexing into it. Panics on an
+    /// index out of range.
+    pub fn new(vertices: Vec<Vector3d>, triangles: Vec<[usize; 3]>) -> Self {
+        assert!(triangles.iter().flatten().all(|&v| v < vertices.len()), "triangle vertex index out of range");
+        Self { vertices, triangles }
+    }
+
+    pub fn vertices(&self) -> &[Vector3d] { &self.vertices }
+    pub fn triangles(&self) -> &[[usize; 3]] { &self.triangles }
+
+    pub fn triangle(&self, index: usize) -> [Vector3d; 3] { self.triangles[index].map(|v| self.vertices[v]) }
+
+    /// Unit normal of a triangle by the right-hand rule on its vertex order;
+    /// zero for a degenerate triangle.
+    pub fn face_normal(&self, index: usize) -> Vector3d {
+        let [a, b, c] = self.triangle(index);
+        Vector3d((b.0 - a.0).cross(&(c.0 - a.0)).try_normalize(epsilon()).unwrap_or_default())
+    }
+
+    pub fn face_normals(&self) -> Vec<Vector3d> { (0..self.triangles.len()).map(|i| self.face_normal(i)).collect() }
+
+    pub fn area(&self) -> f64 {
+        (0..self.triangles.len())
+            .map(|i| {
+                let [a, b, c] = self.triangle(i);
+                (b.0 - a.0).cross(&(c.0 - a.0)).norm() / 2.0
+            })
+            .sum()
+    }
+
+    /// Whether every directed edge is matched by its reverse exactly once: the
+    /// surface is closed and consistently oriented.
+    pub fn is_closed(&self) -> bool {
+        let mut directed: HashMap<(usize, usize), usize> = HashMap::new();
+        for t in &self.triangles {
+            for i in 0..3 {
+                *directed.entry((t[i], t[(i + 1) % 3])).or_insert(0) += 1;
+            }
+        }
+        directed.iter().all(|(&(a, b), &count)| count == 1 && directed.get(&(b, a)) == Some(&1))
+    }
+
+    /// Volume enclosed by a closed mesh, positive when the triangles face
+    /// outwards. `None` for an open or inconsistently oriented mesh.
+    pub fn volume(&self) -> Option<f64> {
+        if !self.is_closed() {
+            return None;
+        }
+        let volume = (0..self.triangles.len())
+            .map(|i| {
+                let [a, b, c] = self.triangle(i);
+                a.0.dot(&b.0.cross(&c.0)) / 6.0
+            })
+            .sum();
+        Some(volume)
+    }
+
+    /// Nearest triangle hit by the ray from `origin` along `direction`, from
+    /// either side; hits behind the origin are ignored.
+    pub fn ray_intersection(&self, origin: &Vector3d, direction: &Vector3d) -> Option<RayHit> {
+        let mut nearest: Option<RayHit> = None;
+        for triangle in 0..self.triangles.len() {
+            // Möller-Trumbore.
+            let [a, b, c] = self.triangle(triangle);
+            let (ab, ac) = (b.0 - a.0, c.0 - a.0);
+            let p = direction.0.cross(&ac);
+            let determinant = ab.dot(&p);
+            if determinant.abs() <= epsilon() * ab.norm() * ac.norm() * direction.0.norm() {
+                continue;
+            }
+            let s = origin.0 - a.0;
+            let u = s.dot(&p) / determinant;
+            let q = s.cross(&ab);
+            let v = direction.0.dot(&q) / determinant;
+            let distance = ac.dot(&q) / determinant;
+            let tolerance = epsilon();
+            if u < -tolerance || v < -tolerance || u + v > 1.0 + tolerance || distance < -tolerance {
+                continue;
+            }
+            if nearest.is_none_or(|hit| distance < hit.distance) {
+                nearest = Some(RayHit { triangle, distance, point: Vector3d(origin.0 + direction.0 * distance) });
+            }
+        }
+        nearest
+    }
+
+    /// Unique edges as sorted vertex pairs, in ascending order.
+    pub fn edges(&self) -> Vec<[usize; 2]> {
+        let mut edges: Vec<[usize; 2]> = self
+            .triangles
+            .iter()
+            .flat_map(|t| (0..3).map(move |i| [t[i].min(t[(i + 1) % 3]), t[i].max(t[(i + 1) % 3])]))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+}
+
+/// Constrained Delaunay triangulation of `polygon`, holes included. Boundary
+/// vertices come first in the mesh, outer ring then holes, followed by the
+/// interior points and the points added by refinement.
+pub fn triangulate(polygon: &Polygon, options: &MeshOptions) -> TriMesh {
+    let local = |point: &Vector3d| {
+        let p = polygon.to_local(*point);
+        Vector2::new(p.x(), p.y())
+    };
+    let size = polygon.bounding_box().extent().norm();
+    let tolerance = 1e-9 * size;
+
+    // Boundary points, with every ring edge split to respect the edge length.
+    let mut global: Vec<Vector3d> = Vec::new();
+    let mut segments: Vec<(usize, usize)> = Vec::new();
+    let mut rings: Vec<Vec<Vector2<f64>>> = Vec::new();
+    for ring in std::iter::once(polygon.vertices()).chain(polygon.holes().iter()) {
+        let first = global.len();
+        for (i, start) in ring.iter().enumerate() {
+            let end = ring[(i + 1) % ring.len()];
+            let parts = options.max_edge_length.map_or(1, |h| ((end.0 - start.0).norm() / h).ceil().max(1.0) as usize);
+            for k in 0..parts {
+                global.push(Vector3d(start.0.lerp(&end.0, k as f64 / parts as f64)));
+            }
+        }
+        let count = global.len() - first;
+        segments.extend((0..count).map(|i| (first + i, first + (i + 1) % count)));
+        rings.push(ring.iter().map(local).collect());
+    }
+
+    let boundary = Bvh::new(global.clone());
+    for point in &options.points {
+        let offset = polygon.to_local(*point);
+        let projected = polygon.to_global(Vector3d::new(offset.x(), offset.y(), 0.0));
+        let on_boundary = polygon.boundary_lines().iter().any(|line| line.distance(&projected) <= tolerance);
+        let duplicate = global.iter().skip(boundary.len()).any(|p| p.distance(&projected) <= tolerance)
+            || !boundary.within(&projected, tolerance).is_empty();
+        if !on_boundary && !duplicate && inside(&rings, &local(&projected)) {
+            global.push(projected);
+        }
+    }
+
+    let mut mesh = Triangulation::new(global.iter().map(local).collect());
+    for vertex in 0..global.len() {
+        mesh.insert(vertex + SUPER);
+    }
+    for &(a, b) in &segments {
+        mesh.constrain(a + SUPER, b + SUPER);
+    }
+    mesh.remove_where(|points, [a, b, c]| !inside(&rings, &((points[a] + points[b] + points[c]) / 3.0)));
+
+    if let Some(h) = options.max_edge_length {
+        mesh.refine(h * (1.0 + 1e-9), |point| global.push(polygon.to_global(Vector3d::new(point.x, point.y, 0.0))));
+    }
+    mesh.into_mesh(&global)
+}
+
+impl Polygon {
+    /// Closed triangulated surface of the prism swept by moving the polygon
+    /// `length` along `direction`: both caps and two triangles per side quad,
+    /// holes included. Triangles run counterclockwise seen from outside. The
+    /// start cap vertices come first, followed by the end cap in the same order.
+    /// Panics on a non-positive length or a direction in the polygon plane.
+    pub fn extrude(&self, direction: Vector3d, length: f64) -> TriMesh {
+        assert!(length > 0.0, "extrusion length must be positive");
+        let normal = self.direction(crate::Axis::AxisZ).0;
+        let offset = direction.0.normalize() * length;
+        let along = offset.dot(&normal);
+        assert!(along.abs() > epsilon() * length, "extrusion direction must leave the polygon plane");
+
+        let cap = triangulate(self, &MeshOptions::new());
+        let count = cap.vertices.len();
+        let mut vertices = cap.vertices.clone();
+        vertices.extend(cap.vertices.iter().map(|v| Vector3d(v.0 + offset)));
+
+        // Cap triangles face along the normal; the cap facing away from the
+        // extrusion is reversed.
+        let mut triangles = Vec::with_capacity(2 * cap.triangles.len() + 2 * count);
+        for &[a, b, c] in &cap.triangles {
+            if along > 0.0 {
+                triangles.push([a, c, b]);
+                triangles.push([a + count, b + count, c + count]);
+            } else {
+                triangles.push([a, b, c]);
+                triangles.push([a + count, c + count, b + count]);
+            }
+        }
+        let index = |point: &Vector3d| {
+            cap.vertices.iter().position(|v| (v.0 - point.0).norm() <= epsilon()).expect("ring vertex in cap mesh")
+        };
+        for ring in std::iter::once(self.vertices()).chain(self.holes().iter()) {
+            for (k, start) in ring.iter().enumerate() {
+                let (i, j) = (index(start), index(&ring[(k + 1) % ring.len()]));
+                // Outer rings run counterclockwise and holes clockwise about the
+                // normal, so edge × normal points out of the solid.
+                if along > 0.0 {
+                    triangles.extend([[i, j, j + count], [i, j + count, i + count]]);
+                } else {
+                    triangles.extend([[j, i, i + count], [j, i + count, j + count]]);
+                }
+            }
+        }
+        TriMesh { vertices, triangles }
+    }
+}
+
+/// Whether a local point lies inside the outer ring and outside every hole.
+fn inside(rings: &[Vec<Vector2<f64>>], point: &Vector2<f64>) -> bool {
+    let mut inside = false;
+    for ring in rings {
+        for (i, a) in ring.iter().enumerate() {
+            let b = ring[(i + 1) % ring.len()];
+            if (a.y > point.y) != (b.y > point.y) && point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+fn orient(a: &Vector2<f64>, b: &Vector2<f64>, c: &Vector2<f64>) -> f64 { (b - a).perp(&(c - a)) }
+
+/// Positive when `p` lies inside the circumcircle of the counterclockwise
+/// triangle `a b c`.
+fn in_circle(a: &Vector2<f64>, b: &Vector2<f64>, c: &Vector2<f64>, p: &Vector2<f64>) -> f64 {
+    let (a, b, c) = (a - p, b - p, c - p);
+    a.norm_squared() * b.perp(&c) + b.norm_squared() * c.perp(&a) + c.norm_squared() * a.perp(&b)
+}
+
+/// Whether segments `a b` and `c d` cross at a point interior to both.
+fn crosses(a: &Vector2<f64>, b: &Vector2<f64>, c: &Vector2<f64>, d: &Vector2<f64>) -> bool {
+    orient(a, b, c) * orient(a, b, d) < 0.0 && orient(c, d, a) * orient(c, d, b) < 0.0
+}
+
+/// Number of super-triangle corners, stored ahead of the input points.
+const SUPER: usize = 3;
+
+/// Counterclockwise triangles with a directed edge index for adjacency. The
+/// first three points are the corners of a super-triangle enclosing the input.
+struct Triangulation {
+    points: Vec<Vector2<f64>>,
+    triangles: Vec<Option<[usize; 3]>>,
+    edges: HashMap<(usize, usize), usize>,
+    constraints: HashSet<(usize, usize)>,
+    last: usize,
+}
+
+impl Triangulation {
+    fn new(input: Vec<Vector2<f64>>) -> Self {
+        let (low, high) = input.iter().fold(
+            (Vector2::repeat(f64::INFINITY), Vector2::repeat(f64::NEG_INFINITY)),
+            |(lo, hi), p| (lo.inf(p), hi.sup(p)),
+        );
+        let center = (low + high) / 2.0;
+        let size = (high - low).amax().max(f64::MIN_POSITIVE) * 100.0;
+        let mut points = vec![
+            center + Vector2::new(-size, -size),
+            center + Vector2::new(size, -size),
+            center + Vector2::new(0.0, size),
+        ];
+        points.extend(input);
+        let mut mesh = Self { points, triangles: Vec::new(), edges: HashMap::new(), constraints: HashSet::new(), last: 0 };
+        mesh.add([0, 1, 2]);
+        mesh
+    }
+
+    fn add(&mut self, triangle: [usize; 3]) -> usize {
+        let index = self.triangles.len();
+        for i in 0..3 {
+            self.edges.insert((triangle[i], triangle[(i + 1) % 3]), index);
+        }
+        self.triangles.push(Some(triangle));
+        self.last = index;
+        index
+    }
+
+    fn remove(&mut self, index: usize) -> [usize; 3] {
+        let triangle = self.triangles[index].take().expect("triangle is alive");
+        for i in 0..3 {
+            self.edges.remove(&(triangle[i], triangle[(i + 1) % 3]));
+        }
+        triangle
+    }
+
+    fn is_constrained(&self, a: usize, b: usize) -> bool { self.constraints.contains(&(a.min(b), a.max(b))) }
+
+    /// Triangle on the other side of the directed edge `a b`.
+    fn twin(&self, a: usize, b: usize) -> Option<usize> { self.edges.get(&(b, a)).copied() }
+
+    /// Triangle containing `p` (on its boundary included): a walk from the last
+    /// triangle, falling back to a scan when the walk leaves the mesh or cycles.
+    fn locate(&self, p: &Vector2<f64>) -> Option<usize> {
+        let contains = |[a, b, c]: [usize; 3]| {
+            let points = &self.points;
+            orient(&points[a], &points[b], p) >= 0.0
+                && orient(&points[b], &points[c], p) >= 0.0
+                && orient(&points[c], &points[a], p) >= 0.0
+        };
+        let mut current = self.triangles.get(self.last).copied().flatten().map(|_| self.last);
+        for _ in 0..self.triangles.len() {
+            let Some(index) = current else { break };
+            let triangle = self.triangles[index].unwrap();
+            let exit = (0..3).find(|&i| orient(&self.points[triangle[i]], &self.points[triangle[(i + 1) % 3]], p) < 0.0);
+            match exit {
+                None => return Some(index),
+                Some(i) => current = self.twin(triangle[i], triangle[(i + 1) % 3]),
+            }
+        }
+        self.triangles.iter().position(|t| t.is_some_and(contains))
+    }
+
+    /// Bowyer-Watson insertion of point `vertex`, with the cavity bounded by
+    /// constrained edges. Returns false, leaving the mesh unchanged, when the
+    /// point lies outside the mesh or on a constrained edge.
+    fn insert(&mut self, vertex: usize) -> bool {
+        let p = self.points[vertex];
+        let Some(start) = self.locate(&p) else { return false };
+        let mut cavity = vec![start];
+        let mut stack = vec![start];
+        while let Some(index) = stack.pop() {
+            let triangle = self.triangles[index].unwrap();
+            for i in 0..3 {
+                let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                if self.is_constrained(a, b) {
+                    continue;
+                }
+                let Some(next) = self.twin(a, b) else { continue };
+                let [x, y, z] = self.triangles[next].unwrap().map(|v| self.points[v]);
+                if !cavity.contains(&next) && in_circle(&x, &y, &z, &p) > 0.0 {
+                    cavity.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut rim = Vec::new();
+        for &index in &cavity {
+            let triangle = self.triangles[index].unwrap();
+            for i in 0..3 {
+                let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                if !self.twin(a, b).is_some_and(|t| cavity.contains(&t)) {
+                    rim.push((a, b));
+                }
+            }
+        }
+        if rim.iter().any(|&(a, b)| orient(&self.points[a], &self.points[b], &p) <= 0.0) {
+            return false;
+        }
+        for index in cavity {
+            self.remove(index);
+        }
+        for (a, b) in rim {
+            self.add([a, b, vertex]);
+        }
+        true
+    }
+
+    /// Triangles on both sides of the edge `a b` and their opposite vertices.
+    fn quad(&self, a: usize, b: usize) -> Option<(usize, usize, usize, usize)> {
+        let left = *self.edges.get(&(a, b))?;
+        let right = *self.edges.get(&(b, a))?;
+        let opposite = |index: usize, u: usize, v: usize| {
+            self.triangles[index].unwrap().into_iter().find(|&w| w != u && w != v).unwrap()
+        };
+        Some((left, right, opposite(left, a, b), opposite(right, a, b)))
+    }
+
+    /// Replace the diagonal `a b` of its convex quadrilateral by the other one.
+    /// Returns the new diagonal.
+    fn flip(&mut self, a: usize, b: usize) -> Option<(usize, usize)> {
+        let (left, right, c, d) = self.quad(a, b)?;
+        let points = &self.points;
+        if !crosses(&points[a], &points[b], &points[c], &points[d]) {
+            return None;
+        }
+        self.remove(left);
+        self.remove(right);
+        self.add([a, d, c]);
+        self.add([d, b, c]);
+        Some((c, d))
+    }
+
+    /// Force the segment `a b` into the mesh by flipping the edges crossing it
+    /// (Sloan's method), then restore the Delaunay property around it.
+    fn constrain(&mut self, a: usize, b: usize) {
+        let (pa, pb) = (self.points[a], self.points[b]);
+        let mut crossing: Vec<(usize, usize)> = self
+            .edges
+            .keys()
+            .filter(|&&(u, v)| u < v && crosses(&pa, &pb, &self.points[u], &self.points[v]))
+            .copied()
+            .collect();
+        crossing.sort_unstable();
+        let mut created = Vec::new();
+        let mut attempts = 0;
+        while let Some((u, v)) = crossing.pop() {
+            attempts += 1;
+            if attempts > 100 * (self.points.len() + 1) {
+                break;
+            }
+            match self.flip(u, v) {
+                Some((c, d)) if crosses(&pa, &pb, &self.points[c], &self.points[d]) => crossing.insert(0, (c, d)),
+                Some(diagonal) => created.push(diagonal),
+                None => crossing.insert(0, (u, v)),
+            }
+        }
+        self.constraints.insert((a.min(b), a.max(b)));
+
+        for _ in 0..created.len() * created.len() + 1 {
+            let mut flipped = false;
+            for edge in created.iter_mut() {
+                let (u, v) = *edge;
+                if self.is_constrained(u, v) || !self.edges.contains_key(&(u, v)) {
+                    continue;
+                }
+                let Some((left, _, _, d)) = self.quad(u, v) else { continue };
+                let [x, y, z] = self.triangles[left].unwrap().map(|w| self.points[w]);
+                if in_circle(&x, &y, &z, &self.points[d]) > 0.0
+                    && let Some(diagonal) = self.flip(u, v)
+                {
+                    *edge = diagonal;
+                    flipped = true;
+                }
+            }
+            if !flipped {
+                break;
+            }
+        }
+    }
+
+    /// Drop triangles touching the super-triangle or matching `outside`.
+    fn remove_where<F: Fn(&[Vector2<f64>], [usize; 3]) -> bool>(&mut self, outside: F) {
+        for index in 0..self.triangles.len() {
+            if let Some(triangle) = self.triangles[index]
+                && (triangle.iter().any(|&v| v < SUPER) || outside(&self.points, triangle))
+            {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Insert edge midpoints, longest first, until no edge exceeds `limit`.
+    /// New points are appended and reported through `added`.
+    fn refine<F: FnMut(Vector2<f64>)>(&mut self, limit: f64, mut added: F) {
+        loop {
+            let mut long: Vec<(f64, usize, usize)> = self
+                .edges
+                .keys()
+                .filter(|&&(u, v)| u < v || self.twin(u, v).is_none())
+                .map(|&(u, v)| ((self.points[u] - self.points[v]).norm(), u, v))
+                .filter(|&(length, _, _)| length > limit)
+                .collect();
+            if long.is_empty() {
+                return;
+            }
+            long.sort_by(|x, y| y.0.total_cmp(&x.0).then((x.1, x.2).cmp(&(y.1, y.2))));
+            let mut inserted = false;
+            for (_, u, v) in long {
+                if !self.edges.contains_key(&(u, v)) || self.is_constrained(u, v) {
+                    continue;
+                }
+                let midpoint = (self.points[u] + self.points[v]) / 2.0;
+                self.points.push(midpoint);
+                if self.insert(self.points.len() - 1) {
+                    added(midpoint);
+                    inserted = true;
+                } else {
+                    self.points.pop();
+                }
+            }
+            if !inserted {
+                return;
+            }
+        }
+    }
+
+    /// Compact the live triangles into a mesh over `global`, which holds the
+    /// original coordinates of every point after the super-triangle corners.
+    fn into_mesh(self, global: &[Vector3d]) -> TriMesh {
+        let mut index = vec![usize::MAX; self.points.len()];
+        for triangle in self.triangles.iter().flatten() {
+            for &v in triangle {
+                index[v] = 0;
+            }
+        }
+        let mut vertices = Vec::new();
+        for (v, slot) in index.iter_mut().enumerate() {
+            if *slot == 0 {
+                *slot = vertices.len();
+                vertices.push(global[v - SUPER]);
+            }
+        }
+        let triangles = self.triangles.into_iter().flatten().map(|t| t.map(|v| index[v])).collect();
+        TriMesh { vertices, triangles }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_circle_sign() {
+        let (a, b, c) = (Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        assert!(in_circle(&a, &b, &c, &Vector2::new(0.5, 0.5)) > 0.0);
+        assert!(in_circle(&a, &b, &c, &Vector2::new(2.0, 2.0)) < 0.0);
+        assert!(in_circle(&a, &b, &c, &Vector2::new(1.0, 1.0)).abs() < 1e-12);
+    }
+}
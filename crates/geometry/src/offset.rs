@@ -0,0 +1,142 @@
+//! Offsetting (buffering) of planar regions in 2D local coordinates.
+//!
+//! The offset region is built as the polygon united with (outward) or minus
+//! (inward) the area swept by its edges: one strip per edge plus a join piece at
+//! every vertex where neighbouring strips open a gap. Reusing the boolean engine
+//! handles collapsing features, splitting and holes without special cases.
+
+use std::f64::consts::FRAC_PI_2;
+
+use crate::Vector2d;
+use crate::boolean::{self, BooleanOp, Ring, signed_area};
+
+/// Corner treatment where offset edges separate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OffsetJoin {
+    /// Extend the edges to their intersection. Corners whose miter would reach
+    /// further than `limit` times the offset distance are bevelled instead.
+    Miter { limit: f64 },
+    /// Circular arc around the vertex, with `segments` chords per quarter circle.
+    Round { segments: usize },
+    /// Straight cut between the offset edge ends.
+    Bevel,
+}
+
+impl Default for OffsetJoin {
+    fn default() -> Self {
+        OffsetJoin::Miter { limit: 4.0 }
+    }
+}
+
+/// Offset `region` (outer ring first, then holes) by `distance`; positive grows
+/// the region. Returns `(outer, holes)` groups.
+pub(crate) fn offset(region: &[Ring], distance: f64, join: OffsetJoin, tol: f64) -> Vec<(Ring, Vec<Ring>)> {
+    let region = boolean::normalized_region(region, tol);
+    if region.is_empty() {
+        return Vec::new();
+    }
+    let pieces = swept_pieces(&region, distance, join, tol);
+    let mut result = vec![region];
+    for piece in pieces {
+        let piece = vec![piece];
+        if distance > 0.0 {
+            unite(&mut result, piece, tol);
+        } else {
+            result = result
+                .iter()
+                .flat_map(|current| split_groups(boolean::boolean(current, &piece, BooleanOp::Difference, tol)))
+                .collect();
+        }
+    }
+    result
+        .into_iter()
+        .map(|mut rings| {
+            let outer = rings.remove(0);
+            (outer, rings)
+        })
+        .collect()
+}
+
+fn split_groups(rings: Vec<Ring>) -> Vec<Vec<Ring>> {
+    boolean::group_rings(rings)
+        .into_iter()
+        .map(|(outer, holes)| std::iter::once(outer).chain(holes).collect())
+        .collect()
+}
+
+/// Merge `piece` into a set of disjoint regions.
+fn unite(regions: &mut Vec<Vec<Ring>>, piece: Vec<Ring>, tol: f64) {
+    let mut merged = piece;
+    let mut i = 0;
+    while i < regions.len() {
+        let mut groups = split_groups(boolean::boolean(&regions[i], &merged, BooleanOp::Union, tol));
+        if groups.len() == 1 {
+            merged = groups.remove(0);
+            regions.swap_remove(i);
+            i = 0;
+        } else {
+            i += 1;
+        }
+    }
+    regions.push(merged);
+}
+
+/// Edge strips and vertex joins on the offset side of every ring.
+fn swept_pieces(region: &[Ring], distance: f64, join: OffsetJoin, tol: f64) -> Vec<Ring> {
+    let mut pieces = Vec::new();
+    for ring in region {
+        let n = ring.len();
+        // Rings are oriented with the material on the left, so the right-hand
+        // normal points out of the region.
+        let normal = |i: usize| {
+            let d = ring[(i + 1) % n] - ring[i];
+            -d.perp().normalize() * distance
+        };
+        for i in 0..n {
+            let a = ring[i];
+            let b = ring[(i + 1) % n];
+            let shift = normal(i);
+            pieces.push(vec![a, b, b + shift, a + shift]);
+
+            let prev = ring[(i + n - 1) % n];
+            let turn = (a - prev).cross(&(b - a));
+            if turn * distance > tol * (a - prev).norm() * (b - a).norm() {
+                pieces.push(join_piece(a, normal((i + n - 1) % n), shift, join));
+            }
+        }
+    }
+    pieces
+        .into_iter()
+        .filter(|piece| signed_area(piece).abs() > tol * tol)
+        .collect()
+}
+
+/// Gap filler at vertex `v` between offset vectors `s1` (incoming edge) and `s2`.
+fn join_piece(v: Vector2d, s1: Vector2d, s2: Vector2d, join: OffsetJoin) -> Ring {
+    match join {
+        OffsetJoin::Bevel => vec![v, v + s1, v + s2],
+        OffsetJoin::Miter { limit } => {
+            let r2 = s1.dot(&s1);
+            let cos = s1.dot(&s2) / r2;
+            let ratio = (2.0 / (1.0 + cos)).sqrt();
+            if ratio <= limit {
+                vec![v, v + s1, v + (s1 + s2) / (1.0 + cos), v + s2]
+            } else {
+                vec![v, v + s1, v + s2]
+            }
+        }
+        OffsetJoin::Round { segments } => {
+            let radius = s1.norm();
+            let start = s1.angle();
+            let sweep = s1.cross(&s2).atan2(s1.dot(&s2));
+            let count = ((sweep.abs() / FRAC_PI_2) * segments.max(1) as f64).ceil().max(1.0) as usize;
+            let mut ring = vec![v, v + s1];
+            for k in 1..count {
+                let angle = start + sweep * k as f64 / count as f64;
+                ring.push(v + Vector2d::new(angle.cos(), angle.sin()) * radius);
+            }
+            ring.push(v + s2);
+            ring
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use nalgebra::{Matrix3, Vector3};
+use utils::epsilon;
+
+use crate::{Line3d, LocalAxis, Vector3d};
+
+/// Infinite plane through `origin` with unit `normal`, carrying an in-plane frame
+/// so points can be expressed in plane coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    origin: Vector3d,
+    // Columns are [ex, ey, normal].
+    rotation: Matrix3<f64>,
+}
+
+impl Plane {
+    /// Plane from a point and a normal; the in-plane x axis is chosen like the
+    /// local axes of a line along the normal (perpendicular to global Y where
+    /// possible). Panics on a zero normal.
+    pub fn new<O: Into<Vector3d>, N: Into<Vector3d>>(origin: O, normal: N) -> Self {
+        let normal = normal.into().0;
+        assert!(normal.norm() > epsilon(), "plane normal must be non-zero");
+        let ez = normal.normalize();
+        let reference = if ez.cross(&Vector3::y()).norm() <= 1e-9 { Vector3::x() } else { Vector3::y() };
+        let ex = reference.cross(&ez).normalize();
+        let ey = ez.cross(&ex);
+        Self { origin: origin.into(), rotation: Matrix3::from_columns(&[ex, ey, ez]) }
+    }
+
+    /// Plane from a point and an in-plane x direction; the normal is `x × y`
+    /// and `y` is re-orthogonalized. Returns `None` for parallel axes.
+    pub fn from_frame<O, X, Y>(origin: O, x_axis: X, y_axis: Y) -> Option<Self>
+    where
+        O: Into<Vector3d>,
+        X: Into<Vector3d>,
+        Y: Into<Vector3d>,
+    {
+        let (x, y) = (x_axis.into().0, y_axis.into().0);
+        let ez = x.cross(&y);
+        if ez.norm() <= epsilon() * x.norm() * y.norm() || x.norm() <= epsilon() {
+            return None;
+        }
+        let ex = x.normalize();
+        let ez = ez.normalize();
+        let ey = ez.cross(&ex);
+        Some(Self { origin: origin.into(), rotation: Matrix3::from_columns(&[ex, ey, ez]) })
+    }
+
+    /// Plane through three points with `a` as origin and `b - a` as x axis.
+    /// Returns `None` for collinear points.
+    pub fn from_points<A, B, C>(a: A, b: B, c: C) -> Option<Self>
+    where
+        A: Into<Vector3d>,
+        B: Into<Vector3d>,
+        C: Into<Vector3d>,
+    {
+        let a = a.into();
+        let (b, c) = (b.into(), c.into());
+        Self::from_frame(a, Vector3d(b.0 - a.0), Vector3d(c.0 - a.0))
+    }
+
+    /// The XY plane of a local frame.
+    pub fn from_local_axis(axis: &LocalAxis) -> Self {
+        let rotation = Matrix3::from_columns(&[
+            axis.direction(crate::Axis::AxisX).0,
+            axis.direction(crate::Axis::AxisY).0,
+            axis.direction(crate::Axis::AxisZ).0,
+        ]);
+        Self { origin: axis.origin(), rotation }
+    }
+
+    pub fn origin(&self) -> Vector3d { self.origin }
+    pub fn normal(&self) -> Vector3d { Vector3d(self.rotation.column(2).into_owned()) }
+    pub fn x_axis(&self) -> Vector3d { Vector3d(self.rotation.column(0).into_owned()) }
+    pub fn y_axis(&self) -> Vector3d { Vector3d(self.rotation.column(1).into_owned()) }
+    pub fn local_axis(&self) -> LocalAxis { LocalAxis::new(self.origin, self.rotation) }
+
+    /// Distance along the normal; positive on the side the normal points to.
+    pub fn signed_distance(&self, point: &Vector3d) -> f64 {
+        (point.0 - self.origin.0).dot(&self.rotation.column(2))
+    }
+
+    pub fn distance(&self, point: &Vector3d) -> f64 { self.signed_distance(point).abs() }
+
+    /// Whether `point` lies within `tolerance` of the plane, [`epsilon`] by default.
+    pub fn contains(&self, point: &Vector3d, tolerance: Option<f64>) -> bool { self.distance(point) <= tolerance.unwrap_or_else(epsilon) }
+
+    /// Orthogonal projection of a point onto the plane.
+    pub fn project(&self, point: &Vector3d) -> Vector3d {
+        Vector3d(point.0 - self.rotation.column(2) * self.signed_distance(point))
+    }
+
+    /// Point in plane coordinates (x, y) plus its signed distance as z.
+    pub fn to_local(&self, point: Vector3d) -> Vector3d { self.local_axis().to_local(point) }
+
+    pub fn to_global(&self, local: Vector3d) -> Vector3d { self.local_axis().to_global(local) }
+
+    /// Plane with the normal (and y axis) flipped.
+    pub fn flipped(&self) -> Self {
+        let mut rotation = self.rotation;
+        rotation.set_column(1, &-self.rotation.column(1));
+        rotation.set_column(2, &-self.rotation.column(2));
+        Self { origin: self.origin, rotation }
+    }
+
+    /// Intersection with a line segment. Segments parallel to the plane
+    /// (including segments lying in it) have no single intersection and return
+    /// `None`; use a [`Ray`](crate::Ray) for half-infinite lines.
+    pub fn intersection_with_line(&self, line: &Line3d) -> Option<Vector3d> {
+        let t = self.line_parameter(line)?;
+        (-epsilon()..=1.0 + epsilon()).contains(&t).then(|| line.point_at(t))
+    }
+
+    /// Parameter along `line` (0 at the start, 1 at the end) where the infinite
+    /// line through it crosses the plane; `None` when parallel.
+    pub(crate) fn line_parameter(&self, line: &Line3d) -> Option<f64> {
+        let direction = line.end().0 - line.start().0;
+        let denominator = direction.dot(&self.rotation.column(2));
+        if denominator.abs() <= epsilon() * direction.norm().max(1.0) {
+            return None;
+        }
+        Some(-self.signed_distance(&line.start()) / denominator)
+    }
+
+    /// Line of intersection with another plane, running along `n1 × n2` from the
+    /// point on it closest to this plane's origin, with unit length. Returns `None`
+    /// for parallel planes.
+    pub fn intersection(&self, other: &Plane) -> Option<Line3d> {
+        let (n1, n2) = (self.rotation.column(2).into_owned(), other.rotation.column(2).into_owned());
+        let direction = n1.cross(&n2);
+        let denominator = direction.norm_squared();
+        if denominator <= epsilon() {
+            return None;
+        }
+        let (d1, d2) = (n1.dot(&self.origin.0), n2.dot(&other.origin.0));
+        let base = (n2.cross(&direction) * d1 + direction.cross(&n1) * d2) / denominator;
+        // Move to the point closest to this origin.
+        let unit = direction / denominator.sqrt();
+        let point = base + unit * unit.dot(&(self.origin.0 - base));
+        Some(Line3d::new(Vector3d(point), Vector3d(point + unit)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::assert_vec3_almost_eq;
+
+    use super::*;
+
+    #[test]
+    fn frame_is_right_handed() {
+        let plane = Plane::new((0.0, 0.0, 0.0), (1.0, 1.0, 1.0));
+        let (ex, ey, ez) = (plane.x_axis(), plane.y_axis(), plane.normal());
+        assert_vec3_almost_eq!(ex.cross(&ey), ez);
+        let flipped = plane.flipped();
+        assert_vec3_almost_eq!(flipped.x_axis().cross(&flipped.y_axis()), flipped.normal());
+    }
+}
@@ -1,24 +1,128 @@
-use nalgebra::{Matrix2, Matrix3, Vector3};
+use nalgebra::{ComplexField, Matrix2, Matrix3, RealField, Vector2, Vector3};
+use num_traits::{One, Zero};
 
-use crate::arc::ArcVector;
-use crate::line::{Axis, Line, LocalAxis};
-use crate::Vector3d;
+use crate::arc::{widened, ArcVector};
+use crate::boolean::{self, BooleanOp, Ring};
+use crate::edge::Edge;
+use crate::line::{Line, LineVector, LocalAxis, SignedAxis};
+use crate::offset::{self, OffsetJoin};
+use crate::polyline::Segment;
+use crate::predicates;
+use crate::{bounding_sphere, BoundingBox3d, GeometryError, Plane, Scalar, Sphere, Transform3d, Vector2d, Vector3d};
 use utils::epsilon;
-#[cfg(test)]
-use crate::Vector2d;
 
+/// Serialized as its rings only; the cached properties are recomputed when
+/// deserializing.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "PolygonRings<V>",
+        into = "PolygonRings<V>",
+        bound(serialize = "V: serde::Serialize", deserialize = "V: serde::Deserialize<'de>")
+    )
+)]
 pub struct Polygon<V>
 where
     V: ArcVector,
 {
     vertices: Vec<V>,
+    // Interior rings (voids), oriented opposite to `vertices`
+    holes: Vec<Vec<V>>,
     // Per-polygon cached geometric properties
-    normal: Vector3<f64>,
-    rotation: Matrix3<f64>, // columns are [ex, ey, ez]
+    normal: Vector3<V::Scalar>,
+    rotation: Matrix3<V::Scalar>, // columns are [ex, ey, ez]
     centroid: V,
-    area: f64,
-    perimeter: f64,
+    area: V::Scalar,
+    perimeter: V::Scalar,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "V: serde::Serialize", deserialize = "V: serde::Deserialize<'de>"))]
+struct PolygonRings<V> {
+    vertices: Vec<V>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    holes: Vec<Vec<V>>,
+}
+
+#[cfg(feature = "serde")]
+impl<V: ArcVector> From<PolygonRings<V>> for Polygon<V> {
+    fn from(rings: PolygonRings<V>) -> Self { Self::with_holes(rings.vertices, rings.holes) }
+}
+
+#[cfg(feature = "serde")]
+impl<V: ArcVector> From<Polygon<V>> for PolygonRings<V> {
+    fn from(polygon: Polygon<V>) -> Self { Self { vertices: polygon.vertices, holes: polygon.holes } }
+}
+
+/// Part of a polygon boundary. `ring` is 0 for the outer ring and `1 + i` for
+/// hole `i`; edge `index` runs from vertex `index` to the next vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryFeature {
+    Vertex { ring: usize, index: usize },
+    Edge { ring: usize, index: usize },
+}
+
+/// Closest boundary point of a polygon together with where it lies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryPoint<V: LineVector> {
+    pub point: V,
+    pub feature: BoundaryFeature,
+    /// Position along the closest edge from 0 (edge start) to 1; 0 for vertices.
+    pub parameter: V::Scalar,
+    /// In-plane distance from the projected query point to the boundary, negative
+    /// when the projection lies inside the polygon.
+    pub signed_distance: V::Scalar,
+    /// Signed distance of the query point from the polygon plane along the normal.
+    pub plane_offset: V::Scalar,
+}
+
+/// Why an edge loop passed to [`Polygon::from_edges`] does not bound a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeLoopError {
+    /// Fewer than three polygon sides are left once degenerate edges are dropped.
+    TooFewSides,
+    /// Neither end of edge `index` meets the end of the edge before it.
+    Disconnected { index: usize },
+    /// The last edge does not end where the first one starts.
+    NotClosed,
+}
+
+impl std::fmt::Display for EdgeLoopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooFewSides => write!(f, "edge loop has fewer than three sides"),
+            Self::Disconnected { index } => write!(f, "edge {index} does not continue from the previous edge"),
+            Self::NotClosed => write!(f, "edge loop is not closed"),
+        }
+    }
+}
+
+impl std::error::Error for EdgeLoopError {}
+
+
+/// Why `ring` cannot bound a polygon, if it cannot.
+fn check_ring<V: ArcVector>(ring: &[V]) -> Result<(), GeometryError> {
+    let mut distinct = ring.to_vec();
+    distinct.dedup_by(|a, b| a.is_approx(b, None));
+    while distinct.len() > 1 && distinct[0].is_approx(&distinct[distinct.len() - 1], None) {
+        distinct.pop();
+    }
+    if distinct.len() < 3 {
+        return Err(GeometryError::TooFewPoints { distinct: distinct.len() });
+    }
+    let points: Vec<Vector3<V::Scalar>> = distinct.iter().map(|v| v.to_vec3()).collect();
+    let far = points
+        .iter()
+        .map(|p| p - points[0])
+        .max_by(|a, b| a.norm().as_f64().total_cmp(&b.norm().as_f64()))
+        .expect("three points");
+    if points.iter().all(|p| far.cross(&(p - points[0])).norm() <= V::Scalar::epsilon()) {
+        return Err(GeometryError::Collinear);
+    }
+    Ok(())
 }
 
 // Local 2D/3D aliases removed; the crate root exports canonical 3D names.
@@ -34,17 +138,57 @@ where
         I: IntoIterator<Item = P>,
         P: Into<V>,
     {
+        Self::with_holes(vertices, std::iter::empty::<Vec<V>>())
+    }
+
+    /// [`Polygon::new`] that reports fewer than three distinct vertices, or
+    /// vertices on one line, instead of panicking or guessing a plane.
+    pub fn try_new<I, P>(vertices: I) -> Result<Self, GeometryError>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<V>,
+    {
+        Self::try_with_holes(vertices, std::iter::empty::<Vec<V>>())
+    }
+
+    /// [`Polygon::with_holes`] checking the outer ring as [`Polygon::try_new`] does.
+    pub fn try_with_holes<I, P, H, R, Q>(vertices: I, holes: H) -> Result<Self, GeometryError>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<V>,
+        H: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Q>,
+        Q: Into<V>,
+    {
+        let vertices: Vec<V> = vertices.into_iter().map(|p| p.into()).collect();
+        check_ring(&vertices)?;
+        Ok(Self::with_holes(vertices, holes))
+    }
+
+    /// Create a polygon with interior rings (voids). The outer boundary defines the
+    /// plane and local frame; hole vertices are projected onto that plane and
+    /// re-oriented opposite to the outer boundary. Rings with fewer than three
+    /// distinct vertices are ignored.
+    pub fn with_holes<I, P, H, R, Q>(vertices: I, holes: H) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<V>,
+        H: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Q>,
+        Q: Into<V>,
+    {
+        let (zero, eps) = (V::Scalar::zero(), V::Scalar::epsilon());
         let mut verts: Vec<V> = vertices.into_iter().map(|p| p.into()).collect();
         assert!(verts.len() >= 3, "Polygon requires at least 3 vertices");
 
         // Remove consecutive duplicates
-        verts.dedup_by(|a, b| a.is_approx(b, Some(epsilon())));
+        verts.dedup_by(|a, b| a.is_approx(b, None));
         assert!(verts.len() >= 3, "Polygon requires at least 3 distinct vertices");
 
         // Establish plane from first non-collinear triple (prefer the first three if possible)
         let (p0, normal) = {
             let mut base = verts[0].to_vec3();
-            let mut normal = Vector3::new(0.0, 0.0, 0.0);
+            let mut normal = Vector3::zeros();
             'outer: for i in 0..verts.len() {
                 for j in i + 1..verts.len() {
                     for k in j + 1..verts.len() {
@@ -54,7 +198,7 @@ where
                         let u = b - a;
                         let v = c - a;
                         let n = u.cross(&v);
-                        if n.norm() > epsilon() {
+                        if n.norm() > eps {
                             base = a;
                             normal = n.normalize();
                             break 'outer;
@@ -62,9 +206,9 @@ where
                     }
                 }
             }
-            if normal.norm() <= epsilon() {
+            if normal.norm() <= eps {
                 // All points collinear or identical; fallback to +Z
-                (base, Vector3::new(0.0, 0.0, 1.0))
+                (base, Vector3::z())
             } else {
                 (base, normal)
             }
@@ -84,25 +228,25 @@ where
     let ez = normal;
     let ex = {
             // Find first non-degenerate edge
-            let mut ex_opt: Option<Vector3<f64>> = None;
+            let mut ex_opt: Option<Vector3<V::Scalar>> = None;
             for i in 0..verts.len() {
                 let a = verts[i].to_vec3();
                 let b = verts[(i + 1) % verts.len()].to_vec3();
                 let edge = b - a;
-                if edge.norm() > epsilon() {
+                if edge.norm() > eps {
                     ex_opt = Some(edge);
                     break;
                 }
             }
-            let mut ex = ex_opt.unwrap_or_else(|| Vector3::new(1.0, 0.0, 0.0));
+            let mut ex = ex_opt.unwrap_or_else(Vector3::x);
             // Project onto plane to ensure orthogonality with ez
             ex = ex - ez * ex.dot(&ez);
-            if ex.norm() <= epsilon() {
+            if ex.norm() <= eps {
                 // choose a stable axis perpendicular to ez
-                let global_y = Vector3::new(0.0, 1.0, 0.0);
+                let global_y = Vector3::y();
                 let mut ex_raw = ez.cross(&global_y);
-                if ex_raw.norm() <= epsilon() {
-                    ex_raw = Vector3::new(1.0, 0.0, 0.0);
+                if ex_raw.norm() <= eps {
+                    ex_raw = Vector3::x();
                 }
                 ex = ex_raw.normalize();
             } else {
@@ -113,41 +257,67 @@ where
         let ey = ez.cross(&ex);
         let rotation = Matrix3::from_columns(&[ex, ey, ez]);
 
-    // Compute area, perimeter, and centroid in local coordinates (z ~ 0)
+        // Transform vertices to local frame using temporary origin at first vertex
+        let origin0 = verts[0].to_vec3();
+        let r_t = rotation.transpose();
+        let to_local = |ring: &[V]| -> Vec<Vector3<V::Scalar>> {
+            ring.iter().map(|v| r_t * (v.to_vec3() - origin0)).collect()
+        };
+        let outer_sign = ring_sums(&to_local(&verts))[0].signum();
+
+        // Project holes onto the plane and orient them opposite to the outer ring so
+        // that all ring integrals can simply be summed.
+        let holes: Vec<Vec<V>> = holes
+            .into_iter()
+            .filter_map(|hole| {
+                let mut ring: Vec<V> = hole.into_iter().map(|p| p.into()).collect();
+                ring.dedup_by(|a, b| a.is_approx(b, None));
+                while ring.len() > 1 && ring[0].is_approx(&ring[ring.len() - 1], None) {
+                    ring.pop();
+                }
+                if ring.len() < 3 {
+                    return None;
+                }
+                let mut ring: Vec<V> = ring
+                    .into_iter()
+                    .map(|v| {
+                        let pv = v.to_vec3();
+                        V::from_vec3(pv - normal * (pv - p0).dot(&normal))
+                    })
+                    .collect();
+                if ring_sums(&to_local(&ring))[0].signum() == outer_sign {
+                    ring.reverse();
+                }
+                Some(ring)
+            })
+            .collect();
+
+        // Compute area, perimeter, and centroid in local coordinates (z ~ 0)
         let (area, centroid_local, perimeter) = {
-            let mut area2 = 0.0; // 2 * area
-            let mut cx_num = 0.0;
-            let mut cy_num = 0.0;
-            let mut perim = 0.0;
-
-            // Transform vertices to local frame using temporary origin at first vertex
-            let origin0 = verts[0].to_vec3();
-            let r_t = rotation.transpose();
-            let local: Vec<Vector3<f64>> = verts
-                .iter()
-                .map(|v| r_t * (v.to_vec3() - origin0))
-                .collect();
-
-            for i in 0..local.len() {
-                let p = local[i];
-                let q = local[(i + 1) % local.len()];
-                let cross = p.x * q.y - q.x * p.y;
-                area2 += cross;
-                cx_num += (p.x + q.x) * cross;
-                cy_num += (p.y + q.y) * cross;
-                perim += (q - p).norm();
+            let mut sums = [zero; 6];
+            let mut perim = zero;
+            for ring in std::iter::once(&verts).chain(holes.iter()) {
+                let local = to_local(ring);
+                for (total, term) in sums.iter_mut().zip(ring_sums(&local)) {
+                    *total += term;
+                }
+                for i in 0..local.len() {
+                    perim += (local[(i + 1) % local.len()] - local[i]).norm();
+                }
             }
-            let area = 0.5 * area2;
-            let (cx, cy) = if area.abs() > epsilon() {
-                (cx_num / (3.0 * area2), cy_num / (3.0 * area2))
+            let [area2, cx_num, cy_num, ..] = sums;
+            let area = area2 * V::Scalar::from_f64_lossy(0.5);
+            let (cx, cy) = if area.abs() > eps {
+                let three = V::Scalar::from_f64_lossy(3.0);
+                (cx_num / (three * area2), cy_num / (three * area2))
             } else {
                 // Fallback: average of vertices
-                let n = local.len() as f64;
-                let sx = local.iter().map(|p| p.x).sum::<f64>() / n;
-                let sy = local.iter().map(|p| p.y).sum::<f64>() / n;
-                (sx, sy)
+                let local = to_local(&verts);
+                let n = V::Scalar::from_f64_lossy(local.len() as f64);
+                let sum = local.iter().fold(Vector3::zeros(), |sum, p| sum + p);
+                (sum.x / n, sum.y / n)
             };
-            let centroid_local = Vector3::new(cx, cy, 0.0);
+            let centroid_local = Vector3::new(cx, cy, zero);
             (area, centroid_local, perim)
         };
 
@@ -155,71 +325,174 @@ where
         let centroid_vec = verts[0].to_vec3() + rotation * centroid_local;
         let centroid = V::from_vec3(centroid_vec);
 
-        Self { vertices: verts, normal: ez, rotation, centroid, area, perimeter }
+        Self { vertices: verts, holes, normal: ez, rotation, centroid, area, perimeter }
+    }
+
+    /// Polygon bounded by a closed loop of edges, e.g. a face boundary read
+    /// from CAD. The edges must follow each other around the loop; any edge may
+    /// run backwards and is then turned round. Curved edges become chords
+    /// within `max_sagitta` of the arc, see [`Arc::linearized_by_tolerance`](crate::Arc::linearized_by_tolerance).
+    ///
+    /// Edge ends within `tolerance` ([`Scalar::epsilon`] by default) of each other meet.
+    ///
+    /// Also returns, for every side `i` of the polygon (vertex `i` to the next),
+    /// the index of the edge it came from. Zero-length edges give no side.
+    pub fn from_edges(edges: &[Edge<V>], max_sagitta: V::Scalar, tolerance: Option<f64>) -> Result<(Self, Vec<usize>), EdgeLoopError> {
+        let meets = |a: V, b: V| a.is_approx(&b, tolerance);
+        let mut oriented: Vec<Edge<V>> = Vec::with_capacity(edges.len());
+        for (index, edge) in edges.iter().enumerate() {
+            let edge = match oriented.last() {
+                // The first edge runs towards whichever of its ends the second one touches.
+                None => match edges.get(1) {
+                    Some(next) if !meets(edge.end(), next.start()) && !meets(edge.end(), next.end()) => edge.reversed_edge(),
+                    _ => *edge,
+                },
+                Some(previous) if meets(previous.end(), edge.start()) => *edge,
+                Some(previous) if meets(previous.end(), edge.end()) => edge.reversed_edge(),
+                Some(_) => return Err(EdgeLoopError::Disconnected { index }),
+            };
+            oriented.push(edge);
+        }
+        match (oriented.first(), oriented.last()) {
+            (Some(first), Some(last)) if meets(last.end(), first.start()) => {}
+            (Some(_), Some(_)) => return Err(EdgeLoopError::NotClosed),
+            _ => return Err(EdgeLoopError::TooFewSides),
+        }
+
+        let mut vertices = Vec::new();
+        let mut sources = Vec::new();
+        for (index, edge) in oriented.iter().enumerate() {
+            let chords = match edge.geometry() {
+                Segment::Line(line) => vec![*line],
+                Segment::Arc(arc) => arc.linearized_by_tolerance(max_sagitta),
+            };
+            for chord in chords.iter().filter(|chord| chord.length() > V::Scalar::epsilon()) {
+                vertices.push(chord.start());
+                sources.push(index);
+            }
+        }
+        if vertices.len() < 3 {
+            return Err(EdgeLoopError::TooFewSides);
+        }
+        Ok((Self::new(vertices), sources))
     }
 
     pub fn vertices(&self) -> &Vec<V> { &self.vertices }
 
+    /// Interior rings, each oriented opposite to the outer boundary.
+    pub fn holes(&self) -> &[Vec<V>] { &self.holes }
+
+    pub fn has_holes(&self) -> bool { !self.holes.is_empty() }
+
+    /// Edges of the outer boundary.
     pub fn lines(&self) -> Vec<Line<V>> {
-        let n = self.vertices.len();
-        (0..n)
-            .map(|i| Line::new(self.vertices[i], self.vertices[(i + 1) % n]))
+        ring_lines(&self.vertices)
+    }
+
+    /// Edges of the outer boundary followed by the edges of every hole.
+    pub fn boundary_lines(&self) -> Vec<Line<V>> {
+        self.rings().flat_map(|ring| ring_lines(ring)).collect()
+    }
+
+    /// Outward unit normal in the polygon plane of every edge of
+    /// [`Polygon::boundary_lines`], e.g. the direction of an edge line load.
+    /// Hole edges point away from the material, into the opening.
+    pub fn edge_normals(&self) -> Vec<Vector3d<V::Scalar>> {
+        let normal = self.normal * self.area.signum();
+        self.boundary_lines()
+            .iter()
+            .map(|line| {
+                let along = line.end().to_vec3() - line.start().to_vec3();
+                Vector3d(along.cross(&normal).try_normalize(V::Scalar::epsilon()).unwrap_or_else(Vector3::zeros))
+            })
             .collect()
     }
 
-    pub fn area(&self) -> f64 { self.area.abs() }
-    pub fn perimeter(&self) -> f64 { self.perimeter }
+    /// Points on the outer boundary every `spacing` of arc length, starting
+    /// at the first vertex, e.g. a stud layout along a slab edge. Each comes
+    /// with the index of the edge of [`Polygon::lines`] it lies on, so it can
+    /// be moved inward along that edge's normal; a point at a corner belongs
+    /// to the edge starting there. Panics unless `spacing` is positive.
+    pub fn points_along_perimeter(&self, spacing: V::Scalar) -> Vec<(V, usize)> {
+        let zero = V::Scalar::zero();
+        assert!(spacing > zero, "perimeter spacing must be positive");
+        let lines = self.lines();
+        let total = lines.iter().fold(zero, |sum, line| sum + line.length());
+        let slack = V::Scalar::epsilon() * total.max(V::Scalar::one());
+        let (mut points, mut index, mut start) = (Vec::new(), 0, zero);
+        for station in (0..).map(|k| V::Scalar::from_f64_lossy(k as f64) * spacing).take_while(|&s| s < total - slack) {
+            while index + 1 < lines.len() && station >= start + lines[index].length() - slack {
+                start += lines[index].length();
+                index += 1;
+            }
+            points.push((lines[index].point_at_length(station - start), index));
+        }
+        points
+    }
+
+    fn rings(&self) -> impl Iterator<Item = &Vec<V>> {
+        std::iter::once(&self.vertices).chain(self.holes.iter())
+    }
+
+    pub fn area(&self) -> V::Scalar { self.area.abs() }
+    pub fn perimeter(&self) -> V::Scalar { self.perimeter }
     pub fn centroid(&self) -> V { self.centroid }
 
     /// Center as used in reference outputs: the first input vertex.
     pub fn center(&self) -> V { self.vertices[0] }
 
-    pub fn bounding_box(&self) -> (V, V) {
-        let mut min = self.vertices[0];
-        let mut max = self.vertices[0];
-        for v in &self.vertices {
-            min = min.component_min(v);
-            max = max.component_max(v);
-        }
-        (min, max)
+    pub fn bounding_box(&self) -> BoundingBox3d {
+        BoundingBox3d::from_points(self.vertices.iter().map(widened)).expect("polygon has vertices")
     }
 
-    pub fn local_axis(&self) -> LocalAxis {
-        // Use centroid as origin
-        LocalAxis::new(Vector3d(self.centroid.to_vec3()), self.rotation)
+    /// Smallest sphere enclosing the polygon (its outer ring).
+    pub fn bounding_sphere(&self) -> Sphere {
+        let points: Vec<Vector3d> = self.vertices.iter().map(widened).collect();
+        bounding_sphere(&points).expect("polygon has vertices")
     }
 
-    pub fn axis(&self, axis: Axis) -> Vector3d {
-        match axis {
-            Axis::AxisX => Vector3d::new(self.rotation.column(0)[0], self.rotation.column(0)[1], self.rotation.column(0)[2]),
-            Axis::AxisY => Vector3d::new(self.rotation.column(1)[0], self.rotation.column(1)[1], self.rotation.column(1)[2]),
-            Axis::AxisZ => Vector3d::new(self.rotation.column(2)[0], self.rotation.column(2)[1], self.rotation.column(2)[2]),
-        }
+    pub fn axis<A: Into<SignedAxis>>(&self, axis: A) -> Vector3d<V::Scalar> {
+        Vector3d(self.rotation * axis.into().to_vector3d().cast::<V::Scalar>().0)
     }
 
     /// Alias to match the external API style (Geometry::direction(Axis))
-    pub fn direction(&self, axis: Axis) -> Vector3d { self.axis(axis) }
+    pub fn direction<A: Into<SignedAxis>>(&self, axis: A) -> Vector3d<V::Scalar> { self.axis(axis) }
 
-    pub fn to_local(&self, point: Vector3d) -> Vector3d {
+    pub fn to_local(&self, point: Vector3d<V::Scalar>) -> Vector3d<V::Scalar> {
         let offset = point.0 - self.centroid.to_vec3();
         let local = self.rotation.transpose() * offset;
         Vector3d::new(local.x, local.y, local.z)
     }
 
-    pub fn to_global(&self, local: Vector3d) -> Vector3d {
+    pub fn to_global(&self, local: Vector3d<V::Scalar>) -> Vector3d<V::Scalar> {
         let global = self.centroid.to_vec3() + self.rotation * local.0;
         Vector3d::new(global.x, global.y, global.z)
     }
 
+    /// In-plane coordinates `(u, v)` of a point along the local x and y axes from
+    /// the centroid, and its signed distance from the plane along the normal.
+    /// The frame is fixed at construction, so the coordinates of an attachment
+    /// point do not change between calls.
+    pub fn plane_coordinates(&self, point: &V) -> (V::Scalar, V::Scalar, V::Scalar) {
+        let local = self.rotation.transpose() * (point.to_vec3() - self.centroid.to_vec3());
+        (local.x, local.y, local.z)
+    }
+
+    /// Point in the plane at coordinates `(u, v)`, the inverse of
+    /// [`Self::plane_coordinates`] for points on the plane.
+    pub fn point_at_plane_coordinates(&self, u: V::Scalar, v: V::Scalar) -> V {
+        V::from_vec3(self.centroid.to_vec3() + self.rotation * Vector3::new(u, v, V::Scalar::zero()))
+    }
+
     /// Compute the 2D second moment of area matrix in the polygon's local plane
     /// about the modeling origin (matching the Python reference behaviour). The
     /// origin is the first provided vertex projected into the polygon plane. The
     /// returned 2x2 matrix [Ixx Ixy; Ixy Iyy] is aligned with the polygon's local
     /// X,Y axes (columns of `self.rotation`).
-    pub fn local_second_moment_of_area(&self) -> Matrix2<f64> {
+    pub fn local_second_moment_of_area(&self) -> Matrix2<V::Scalar> {
         let mut inertia = self.centroidal_local_second_moment();
         let area = self.area.abs();
-        if area <= epsilon() {
+        if area <= V::Scalar::epsilon() {
             return inertia;
         }
         let centroid_local = self.rotation.transpose() * self.centroid.to_vec3();
@@ -233,28 +506,70 @@ where
         inertia
     }
 
-    /// Centroidal variant of the local second moment of area tensor.
-    pub fn centroidal_local_second_moment_of_area(&self) -> Matrix2<f64> {
-        self.centroidal_local_second_moment()
+    /// Centroidal variant of the local second moment of area tensor; same as
+    /// [`Polygon::about_centroid_local`].
+    pub fn centroidal_local_second_moment_of_area(&self) -> Matrix2<V::Scalar> { self.about_centroid_local() }
+
+    /// Second moments of area `[Ixx Ixy; Ixy Iyy]` about the centroid along the
+    /// local X,Y axes, with `Ixx = ∫y² dA`, `Iyy = ∫x² dA` and `Ixy = ∫xy dA`.
+    pub fn about_centroid_local(&self) -> Matrix2<V::Scalar> { self.centroidal_local_second_moment() }
+
+    /// Thin-plate inertia tensor about the centroid in global axes: the local
+    /// tensor `[Ixx −Ixy 0; −Ixy Iyy 0; 0 0 Ixx+Iyy]` rotated to the global frame.
+    pub fn about_centroid_global(&self) -> Matrix3<V::Scalar> { self.embed_plate_inertia(self.about_centroid_local()) }
+
+    /// Thin-plate inertia tensor in global axes about any point, in or out of
+    /// the polygon plane, by the parallel axis theorem `J + A (|d|² I − d dᵀ)`
+    /// with `d` the offset from `point` to the centroid.
+    pub fn about_point(&self, point: &V) -> Matrix3<V::Scalar> {
+        let d = self.centroid.to_vec3() - point.to_vec3();
+        self.about_centroid_global() + (Matrix3::identity() * d.norm_squared() - d * d.transpose()) * self.area()
+    }
+
+    /// Elastic section moduli about the centroidal local X and Y axes, each
+    /// second moment divided by the distance to the extreme fibre: `Wy` and `Wz`
+    /// of a section drawn in its local plane.
+    pub fn elastic_section_modulus(&self) -> Vector2d<V::Scalar> {
+        let zero = V::Scalar::zero();
+        let inertia = self.about_centroid_local();
+        let (mut reach_x, mut reach_y) = (zero, zero);
+        for vertex in &self.vertices {
+            let local = self.rotation.transpose() * (vertex.to_vec3() - self.centroid.to_vec3());
+            reach_x = reach_x.max(local.x.abs());
+            reach_y = reach_y.max(local.y.abs());
+        }
+        let modulus = |second_moment: V::Scalar, reach: V::Scalar| if reach <= V::Scalar::epsilon() { zero } else { second_moment / reach };
+        Vector2d::new(modulus(inertia[(0, 0)], reach_y), modulus(inertia[(1, 1)], reach_x))
+    }
+
+    /// Radii of gyration `√(I / A)` about the centroidal local X and Y axes.
+    pub fn radius_of_gyration(&self) -> Vector2d<V::Scalar> {
+        let inertia = self.about_centroid_local();
+        let area = self.area();
+        if area <= V::Scalar::epsilon() {
+            return Vector2d::new(V::Scalar::zero(), V::Scalar::zero());
+        }
+        Vector2d::new((inertia[(0, 0)] / area).sqrt(), (inertia[(1, 1)] / area).sqrt())
     }
 
     /// Local principal axes in the polygon plane as a 2x2 orthonormal matrix whose
     /// columns are eigenvectors of the local second moment matrix.
-    pub fn local_principal_axes(&self) -> Matrix2<f64> {
+    pub fn local_principal_axes(&self) -> Matrix2<V::Scalar> {
+        let (zero, one) = (V::Scalar::zero(), V::Scalar::one());
         let s = self.centroidal_local_second_moment_of_area();
         let ixx = s[(0, 0)];
         let iyy = s[(1, 1)];
         let ixy = s[(0, 1)];
 
-        if ixy.abs() <= epsilon() {
+        if ixy.abs() <= V::Scalar::epsilon() {
             if ixx <= iyy {
                 return Matrix2::identity();
             }
-            return Matrix2::new(0.0, 1.0, 1.0, 0.0);
+            return Matrix2::new(zero, one, one, zero);
         }
 
-        let two_theta = (-2.0 * ixy).atan2(iyy - ixx);
-        let theta = 0.5 * two_theta;
+        let two_theta = (-(ixy + ixy)).atan2(iyy - ixx);
+        let theta = two_theta * V::Scalar::from_f64_lossy(0.5);
         let cos_t = theta.cos();
         let sin_t = theta.sin();
         Matrix2::new(cos_t, -sin_t, sin_t, cos_t)
@@ -263,54 +578,21 @@ where
     /// Global 3D second moment of area tensor about the modeling origin (first
     /// vertex). This matches the Python bindings where the inertia is reported
     /// before shifting to the centroid.
-    pub fn second_moment_of_area(&self) -> Matrix3<f64> {
+    pub fn second_moment_of_area(&self) -> Matrix3<V::Scalar> {
         let local = self.local_second_moment_of_area();
         self.embed_plate_inertia(local)
     }
 
     /// Centroidal 3D second moment of area tensor (thin-plate inertia rotated to
-    /// the global frame).
-    pub fn centroidal_second_moment_of_area(&self) -> Matrix3<f64> {
-        let local = self.centroidal_local_second_moment_of_area();
-        self.embed_plate_inertia(local)
-    }
+    /// the global frame); same as [`Polygon::about_centroid_global`].
+    pub fn centroidal_second_moment_of_area(&self) -> Matrix3<V::Scalar> { self.about_centroid_global() }
 
     /// Global 3D second moment of area tensor computed about the polygon "center"
     /// (the first provided vertex). This matches the historical helper that returns
     /// inertia prior to shifting into the modeling origin.
-    pub fn second_moment_of_area_at_center(&self) -> Matrix3<f64> {
-        let r_t = self.rotation.transpose();
-        let origin0 = self.vertices[0].to_vec3();
-        let locals: Vec<Vector3<f64>> = self
-            .vertices
-            .iter()
-            .map(|v| r_t * (v.to_vec3() - origin0))
-            .collect();
-
-        let mut area2_sum = 0.0;
-        let mut ix0_sum = 0.0;
-        let mut iy0_sum = 0.0;
-        let mut ixy0_sum = 0.0;
-
-        for i in 0..locals.len() {
-            let p = locals[i];
-            let q = locals[(i + 1) % locals.len()];
-            let cross = p.x * q.y - q.x * p.y;
-            area2_sum += cross;
-            let yy = p.y * p.y + p.y * q.y + q.y * q.y;
-            let xx = p.x * p.x + p.x * q.x + q.x * q.x;
-            let xy = p.x * q.y + 2.0 * p.x * p.y + 2.0 * q.x * q.y + q.x * p.y;
-            ix0_sum += yy * cross;
-            iy0_sum += xx * cross;
-            ixy0_sum += xy * cross;
-        }
-
-        let sign = if area2_sum >= 0.0 { 1.0 } else { -1.0 };
-
-        let ixx0 = (ix0_sum / 12.0) * sign;
-        let iyy0 = (iy0_sum / 12.0) * sign;
-        let ixy0 = (ixy0_sum / 24.0) * sign;
-
+    #[deprecated(note = "use `about_point(&polygon.center())`")]
+    pub fn second_moment_of_area_at_center(&self) -> Matrix3<V::Scalar> {
+        let (_, _, _, ixx0, iyy0, ixy0) = self.planar_moment_terms();
         let mut j_local = Matrix3::zeros();
         j_local[(0, 0)] = ixx0;
         j_local[(1, 1)] = iyy0;
@@ -320,9 +602,9 @@ where
         self.rotation * j_local * self.rotation.transpose()
     }
 
-    fn centroidal_local_second_moment(&self) -> Matrix2<f64> {
+    fn centroidal_local_second_moment(&self) -> Matrix2<V::Scalar> {
         let (area, cx, cy, ix0, iy0, ixy0) = self.planar_moment_terms();
-        if area <= epsilon() {
+        if area <= V::Scalar::epsilon() {
             return Matrix2::zeros();
         }
         let ixx_c = ix0 - area * cy * cy;
@@ -331,7 +613,7 @@ where
         Matrix2::new(ixx_c, ixy_c, ixy_c, iyy_c)
     }
 
-    fn embed_plate_inertia(&self, local: Matrix2<f64>) -> Matrix3<f64> {
+    fn embed_plate_inertia(&self, local: Matrix2<V::Scalar>) -> Matrix3<V::Scalar> {
         let ixx = local[(0, 0)];
         let iyy = local[(1, 1)];
         let ixy = local[(0, 1)];
@@ -344,115 +626,108 @@ where
         self.rotation * j_local * self.rotation.transpose()
     }
 
-    fn planar_moment_terms(&self) -> (f64, f64, f64, f64, f64, f64) {
+    #[allow(clippy::type_complexity)]
+    fn planar_moment_terms(&self) -> (V::Scalar, V::Scalar, V::Scalar, V::Scalar, V::Scalar, V::Scalar) {
+        let (zero, one) = (V::Scalar::zero(), V::Scalar::one());
+        let literal = V::Scalar::from_f64_lossy;
         let r_t = self.rotation.transpose();
         let origin0 = self.vertices[0].to_vec3();
-        let locals: Vec<Vector3<f64>> = self
-            .vertices
-            .iter()
-            .map(|v| r_t * (v.to_vec3() - origin0))
-            .collect();
+        let mut sums = [zero; 6];
+        for ring in self.rings() {
+            let locals: Vec<Vector3<V::Scalar>> = ring.iter().map(|v| r_t * (v.to_vec3() - origin0)).collect();
+            for (total, term) in sums.iter_mut().zip(ring_sums(&locals)) {
+                *total += term;
+            }
+        }
+        let [area2, cx_num, cy_num, ix0_sum, iy0_sum, ixy0_sum] = sums;
 
-        let mut area2 = 0.0;
-        let mut cx_num = 0.0;
-        let mut cy_num = 0.0;
-        let mut ix0_sum = 0.0;
-        let mut iy0_sum = 0.0;
-        let mut ixy0_sum = 0.0;
-
-        for i in 0..locals.len() {
-            let p = locals[i];
-            let q = locals[(i + 1) % locals.len()];
-            let cross = p.x * q.y - q.x * p.y;
-            area2 += cross;
-            cx_num += (p.x + q.x) * cross;
-            cy_num += (p.y + q.y) * cross;
-
-            let yy = p.y * p.y + p.y * q.y + q.y * q.y;
-            let xx = p.x * p.x + p.x * q.x + q.x * q.x;
-            let xy = p.x * q.y + 2.0 * p.x * p.y + 2.0 * q.x * q.y + q.x * p.y;
-            ix0_sum += yy * cross;
-            iy0_sum += xx * cross;
-            ixy0_sum += xy * cross;
-        }
-
-        let area_signed = 0.5 * area2;
-        if area_signed.abs() <= epsilon() {
-            return (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
-        }
-
-        let cx = cx_num / (3.0 * area2);
-        let cy = cy_num / (3.0 * area2);
-        let sign = if area_signed >= 0.0 { 1.0 } else { -1.0 };
-        let ix0 = (ix0_sum / 12.0) * sign;
-        let iy0 = (iy0_sum / 12.0) * sign;
-        let ixy0 = (ixy0_sum / 24.0) * sign;
+        let area_signed = area2 * literal(0.5);
+        if area_signed.abs() <= V::Scalar::epsilon() {
+            return (zero, zero, zero, zero, zero, zero);
+        }
+
+        let cx = cx_num / (literal(3.0) * area2);
+        let cy = cy_num / (literal(3.0) * area2);
+        let sign = if area_signed >= zero { one } else { -one };
+        let ix0 = (ix0_sum / literal(12.0)) * sign;
+        let iy0 = (iy0_sum / literal(12.0)) * sign;
+        let ixy0 = (ixy0_sum / literal(24.0)) * sign;
 
         (area_signed.abs(), cx, cy, ix0, iy0, ixy0)
     }
 
     /// Global 3D principal axes as a 3x3 rotation matrix whose columns are the principal
     /// directions (two in-plane, one along the polygon normal).
-    pub fn principal_axes(&self) -> Matrix3<f64> {
+    pub fn principal_axes(&self) -> Matrix3<V::Scalar> {
+        let zero = V::Scalar::zero();
         let p2 = self.local_principal_axes();
-        let u1_local = Vector3::new(p2[(0, 0)], p2[(1, 0)], 0.0);
-        let u2_local = Vector3::new(p2[(0, 1)], p2[(1, 1)], 0.0);
-        let u3_local = Vector3::new(0.0, 0.0, 1.0);
+        let u1_local = Vector3::new(p2[(0, 0)], p2[(1, 0)], zero);
+        let u2_local = Vector3::new(p2[(0, 1)], p2[(1, 1)], zero);
+        let u3_local = Vector3::z();
         let u1_global = self.rotation * u1_local;
         let u2_global = self.rotation * u2_local;
         let u3_global = self.rotation * u3_local;
         Matrix3::from_columns(&[u1_global, u2_global, u3_global])
     }
 
-    pub fn contains(&self, point: &V) -> bool {
+    /// Whether `point` lies inside the polygon and within `tolerance` of its
+    /// plane ([`Scalar::epsilon`] by default). Inside and outside are decided exactly,
+    /// so points on the border may go either way; see [`Polygon::border_contains`].
+    pub fn contains(&self, point: &V, tolerance: Option<f64>) -> bool {
         // Project to local; ensure on plane; then 2D point-in-polygon (ray crossing)
         let p_local = self.to_local(Vector3d(point.to_vec3()));
-        if p_local.z().abs() > epsilon() {
+        if p_local.z().abs() > V::Scalar::tolerance(tolerance) {
             return false;
         }
-        let r_t = self.rotation.transpose();
-        let origin = self.centroid.to_vec3();
-        let locals: Vec<Vector3<f64>> = self
-            .vertices
-            .iter()
-            .map(|v| r_t * (v.to_vec3() - origin))
-            .collect();
-        // Ray cast along +X
+        // Ray cast along the first flattened axis; crossings with hole edges
+        // toggle back to outside. An edge is crossed when it straddles the ray
+        // and turns around the point in the direction it climbs.
+        let flat = self.flattened();
+        let p = flat(point);
         let mut inside = false;
-        for i in 0..locals.len() {
-            let a = locals[i];
-            let b = locals[(i + 1) % locals.len()];
-            let yi = a.y;
-            let yj = b.y;
-            let xi = a.x;
-            let xj = b.x;
-            let intersects = ((yi > p_local.y() && yj <= p_local.y()) || (yj > p_local.y() && yi <= p_local.y()))
-                && (xi + (p_local.y() - yi) * (xj - xi) / (yj - yi + 1e-30) > p_local.x());
-            if intersects {
-                inside = !inside;
+        for ring in self.rings() {
+            for (a, b) in ring_edges(&ring.iter().map(&flat).collect::<Vec<_>>()) {
+                if (a.y > p.y) != (b.y > p.y) && (predicates::orient2d(&a, &b, &p) > 0.0) == (b.y > a.y) {
+                    inside = !inside;
+                }
             }
         }
         inside
     }
 
-    pub fn border_contains(&self, point: &V) -> bool {
+    /// Maps points to two of their global coordinates, dropping the one the
+    /// normal leans on most: a projection onto the plane without rounding, so
+    /// the exact predicates see the vertices as given. Single-precision
+    /// coordinates widen to `f64` exactly.
+    fn flattened(&self) -> impl Fn(&V) -> Vector2<f64> + use<V> {
+        let (i, j) = match self.rotation.column(2).map(Scalar::as_f64).iamax() {
+            0 => (1, 2),
+            1 => (2, 0),
+            _ => (0, 1),
+        };
+        move |v| {
+            let p = v.to_vec3();
+            Vector2::new(p[i].as_f64(), p[j].as_f64())
+        }
+    }
+
+    /// Whether `point` lies within `tolerance` of a boundary edge, [`Scalar::epsilon`] by default.
+    pub fn border_contains(&self, point: &V, tolerance: Option<f64>) -> bool {
+        let tolerance = V::Scalar::tolerance(tolerance);
         let p_local = self.to_local(Vector3d(point.to_vec3()));
-        if p_local.z().abs() > epsilon() {
+        if p_local.z().abs() > tolerance {
             return false;
         }
         let r_t = self.rotation.transpose();
         let origin = self.centroid.to_vec3();
-        let locals: Vec<Vector3<f64>> = self
-            .vertices
-            .iter()
-            .map(|v| r_t * (v.to_vec3() - origin))
-            .collect();
-
-        for i in 0..locals.len() {
-            let a = locals[i];
-            let b = locals[(i + 1) % locals.len()];
-            if point_on_segment_2d(p_local.0, a, b) {
-                return true;
+        for ring in self.rings() {
+            let locals: Vec<Vector3<V::Scalar>> = ring.iter().map(|v| r_t * (v.to_vec3() - origin)).collect();
+            for i in 0..locals.len() {
+                let a = locals[i];
+                let b = locals[(i + 1) % locals.len()];
+                if point_on_segment_2d(p_local.0, a, b, tolerance) {
+                    return true;
+                }
             }
         }
         false
@@ -466,24 +741,87 @@ where
         let to_point = p - c;
         let p_proj = p - n * to_point.dot(&n);
         let p_proj_v = V::from_vec3(p_proj);
-        if self.contains(&p_proj_v) || self.border_contains(&p_proj_v) {
+        if self.contains(&p_proj_v, None) || self.border_contains(&p_proj_v, None) {
             return p_proj_v;
         }
-        // Otherwise, closest among edges
+        // Otherwise, closest among edges (including hole edges)
         let mut best = self.vertices[0];
-        let mut best_dist = f64::INFINITY;
-        for line in self.lines() {
+        let mut best_dist = None;
+        for line in self.boundary_lines() {
             let cp = line.closest_point(&p_proj_v);
             let d = cp.sub(&p_proj_v).norm();
-            if d < best_dist {
-                best_dist = d;
+            if best_dist.is_none_or(|best_dist| d < best_dist) {
+                best_dist = Some(d);
                 best = cp;
             }
         }
         best
     }
 
-    pub fn intersection_with_line(&self, line: &Line<V>, treat_as_ray: bool) -> Vec<V> {
+    /// Closest point on the boundary (outer ring or a hole), with the edge or
+    /// vertex it lies on. Unlike [`Self::closest_point`], interior points are
+    /// projected onto the boundary as well; the sign of the distance tells on
+    /// which side of it the query point is. Points within `tolerance`
+    /// ([`Scalar::epsilon`] by default) of a vertex, measured along the edge, snap to it.
+    pub fn closest_boundary_point(&self, point: &V, tolerance: Option<f64>) -> BoundaryPoint<V> {
+        let zero = V::Scalar::zero();
+        let tol = V::Scalar::tolerance(tolerance);
+        let p = point.to_vec3();
+        let plane_offset = (p - self.centroid.to_vec3()).dot(&self.normal);
+        let projected = V::from_vec3(p - self.normal * plane_offset);
+
+        #[allow(clippy::type_complexity)]
+        let mut best: Option<(V::Scalar, usize, usize, V::Scalar, V::Scalar, V)> = None;
+        for (ring_index, ring) in self.rings().enumerate() {
+            for (index, line) in ring_lines(ring).iter().enumerate() {
+                let closest = line.closest_point(&projected);
+                let distance = closest.sub(&projected).norm();
+                if best.is_none_or(|b| distance < b.0) {
+                    let along = closest.sub(&line.start()).norm();
+                    best = Some((distance, ring_index, index, along, line.length(), closest));
+                }
+            }
+        }
+        let (distance, ring, index, along, length, point) = best.expect("polygon has at least one edge");
+        let (feature, parameter) = if along <= tol {
+            (BoundaryFeature::Vertex { ring, index }, zero)
+        } else if length - along <= tol {
+            let n = self.rings().nth(ring).map_or(1, Vec::len);
+            (BoundaryFeature::Vertex { ring, index: (index + 1) % n }, zero)
+        } else {
+            (BoundaryFeature::Edge { ring, index }, along / length)
+        };
+        let inside = distance > tol && self.contains(&projected, tolerance);
+        BoundaryPoint {
+            point,
+            feature,
+            parameter,
+            signed_distance: if inside { -distance } else { distance },
+            plane_offset,
+        }
+    }
+
+    /// Points where the infinite line through `line` meets the polygon. A line
+    /// crossing the plane gives at most one point; a line in the plane gives
+    /// the ends of every part of it inside the polygon, in order along the
+    /// line, see [`Polygon::clip_line`]. Points within `tolerance` ([`Scalar::epsilon`]
+    /// by default) of the polygon count as on it.
+    pub fn intersection_with_line(&self, line: &Line<V>, tolerance: Option<f64>) -> Vec<V> {
+        self.crossings(line, None, tolerance)
+    }
+
+    /// Parts of the infinite line through `line` inside the polygon, boundary
+    /// included and holes excluded, as segments of positive length in order
+    /// along the line. Empty unless the line lies within `tolerance` of the
+    /// polygon's plane, by default a small fraction of the polygon's size.
+    pub fn clip_line(&self, line: &Line<V>, tolerance: Option<f64>) -> Vec<Line<V>> {
+        self.clipped(line, None, tolerance)
+    }
+
+    /// [`Polygon::intersection_with_line`] for the part of the line from
+    /// parameter `from` onwards, or all of it without; rays start at 0.
+    pub(crate) fn crossings(&self, line: &Line<V>, from: Option<V::Scalar>, tolerance: Option<f64>) -> Vec<V> {
+        let eps = V::Scalar::epsilon();
         // Intersect infinite line with plane of polygon, then test containment
         // Line parametric: L(s) = s_start + s * dir
         let s0 = line.start().to_vec3();
@@ -491,27 +829,77 @@ where
         let dir = s1 - s0;
         let n = self.normal;
         let denom = n.dot(&dir);
-        if denom.abs() <= epsilon() {
-            // Parallel: no intersection unless coplanar (not handled here)
-            return Vec::new();
+        if denom.abs() <= eps * dir.norm() {
+            // Parallel: only a coplanar line meets the polygon, along segments.
+            return self.clipped(line, from, tolerance).iter().flat_map(|segment| [segment.start(), segment.end()]).collect();
         }
         let c = self.centroid.to_vec3();
         let t = n.dot(&(c - s0)) / denom;
-        if treat_as_ray && t < -epsilon() {
+        if from.is_some_and(|from| t < from - eps) {
             return Vec::new();
         }
-        let t = if treat_as_ray && t < 0.0 { 0.0 } else { t };
+        let t = from.map_or(t, |from| t.max(from));
         let p = s0 + dir * t;
         let p_v = V::from_vec3(p);
-        if self.contains(&p_v) || self.border_contains(&p_v) {
+        if self.contains(&p_v, tolerance) || self.border_contains(&p_v, tolerance) {
             vec![p_v]
         } else {
             Vec::new()
         }
     }
 
+    /// [`Polygon::clip_line`] for the part of the line from parameter `from`
+    /// onwards, or all of it without.
+    pub(crate) fn clipped(&self, line: &Line<V>, from: Option<V::Scalar>, tolerance: Option<f64>) -> Vec<Line<V>> {
+        let one = V::Scalar::one();
+        let tol = tolerance.map_or_else(|| self.boolean_tolerance(self), V::Scalar::from_f64_lossy);
+        let (start, end) = (self.to_local(Vector3d(line.start().to_vec3())), self.to_local(Vector3d(line.end().to_vec3())));
+        let (a, d) = (Vector2d::new(start.x(), start.y()), Vector2d::new(end.x() - start.x(), end.y() - start.y()));
+        if start.z().abs() > tol || end.z().abs() > tol || d.norm() <= tol {
+            return Vec::new();
+        }
+        // Line parameters where the line crosses or touches an edge.
+        let rings = self.local_rings(self, tol).expect("polygon lies in its own plane");
+        let mut cuts: Vec<V::Scalar> = Vec::new();
+        for (p, q) in rings.iter().flat_map(|ring| ring_edges(ring)) {
+            let edge = q - p;
+            let denom = d.cross(&edge);
+            if denom.abs() <= V::Scalar::epsilon() * d.norm() * edge.norm() {
+                continue;
+            }
+            let along_edge = (p - a).cross(&d) / denom;
+            let slack = tol / edge.norm();
+            if (-slack..=one + slack).contains(&along_edge) {
+                cuts.push((p - a).cross(&edge) / denom);
+            }
+        }
+        if let Some(from) = from {
+            cuts.retain(|&t| t > from);
+            cuts.push(from);
+        }
+        cuts.sort_by(|a, b| a.as_f64().total_cmp(&b.as_f64()));
+        cuts.dedup_by(|t, previous| (*t - *previous) * d.norm() <= tol);
+
+        let at = |t: V::Scalar| V::from_vec3(self.to_global(Vector3d::from(a + d * t)).0);
+        let mut spans: Vec<(V::Scalar, V::Scalar)> = Vec::new();
+        for pair in cuts.windows(2) {
+            let middle = at((pair[0] + pair[1]) * V::Scalar::from_f64_lossy(0.5));
+            if !(self.contains(&middle, tolerance) || self.border_contains(&middle, tolerance)) {
+                continue;
+            }
+            match spans.last_mut() {
+                Some(span) if span.1 == pair[0] => span.1 = pair[1],
+                _ => spans.push((pair[0], pair[1])),
+            }
+        }
+        spans.into_iter().map(|(t0, t1)| Line::new(at(t0), at(t1))).collect()
+    }
+
     pub fn reverse(&mut self) {
         self.vertices.reverse();
+        for hole in &mut self.holes {
+            hole.reverse();
+        }
         // Flip normal and rebuild rotation
         self.normal = -self.normal;
         let ex = Vector3::new(self.rotation.column(0)[0], self.rotation.column(0)[1], self.rotation.column(0)[2]);
@@ -522,56 +910,520 @@ where
         self.area = -self.area;
     }
 
-    /// Whether the polygon is valid: non-degenerate area and no self-intersections.
-    pub fn is_valid(&self) -> bool {
-        self.area().abs() > epsilon() && !self.self_intersects()
+    /// Whether both polygons lie in the same plane, up to the boolean tolerance.
+    pub fn is_coplanar(&self, other: &Self) -> bool {
+        let tol = self.boolean_tolerance(other);
+        self.normal.cross(&other.normal).norm() <= tol && self.local_rings(other, tol).is_some()
     }
 
-    fn self_intersects(&self) -> bool {
-        let edges = self.lines();
-        let n = edges.len();
-        for i in 0..n {
-            for j in i + 1..n {
-                // Skip adjacent edges and the first-last pair
-                let next_i = (i + 1) % n;
-                let next_j = (j + 1) % n;
-                if j == i || j == next_i || i == next_j || (i == 0 && j == n - 1) {
-                    continue;
+    /// Length tolerance for boolean operations, scaled to the extent of both polygons.
+    fn boolean_tolerance(&self, other: &Self) -> V::Scalar {
+        let extent = self.bounding_box().union(&other.bounding_box()).extent().norm();
+        V::Scalar::epsilon().max(V::Scalar::from_f64_lossy(1e-9 * extent))
+    }
+
+    /// Rings of `polygon` (outer first) in this polygon's local XY plane, or `None`
+    /// if any vertex lies off the plane.
+    fn local_rings(&self, polygon: &Self, tol: V::Scalar) -> Option<Vec<Vec<Vector2d<V::Scalar>>>> {
+        polygon
+            .rings()
+            .map(|ring| {
+                ring.iter()
+                    .map(|v| {
+                        let local = self.to_local(Vector3d(v.to_vec3()));
+                        (local.z().abs() <= tol).then(|| Vector2d::new(local.x(), local.y()))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Whether the polygon is valid: non-degenerate area, no self-intersections and
+    /// every hole strictly inside the outer boundary without touching other rings.
+    /// Rings closer than `tolerance` count as touching; see [`Polygon::self_intersects`].
+    pub fn is_valid(&self, tolerance: Option<f64>) -> bool {
+        self.area() > V::Scalar::epsilon() && !self.self_intersects(tolerance) && self.holes_are_enclosed(tolerance)
+    }
+
+    /// Whether a boundary ring crosses or touches itself anywhere but at the
+    /// corners neighboring edges share. Edges closer than `tolerance` touch;
+    /// without one the question is decided exactly, with the robust predicates
+    /// on the vertices as given.
+    pub fn self_intersects(&self, tolerance: Option<f64>) -> bool {
+        let touch = self.edge_contact(tolerance);
+        self.rings().any(|ring| {
+            let n = ring.len();
+            // Skip adjacent edges and the first-last pair
+            (0..n).any(|i| (i + 2..n).any(|j| !(i == 0 && j == n - 1) && touch((ring[i], ring[(i + 1) % n]), (ring[j], ring[(j + 1) % n]))))
+        })
+    }
+
+    fn holes_are_enclosed(&self, tolerance: Option<f64>) -> bool {
+        let outer = Self::new(self.vertices.iter().copied());
+        let touch = self.edge_contact(tolerance);
+        let rings: Vec<&Vec<V>> = self.rings().collect();
+        self.holes.iter().enumerate().all(|(h, hole)| {
+            let index = h + 1;
+            hole.iter().all(|v| outer.contains(v, tolerance) && !outer.border_contains(v, tolerance))
+                && rings.iter().enumerate().all(|(other, other_ring)| {
+                    other == index || ring_edges(hole).all(|a| ring_edges(other_ring).all(|b| !touch(a, b)))
+                })
+        })
+    }
+
+    /// Whether two edges share a point: exactly without a tolerance, or by
+    /// coming within it.
+    fn edge_contact(&self, tolerance: Option<f64>) -> impl Fn((V, V), (V, V)) -> bool + use<V> {
+        let flat = self.flattened();
+        move |(a, b), (c, d)| match tolerance {
+            None => predicates::segments_intersect(&flat(&a), &flat(&b), &flat(&c), &flat(&d)),
+            Some(tolerance) => Line::<V>::new(a, b).closest_points(&Line::new(c, d)).2 <= V::Scalar::from_f64_lossy(tolerance),
+        }
+    }
+}
+
+/// Frames, planes, rigid moves and the boolean operations, which work on the
+/// double-precision types.
+impl<V> Polygon<V>
+where
+    V: ArcVector<Scalar = f64>,
+{
+    pub fn local_axis(&self) -> LocalAxis {
+        // Use centroid as origin
+        LocalAxis::new(Vector3d(self.centroid.to_vec3()), self.rotation)
+    }
+
+    /// Orientation triad at the centroid; see [`LocalAxis::triad_geometry`].
+    pub fn triad_geometry(&self, scale: f64) -> [crate::TriadLine; 3] { self.local_axis().triad_geometry(scale) }
+
+    /// Plane of the polygon, with the local axes as in-plane frame.
+    pub fn plane(&self) -> Plane { Plane::from_local_axis(&self.local_axis()) }
+
+    /// Second moment of area about an axis line, `nᵀ J n` with `J` taken about
+    /// a point of the axis. Panics on a degenerate axis.
+    pub fn about_axis(&self, axis: &crate::Line3d) -> f64 {
+        let direction = axis.direction().expect("axis must have non-zero length").0;
+        let inertia = self.about_point(&V::from_vec3(axis.start().0));
+        direction.dot(&(inertia * direction))
+    }
+
+    /// First moment of area about an in-plane axis line, `A·d` with `d` the
+    /// distance of the centroid from the axis, positive to the left of the axis
+    /// seen from the side the normal points to. Panics on a degenerate axis.
+    pub fn first_moment_of_area(&self, axis: &crate::Line3d) -> f64 {
+        let direction = axis.direction().expect("axis must have non-zero length").0;
+        let side = self.normal.cross(&direction);
+        self.area() * (self.centroid.to_vec3() - axis.start().0).dot(&side)
+    }
+
+    /// Static moment `Q(y)` of the part above the local line `y` (measured from
+    /// the centroid along the local Y axis) about the centroidal local X axis,
+    /// as used in the shear stress `V·Q / (I·b)`. Zero at and beyond the
+    /// extreme fibres.
+    pub fn static_moment_above(&self, y: f64) -> f64 {
+        let ey = self.rotation.column(1).into_owned();
+        let centroid = self.centroid.to_vec3();
+        let cut = Plane::new(Vector3d(centroid + ey * y), Vector3d(ey));
+        self.clip_by_plane(&cut).iter().map(|part| part.area() * (part.centroid.to_vec3() - centroid).dot(&ey)).sum()
+    }
+
+    /// Rotate by `angle` (radians, right-hand rule) about `axis`. The cached
+    /// frame, normal and centroid are rotated along with the vertices, so the
+    /// local axes keep their alignment to the outline. A degenerate axis
+    /// leaves the polygon unchanged. 2D polygons should only be rotated about
+    /// axes parallel to Z.
+    pub fn rotated_about(&self, axis: &crate::Line3d, angle: f64) -> Self {
+        if axis.length() <= epsilon() {
+            return self.clone();
+        }
+        self.moved(&Transform3d::rotation_about(angle, axis.end() - axis.start(), axis.start()))
+    }
+
+    /// Translate by `offset`; area, perimeter and local axes are unchanged.
+    pub fn translated<T: Into<V>>(&self, offset: T) -> Self {
+        self.moved(&Transform3d::translation(Vector3d(offset.into().to_vec3())))
+    }
+
+    /// Move by `offset` in place; area, perimeter and local axes are unchanged.
+    pub fn translate<T: Into<V>>(&mut self, offset: T) { *self = self.translated(offset); }
+
+    /// Rotate in place by `angle` (radians, right-hand rule) about the axis
+    /// along `axis` through `about`, see [`Polygon::rotated_about`]. Panics on a
+    /// zero axis.
+    pub fn rotate<A: Into<Vector3d>, P: Into<V>>(&mut self, angle: f64, axis: A, about: P) {
+        *self = self.moved(&Transform3d::rotation_about(angle, axis, Vector3d(about.into().to_vec3())));
+    }
+
+    /// Scale in place by `factor` about the point `about`: distances grow by
+    /// the factor and the area by its square, while normal and local axes keep
+    /// their directions. Panics unless the factor is positive.
+    pub fn scale<P: Into<V>>(&mut self, factor: f64, about: P) {
+        *self = self.moved(&Transform3d::scaling_about(factor, Vector3d(about.into().to_vec3())));
+    }
+
+    /// The polygon carried by a similarity transform, its cached frame, normal
+    /// and centroid included. A mirror reverses the winding, so the normal is
+    /// flipped to follow it while the in-plane axes follow the outline.
+    fn moved(&self, transform: &Transform3d) -> Self {
+        let map = |v: &V| V::from_vec3(transform.apply_point(&Vector3d(v.to_vec3())).0);
+        let ring = |ring: &[V]| ring.iter().map(map).collect::<Vec<V>>();
+        let (scale, orthogonal) = (transform.scale(), transform.linear() / transform.scale());
+        let flip = if transform.is_mirroring() { -1.0 } else { 1.0 };
+        let (x, y, z) = (self.rotation.column(0), self.rotation.column(1), self.rotation.column(2));
+        Self {
+            vertices: ring(&self.vertices),
+            holes: self.holes.iter().map(|hole| ring(hole)).collect(),
+            normal: orthogonal * self.normal * flip,
+            rotation: Matrix3::from_columns(&[orthogonal * x, orthogonal * y, orthogonal * z * flip]),
+            centroid: map(&self.centroid),
+            area: self.area * scale * scale,
+            perimeter: self.perimeter * scale,
+        }
+    }
+
+    /// Union with a coplanar polygon. Overlapping or edge-sharing polygons merge into
+    /// one; disjoint or non-coplanar polygons are returned unchanged.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        match self.boolean(other, BooleanOp::Union) {
+            Some(result) => result,
+            None => vec![self.clone(), other.clone()],
+        }
+    }
+
+    /// Region shared by this polygon and a coplanar polygon. Empty when they do not
+    /// overlap or are not coplanar.
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        self.boolean(other, BooleanOp::Intersection).unwrap_or_default()
+    }
+
+    /// Whether the polygons share at least one point; touching counts. Coplanar
+    /// polygons intersect when they overlap, touch, or one lies inside the
+    /// other; other pairs when their surfaces cross or touch.
+    pub fn intersects(&self, other: &Self) -> bool {
+        if !self.crossing_points(other).is_empty() {
+            return true;
+        }
+        let on = |polygon: &Self, point: &V| polygon.contains(point, None) || polygon.border_contains(point, None);
+        self.is_coplanar(other) && (on(self, &other.vertices[0]) || on(other, &self.vertices[0]))
+    }
+
+    /// Area shared with a coplanar polygon; zero for non-coplanar pairs.
+    pub fn overlap_area(&self, other: &Self) -> f64 { self.intersection(other).iter().map(|p| p.area()).sum() }
+
+    /// Points where the boundaries of the polygons meet. For coplanar polygons
+    /// these are the crossings of their edges, with both ends of any collinear
+    /// overlap. Otherwise they are the points where the edges of either polygon
+    /// pass through the other, i.e. the ends of the segments along which the
+    /// surfaces cross. Points closer than the tolerance are reported once.
+    pub fn crossing_points(&self, other: &Self) -> Vec<V> {
+        let tol = self.boolean_tolerance(other);
+        let mut points: Vec<Vector3<f64>> = Vec::new();
+        if self.is_coplanar(other) {
+            let region_a = self.local_rings(self, tol).expect("polygon lies in its own plane");
+            let region_b = self.local_rings(other, tol).expect("coplanar polygon");
+            for a in region_a.iter().flat_map(|ring| ring_edges(ring)) {
+                for b in region_b.iter().flat_map(|ring| ring_edges(ring)) {
+                    for p in segment_crossings(a, b, tol) {
+                        points.push(self.to_global(Vector3d::from(p)).0);
+                    }
                 }
-                if let Some(_p) = edges[i].intersection(&edges[j], false) {
-                    // Ignore intersections exactly at non-shared endpoints (handled by adjacency skip)
-                    // If we got here, it's a proper crossing
-                    return true;
+            }
+        } else {
+            for (edges, target) in [(self.boundary_lines(), other), (other.boundary_lines(), self)] {
+                let plane = target.plane();
+                for edge in edges {
+                    let line = Line::new(Vector3d(edge.start().to_vec3()), Vector3d(edge.end().to_vec3()));
+                    let Some(point) = plane.intersection_with_line(&line) else { continue };
+                    let point_v = V::from_vec3(point.0);
+                    if target.contains(&point_v, None) || target.border_contains(&point_v, None) {
+                        points.push(point.0);
+                    }
                 }
             }
         }
-        false
+        let mut unique: Vec<Vector3<f64>> = Vec::new();
+        for point in points {
+            if unique.iter().all(|q| (q - point).norm() > tol) {
+                unique.push(point);
+            }
+        }
+        unique.into_iter().map(V::from_vec3).collect()
+    }
+
+    /// This polygon with a coplanar polygon removed. A fully enclosed subtrahend
+    /// leaves a hole in the result.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        match self.boolean(other, BooleanOp::Difference) {
+            Some(result) => result,
+            None => vec![self.clone()],
+        }
+    }
+
+    /// Part of the polygon on the positive side of `plane` (the side its normal
+    /// points to); a non-convex polygon may fall apart into several pieces. Points
+    /// on the plane count as inside, so a polygon parallel to the plane is kept or
+    /// dropped whole.
+    pub fn clip_by_plane(&self, plane: &Plane) -> Vec<Self> {
+        let tol = self.boolean_tolerance(self);
+        let distances: Vec<f64> = self.vertices.iter().map(|v| plane.signed_distance(&Vector3d(v.to_vec3()))).collect();
+        if distances.iter().all(|&d| d >= -tol) {
+            return vec![self.clone()];
+        }
+        if distances.iter().all(|&d| d <= tol) {
+            return Vec::new();
+        }
+        let Some(region) = self.local_rings(self, tol) else {
+            return Vec::new();
+        };
+
+        // Signed distance is affine in local coordinates: origin + gradient · p.
+        let distance = |p: Vector2d| plane.signed_distance(&self.to_global(Vector3d::from(p)));
+        let origin = distance(Vector2d::new(0.0, 0.0));
+        let gradient = Vector2d::new(distance(Vector2d::new(1.0, 0.0)) - origin, distance(Vector2d::new(0.0, 1.0)) - origin);
+
+        // Cut an enlarged bounding box of the outer ring down to the half-plane and
+        // intersect with it.
+        let min = region[0].iter().fold(Vector2::repeat(f64::INFINITY), |m, p| m.inf(&p.0));
+        let max = region[0].iter().fold(Vector2::repeat(f64::NEG_INFINITY), |m, p| m.sup(&p.0));
+        let margin = Vector2::repeat((max - min).norm());
+        let (low, high) = (Vector2d(min - margin), Vector2d(max + margin));
+        let corners = [low, Vector2d::new(high.x(), low.y()), high, Vector2d::new(low.x(), high.y())];
+        let mut half = Vec::new();
+        for (i, &a) in corners.iter().enumerate() {
+            let b = corners[(i + 1) % 4];
+            let (da, db) = (origin + gradient.dot(&a), origin + gradient.dot(&b));
+            if da >= 0.0 {
+                half.push(a);
+            }
+            if (da >= 0.0) != (db >= 0.0) {
+                half.push(a + (b - a) * (da / (da - db)));
+            }
+        }
+        boolean::group_rings(boolean::boolean(&region, &[half], BooleanOp::Intersection, tol))
+            .into_iter()
+            .map(|(outer, holes)| self.polygon_from_local_rings(&outer, &holes))
+            .collect()
+    }
+
+    /// Run a boolean operation in this polygon's local plane. Returns `None` when the
+    /// polygons are not coplanar.
+    fn boolean(&self, other: &Self, op: BooleanOp) -> Option<Vec<Self>> {
+        let tol = self.boolean_tolerance(other);
+        if self.normal.cross(&other.normal).norm() > tol {
+            return None;
+        }
+        let region_a = self.local_rings(self, tol)?;
+        let region_b = self.local_rings(other, tol)?;
+        let rings = boolean::boolean(&region_a, &region_b, op, tol);
+        Some(
+            boolean::group_rings(rings)
+                .into_iter()
+                .map(|(outer, holes)| self.polygon_from_local_rings(&outer, &holes))
+                .collect(),
+        )
+    }
+
+    /// Offset the polygon by `distance` in its plane with mitred corners (see
+    /// [`OffsetJoin::default`]). Positive distances grow the polygon and shrink its
+    /// holes; negative distances shrink it, which may split it into several
+    /// polygons or remove it entirely.
+    pub fn offset(&self, distance: f64) -> Vec<Self> {
+        self.offset_with(distance, OffsetJoin::default())
+    }
+
+    /// Offset with an explicit corner treatment where offset edges separate
+    /// (convex corners when growing, concave corners when shrinking).
+    pub fn offset_with(&self, distance: f64, join: OffsetJoin) -> Vec<Self> {
+        if distance == 0.0 {
+            return vec![self.clone()];
+        }
+        let tol = self.boolean_tolerance(self).max(1e-9 * distance.abs());
+        let Some(region) = self.local_rings(self, tol) else {
+            return Vec::new();
+        };
+        offset::offset(&region, distance, join, tol)
+            .into_iter()
+            .map(|(outer, holes)| self.polygon_from_local_rings(&outer, &holes))
+            .collect()
+    }
+
+    fn polygon_from_local_rings(&self, outer: &[Vector2d], holes: &[Ring]) -> Self {
+        let to_global = |p: &Vector2d| V::from_vec3(self.to_global(Vector3d::from(*p)).0);
+        Self::with_holes(
+            outer.iter().map(to_global),
+            holes.iter().map(|hole| hole.iter().map(to_global).collect::<Vec<V>>()),
+        )
     }
 }
 
-fn point_on_segment_2d(p: Vector3<f64>, a: Vector3<f64>, b: Vector3<f64>) -> bool {
-    // Check if p is on segment ab in 2D (x,y)
-    let ap = p - a;
-    let ab = b - a;
-    let cross = ap.x * ab.y - ap.y * ab.x;
-    if cross.abs() > epsilon() {
-        return false;
+/// Common points of two segments: the crossing point, or both ends of the
+/// shared part of collinear overlapping segments.
+fn segment_crossings(
+    (a0, a1): (Vector2d, Vector2d),
+    (b0, b1): (Vector2d, Vector2d),
+    tol: f64,
+) -> Vec<Vector2d> {
+    let (da, db) = (a1 - a0, b1 - b0);
+    let (la, lb) = (da.norm(), db.norm());
+    if la <= tol || lb <= tol {
+        return Vec::new();
     }
-    let dot = ap.x * ab.x + ap.y * ab.y;
-    if dot < -epsilon() {
-        return false;
+    let denominator = da.cross(&db);
+    let offset = b0 - a0;
+    if denominator.abs() <= tol * la.max(lb) * 1e-3 {
+        // Parallel: only collinear segments can share points.
+        if offset.cross(&da).abs() / la > tol {
+            return Vec::new();
+        }
+        let along = |p: Vector2d| (p - a0).dot(&da) / (la * la);
+        let (t0, t1) = (along(b0), along(b1));
+        let (start, end) = (t0.min(t1).max(0.0), t0.max(t1).min(1.0));
+        if (end - start) * la < -tol {
+            return Vec::new();
+        }
+        let (p, q) = (a0 + da * start, a0 + da * end.max(start));
+        return if (q - p).norm() <= tol { vec![p] } else { vec![p, q] };
+    }
+    let t = offset.cross(&db) / denominator;
+    let u = offset.cross(&da) / denominator;
+    let (slack_a, slack_b) = (tol / la, tol / lb);
+    if t < -slack_a || t > 1.0 + slack_a || u < -slack_b || u > 1.0 + slack_b {
+        return Vec::new();
     }
-    let ab_len2 = ab.x * ab.x + ab.y * ab.y;
-    if dot > ab_len2 + epsilon() {
+    vec![a0 + da * t.clamp(0.0, 1.0)]
+}
+
+/// Edges of a closed ring as pairs of corners.
+fn ring_edges<V: Copy>(ring: &[V]) -> impl Iterator<Item = (V, V)> + '_ { (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])) }
+
+fn ring_lines<V: ArcVector>(ring: &[V]) -> Vec<Line<V>> {
+    let n = ring.len();
+    (0..n).map(|i| Line::new(ring[i], ring[(i + 1) % n])).collect()
+}
+
+/// Shoelace sums for one ring of local points: `[2A, Sx, Sy, Ixx, Iyy, Ixy]`
+/// numerators. Orientation determines the sign, so rings can be accumulated.
+fn ring_sums<T: Scalar>(locals: &[Vector3<T>]) -> [T; 6] {
+    let two = T::from_f64_lossy(2.0);
+    let mut sums = [T::zero(); 6];
+    for (p, q) in ring_edges(locals) {
+        let (p, q) = (Vector2d::new(p.x, p.y), Vector2d::new(q.x, q.y));
+        let cross = p.cross(&q);
+        sums[0] += cross;
+        sums[1] += (p.x() + q.x()) * cross;
+        sums[2] += (p.y() + q.y()) * cross;
+        sums[3] += (p.y() * p.y() + p.y() * q.y() + q.y() * q.y()) * cross;
+        sums[4] += (p.x() * p.x() + p.x() * q.x() + q.x() * q.x()) * cross;
+        sums[5] += (p.x() * q.y() + two * p.x() * p.y() + two * q.x() * q.y() + q.x() * p.y()) * cross;
+    }
+    sums
+}
+
+fn point_on_segment_2d<T: Scalar>(p: Vector3<T>, a: Vector3<T>, b: Vector3<T>, tolerance: T) -> bool {
+    // Check if p is on segment ab in 2D (x,y), the products scaled to lengths
+    let (ap, ab) = (Vector2d::new(p.x - a.x, p.y - a.y), Vector2d::new(b.x - a.x, b.y - a.y));
+    let ab_len = ab.norm();
+    if ab_len <= tolerance {
+        return ap.norm() <= tolerance;
+    }
+    if ap.cross(&ab).abs() > tolerance * ab_len {
         return false;
     }
-    true
+    (-tolerance * ab_len..=ab_len * (ab_len + tolerance)).contains(&ap.dot(&ab))
+}
+
+impl<V: ArcVector> Polygon<V> {
+    /// Point where the segment `line` passes through the polygon from one side
+    /// of its plane to the other, if it does.
+    fn piercing(&self, line: &Line<V>) -> Option<V> {
+        let (a, b) = (line.start().to_vec3(), line.end().to_vec3());
+        let centroid = self.centroid.to_vec3();
+        let (da, db) = ((a - centroid).dot(&self.normal), (b - centroid).dot(&self.normal));
+        if da * db > V::Scalar::zero() || da == db {
+            return None;
+        }
+        let point = V::from_vec3(a + (b - a) * (da / (da - db)));
+        (self.contains(&point, None) || self.border_contains(&point, None)).then_some(point)
+    }
+}
+
+impl<V: ArcVector> crate::DistanceTo<V, V> for Polygon<V> {
+    /// The nearest point of the polygon's area, see [`Polygon::closest_point`].
+    fn distance_to(&self, point: &V) -> (V, V, V::Scalar) {
+        let closest = self.closest_point(point);
+        (closest, *point, closest.sub(point).norm())
+    }
+}
+
+impl<V: ArcVector> crate::DistanceTo<Line<V>, V> for Polygon<V> {
+    /// Zero where the segment passes through the polygon; otherwise the nearest
+    /// of the segment ends to the polygon and the boundary edges to the segment.
+    fn distance_to(&self, line: &Line<V>) -> (V, V, V::Scalar) {
+        if let Some(point) = self.piercing(line) {
+            return (point, point, V::Scalar::zero());
+        }
+        let ends = [line.start(), line.end()].map(|end| self.distance_to(&end));
+        let edges = self.boundary_lines().into_iter().map(|edge| edge.closest_points(line));
+        ends.into_iter().chain(edges).min_by(|a, b| a.2.as_f64().total_cmp(&b.2.as_f64())).expect("segment has ends")
+    }
+}
+
+impl<V: ArcVector> crate::DistanceTo<Polygon<V>, V> for Polygon<V> {
+    /// The nearest of every boundary edge of one polygon to the other polygon.
+    fn distance_to(&self, other: &Self) -> (V, V, V::Scalar) {
+        let own = self.boundary_lines().into_iter().map(|edge| {
+            let (on_other, on_edge, distance) = other.distance_to(&edge);
+            (on_edge, on_other, distance)
+        });
+        let theirs = other.boundary_lines().into_iter().map(|edge| self.distance_to(&edge));
+        own.chain(theirs).min_by(|a, b| a.2.as_f64().total_cmp(&b.2.as_f64())).expect("polygons have edges")
+    }
+}
+
+/// Ring by ring and vertex by vertex, so outlines traced from different
+/// start vertices differ.
+#[cfg(feature = "approx")]
+impl<V: ArcVector + approx::AbsDiffEq<Epsilon = <V as LineVector>::Scalar>> approx::AbsDiffEq for Polygon<V> {
+    type Epsilon = V::Scalar;
+    fn default_epsilon() -> V::Scalar { V::default_epsilon() }
+    fn abs_diff_eq(&self, other: &Self, epsilon: V::Scalar) -> bool { self.rings_match(other, |a, b| a.abs_diff_eq(b, epsilon)) }
+}
+
+#[cfg(feature = "approx")]
+impl<V: ArcVector + approx::RelativeEq<Epsilon = <V as LineVector>::Scalar>> approx::RelativeEq for Polygon<V> {
+    fn default_max_relative() -> V::Scalar { V::default_max_relative() }
+    fn relative_eq(&self, other: &Self, epsilon: V::Scalar, max_relative: V::Scalar) -> bool {
+        self.rings_match(other, |a, b| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<V: ArcVector + approx::UlpsEq<Epsilon = <V as LineVector>::Scalar>> approx::UlpsEq for Polygon<V> {
+    fn default_max_ulps() -> u32 { V::default_max_ulps() }
+    fn ulps_eq(&self, other: &Self, epsilon: V::Scalar, max_ulps: u32) -> bool {
+        self.rings_match(other, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<V: ArcVector> Polygon<V> {
+    fn rings_match(&self, other: &Self, equal: impl Fn(&V, &V) -> bool) -> bool {
+        let (ours, theirs): (Vec<_>, Vec<_>) = (self.rings().collect(), other.rings().collect());
+        ours.len() == theirs.len()
+            && ours.iter().zip(&theirs).all(|(a, b)| a.len() == b.len() && a.iter().zip(b.iter()).all(|(p, q)| equal(p, q)))
+    }
+}
+
+impl crate::Transformable for Polygon<Vector3d> {
+    /// Carries the cached properties along, so the local axes keep their
+    /// alignment to the outline.
+    fn transform(&mut self, transform: &Transform3d) { *self = self.moved(transform); }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Line;
+    use crate::{Axis, Line};
     use utils::assert_almost_eq;
     use crate::Polygon as Polygon3d;
 
@@ -610,9 +1462,9 @@ mod tests {
         let inside = Vector2d::new(1.0, 0.5);
         let edge_pt = Vector2d::new(0.0, 0.5);
         let outside = Vector2d::new(3.0, 0.5);
-        assert!(poly.contains(&Vector3d::from(inside)));
-        assert!(poly.border_contains(&Vector3d::from(edge_pt)));
-        assert!(!poly.contains(&Vector3d::from(outside)));
+        assert!(poly.contains(&Vector3d::from(inside), None));
+        assert!(poly.border_contains(&Vector3d::from(edge_pt), None));
+        assert!(!poly.contains(&Vector3d::from(outside), None));
 
         let p = Vector3d::new(3.0, 0.5, 2.0);
         let cp = poly.closest_point(&p);
@@ -630,9 +1482,9 @@ mod tests {
             Vector2d::new(0.0, 1.0),
         ]);
         let line = Line::new(Vector3d::new(0.5, 0.5, -1.0), Vector3d::new(0.5, 0.5, 1.0));
-        let hits = poly.intersection_with_line(&line, false);
+        let hits = poly.intersection_with_line(&line, None);
         assert_almost_eq!(hits.len() as f64, 1.0);
-        assert!(poly.contains(&hits[0]));
+        assert!(poly.contains(&hits[0], None));
         assert!(hits[0].is_approx(&Vector3d::new(0.5, 0.5, 0.0), None));
     }
 }
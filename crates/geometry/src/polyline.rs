@@ -0,0 +1,347 @@
+use crate::arc::{Arc, ArcVector};
+use crate::line::Line;
+use nalgebra::RealField;
+use num_traits::{One, Zero};
+
+use crate::{BoundingBox3d, Curve, GeometryError, Scalar};
+
+/// Straight or circular piece of a [`Polyline`], also the geometry of an
+/// [`Edge`](crate::Edge).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "V: serde::Serialize, V::Scalar: serde::Serialize",
+        deserialize = "V: serde::Deserialize<'de>, V::Scalar: serde::Deserialize<'de>"
+    ))
+)]
+pub enum Segment<V>
+where
+    V: ArcVector,
+{
+    Line(Line<V>),
+    Arc(Arc<V>),
+}
+
+impl<V> Segment<V>
+where
+    V: ArcVector,
+{
+    pub fn start(&self) -> V {
+        match self {
+            Segment::Line(line) => line.start(),
+            Segment::Arc(arc) => arc.start(),
+        }
+    }
+
+    pub fn end(&self) -> V {
+        match self {
+            Segment::Line(line) => line.end(),
+            Segment::Arc(arc) => arc.end(),
+        }
+    }
+
+    pub fn length(&self) -> V::Scalar {
+        match self {
+            Segment::Line(line) => line.length(),
+            Segment::Arc(arc) => arc.length(),
+        }
+    }
+
+    pub fn point_at(&self, t: V::Scalar) -> V {
+        match self {
+            Segment::Line(line) => line.point_at(t),
+            Segment::Arc(arc) => arc.point_at(t),
+        }
+    }
+
+    pub fn closest_point(&self, point: &V) -> V {
+        match self {
+            Segment::Line(line) => line.closest_point(point),
+            Segment::Arc(arc) => arc.closest_point(point),
+        }
+    }
+
+    pub fn length_at_point(&self, point: &V) -> V::Scalar {
+        match self {
+            Segment::Line(line) => line.length_at_point(point),
+            Segment::Arc(arc) => arc.length_at_point(point),
+        }
+    }
+
+    pub fn contains(&self, point: &V, tolerance: Option<f64>) -> bool {
+        match self {
+            Segment::Line(line) => line.contains(point, tolerance),
+            Segment::Arc(arc) => arc.contains(point, tolerance),
+        }
+    }
+
+    pub fn break_at(&self, t: V::Scalar) -> Vec<Self> {
+        match self {
+            Segment::Line(line) => line.break_at(t).into_iter().map(Segment::Line).collect(),
+            Segment::Arc(arc) => arc.break_at(t).into_iter().map(Segment::Arc).collect(),
+        }
+    }
+
+    pub fn break_at_points(&self, points: &[V]) -> Vec<Self> {
+        match self {
+            Segment::Line(line) => line.break_at_points(points).into_iter().map(Segment::Line).collect(),
+            Segment::Arc(arc) => arc.break_at_points(points).into_iter().map(Segment::Arc).collect(),
+        }
+    }
+
+    /// Points where the segment meets `line`; `tolerance` as for
+    /// [`Line::intersection`], straight segments only.
+    pub fn intersection_with_line(&self, line: &Line<V>, tolerance: Option<f64>) -> Vec<V> {
+        match self {
+            Segment::Line(own) => line.intersection(own, tolerance).into_iter().collect(),
+            Segment::Arc(arc) => arc.intersection_with_line(line),
+        }
+    }
+
+    pub fn intersection(&self, other: &Self, tolerance: Option<f64>) -> Vec<V> {
+        match (self, other) {
+            (_, Segment::Line(line)) => self.intersection_with_line(line, tolerance),
+            (Segment::Line(line), Segment::Arc(arc)) => arc.intersection_with_line(line),
+            (Segment::Arc(a), Segment::Arc(b)) => a.intersection_with_arc(b),
+        }
+    }
+
+    /// The segment behind the common [`Curve`] interface.
+    pub fn curve(&self) -> &dyn Curve<V> {
+        match self {
+            Segment::Line(line) => line,
+            Segment::Arc(arc) => arc,
+        }
+    }
+
+    pub fn reversed(&self) -> Self {
+        match self {
+            Segment::Line(line) => Segment::Line(line.reversed()),
+            Segment::Arc(arc) => Segment::Arc(arc.reversed()),
+        }
+    }
+}
+
+impl<V: ArcVector> From<Line<V>> for Segment<V> {
+    fn from(line: Line<V>) -> Self { Segment::Line(line) }
+}
+
+impl<V: ArcVector> From<Arc<V>> for Segment<V> {
+    fn from(arc: Arc<V>) -> Self { Segment::Arc(arc) }
+}
+
+/// Connected chain of line and arc segments, each starting where the previous
+/// one ends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline<V>
+where
+    V: ArcVector,
+{
+    segments: Vec<Segment<V>>,
+}
+
+impl<V> Polyline<V>
+where
+    V: ArcVector,
+{
+    /// Panics when the segments are empty or not connected end to start, see
+    /// [`Polyline::try_new`].
+    pub fn new<I, S>(segments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Segment<V>>,
+    {
+        Self::try_new(segments, None).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// [`Polyline::new`] that reports an empty chain, or a segment starting
+    /// farther than `tolerance` ([`Scalar::epsilon`] by default) from where the previous
+    /// one ends, instead of panicking.
+    pub fn try_new<I, S>(segments: I, tolerance: Option<f64>) -> Result<Self, GeometryError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Segment<V>>,
+    {
+        let segments: Vec<Segment<V>> = segments.into_iter().map(Into::into).collect();
+        if segments.is_empty() {
+            return Err(GeometryError::InvalidArgument { argument: "segments", reason: "must not be empty" });
+        }
+        if let Some(index) = (1..segments.len()).find(|&i| !segments[i - 1].end().is_approx(&segments[i].start(), tolerance)) {
+            return Err(GeometryError::Disconnected { index });
+        }
+        Ok(Self { segments })
+    }
+
+    /// Straight segments through consecutive points.
+    pub fn from_points<I, P>(points: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<V>,
+    {
+        let points: Vec<V> = points.into_iter().map(Into::into).collect();
+        Self::new(points.windows(2).map(|pair| Line::new(pair[0], pair[1])))
+    }
+
+    pub fn segments(&self) -> &[Segment<V>] { &self.segments }
+
+    pub fn start(&self) -> V { self.segments[0].start() }
+
+    pub fn end(&self) -> V { self.segments[self.segments.len() - 1].end() }
+
+    pub fn is_closed(&self) -> bool { self.start().is_approx(&self.end(), None) }
+
+    pub fn length(&self) -> V::Scalar {
+        self.segments.iter().map(Segment::length).fold(V::Scalar::zero(), |sum, length| sum + length)
+    }
+
+    /// Point at arc length `length` from the start, clamped to the polyline.
+    pub fn point_at_length(&self, length: V::Scalar) -> V {
+        let (segment, t) = self.locate(length);
+        segment.point_at(t)
+    }
+
+    /// Parameter `t` of [`Curve::point_at`] at arc length `length` from the
+    /// start, clamped to `[0, 1]`.
+    pub fn parameter_at_length(&self, length: V::Scalar) -> V::Scalar { crate::curve::parameter_at_length(length, self.length()) }
+
+    /// Segment at arc length `length` from the start, clamped to the polyline,
+    /// and the parameter within it.
+    fn locate(&self, length: V::Scalar) -> (&Segment<V>, V::Scalar) {
+        let mut remaining = length.max(V::Scalar::zero());
+        for segment in &self.segments {
+            let segment_length = segment.length();
+            if remaining <= segment_length {
+                let t = if segment_length <= V::Scalar::epsilon() { V::Scalar::zero() } else { remaining / segment_length };
+                return (segment, t);
+            }
+            remaining -= segment_length;
+        }
+        (&self.segments[self.segments.len() - 1], V::Scalar::one())
+    }
+
+    pub fn closest_point(&self, point: &V) -> V {
+        self.closest(point).1
+    }
+
+    pub fn distance(&self, point: &V) -> V::Scalar {
+        self.closest_point(point).sub(point).norm()
+    }
+
+    /// Arc length from the start to the point of the polyline closest to `point`.
+    pub fn length_at_point(&self, point: &V) -> V::Scalar {
+        let (index, closest) = self.closest(point);
+        let before = self.segments[..index].iter().map(Segment::length).fold(V::Scalar::zero(), |sum, length| sum + length);
+        before + self.segments[index].length_at_point(&closest)
+    }
+
+    fn closest(&self, point: &V) -> (usize, V) {
+        let mut best = (0, self.start(), None);
+        for (index, segment) in self.segments.iter().enumerate() {
+            let candidate = segment.closest_point(point);
+            let distance = candidate.sub(point).norm();
+            if best.2.is_none_or(|nearest| distance < nearest) {
+                best = (index, candidate, Some(distance));
+            }
+        }
+        (best.0, best.1)
+    }
+
+    /// Intersections with a line segment ordered along the polyline. Points where
+    /// the line passes through a joint, within `tolerance` ([`Scalar::epsilon`] by
+    /// default) of each other, are reported once.
+    pub fn intersection_with_line(&self, line: &Line<V>, tolerance: Option<f64>) -> Vec<V> {
+        self.collect(tolerance, |segment| segment.intersection_with_line(line, tolerance))
+    }
+
+    /// Intersections with another polyline ordered along this one, merged as in
+    /// [`Polyline::intersection_with_line`].
+    pub fn intersection_with_polyline(&self, other: &Self, tolerance: Option<f64>) -> Vec<V> {
+        self.collect(tolerance, |segment| other.segments.iter().flat_map(|theirs| segment.intersection(theirs, tolerance)).collect())
+    }
+
+    fn collect<F: Fn(&Segment<V>) -> Vec<V>>(&self, tolerance: Option<f64>, intersect: F) -> Vec<V> {
+        let mut points: Vec<(V::Scalar, V)> = Vec::new();
+        let mut offset = V::Scalar::zero();
+        for segment in &self.segments {
+            for point in intersect(segment) {
+                if !points.iter().any(|(_, p)| p.is_approx(&point, tolerance)) {
+                    points.push((offset + segment.length_at_point(&point), point));
+                }
+            }
+            offset += segment.length();
+        }
+        points.sort_by(|a, b| a.0.as_f64().total_cmp(&b.0.as_f64()));
+        points.into_iter().map(|(_, point)| point).collect()
+    }
+
+    pub fn reverse(&mut self) {
+        self.segments = self.segments.iter().rev().map(Segment::reversed).collect();
+    }
+
+    pub fn reversed(&self) -> Self {
+        let mut clone = self.clone();
+        clone.reverse();
+        clone
+    }
+}
+
+impl<V: ArcVector> Curve<V> for Segment<V> {
+    fn start(&self) -> V { Segment::start(self) }
+    fn end(&self) -> V { Segment::end(self) }
+    fn length(&self) -> V::Scalar { Segment::length(self) }
+    fn point_at(&self, t: V::Scalar) -> V { Segment::point_at(self, t) }
+    fn tangent_at(&self, t: V::Scalar) -> V { self.curve().tangent_at(t) }
+    fn closest_point(&self, point: &V) -> V { Segment::closest_point(self, point) }
+    fn bounding_box(&self) -> BoundingBox3d { self.curve().bounding_box() }
+    fn linearized(&self, segments: usize) -> Vec<Line<V>> { self.curve().linearized(segments) }
+}
+
+impl<V: ArcVector> Curve<V> for Polyline<V> {
+    fn start(&self) -> V { Polyline::start(self) }
+    fn end(&self) -> V { Polyline::end(self) }
+    fn length(&self) -> V::Scalar { Polyline::length(self) }
+    fn point_at(&self, t: V::Scalar) -> V { self.point_at_length(t * Polyline::length(self)) }
+
+    /// At a joint, the tangent of the segment ending there.
+    fn tangent_at(&self, t: V::Scalar) -> V {
+        let (segment, local) = self.locate(t * Polyline::length(self));
+        segment.tangent_at(local)
+    }
+
+    fn closest_point(&self, point: &V) -> V { Polyline::closest_point(self, point) }
+
+    fn bounding_box(&self) -> BoundingBox3d {
+        self.segments.iter().map(Curve::bounding_box).reduce(|a, b| a.union(&b)).expect("polyline has segments")
+    }
+
+    fn linearized(&self, segments: usize) -> Vec<Line<V>> {
+        self.segments.iter().flat_map(|segment| segment.linearized(segments)).collect()
+    }
+}
+
+impl crate::Transformable for Segment<crate::Vector3d> {
+    fn transform(&mut self, transform: &crate::Transform3d) {
+        match self {
+            Segment::Line(line) => line.transform(transform),
+            Segment::Arc(arc) => arc.transform(transform),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector2d;
+
+    #[test]
+    fn joint_intersections_are_reported_once() {
+        let polyline: Polyline<Vector2d> =
+            Polyline::from_points([Vector2d::new(0.0, 0.0), Vector2d::new(1.0, 1.0), Vector2d::new(2.0, 0.0)]);
+        let line = Line::new(Vector2d::new(1.0, -1.0), Vector2d::new(1.0, 2.0));
+        let points = polyline.intersection_with_line(&line, None);
+        assert_eq!(points.len(), 1);
+        assert!(points[0].is_approx(&Vector2d::new(1.0, 1.0), Some(1e-9)));
+    }
+}
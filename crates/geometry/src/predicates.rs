@@ -0,0 +1,154 @@
+//! Orientation and incircle tests with exact signs, after Shewchuk's adaptive
+//! predicates. A plain floating-point determinant is returned when its error
+//! bound proves the sign; otherwise the determinant is evaluated exactly as a
+//! floating-point expansion, a sum of non-overlapping `f64` components. Nearly
+//! degenerate inputs are then classified the same way every time, instead of
+//! by which side of a tolerance the rounding happens to fall.
+//!
+//! Polygon containment and self-intersection use these. `Line::intersection`
+//! does not yet: it works in 3D and within a caller tolerance, so it keeps its
+//! parametric test. `incircle` is provided for triangulation and is not used
+//! inside the crate so far.
+
+use nalgebra::Vector2;
+
+/// Half a unit in the last place of 1.
+const ROUNDOFF: f64 = f64::EPSILON / 2.0;
+const ORIENT_BOUND: f64 = (3.0 + 16.0 * ROUNDOFF) * ROUNDOFF;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * ROUNDOFF) * ROUNDOFF;
+
+/// Twice the signed area of the triangle `a b c`: positive when it turns
+/// counterclockwise, negative when clockwise and zero exactly when the points
+/// are collinear.
+pub fn orient2d(a: &Vector2<f64>, b: &Vector2<f64>, c: &Vector2<f64>) -> f64 {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    if det.abs() > ORIENT_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+    let [acx, acy, bcx, bcy] = [(a.x, c.x), (a.y, c.y), (b.x, c.x), (b.y, c.y)].map(|(p, q)| difference(p, q));
+    estimate(&subtract(&product(&acx, &bcy), &product(&acy, &bcx)))
+}
+
+/// Positive when `d` lies inside the circle through the counterclockwise
+/// triangle `a b c`, negative outside and zero exactly on it. The sign flips
+/// for a clockwise triangle.
+pub fn incircle(a: &Vector2<f64>, b: &Vector2<f64>, c: &Vector2<f64>, d: &Vector2<f64>) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let (bc, cb) = (bd.x * cd.y, cd.x * bd.y);
+    let (ca, ac) = (cd.x * ad.y, ad.x * cd.y);
+    let (ab, ba) = (ad.x * bd.y, bd.x * ad.y);
+    let lifts = [ad.norm_squared(), bd.norm_squared(), cd.norm_squared()];
+    let det = lifts[0] * (bc - cb) + lifts[1] * (ca - ac) + lifts[2] * (ab - ba);
+    let permanent = lifts[0] * (bc.abs() + cb.abs()) + lifts[1] * (ca.abs() + ac.abs()) + lifts[2] * (ab.abs() + ba.abs());
+    if det.abs() > INCIRCLE_BOUND * permanent {
+        return det;
+    }
+    let [x, y] = [0, 1].map(|k| [a, b, c].map(|p| difference(p[k], d[k])));
+    let lift = |i: usize| add(&product(&x[i], &x[i]), &product(&y[i], &y[i]));
+    let cross = |i: usize, j: usize| subtract(&product(&x[i], &y[j]), &product(&x[j], &y[i]));
+    let terms = [product(&lift(0), &cross(1, 2)), product(&lift(1), &cross(2, 0)), product(&lift(2), &cross(0, 1))];
+    estimate(&add(&add(&terms[0], &terms[1]), &terms[2]))
+}
+
+/// Whether the closed segments `p0 p1` and `q0 q1` share a point, including
+/// touching ends and collinear overlaps.
+pub fn segments_intersect(p0: &Vector2<f64>, p1: &Vector2<f64>, q0: &Vector2<f64>, q1: &Vector2<f64>) -> bool {
+    let (d0, d1) = (orient2d(q0, q1, p0), orient2d(q0, q1, p1));
+    let (d2, d3) = (orient2d(p0, p1, q0), orient2d(p0, p1, q1));
+    if opposite(d0, d1) && opposite(d2, d3) {
+        return true;
+    }
+    (d0 == 0.0 && within(q0, q1, p0))
+        || (d1 == 0.0 && within(q0, q1, p1))
+        || (d2 == 0.0 && within(p0, p1, q0))
+        || (d3 == 0.0 && within(p0, p1, q1))
+}
+
+/// Whether two determinants have strictly opposite signs. Their product could
+/// underflow to zero for tiny inputs.
+fn opposite(a: f64, b: f64) -> bool { a != 0.0 && b != 0.0 && a.signum() != b.signum() }
+
+/// Whether `p`, collinear with `a b`, lies between them.
+fn within(a: &Vector2<f64>, b: &Vector2<f64>, p: &Vector2<f64>) -> bool {
+    (0..2).all(|k| a[k].min(b[k]) <= p[k] && p[k] <= a[k].max(b[k]))
+}
+
+/// Expansion with components in increasing magnitude and no zeros; empty for zero.
+type Expansion = Vec<f64>;
+
+/// `a + b` as the rounded sum and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// `a * b` as the rounded product and its rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+fn pair((high, low): (f64, f64)) -> Expansion { [low, high].into_iter().filter(|&x| x != 0.0).collect() }
+
+fn difference(a: f64, b: f64) -> Expansion { pair(two_sum(a, -b)) }
+
+/// Adds one component to an expansion.
+fn grow(expansion: &[f64], value: f64) -> Expansion {
+    let mut carry = value;
+    let mut result = Vec::with_capacity(expansion.len() + 1);
+    for &component in expansion {
+        let (sum, error) = two_sum(carry, component);
+        if error != 0.0 {
+            result.push(error);
+        }
+        carry = sum;
+    }
+    if carry != 0.0 {
+        result.push(carry);
+    }
+    result
+}
+
+fn add(e: &[f64], f: &[f64]) -> Expansion { f.iter().fold(e.to_vec(), |sum, &component| grow(&sum, component)) }
+
+fn subtract(e: &[f64], f: &[f64]) -> Expansion { f.iter().fold(e.to_vec(), |sum, &component| grow(&sum, -component)) }
+
+fn scale(expansion: &[f64], factor: f64) -> Expansion {
+    let Some((&first, rest)) = expansion.split_first() else { return Vec::new() };
+    let (mut carry, error) = two_product(first, factor);
+    let mut result = pair((0.0, error));
+    for &component in rest {
+        let (high, low) = two_product(component, factor);
+        let (sum, error) = two_sum(carry, low);
+        result.extend(pair((0.0, error)));
+        let (next, error) = two_sum(high, sum);
+        result.extend(pair((0.0, error)));
+        carry = next;
+    }
+    result.extend(pair((0.0, carry)));
+    result
+}
+
+fn product(e: &[f64], f: &[f64]) -> Expansion { f.iter().fold(Vec::new(), |sum, &component| add(&sum, &scale(e, component))) }
+
+/// Nearest `f64` to the expansion's value, with the same sign.
+fn estimate(expansion: &[f64]) -> f64 { expansion.iter().sum() }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expansions_are_exact() {
+        let tiny = f64::EPSILON / 4.0;
+        // 1 + tiny rounds to 1, the expansion keeps both parts.
+        assert_eq!(add(&[1.0], &[tiny]), vec![tiny, 1.0]);
+        assert_eq!(estimate(&subtract(&add(&[1.0], &[tiny]), &[1.0])), tiny);
+        let square = product(&pair(two_sum(1.0, tiny)), &pair(two_sum(1.0, tiny)));
+        assert_eq!(estimate(&subtract(&square, &[1.0])), 2.0 * tiny + tiny * tiny);
+    }
+}
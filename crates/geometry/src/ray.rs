@@ -0,0 +1,159 @@
+use nalgebra::RealField;
+use num_traits::{One, Zero};
+
+use crate::arc::{Arc, ArcVector};
+use crate::line::Line;
+use crate::polygon::Polygon;
+use crate::polyline::{Polyline, Segment};
+use crate::{Cylinder, Plane, Scalar, Sphere, Vector3d};
+
+/// Half-infinite line from `origin` along `direction`, e.g. a line of sight or
+/// a probe shot from a node. Intersections only count points at or beyond the
+/// origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray<V>
+where
+    V: ArcVector,
+{
+    origin: V,
+    direction: V,
+}
+
+impl<V> Ray<V>
+where
+    V: ArcVector,
+{
+    /// Panics on a zero direction; its length only sets the scale of
+    /// [`Ray::point_at`].
+    pub fn new<O: Into<V>, D: Into<V>>(origin: O, direction: D) -> Self {
+        let direction = direction.into();
+        assert!(direction.norm() > V::Scalar::epsilon(), "ray direction must be non-zero");
+        Self { origin: origin.into(), direction }
+    }
+
+    /// Ray from the start of `line` through its end. Panics on a zero-length line.
+    pub fn from_line(line: &Line<V>) -> Self { Self::new(line.start(), line.end().sub(&line.start())) }
+
+    pub fn origin(&self) -> V { self.origin }
+    pub fn direction(&self) -> V { self.direction }
+
+    /// Point at `t` times the direction from the origin.
+    pub fn point_at(&self, t: V::Scalar) -> V { self.origin.add(&self.direction.scale(t)) }
+
+    /// Ray parameter of the orthogonal projection of `point`.
+    pub fn parameter(&self, point: &V) -> V::Scalar {
+        point.sub(&self.origin).dot(&self.direction) / self.direction.dot(&self.direction)
+    }
+
+    /// First point where the ray meets the segment `line`, counting points
+    /// within `tolerance` ([`Scalar::epsilon`] by default) of both. A segment lying on
+    /// the ray gives its end nearer to the origin, or the origin itself when
+    /// the segment runs across it.
+    pub fn intersection_with_line(&self, line: &Line<V>, tolerance: Option<f64>) -> Option<V> {
+        let on_ray_line = |point: V| self.point_at(self.parameter(&point)).is_approx(&point, tolerance);
+        if on_ray_line(line.start()) && on_ray_line(line.end()) {
+            let (a, b) = (self.parameter(&line.start()), self.parameter(&line.end()));
+            return (a.max(b) >= -self.slack(tolerance)).then(|| self.point_at(a.min(b).max(V::Scalar::zero())));
+        }
+        self.intersection_with_infinite_line(line, tolerance).filter(|point| line.contains(point, tolerance))
+    }
+
+    /// Point where the ray crosses the infinite line through `line`, within
+    /// `tolerance` ([`Scalar::epsilon`] by default). A parallel line only meets the
+    /// ray at its origin.
+    pub fn intersection_with_infinite_line(&self, line: &Line<V>, tolerance: Option<f64>) -> Option<V> {
+        let along = line.end().sub(&line.start());
+        let (a, b, e) = (self.direction.dot(&self.direction), self.direction.dot(&along), along.dot(&along));
+        let offset = self.origin.sub(&line.start());
+        let (c, f) = (self.direction.dot(&offset), along.dot(&offset));
+        // a·e - b² = a·e·sin²θ, so compare against a·e to test the angle alone.
+        let denom = a * e - b * b;
+        let eps = V::Scalar::epsilon();
+        if denom <= eps * eps * a * e {
+            let foot = line.start().add(&along.scale(f / e));
+            return foot.is_approx(&self.origin, tolerance).then_some(self.origin);
+        }
+        let s = (b * f - c * e) / denom;
+        if s < -self.slack(tolerance) {
+            return None;
+        }
+        let point = self.point_at(s.max(V::Scalar::zero()));
+        point.is_approx(&line.start().add(&along.scale((a * f - b * c) / denom)), tolerance).then_some(point)
+    }
+
+    /// Points where the ray crosses the arc, nearest first. Points on the
+    /// arc's circle but outside its sweep are not reported; points up to
+    /// `tolerance` behind the origin count as the origin.
+    pub fn intersection_with_arc(&self, arc: &Arc<V>, tolerance: Option<f64>) -> Vec<V> {
+        self.hits(arc.line_parameters(&self.guide()), tolerance)
+    }
+
+    /// Points where the ray meets the polyline, nearest first. Points where
+    /// it passes through a joint, within `tolerance` of each other, are
+    /// reported once.
+    pub fn intersection_with_polyline(&self, polyline: &Polyline<V>, tolerance: Option<f64>) -> Vec<V> {
+        let mut points: Vec<V> = Vec::new();
+        for segment in polyline.segments() {
+            let hits = match segment {
+                Segment::Line(line) => self.intersection_with_line(line, tolerance).into_iter().collect(),
+                Segment::Arc(arc) => self.intersection_with_arc(arc, tolerance),
+            };
+            for point in hits {
+                if !points.iter().any(|p| p.is_approx(&point, tolerance)) {
+                    points.push(point);
+                }
+            }
+        }
+        points.sort_by(|a, b| self.parameter(a).as_f64().total_cmp(&self.parameter(b).as_f64()));
+        points
+    }
+
+    /// Points where the ray meets the polygon: at most one where it crosses
+    /// the plane, or the ends of the parts inside the polygon when it runs in
+    /// the plane, see [`Ray::clip_to_polygon`]. `tolerance` is as for
+    /// [`Polygon::intersection_with_line`].
+    pub fn intersection_with_polygon(&self, polygon: &Polygon<V>, tolerance: Option<f64>) -> Vec<V> {
+        polygon.crossings(&self.guide(), Some(V::Scalar::zero()), tolerance)
+    }
+
+    /// Parts of the ray inside the polygon, boundary included and holes
+    /// excluded, in order from the origin. Empty unless the ray lies within
+    /// `tolerance` of the polygon's plane, as for [`Polygon::clip_line`].
+    pub fn clip_to_polygon(&self, polygon: &Polygon<V>, tolerance: Option<f64>) -> Vec<Line<V>> {
+        polygon.clipped(&self.guide(), Some(V::Scalar::zero()), tolerance)
+    }
+
+    /// Segment from the origin to the tip of the direction, whose line
+    /// parameters are ray parameters.
+    fn guide(&self) -> Line<V> { Line::new(self.origin, self.point_at(V::Scalar::one())) }
+
+    /// Ray parameter spanned by `tolerance`, [`Scalar::epsilon`] by default.
+    fn slack(&self, tolerance: Option<f64>) -> V::Scalar { V::Scalar::tolerance(tolerance) / self.direction.norm() }
+
+    /// Points at `parameters` on or beyond the origin, allowing `tolerance`
+    /// behind it.
+    fn hits(&self, parameters: Vec<V::Scalar>, tolerance: Option<f64>) -> Vec<V> {
+        let slack = self.slack(tolerance);
+        parameters.into_iter().filter(|&t| t >= -slack).map(|t| self.point_at(t.max(V::Scalar::zero()))).collect()
+    }
+}
+
+impl Ray<Vector3d> {
+    /// Point where the ray crosses `plane`; `None` when it points away from the
+    /// plane, by more than `tolerance` at the origin, or runs parallel to it.
+    pub fn intersection_with_plane(&self, plane: &Plane, tolerance: Option<f64>) -> Option<Vector3d> {
+        let t = plane.line_parameter(&self.guide())?;
+        (t >= -self.slack(tolerance)).then(|| self.point_at(t.max(0.0)))
+    }
+
+    /// Points where the ray pierces or touches `sphere`, nearest first.
+    pub fn intersection_with_sphere(&self, sphere: &Sphere, tolerance: Option<f64>) -> Vec<Vector3d> {
+        self.hits(sphere.line_parameters(&self.guide()), tolerance)
+    }
+
+    /// Points where the ray pierces or touches `cylinder`, nearest first.
+    pub fn intersection_with_cylinder(&self, cylinder: &Cylinder, tolerance: Option<f64>) -> Vec<Vector3d> {
+        self.hits(cylinder.line_parameters(&self.guide()), tolerance)
+    }
+}
@@ -0,0 +1,82 @@
+use nalgebra::{Matrix2, Matrix3};
+use utils::epsilon;
+
+use crate::{Axis, Polygon, Vector3d};
+
+/// Planar region made of several polygons, with openings cut out of them, e.g.
+/// a built-up section of a plate and a rolled profile with bolt holes. Holes of
+/// the polygons themselves count as usual; openings subtract their full area,
+/// so they must lie inside the polygons.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    polygons: Vec<Polygon>,
+    openings: Vec<Polygon>,
+}
+
+impl Region {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_polygon(mut self, polygon: Polygon) -> Self {
+        self.add_polygon(polygon);
+        self
+    }
+
+    pub fn with_opening(mut self, opening: Polygon) -> Self {
+        self.add_opening(opening);
+        self
+    }
+
+    pub fn add_polygon(&mut self, polygon: Polygon) { self.polygons.push(polygon); }
+    pub fn add_opening(&mut self, opening: Polygon) { self.openings.push(opening); }
+
+    pub fn polygons(&self) -> &[Polygon] { &self.polygons }
+    pub fn openings(&self) -> &[Polygon] { &self.openings }
+
+    /// Signed parts: every polygon with `1`, every opening with `-1`.
+    fn parts(&self) -> impl Iterator<Item = (&Polygon, f64)> {
+        self.polygons.iter().map(|p| (p, 1.0)).chain(self.openings.iter().map(|p| (p, -1.0)))
+    }
+
+    /// Area of the polygons less the area of the openings.
+    pub fn area(&self) -> f64 { self.parts().map(|(polygon, sign)| sign * polygon.area()).sum() }
+
+    /// Area-weighted centroid; `None` for a region without area.
+    pub fn centroid(&self) -> Option<Vector3d> {
+        let area = self.area();
+        if area <= epsilon() {
+            return None;
+        }
+        let moment = self.parts().fold(nalgebra::Vector3::zeros(), |sum, (polygon, sign)| {
+            sum + polygon.centroid().0 * (sign * polygon.area())
+        });
+        Some(Vector3d(moment / area))
+    }
+
+    /// Thin-plate inertia tensor in global axes about `point`, the tensors of
+    /// the polygons less those of the openings.
+    pub fn about_point(&self, point: &Vector3d) -> Matrix3<f64> {
+        self.parts().map(|(polygon, sign)| polygon.about_point(point) * sign).sum()
+    }
+
+    /// Inertia tensor about the centroid; `None` for a region without area.
+    pub fn about_centroid_global(&self) -> Option<Matrix3<f64>> { Some(self.about_point(&self.centroid()?)) }
+
+    /// Second moments `[Ixx Ixy; Ixy Iyy]` about the centroid along the local
+    /// X,Y axes of the first polygon, as [`Polygon::about_centroid_local`].
+    /// Meaningful for coplanar regions only.
+    pub fn about_centroid_local(&self) -> Option<Matrix2<f64>> {
+        let first = self.polygons.first()?;
+        let (ex, ey) = (first.axis(Axis::AxisX).0, first.axis(Axis::AxisY).0);
+        let tensor = self.about_centroid_global()?;
+        let ixy = -ex.dot(&(tensor * ey));
+        Some(Matrix2::new(ex.dot(&(tensor * ex)), ixy, ixy, ey.dot(&(tensor * ey))))
+    }
+
+    /// Whether `point` lies in one of the polygons (or on its border) and not
+    /// strictly inside an opening, borders and planes taken within `tolerance`.
+    pub fn contains(&self, point: &Vector3d, tolerance: Option<f64>) -> bool {
+        self.polygons.iter().any(|p| p.contains(point, tolerance) || p.border_contains(point, tolerance))
+            && !self.openings.iter().any(|o| o.contains(point, tolerance) && !o.border_contains(point, tolerance))
+    }
+}
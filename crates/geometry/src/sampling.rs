@@ -0,0 +1,174 @@
+//! Area integration points over polygons: grid cells for fiber section
+//! analysis and Gauss points for integrating loads over panels.
+
+use nalgebra::Vector2;
+
+use crate::mesh::{triangulate, MeshOptions};
+use crate::{Polygon, Vector2d};
+
+/// Point inside a polygon with the area it stands for, in the polygon's local
+/// coordinates (origin at the centroid, see [`Polygon::to_local`]). The
+/// weights of a sampling add up to the polygon area.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntegrationPoint {
+    pub position: Vector2d,
+    pub weight: f64,
+}
+
+/// Symmetric triangle rules as (barycentric orbit, weight) pairs: `[a, a, 1 - 2a]`
+/// and its permutations, or the centroid for `a = 1/3`. Weights are per point
+/// and sum to one over the rule.
+const DEGREE_1: &[(f64, f64)] = &[(1.0 / 3.0, 1.0)];
+const DEGREE_2: &[(f64, f64)] = &[(1.0 / 6.0, 1.0 / 3.0)];
+const DEGREE_4: &[(f64, f64)] =
+    &[(0.445_948_490_915_965, 0.223_381_589_678_011), (0.091_576_213_509_771, 0.109_951_743_655_322)];
+const DEGREE_5: &[(f64, f64)] = &[
+    (1.0 / 3.0, 0.225),
+    (0.470_142_064_105_115, 0.132_394_152_788_506),
+    (0.101_286_507_323_456, 0.125_939_180_544_827),
+];
+
+impl Polygon {
+    /// One point per cell of an `nx` × `ny` grid over the local bounding box,
+    /// e.g. the fibers of a section. Each point sits at the centroid of the
+    /// part of its cell inside the polygon, holes excluded, and weighs that
+    /// part's area; cells outside the polygon give no point. Panics on a zero
+    /// cell count.
+    pub fn sample_grid(&self, nx: usize, ny: usize) -> Vec<IntegrationPoint> {
+        assert!(nx > 0 && ny > 0, "grid needs at least one cell in each direction");
+        let local = |ring: &[crate::Vector3d]| -> Vec<Vector2<f64>> {
+            ring.iter().map(|v| self.to_local(*v)).map(|p| Vector2::new(p.x(), p.y())).collect()
+        };
+        let outer = local(self.vertices());
+        let holes: Vec<_> = self.holes().iter().map(|hole| local(hole)).collect();
+        let min = outer.iter().fold(Vector2::repeat(f64::INFINITY), |m, p| m.inf(p));
+        let max = outer.iter().fold(Vector2::repeat(f64::NEG_INFINITY), |m, p| m.sup(p));
+        let cell = (max - min).component_div(&Vector2::new(nx as f64, ny as f64));
+        let tiny = 1e-12 * (max - min).norm_squared();
+
+        let mut points = Vec::new();
+        for j in 0..ny {
+            for i in 0..nx {
+                let low = min + cell.component_mul(&Vector2::new(i as f64, j as f64));
+                let high = low + cell;
+                let (mut area, mut moment) = moments(&clip_to_box(&outer, low, high));
+                for hole in &holes {
+                    let (hole_area, hole_moment) = moments(&clip_to_box(hole, low, high));
+                    area -= hole_area;
+                    moment -= hole_moment;
+                }
+                if area > tiny {
+                    points.push(IntegrationPoint { position: Vector2d(moment / area), weight: area });
+                }
+            }
+        }
+        points
+    }
+
+    /// Gauss points of a triangulation of the polygon, integrating polynomials
+    /// up to degree `order` in the plane exactly. Supported orders are 1 to 5;
+    /// order 3 uses the degree 4 rule. Panics on any other order.
+    pub fn gauss_points(&self, order: usize) -> Vec<IntegrationPoint> {
+        let rule = match order {
+            1 => DEGREE_1,
+            2 => DEGREE_2,
+            3 | 4 => DEGREE_4,
+            5 => DEGREE_5,
+            _ => panic!("Gauss points are available for orders 1 to 5"),
+        };
+        let mut points = Vec::new();
+        for [a, b, c] in self.local_triangles() {
+            let area = (b - a).perp(&(c - a)).abs() / 2.0;
+            for &(orbit, weight) in rule {
+                let centroid = (orbit - 1.0 / 3.0).abs() <= 1e-15;
+                let barycentric: &[[f64; 3]] = if centroid {
+                    &[[orbit; 3]]
+                } else {
+                    let rest = 1.0 - 2.0 * orbit;
+                    &[[orbit, orbit, rest], [orbit, rest, orbit], [rest, orbit, orbit]]
+                };
+                for [l1, l2, l3] in barycentric {
+                    points.push(IntegrationPoint { position: Vector2d(a * *l1 + b * *l2 + c * *l3), weight: weight * area });
+                }
+            }
+        }
+        points
+    }
+
+    /// `n` points drawn uniformly over the polygon, holes excluded, each
+    /// weighing `area / n`, e.g. for Monte Carlo integration of a nonuniform
+    /// pressure over an irregular panel. A triangle of a triangulation is
+    /// drawn with probability proportional to its area, then a point uniformly
+    /// inside it.
+    #[cfg(feature = "rand")]
+    pub fn sample_uniform<R: rand::Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<IntegrationPoint> {
+        let triangles = self.local_triangles();
+        let cumulative: Vec<f64> = triangles
+            .iter()
+            .scan(0.0, |total, [a, b, c]| {
+                *total += (b - a).perp(&(c - a)).abs() / 2.0;
+                Some(*total)
+            })
+            .collect();
+        let Some(&total) = cumulative.last() else { return Vec::new() };
+        let weight = total / n as f64;
+        (0..n)
+            .map(|_| {
+                let pick = rng.random::<f64>() * total;
+                let [a, b, c] = triangles[cumulative.partition_point(|&area| area <= pick).min(triangles.len() - 1)];
+                let (mut s, mut t) = (rng.random::<f64>(), rng.random::<f64>());
+                // Fold the far half of the parallelogram back onto the triangle.
+                if s + t > 1.0 {
+                    (s, t) = (1.0 - s, 1.0 - t);
+                }
+                IntegrationPoint { position: Vector2d(a + (b - a) * s + (c - a) * t), weight }
+            })
+            .collect()
+    }
+
+    /// Triangles of [`triangulate`] in local coordinates.
+    fn local_triangles(&self) -> Vec<[Vector2<f64>; 3]> {
+        let mesh = triangulate(self, &MeshOptions::new());
+        (0..mesh.triangles().len())
+            .map(|triangle| {
+                mesh.triangle(triangle).map(|v| {
+                    let p = self.to_local(v);
+                    Vector2::new(p.x(), p.y())
+                })
+            })
+            .collect()
+    }
+}
+
+/// Sutherland-Hodgman clip of a ring to the box `[low, high]`.
+fn clip_to_box(ring: &[Vector2<f64>], low: Vector2<f64>, high: Vector2<f64>) -> Vec<Vector2<f64>> {
+    let mut points = ring.to_vec();
+    for axis in 0..2 {
+        for (bound, keep_above) in [(low[axis], true), (high[axis], false)] {
+            let inside = |p: &Vector2<f64>| if keep_above { p[axis] >= bound } else { p[axis] <= bound };
+            let input = std::mem::take(&mut points);
+            for k in 0..input.len() {
+                let (p, q) = (input[k], input[(k + 1) % input.len()]);
+                if inside(&p) {
+                    points.push(p);
+                }
+                if inside(&p) != inside(&q) {
+                    points.push(p + (q - p) * ((bound - p[axis]) / (q[axis] - p[axis])));
+                }
+            }
+        }
+    }
+    points
+}
+
+/// Unsigned area of a ring and its first moment of area.
+fn moments(ring: &[Vector2<f64>]) -> (f64, Vector2<f64>) {
+    let (mut area, mut moment) = (0.0, Vector2::zeros());
+    for k in 0..ring.len() {
+        let (p, q) = (ring[k], ring[(k + 1) % ring.len()]);
+        let cross = p.perp(&q);
+        area += cross / 2.0;
+        moment += (p + q) * cross / 6.0;
+    }
+    if area < 0.0 { (-area, -moment) } else { (area, moment) }
+}
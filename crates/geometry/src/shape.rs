@@ -1,9 +1,14 @@
-use std::f64::consts::{PI, TAU};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::fmt;
 
 use nalgebra::Matrix3;
 
+use crate::arc::Arc;
+use crate::boolean::signed_area;
+use crate::descriptor::{CompoundPart, ShapeDescriptor};
+use crate::line::Line;
 use crate::polygon::Polygon as RawPolygon;
-use crate::Vector3d;
+use crate::{GeometryError, Line3d, Vector2d, Vector3d};
 use utils::epsilon;
 
 /// Common interface shared by all cross-sectional shapes.
@@ -28,35 +33,153 @@ pub trait Shape {
     /// Polygonal approximation of the boundary (useful for meshing or tests).
     fn linearized(&self, sides: usize) -> RawPolygon<Vector3d>;
 
+    /// Polygonal approximation whose edges stay within `max_sagitta` of every
+    /// curved part of the boundary, each arc with as many chords as it needs.
+    fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d>;
+
     /// Circumference alias for shapes where that terminology is preferred.
     fn circumference(&self) -> f64 { self.perimeter() }
+
+    /// Parameters to rebuild the shape from, e.g. for storing it with a model;
+    /// `None` for shapes that cannot be described that way.
+    fn descriptor(&self) -> Option<ShapeDescriptor> { None }
+}
+
+/// Invalid dimension passed to a shape constructor: the name of the offending
+/// argument and the condition it breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeError {
+    pub dimension: &'static str,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{} {}", self.dimension, self.reason) }
+}
+
+impl std::error::Error for ShapeError {}
+
+fn ensure(condition: bool, dimension: &'static str, reason: &'static str) -> Result<(), ShapeError> {
+    if condition { Ok(()) } else { Err(ShapeError { dimension, reason }) }
+}
+
+fn positive(value: f64, dimension: &'static str) -> Result<(), ShapeError> {
+    ensure(value > epsilon(), dimension, "must be positive")
+}
+
+fn non_negative(value: f64, dimension: &'static str) -> Result<(), ShapeError> {
+    ensure(value >= 0.0, dimension, "must not be negative")
+}
+
+fn taper(angle: f64, dimension: &'static str) -> Result<(), ShapeError> {
+    ensure(angle.abs() < FRAC_PI_2, dimension, "must be less than a right angle")
 }
 
+/// Unwraps a constructor result for the panicking constructors.
+fn valid<T>(result: Result<T, ShapeError>) -> T { result.unwrap_or_else(|error| panic!("{error}")) }
+
 /// Helper: creates an axis-aligned rectangle centred at the origin.
-fn rectangle_polygon(width: f64, height: f64) -> RawPolygon<Vector3d> {
+fn rectangle_polygon(width: f64, height: f64) -> RawPolygon<Vector3d> { RawPolygon::new(rectangle_ring(width, height)) }
+
+fn rectangle_ring(width: f64, height: f64) -> Vec<Vector3d> {
     let hw = width / 2.0;
     let hh = height / 2.0;
-    let verts = vec![
+    vec![
         Vector3d::new(-hw, -hh, 0.0),
         Vector3d::new(hw, -hh, 0.0),
         Vector3d::new(hw, hh, 0.0),
         Vector3d::new(-hw, hh, 0.0),
-    ];
-    RawPolygon::new(verts)
+    ]
 }
 
-/// Helper: builds a regular N-gon approximation for a circle centred at the origin.
-fn regular_ngon(radius: f64, sides: usize) -> RawPolygon<Vector3d> {
+/// Helper: builds the vertices of a regular N-gon approximating a circle centred at the origin.
+fn regular_ngon(radius: f64, sides: usize) -> Vec<Vector3d> {
     assert!(radius > 0.0, "radius must be positive");
     assert!(sides >= 3, "need at least three sides to form a polygon");
     let step = TAU / sides as f64;
-    let verts = (0..sides)
+    (0..sides)
         .map(|i| {
             let angle = i as f64 * step;
             Vector3d::new(radius * angle.cos(), radius * angle.sin(), 0.0)
         })
-        .collect::<Vec<_>>();
-    RawPolygon::new(verts)
+        .collect()
+}
+
+impl RawPolygon<Vector3d> {
+    /// Regular polygon with its corners on a circle of `radius` about the origin
+    /// in the XY plane, the first on the X axis. Panics on invalid arguments,
+    /// see [`RawPolygon::try_regular_ngon`].
+    pub fn regular_ngon(radius: f64, sides: usize) -> Self {
+        Self::try_regular_ngon(radius, sides).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_regular_ngon(radius: f64, sides: usize) -> Result<Self, GeometryError> {
+        if radius.is_nan() || radius <= epsilon() {
+            return Err(GeometryError::InvalidArgument { argument: "radius", reason: "must be positive" });
+        }
+        if sides < 3 {
+            return Err(GeometryError::TooFewPoints { distinct: sides });
+        }
+        Ok(Self::new(regular_ngon(radius, sides)))
+    }
+}
+
+/// Chords per fillet or toe arc in the stored outline of rolled profiles.
+const ARC_SEGMENTS: usize = 8;
+
+/// How the arcs of an outline are drawn: a fixed number of chords each, zero
+/// leaving corners sharp, or as many as keep the chords within a sagitta.
+#[derive(Debug, Clone, Copy)]
+enum Chords {
+    PerArc(usize),
+    Sagitta(f64),
+}
+
+impl Chords {
+    fn count(self, arc: &Arc<Vector3d>) -> usize {
+        match self {
+            Self::PerArc(segments) => segments,
+            Self::Sagitta(max_sagitta) => arc.segments_for_sagitta(max_sagitta),
+        }
+    }
+}
+
+/// Outline corner `(x, y, radius, name of the radius dimension)`.
+type Corner = (f64, f64, f64, &'static str);
+
+/// Outline through the corners in order, each rounded by a tangent arc of its
+/// radius drawn with `chords`; zero radii, or zero chords per arc, leave the
+/// corner sharp. The outline starts where the first corner ends, so its
+/// first edge (and with it the polygon frame) keeps the direction of the first
+/// side. Fails when a radius does not fit on the edges next to its corner.
+fn rounded_outline(corners: &[Corner], chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> {
+    Ok(RawPolygon::new(rounded_ring(corners, chords)?))
+}
+
+/// Vertices of [`rounded_outline`].
+fn rounded_ring(corners: &[Corner], chords: Chords) -> Result<Vec<Vector3d>, ShapeError> {
+    let n = corners.len();
+    let point = |i: usize| Vector3d::new(corners[i % n].0, corners[i % n].1, 0.0);
+    let mut vertices = Vec::new();
+    let mut first = 0;
+    for (i, &(_, _, radius, dimension)) in corners.iter().enumerate() {
+        if i == 1 {
+            first = vertices.len() - 1;
+        }
+        if radius <= 0.0 || matches!(chords, Chords::PerArc(0)) {
+            vertices.push(point(i));
+            continue;
+        }
+        let (before, at, after) = (point(i + n - 1), point(i), point(i + 1));
+        let arc = Arc::fillet(&Line::new(before, at), &Line::new(at, after), radius).ok_or(ShapeError {
+            dimension,
+            reason: "must fit on the edges next to its corner",
+        })?;
+        vertices.extend(arc.linearized(chords.count(&arc)).iter().map(|chord| chord.start()));
+        vertices.push(arc.end());
+    }
+    vertices.rotate_left(first);
+    Ok(vertices)
 }
 
 macro_rules! impl_polygon_shape {
@@ -72,18 +195,28 @@ macro_rules! impl_polygon_shape {
             fn perimeter(&self) -> f64 { self.polygon.perimeter() }
             fn centroid(&self) -> Vector3d { self.polygon.centroid() }
             fn second_moment_of_area(&self) -> Matrix3<f64> {
-                self.polygon.second_moment_of_area()
+                self.polygon.about_centroid_global()
             }
-            fn linearized(&self, _sides: usize) -> RawPolygon<Vector3d> {
-                self.polygon.clone()
+            fn linearized(&self, sides: usize) -> RawPolygon<Vector3d> {
+                valid(self.outline(Chords::PerArc(sides)))
+            }
+            fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d> {
+                valid(self.outline(Chords::Sagitta(max_sagitta)))
             }
+            fn descriptor(&self) -> Option<ShapeDescriptor> { Some(self.clone().into()) }
         }
     };
 }
 
-/// Solid rectangle defined by width and height. Optional hole dimensions are
-/// retained for future development but currently ignored in the polygon.
+/// Rectangle defined by width and height, hollow when both hole dimensions are
+/// positive: the polygon then carries a concentric rectangular hole, which its
+/// area and inertia subtract.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
 pub struct Rectangle {
     pub width: f64,
     pub height: f64,
@@ -93,17 +226,36 @@ pub struct Rectangle {
 }
 
 impl Rectangle {
+    /// Panics on invalid dimensions, see [`Rectangle::try_new`].
     pub fn new(width: f64, height: f64, hole_width: f64, hole_height: f64) -> Self {
-        assert!(width > epsilon() && height > epsilon(), "rectangle dimensions must be positive");
-        let polygon = rectangle_polygon(width, height);
-        Self { width, height, hole_width, hole_height, polygon }
+        valid(Self::try_new(width, height, hole_width, hole_height))
+    }
+
+    pub fn try_new(width: f64, height: f64, hole_width: f64, hole_height: f64) -> Result<Self, ShapeError> {
+        positive(width, "width")?;
+        positive(height, "height")?;
+        let polygon = if hole_width > epsilon() && hole_height > epsilon() {
+            ensure(hole_width < width, "hole_width", "must be less than the width")?;
+            ensure(hole_height < height, "hole_height", "must be less than the height")?;
+            RawPolygon::with_holes(rectangle_ring(width, height), [rectangle_ring(hole_width, hole_height)])
+        } else {
+            rectangle_polygon(width, height)
+        };
+        Ok(Self { width, height, hole_width, hole_height, polygon })
     }
+
+    fn outline(&self, _chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> { Ok(self.polygon.clone()) }
 }
 
 impl_polygon_shape!(Rectangle);
 
 /// Disk (solid circle) optionally with a concentric hole.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
 pub struct Disk {
     pub radius: f64,
     pub hole_radius: f64,
@@ -112,13 +264,27 @@ pub struct Disk {
 impl Disk {
     const DEFAULT_LINEARIZATION_SIDES: usize = 256;
 
-    pub fn new(radius: f64, hole_radius: f64) -> Self {
-        assert!(radius > hole_radius, "outer radius must exceed hole radius");
-        Self { radius, hole_radius }
+    /// Panics on invalid dimensions, see [`Disk::try_new`].
+    pub fn new(radius: f64, hole_radius: f64) -> Self { valid(Self::try_new(radius, hole_radius)) }
+
+    pub fn try_new(radius: f64, hole_radius: f64) -> Result<Self, ShapeError> {
+        positive(radius, "radius")?;
+        non_negative(hole_radius, "hole_radius")?;
+        ensure(hole_radius < radius, "hole_radius", "must be less than the radius")?;
+        Ok(Self { radius, hole_radius })
     }
 
     pub fn circumference(&self) -> f64 { TAU * self.radius }
 
+    fn rings(&self, sides: usize, hole_sides: usize) -> RawPolygon<Vector3d> {
+        let outer = regular_ngon(self.radius, sides);
+        if self.hole_radius > epsilon() {
+            RawPolygon::with_holes(outer, [regular_ngon(self.hole_radius, hole_sides)])
+        } else {
+            RawPolygon::new(outer)
+        }
+    }
+
     fn solid_area(&self) -> f64 {
         let outer = self.radius * self.radius;
         let inner = self.hole_radius * self.hole_radius;
@@ -148,12 +314,164 @@ impl Shape for Disk {
 
     fn linearized(&self, sides: usize) -> RawPolygon<Vector3d> {
         let sides = sides.max(Self::DEFAULT_LINEARIZATION_SIDES);
-        regular_ngon(self.radius, sides)
+        self.rings(sides, sides)
+    }
+
+    /// Each ring with its own number of sides, a multiple of four.
+    fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d> {
+        let sides = |radius: f64| {
+            let quarter: Arc<Vector3d> =
+                Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(radius, 0.0, 0.0), Vector3d::new(0.0, radius, 0.0), false);
+            4 * quarter.segments_for_sagitta(max_sagitta)
+        };
+        let hole_sides = if self.hole_radius > epsilon() { sides(self.hole_radius) } else { 0 };
+        self.rings(sides(self.radius), hole_sides)
     }
+
+    fn descriptor(&self) -> Option<ShapeDescriptor> { Some(self.clone().into()) }
 }
 
-/// Doubly-symmetric I profile.
+/// Rectangular or square hollow section (RHS/SHS) of uniform wall thickness,
+/// centred at the origin. The outer corners are rounded with `outer_radius` and
+/// the inner ones with what is left of it inside the wall, as for cold-formed
+/// tubes; area and inertia are exact. `linearized(n)` draws every corner arc
+/// with `n` chords.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
+pub struct ShapeBox {
+    pub width: f64,
+    pub height: f64,
+    pub thickness: f64,
+    pub outer_radius: f64,
+}
+
+impl ShapeBox {
+    /// Panics on invalid dimensions, see [`ShapeBox::try_new`].
+    pub fn new(width: f64, height: f64, thickness: f64, outer_radius: f64) -> Self {
+        valid(Self::try_new(width, height, thickness, outer_radius))
+    }
+
+    pub fn try_new(width: f64, height: f64, thickness: f64, outer_radius: f64) -> Result<Self, ShapeError> {
+        positive(width, "width")?;
+        positive(height, "height")?;
+        positive(thickness, "thickness")?;
+        ensure(2.0 * thickness < width.min(height), "thickness", "must be less than half the width and height")?;
+        non_negative(outer_radius, "outer_radius")?;
+        ensure(outer_radius < width.min(height) / 2.0, "outer_radius", "must be less than half the width and height")?;
+        Ok(Self { width, height, thickness, outer_radius })
+    }
+
+    pub fn inner_radius(&self) -> f64 { (self.outer_radius - self.thickness).max(0.0) }
+
+    fn outline(&self, chords: Chords) -> RawPolygon<Vector3d> {
+        let t = self.thickness;
+        let outer = Self::ring(self.width, self.height, self.outer_radius, chords);
+        let inner = Self::ring(self.width - 2.0 * t, self.height - 2.0 * t, self.inner_radius(), chords);
+        RawPolygon::with_holes(outer, [inner])
+    }
+
+    /// Area and second moments of the outer outline less the inner one.
+    fn properties(&self) -> (f64, f64, f64) {
+        let outer = rounded_rectangle(self.width, self.height, self.outer_radius);
+        let inner = rounded_rectangle(self.width - 2.0 * self.thickness, self.height - 2.0 * self.thickness, self.inner_radius());
+        (outer.0 - inner.0, outer.1 - inner.1, outer.2 - inner.2)
+    }
+
+    fn ring(width: f64, height: f64, radius: f64, chords: Chords) -> Vec<Vector3d> {
+        let (hw, hh) = (width / 2.0, height / 2.0);
+        let name = "outer_radius";
+        valid(rounded_ring(&[(-hw, -hh, radius, name), (hw, -hh, radius, name), (hw, hh, radius, name), (-hw, hh, radius, name)], chords))
+    }
+}
+
+impl Shape for ShapeBox {
+    fn area(&self) -> f64 { self.properties().0 }
+
+    /// Length of the outer outline.
+    fn perimeter(&self) -> f64 { 2.0 * (self.width + self.height) - (8.0 - TAU) * self.outer_radius }
+
+    fn centroid(&self) -> Vector3d { Vector3d::new(0.0, 0.0, 0.0) }
+
+    fn second_moment_of_area(&self) -> Matrix3<f64> {
+        let (_, ix, iy) = self.properties();
+        Matrix3::from_diagonal(&nalgebra::Vector3::new(ix, iy, ix + iy))
+    }
+
+    fn linearized(&self, sides: usize) -> RawPolygon<Vector3d> { self.outline(Chords::PerArc(sides)) }
+
+    fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d> { self.outline(Chords::Sagitta(max_sagitta)) }
+
+    fn descriptor(&self) -> Option<ShapeDescriptor> { Some(self.clone().into()) }
+}
+
+/// Area, `Ix = ∫y² dA` and `Iy = ∫x² dA` of a centred rectangle with corners
+/// rounded to `radius`: the full rectangle less the four corner spandrels,
+/// each of area `(1 − π/4) r²` with moments `(5/6 − π/4) r³` and
+/// `(1 − 5π/16) r⁴` about its outer edges.
+fn rounded_rectangle(width: f64, height: f64, radius: f64) -> (f64, f64, f64) {
+    let area = (1.0 - PI / 4.0) * radius.powi(2);
+    let first = (5.0 / 6.0 - PI / 4.0) * radius.powi(3);
+    let second = (1.0 - 5.0 * PI / 16.0) * radius.powi(4);
+    // Spandrel about the centroidal axis parallel to the side at distance `d`.
+    let corner = |d: f64| d * d * area - 2.0 * d * first + second;
+    (
+        width * height - 4.0 * area,
+        width * height.powi(3) / 12.0 - 4.0 * corner(height / 2.0),
+        height * width.powi(3) / 12.0 - 4.0 * corner(width / 2.0),
+    )
+}
+
+/// Circular hollow section (CHS) given by its outer diameter and wall
+/// thickness, with the properties of the equivalent [`Disk`].
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
+pub struct ShapeTube {
+    pub diameter: f64,
+    pub thickness: f64,
+}
+
+impl ShapeTube {
+    /// Panics on invalid dimensions, see [`ShapeTube::try_new`].
+    pub fn new(diameter: f64, thickness: f64) -> Self { valid(Self::try_new(diameter, thickness)) }
+
+    pub fn try_new(diameter: f64, thickness: f64) -> Result<Self, ShapeError> {
+        positive(diameter, "diameter")?;
+        positive(thickness, "thickness")?;
+        ensure(2.0 * thickness < diameter, "thickness", "must be less than half the diameter")?;
+        Ok(Self { diameter, thickness })
+    }
+
+    pub fn disk(&self) -> Disk { Disk::new(self.diameter / 2.0, self.diameter / 2.0 - self.thickness) }
+}
+
+impl Shape for ShapeTube {
+    fn area(&self) -> f64 { self.disk().area() }
+    fn perimeter(&self) -> f64 { self.disk().perimeter() }
+    fn centroid(&self) -> Vector3d { self.disk().centroid() }
+    fn second_moment_of_area(&self) -> Matrix3<f64> { self.disk().second_moment_of_area() }
+    fn linearized(&self, sides: usize) -> RawPolygon<Vector3d> { self.disk().linearized(sides) }
+    fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d> { self.disk().linearized_by_tolerance(max_sagitta) }
+    fn descriptor(&self) -> Option<ShapeDescriptor> { Some(self.clone().into()) }
+}
+
+/// I profile, symmetric about the web. The outline includes the root fillets
+/// and toe radii; flanges with a taper angle (radians) slope on their inner
+/// face, with the flange thickness measured a quarter of the flange width from
+/// the toe as for IPN sections. `linearized(n)` draws every arc with `n` chords.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
 pub struct ShapeI {
     pub bottom_width: f64,
     pub top_width: f64,
@@ -170,6 +488,8 @@ pub struct ShapeI {
 }
 
 impl ShapeI {
+    /// Panics on invalid dimensions, see [`ShapeI::try_new`].
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bottom_width: f64,
         top_width: f64,
@@ -183,29 +503,48 @@ impl ShapeI {
         top_taper_angle: f64,
         bottom_taper_angle: f64,
     ) -> Self {
-        assert!(height > bottom_thickness + top_thickness, "height must exceed flange thickness");
-        let hw = height / 2.0;
-        let bottom_half = bottom_width / 2.0;
-        let top_half = top_width / 2.0;
-        let web_half = web_thickness / 2.0;
-
-        let verts = vec![
-            Vector3d::new(-bottom_half, -hw, 0.0),
-            Vector3d::new(bottom_half, -hw, 0.0),
-            Vector3d::new(bottom_half, -hw + bottom_thickness, 0.0),
-            Vector3d::new(web_half, -hw + bottom_thickness, 0.0),
-            Vector3d::new(web_half, hw - top_thickness, 0.0),
-            Vector3d::new(top_half, hw - top_thickness, 0.0),
-            Vector3d::new(top_half, hw, 0.0),
-            Vector3d::new(-top_half, hw, 0.0),
-            Vector3d::new(-top_half, hw - top_thickness, 0.0),
-            Vector3d::new(-web_half, hw - top_thickness, 0.0),
-            Vector3d::new(-web_half, -hw + bottom_thickness, 0.0),
-            Vector3d::new(-bottom_half, -hw + bottom_thickness, 0.0),
-        ];
+        valid(Self::try_new(
+            bottom_width,
+            top_width,
+            height,
+            bottom_thickness,
+            top_thickness,
+            web_thickness,
+            fillet,
+            top_toe_radius,
+            bottom_toe_radius,
+            top_taper_angle,
+            bottom_taper_angle,
+        ))
+    }
 
-        let polygon = RawPolygon::new(verts);
-        Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        bottom_thickness: f64,
+        top_thickness: f64,
+        web_thickness: f64,
+        fillet: f64,
+        top_toe_radius: f64,
+        bottom_toe_radius: f64,
+        top_taper_angle: f64,
+        bottom_taper_angle: f64,
+    ) -> Result<Self, ShapeError> {
+        positive(bottom_width, "bottom_width")?;
+        positive(top_width, "top_width")?;
+        positive(bottom_thickness, "bottom_thickness")?;
+        positive(top_thickness, "top_thickness")?;
+        positive(web_thickness, "web_thickness")?;
+        ensure(web_thickness < bottom_width.min(top_width), "web_thickness", "must be less than the flange widths")?;
+        ensure(height > bottom_thickness + top_thickness, "height", "must exceed the flange thicknesses")?;
+        non_negative(fillet, "fillet")?;
+        non_negative(top_toe_radius, "top_toe_radius")?;
+        non_negative(bottom_toe_radius, "bottom_toe_radius")?;
+        taper(top_taper_angle, "top_taper_angle")?;
+        taper(bottom_taper_angle, "bottom_taper_angle")?;
+        let mut shape = Self {
             bottom_width,
             top_width,
             height,
@@ -217,15 +556,68 @@ impl ShapeI {
             bottom_toe_radius,
             top_taper_angle,
             bottom_taper_angle,
-            polygon,
-        }
+            polygon: rectangle_polygon(bottom_width.max(top_width), height),
+        };
+        shape.polygon = shape.outline(Chords::PerArc(ARC_SEGMENTS))?;
+        Ok(shape)
+    }
+
+    fn outline(&self, chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> {
+        let hw = self.height / 2.0;
+        let bottom_half = self.bottom_width / 2.0;
+        let top_half = self.top_width / 2.0;
+        let web_half = self.web_thickness / 2.0;
+        // Flange thickness at the toe and at the web face.
+        let flange = |thickness: f64, half: f64, angle: f64| {
+            let slope = angle.tan();
+            (thickness - slope * half / 2.0, thickness + slope * (half / 2.0 - web_half))
+        };
+        let (bottom_toe, bottom_root) = flange(self.bottom_thickness, bottom_half, self.bottom_taper_angle);
+        let (top_toe, top_root) = flange(self.top_thickness, top_half, self.top_taper_angle);
+        tapered_flanges([bottom_toe, top_toe], bottom_root + top_root, self.height)?;
+        let (fillet, bottom_radius, top_radius) = (self.fillet, self.bottom_toe_radius, self.top_toe_radius);
+
+        rounded_outline(
+            &[
+                (-bottom_half, -hw, 0.0, ""),
+                (bottom_half, -hw, 0.0, ""),
+                (bottom_half, -hw + bottom_toe, bottom_radius, "bottom_toe_radius"),
+                (web_half, -hw + bottom_root, fillet, "fillet"),
+                (web_half, hw - top_root, fillet, "fillet"),
+                (top_half, hw - top_toe, top_radius, "top_toe_radius"),
+                (top_half, hw, 0.0, ""),
+                (-top_half, hw, 0.0, ""),
+                (-top_half, hw - top_toe, top_radius, "top_toe_radius"),
+                (-web_half, hw - top_root, fillet, "fillet"),
+                (-web_half, -hw + bottom_root, fillet, "fillet"),
+                (-bottom_half, -hw + bottom_toe, bottom_radius, "bottom_toe_radius"),
+            ],
+            chords,
+        )
     }
 }
 
 impl_polygon_shape!(ShapeI);
 
-/// Channel (C) section.
+/// Checks that tapered flanges keep some thickness at their toes `[bottom,
+/// top]` and leave some web between their roots.
+fn tapered_flanges([bottom_toe, top_toe]: [f64; 2], roots: f64, height: f64) -> Result<(), ShapeError> {
+    ensure(bottom_toe > 0.0, "bottom_taper_angle", "must leave the bottom flange some thickness at the toe")?;
+    ensure(top_toe > 0.0, "top_taper_angle", "must leave the top flange some thickness at the toe")?;
+    ensure(roots < height, "height", "must exceed the tapered flange thicknesses at the web")
+}
+
+/// Channel (C) section with its back on the Y axis. The outline includes the
+/// root fillets, toe radii and back fillets; flanges with a taper angle
+/// (radians) slope on their inner face, with the flange thickness measured at
+/// half the flange width as for UPN sections. `linearized(n)` draws every arc
+/// with `n` chords.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
 pub struct ShapeC {
     pub bottom_width: f64,
     pub top_width: f64,
@@ -244,6 +636,7 @@ pub struct ShapeC {
 }
 
 impl ShapeC {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bottom_width: f64,
         top_width: f64,
@@ -259,20 +652,7 @@ impl ShapeC {
         top_taper_angle: f64,
         bottom_taper_angle: f64,
     ) -> Self {
-        assert!(height > bottom_thickness + top_thickness, "height must exceed flange thickness");
-        let half_h = height / 2.0;
-        let verts = vec![
-            Vector3d::new(0.0, -half_h, 0.0),
-            Vector3d::new(bottom_width, -half_h, 0.0),
-            Vector3d::new(bottom_width, -half_h + bottom_thickness, 0.0),
-            Vector3d::new(web_thickness, -half_h + bottom_thickness, 0.0),
-            Vector3d::new(web_thickness, half_h - top_thickness, 0.0),
-            Vector3d::new(top_width, half_h - top_thickness, 0.0),
-            Vector3d::new(top_width, half_h, 0.0),
-            Vector3d::new(0.0, half_h, 0.0),
-        ];
-        let polygon = RawPolygon::new(verts);
-        Self {
+        valid(Self::try_new(
             bottom_width,
             top_width,
             height,
@@ -286,15 +666,100 @@ impl ShapeC {
             bottom_back_fillet,
             top_taper_angle,
             bottom_taper_angle,
-            polygon,
-        }
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        bottom_thickness: f64,
+        top_thickness: f64,
+        web_thickness: f64,
+        fillet: f64,
+        top_toe_radius: f64,
+        bottom_toe_radius: f64,
+        top_back_fillet: f64,
+        bottom_back_fillet: f64,
+        top_taper_angle: f64,
+        bottom_taper_angle: f64,
+    ) -> Result<Self, ShapeError> {
+        positive(bottom_width, "bottom_width")?;
+        positive(top_width, "top_width")?;
+        positive(bottom_thickness, "bottom_thickness")?;
+        positive(top_thickness, "top_thickness")?;
+        positive(web_thickness, "web_thickness")?;
+        ensure(web_thickness < bottom_width.min(top_width), "web_thickness", "must be less than the flange widths")?;
+        ensure(height > bottom_thickness + top_thickness, "height", "must exceed the flange thicknesses")?;
+        non_negative(fillet, "fillet")?;
+        non_negative(top_toe_radius, "top_toe_radius")?;
+        non_negative(bottom_toe_radius, "bottom_toe_radius")?;
+        non_negative(top_back_fillet, "top_back_fillet")?;
+        non_negative(bottom_back_fillet, "bottom_back_fillet")?;
+        taper(top_taper_angle, "top_taper_angle")?;
+        taper(bottom_taper_angle, "bottom_taper_angle")?;
+        let mut shape = Self {
+            bottom_width,
+            top_width,
+            height,
+            bottom_thickness,
+            top_thickness,
+            web_thickness,
+            fillet,
+            top_toe_radius,
+            bottom_toe_radius,
+            top_back_fillet,
+            bottom_back_fillet,
+            top_taper_angle,
+            bottom_taper_angle,
+            polygon: rectangle_polygon(bottom_width.max(top_width), height),
+        };
+        shape.polygon = shape.outline(Chords::PerArc(ARC_SEGMENTS))?;
+        Ok(shape)
+    }
+
+    fn outline(&self, chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> {
+        let half_h = self.height / 2.0;
+        let web = self.web_thickness;
+        // Flange thickness at the toe and at the web face.
+        let flange = |thickness: f64, width: f64, angle: f64| {
+            let slope = angle.tan();
+            (thickness - slope * width / 2.0, thickness + slope * (width / 2.0 - web))
+        };
+        let (bottom_toe, bottom_root) = flange(self.bottom_thickness, self.bottom_width, self.bottom_taper_angle);
+        let (top_toe, top_root) = flange(self.top_thickness, self.top_width, self.top_taper_angle);
+        tapered_flanges([bottom_toe, top_toe], bottom_root + top_root, self.height)?;
+
+        rounded_outline(
+            &[
+                (0.0, -half_h, self.bottom_back_fillet, "bottom_back_fillet"),
+                (self.bottom_width, -half_h, 0.0, ""),
+                (self.bottom_width, -half_h + bottom_toe, self.bottom_toe_radius, "bottom_toe_radius"),
+                (web, -half_h + bottom_root, self.fillet, "fillet"),
+                (web, half_h - top_root, self.fillet, "fillet"),
+                (self.top_width, half_h - top_toe, self.top_toe_radius, "top_toe_radius"),
+                (self.top_width, half_h, 0.0, ""),
+                (0.0, half_h, self.top_back_fillet, "top_back_fillet"),
+            ],
+            chords,
+        )
     }
 }
 
 impl_polygon_shape!(ShapeC);
 
-/// Angle (L) section.
+/// Angle (L) section, the vertical leg centered on the Y axis and the
+/// horizontal leg along the bottom. The outline includes the root fillet, the
+/// toe radii of both legs and the back fillet at the heel; a taper angle
+/// (radians) slopes the inner faces of both legs, with the leg thicknesses
+/// measured halfway along them. `linearized(n)` draws every arc with `n` chords.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
 pub struct ShapeL {
     pub width: f64,
     pub height: f64,
@@ -308,6 +773,8 @@ pub struct ShapeL {
 }
 
 impl ShapeL {
+    /// Panics on invalid dimensions, see [`ShapeL::try_new`].
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         width: f64,
         height: f64,
@@ -318,23 +785,29 @@ impl ShapeL {
         back_fillet: f64,
         taper_angle: f64,
     ) -> Self {
-        assert!(width > web_thickness && height > flange_thickness, "invalid L-section dimensions");
-        
-        // Position L-section with web centered on Y-axis and flange extending to the right
-        // Height extends symmetrically about X-axis
-        let web_half = web_thickness / 2.0;
-        let height_half = height / 2.0;
-        
-        let verts = vec![
-            Vector3d::new(-web_half, -height_half, 0.0),
-            Vector3d::new(width - web_half, -height_half, 0.0),
-            Vector3d::new(width - web_half, -height_half + flange_thickness, 0.0),
-            Vector3d::new(web_half, -height_half + flange_thickness, 0.0),
-            Vector3d::new(web_half, height_half, 0.0),
-            Vector3d::new(-web_half, height_half, 0.0),
-        ];
-        let polygon = RawPolygon::new(verts);
-        Self {
+        valid(Self::try_new(width, height, flange_thickness, web_thickness, fillet, toe_radius, back_fillet, taper_angle))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        width: f64,
+        height: f64,
+        flange_thickness: f64,
+        web_thickness: f64,
+        fillet: f64,
+        toe_radius: f64,
+        back_fillet: f64,
+        taper_angle: f64,
+    ) -> Result<Self, ShapeError> {
+        positive(flange_thickness, "flange_thickness")?;
+        positive(web_thickness, "web_thickness")?;
+        ensure(width > web_thickness, "width", "must exceed the web thickness")?;
+        ensure(height > flange_thickness, "height", "must exceed the flange thickness")?;
+        non_negative(fillet, "fillet")?;
+        non_negative(toe_radius, "toe_radius")?;
+        non_negative(back_fillet, "back_fillet")?;
+        taper(taper_angle, "taper_angle")?;
+        let mut shape = Self {
             width,
             height,
             flange_thickness,
@@ -343,15 +816,56 @@ impl ShapeL {
             toe_radius,
             back_fillet,
             taper_angle,
-            polygon,
-        }
+            polygon: rectangle_polygon(width, height),
+        };
+        shape.polygon = shape.outline(Chords::PerArc(ARC_SEGMENTS))?;
+        Ok(shape)
+    }
+
+    fn outline(&self, chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> {
+        // Position L-section with web centered on Y-axis and flange extending to the right
+        // Height extends symmetrically about X-axis
+        let web_half = self.web_thickness / 2.0;
+        let height_half = self.height / 2.0;
+        let toe = self.width - web_half;
+        let slope = self.taper_angle.tan();
+
+        // Inner faces: flange y = y0 + slope (xm − x), web x = web_half + slope (ym − y),
+        // through the nominal thickness halfway along each leg.
+        let (xm, ym) = (self.width / 2.0, self.flange_thickness / 2.0);
+        let y0 = -height_half + self.flange_thickness;
+        let root_x = (web_half + slope * (ym - y0) - slope * slope * xm) / (1.0 - slope * slope);
+        let root_y = y0 + slope * (xm - root_x);
+        let (flange_toe, web_toe) = (y0 + slope * (xm - toe), web_half + slope * (ym - height_half));
+        ensure(flange_toe > -height_half && web_toe > -web_half, "taper_angle", "must leave the legs some thickness at the toes")?;
+
+        rounded_outline(
+            &[
+                (-web_half, -height_half, self.back_fillet, "back_fillet"),
+                (toe, -height_half, 0.0, ""),
+                (toe, flange_toe, self.toe_radius, "toe_radius"),
+                (root_x, root_y, self.fillet, "fillet"),
+                (web_toe, height_half, self.toe_radius, "toe_radius"),
+                (-web_half, height_half, 0.0, ""),
+            ],
+            chords,
+        )
     }
 }
 
 impl_polygon_shape!(ShapeL);
 
-/// Tee (T) section.
+/// Tee (T) section, symmetric about the web with the flange on top. The
+/// outline includes the root fillets and the flange toe radii; a taper angle
+/// (radians) slopes the inner flange face, with the flange thickness measured
+/// halfway along the outstand.
+/// `linearized(n)` draws every arc with `n` chords.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
 pub struct ShapeT {
     pub width: f64,
     pub height: f64,
@@ -364,6 +878,7 @@ pub struct ShapeT {
 }
 
 impl ShapeT {
+    /// Panics on invalid dimensions, see [`ShapeT::try_new`].
     pub fn new(
         width: f64,
         height: f64,
@@ -373,22 +888,26 @@ impl ShapeT {
         toe_radius: f64,
         taper_angle: f64,
     ) -> Self {
-        assert!(height > flange_thickness, "height must exceed flange thickness");
-        let half_h = height / 2.0;
-        let half_w = width / 2.0;
-        let web_half = web_thickness / 2.0;
-        let verts = vec![
-            Vector3d::new(-web_half, -half_h, 0.0),
-            Vector3d::new(web_half, -half_h, 0.0),
-            Vector3d::new(web_half, half_h - flange_thickness, 0.0),
-            Vector3d::new(half_w, half_h - flange_thickness, 0.0),
-            Vector3d::new(half_w, half_h, 0.0),
-            Vector3d::new(-half_w, half_h, 0.0),
-            Vector3d::new(-half_w, half_h - flange_thickness, 0.0),
-            Vector3d::new(-web_half, half_h - flange_thickness, 0.0),
-        ];
-        let polygon = RawPolygon::new(verts);
-        Self {
+        valid(Self::try_new(width, height, flange_thickness, web_thickness, fillet, toe_radius, taper_angle))
+    }
+
+    pub fn try_new(
+        width: f64,
+        height: f64,
+        flange_thickness: f64,
+        web_thickness: f64,
+        fillet: f64,
+        toe_radius: f64,
+        taper_angle: f64,
+    ) -> Result<Self, ShapeError> {
+        positive(flange_thickness, "flange_thickness")?;
+        positive(web_thickness, "web_thickness")?;
+        ensure(width > web_thickness, "width", "must exceed the web thickness")?;
+        ensure(height > flange_thickness, "height", "must exceed the flange thickness")?;
+        non_negative(fillet, "fillet")?;
+        non_negative(toe_radius, "toe_radius")?;
+        taper(taper_angle, "taper_angle")?;
+        let mut shape = Self {
             width,
             height,
             flange_thickness,
@@ -396,13 +915,253 @@ impl ShapeT {
             fillet,
             toe_radius,
             taper_angle,
-            polygon,
-        }
+            polygon: rectangle_polygon(width, height),
+        };
+        shape.polygon = shape.outline(Chords::PerArc(ARC_SEGMENTS))?;
+        Ok(shape)
+    }
+
+    fn outline(&self, chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> {
+        let half_h = self.height / 2.0;
+        let half_w = self.width / 2.0;
+        let web_half = self.web_thickness / 2.0;
+        let rise = self.taper_angle.tan() * (half_w - web_half) / 2.0;
+        let (toe, root) = (self.flange_thickness - rise, self.flange_thickness + rise);
+        ensure(toe > 0.0, "taper_angle", "must leave the flange some thickness at the toe")?;
+        ensure(root < self.height, "height", "must exceed the tapered flange thickness at the web")?;
+        let (fillet, radius) = (self.fillet, self.toe_radius);
+
+        rounded_outline(
+            &[
+                (-web_half, -half_h, 0.0, ""),
+                (web_half, -half_h, 0.0, ""),
+                (web_half, half_h - root, fillet, "fillet"),
+                (half_w, half_h - toe, radius, "toe_radius"),
+                (half_w, half_h, 0.0, ""),
+                (-half_w, half_h, 0.0, ""),
+                (-half_w, half_h - toe, radius, "toe_radius"),
+                (-web_half, half_h - root, fillet, "fillet"),
+            ],
+            chords,
+        )
     }
 }
 
 impl_polygon_shape!(ShapeT);
 
+/// User-defined section outline in the XY plane.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ShapeDescriptor", into = "ShapeDescriptor")
+)]
+pub struct GenericShape {
+    polygon: RawPolygon<Vector3d>,
+}
+
+impl GenericShape {
+    /// Build a shape from outline points in any order of traversal. The input
+    /// is cleaned first: z coordinates are dropped, duplicate points and
+    /// collinear or spike vertices are removed, crossing edges are untangled by
+    /// reversing the run of points between them, and the outline is oriented
+    /// counter-clockwise. Returns `None` when fewer than three points or no
+    /// area remain, or the outline still is not simple.
+    pub fn from_points<I, P>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Vector3d>,
+    {
+        let mut ring: Vec<Vector2d> = points.into_iter().map(|p| Vector2d(p.into().0.xy())).collect();
+        remove_degenerate_vertices(&mut ring);
+        if untangle(&mut ring) {
+            remove_degenerate_vertices(&mut ring);
+        }
+        if ring.len() < 3 {
+            return None;
+        }
+        let area = signed_area(&ring);
+        if area.abs() <= epsilon() {
+            return None;
+        }
+        if area < 0.0 {
+            ring.reverse();
+        }
+        let polygon = RawPolygon::new(ring.iter().map(|&p| Vector3d::from(p)));
+        polygon.is_valid(None).then_some(Self { polygon })
+    }
+
+    fn outline(&self, _chords: Chords) -> Result<RawPolygon<Vector3d>, ShapeError> { Ok(self.polygon.clone()) }
+}
+
+impl_polygon_shape!(GenericShape);
+
+/// Built-up section of shapes placed in the XY plane, e.g. a welded plate
+/// girder or a battened column. Each part is rotated by its angle (radians)
+/// about the origin of its own coordinates and then moved by its offset;
+/// parts should touch but not overlap. Properties combine the parts by the
+/// parallel axis theorem.
+#[derive(Default)]
+pub struct CompoundShape {
+    parts: Vec<(Box<dyn Shape>, Vector2d, f64)>,
+}
+
+impl CompoundShape {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_part<S: Shape + 'static>(mut self, shape: S, offset: Vector2d, angle: f64) -> Self {
+        self.add_part(shape, offset, angle);
+        self
+    }
+
+    pub fn add_part<S: Shape + 'static>(&mut self, shape: S, offset: Vector2d, angle: f64) {
+        self.add_boxed_part(Box::new(shape), offset, angle);
+    }
+
+    pub(crate) fn add_boxed_part(&mut self, shape: Box<dyn Shape>, offset: Vector2d, angle: f64) {
+        self.parts.push((shape, offset, angle));
+    }
+
+    pub fn part_count(&self) -> usize { self.parts.len() }
+
+    /// Outline of every part as placed, each arc drawn as for
+    /// [`Shape::linearized`].
+    pub fn part_outlines(&self, sides: usize) -> Vec<RawPolygon<Vector3d>> { self.placed(|shape| shape.linearized(sides)) }
+
+    /// Part outlines merged where they touch or overlap: one polygon for a
+    /// plate girder, one per chord and batten group for separated parts.
+    pub fn outlines(&self, sides: usize) -> Vec<RawPolygon<Vector3d>> { Self::merged(self.part_outlines(sides)) }
+
+    /// Outline of every part, drawn by `outline`, as placed.
+    fn placed(&self, outline: impl Fn(&dyn Shape) -> RawPolygon<Vector3d>) -> Vec<RawPolygon<Vector3d>> {
+        let axis = Line3d::new((0.0, 0.0, 0.0), (0.0, 0.0, 1.0));
+        self.parts
+            .iter()
+            .map(|(shape, offset, angle)| outline(shape.as_ref()).rotated_about(&axis, *angle).translated((offset.x(), offset.y(), 0.0)))
+            .collect()
+    }
+
+    fn merged(outlines: Vec<RawPolygon<Vector3d>>) -> Vec<RawPolygon<Vector3d>> {
+        let mut merged: Vec<RawPolygon<Vector3d>> = Vec::new();
+        for mut outline in outlines {
+            let mut apart = Vec::new();
+            while let Some(index) = merged.iter().position(|piece| piece.intersects(&outline)) {
+                let piece = merged.swap_remove(index);
+                let mut union = piece.union(&outline);
+                if union.len() == 1 {
+                    outline = union.remove(0);
+                } else {
+                    // Touching in a point only.
+                    apart.push(piece);
+                }
+            }
+            merged.extend(apart);
+            merged.push(outline);
+        }
+        merged
+    }
+
+    fn single(mut outlines: Vec<RawPolygon<Vector3d>>) -> RawPolygon<Vector3d> {
+        assert!(outlines.len() == 1, "compound parts must form one connected outline");
+        outlines.remove(0)
+    }
+
+    /// Centroid of a part as placed.
+    fn part_centroid(shape: &dyn Shape, offset: &Vector2d, angle: f64) -> nalgebra::Vector3<f64> {
+        let local = shape.centroid();
+        let (sin, cos) = angle.sin_cos();
+        nalgebra::Vector3::new(cos * local.x() - sin * local.y() + offset.x(), sin * local.x() + cos * local.y() + offset.y(), 0.0)
+    }
+}
+
+impl Shape for CompoundShape {
+    fn area(&self) -> f64 { self.parts.iter().map(|(shape, ..)| shape.area()).sum() }
+
+    /// Length of the merged outlines, holes included.
+    fn perimeter(&self) -> f64 { self.outlines(ARC_SEGMENTS).iter().map(|outline| outline.perimeter()).sum() }
+
+    fn centroid(&self) -> Vector3d {
+        let moment: nalgebra::Vector3<f64> =
+            self.parts.iter().map(|(shape, offset, angle)| Self::part_centroid(shape.as_ref(), offset, *angle) * shape.area()).sum();
+        Vector3d(moment / self.area())
+    }
+
+    fn second_moment_of_area(&self) -> Matrix3<f64> {
+        let centroid = self.centroid().0;
+        self.parts
+            .iter()
+            .map(|(shape, offset, angle)| {
+                let rotation = *nalgebra::Rotation3::from_axis_angle(&nalgebra::Vector3::z_axis(), *angle).matrix();
+                let d = Self::part_centroid(shape.as_ref(), offset, *angle) - centroid;
+                rotation * shape.second_moment_of_area() * rotation.transpose()
+                    + (Matrix3::identity() * d.norm_squared() - d * d.transpose()) * shape.area()
+            })
+            .sum()
+    }
+
+    /// The merged outline. Panics when the parts do not form one connected
+    /// outline; see [`CompoundShape::outlines`] for separated parts.
+    fn linearized(&self, sides: usize) -> RawPolygon<Vector3d> { Self::single(self.outlines(sides)) }
+
+    fn linearized_by_tolerance(&self, max_sagitta: f64) -> RawPolygon<Vector3d> {
+        Self::single(Self::merged(self.placed(|shape| shape.linearized_by_tolerance(max_sagitta))))
+    }
+
+    /// `None` when any part has no descriptor.
+    fn descriptor(&self) -> Option<ShapeDescriptor> {
+        let parts = self.parts.iter().map(|(shape, offset, angle)| {
+            Some(CompoundPart { shape: shape.descriptor()?, offset: *offset, angle: *angle })
+        });
+        Some(ShapeDescriptor::CompoundShape { parts: parts.collect::<Option<_>>()? })
+    }
+}
+
+/// Drop repeated points and vertices whose adjacent edges are parallel, i.e.
+/// collinear midpoints and spikes, until none are left.
+fn remove_degenerate_vertices(ring: &mut Vec<Vector2d>) {
+    let mut i = 0;
+    let mut unchanged = 0;
+    while ring.len() >= 3 && unchanged < ring.len() {
+        let n = ring.len();
+        let (prev, next) = (ring[(i + n - 1) % n], ring[(i + 1) % n]);
+        let (a, b) = (ring[i] - prev, next - ring[i]);
+        if a.norm() <= epsilon() || a.cross(&b).abs() <= epsilon() * a.norm() * b.norm() {
+            ring.remove(i);
+            unchanged = 0;
+            i = if i == 0 { ring.len().saturating_sub(1) } else { i - 1 };
+        } else {
+            unchanged += 1;
+            i = (i + 1) % n;
+        }
+    }
+    ring.dedup_by(|a, b| (*a - *b).norm() <= epsilon());
+}
+
+/// Remove proper edge crossings by reversing the points between the crossing
+/// edges. Each reversal shortens the outline, so this terminates. Returns
+/// whether anything changed.
+fn untangle(ring: &mut [Vector2d]) -> bool {
+    let n = ring.len();
+    let mut changed = false;
+    'search: loop {
+        for i in 0..n {
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+                let (a, b, c, d) = (ring[i], ring[i + 1], ring[j], ring[(j + 1) % n]);
+                let side = |p: Vector2d, q: Vector2d, r: Vector2d| (q - p).cross(&(r - p));
+                if side(a, b, c) * side(a, b, d) < 0.0 && side(c, d, a) * side(c, d, b) < 0.0 {
+                    ring[i + 1..=j].reverse();
+                    changed = true;
+                    continue 'search;
+                }
+            }
+        }
+        return changed;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::PI;
@@ -0,0 +1,306 @@
+//! Shear properties of sections: the shear areas from the strain energy of the
+//! shear stresses due to unit shear forces, the shear center as the pole of
+//! the warping function (Trefftz) and the warping constant about it. Solid polygons are solved with linear
+//! triangles, neglecting Poisson's ratio; thin-walled open profiles follow from
+//! the shear flow and sectorial coordinates along their wall mid-lines.
+
+use nalgebra::{Matrix2, Vector2};
+
+use crate::mesh::{triangulate, MeshOptions};
+use crate::torsion::{conjugate_gradient, laplacian, shape_gradients, triangle_area, ELEMENTS_ACROSS};
+use crate::{Polygon, Vector2d, Vector3d};
+
+impl Polygon {
+    /// Shear areas for shear forces along the local X and Y axes, `V² / ∫τ² dA`
+    /// with the stress distribution of the elastic solution (`5/6 A` for a
+    /// rectangle). Solved numerically; thin-walled profiles are better served
+    /// by [`ThinWalledSection`].
+    pub fn shear_areas(&self) -> Vector2d {
+        let [energy_x, energy_y, ..] = self.shear_terms(true);
+        let inertia = self.about_centroid_local();
+        let delta = 2.0 * inertia.determinant();
+        Vector2d::new(delta * delta / energy_x, delta * delta / energy_y)
+    }
+
+    /// Shear center, the point the shear force has to pass through for the
+    /// section to bend without twisting. Lies on every axis of symmetry.
+    pub fn shear_center(&self) -> Vector3d {
+        let [x_omega, y_omega, ..] = self.shear_terms(false);
+        let (x, y) = pole(&self.about_centroid_local(), x_omega, y_omega);
+        self.to_global(Vector3d::new(x, y, 0.0))
+    }
+
+    /// Warping constant `Iw = ∫ωₛ² dA − (∫ωₛ dA)² / A` of the warping function
+    /// about the shear center; `b³ h² t / 24` for a thin I-section with flanges
+    /// `b × t` at distance `h`, and nearly zero for sections whose walls meet in
+    /// one point, like angles and tees.
+    pub fn warping_constant(&self) -> f64 {
+        let [x_omega, y_omega, omega, omega_sq] = self.shear_terms(false);
+        let inertia = self.about_centroid_local();
+        let (ixx, iyy, ixy) = (inertia[(0, 0)], inertia[(1, 1)], inertia[(0, 1)]);
+        // Moving the pole to the shear center adds x₀ y − y₀ x to the warping.
+        let (x0, y0) = pole(&inertia, x_omega, y_omega);
+        let squares = omega_sq + 2.0 * (x0 * y_omega - y0 * x_omega) + y0 * y0 * iyy - 2.0 * x0 * y0 * ixy + x0 * x0 * ixx;
+        squares - omega * omega / self.area()
+    }
+
+    /// Either the shear energies (then padded with zeros), or the warping
+    /// integrals `[∫xω, ∫yω, ∫ω, ∫ω²]` about the centroid, extrapolated from
+    /// two meshes like [`Polygon::torsion_constant`].
+    fn shear_terms(&self, energies: bool) -> [f64; 4] {
+        let h = 2.0 * self.area() / self.perimeter() / ELEMENTS_ACROSS;
+        let (coarse, fine) = (self.shear_solution(h, energies), self.shear_solution(h / 2.0, energies));
+        [0, 1, 2, 3].map(|i| (4.0 * fine[i] - coarse[i]) / 3.0)
+    }
+
+    /// With centroidal coordinates, the warping function `∇²ω = 0`,
+    /// `∂ω/∂n = y nₓ − x n_y` and the shear functions `∇²Ψ = 2(Ixy y − Ixx x)`,
+    /// `∇²Φ = 2(Ixy x − Iyy y)` with zero normal derivative (Pilkey, ν = 0).
+    /// The shear stresses are `∇Ψ / Δ` and `∇Φ / Δ` per unit shear force along X
+    /// and Y, with `Δ = 2 (Ixx Iyy − Ixy²)`.
+    fn shear_solution(&self, h: f64, energies: bool) -> [f64; 4] {
+        let mesh = triangulate(self, &MeshOptions::new().with_max_edge_length(h));
+        let points: Vec<Vector2<f64>> = self.local_ring(mesh.vertices());
+        let inertia = self.about_centroid_local();
+        let (ixx, iyy, ixy) = (inertia[(0, 0)], inertia[(1, 1)], inertia[(0, 1)]);
+
+        // Pure Neumann problems: the first vertex is held to fix the constant.
+        let dof: Vec<Option<usize>> = (0..points.len()).map(|i| i.checked_sub(1)).collect();
+        let count = points.len() - 1;
+        let stiffness = laplacian(&points, mesh.triangles(), &dof, count);
+        let mut loads = [vec![0.0; count], vec![0.0; count], vec![0.0; count]];
+        for triangle in mesh.triangles() {
+            let corners = triangle.map(|v| points[v]);
+            let area = triangle_area(corners);
+            let gradients = shape_gradients(corners);
+            let sum = corners[0] + corners[1] + corners[2];
+            for (i, &vertex) in triangle.iter().enumerate() {
+                let Some(row) = dof[vertex] else { continue };
+                // ∫ Nᵢ p dA = A/12 (pᵢ + Σp) for linear p.
+                let weighted = (corners[i] + sum) * area / 12.0;
+                loads[0][row] += area * (sum.y * gradients[i].x - sum.x * gradients[i].y) / 3.0;
+                loads[1][row] += 2.0 * (ixx * weighted.x - ixy * weighted.y);
+                loads[2][row] += 2.0 * (iyy * weighted.y - ixy * weighted.x);
+            }
+        }
+        let solve = |load: &[f64]| std::iter::once(0.0).chain(conjugate_gradient(&stiffness, load)).collect::<Vec<f64>>();
+        if energies {
+            let energy = |load: &[f64]| -> f64 { load.iter().zip(&solve(load)[1..]).map(|(f, x)| f * x).sum() };
+            return [energy(&loads[1]), energy(&loads[2]), 0.0, 0.0];
+        }
+
+        let omega = solve(&loads[0]);
+        let mut integrals = [0.0; 4];
+        for triangle in mesh.triangles() {
+            let corners = triangle.map(|v| points[v]);
+            let area = triangle_area(corners);
+            let values = triangle.map(|v| omega[v]);
+            // ∫ p ω dA = A/12 (Σ pᵢωᵢ + Σp Σω) for linear p and ω.
+            let sum_omega: f64 = values.iter().sum();
+            let sum = corners[0] + corners[1] + corners[2];
+            let products = (0..3).fold(Vector2::zeros(), |acc, i| acc + corners[i] * values[i]);
+            let squares: f64 = values.iter().map(|w| w * w).sum();
+            integrals[0] += area / 12.0 * (products.x + sum.x * sum_omega);
+            integrals[1] += area / 12.0 * (products.y + sum.y * sum_omega);
+            integrals[2] += area / 3.0 * sum_omega;
+            integrals[3] += area / 12.0 * (squares + sum_omega * sum_omega);
+        }
+        integrals
+    }
+}
+
+/// Centroidal coordinates of the shear center from the warping moments about
+/// the centroid: the pole about which the warping is orthogonal to `x` and `y`.
+fn pole(inertia: &Matrix2<f64>, x_omega: f64, y_omega: f64) -> (f64, f64) {
+    let (ixx, iyy, ixy) = (inertia[(0, 0)], inertia[(1, 1)], inertia[(0, 1)]);
+    let determinant = ixx * iyy - ixy * ixy;
+    ((ixy * x_omega - iyy * y_omega) / determinant, (ixx * x_omega - ixy * y_omega) / determinant)
+}
+
+/// Thin-walled open profile given by the mid-lines of its walls, e.g. a channel
+/// as three walls. Walls join where their end points coincide and must form a
+/// tree; profiles with closed cells are rejected by the shear methods.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThinWalledSection {
+    walls: Vec<(Vector2d, Vector2d, f64)>,
+}
+
+impl ThinWalledSection {
+    pub fn new() -> Self { Self::default() }
+
+    /// Wall along the mid-line from `start` to `end`. Panics on a non-positive
+    /// thickness or a zero-length wall.
+    pub fn with_wall(mut self, start: Vector2d, end: Vector2d, thickness: f64) -> Self {
+        assert!(thickness > 0.0, "wall thickness must be positive");
+        assert!((end.0 - start.0).norm() > utils::epsilon(), "wall must have non-zero length");
+        self.walls.push((start, end, thickness));
+        self
+    }
+
+    pub fn walls(&self) -> &[(Vector2d, Vector2d, f64)] { &self.walls }
+
+    pub fn area(&self) -> f64 { self.walls.iter().map(|(a, b, t)| (b.0 - a.0).norm() * t).sum() }
+
+    pub fn centroid(&self) -> Vector2d {
+        let moment = self.walls.iter().fold(Vector2::zeros(), |sum, (a, b, t)| sum + (a.0 + b.0) / 2.0 * (b.0 - a.0).norm() * *t);
+        Vector2d(moment / self.area())
+    }
+
+    /// Centroidal second moments `[Ixx Ixy; Ixy Iyy]` as for
+    /// [`Polygon::about_centroid_local`], walls taken as thin rectangles.
+    pub fn second_moments(&self) -> Matrix2<f64> { self.moments(true) }
+
+    /// Second moments with or without the walls' own thickness terms. The
+    /// shear properties use the mid-line moments, matching their mid-line
+    /// integrals, unless the walls are collinear and those vanish.
+    fn moments(&self, own_thickness: bool) -> Matrix2<f64> {
+        let centroid = self.centroid().0;
+        let mut inertia = Matrix2::zeros();
+        for (a, b, t) in &self.walls {
+            let (p, q) = (a.0 - centroid, b.0 - centroid);
+            let length = (q - p).norm();
+            let (c, s) = ((q.x - p.x) / length, (q.y - p.y) / length);
+            let own = if own_thickness { length * t.powi(3) / 12.0 } else { 0.0 };
+            let ixx = t * length * linear_product(p.y, q.y, p.y, q.y) + own * c * c;
+            let iyy = t * length * linear_product(p.x, q.x, p.x, q.x) + own * s * s;
+            let ixy = t * length * linear_product(p.x, q.x, p.y, q.y) - own * s * c;
+            inertia += Matrix2::new(ixx, ixy, ixy, iyy);
+        }
+        inertia
+    }
+
+    fn shear_moments(&self) -> Matrix2<f64> {
+        let midline = self.moments(false);
+        if midline.determinant() > 1e-9 * midline.trace().powi(2) { midline } else { self.moments(true) }
+    }
+
+    /// Shear center from the sectorial coordinates about the centroid; `None`
+    /// when the walls do not form a single open profile.
+    pub fn shear_center(&self) -> Option<Vector2d> {
+        let (x, y) = self.warping()?.pole;
+        Some(Vector2d(self.centroid().0 + Vector2::new(x, y)))
+    }
+
+    /// Warping constant from the sectorial coordinates about the shear center,
+    /// `Iw = ∫ω² dA − (∫ω dA)² / A`; `None` when the walls do not form a single
+    /// open profile.
+    pub fn warping_constant(&self) -> Option<f64> {
+        let warping = self.warping()?;
+        let (x0, y0) = warping.pole;
+        let values: Vec<f64> = warping.positions.iter().zip(&warping.values).map(|(p, w)| w - y0 * p.x + x0 * p.y).collect();
+        let (mut sum, mut squares) = (0.0, 0.0);
+        for (wall, &(i, j)) in self.walls.iter().zip(&warping.tree.ends) {
+            let weight = wall.2 * (warping.positions[j] - warping.positions[i]).norm();
+            sum += weight * (values[i] + values[j]) / 2.0;
+            squares += weight * linear_product(values[i], values[j], values[i], values[j]);
+        }
+        Some(squares - sum * sum / self.area())
+    }
+
+    /// Warping function at the joints about the centroid, minus the sectorial
+    /// coordinate for an open profile, and the shear center it gives.
+    fn warping(&self) -> Option<Warping> {
+        let tree = self.tree()?;
+        let centroid = self.centroid().0;
+        let positions: Vec<Vector2<f64>> = tree.nodes.iter().map(|node| node - centroid).collect();
+        let mut values = vec![0.0; positions.len()];
+        for &(node, parent, _) in &tree.order[1..] {
+            values[node] = values[parent] - positions[parent].perp(&positions[node]);
+        }
+        let (mut x_omega, mut y_omega) = (0.0, 0.0);
+        for (wall, &(i, j)) in self.walls.iter().zip(&tree.ends) {
+            let weight = wall.2 * (positions[j] - positions[i]).norm();
+            x_omega += weight * linear_product(positions[i].x, positions[j].x, values[i], values[j]);
+            y_omega += weight * linear_product(positions[i].y, positions[j].y, values[i], values[j]);
+        }
+        let pole = pole(&self.shear_moments(), x_omega, y_omega);
+        Some(Warping { tree, positions, values, pole })
+    }
+
+    /// Shear areas for shear forces along X and Y from the shear flow
+    /// `q = V Q / I`, generalised to unsymmetric profiles; `None` when the walls
+    /// do not form a single open profile.
+    pub fn shear_areas(&self) -> Option<Vector2d> {
+        let tree = self.tree()?;
+        let centroid = self.centroid().0;
+        let inertia = self.shear_moments();
+        let (ixx, iyy, ixy) = (inertia[(0, 0)], inertia[(1, 1)], inertia[(0, 1)]);
+        let determinant = ixx * iyy - ixy * ixy;
+        // First moments (∫x dA, ∫y dA) of the walls cut off beyond each node.
+        let mut cut_off = vec![Vector2::<f64>::zeros(); tree.nodes.len()];
+        let (mut energy_x, mut energy_y) = (0.0, 0.0);
+        let gauss = [(-0.6_f64.sqrt(), 5.0 / 9.0), (0.0, 8.0 / 9.0), (0.6_f64.sqrt(), 5.0 / 9.0)];
+        for &(node, parent, wall) in tree.order[1..].iter().rev() {
+            let (a, b) = (tree.nodes[node] - centroid, tree.nodes[parent] - centroid);
+            let (length, t) = ((b - a).norm(), self.walls[wall].2);
+            let start = cut_off[node];
+            let moment_at = |xi: f64| start + (a * xi + (b - a) * xi * xi / (2.0 * length)) * t;
+            for (point, weight) in gauss {
+                let moment = moment_at(length * (1.0 + point) / 2.0);
+                let flow_x = (ixx * moment.x - ixy * moment.y) / determinant;
+                let flow_y = (iyy * moment.y - ixy * moment.x) / determinant;
+                energy_x += weight * length / 2.0 * flow_x * flow_x / t;
+                energy_y += weight * length / 2.0 * flow_y * flow_y / t;
+            }
+            let end = moment_at(length);
+            cut_off[parent] += end;
+        }
+        Some(Vector2d::new(1.0 / energy_x, 1.0 / energy_y))
+    }
+
+    /// Walls as a tree over their joints, traversed breadth first from the start
+    /// of the first wall.
+    fn tree(&self) -> Option<WallTree> {
+        let extent = self.walls.iter().map(|(a, b, _)| a.0.abs().max().max(b.0.abs().max())).fold(0.0, f64::max);
+        let tolerance = 1e-9 * extent.max(1.0);
+        let mut nodes: Vec<Vector2<f64>> = Vec::new();
+        let mut node_of = |point: &Vector2d| match nodes.iter().position(|node| (node - point.0).norm() <= tolerance) {
+            Some(index) => index,
+            None => {
+                nodes.push(point.0);
+                nodes.len() - 1
+            }
+        };
+        let ends: Vec<(usize, usize)> = self.walls.iter().map(|(a, b, _)| (node_of(a), node_of(b))).collect();
+        if ends.is_empty() || ends.len() + 1 != nodes.len() {
+            return None;
+        }
+        let mut order = vec![(0, 0, usize::MAX)];
+        let mut visited = vec![false; nodes.len()];
+        visited[0] = true;
+        let mut next = 0;
+        while next < order.len() {
+            let current = order[next].0;
+            for (wall, &(i, j)) in ends.iter().enumerate() {
+                let other = if i == current { j } else if j == current { i } else { continue };
+                if !visited[other] {
+                    visited[other] = true;
+                    order.push((other, current, wall));
+                }
+            }
+            next += 1;
+        }
+        (order.len() == nodes.len()).then_some(WallTree { nodes, ends, order })
+    }
+}
+
+struct Warping {
+    tree: WallTree,
+    /// Joints relative to the centroid.
+    positions: Vec<Vector2<f64>>,
+    values: Vec<f64>,
+    /// Shear center relative to the centroid.
+    pole: (f64, f64),
+}
+
+struct WallTree {
+    nodes: Vec<Vector2<f64>>,
+    /// Joint indices at the start and end of every wall.
+    ends: Vec<(usize, usize)>,
+    /// `(node, parent, wall to parent)` in breadth-first order from the root.
+    order: Vec<(usize, usize, usize)>,
+}
+
+/// Mean of `f g` along a wall with `f` and `g` varying linearly between the
+/// given end values.
+fn linear_product(f0: f64, f1: f64, g0: f64, g1: f64) -> f64 { (2.0 * f0 * g0 + f0 * g1 + f1 * g0 + 2.0 * f1 * g1) / 6.0 }
@@ -0,0 +1,117 @@
+//! Tolerance-based welding of points, e.g. merging polygon vertices or model
+//! nodes that should coincide. Points are hashed by the grid cell of size
+//! `tolerance` they fall in, so a lookup only visits the 27 cells around a point.
+
+use std::collections::HashMap;
+
+use crate::Vector3d;
+
+/// Merges points closer than a tolerance into shared vertices with stable
+/// indices.
+///
+/// [`VertexWelder::insert`] welds incrementally: indices never change once
+/// handed out, and a point joins the nearest vertex within tolerance. The
+/// result of a chain of points each within tolerance of the next then depends
+/// on the insertion order; [`VertexWelder::weld`] clusters a whole batch
+/// instead and does not.
+#[derive(Debug, Clone)]
+pub struct VertexWelder {
+    tolerance: f64,
+    vertices: Vec<Vector3d>,
+    cells: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl VertexWelder {
+    /// Panics unless the tolerance is positive.
+    pub fn new(tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "welding tolerance must be positive");
+        Self { tolerance, vertices: Vec::new(), cells: HashMap::new() }
+    }
+
+    pub fn tolerance(&self) -> f64 { self.tolerance }
+    pub fn vertices(&self) -> &[Vector3d] { &self.vertices }
+    pub fn len(&self) -> usize { self.vertices.len() }
+    pub fn is_empty(&self) -> bool { self.vertices.is_empty() }
+
+    /// Index of the vertex nearest to `point` within the tolerance, ties going
+    /// to the lower index.
+    pub fn find(&self, point: &Vector3d) -> Option<usize> {
+        neighbors(self.cell(point))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .map(|&index| (index, (self.vertices[index].0 - point.0).norm()))
+            .filter(|&(_, distance)| distance <= self.tolerance)
+            .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)))
+            .map(|(index, _)| index)
+    }
+
+    /// Index of the vertex `point` welds to; a point with no vertex within
+    /// the tolerance becomes a new vertex.
+    pub fn insert(&mut self, point: Vector3d) -> usize {
+        if let Some(index) = self.find(&point) {
+            return index;
+        }
+        let index = self.vertices.len();
+        self.cells.entry(self.cell(&point)).or_default().push(index);
+        self.vertices.push(point);
+        index
+    }
+
+    /// Weld a batch: points are grouped with every point within `tolerance`,
+    /// transitively, so the groups do not depend on the order of `points`.
+    /// Returns one vertex per group, the lexicographically smallest of its
+    /// points, numbered in order of the group's first point, and the vertex
+    /// index of every input point.
+    pub fn weld(points: &[Vector3d], tolerance: f64) -> (Vec<Vector3d>, Vec<usize>) {
+        let mut grid = Self::new(tolerance);
+        let mut parent: Vec<usize> = (0..points.len()).collect();
+        for (index, point) in points.iter().enumerate() {
+            let near: Vec<usize> = neighbors(grid.cell(point))
+                .filter_map(|cell| grid.cells.get(&cell))
+                .flatten()
+                .copied()
+                .filter(|&other| (points[other].0 - point.0).norm() <= tolerance)
+                .collect();
+            for other in near {
+                let (a, b) = (root(&mut parent, index), root(&mut parent, other));
+                parent[a.max(b)] = a.min(b);
+            }
+            grid.cells.entry(grid.cell(point)).or_default().push(index);
+        }
+
+        let mut vertices: Vec<Vector3d> = Vec::new();
+        let mut group_vertex: HashMap<usize, usize> = HashMap::new();
+        let mut indices = Vec::with_capacity(points.len());
+        for (index, point) in points.iter().enumerate() {
+            let group = root(&mut parent, index);
+            let vertex = *group_vertex.entry(group).or_insert_with(|| {
+                vertices.push(*point);
+                vertices.len() - 1
+            });
+            let smallest = &mut vertices[vertex];
+            if lexicographic(point, smallest) {
+                *smallest = *point;
+            }
+            indices.push(vertex);
+        }
+        (vertices, indices)
+    }
+
+    fn cell(&self, point: &Vector3d) -> [i64; 3] { [0, 1, 2].map(|i| (point.0[i] / self.tolerance).floor() as i64) }
+}
+
+fn neighbors(cell: [i64; 3]) -> impl Iterator<Item = [i64; 3]> {
+    (0..27).map(move |k| [cell[0] + k % 3 - 1, cell[1] + k / 3 % 3 - 1, cell[2] + k / 9 - 1])
+}
+
+fn root(parent: &mut [usize], mut index: usize) -> usize {
+    while parent[index] != index {
+        parent[index] = parent[parent[index]];
+        index = parent[index];
+    }
+    index
+}
+
+fn lexicographic(a: &Vector3d, b: &Vector3d) -> bool {
+    a.0.iter().zip(b.0.iter()).map(|(x, y)| x.total_cmp(y)).find(|order| order.is_ne()) == Some(std::cmp::Ordering::Less)
+}
@@ -0,0 +1,285 @@
+//! Bounding volume hierarchy for proximity queries over many entities, e.g.
+//! coincident nodes or crossing members, without testing every pair.
+
+use std::cmp::Ordering;
+
+use nalgebra::Vector3;
+
+use utils::epsilon;
+
+use crate::{Arc, BoundingBox3d, Line3d, Sphere, Vector3d};
+
+/// Entity with an axis-aligned bounding box.
+pub trait Bounded {
+    /// Axis-aligned bounding box of the entity.
+    fn bounds(&self) -> BoundingBox3d;
+
+    /// Distance from `point` to the entity. Defaults to the distance to the
+    /// bounding box, which is exact for points and boxes only.
+    fn distance(&self, point: &Vector3d) -> f64 { Aabb::of(self).distance_squared(&point.0).sqrt() }
+}
+
+impl Bounded for Vector3d {
+    fn bounds(&self) -> BoundingBox3d { BoundingBox3d::from_point(*self) }
+    fn distance(&self, point: &Vector3d) -> f64 { (self.0 - point.0).norm() }
+}
+
+impl Bounded for Line3d {
+    fn bounds(&self) -> BoundingBox3d { self.bounding_box() }
+
+    fn distance(&self, point: &Vector3d) -> f64 { Line3d::distance(self, point) }
+}
+
+impl Bounded for Arc {
+    fn bounds(&self) -> BoundingBox3d { self.bounding_box() }
+    fn distance(&self, point: &Vector3d) -> f64 { Arc::distance(self, point) }
+}
+
+impl Bounded for Sphere {
+    fn bounds(&self) -> BoundingBox3d {
+        let extent = Vector3::repeat(self.radius());
+        BoundingBox3d::new(Vector3d(self.center().0 - extent), Vector3d(self.center().0 + extent))
+    }
+
+    fn distance(&self, point: &Vector3d) -> f64 { ((point.0 - self.center().0).norm() - self.radius()).max(0.0) }
+}
+
+impl<T: Bounded + ?Sized> Bounded for &T {
+    fn bounds(&self) -> BoundingBox3d { (**self).bounds() }
+    fn distance(&self, point: &Vector3d) -> f64 { (**self).distance(point) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Aabb {
+    min: Vector3<f64>,
+    max: Vector3<f64>,
+}
+
+impl Aabb {
+    fn of<T: Bounded + ?Sized>(item: &T) -> Self {
+        let bounds = item.bounds();
+        Self { min: bounds.min().0, max: bounds.max().0 }
+    }
+
+    fn union(&self, other: &Self) -> Self { Self { min: self.min.inf(&other.min), max: self.max.sup(&other.max) } }
+
+    fn center(&self) -> Vector3<f64> { (self.min + self.max) / 2.0 }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    fn distance_squared(&self, point: &Vector3<f64>) -> f64 {
+        (0..3).map(|i| (self.min[i] - point[i]).max(point[i] - self.max[i]).max(0.0).powi(2)).sum()
+    }
+
+    /// Distance along a unit direction at which the ray enters the box (zero
+    /// when it starts inside), or `None` when it misses.
+    fn ray_entry(&self, origin: &Vector3<f64>, direction: &Vector3<f64>) -> Option<f64> {
+        let (mut near, mut far) = (0.0_f64, f64::INFINITY);
+        for i in 0..3 {
+            if direction[i] == 0.0 {
+                if origin[i] < self.min[i] || origin[i] > self.max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let a = (self.min[i] - origin[i]) / direction[i];
+            let b = (self.max[i] - origin[i]) / direction[i];
+            near = near.max(a.min(b));
+            far = far.min(a.max(b));
+            if near > far {
+                return None;
+            }
+        }
+        Some(near)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BvhNode {
+    bounds: Aabb,
+    /// Child node indices; `None` for a leaf.
+    children: Option<(usize, usize)>,
+    /// Range of `order` covered by the node.
+    start: usize,
+    end: usize,
+}
+
+const LEAF_SIZE: usize = 4;
+
+/// Bounding volume hierarchy over a list of entities, built by median splits
+/// along the widest axis. Queries return indices into the list the tree was
+/// built from.
+#[derive(Debug, Clone)]
+pub struct Bvh<T> {
+    items: Vec<T>,
+    boxes: Vec<Aabb>,
+    order: Vec<usize>,
+    nodes: Vec<BvhNode>,
+}
+
+impl<T: Bounded> Bvh<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        let boxes: Vec<Aabb> = items.iter().map(Aabb::of).collect();
+        let mut bvh = Self { order: (0..items.len()).collect(), items, boxes, nodes: Vec::new() };
+        if !bvh.items.is_empty() {
+            bvh.build(0, bvh.items.len());
+        }
+        bvh
+    }
+
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let bounds = self.order[start..end].iter().map(|&i| self.boxes[i]).reduce(|a, b| a.union(&b)).unwrap();
+        let index = self.nodes.len();
+        self.nodes.push(BvhNode { bounds, children: None, start, end });
+        if end - start <= LEAF_SIZE {
+            return index;
+        }
+
+        let centers = self.order[start..end].iter().map(|&i| self.boxes[i].center());
+        let (low, high) = centers.fold((Vector3::repeat(f64::INFINITY), Vector3::repeat(f64::NEG_INFINITY)), |(lo, hi), c| {
+            (lo.inf(&c), hi.sup(&c))
+        });
+        let axis = (high - low).imax();
+        let middle = (end - start) / 2;
+        let boxes = &self.boxes;
+        self.order[start..end].select_nth_unstable_by(middle, |&a, &b| {
+            boxes[a].center()[axis].total_cmp(&boxes[b].center()[axis])
+        });
+
+        let left = self.build(start, start + middle);
+        let right = self.build(start + middle, end);
+        self.nodes[index].children = Some((left, right));
+        index
+    }
+
+    pub fn len(&self) -> usize { self.items.len() }
+    pub fn is_empty(&self) -> bool { self.items.is_empty() }
+    pub fn items(&self) -> &[T] { &self.items }
+    pub fn get(&self, index: usize) -> &T { &self.items[index] }
+
+    /// Indices of entities whose bounding boxes overlap the box `min`–`max`
+    /// (touching counts), in ascending order.
+    pub fn query_box(&self, min: Vector3d, max: Vector3d) -> Vec<usize> {
+        let query = Aabb { min: min.0.inf(&max.0), max: min.0.sup(&max.0) };
+        let mut found = self.collect(|bounds| bounds.overlaps(&query));
+        found.sort_unstable();
+        found
+    }
+
+    /// Indices of entities within `radius` of `point`, in ascending order.
+    pub fn within(&self, point: &Vector3d, radius: f64) -> Vec<usize> {
+        let mut found = self.collect(|bounds| bounds.distance_squared(&point.0) <= radius * radius);
+        found.retain(|&i| self.items[i].distance(point) <= radius);
+        found.sort_unstable();
+        found
+    }
+
+    /// Nearest entity to `point` and its distance; ties go to the lower index.
+    pub fn nearest(&self, point: &Vector3d) -> Option<(usize, f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        let mut stack = if self.nodes.is_empty() { vec![] } else { vec![0] };
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if best.is_some_and(|(_, d)| node.bounds.distance_squared(&point.0) > d * d) {
+                continue;
+            }
+            match node.children {
+                Some((left, right)) => {
+                    // Visit the nearer child first so it tightens the bound.
+                    let dl = self.nodes[left].bounds.distance_squared(&point.0);
+                    let dr = self.nodes[right].bounds.distance_squared(&point.0);
+                    if dl <= dr {
+                        stack.extend([right, left]);
+                    } else {
+                        stack.extend([left, right]);
+                    }
+                }
+                None => {
+                    for &i in &self.order[node.start..node.end] {
+                        let distance = self.items[i].distance(point);
+                        let closer = match best {
+                            None => true,
+                            Some((j, d)) => match distance.total_cmp(&d) {
+                                Ordering::Less => true,
+                                Ordering::Equal => i < j,
+                                Ordering::Greater => false,
+                            },
+                        };
+                        if closer {
+                            best = Some((i, distance));
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    /// Entities whose bounding boxes are hit by the ray from `origin` along
+    /// `direction`, with the distance at which the ray enters each box, sorted
+    /// by that distance. These are candidates for an exact intersection test.
+    /// Returns nothing for a zero direction.
+    pub fn query_ray(&self, origin: &Vector3d, direction: &Vector3d) -> Vec<(usize, f64)> {
+        let Some(direction) = direction.0.try_normalize(epsilon()) else {
+            return Vec::new();
+        };
+        let mut hits: Vec<(usize, f64)> = self
+            .collect(|bounds| bounds.ray_entry(&origin.0, &direction).is_some())
+            .into_iter()
+            .filter_map(|i| self.boxes[i].ray_entry(&origin.0, &direction).map(|t| (i, t)))
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        hits
+    }
+
+    /// Pairs `(i, j)` with `i < j` whose bounding boxes overlap, sorted. Broad
+    /// phase for intersection or clash detection.
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (i, bounds) in self.boxes.iter().enumerate() {
+            let mut others = self.collect(|node| node.overlaps(bounds));
+            others.retain(|&j| j > i);
+            others.sort_unstable();
+            pairs.extend(others.into_iter().map(|j| (i, j)));
+        }
+        pairs
+    }
+
+    /// Items in leaves reached through nodes accepted by `visit`, with the
+    /// item boxes checked by the same predicate.
+    fn collect<F: Fn(&Aabb) -> bool>(&self, visit: F) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack = if self.nodes.is_empty() { vec![] } else { vec![0] };
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !visit(&node.bounds) {
+                continue;
+            }
+            match node.children {
+                Some((left, right)) => stack.extend([left, right]),
+                None => found.extend(self.order[node.start..node.end].iter().copied().filter(|&i| visit(&self.boxes[i]))),
+            }
+        }
+        found
+    }
+}
+
+impl<T: Bounded> FromIterator<T> for Bvh<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self { Self::new(iter.into_iter().collect()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_entry_of_box() {
+        let bounds = Aabb { min: Vector3::new(1.0, -1.0, -1.0), max: Vector3::new(2.0, 1.0, 1.0) };
+        assert_eq!(bounds.ray_entry(&Vector3::zeros(), &Vector3::x()), Some(1.0));
+        assert_eq!(bounds.ray_entry(&Vector3::zeros(), &-Vector3::x()), None);
+        assert_eq!(bounds.ray_entry(&Vector3::new(1.5, 0.0, 0.0), &Vector3::y()), Some(0.0));
+        assert_eq!(bounds.ray_entry(&Vector3::new(0.0, 2.0, 0.0), &Vector3::x()), None);
+    }
+}
@@ -0,0 +1,181 @@
+use nalgebra::{Matrix3, Vector3};
+use utils::epsilon;
+
+use crate::{Line3d, Vector3d};
+
+/// Sphere given by center and radius, used as a cheap bounding volume.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    center: Vector3d,
+    radius: f64,
+}
+
+impl Sphere {
+    /// Panics on a negative radius.
+    pub fn new<C: Into<Vector3d>>(center: C, radius: f64) -> Self {
+        assert!(radius >= 0.0, "sphere radius must not be negative");
+        Self { center: center.into(), radius }
+    }
+
+    pub fn center(&self) -> Vector3d { self.center }
+    pub fn radius(&self) -> f64 { self.radius }
+
+    /// Whether `point` lies inside or on the sphere, with a relative tolerance.
+    pub fn contains(&self, point: &Vector3d) -> bool {
+        (point.0 - self.center.0).norm() <= self.radius + tolerance(self.radius)
+    }
+
+    pub fn intersects(&self, other: &Sphere) -> bool {
+        let reach = self.radius + other.radius;
+        (other.center.0 - self.center.0).norm() <= reach + tolerance(reach)
+    }
+
+    /// Point of the surface nearest to `point`; any surface point for the center.
+    pub fn closest_point(&self, point: &Vector3d) -> Vector3d {
+        let direction = (point.0 - self.center.0).try_normalize(epsilon()).unwrap_or_else(Vector3::x);
+        Vector3d(self.center.0 + direction * self.radius)
+    }
+
+    /// Distance from `point` to the surface, negative inside.
+    pub fn signed_distance(&self, point: &Vector3d) -> f64 { (point.0 - self.center.0).norm() - self.radius }
+
+    /// Points where the segment `line` pierces or touches the surface, ordered
+    /// from its start.
+    pub fn intersection_with_line(&self, line: &Line3d) -> Vec<Vector3d> {
+        self.line_parameters(line)
+            .into_iter()
+            .filter(|t| (-epsilon()..=1.0 + epsilon()).contains(t))
+            .map(|t| line.point_at(t))
+            .collect()
+    }
+
+    /// Parameters along `line` (0 at the start, 1 at the end) where the infinite
+    /// line through it meets the surface, in increasing order; one for a tangent.
+    pub(crate) fn line_parameters(&self, line: &Line3d) -> Vec<f64> {
+        let (offset, direction) = (line.start().0 - self.center.0, line.end().0 - line.start().0);
+        let a = direction.norm_squared();
+        if a <= epsilon() * epsilon() {
+            return Vec::new();
+        }
+        let b = offset.dot(&direction);
+        let discriminant = b * b - a * (offset.norm_squared() - self.radius * self.radius);
+        let tangent = epsilon() * a * self.radius.max(1.0);
+        if discriminant < -tangent {
+            Vec::new()
+        } else if discriminant <= tangent {
+            vec![-b / a]
+        } else {
+            let root = discriminant.sqrt();
+            vec![(-b - root) / a, (-b + root) / a]
+        }
+    }
+
+    /// Smallest sphere enclosing both spheres.
+    pub fn merged(&self, other: &Sphere) -> Sphere {
+        let offset = other.center.0 - self.center.0;
+        let distance = offset.norm();
+        if distance + other.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (distance + self.radius + other.radius) / 2.0;
+        let center = self.center.0 + offset * ((radius - self.radius) / distance);
+        Sphere { center: Vector3d(center), radius }
+    }
+
+    fn around(center: Vector3<f64>, point: &Vector3<f64>) -> Self {
+        Self { center: Vector3d(center), radius: (point - center).norm() }
+    }
+}
+
+fn tolerance(radius: f64) -> f64 { epsilon().max(1e-12 * radius) }
+
+/// Smallest sphere enclosing a point set (Welzl's algorithm, in its iterative
+/// move-to-front form over a fixed pseudo-random order). Returns `None` for an
+/// empty set.
+pub fn bounding_sphere(points: &[Vector3d]) -> Option<Sphere> {
+    let first = points.first()?;
+    // Shuffle deterministically so sorted input (e.g. nodes along a member) does
+    // not hit the slow worst case.
+    let mut order: Vec<Vector3<f64>> = points.iter().map(|p| p.0).collect();
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for i in (1..order.len()).rev() {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        order.swap(i, (state >> 33) as usize % (i + 1));
+    }
+
+    let mut sphere = Sphere::around(first.0, &first.0);
+    for i in 0..order.len() {
+        if sphere.contains(&Vector3d(order[i])) {
+            continue;
+        }
+        sphere = Sphere::around(order[i], &order[i]);
+        for j in 0..i {
+            if sphere.contains(&Vector3d(order[j])) {
+                continue;
+            }
+            sphere = through_two(&order[i], &order[j]);
+            for k in 0..j {
+                if sphere.contains(&Vector3d(order[k])) {
+                    continue;
+                }
+                sphere = through_three(&order[i], &order[j], &order[k]);
+                for l in 0..k {
+                    if !sphere.contains(&Vector3d(order[l])) {
+                        sphere = through_four(&order[i], &order[j], &order[k], &order[l]);
+                    }
+                }
+            }
+        }
+    }
+    Some(sphere)
+}
+
+fn through_two(a: &Vector3<f64>, b: &Vector3<f64>) -> Sphere {
+    Sphere::around((a + b) / 2.0, a)
+}
+
+/// Smallest sphere with three points on its surface: the circumcircle of the
+/// triangle, or the widest pair for collinear points.
+fn through_three(a: &Vector3<f64>, b: &Vector3<f64>, c: &Vector3<f64>) -> Sphere {
+    let (ab, ac) = (b - a, c - a);
+    let normal = ab.cross(&ac);
+    let denominator = 2.0 * normal.norm_squared();
+    if denominator <= epsilon() * ab.norm_squared().max(ac.norm_squared()).powi(2) {
+        return widest([through_two(a, b), through_two(a, c), through_two(b, c)]);
+    }
+    let offset = (normal.cross(&ab) * ac.norm_squared() + ac.cross(&normal) * ab.norm_squared()) / denominator;
+    Sphere::around(a + offset, a)
+}
+
+/// Sphere through four points, or the best three-point sphere when they are
+/// coplanar.
+fn through_four(a: &Vector3<f64>, b: &Vector3<f64>, c: &Vector3<f64>, d: &Vector3<f64>) -> Sphere {
+    let rows = Matrix3::from_rows(&[(b - a).transpose(), (c - a).transpose(), (d - a).transpose()]);
+    let scale = rows.row_iter().map(|row| row.norm()).fold(0.0, f64::max);
+    let rhs = Vector3::new((b - a).norm_squared(), (c - a).norm_squared(), (d - a).norm_squared()) / 2.0;
+    match rows.lu().solve(&rhs) {
+        Some(offset) if rows.determinant().abs() > epsilon() * scale.powi(3) => Sphere::around(a + offset, a),
+        _ => widest([through_three(a, b, c), through_three(a, b, d), through_three(a, c, d), through_three(b, c, d)]),
+    }
+}
+
+fn widest<const N: usize>(spheres: [Sphere; N]) -> Sphere {
+    spheres.into_iter().max_by(|x, y| x.radius.total_cmp(&y.radius)).expect("at least one sphere")
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+    use super::*;
+
+    #[test]
+    fn circumcircle_of_right_triangle_uses_hypotenuse() {
+        let sphere = through_three(&Vector3::new(0.0, 0.0, 0.0), &Vector3::new(4.0, 0.0, 0.0), &Vector3::new(0.0, 3.0, 0.0));
+        assert_almost_eq!(sphere.radius(), 2.5);
+        assert_vec3_almost_eq!(sphere.center(), Vector3d::new(2.0, 1.5, 0.0));
+    }
+}
@@ -0,0 +1,178 @@
+//! St. Venant torsion constant of a polygonal section from the Prandtl stress
+//! function: `∇²φ = −2` over the section, `φ = 0` on the outer boundary and an
+//! unknown constant on every hole boundary, with `J = 2∫φ dA + 2Σ φₖ Aₖ`. The
+//! problem is solved with linear triangles on two meshes and the results
+//! extrapolated to zero element size.
+
+use std::collections::BTreeMap;
+
+use nalgebra::Vector2;
+
+use crate::mesh::{triangulate, MeshOptions};
+use crate::Polygon;
+
+/// Slenderness `P² / 4A` beyond which the section is treated as thin-walled;
+/// about the ratio of wall length to wall thickness.
+const THIN_WALLED: f64 = 200.0;
+
+/// Elements across the characteristic wall thickness `2A / P` on the coarse mesh.
+pub(crate) const ELEMENTS_ACROSS: f64 = 2.0;
+
+impl Polygon {
+    /// St. Venant torsion constant `J` of the polygon as a solid section, holes
+    /// included. Thin-walled sections (walls about 200 times longer than thick)
+    /// without holes use `J = L t³ / 3` and with a single hole Bredt's formula
+    /// `J = 4 Aₘ² t / sₘ`, taking the mid-line from the mean of the rings; all
+    /// other sections are solved numerically, to within about a percent.
+    pub fn torsion_constant(&self) -> f64 {
+        let (area, perimeter) = (self.area(), self.perimeter());
+        if area <= utils::epsilon() {
+            return 0.0;
+        }
+        let thickness = 2.0 * area / perimeter;
+        if perimeter * perimeter / (4.0 * area) > THIN_WALLED {
+            match self.holes() {
+                [] => return perimeter / 2.0 * thickness.powi(3) / 3.0,
+                [hole] => {
+                    // Mid-line halfway between the outer ring and the hole.
+                    let enclosed = area / 2.0 + ring_area(&self.local_ring(hole));
+                    let midline = perimeter / 2.0;
+                    return 4.0 * enclosed * enclosed * (area / midline) / midline;
+                }
+                _ => {}
+            }
+        }
+        let coarse = self.prandtl(thickness / ELEMENTS_ACROSS);
+        let fine = self.prandtl(thickness / (2.0 * ELEMENTS_ACROSS));
+        // Linear elements converge with h², so halving h quarters the error.
+        (4.0 * fine - coarse) / 3.0
+    }
+
+    pub(crate) fn local_ring(&self, ring: &[crate::Vector3d]) -> Vec<Vector2<f64>> {
+        ring.iter()
+            .map(|point| {
+                let local = self.to_local(*point);
+                Vector2::new(local.x(), local.y())
+            })
+            .collect()
+    }
+
+    /// Torsion constant from the stress function on a mesh of element size `h`.
+    fn prandtl(&self, h: f64) -> f64 {
+        let mesh = triangulate(self, &MeshOptions::new().with_max_edge_length(h));
+        let points: Vec<Vector2<f64>> = self.local_ring(mesh.vertices());
+        let tolerance = 1e-9 * h;
+
+        // Outer boundary nodes are fixed at zero; the nodes of each hole share
+        // one unknown, which enforces the single-valued warping around it.
+        let holes: Vec<Vec<Vector2<f64>>> = self.holes().iter().map(|hole| self.local_ring(hole)).collect();
+        let outer = self.local_ring(self.vertices());
+        let mut dof: Vec<Option<usize>> = Vec::with_capacity(points.len());
+        let mut count = holes.len();
+        for point in &points {
+            if on_ring(&outer, point, tolerance) {
+                dof.push(None);
+            } else if let Some(hole) = holes.iter().position(|ring| on_ring(ring, point, tolerance)) {
+                dof.push(Some(hole));
+            } else {
+                dof.push(Some(count));
+                count += 1;
+            }
+        }
+
+        let stiffness = laplacian(&points, mesh.triangles(), &dof, count);
+        let mut load = vec![0.0; count];
+        for (hole, ring) in holes.iter().enumerate() {
+            load[hole] += 2.0 * ring_area(ring);
+        }
+        for triangle in mesh.triangles() {
+            let area = triangle_area(triangle.map(|v| points[v]));
+            for &vertex in triangle {
+                if let Some(row) = dof[vertex] {
+                    load[row] += 2.0 * area / 3.0;
+                }
+            }
+        }
+        let phi = conjugate_gradient(&stiffness, &load);
+        load.iter().zip(&phi).map(|(f, x)| f * x).sum()
+    }
+}
+
+/// Stiffness `∫ ∇Nᵢ·∇Nⱼ dA` of linear triangles, by rows of unknowns; `dof`
+/// maps every mesh vertex to its unknown, `None` for vertices held at zero.
+pub(crate) fn laplacian(
+    points: &[Vector2<f64>],
+    triangles: &[[usize; 3]],
+    dof: &[Option<usize>],
+    count: usize,
+) -> Vec<BTreeMap<usize, f64>> {
+    let mut stiffness: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); count];
+    for triangle in triangles {
+        let gradients = shape_gradients(triangle.map(|v| points[v]));
+        let area = triangle_area(triangle.map(|v| points[v]));
+        for i in 0..3 {
+            let Some(row) = dof[triangle[i]] else { continue };
+            for j in 0..3 {
+                let Some(column) = dof[triangle[j]] else { continue };
+                *stiffness[row].entry(column).or_insert(0.0) += gradients[i].dot(&gradients[j]) * area;
+            }
+        }
+    }
+    stiffness
+}
+
+/// Gradients of the three linear shape functions of a triangle.
+pub(crate) fn shape_gradients([a, b, c]: [Vector2<f64>; 3]) -> [Vector2<f64>; 3] {
+    let double_area = (b - a).perp(&(c - a));
+    [c - b, a - c, b - a].map(|edge| Vector2::new(-edge.y, edge.x) / double_area)
+}
+
+pub(crate) fn triangle_area([a, b, c]: [Vector2<f64>; 3]) -> f64 { (b - a).perp(&(c - a)).abs() / 2.0 }
+
+/// Unsigned area of a closed ring.
+fn ring_area(ring: &[Vector2<f64>]) -> f64 {
+    (0..ring.len()).map(|i| ring[i].perp(&ring[(i + 1) % ring.len()])).sum::<f64>().abs() / 2.0
+}
+
+fn on_ring(ring: &[Vector2<f64>], point: &Vector2<f64>, tolerance: f64) -> bool {
+    (0..ring.len()).any(|i| {
+        let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+        let edge = b - a;
+        let t = ((point - a).dot(&edge) / edge.norm_squared()).clamp(0.0, 1.0);
+        (a + edge * t - point).norm() <= tolerance
+    })
+}
+
+/// Jacobi-preconditioned conjugate gradients for a symmetric positive
+/// definite system stored by rows.
+pub(crate) fn conjugate_gradient(matrix: &[BTreeMap<usize, f64>], rhs: &[f64]) -> Vec<f64> {
+    let rows: Vec<Vec<(usize, f64)>> = matrix.iter().map(|row| row.iter().map(|(&j, &value)| (j, value)).collect()).collect();
+    let multiply = |x: &[f64]| -> Vec<f64> { rows.iter().map(|row| row.iter().map(|&(j, value)| value * x[j]).sum()).collect() };
+    let dot = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b).map(|(x, y)| x * y).sum() };
+    let inverse_diagonal: Vec<f64> = matrix.iter().enumerate().map(|(i, row)| 1.0 / row[&i]).collect();
+
+    let mut x = vec![0.0; rhs.len()];
+    let mut residual = rhs.to_vec();
+    let mut z: Vec<f64> = residual.iter().zip(&inverse_diagonal).map(|(r, d)| r * d).collect();
+    let mut direction = z.clone();
+    let mut rz = dot(&residual, &z);
+    let limit = 1e-24 * dot(rhs, rhs);
+    for _ in 0..10 * rhs.len().max(1) {
+        if dot(&residual, &residual) <= limit {
+            break;
+        }
+        let product = multiply(&direction);
+        let step = rz / dot(&direction, &product);
+        for i in 0..x.len() {
+            x[i] += step * direction[i];
+            residual[i] -= step * product[i];
+        }
+        z = residual.iter().zip(&inverse_diagonal).map(|(r, d)| r * d).collect();
+        let next = dot(&residual, &z);
+        for i in 0..direction.len() {
+            direction[i] = z[i] + next / rz * direction[i];
+        }
+        rz = next;
+    }
+    x
+}
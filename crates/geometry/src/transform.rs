@@ -0,0 +1,176 @@
+use std::ops::Mul;
+
+use nalgebra::{Matrix3, Matrix4, Rotation3, Unit, Vector3};
+use utils::epsilon;
+
+use crate::{Axis, LocalAxis, Plane, Vector3d};
+
+/// Affine transform `x ↦ L x + t` restricted to similarity transforms: rotation,
+/// translation, uniform scaling and mirroring, and compositions of them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3d {
+    linear: Matrix3<f64>,
+    translation: Vector3<f64>,
+}
+
+impl Default for Transform3d {
+    fn default() -> Self { Self::identity() }
+}
+
+impl Transform3d {
+    pub fn identity() -> Self {
+        Self { linear: Matrix3::identity(), translation: Vector3::zeros() }
+    }
+
+    pub fn translation<T: Into<Vector3d>>(offset: T) -> Self {
+        Self { linear: Matrix3::identity(), translation: offset.into().0 }
+    }
+
+    /// Rotation by `angle` (radians, right-hand rule) about `axis` through the
+    /// origin. Panics on a zero axis.
+    pub fn rotation<A: Into<Vector3d>>(angle: f64, axis: A) -> Self {
+        let axis = Unit::try_new(axis.into().0, epsilon()).expect("rotation axis must be non-zero");
+        Self { linear: Rotation3::from_axis_angle(&axis, angle).into_inner(), translation: Vector3::zeros() }
+    }
+
+    /// Rotation about an axis through `point`.
+    pub fn rotation_about<A: Into<Vector3d>, P: Into<Vector3d>>(angle: f64, axis: A, point: P) -> Self {
+        Self::about(Self::rotation(angle, axis), point.into())
+    }
+
+    /// Uniform scaling about the origin. Panics unless `factor` is positive.
+    pub fn scaling(factor: f64) -> Self {
+        assert!(factor > epsilon(), "scale factor must be positive");
+        Self { linear: Matrix3::identity() * factor, translation: Vector3::zeros() }
+    }
+
+    pub fn scaling_about<P: Into<Vector3d>>(factor: f64, point: P) -> Self {
+        Self::about(Self::scaling(factor), point.into())
+    }
+
+    /// Reflection in a plane.
+    pub fn mirror(plane: &Plane) -> Self {
+        let n = plane.normal().0;
+        let linear = Matrix3::identity() - n * n.transpose() * 2.0;
+        Self::about(Self { linear, translation: Vector3::zeros() }, plane.origin())
+    }
+
+    /// Map from the coordinates of a local frame to global coordinates.
+    pub fn from_local_axis(axis: &LocalAxis) -> Self {
+        let linear = Matrix3::from_columns(&[
+            axis.direction(Axis::AxisX).0,
+            axis.direction(Axis::AxisY).0,
+            axis.direction(Axis::AxisZ).0,
+        ]);
+        Self { linear, translation: axis.origin().0 }
+    }
+
+    fn about(transform: Self, point: Vector3d) -> Self {
+        Self::translation(Vector3d(-point.0)).then(&transform).then(&Self::translation(point))
+    }
+
+    pub fn linear(&self) -> Matrix3<f64> { self.linear }
+    pub fn translation_vector(&self) -> Vector3d { Vector3d(self.translation) }
+
+    /// Homogeneous 4x4 matrix.
+    pub fn matrix(&self) -> Matrix4<f64> {
+        let mut matrix = self.linear.to_homogeneous();
+        matrix.fixed_view_mut::<3, 1>(0, 3).copy_from(&self.translation);
+        matrix
+    }
+
+    /// Uniform scale factor.
+    pub fn scale(&self) -> f64 { self.linear.determinant().abs().cbrt() }
+
+    /// Whether the transform reverses orientation (an odd number of mirrors).
+    pub fn is_mirroring(&self) -> bool { self.linear.determinant() < 0.0 }
+
+    /// Pure rotation part, with scaling and mirroring removed.
+    pub fn rotation_part(&self) -> Matrix3<f64> {
+        let sign = if self.is_mirroring() { -1.0 } else { 1.0 };
+        self.linear * (sign / self.scale())
+    }
+
+    /// Orthonormal frame (axes as columns) carried along: turned by a rotation;
+    /// under mirroring reflected, so the x-axis follows the mirrored geometry,
+    /// with the y-axis flipped back to keep the frame right-handed.
+    pub fn apply_frame(&self, frame: &Matrix3<f64>) -> Matrix3<f64> {
+        let mut carried = self.linear * frame / self.scale();
+        if self.is_mirroring() {
+            carried.column_mut(1).neg_mut();
+        }
+        carried
+    }
+
+    /// `self` followed by `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        Self { linear: next.linear * self.linear, translation: next.linear * self.translation + next.translation }
+    }
+
+    /// Inverse transform; `None` if the transform is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let linear = self.linear.try_inverse()?;
+        Some(Self { linear, translation: -(linear * self.translation) })
+    }
+
+    pub fn apply_point(&self, point: &Vector3d) -> Vector3d {
+        Vector3d(self.linear * point.0 + self.translation)
+    }
+
+    /// Apply to a direction or offset (translation ignored).
+    pub fn apply_vector(&self, vector: &Vector3d) -> Vector3d {
+        Vector3d(self.linear * vector.0)
+    }
+}
+
+/// `a * b` applies `b` first, like matrix products.
+impl Mul for Transform3d {
+    type Output = Transform3d;
+
+    fn mul(self, rhs: Transform3d) -> Transform3d { rhs.then(&self) }
+}
+
+/// Geometry that can be moved, rotated, scaled or mirrored by a [`Transform3d`].
+pub trait Transformable {
+    fn transform(&mut self, transform: &Transform3d);
+
+    fn transformed(&self, transform: &Transform3d) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        let mut clone = self.clone();
+        clone.transform(transform);
+        clone
+    }
+
+    /// Reflect in `plane`. Local frames stay right-handed: implementors carry
+    /// them with [`Transform3d::apply_frame`] instead of reflecting them.
+    fn mirror_about_plane(&mut self, plane: &Plane) {
+        self.transform(&Transform3d::mirror(plane));
+    }
+}
+
+impl Transformable for Vector3d {
+    fn transform(&mut self, transform: &Transform3d) {
+        *self = transform.apply_point(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+    use super::*;
+
+    #[test]
+    fn composition_and_inverse() {
+        let a = Transform3d::rotation_about(0.3, (0.0, 0.0, 1.0), (1.0, 2.0, 0.0));
+        let b = Transform3d::scaling(2.0).then(&Transform3d::translation((0.0, 0.0, 5.0)));
+        let combined = a.then(&b);
+        let p = Vector3d::new(0.5, -1.0, 2.0);
+        assert_vec3_almost_eq!(combined.apply_point(&p), b.apply_point(&a.apply_point(&p)));
+        assert_vec3_almost_eq!((b * a).apply_point(&p), combined.apply_point(&p));
+        assert_vec3_almost_eq!(combined.inverse().unwrap().apply_point(&combined.apply_point(&p)), p);
+        assert_almost_eq!(combined.scale(), 2.0);
+    }
+}
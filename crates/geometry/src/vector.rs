@@ -1,25 +1,52 @@
-use nalgebra::{Vector2, Vector3};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use nalgebra::{RealField, Rotation3, Unit, Vector2, Vector3};
 
 use utils::epsilon;
 
-/// Simple 2D vector type backed by `nalgebra::Vector2<f64>`.
+/// Floating-point type of the geometry: `f64` (the default everywhere) for
+/// analysis, `f32` e.g. for visualization-scale models where memory matters
+/// more than precision. Vectors, lines, arcs and polygons compute in the
+/// precision of their points.
+pub trait Scalar: RealField + Copy {
+    /// Default tolerance of comparisons at unit scale: [`epsilon`] for `f64`,
+    /// `1e-5` for `f32`.
+    fn epsilon() -> Self;
+
+    /// `tolerance` in this precision, [`Scalar::epsilon`] when `None`.
+    fn tolerance(tolerance: Option<f64>) -> Self { tolerance.map_or_else(Self::epsilon, Self::from_f64_lossy) }
+
+    fn from_f64_lossy(value: f64) -> Self { Self::from_subset(&value) }
+    fn as_f64(self) -> f64 { self.to_subset_unchecked() }
+}
+
+impl Scalar for f32 {
+    fn epsilon() -> Self { 1e-5 }
+}
+
+impl Scalar for f64 {
+    fn epsilon() -> Self { epsilon() }
+}
+
+/// Simple 2D vector type backed by `nalgebra::Vector2`, `f64` unless stated.
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Vector2d(pub Vector2<f64>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2d<T: Scalar = f64>(pub Vector2<T>);
 
-impl Vector2d {
-    pub fn new(x: f64, y: f64) -> Self {
+impl<T: Scalar> Vector2d<T> {
+    pub fn new(x: T, y: T) -> Self {
         Self(Vector2::new(x, y))
     }
 
-    pub fn x(&self) -> f64 { self.0.x }
-    pub fn y(&self) -> f64 { self.0.y }
-    pub fn z(&self) -> f64 { 0.0 }
+    pub fn x(&self) -> T { self.0.x }
+    pub fn y(&self) -> T { self.0.y }
+    pub fn z(&self) -> T { T::zero() }
 
-    pub fn dot(&self, other: &Self) -> f64 {
+    pub fn dot(&self, other: &Self) -> T {
         self.0.dot(&other.0)
     }
 
-    pub fn norm(&self) -> f64 {
+    pub fn norm(&self) -> T {
         self.0.norm()
     }
 
@@ -27,30 +54,49 @@ impl Vector2d {
         Self(self.0.normalize())
     }
 
+    /// The vector turned a quarter counterclockwise, `(-y, x)`.
+    pub fn perp(&self) -> Self {
+        Self::new(-self.0.y, self.0.x)
+    }
+
+    /// Scalar cross product `x₁y₂ − y₁x₂`: twice the signed area of the
+    /// triangle spanned, positive when `other` lies counterclockwise.
+    pub fn cross(&self, other: &Self) -> T {
+        self.0.perp(&other.0)
+    }
+
+    /// Direction angle from the X axis in `(-π, π]`.
+    pub fn angle(&self) -> T {
+        self.0.y.atan2(self.0.x)
+    }
+
     pub fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool {
-        let eps = precision.unwrap_or_else(epsilon);
-        (self.0 - other.0).norm() <= eps
+        (self.0 - other.0).norm() <= T::tolerance(precision)
     }
+
+    /// The vector in another precision, rounded when narrowing.
+    pub fn cast<U: Scalar>(&self) -> Vector2d<U> { Vector2d(self.0.map(|v| U::from_f64_lossy(v.as_f64()))) }
 }
 
-/// Simple 3D vector type backed by `nalgebra::Vector3<f64>`.
+/// Simple 3D vector type backed by `nalgebra::Vector3`, `f64` unless stated.
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Vector3d(pub Vector3<f64>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3d<T: Scalar = f64>(pub Vector3<T>);
 
-impl Vector3d {
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+impl<T: Scalar> Vector3d<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
         Self(Vector3::new(x, y, z))
     }
 
-    pub fn x(&self) -> f64 { self.0.x }
-    pub fn y(&self) -> f64 { self.0.y }
-    pub fn z(&self) -> f64 { self.0.z }
+    pub fn x(&self) -> T { self.0.x }
+    pub fn y(&self) -> T { self.0.y }
+    pub fn z(&self) -> T { self.0.z }
 
-    pub fn dot(&self, other: &Self) -> f64 {
+    pub fn dot(&self, other: &Self) -> T {
         self.0.dot(&other.0)
     }
 
-    pub fn norm(&self) -> f64 {
+    pub fn norm(&self) -> T {
         self.0.norm()
     }
 
@@ -62,26 +108,143 @@ impl Vector3d {
         Self(self.0.cross(&other.0))
     }
 
+    /// Unsigned angle to `other` in `[0, π]`, accurate also for nearly
+    /// parallel vectors.
+    pub fn angle_to(&self, other: &Self) -> T {
+        self.0.cross(&other.0).norm().atan2(self.0.dot(&other.0))
+    }
+
+    /// Component along `other`. Panics if `other` is zero.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let length_sq = other.0.norm_squared();
+        assert!(length_sq > T::epsilon() * T::epsilon(), "cannot project onto a zero vector");
+        Self(other.0 * (self.0.dot(&other.0) / length_sq))
+    }
+
+    /// Component perpendicular to `other`. Panics if `other` is zero.
+    pub fn reject_from(&self, other: &Self) -> Self {
+        Self(self.0 - self.project_onto(other).0)
+    }
+
+    /// Rotated by `angle` (radians, right-hand rule) about the direction `axis`
+    /// through the origin. Panics if `axis` is zero.
+    pub fn rotate_about_axis(&self, axis: &Self, angle: T) -> Self {
+        let axis = Unit::try_new(axis.0, T::epsilon()).expect("rotation axis must not be zero");
+        Self(Rotation3::from_axis_angle(&axis, angle) * self.0)
+    }
+
     pub fn is_approx(&self, other: &Self, precision: Option<f64>) -> bool {
-        let eps = precision.unwrap_or_else(epsilon);
-        (self.0 - other.0).norm() <= eps
+        (self.0 - other.0).norm() <= T::tolerance(precision)
     }
+
+    /// The vector in another precision, rounded when narrowing.
+    pub fn cast<U: Scalar>(&self) -> Vector3d<U> { Vector3d(self.0.map(|v| U::from_f64_lossy(v.as_f64()))) }
 }
 
-impl From<Vector2d> for Vector3d {
-    fn from(v: Vector2d) -> Self {
-        Vector3d::new(v.x(), v.y(), 0.0)
+/// Componentwise arithmetic, interpolation and indexing, forwarding to the
+/// nalgebra vector.
+macro_rules! impl_vector_ops {
+    ($vector:ident) => {
+        impl<T: Scalar> $vector<T> {
+            /// Linear interpolation: `self` at `t = 0`, `other` at `t = 1`,
+            /// extrapolating outside.
+            pub fn lerp(&self, other: &Self, t: T) -> Self { Self(self.0.lerp(&other.0, t)) }
+
+            pub fn midpoint(&self, other: &Self) -> Self { self.lerp(other, T::from_f64_lossy(0.5)) }
+        }
+
+        impl<T: Scalar> Add for $vector<T> {
+            type Output = Self;
+            fn add(self, other: Self) -> Self { Self(self.0 + other.0) }
+        }
+
+        impl<T: Scalar> Sub for $vector<T> {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self { Self(self.0 - other.0) }
+        }
+
+        impl<T: Scalar> Neg for $vector<T> {
+            type Output = Self;
+            fn neg(self) -> Self { Self(-self.0) }
+        }
+
+        impl<T: Scalar> Mul<T> for $vector<T> {
+            type Output = Self;
+            fn mul(self, factor: T) -> Self { Self(self.0 * factor) }
+        }
+
+        impl Mul<$vector<f64>> for f64 {
+            type Output = $vector<f64>;
+            fn mul(self, vector: $vector<f64>) -> $vector<f64> { vector * self }
+        }
+
+        impl Mul<$vector<f32>> for f32 {
+            type Output = $vector<f32>;
+            fn mul(self, vector: $vector<f32>) -> $vector<f32> { vector * self }
+        }
+
+        impl<T: Scalar> Div<T> for $vector<T> {
+            type Output = Self;
+            fn div(self, divisor: T) -> Self { Self(self.0 / divisor) }
+        }
+
+        impl<T: Scalar> AddAssign for $vector<T> {
+            fn add_assign(&mut self, other: Self) { self.0 += other.0; }
+        }
+
+        impl<T: Scalar> SubAssign for $vector<T> {
+            fn sub_assign(&mut self, other: Self) { self.0 -= other.0; }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T: Scalar> approx::AbsDiffEq for $vector<T> {
+            type Epsilon = T;
+            fn default_epsilon() -> T { T::default_epsilon() }
+            fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool { self.0.abs_diff_eq(&other.0, epsilon) }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T: Scalar> approx::RelativeEq for $vector<T> {
+            fn default_max_relative() -> T { T::default_max_relative() }
+            fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+                self.0.relative_eq(&other.0, epsilon, max_relative)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T: Scalar> approx::UlpsEq for $vector<T> {
+            fn default_max_ulps() -> u32 { T::default_max_ulps() }
+            fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool { self.0.ulps_eq(&other.0, epsilon, max_ulps) }
+        }
+
+        impl<T: Scalar> Index<usize> for $vector<T> {
+            type Output = T;
+            fn index(&self, index: usize) -> &T { &self.0[index] }
+        }
+
+        impl<T: Scalar> IndexMut<usize> for $vector<T> {
+            fn index_mut(&mut self, index: usize) -> &mut T { &mut self.0[index] }
+        }
+    };
+}
+
+impl_vector_ops!(Vector2d);
+impl_vector_ops!(Vector3d);
+
+impl<T: Scalar> From<Vector2d<T>> for Vector3d<T> {
+    fn from(v: Vector2d<T>) -> Self {
+        Vector3d::new(v.x(), v.y(), T::zero())
     }
 }
 
-impl From<[f64; 3]> for Vector3d {
-    fn from(values: [f64; 3]) -> Self {
+impl<T: Scalar> From<[T; 3]> for Vector3d<T> {
+    fn from(values: [T; 3]) -> Self {
         Vector3d::new(values[0], values[1], values[2])
     }
 }
 
-impl From<(f64, f64, f64)> for Vector3d {
-    fn from(values: (f64, f64, f64)) -> Self {
+impl<T: Scalar> From<(T, T, T)> for Vector3d<T> {
+    fn from(values: (T, T, T)) -> Self {
         Vector3d::new(values.0, values.1, values.2)
     }
 }
@@ -98,6 +261,19 @@ mod tests {
         assert_almost_eq!(a.dot(&b), -26.0);
     }
 
+    #[test]
+    fn vector2d_perp_cross_and_angle() {
+        let a = Vector2d::new(2.0, 1.0);
+        let b = Vector2d::new(-1.0, 3.0);
+        assert_eq!(a.perp(), Vector2d::new(-1.0, 2.0));
+        assert_almost_eq!(a.perp().dot(&a), 0.0);
+        assert_almost_eq!(a.cross(&b), 7.0);
+        assert_almost_eq!(b.cross(&a), -7.0);
+        assert_almost_eq!(a.cross(&a.perp()), a.dot(&a));
+        assert_almost_eq!(Vector2d::new(0.0, 2.0).angle(), std::f64::consts::FRAC_PI_2);
+        assert_almost_eq!(Vector2d::new(-1.0, 0.0).angle(), std::f64::consts::PI);
+    }
+
     #[test]
     fn vector3d_normalize_produces_unit_vector() {
         let v = Vector3d::new(3.0, 4.0, 0.0);
@@ -121,6 +297,39 @@ mod tests {
         assert_almost_eq!(z_axis.dot(&y_axis), 0.0);
     }
 
+    #[test]
+    fn vector3d_projection_and_rotation() {
+        let v = Vector3d::new(1.0, 1.0, 0.0);
+        let x_axis = Vector3d::new(1.0, 0.0, 0.0);
+        let z_axis = Vector3d::new(0.0, 0.0, 1.0);
+        assert_almost_eq!(v.angle_to(&x_axis), std::f64::consts::FRAC_PI_4);
+        assert_almost_eq!(x_axis.angle_to(&(-x_axis)), std::f64::consts::PI);
+        assert_vec3_almost_eq!(v.project_onto(&(x_axis * 3.0)), x_axis);
+        assert_vec3_almost_eq!(v.reject_from(&x_axis), Vector3d::new(0.0, 1.0, 0.0));
+        assert_vec3_almost_eq!(x_axis.rotate_about_axis(&(z_axis * 2.0), std::f64::consts::FRAC_PI_2), Vector3d::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn operators_act_componentwise() {
+        let a = Vector3d::new(1.0, 2.0, 3.0);
+        let b = Vector3d::new(-1.0, 0.5, 2.0);
+        assert_vec3_almost_eq!(a + b, Vector3d::new(0.0, 2.5, 5.0));
+        assert_vec3_almost_eq!(a - b, Vector3d::new(2.0, 1.5, 1.0));
+        assert_vec3_almost_eq!(-a, Vector3d::new(-1.0, -2.0, -3.0));
+        assert_vec3_almost_eq!(a * 2.0, 2.0 * a);
+        assert_vec3_almost_eq!(a / 2.0, Vector3d::new(0.5, 1.0, 1.5));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c[2] = 7.0;
+        assert_eq!(c, Vector3d::new(-1.0, 0.5, 7.0));
+        assert_almost_eq!(a[1], 2.0);
+
+        let d = Vector2d::new(3.0, 4.0) - Vector2d::new(1.0, 1.0);
+        assert_almost_eq!((d * 0.5)[1], 1.5);
+    }
+
     #[test]
     fn vector_is_approx_uses_global_epsilon() {
         let a = Vector2d::new(1.0, 1.0);
@@ -0,0 +1,32 @@
+#![cfg(feature = "approx")]
+
+use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+use geometry::{LocalAxis, Polygon, Vector2d, Vector3d};
+
+#[test]
+fn vectors_compare_with_approx() {
+    let a = Vector3d::new(1.0, 2.0, 3.0);
+    assert_relative_eq!(a, Vector3d::new(0.1 + 0.9, 2.0, 3.0));
+    assert_ulps_eq!(a * 0.1 * 10.0, a);
+    assert_abs_diff_eq!(a, Vector3d::new(1.0, 2.0, 3.001), epsilon = 1e-2);
+    assert_relative_ne!(a, Vector3d::new(1.0, 2.0, 3.001));
+    assert_relative_eq!(Vector2d::new(1e6, 0.0), Vector2d::new(1e6 + 1e-4, 0.0), max_relative = 1e-9);
+    assert_relative_eq!(Vector3d::new(1.0f32, 0.0, 0.0), Vector3d::new(1.0f32, 0.0, 1e-9));
+}
+
+#[test]
+fn frames_and_polygons_compare_with_approx() {
+    let frame = LocalAxis::from_euler_angles(Vector3d::new(1.0, 0.0, 0.0), 0.1, 0.2, 0.3);
+    assert_relative_eq!(frame.compose(&frame.inverse()), LocalAxis::from_euler_angles(Vector3d::new(0.0, 0.0, 0.0), 0.0, 0.0, 0.0));
+    assert_relative_ne!(frame, frame.inverse());
+
+    let square = Polygon::new([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]);
+    let nudged = Polygon::new([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0 + 1e-14, 0.0), (0.0, 1.0, 0.0)]);
+    assert_relative_ne!(square, nudged);
+    assert_relative_eq!(square, nudged, max_relative = 1e-12);
+    assert_abs_diff_eq!(square, square.translated((0.0, 0.0, 1e-3)), epsilon = 1e-2);
+    let rotated_start = Polygon::new([(1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 0.0)]);
+    assert_relative_ne!(square, rotated_start);
+    let pierced = Polygon::with_holes(square.vertices().clone(), [[(0.4, 0.4, 0.0), (0.6, 0.4, 0.0), (0.6, 0.6, 0.0)]]);
+    assert_relative_ne!(square, pierced);
+}
@@ -1,13 +1,13 @@
-use geometry::{Arc, Line, Vector2d, Vector3d};
+use geometry::{fillet, Arc, DistanceTo, GeometryError, Line, Ray, Vector2d, Vector3d};
 use utils::{assert_almost_eq, assert_vec3_almost_eq};
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
 
 #[test]
 fn arc2d_basic_properties() {
     let arc = Arc::new(Vector2d::new(0.0, 0.0), Vector2d::new(1.0, 0.0), Vector2d::new(0.0, 1.0), false);
     assert_almost_eq!(arc.radius(), 1.0);
     let p = Vector3d::from(Vector2d::new((2.0f64).sqrt() / 2.0, (2.0f64).sqrt() / 2.0));
-    assert!(arc.contains(&p));
+    assert!(arc.contains(&p, None));
     assert_almost_eq!(arc.length(), PI / 2.0);
 }
 
@@ -42,9 +42,9 @@ fn arc_line_intersection_filters_points_on_arc() {
         false,
     );
     let line = Line::new(Vector3d::new(0.0, 0.5, 0.0), Vector3d::new(1.0, 0.5, 0.0));
-    let intersections = arc.intersection_with_line(&line, false);
+    let intersections = arc.intersection_with_line(&line);
     assert_almost_eq!(intersections.len() as f64, 1.0);
-    assert!(arc.contains(&intersections[0]));
+    assert!(arc.contains(&intersections[0], None));
 }
 
 #[test]
@@ -54,7 +54,7 @@ fn arc_from_three_points_recovers_arc() {
     let p3 = Vector2d::new(0.0, 1.0);
     let arc = Arc::from_three_points(p1, p2, p3).expect("valid arc");
     let p2_3d = Vector3d::from(p2);
-    assert!(arc.contains(&p2_3d));
+    assert!(arc.contains(&p2_3d, None));
     assert_almost_eq!(arc.length(), PI / 2.0);
 }
 
@@ -67,6 +67,21 @@ fn arc_linearized_returns_segments() {
     assert_almost_eq!(segments.last().unwrap().end().y(), 1.0);
 }
 
+#[test]
+fn arc_linearized_by_tolerance_bounds_the_sagitta() {
+    let arc = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(0.0, 2.0, 0.0), false);
+    // Each chord may span 2 acos(1 − 0.01/2) = 0.2 rad of the quarter circle.
+    assert_eq!(arc.segments_for_sagitta(0.01), 8);
+    let chords = arc.linearized_by_tolerance(0.01);
+    assert_eq!(chords.len(), 8);
+    for chord in &chords {
+        let middle = Vector3d((chord.start().0 + chord.end().0) / 2.0);
+        assert!(2.0 - middle.0.norm() <= 0.01);
+    }
+    // Tolerances beyond the radius leave the single chord.
+    assert_eq!(arc.segments_for_sagitta(5.0), 1);
+}
+
 #[test]
 fn arc3d_contains_points_on_z_plane() {
     let arc = Arc::new(
@@ -76,17 +91,17 @@ fn arc3d_contains_points_on_z_plane() {
         false,
     );
     let mid = arc.point_at(0.5);
-    assert!(mid.is_approx(&Vector3d::new(0.7071067811865476, 0.7071067811865475, 0.0), None));
-    assert!(arc.contains(&arc.start()));
-    assert!(arc.contains(&arc.end()));
-    assert!(arc.contains(&mid));
+    assert!(mid.is_approx(&Vector3d::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), None));
+    assert!(arc.contains(&arc.start(), None));
+    assert!(arc.contains(&arc.end(), None));
+    assert!(arc.contains(&mid, None));
 
     let unit = Vector3d::new(mid.x(), mid.y(), 0.0).normalize();
     let outside_radius = Vector3d::new(unit.x() * 1.2, unit.y() * 1.2, unit.z() * 1.2);
-    assert!(!arc.contains(&outside_radius));
+    assert!(!arc.contains(&outside_radius, None));
 
     let off_plane = Vector3d::new(mid.x(), mid.y(), 0.1);
-    assert!(!arc.contains(&off_plane));
+    assert!(!arc.contains(&off_plane, None));
 }
 
 #[test]
@@ -101,9 +116,9 @@ fn arc3d_contains_points_on_3d_plane_manually_input() {
     let mid = arc.point_at(0.5);
     let manually_calculated_mid = Vector3d::new(-1., 1., 0.);
     assert!(mid.is_approx(&manually_calculated_mid, None));
-    assert!(arc.contains(&arc.start()));
-    assert!(arc.contains(&arc.end()));
-    assert!(arc.contains(&manually_calculated_mid));
+    assert!(arc.contains(&arc.start(), None));
+    assert!(arc.contains(&arc.end(), None));
+    assert!(arc.contains(&manually_calculated_mid, None));
 }
 
 #[test]
@@ -122,12 +137,12 @@ fn arc3d_contains_respects_orientation() {
     );
 
     let p = Vector3d::new((2.0f64).sqrt() / 2.0, (2.0f64).sqrt() / 2.0, 0.0);
-    assert!(counter_arc.contains(&p));
-    assert!(clockwise_arc.contains(&p));
+    assert!(counter_arc.contains(&p, None));
+    assert!(clockwise_arc.contains(&p, None));
 
     let beyond = Vector3d::new(-0.5, 0.5, 0.0);
-    assert!(!counter_arc.contains(&beyond));
-    assert!(!clockwise_arc.contains(&beyond));
+    assert!(!counter_arc.contains(&beyond, None));
+    assert!(!clockwise_arc.contains(&beyond, None));
 }
 
 #[test]
@@ -153,11 +168,15 @@ fn arc3d_intersection_with_line() {
         false,
     );
     let line = Line::new(Vector3d::new(-1.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0));
-    let hits = arc.intersection_with_line(&line, false);
+    let hits = arc.intersection_with_line(&line);
     assert_almost_eq!(hits.len() as f64, 1.0);
-    assert!(arc.contains(&hits[0]));
-    let ray_hits = arc.intersection_with_line(&line, true);
-    assert_almost_eq!(ray_hits.len() as f64, 2.0);
+    assert!(arc.contains(&hits[0], None));
+    // The ray also meets the circle behind its origin, off the arc.
+    let short = Line::new(Vector3d::new(-3.0, 0.0, 0.0), Vector3d::new(-2.0, 0.0, 0.0));
+    assert!(arc.intersection_with_line(&short).is_empty());
+    let ray_hits = Ray::from_line(&short).intersection_with_arc(&arc, None);
+    assert_eq!(ray_hits.len(), 1);
+    assert_vec3_almost_eq!(ray_hits[0], Vector3d::new(1.0, 0.0, 0.0));
 }
 
 #[test]
@@ -176,8 +195,8 @@ fn arc3d_intersection_with_arc() {
     );
     let intersections = arc1.intersection_with_arc(&arc2);
     assert_almost_eq!(intersections.len() as f64, 1.0);
-    assert!(arc1.contains(&intersections[0]));
-    assert!(arc2.contains(&intersections[0]));
+    assert!(arc1.contains(&intersections[0], None));
+    assert!(arc2.contains(&intersections[0], None));
 }
 
 #[test]
@@ -191,6 +210,169 @@ fn arc3d_break_at_point() {
     let point = arc.point_at(0.3);
     let segments = arc.break_at_point(&point);
     assert_almost_eq!(segments.len() as f64, 2.0);
-    assert!(segments.iter().all(|seg| seg.contains(&point)));
+    assert!(segments.iter().all(|seg| seg.contains(&point, None)));
     assert_almost_eq!(segments[0].length() + segments[1].length(), arc.length());
 }
+
+#[test]
+fn arc_fillet_between_lines() {
+    let first = Line::new(Vector3d::new(-5.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 0.0));
+    let second = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 5.0, 0.0));
+    let fillet = Arc::fillet(&first, &second, 1.0).unwrap();
+    assert_vec3_almost_eq!(fillet.center(), Vector3d::new(-1.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(fillet.start(), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(fillet.end(), Vector3d::new(0.0, 1.0, 0.0));
+    assert_almost_eq!(fillet.length(), PI / 2.0);
+    // Tangent to both lines at its ends.
+    assert_almost_eq!(fillet.start_tangent().y().abs(), 0.0);
+    assert_almost_eq!(fillet.end_tangent().x().abs(), 0.0);
+
+    // Lines stopping short of the corner are extended; an oversized fillet does not fit.
+    let short = Line::new(Vector3d::new(0.0, 0.5, 0.0), Vector3d::new(0.0, 5.0, 0.0));
+    assert!(Arc::fillet(&first, &short, 1.0).is_some());
+    assert!(Arc::fillet(&first, &second, 10.0).is_none());
+    let parallel = Line::new(Vector3d::new(0.0, 1.0, 0.0), Vector3d::new(5.0, 1.0, 0.0));
+    assert!(Arc::fillet(&first, &parallel, 1.0).is_none());
+
+    // Obtuse corner in 3D.
+    let a = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 4.0));
+    let b = Line::new(Vector3d::new(0.0, 0.0, 4.0), Vector3d::new(0.0, 4.0, 8.0));
+    let fillet = Arc::fillet(&a, &b, 0.5).unwrap();
+    assert_almost_eq!(fillet.angle().abs(), PI / 4.0);
+    assert!(a.contains(&fillet.start(), None) && b.contains(&fillet.end(), None));
+}
+
+#[test]
+fn arc_tangent_lines() {
+    let left = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(-1.0, 0.0, 0.0), false);
+    let right = Arc::new(Vector3d::new(5.0, 0.0, 0.0), Vector3d::new(6.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0), false);
+    // Upper half circles share only the top tangent.
+    let lines = left.tangent_lines(&right);
+    assert_eq!(lines.len(), 1);
+    assert_vec3_almost_eq!(lines[0].start(), Vector3d::new(0.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(lines[0].end(), Vector3d::new(5.0, 1.0, 0.0));
+
+    // An upper and a lower half circle share a single crossing tangent.
+    let lower = Arc::new(Vector3d::new(5.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0), Vector3d::new(6.0, 0.0, 0.0), false);
+    let crossing = left.tangent_lines(&lower);
+    assert_eq!(crossing.len(), 1);
+    for line in &crossing {
+        assert_almost_eq!(line.distance(&Vector3d::new(0.0, 0.0, 0.0)), 1.0);
+        assert_almost_eq!(line.distance(&Vector3d::new(5.0, 0.0, 0.0)), 1.0);
+        // Crossing tangents pass between the circles.
+        assert!(line.start().y() * line.end().y() < 0.0);
+    }
+}
+
+#[test]
+fn fillet_trims_lines_into_a_path() {
+    // First line drawn away from the corner, second stopping short of it.
+    let first = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(-5.0, 0.0, 0.0));
+    let second = Line::new(Vector3d::new(0.0, 0.5, 0.0), Vector3d::new(0.0, 5.0, 0.0));
+    let (a, arc, b) = fillet(&first, &second, 1.0).unwrap();
+    assert_vec3_almost_eq!(a.start(), Vector3d::new(-5.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(a.end(), arc.start());
+    assert_vec3_almost_eq!(arc.start(), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(b.start(), arc.end());
+    assert_vec3_almost_eq!(b.start(), Vector3d::new(0.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(b.end(), Vector3d::new(0.0, 5.0, 0.0));
+    assert_almost_eq!(a.length() + arc.length() + b.length(), 4.0 + PI / 2.0 + 4.0);
+
+    // Toe of a flange meeting a web in 2D.
+    let flange = Line::new(Vector2d::new(3.0, 1.0), Vector2d::new(0.5, 1.0));
+    let web = Line::new(Vector2d::new(0.5, 1.0), Vector2d::new(0.5, 6.0));
+    let (flange, root, web) = fillet(&flange, &web, 0.2).unwrap();
+    assert_almost_eq!(flange.end().x(), 0.7);
+    assert_almost_eq!(web.start().y(), 1.2);
+    assert_almost_eq!(root.radius(), 0.2);
+
+    assert!(fillet(&first, &second, 10.0).is_none());
+}
+
+#[test]
+fn arc_bounding_box_includes_swept_extremes() {
+    let origin = Vector3d::new(0.0, 0.0, 0.0);
+    let quarter = Arc::new(origin, Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0), false);
+    let bounds = quarter.bounding_box();
+    assert_vec3_almost_eq!(bounds.min(), origin);
+    assert_vec3_almost_eq!(bounds.max(), Vector3d::new(1.0, 1.0, 0.0));
+
+    let upper = Arc::new(origin, Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(-1.0, 0.0, 0.0), false);
+    let bounds = upper.bounding_box();
+    assert_vec3_almost_eq!(bounds.min(), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(bounds.max(), Vector3d::new(1.0, 1.0, 0.0));
+
+    let lower = Arc::new(origin, Vector3d::new(-1.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), false);
+    let bounds = lower.bounding_box();
+    assert_vec3_almost_eq!(bounds.min(), Vector3d::new(-1.0, -1.0, 0.0));
+    assert_vec3_almost_eq!(bounds.max(), Vector3d::new(1.0, 0.0, 0.0));
+
+    // Tilted arc: compare with a dense polyline, which can only fall inside.
+    let tilted = Arc::from_three_points(
+        Vector3d::new(2.0, 0.0, 1.0),
+        Vector3d::new(0.0, 2.0, 2.0),
+        Vector3d::new(-2.0, 0.5, 0.0),
+    )
+    .unwrap();
+    let bounds = tilted.bounding_box();
+    let (mut poly_min, mut poly_max) = (tilted.start().0, tilted.start().0);
+    for line in tilted.linearized(2000) {
+        poly_min = poly_min.inf(&line.end().0);
+        poly_max = poly_max.sup(&line.end().0);
+    }
+    assert_vec3_almost_eq!("min", bounds.min(), Vector3d(poly_min), 1e-5);
+    assert_vec3_almost_eq!("max", bounds.max(), Vector3d(poly_max), 1e-5);
+}
+
+#[test]
+fn arc_local_frame_follows_start_tangent_and_normal() {
+    let arc = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(0.0, 2.0, 0.0), false);
+    let axes = arc.local_axis();
+    assert_vec3_almost_eq!(axes.origin(), Vector3d::new(2.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(axes.direction(geometry::Axis::AxisX), Vector3d::new(0.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(axes.direction(geometry::Axis::AxisY), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(axes.direction(geometry::Axis::AxisZ), Vector3d::new(0.0, 0.0, 1.0));
+    assert_vec3_almost_eq!(arc.to_local(arc.center()), Vector3d::new(0.0, 2.0, 0.0));
+    assert_vec3_almost_eq!(arc.to_local(arc.end()), Vector3d::new(2.0, 2.0, 0.0));
+
+    // Arc in the XZ plane: the frame stays orthonormal and round-trips.
+    let tilted = Arc::from_three_points(
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(1.0, 0.0, 1.0),
+        Vector3d::new(2.0, 0.0, 1.5),
+    )
+    .unwrap();
+    let rotation = tilted.rotation_matrix();
+    assert_almost_eq!((rotation.transpose() * rotation - nalgebra::Matrix3::identity()).norm(), 0.0);
+    assert_almost_eq!(rotation.determinant(), 1.0);
+    assert_almost_eq!(rotation.column(0).dot(&(tilted.start().0 - tilted.center().0)), 0.0);
+    assert_almost_eq!(rotation[(1, 2)].abs(), 1.0);
+    let point = Vector3d::new(0.3, -0.7, 1.1);
+    assert_vec3_almost_eq!(tilted.to_global(tilted.to_local(point)), point);
+    assert_almost_eq!(tilted.to_local(tilted.point_at(0.3)).z(), 0.0);
+}
+
+#[test]
+fn three_point_arcs_say_why_they_fail() {
+    let arc = Arc::try_from_three_points((1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (-1.0, 0.0, 0.0)).unwrap();
+    assert_almost_eq!(arc.radius(), 1.0);
+    let coincident = Arc::try_from_three_points((1.0, 0.0, 0.0), (1.0, 0.0, 0.0), (-1.0, 0.0, 0.0));
+    assert_eq!(coincident.unwrap_err(), GeometryError::CoincidentPoints);
+    let collinear = Arc::try_from_three_points((0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0));
+    assert_eq!(collinear.unwrap_err(), GeometryError::Collinear);
+}
+
+#[test]
+fn distance_between_arc_and_segment() {
+    let arc = Arc::new(Vector2d::new(0.0, 0.0), Vector2d::new(1.0, 0.0), Vector2d::new(0.0, 1.0), false);
+    let chord = Line::new(Vector3d::new(3.0, -1.0, 0.0), Vector3d::new(-1.0, 3.0, 0.0));
+    let (on_arc, on_line, distance) = arc.distance_to(&chord);
+    assert_almost_eq!(distance, 2.0f64.sqrt() - 1.0, 1e-9);
+    assert_vec3_almost_eq!("arc witness", on_arc, Vector3d::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), 1e-6);
+    assert_vec3_almost_eq!("segment witness", on_line, Vector3d::new(1.0, 1.0, 0.0), 1e-6);
+
+    let crossing = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 2.0, 0.0));
+    let (on_line, on_arc, distance) = crossing.distance_to(&arc);
+    assert_almost_eq!(distance, 0.0);
+    assert_vec3_almost_eq!(on_line, on_arc);
+}
@@ -0,0 +1,51 @@
+use geometry::{Bezier3d, Vector3d};
+use std::f64::consts::PI;
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+/// Standard cubic approximation of a unit quarter circle in the XY plane.
+fn quarter_circle() -> Bezier3d {
+    let k = 4.0 / 3.0 * (2.0_f64.sqrt() - 1.0);
+    Bezier3d::new((1.0, 0.0, 0.0), (1.0, k, 0.0), (k, 1.0, 0.0), (0.0, 1.0, 0.0))
+}
+
+#[test]
+fn bezier_evaluation() {
+    let curve = quarter_circle();
+    assert_vec3_almost_eq!(curve.point_at(0.0), curve.start());
+    assert_vec3_almost_eq!(curve.point_at(1.0), curve.end());
+    assert_almost_eq!(curve.point_at(0.5).0.norm(), 1.0, 1e-3);
+    assert_vec3_almost_eq!(curve.tangent_at(0.0), Vector3d::new(0.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(curve.tangent_at(1.0), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_almost_eq!(curve.length(), PI / 2.0, 1e-3);
+
+    let (first, second) = curve.split_at(0.3);
+    assert_vec3_almost_eq!(first.end(), curve.point_at(0.3));
+    assert_almost_eq!(first.length() + second.length(), curve.length());
+}
+
+#[test]
+fn bezier_closest_point() {
+    let curve = quarter_circle();
+    let target = Vector3d::new(2.0, 2.0, 0.0);
+    let closest = curve.closest_point(&target);
+    // By symmetry the closest point is the curve midpoint.
+    assert_vec3_almost_eq!(closest, curve.point_at(0.5));
+    assert_almost_eq!(curve.closest_parameter(&target), 0.5);
+    assert_vec3_almost_eq!(curve.closest_point(&Vector3d::new(3.0, -1.0, 0.0)), curve.start());
+}
+
+#[test]
+fn bezier_linearization() {
+    let curve = quarter_circle();
+    let lines = curve.linearized(8);
+    assert_eq!(lines.len(), 8);
+    assert_vec3_almost_eq!(lines[7].end(), curve.end());
+    let total: f64 = lines.iter().map(|l| l.length()).sum();
+    assert!(total < curve.length() && total > 0.99 * curve.length());
+
+    let lines = curve.linearized_with_tolerance(1e-4);
+    for line in &lines {
+        assert!(curve.distance(&line.midpoint()) <= 1.1e-4);
+    }
+    assert!(lines.len() > curve.linearized_with_tolerance(1e-2).len());
+}
@@ -0,0 +1,48 @@
+use geometry::{BoundingBox3d, Line, Polygon, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+#[test]
+fn bounding_box_sphere_passes_through_corners() {
+    let bbox = BoundingBox3d::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 4.0, 4.0));
+    let sphere = bbox.bounding_sphere();
+    assert_vec3_almost_eq!(sphere.center(), Vector3d::new(1.0, 2.0, 2.0));
+    assert_almost_eq!(sphere.radius(), 3.0);
+}
+
+#[test]
+fn boxes_combine_and_grow() {
+    let slab = BoundingBox3d::new((4.0, 2.0, 0.2), (0.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(slab.min(), Vector3d::new(0.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(slab.center(), Vector3d::new(2.0, 1.0, 0.1));
+    assert_vec3_almost_eq!(slab.extent(), Vector3d::new(4.0, 2.0, 0.2));
+    assert!(slab.contains(&Vector3d::new(4.0, 1.0, 0.1), None));
+    assert!(!slab.contains(&Vector3d::new(4.001, 1.0, 0.1), None));
+    assert!(slab.contains(&Vector3d::new(4.001, 1.0, 0.1), Some(0.01)));
+
+    let column = BoundingBox3d::new((1.0, 1.0, -3.0), (1.5, 1.5, 0.0));
+    let both = slab.union(&column);
+    assert_vec3_almost_eq!(both.min(), Vector3d::new(0.0, 0.0, -3.0));
+    assert_vec3_almost_eq!(both.max(), Vector3d::new(4.0, 2.0, 0.2));
+    let contact = slab.intersection(&column).unwrap();
+    assert_almost_eq!(contact.extent().z(), 0.0);
+    assert_vec3_almost_eq!(contact.min(), Vector3d::new(1.0, 1.0, 0.0));
+    assert!(slab.intersection(&BoundingBox3d::from_point(Vector3d::new(5.0, 0.0, 0.0))).is_none());
+
+    let clearance = column.inflate(0.25);
+    assert_vec3_almost_eq!(clearance.min(), Vector3d::new(0.75, 0.75, -3.25));
+    assert_vec3_almost_eq!(column.inflate(-0.5).extent(), Vector3d::new(0.0, 0.0, 2.0));
+
+    assert!(BoundingBox3d::from_points(Vec::<Vector3d>::new()).is_none());
+    let cloud = BoundingBox3d::from_points([(1.0, 5.0, 0.0), (-1.0, 2.0, 3.0), (0.0, 0.0, 1.0)]).unwrap();
+    assert_eq!(cloud, BoundingBox3d::new((-1.0, 0.0, 0.0), (1.0, 5.0, 3.0)));
+}
+
+#[test]
+fn lines_and_polygons_report_shared_boxes() {
+    let member = Line::new(Vector3d::new(1.0, 2.0, 0.0), Vector3d::new(0.0, 0.0, 3.0));
+    let panel = Polygon::new([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 0.0, 3.0)]);
+    let model = member.bounding_box().union(&panel.bounding_box());
+    assert_eq!(model, BoundingBox3d::new((0.0, 0.0, 0.0), (2.0, 2.0, 3.0)));
+    let overlap = member.bounding_box().intersection(&panel.bounding_box()).unwrap();
+    assert_vec3_almost_eq!(overlap.max(), Vector3d::new(1.0, 0.0, 3.0));
+}
@@ -0,0 +1,71 @@
+use std::f64::consts::PI;
+
+use geometry::{Arc, CurvedPolygon, Line, Polyline, Segment, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+/// Circle of four quarter arcs, counterclockwise unless `clockwise`.
+fn circle(x: f64, y: f64, radius: f64, clockwise: bool) -> Polyline {
+    let center = Vector3d::new(x, y, 0.0);
+    let mut points: Vec<Vector3d> = [(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)]
+        .map(|(dx, dy)| Vector3d::new(x + radius * dx, y + radius * dy, 0.0))
+        .to_vec();
+    if clockwise {
+        points.reverse();
+    }
+    Polyline::new((0..4).map(|i| Arc::new(center, points[i], points[(i + 1) % 4], clockwise)))
+}
+
+#[test]
+fn circle_matches_closed_form() {
+    let r = 0.1;
+    let section = CurvedPolygon::new(circle(1.0, 2.0, r, false));
+    assert_almost_eq!(section.area(), PI * r * r);
+    assert_vec3_almost_eq!(section.centroid(), Vector3d::new(1.0, 2.0, 0.0));
+    let local = section.about_centroid_local();
+    assert_almost_eq!(local[(0, 0)], PI * r.powi(4) / 4.0);
+    assert_almost_eq!(local[(1, 1)], PI * r.powi(4) / 4.0);
+    assert!(local[(0, 1)].abs() < 1e-15);
+    assert_almost_eq!(section.about_centroid_global()[(2, 2)], PI * r.powi(4) / 2.0);
+}
+
+#[test]
+fn tube_subtracts_hole_of_either_orientation() {
+    let (outer, inner) = (0.1f64, 0.08f64);
+    let tube = CurvedPolygon::new(circle(0.0, 0.0, outer, false)).with_hole(circle(0.0, 0.0, inner, true));
+    assert_almost_eq!(tube.area(), PI * (outer.powi(2) - inner.powi(2)));
+    let local = tube.about_centroid_local();
+    assert_almost_eq!(local[(0, 0)], PI * (outer.powi(4) - inner.powi(4)) / 4.0);
+
+    let same = CurvedPolygon::new(circle(0.0, 0.0, outer, true)).with_hole(circle(0.0, 0.0, inner, false));
+    assert_almost_eq!(same.area(), tube.area());
+    assert_almost_eq!(same.about_centroid_local()[(1, 1)], local[(1, 1)]);
+}
+
+#[test]
+fn filleted_and_notched_plate_match_fine_linearization() {
+    // 200x100 plate at z = 0.5 with 20 mm fillets on the top corners and
+    // a semicircular notch of radius 30 mm in the bottom edge.
+    let (w, h, r, n) = (0.2, 0.1, 0.02, 0.03);
+    let p = |x: f64, y: f64| Vector3d::new(x, y, 0.5);
+    let segments: Vec<Segment> = vec![
+        Line::new(p(0.0, 0.0), p(0.5 * w - n, 0.0)).into(),
+        Arc::new(p(0.5 * w, 0.0), p(0.5 * w - n, 0.0), p(0.5 * w, n), true).into(),
+        Arc::new(p(0.5 * w, 0.0), p(0.5 * w, n), p(0.5 * w + n, 0.0), true).into(),
+        Line::new(p(0.5 * w + n, 0.0), p(w, 0.0)).into(),
+        Line::new(p(w, 0.0), p(w, h - r)).into(),
+        Arc::new(p(w - r, h - r), p(w, h - r), p(w - r, h), false).into(),
+        Line::new(p(w - r, h), p(r, h)).into(),
+        Arc::new(p(r, h - r), p(r, h), p(0.0, h - r), false).into(),
+        Line::new(p(0.0, h - r), p(0.0, 0.0)).into(),
+    ];
+    let section = CurvedPolygon::new(Polyline::new(segments));
+    assert_almost_eq!(section.area(), w * h - (4.0 - PI) * r * r / 2.0 - PI * n * n / 2.0);
+
+    let polygon = section.linearized(512);
+    assert_almost_eq!(section.area(), polygon.area(), 1e-5);
+    assert_vec3_almost_eq!("centroid", section.centroid(), polygon.centroid(), 1e-5);
+    let (exact, linear) = (section.about_centroid_global(), polygon.about_centroid_global());
+    for (a, b) in exact.iter().zip(linear.iter()) {
+        assert!((a - b).abs() <= 1e-4 * linear[(0, 0)], "{a} != {b}");
+    }
+}
@@ -0,0 +1,56 @@
+use geometry::{Arc, Bezier3d, Curve, Edge, Line, Polyline, Segment, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+fn curves() -> Vec<Box<dyn Curve<Vector3d>>> {
+    let hook = Polyline::new([
+        Segment::Line(Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0))),
+        Segment::Arc(Arc::new(Vector3d::new(2.0, 1.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(3.0, 1.0, 0.0), false)),
+        Segment::Line(Line::new(Vector3d::new(3.0, 1.0, 0.0), Vector3d::new(3.0, 3.0, 0.0))),
+    ]);
+    vec![
+        Box::new(Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(3.0, 4.0, 0.0))),
+        Box::new(Edge::new(Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, -2.0))),
+        Box::new(Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0), false)),
+        Box::new(Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0), true)),
+        Box::new(hook),
+        Box::new(Bezier3d::new((0.0, 0.0, 0.0), (1.0, 2.0, 0.0), (2.0, -2.0, 1.0), (3.0, 0.0, 0.0))),
+    ]
+}
+
+#[test]
+fn curves_share_one_parametrization() {
+    for curve in curves() {
+        assert_vec3_almost_eq!(curve.point_at(0.0), curve.start());
+        assert_vec3_almost_eq!(curve.point_at(1.0), curve.end());
+        let chords = curve.linearized(64);
+        assert_vec3_almost_eq!(chords[0].start(), curve.start());
+        assert_vec3_almost_eq!(chords[chords.len() - 1].end(), curve.end());
+        assert!(curve.length() >= chords.iter().map(|c| c.length()).sum::<f64>() - 1e-9);
+    }
+}
+
+#[test]
+fn tangents_follow_the_direction_of_travel() {
+    for curve in curves() {
+        for t in [0.1, 0.45, 0.9] {
+            let tangent = curve.tangent_at(t);
+            assert_almost_eq!(tangent.norm(), 1.0);
+            let step = curve.point_at(t + 1e-6).0 - curve.point_at(t - 1e-6).0;
+            assert!((step.normalize() - tangent.0).norm() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn bounding_boxes_hold_the_whole_curve() {
+    for curve in curves() {
+        let bounds = curve.bounding_box();
+        for i in 0..=100 {
+            assert!(bounds.contains(&curve.point_at(i as f64 / 100.0), Some(1e-9)));
+        }
+        assert!(bounds.contains(&curve.closest_point(&Vector3d::new(10.0, 10.0, 10.0)), Some(1e-9)));
+    }
+    // The quarter circle reaches x = 1 and y = 1 only at its ends.
+    let arc: &dyn Curve<Vector3d> = &Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0), false);
+    assert_vec3_almost_eq!(arc.bounding_box().max(), Vector3d::new(1.0, 1.0, 0.0));
+}
@@ -1,11 +1,12 @@
-use geometry::{Edge, Vector2d, Vector3d};
+use geometry::{Arc, Edge, Line, Vector2d, Vector3d};
+use std::f64::consts::PI;
 use utils::{assert_almost_eq, assert_vec3_almost_eq};
 
 #[test]
 fn edge2d_basic_behaviour() {
     let edge = Edge::new(Vector2d::new(0.0, 0.0), Vector2d::new(3.0, 0.0));
     assert_almost_eq!(edge.length(), 3.0);
-    assert!(edge.contains(&Vector3d::from(Vector2d::new(1.5, 0.0))));
+    assert!(edge.contains(&Vector3d::from(Vector2d::new(1.5, 0.0)), None));
     let centroid = edge.centroid();
     assert_almost_eq!(centroid.x(), 1.5);
 }
@@ -15,10 +16,13 @@ fn edge_break_and_intersection() {
     let edge = Edge::new(Vector2d::new(0.0, 0.0), Vector2d::new(4.0, 4.0));
     let parts = edge.break_at(0.5);
     assert_almost_eq!(parts.len() as f64, 2.0);
+    let parts = edge.break_at_points(&[Vector3d::new(3.0, 3.0, 0.0), Vector3d::new(1.0, 1.0, 0.0)]);
+    assert_eq!(parts.len(), 3);
+    assert_almost_eq!(parts[1].length(), 8.0_f64.sqrt());
     // Use a 3D edge to test intersection with a 3D line
     let edge3 = Edge::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 4.0, 0.0));
     let _diag = geometry::Line::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(4.0, 0.0, 0.0));
-    let hit = edge3.intersection_with_line(&geometry::Line::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(4.0, 0.0, 0.0)), false).unwrap();
+    let hit = edge3.intersection_with_line(&geometry::Line::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(4.0, 0.0, 0.0))).unwrap();
     assert_almost_eq!(hit.x(), 2.0);
 }
 
@@ -56,3 +60,38 @@ fn edge_closest_point_matches_point_at() {
     assert_vec3_almost_eq!(closest, expected);
     assert_almost_eq!(edge.length_at_point(&point), 3.0);
 }
+
+#[test]
+fn curved_edges_follow_their_arc() {
+    let arc = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(-2.0, 0.0, 0.0), false);
+    let edge = Edge::from_arc(arc);
+    assert!(edge.is_curved());
+    assert_almost_eq!(edge.length(), 2.0 * PI);
+    assert_vec3_almost_eq!(edge.point_at(0.5), Vector3d::new(0.0, 2.0, 0.0));
+    assert_vec3_almost_eq!(edge.tangent_at(0.5), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(edge.centroid(), Vector3d::new(0.0, 4.0 / PI, 0.0));
+    assert!(edge.contains(&Vector3d::new(0.0, 2.0, 0.0), None));
+
+    let halves = edge.break_at(0.5);
+    assert_eq!(halves.len(), 2);
+    assert!(halves.iter().all(|half| half.is_curved() && (half.length() - PI).abs() < 1e-9));
+    let s = 2.0_f64.sqrt();
+    let pieces = edge.break_at_points(&[Vector3d::new(-s, s, 0.0), Vector3d::new(s, s, 0.0), Vector3d::new(5.0, 0.0, 0.0)]);
+    assert_eq!(pieces.len(), 3);
+    assert_vec3_almost_eq!(pieces[0].end(), Vector3d::new(s, s, 0.0));
+    assert_almost_eq!(pieces[1].length(), PI);
+
+    // A horizontal chord crosses the half circle twice, in order along the edge.
+    let chord = Line::new(Vector3d::new(-3.0, 1.0, 0.0), Vector3d::new(3.0, 1.0, 0.0));
+    let hits = edge.intersections_with_line(&chord);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].x() > 0.0 && hits[1].x() < 0.0);
+    assert_vec3_almost_eq!(edge.intersection_with_line(&chord).unwrap(), hits[0]);
+
+    let reversed = edge.reversed_edge();
+    assert_vec3_almost_eq!(reversed.start(), Vector3d::new(-2.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(reversed.tangent_at(0.0), Vector3d::new(0.0, 1.0, 0.0));
+
+    let probe = Edge::new(Vector3d::new(0.0, -5.0, 0.0), Vector3d::new(0.0, -4.0, 0.0));
+    assert_vec3_almost_eq!(probe.ray_intersection_with_edge(&edge).unwrap(), Vector3d::new(0.0, 2.0, 0.0));
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "exact")]
+
+use geometry::exact::{relative_error, ExactProperties};
+use geometry::Polygon;
+use utils::assert_almost_eq;
+
+#[test]
+fn rectangle_with_hole_is_exact() {
+    let frame = Polygon::with_holes(
+        vec![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (0.0, 2.0, 0.0)],
+        vec![vec![(1.0, 0.5, 0.0), (1.0, 1.5, 0.0), (2.0, 1.5, 0.0), (2.0, 0.5, 0.0)]],
+    );
+    let exact = ExactProperties::of_polygon(&frame).unwrap();
+    assert_almost_eq!(exact.area_f64(), 7.0);
+    // Centroid x: (8 * 2 - 1 * 1.5) / 7.
+    assert_almost_eq!(exact.centroid_f64()[0], 14.5 / 7.0);
+    assert_almost_eq!(exact.centroid_f64()[1], 1.0);
+    // Ixx: 4 * 2³ / 12 - 1 * 1³ / 12 about y = 1.
+    assert_almost_eq!(exact.second_moments_f64()[0], 31.0 / 12.0);
+
+    let inertia = frame.about_centroid_global();
+    assert!(relative_error(&exact.area, frame.area()) < 1e-15);
+    assert!(relative_error(&exact.second_moments[0], inertia[(0, 0)]) < 1e-12);
+    assert!(relative_error(&exact.second_moments[1], inertia[(1, 1)]) < 1e-12);
+}
+
+#[test]
+fn far_sliver_stays_close_to_exact() {
+    // A 1 mm wide, 10 m long sliver placed 1 km from the origin.
+    let sliver = Polygon::new(vec![(1000.0, 1000.0, 0.0), (1010.0, 1000.001, 0.0), (1000.0, 1000.002, 0.0)]);
+    let exact = ExactProperties::of_polygon(&sliver).unwrap();
+    let inertia = sliver.about_centroid_global();
+    let centroid = sliver.centroid();
+    assert!(relative_error(&exact.area, sliver.area()) < 1e-9);
+    assert!(relative_error(&exact.centroid[0], centroid.x()) < 1e-12);
+    assert!(relative_error(&exact.centroid[1], centroid.y()) < 1e-12);
+    assert!(relative_error(&exact.second_moments[0], inertia[(0, 0)]) < 1e-9);
+    assert!(relative_error(&exact.second_moments[1], inertia[(1, 1)]) < 1e-9);
+    assert!(relative_error(&exact.second_moments[2], -inertia[(0, 1)]) < 1e-9);
+}
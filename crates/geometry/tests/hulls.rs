@@ -0,0 +1,44 @@
+use geometry::{convex_hull_2d, convex_hull_planar, Vector2d, Vector3d};
+use utils::assert_almost_eq;
+
+#[test]
+fn convex_hull_2d_drops_interior_and_collinear_points() {
+    let points = [
+        Vector2d::new(0.0, 0.0),
+        Vector2d::new(2.0, 0.0),
+        Vector2d::new(4.0, 0.0),
+        Vector2d::new(1.0, 1.0),
+        Vector2d::new(4.0, 3.0),
+        Vector2d::new(0.0, 3.0),
+        Vector2d::new(2.0, 2.0),
+        Vector2d::new(4.0, 3.0),
+    ];
+    let hull = convex_hull_2d(&points);
+    assert_eq!(hull.len(), 4);
+    assert_almost_eq!(hull[0].x(), 0.0);
+    assert_almost_eq!(hull[0].y(), 0.0);
+    assert_almost_eq!(hull[1].x(), 4.0);
+    assert_almost_eq!(hull[1].y(), 0.0);
+    assert_almost_eq!(hull[2].x(), 4.0);
+    assert_almost_eq!(hull[2].y(), 3.0);
+    assert!(convex_hull_2d(&[]).is_empty());
+}
+
+#[test]
+fn convex_hull_planar_returns_polygon_in_the_point_plane() {
+    // Projected node set on a sloped roof plane z = 0.5 x.
+    let points: Vec<Vector3d> = [(0.0, 0.0), (6.0, 0.0), (6.0, 4.0), (0.0, 4.0), (3.0, 2.0), (1.0, 3.0)]
+        .iter()
+        .map(|&(x, y)| Vector3d::new(x, y, 0.5 * x))
+        .collect();
+    let hull = convex_hull_planar(&points).unwrap();
+    assert_eq!(hull.vertices().len(), 4);
+    assert_almost_eq!(hull.area(), 6.0 * 1.25_f64.sqrt() * 4.0);
+    let centroid = hull.centroid();
+    assert_almost_eq!(centroid.x(), 3.0);
+    assert_almost_eq!(centroid.y(), 2.0);
+    assert_almost_eq!(centroid.z(), 1.5);
+    for v in hull.vertices() {
+        assert_almost_eq!(v.z(), 0.5 * v.x());
+    }
+}
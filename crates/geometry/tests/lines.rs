@@ -1,4 +1,6 @@
-use geometry::{Axis, Line, Vector3d};
+#![allow(clippy::excessive_precision)]
+
+use geometry::{Axis, InfiniteLine3d, SignedAxis, Line, Line2d, Line3d, Plane, Polygon, Vector2d, Vector3d};
 use utils::{assert_almost_eq, assert_vec3_almost_eq};
 
 #[test]
@@ -9,9 +11,9 @@ fn line2d_basic_properties() {
     assert_almost_eq!(dir.x(), 1.0);
     assert_almost_eq!(dir.y(), 0.0);
     assert_almost_eq!(dir.z(), 0.0);
-    let (min, max) = line.bounding_box();
-    assert_almost_eq!(min.x(), 0.0);
-    assert_almost_eq!(max.x(), 4.0);
+    let bounds = line.bounding_box();
+    assert_almost_eq!(bounds.min().x(), 0.0);
+    assert_almost_eq!(bounds.max().x(), 4.0);
 }
 
 #[test]
@@ -44,13 +46,21 @@ fn line_break_and_reverse() {
 fn intersection_behaviour() {
     let a = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 4.0, 0.0));
     let b = Line::new(Vector3d::new(4.0, 0.0, 0.0), Vector3d::new(0.0, 4.0, 0.0));
-    let point = a.intersection(&b, false).unwrap();
+    let point = a.intersection(&b, None).unwrap();
     assert_almost_eq!(point.x(), 2.0);
     assert_almost_eq!(point.y(), 2.0);
 
     let c = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 5.0));
     let d = Line::new(Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 5.0));
-    assert!(c.intersection(&d, false).is_none());
+    assert!(c.intersection(&d, None).is_none());
+}
+
+#[test]
+fn short_segments_are_not_taken_as_parallel() {
+    // 0.1 mm segments in metres: a·e - b² is 1e-16 although they are perpendicular.
+    let a = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1e-4, 0.0, 0.0));
+    let b = Line::new(Vector3d::new(5e-5, -5e-5, 0.0), Vector3d::new(5e-5, 5e-5, 0.0));
+    assert_vec3_almost_eq!(a.intersection(&b, None).unwrap(), Vector3d::new(5e-5, 0.0, 0.0));
 }
 
 #[test]
@@ -67,7 +77,7 @@ fn epsilon_respected_in_lines() {
 fn contains_and_point_parameter() {
     let line = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(10.0, 0.0, 0.0));
     let point = Vector3d::new(3.0, 0.0, 0.0);
-    assert!(line.contains(&point));
+    assert!(line.contains(&point, None));
     assert_almost_eq!(line.point_parameter(&point).unwrap(), 0.3);
 
     let outside = Vector3d::new(12.0, 0.0, 0.0);
@@ -109,6 +119,25 @@ fn break_at_point_splits_line() {
     assert_almost_eq!(segments[1].start().z(), 2.5);
 }
 
+#[test]
+fn break_at_points_orders_and_dedupes_cuts() {
+    let line = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 10.0));
+    let cuts = [
+        Vector3d::new(0.0, 0.0, 7.5),
+        Vector3d::new(0.0, 0.0, 2.5),
+        Vector3d::new(0.0, 0.0, 7.5),
+        Vector3d::new(1.0, 0.0, 5.0),
+        Vector3d::new(0.0, 0.0, 10.0),
+    ];
+    let segments = line.break_at_points(&cuts);
+    assert_eq!(segments.len(), 3);
+    assert_vec3_almost_eq!(segments[0].start(), line.start());
+    assert_almost_eq!(segments[0].end().z(), 2.5);
+    assert_almost_eq!(segments[1].end().z(), 7.5);
+    assert_vec3_almost_eq!(segments[2].end(), line.end());
+    assert_eq!(line.break_at_points(&[]), vec![line]);
+}
+
 #[test]
 fn move_start_and_end_mutate_line() {
     let mut line = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0));
@@ -137,15 +166,20 @@ fn break_at_out_of_range_returns_original() {
 }
 
 #[test]
-fn ray_intersection_respects_direction() {
-    let ray = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(5.0, 0.0, 0.0));
-    let target = Line::new(Vector3d::new(-5.0, -5.0, 0.0), Vector3d::new(-5.0, 5.0, 0.0));
-    assert!(ray.ray_intersection(&target).is_none());
-
-    let target2 = Line::new(Vector3d::new(3.0, -5.0, 0.0), Vector3d::new(3.0, 5.0, 0.0));
-    let hit = ray.ray_intersection(&target2).unwrap();
-    assert_almost_eq!(hit.x(), 3.0);
-    assert_almost_eq!(hit.y(), 0.0);
+fn signed_axes_point_both_ways() {
+    assert_eq!(-Axis::AxisZ, SignedAxis::NegZ);
+    assert_eq!(-SignedAxis::NegY, SignedAxis::from(Axis::AxisY));
+    assert_eq!(Vector3d::from(SignedAxis::NegX), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_eq!(SignedAxis::from_vector3d(&Vector3d::new(0.0, 0.0, -2.5)), Some(SignedAxis::NegZ));
+    assert_eq!(SignedAxis::from_vector3d(&Vector3d::new(0.0, 1.0, 0.0)), Some(SignedAxis::PosY));
+    assert_eq!(SignedAxis::from_vector3d(&Vector3d::new(1.0, 1.0, 0.0)), None);
+    assert_eq!(SignedAxis::from_vector3d(&Vector3d::new(0.0, 0.0, 0.0)), None);
+    assert_eq!(SignedAxis::NegZ.to_string(), "-z");
+
+    let frame = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 4.0, 0.0)).local_axis().unwrap();
+    let face = frame.direction(-Axis::AxisZ);
+    assert_vec3_almost_eq!(face, Vector3d(-frame.direction(Axis::AxisZ).0));
+    assert_vec3_almost_eq!(frame.direction(SignedAxis::PosX), Vector3d::new(0.0, 1.0, 0.0));
 }
 
 #[test]
@@ -313,3 +347,139 @@ fn reference_line_cases() -> [LineReference; 5] {
         },
     ]
 }
+
+#[test]
+fn line_closest_points_between_segments() {
+    // Skew segments: one along X at z = 0, one along Y at z = 2.
+    let a = Line::new(Vector3d::new(-1.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0));
+    let b = Line::new(Vector3d::new(0.5, -1.0, 2.0), Vector3d::new(0.5, 1.0, 2.0));
+    let (p, q, distance) = a.closest_points(&b);
+    assert_vec3_almost_eq!(p, Vector3d::new(0.5, 0.0, 0.0));
+    assert_vec3_almost_eq!(q, Vector3d::new(0.5, 0.0, 2.0));
+    assert_almost_eq!(distance, 2.0);
+
+    // Closest points at segment ends.
+    let c = Line::new(Vector3d::new(3.0, 1.0, 0.0), Vector3d::new(3.0, 5.0, 0.0));
+    let (p, q, distance) = a.closest_points(&c);
+    assert_vec3_almost_eq!(p, Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(q, Vector3d::new(3.0, 1.0, 0.0));
+    assert_almost_eq!(distance, 5.0_f64.sqrt());
+
+    // Intersecting segments.
+    let d = Line::new(Vector3d::new(0.0, -1.0, 0.0), Vector3d::new(0.0, 1.0, 0.0));
+    let (p, _, distance) = a.closest_points(&d);
+    assert_vec3_almost_eq!(p, Vector3d::new(0.0, 0.0, 0.0));
+    assert!(distance < 1e-12);
+
+    // Parallel segments, overlapping and apart.
+    let e = Line::new(Vector3d::new(0.0, 0.0, 1.5), Vector3d::new(4.0, 0.0, 1.5));
+    assert_almost_eq!(a.distance_to_line(&e), 1.5);
+    let f = Line::new(Vector3d::new(4.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0));
+    let (p, q, distance) = a.closest_points(&f);
+    assert_vec3_almost_eq!(p, Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(q, Vector3d::new(2.0, 0.0, 0.0));
+    assert_almost_eq!(distance, 1.0);
+
+    // Degenerate segment acts as a point.
+    let point = Line::new(Vector3d::new(0.0, 3.0, 0.0), Vector3d::new(0.0, 3.0, 0.0));
+    assert_almost_eq!(point.distance_to_line(&a), 3.0);
+}
+
+#[test]
+fn line_extended_and_trimmed_to_plane() {
+    let line = Line3d::new((0.0, 0.0, 0.0), (4.0, 0.0, 0.0));
+    let extended = line.extended(1.0, -0.5);
+    assert_vec3_almost_eq!(extended.start(), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(extended.end(), Vector3d::new(3.5, 0.0, 0.0));
+
+    // Support face beyond the end: the end is extended onto it.
+    let face = Plane::new((5.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+    let to_face = line.trim_to_plane(&face).unwrap();
+    assert_vec3_almost_eq!(to_face.start(), line.start());
+    assert_vec3_almost_eq!(to_face.end(), Vector3d::new(5.0, 0.0, 0.0));
+
+    // Plane crossing near the start: the start is trimmed back to it.
+    let edge = Plane::new((1.0, 0.0, 0.0), (-1.0, 0.0, 0.0));
+    let trimmed = line.trim_to_plane(&edge).unwrap();
+    assert_vec3_almost_eq!(trimmed.start(), Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(trimmed.end(), line.end());
+
+    assert!(line.trim_to_plane(&Plane::new((0.0, 0.0, 1.0), (0.0, 0.0, 1.0))).is_none());
+}
+
+#[test]
+fn line_trimmed_to_polygon() {
+    let slab = Polygon::with_holes(
+        vec![(0.0, 0.0, 0.0), (6.0, 0.0, 0.0), (6.0, 4.0, 0.0), (0.0, 4.0, 0.0)],
+        vec![vec![(2.0, 1.0, 0.0), (2.0, 3.0, 0.0), (4.0, 3.0, 0.0), (4.0, 1.0, 0.0)]],
+    );
+    let beam = Line3d::new((-1.0, 2.0, 0.0), (8.0, 2.0, 0.0));
+    let parts = beam.trim_to_polygon(&slab);
+    assert_eq!(parts.len(), 2);
+    assert_vec3_almost_eq!(parts[0].start(), Vector3d::new(0.0, 2.0, 0.0));
+    assert_vec3_almost_eq!(parts[0].end(), Vector3d::new(2.0, 2.0, 0.0));
+    assert_vec3_almost_eq!(parts[1].start(), Vector3d::new(4.0, 2.0, 0.0));
+    assert_vec3_almost_eq!(parts[1].end(), Vector3d::new(6.0, 2.0, 0.0));
+
+    // Along the slab edge the beam is kept.
+    let edge_beam = Line3d::new((-1.0, 0.0, 0.0), (3.0, 0.0, 0.0));
+    let parts = edge_beam.trim_to_polygon(&slab);
+    assert_eq!(parts.len(), 1);
+    assert_vec3_almost_eq!(parts[0].start(), Vector3d::new(0.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(parts[0].end(), Vector3d::new(3.0, 0.0, 0.0));
+
+    assert!(Line3d::new((1.0, 1.0, 1.0), (1.0, 2.0, 1.0)).trim_to_polygon(&slab).is_empty());
+}
+
+#[test]
+fn line_offset_in_plane() {
+    let edge = Line3d::new((0.0, 0.0, 0.0), (6.0, 0.0, 0.0));
+    let slab = Plane::new((0.0, 0.0, 0.0), (0.0, 0.0, 1.0));
+    let beam = edge.offset_in_plane(&slab, 0.5).unwrap();
+    assert_vec3_almost_eq!(beam.start(), Vector3d::new(0.0, 0.5, 0.0));
+    assert_vec3_almost_eq!(beam.end(), Vector3d::new(6.0, 0.5, 0.0));
+    let outside = edge.offset_in_plane(&slab.flipped(), 0.5).unwrap();
+    assert_vec3_almost_eq!(outside.start(), Vector3d::new(0.0, -0.5, 0.0));
+
+    // Only the in-plane part of the direction sets the side.
+    let sloped = Line3d::new((0.0, 0.0, 0.0), (3.0, 0.0, 4.0));
+    let shifted = sloped.offset_in_plane(&slab, -2.0).unwrap();
+    assert_vec3_almost_eq!(shifted.end(), Vector3d::new(3.0, -2.0, 4.0));
+    assert!(Line3d::new((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)).offset_in_plane(&slab, 1.0).is_none());
+
+    let flat = Line2d::new(Vector2d::new(1.0, 1.0), Vector2d::new(1.0, 3.0));
+    let left = flat.offset(2.0).unwrap();
+    assert_almost_eq!(left.start().x(), -1.0);
+    assert_almost_eq!(left.end().y(), 3.0);
+}
+
+#[test]
+fn infinite_line_projects_and_intersects_beyond_any_segment() {
+    let gridline = InfiniteLine3d::new(Vector3d::new(0.0, 5.0, 0.0), Vector3d::new(2.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(gridline.direction(), Vector3d::new(1.0, 0.0, 0.0));
+    let node = Vector3d::new(-250.0, 7.0, 1.0);
+    assert_vec3_almost_eq!(gridline.closest_point(&node), Vector3d::new(-250.0, 5.0, 0.0));
+    assert_almost_eq!(gridline.distance(&node), 5.0_f64.sqrt());
+    assert_almost_eq!(gridline.parameter(&node), -250.0);
+
+    let column = Line::new(Vector3d::new(-100.0, 0.0, 0.0), Vector3d::new(-100.0, 10.0, 0.0));
+    assert_vec3_almost_eq!(gridline.intersection_with_line(&column).unwrap(), Vector3d::new(-100.0, 5.0, 0.0));
+    let short = Line::new(Vector3d::new(3.0, 0.0, 0.0), Vector3d::new(3.0, 4.0, 0.0));
+    assert!(gridline.intersection_with_line(&short).is_none());
+    let skew = Line::new(Vector3d::new(3.0, 5.0, 1.0), Vector3d::new(3.0, 6.0, 1.0));
+    assert!(gridline.intersection_with_line(&skew).is_none());
+
+    let wall = Plane::new((40.0, 0.0, 0.0), (1.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(gridline.intersection_with_plane(&wall).unwrap(), Vector3d::new(35.0, 5.0, 0.0));
+
+    let slab = Polygon::new([
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(10.0, 0.0, 0.0),
+        Vector3d::new(10.0, 10.0, 0.0),
+        Vector3d::new(0.0, 10.0, 0.0),
+    ]);
+    let parts = gridline.clip_to_polygon(&slab);
+    assert_eq!(parts.len(), 1);
+    assert_almost_eq!(parts[0].length(), 10.0);
+    assert_eq!(gridline.intersection_with_polygon(&slab).len(), 2);
+}
@@ -0,0 +1,138 @@
+use geometry::mesh::{triangulate, MeshOptions, TriMesh};
+use geometry::{Polygon, Vector3d};
+use utils::assert_almost_eq;
+
+fn longest_edge(mesh: &TriMesh) -> f64 {
+    mesh.edges().iter().map(|[a, b]| (mesh.vertices()[*a].0 - mesh.vertices()[*b].0).norm()).fold(0.0, f64::max)
+}
+
+/// Every triangle is counterclockwise about `normal` and no vertex lies inside
+/// the circumcircle of a triangle it can see (checked for convex domains only).
+fn assert_delaunay(mesh: &TriMesh, normal: Vector3d) {
+    for i in 0..mesh.triangles().len() {
+        let [a, b, c] = mesh.triangle(i);
+        let cross = (b.0 - a.0).cross(&(c.0 - a.0));
+        assert!(cross.dot(&normal.0) > 0.0, "triangle {i} is not counterclockwise");
+        // Circumcenter in 3D.
+        let (ab, ac) = (b.0 - a.0, c.0 - a.0);
+        let center = a.0 + (cross.cross(&ab) * ac.norm_squared() + ac.cross(&cross) * ab.norm_squared()) / (2.0 * cross.norm_squared());
+        let radius = (a.0 - center).norm();
+        for v in mesh.vertices() {
+            assert!((v.0 - center).norm() >= radius * (1.0 - 1e-9), "triangle {i} is not Delaunay");
+        }
+    }
+}
+
+#[test]
+fn square_without_options_has_two_triangles() {
+    let square = Polygon::new(vec![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0), (0.0, 2.0, 0.0)]);
+    let mesh = triangulate(&square, &MeshOptions::new());
+    assert_eq!(mesh.vertices().len(), 4);
+    assert_eq!(mesh.triangles().len(), 2);
+    assert_almost_eq!(mesh.area(), 4.0);
+    assert_eq!(mesh.vertices()[0], Vector3d::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn refined_rectangle_respects_edge_length() {
+    let rectangle = Polygon::new(vec![(0.0, 0.0, 0.0), (6.0, 0.0, 0.0), (6.0, 3.0, 0.0), (0.0, 3.0, 0.0)]);
+    let mesh = triangulate(&rectangle, &MeshOptions::new().with_max_edge_length(1.0));
+    assert_almost_eq!(mesh.area(), 18.0, 1e-9);
+    assert!(longest_edge(&mesh) <= 1.0 + 1e-9);
+    assert!(mesh.vertices().len() > 18);
+    assert_delaunay(&mesh, Vector3d::new(0.0, 0.0, 1.0));
+    // Euler: a triangulated disk has V - E + F = 1.
+    assert_eq!(mesh.vertices().len() as i64 - mesh.edges().len() as i64 + mesh.triangles().len() as i64, 1);
+}
+
+#[test]
+fn concave_polygon_with_hole_keeps_constraints() {
+    // L-shape with a square hole in its long leg.
+    let shape = Polygon::with_holes(
+        vec![(0.0, 0.0, 0.0), (8.0, 0.0, 0.0), (8.0, 2.0, 0.0), (2.0, 2.0, 0.0), (2.0, 6.0, 0.0), (0.0, 6.0, 0.0)],
+        vec![vec![(4.0, 0.5, 0.0), (4.0, 1.5, 0.0), (5.0, 1.5, 0.0), (5.0, 0.5, 0.0)]],
+    );
+    let mesh = triangulate(&shape, &MeshOptions::new());
+    assert_almost_eq!(mesh.area(), shape.area(), 1e-9);
+    assert_eq!(mesh.vertices().len(), 10);
+    // A triangulated disk with one hole has V - E + F = 0.
+    assert_eq!(mesh.vertices().len() as i64 - mesh.edges().len() as i64 + mesh.triangles().len() as i64, 0);
+    for i in 0..mesh.triangles().len() {
+        let [a, b, c] = mesh.triangle(i);
+        assert!(shape.contains(&Vector3d((a.0 + b.0 + c.0) / 3.0), None));
+    }
+
+    let refined = triangulate(&shape, &MeshOptions::new().with_max_edge_length(0.5));
+    assert_almost_eq!(refined.area(), shape.area(), 1e-9);
+    assert!(longest_edge(&refined) <= 0.5 + 1e-9);
+}
+
+#[test]
+fn interior_points_are_mesh_vertices() {
+    // Tilted square slab in 3D with two column points; one point outside and
+    // one on the boundary are ignored.
+    let slab = Polygon::new(vec![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 0.0, 4.0), (0.0, 0.0, 4.0)]);
+    let columns = vec![Vector3d::new(1.0, 0.0, 1.0), Vector3d::new(3.0, 0.3, 2.5)];
+    let options = MeshOptions::new()
+        .with_points(columns.clone())
+        .with_points([Vector3d::new(5.0, 0.0, 1.0), Vector3d::new(2.0, 0.0, 0.0)]);
+    let mesh = triangulate(&slab, &options);
+    assert_eq!(mesh.vertices().len(), 6);
+    assert!(mesh.vertices().iter().any(|v| v.is_approx(&columns[0], Some(1e-9))));
+    assert!(mesh.vertices().iter().any(|v| v.is_approx(&Vector3d::new(3.0, 0.0, 2.5), Some(1e-9))));
+    assert_almost_eq!(mesh.area(), 16.0, 1e-9);
+    let normal = slab.axis(geometry::Axis::AxisZ);
+    assert_delaunay(&mesh, normal);
+}
+
+#[test]
+fn extruded_polygon_is_a_closed_prism() {
+    let frame = Polygon::with_holes(
+        [(0.0, 0.0, 0.0), (0.4, 0.0, 0.0), (0.4, 0.3, 0.0), (0.0, 0.3, 0.0)],
+        [vec![(0.1, 0.1, 0.0), (0.3, 0.1, 0.0), (0.3, 0.2, 0.0), (0.1, 0.2, 0.0)]],
+    );
+    let area = frame.area();
+
+    let prism = frame.extrude(Vector3d::new(0.0, 0.0, 2.0), 3.0);
+    assert_eq!(prism.vertices().len(), 16);
+    assert_eq!(prism.triangles().len(), 2 * 8 + 2 * 8);
+    assert!(prism.is_closed());
+    assert_almost_eq!(prism.volume().unwrap(), area * 3.0, 1e-9);
+    assert_almost_eq!(prism.area(), 2.0 * area + 3.0 * frame.perimeter(), 1e-9);
+    assert_almost_eq!(prism.vertices()[8].z(), 3.0);
+
+    // Against the normal and oblique: still outward facing, volume from the
+    // height above the base.
+    let sheared = frame.extrude(Vector3d::new(1.0, 0.0, -1.0), 2.0f64.sqrt());
+    assert!(sheared.is_closed());
+    assert_almost_eq!(sheared.volume().unwrap(), area, 1e-9);
+}
+
+#[test]
+fn unit_cube_normals_volume_and_ray_hits() {
+    let square = Polygon::new(vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]);
+    let cube = square.extrude(Vector3d::new(0.0, 0.0, 1.0), 1.0);
+    let center = Vector3d::new(0.5, 0.5, 0.5);
+    for (i, normal) in cube.face_normals().iter().enumerate() {
+        let [a, _, _] = cube.triangle(i);
+        assert_almost_eq!(normal.0.norm(), 1.0);
+        assert!(normal.0.dot(&(a.0 - center.0)) > 0.0, "triangle {i} faces inwards");
+    }
+    assert_almost_eq!(cube.volume().unwrap(), 1.0);
+
+    // From inside, the ray leaves through the top; from below it enters the bottom.
+    let up = Vector3d::new(0.0, 0.0, 1.0);
+    let hit = cube.ray_intersection(&Vector3d::new(0.3, 0.6, 0.25), &up).unwrap();
+    assert_almost_eq!(hit.distance, 0.75);
+    assert_almost_eq!(hit.point.z(), 1.0);
+    assert_almost_eq!(cube.face_normal(hit.triangle).z(), 1.0);
+    let hit = cube.ray_intersection(&Vector3d::new(0.3, 0.6, -2.0), &up).unwrap();
+    assert_almost_eq!(hit.distance, 2.0);
+    assert!(cube.ray_intersection(&Vector3d::new(0.3, 0.6, 2.0), &up).is_none());
+    assert!(cube.ray_intersection(&Vector3d::new(1.5, 0.5, 0.5), &up).is_none());
+
+    // A single triangle is open and has no volume.
+    let open = TriMesh::new(cube.vertices().to_vec(), vec![cube.triangles()[0]]);
+    assert!(!open.is_closed());
+    assert!(open.volume().is_none());
+}
@@ -0,0 +1,79 @@
+use geometry::{Line3d, Plane, Polygon, Ray, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+#[test]
+fn plane_distance_and_projection() {
+    let plane = Plane::new((0.0, 0.0, 2.0), (0.0, 0.0, 3.0));
+    assert_vec3_almost_eq!(plane.normal(), Vector3d::new(0.0, 0.0, 1.0));
+    assert_almost_eq!(plane.signed_distance(&Vector3d::new(4.0, 1.0, 5.0)), 3.0);
+    assert_almost_eq!(plane.signed_distance(&Vector3d::new(4.0, 1.0, -1.0)), -3.0);
+    assert_vec3_almost_eq!(plane.project(&Vector3d::new(4.0, 1.0, 5.0)), Vector3d::new(4.0, 1.0, 2.0));
+    assert!(plane.contains(&Vector3d::new(-7.0, 3.0, 2.0), None));
+
+    let tilted = Plane::from_points((1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)).unwrap();
+    let third = 1.0 / 3.0;
+    assert_vec3_almost_eq!(tilted.project(&Vector3d::new(0.0, 0.0, 0.0)), Vector3d::new(third, third, third));
+    assert_almost_eq!(tilted.distance(&Vector3d::new(0.0, 0.0, 0.0)), 3.0_f64.sqrt() / 3.0);
+    let local = tilted.to_local(Vector3d::new(0.0, 1.0, 0.0));
+    assert_almost_eq!(local.x(), 2.0_f64.sqrt());
+    assert!(local.z().abs() < 1e-12);
+    assert!(Plane::from_points((0.0, 0.0, 0.0), (1.0, 1.0, 1.0), (2.0, 2.0, 2.0)).is_none());
+}
+
+#[test]
+fn plane_line_intersection() {
+    let plane = Plane::new((0.0, 0.0, 1.0), (0.0, 0.0, 1.0));
+    let segment = Line3d::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 2.0, 4.0));
+    assert_vec3_almost_eq!(plane.intersection_with_line(&segment).unwrap(), Vector3d::new(0.5, 0.5, 1.0));
+
+    let short = Line3d::new(Vector3d::new(0.0, 0.0, -2.0), Vector3d::new(0.0, 0.0, -1.0));
+    assert!(plane.intersection_with_line(&short).is_none());
+    assert_vec3_almost_eq!(Ray::from_line(&short).intersection_with_plane(&plane, None).unwrap(), Vector3d::new(0.0, 0.0, 1.0));
+
+    let parallel = Line3d::new(Vector3d::new(0.0, 0.0, 1.0), Vector3d::new(1.0, 0.0, 1.0));
+    assert!(Ray::from_line(&parallel).intersection_with_plane(&plane, None).is_none());
+}
+
+#[test]
+fn plane_plane_intersection() {
+    let floor = Plane::new((0.0, 0.0, 3.0), (0.0, 0.0, 1.0));
+    let wall = Plane::new((2.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+    let line = floor.intersection(&wall).unwrap();
+    assert_almost_eq!(line.length(), 1.0);
+    for point in [line.start(), line.end()] {
+        assert!(floor.contains(&point, None) && wall.contains(&point, None));
+    }
+    assert_vec3_almost_eq!(line.start(), Vector3d::new(2.0, 0.0, 3.0));
+    assert!(floor.intersection(&Plane::new((0.0, 0.0, 5.0), (0.0, 0.0, -1.0))).is_none());
+}
+
+#[test]
+fn polygon_plane() {
+    let polygon = Polygon::new([
+        Vector3d::new(0.0, 0.0, 1.0),
+        Vector3d::new(2.0, 0.0, 1.0),
+        Vector3d::new(2.0, 0.0, 3.0),
+        Vector3d::new(0.0, 0.0, 3.0),
+    ]);
+    let plane = polygon.plane();
+    assert_almost_eq!(plane.normal().y().abs(), 1.0);
+    assert_vec3_almost_eq!(plane.origin(), Vector3d::new(1.0, 0.0, 2.0));
+}
+
+#[test]
+fn line_plane_section_cut() {
+    // Cut a sloped member with a horizontal section plane.
+    let member = Line3d::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 2.0));
+    let cut = Plane::new((0.0, 0.0, 1.5), (0.0, 0.0, 1.0));
+    assert_vec3_almost_eq!(member.intersection_with_plane(&cut).unwrap(), Vector3d::new(3.0, 0.0, 1.5));
+    let above = Plane::new((0.0, 0.0, 2.5), (0.0, 0.0, 1.0));
+    assert!(member.intersection_with_plane(&above).is_none());
+
+    // Two inclined planes meet along a line lying in both.
+    let roof_left = Plane::from_points((0.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 0.0, 1.0)).unwrap();
+    let roof_right = Plane::from_points((2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (1.0, 0.0, 1.0)).unwrap();
+    let ridge = roof_left.intersection(&roof_right).unwrap();
+    assert_almost_eq!(ridge.start().x(), 1.0);
+    assert_almost_eq!(ridge.start().z(), 1.0);
+    assert_almost_eq!(ridge.direction().unwrap().y().abs(), 1.0);
+}
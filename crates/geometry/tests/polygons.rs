@@ -1,5 +1,6 @@
-use geometry::{Axis, Line, Polygon, Vector2d, Vector3d};
-use utils::assert_almost_eq;
+use geometry::{Arc, Axis, BoundaryFeature, DistanceTo, Edge, EdgeLoopError, GeometryError, Line, OffsetJoin, Plane, Polygon, Ray, Vector2d, Vector3d};
+use std::f64::consts::PI;
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
 
 #[test]
 fn square_xy_metrics_axes_and_contains() {
@@ -29,9 +30,9 @@ fn square_xy_metrics_axes_and_contains() {
     assert_almost_eq!(ez.y(), 0.0);
     assert_almost_eq!(ez.z(), 1.0);
 
-    assert!(poly.contains(&Vector3d::new(1.0, 1.0, 0.0)));
-    assert!(poly.border_contains(&Vector3d::new(0.0, 1.0, 0.0)));
-    assert!(!poly.contains(&Vector3d::new(3.0, 3.0, 0.0)));
+    assert!(poly.contains(&Vector3d::new(1.0, 1.0, 0.0), None));
+    assert!(poly.border_contains(&Vector3d::new(0.0, 1.0, 0.0), None));
+    assert!(!poly.contains(&Vector3d::new(3.0, 3.0, 0.0), None));
 }
 
 #[test]
@@ -44,7 +45,7 @@ fn polygon_line_intersection_and_transforms() {
     ]);
 
     let line = Line::new(Vector3d::new(0.5, 0.5, -2.0), Vector3d::new(0.5, 0.5, 2.0));
-    let hits = poly.intersection_with_line(&line, false);
+    let hits = poly.intersection_with_line(&line, None);
     assert_almost_eq!(hits.len() as f64, 1.0);
     let p = hits[0];
     assert_almost_eq!(p.x(), 0.5);
@@ -80,7 +81,7 @@ fn polygon_axes_and_validity_examples() {
     assert_almost_eq!(dirz.x(), 0.0);
     assert_almost_eq!(dirz.y(), 0.0);
     assert_almost_eq!(dirz.z(), 1.0);
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // square 1 (simple axis-aligned)
     let p = Polygon::new([v1, v2, v4, v3]);
@@ -96,11 +97,11 @@ fn polygon_axes_and_validity_examples() {
     assert_almost_eq!(dirz.x(), 0.0);
     assert_almost_eq!(dirz.y(), 0.0);
     assert_almost_eq!(dirz.z(), 1.0);
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // square 2: self-intersecting order -> invalid
     let p = Polygon::new([v1, v4, v2, v3]);
-    assert!(!p.is_valid());
+    assert!(!p.is_valid(None));
 
     // square 3: translated in Z, non-planar input last is projected
     let v1 = Vector3d::new(0.0, 0.0, -1.0);
@@ -121,7 +122,7 @@ fn polygon_axes_and_validity_examples() {
     assert_almost_eq!(dirz.x(), 0.0);
     assert_almost_eq!(dirz.y(), 0.0);
     assert_almost_eq!(dirz.z(), 1.0);
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 }
 
 #[test]
@@ -141,7 +142,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.9345018409130872, -0.33205861288334715, 0.1282317704004688));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.3532031024386168, -0.8202752406640758, 0.44988453849985616));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.044202689485902, -0.46570978856309214, -0.8838328547178662));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // triangle 2
     let v1 = Vector3d::new(-2.480034, -1.498400, -1.463015);
@@ -151,7 +152,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.8738977444125401, -0.4451349668204331, -0.1953396877862937));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.23536062221153137, 0.035850002044947905, 0.9712467013408992));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.4253329399692381, 0.8947455720299202, -0.1360964786832314));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // triangle 3
     let v1 = Vector3d::new(2.804859, 3.344900, 2.990635);
@@ -161,7 +162,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.5174532293996775, -0.7849748182854731, -0.340670940999571));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.373531982697303, 0.15097710863715338, -0.915248474770533));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.769880518837869, -0.6008497710120673, 0.21508960781190695));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // triangle 4
     let v1 = Vector3d::new(-0.023743, -1.718483, 0.162244);
@@ -171,7 +172,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.4562157102950194, 0.8729651166480851, 0.17262424741503224));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.8639480811079011, -0.38803235900871336, -0.320974456167634));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.2132157096228755, 0.2955719768139874, -0.9312229956848602));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // square 1
     let v1 = Vector3d::new(0.466704, -1.308872, -0.402346);
@@ -182,7 +183,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.9333507669921716, -0.03347209907582006, -0.357401684856946));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.13725619607812056, 0.8867074592154259, -0.4414868269947457));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.33168823071265235, -0.46111766432786155, -0.8230148341625002));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // square 2
     let v1 = Vector3d::new(1.820030, 1.625624, 1.968143);
@@ -193,7 +194,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.7363923325025404, 0.17767845588736159, 0.6528067853086018));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.6097879444370069, -0.5922704466883776, -0.5266634416767879));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.2930614192464742, -0.7859046279880483, 0.5444895960954715));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // square 3
     let v1 = Vector3d::new(0.477334, 0.986900, -1.312910);
@@ -204,7 +205,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.2713875914355038, 0.27110387198728725, 0.9234995754240154));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.5856119163029412, 0.8079734417165727, -0.0650968583318195));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.7638111407270995, 0.5231458764763196, -0.3780356242829376));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // square 4
     let v1 = Vector3d::new(1.660860, 0.312090, 0.080616);
@@ -215,7 +216,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.37527702413043856, -0.926373278504709, 0.031618096594187704));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.88387778289721, 0.3473721935208172, -0.31319742027872505));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.2791544734739436, -0.14548232896463065, -0.9491615625901167));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // pentagon 1
     let v1 = Vector3d::new(-1.901807, 1.981353, -0.252286);
@@ -227,7 +228,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.1397320198790167, -0.31991096881017955, -0.9370869408200407));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.45849203101134806, 0.8597105836782297, -0.22512834075417226));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.8776445864469393, -0.3981892569451993, 0.26680572620131465));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // pentagon 2
     let v1 = Vector3d::new(-0.627362, 2.820809, 0.013486);
@@ -239,7 +240,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.49464705217703553, -0.7724007441518211, 0.398398524352541));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.3629958314708085, -0.2328932696861457, -0.9022165766985843));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.789657090202694, -0.5908957736933048, -0.16517828102993817));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // pentagon 3
     let v1 = Vector3d::new(-1.618389, 2.812376, -2.307240);
@@ -251,7 +252,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.2133344492701482, -0.9194973920636131, -0.3301711052512268));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.2987235921637419, -0.26037399043914927, 0.9181337596383133));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.9301895657549557, -0.29449945855808957, 0.21912882208782833));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // pentagon 4
     let v1 = Vector3d::new(1.410699, 0.438958, -2.088725);
@@ -263,7 +264,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.22947998577874223, -0.9157146643798515, -0.3298569228721512));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.35157905223012453, -0.23803175446001687, 0.9053911054906899));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.9075963343689485, -0.32373992232714727, 0.2673225701875182));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // hexagon 1
     let v1 = Vector3d::new(2.558109, -0.859465, -0.350182);
@@ -276,7 +277,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.05248388400442096, -0.3583733812753347, 0.9321019051118253));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.933095068201001, 0.3149398238279995, 0.17362747785462476));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.3557794761067311, 0.8788523351283037, 0.31786716946037014));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // hexagon 2
     let v1 = Vector3d::new(-3.551911, -1.056528, 3.557519);
@@ -289,7 +290,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.17286750764397454, -0.8745152547162658, -0.45314445165918693));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.8955342557064535, 0.05197936142659652, -0.4419463121714803));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.41004295099080623, -0.4822045367416487, 0.7741728250775297));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // hexagon 3
     let v1 = Vector3d::new(2.843711, 1.362341, 1.418280);
@@ -302,7 +303,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.501331603114364, 0.16592507121721117, 0.8491969703551381));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.3969849368396265, -0.8279372122525577, 0.39613499276118097));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.7688104991746794, -0.5357133966114964, -0.3492013359793371));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // hexagon 4
     let v1 = Vector3d::new(-1.619455, 0.220686, -3.132679);
@@ -315,7 +316,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.4575329518558935, -0.4515365885086079, 0.7660145606997565));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(0.4927992260170934, -0.8458332563800127, -0.2042425647080875));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(0.7401435812077182, 0.28404367910515405, 0.609513467904871));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // heptagon 1
     let v1 = Vector3d::new(0.312292, 1.380112, -0.955770);
@@ -329,7 +330,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.7112018052835161, -0.1332194964582779, -0.6902496344981798));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.44414780809942095, -0.8462373964823949, -0.29430425303616986));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.5449079892256562, 0.5158825782657337, -0.6610147114247545));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // heptagon 2
     let v1 = Vector3d::new(0.360931, 1.014467, 3.218626);
@@ -343,7 +344,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.5970858865786243, 0.19915252915566556, -0.7770628765933425));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.7480649450443138, -0.21151862100061375, -0.6290140785116392));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.2896330126979072, 0.9568689268133335, 0.022684242430229585));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // heptagon 3
     let v1 = Vector3d::new(-2.857029, 1.215964, -2.075082);
@@ -357,7 +358,7 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(0.990402420629914, 0.09664979812654678, 0.0988021342507567));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.13789363867082355, 0.7395877053992996, 0.6587832499664203));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.009401575640664569, -0.6660847112374644, 0.7458168460360604));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 
     // heptagon 4
     let v1 = Vector3d::new(-1.246985, -2.606753, -1.902219);
@@ -371,10 +372,11 @@ fn polygon_direction_and_validity_reference_examples() {
     assert_vec3_approx(p.direction(Axis::AxisX), Vector3d::new(-0.6237388859176921, 0.6069727436702197, 0.4924772996145123));
     assert_vec3_approx(p.direction(Axis::AxisY), Vector3d::new(-0.7627614437107425, -0.33505340733135097, -0.5531131839180435));
     assert_vec3_approx(p.direction(Axis::AxisZ), Vector3d::new(-0.17071842953372066, -0.7206408971721615, 0.6719612452667508));
-    assert!(p.is_valid());
+    assert!(p.is_valid(None));
 }
 
 #[test]
+#[allow(deprecated)]
 fn polygon_geometry_properties_reference_examples() {
     // Helper: compare vectors approximately
     fn approx_vec3(a: Vector3d, b: Vector3d) { assert!(a.is_approx(&b, None), "expected {:?} ~ {:?}", a, b); }
@@ -887,3 +889,770 @@ fn polygon_geometry_properties_reference_examples() {
     assert!(d[(0,1)].abs() < 1e-9 && d[(1,0)].abs() < 1e-9);
     // Eigenvalues may suffer tiny negative drift numerically; we only check diagonalization.
 }
+
+#[test]
+fn polygon_boolean_operations() {
+    let rect = |x0: f64, y0: f64, x1: f64, y1: f64| {
+        Polygon::new([
+            Vector3d::new(x0, y0, 0.0),
+            Vector3d::new(x1, y0, 0.0),
+            Vector3d::new(x1, y1, 0.0),
+            Vector3d::new(x0, y1, 0.0),
+        ])
+    };
+
+    // Tee built from a flange and a web sharing part of an edge.
+    let flange = rect(-60.0, 88.0, 60.0, 100.0);
+    let web = rect(-4.0, 0.0, 4.0, 88.0);
+    let tee = flange.union(&web);
+    assert_eq!(tee.len(), 1);
+    assert_almost_eq!(tee[0].area(), 120.0 * 12.0 + 8.0 * 88.0);
+    assert_eq!(tee[0].vertices().len(), 8);
+    let expected_cy = (1440.0 * 94.0 + 704.0 * 44.0) / 2144.0;
+    assert_almost_eq!(tee[0].centroid().y(), expected_cy);
+
+    // Clipping a load area against a panel.
+    let panel = rect(0.0, 0.0, 4.0, 3.0);
+    let load = rect(2.0, 1.0, 6.0, 5.0);
+    let clipped = panel.intersection(&load);
+    assert_eq!(clipped.len(), 1);
+    assert_almost_eq!(clipped[0].area(), 4.0);
+    assert!(clipped[0].contains(&Vector3d::new(3.0, 2.0, 0.0), None));
+    let remainder = panel.difference(&load);
+    assert_eq!(remainder.len(), 1);
+    assert_almost_eq!(remainder[0].area(), 8.0);
+
+    // Disjoint pieces stay separate; nothing is shared.
+    let far = rect(10.0, 10.0, 11.0, 11.0);
+    assert_eq!(panel.union(&far).len(), 2);
+    assert!(panel.intersection(&far).is_empty());
+
+    // Enclosed opening keeps area and centroid of the holed region.
+    let opening = rect(1.0, 1.0, 2.0, 2.0);
+    let holed = panel.difference(&opening);
+    assert_eq!(holed.len(), 1);
+    assert_eq!(holed[0].holes().len(), 1);
+    assert_almost_eq!(holed[0].area(), 11.0);
+    assert_almost_eq!(holed[0].centroid().x(), (12.0 * 2.0 - 1.5) / 11.0);
+
+    // Operations happen in the shared plane for tilted polygons too.
+    let tilt = |p: Vector3d| Vector3d::new(p.x(), p.y() * 0.6, p.y() * 0.8);
+    let a = Polygon::new(panel.vertices().iter().map(|v| tilt(*v)));
+    let b = Polygon::new(load.vertices().iter().map(|v| tilt(*v)));
+    let clipped = a.intersection(&b);
+    assert_eq!(clipped.len(), 1);
+    assert_almost_eq!(clipped[0].area(), 4.0);
+
+    // Non-coplanar polygons do not overlap.
+    let lifted = Polygon::new(load.vertices().iter().map(|v| Vector3d::new(v.x(), v.y(), 1.0)));
+    assert!(panel.intersection(&lifted).is_empty());
+    assert_eq!(panel.difference(&lifted).len(), 1);
+}
+
+#[test]
+fn polygon_with_holes() {
+    let outer = [
+        Vector2d::new(0.0, 0.0),
+        Vector2d::new(4.0, 0.0),
+        Vector2d::new(4.0, 3.0),
+        Vector2d::new(0.0, 3.0),
+    ];
+    // Hole given with the same orientation as the outer ring; it is flipped internally.
+    let hole = [
+        Vector2d::new(1.5, 1.0),
+        Vector2d::new(2.5, 1.0),
+        Vector2d::new(2.5, 2.0),
+        Vector2d::new(1.5, 2.0),
+    ];
+    let poly = Polygon::with_holes(outer, [hole]);
+    assert!(poly.has_holes());
+    assert!(poly.is_valid(None));
+    assert_almost_eq!(poly.area(), 11.0);
+    assert_almost_eq!(poly.perimeter(), 18.0);
+    assert_almost_eq!(poly.centroid().x(), 2.0);
+    assert_almost_eq!(poly.centroid().y(), 1.5);
+    assert_eq!(poly.boundary_lines().len(), 8);
+
+    let inertia = poly.centroidal_local_second_moment_of_area();
+    assert_almost_eq!(inertia[(0, 0)], 4.0 * 27.0 / 12.0 - 1.0 / 12.0);
+    assert_almost_eq!(inertia[(1, 1)], 3.0 * 64.0 / 12.0 - 1.0 / 12.0);
+    assert_almost_eq!(inertia[(0, 1)], 0.0);
+
+    let void = Vector3d::new(2.0, 1.5, 0.0);
+    assert!(!poly.contains(&void, None));
+    assert!(poly.contains(&Vector3d::new(0.5, 1.5, 0.0), None));
+    assert!(poly.border_contains(&Vector3d::new(1.5, 1.5, 0.0), None));
+    let through = Line::new(Vector3d::new(2.0, 1.5, -1.0), Vector3d::new(2.0, 1.5, 1.0));
+    assert!(poly.intersection_with_line(&through, None).is_empty());
+    let closest = poly.closest_point(&void);
+    assert_almost_eq!((closest.0 - void.0).norm(), 0.5);
+
+    let mut flipped = poly.clone();
+    flipped.reverse();
+    assert_almost_eq!(flipped.area(), 11.0);
+
+    let crossing = Polygon::with_holes(
+        outer,
+        [[Vector2d::new(3.0, 1.0), Vector2d::new(5.0, 1.0), Vector2d::new(5.0, 2.0), Vector2d::new(3.0, 2.0)]],
+    );
+    assert!(!crossing.is_valid(None));
+}
+
+#[test]
+fn polygon_offset() {
+    let rect = |x0: f64, y0: f64, w: f64, h: f64| {
+        Polygon::new([
+            Vector2d::new(x0, y0),
+            Vector2d::new(x0 + w, y0),
+            Vector2d::new(x0 + w, y0 + h),
+            Vector2d::new(x0, y0 + h),
+        ])
+    };
+    let square = rect(0.0, 0.0, 10.0, 10.0);
+
+    // Mitred growth keeps sharp corners.
+    let grown = square.offset(1.0);
+    assert_eq!(grown.len(), 1);
+    assert_eq!(grown[0].vertices().len(), 4);
+    assert_almost_eq!(grown[0].area(), 144.0, 1e-9);
+    assert_almost_eq!(grown[0].centroid().x(), 5.0, 1e-9);
+
+    // Round joins add quarter circles (inscribed chords) at the corners.
+    let rounded = square.offset_with(1.0, OffsetJoin::Round { segments: 8 });
+    let chord_area = 4.0 * 8.0 * 0.5 * (std::f64::consts::FRAC_PI_2 / 8.0).sin();
+    assert_eq!(rounded.len(), 1);
+    assert_almost_eq!(rounded[0].area(), 100.0 + 40.0 + chord_area, 1e-9);
+    let bevelled = square.offset_with(1.0, OffsetJoin::Bevel);
+    assert_almost_eq!(bevelled[0].area(), 100.0 + 40.0 + 2.0, 1e-9);
+
+    // Cover line inside a section.
+    let cover = square.offset(-1.5);
+    assert_eq!(cover.len(), 1);
+    assert_almost_eq!(cover[0].area(), 49.0, 1e-9);
+    assert!(square.offset(-5.0).is_empty());
+
+    // Concave corner of an L shape: shrinking rounds or mitres the re-entrant corner.
+    let l_shape = Polygon::new([
+        Vector2d::new(0.0, 0.0),
+        Vector2d::new(4.0, 0.0),
+        Vector2d::new(4.0, 1.0),
+        Vector2d::new(1.0, 1.0),
+        Vector2d::new(1.0, 4.0),
+        Vector2d::new(0.0, 4.0),
+    ]);
+    let mitred = l_shape.offset(-0.25);
+    assert_eq!(mitred.len(), 1);
+    assert_almost_eq!(mitred[0].area(), 2.0 * 3.5 * 0.5 - 0.25, 1e-9);
+    let round = l_shape.offset_with(-0.25, OffsetJoin::Round { segments: 16 });
+    assert!(round[0].area() > mitred[0].area());
+    assert!(round[0].area() < mitred[0].area() + 0.0625);
+
+    // Growing the L keeps its concave corner sharp.
+    let grown_l = l_shape.offset(0.5);
+    assert_almost_eq!(grown_l[0].area(), 5.0 * 2.0 + 2.0 * 3.0, 1e-9);
+
+    // Thin parts vanish, splitting the polygon.
+    let dumbbell = rect(0.0, 0.0, 3.0, 3.0)
+        .union(&rect(3.0, 1.25, 2.0, 0.5))
+        .remove(0)
+        .union(&rect(5.0, 0.0, 3.0, 3.0))
+        .remove(0);
+    assert_eq!(dumbbell.offset(-0.5).len(), 2);
+
+    // Holes shrink when the polygon grows.
+    let frame = Polygon::with_holes(
+        [Vector2d::new(0.0, 0.0), Vector2d::new(6.0, 0.0), Vector2d::new(6.0, 6.0), Vector2d::new(0.0, 6.0)],
+        [[Vector2d::new(2.0, 2.0), Vector2d::new(4.0, 2.0), Vector2d::new(4.0, 4.0), Vector2d::new(2.0, 4.0)]],
+    );
+    let thick = frame.offset(0.5);
+    assert_eq!(thick.len(), 1);
+    assert_eq!(thick[0].holes().len(), 1);
+    assert_almost_eq!(thick[0].area(), 49.0 - 1.0, 1e-9);
+}
+
+#[test]
+fn polygon_closest_boundary_point() {
+    let outer = [
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(4.0, 0.0, 0.0),
+        Vector3d::new(4.0, 3.0, 0.0),
+        Vector3d::new(0.0, 3.0, 0.0),
+    ];
+    let hole = [
+        Vector3d::new(1.0, 1.0, 0.0),
+        Vector3d::new(1.0, 2.0, 0.0),
+        Vector3d::new(2.0, 2.0, 0.0),
+        Vector3d::new(2.0, 1.0, 0.0),
+    ];
+    let polygon = Polygon::with_holes(outer, [hole]);
+
+    // Outside, above the plane, nearest to the bottom edge.
+    let hit = polygon.closest_boundary_point(&Vector3d::new(3.0, -2.0, 5.0), None);
+    assert_eq!(hit.feature, BoundaryFeature::Edge { ring: 0, index: 0 });
+    assert_almost_eq!(hit.parameter, 0.75);
+    assert_almost_eq!(hit.signed_distance, 2.0);
+    assert_almost_eq!(hit.plane_offset.abs(), 5.0);
+
+    // Outside beyond a corner.
+    let hit = polygon.closest_boundary_point(&Vector3d::new(5.0, 4.0, 0.0), None);
+    assert_eq!(hit.feature, BoundaryFeature::Vertex { ring: 0, index: 2 });
+    assert_almost_eq!(hit.signed_distance, 2.0_f64.sqrt());
+
+    // Inside the material, closer to the hole than to the outer ring.
+    let hit = polygon.closest_boundary_point(&Vector3d::new(1.5, 2.25, 0.0), None);
+    assert_eq!(hit.feature, BoundaryFeature::Edge { ring: 1, index: 1 });
+    assert_almost_eq!(hit.signed_distance, -0.25);
+
+    // Inside the hole counts as outside.
+    let hit = polygon.closest_boundary_point(&Vector3d::new(1.5, 1.25, 0.0), None);
+    assert!(matches!(hit.feature, BoundaryFeature::Edge { ring: 1, .. }));
+    assert_almost_eq!(hit.signed_distance, 0.25);
+
+    // On the boundary.
+    let hit = polygon.closest_boundary_point(&Vector3d::new(0.0, 1.5, 0.0), None);
+    assert_eq!(hit.feature, BoundaryFeature::Edge { ring: 0, index: 3 });
+    assert!(hit.signed_distance.abs() < 1e-12);
+
+    // Vertex snapping is a distance: the same offset snaps on a short or a long edge.
+    let hit = polygon.closest_boundary_point(&Vector3d::new(4.0, 3.0 - 1e-7, 0.0), Some(1e-6));
+    assert_eq!(hit.feature, BoundaryFeature::Vertex { ring: 0, index: 2 });
+    let long = Polygon::new(outer.map(|v| v * 1e3));
+    let hit = long.closest_boundary_point(&Vector3d::new(4e3, 3e3 - 1e-7, 0.0), Some(1e-6));
+    assert_eq!(hit.feature, BoundaryFeature::Vertex { ring: 0, index: 2 });
+    let hit = long.closest_boundary_point(&Vector3d::new(4e3, 3e3 - 1e-3, 0.0), Some(1e-6));
+    assert_eq!(hit.feature, BoundaryFeature::Edge { ring: 0, index: 1 });
+}
+
+#[test]
+fn polygon_clip_by_plane() {
+    let v = |x: f64, y: f64| Vector3d::new(x, y, 0.0);
+    // U shape: two 1 m wide legs joined by a 1 m deep base, 3 m wide and 3 m tall.
+    let u = Polygon::new([v(0.0, 0.0), v(3.0, 0.0), v(3.0, 3.0), v(2.0, 3.0), v(2.0, 1.0), v(1.0, 1.0), v(1.0, 3.0), v(0.0, 3.0)]);
+
+    let upper = u.clip_by_plane(&Plane::new((0.0, 2.0, 0.0), (0.0, 1.0, 0.0)));
+    assert_eq!(upper.len(), 2);
+    assert!(upper.iter().all(|p| (p.area() - 1.0).abs() < 1e-9));
+
+    let lower = u.clip_by_plane(&Plane::new((0.0, 2.0, 0.0), (0.0, -1.0, 0.0)));
+    assert_eq!(lower.len(), 1);
+    assert_almost_eq!(lower[0].area(), 5.0);
+
+    // A vertical plane through the hole leaves a hole-free C shape.
+    let holed = Polygon::with_holes(
+        [v(0.0, 0.0), v(4.0, 0.0), v(4.0, 4.0), v(0.0, 4.0)],
+        [vec![v(1.0, 1.0), v(1.0, 3.0), v(3.0, 3.0), v(3.0, 1.0)]],
+    );
+    let left = holed.clip_by_plane(&Plane::new((2.0, 0.0, 0.0), (-1.0, 0.0, 0.0)));
+    assert_eq!(left.len(), 1);
+    assert!(!left[0].has_holes());
+    assert_almost_eq!(left[0].area(), 6.0);
+
+    // An oblique plane cuts the square diagonally; whole and empty cases are exact.
+    let diagonal = holed.clip_by_plane(&Plane::new((0.0, 0.0, 0.0), (1.0, -1.0, 0.5)));
+    assert_almost_eq!(diagonal.iter().map(|p| p.area()).sum::<f64>(), 6.0);
+    assert_eq!(holed.clip_by_plane(&Plane::new((0.0, 0.0, -1.0), (0.0, 0.0, 1.0))), vec![holed.clone()]);
+    assert!(holed.clip_by_plane(&Plane::new((0.0, 0.0, 1.0), (0.0, 0.0, 1.0))).is_empty());
+}
+
+#[test]
+fn polygon_triad_sits_at_centroid() {
+    // Wall outline in the XZ plane.
+    let wall = Polygon::new(vec![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 0.0, 2.0), (0.0, 0.0, 2.0)]);
+    let triad = wall.triad_geometry(0.5);
+    for (arm, axis) in triad.iter().zip([Axis::AxisX, Axis::AxisY, Axis::AxisZ]) {
+        assert_eq!(arm.axis, axis);
+        assert_eq!(arm.color, axis.color());
+        assert!(arm.line.start().is_approx(&Vector3d::new(2.0, 0.0, 1.0), None));
+        assert_almost_eq!(arm.line.length(), 0.5);
+        assert!(arm.line.direction().unwrap().is_approx(&wall.axis(axis), None));
+    }
+    assert_eq!(triad[0].color, [255, 0, 0]);
+    assert_almost_eq!(triad[2].line.direction().unwrap().y().abs(), 1.0);
+}
+
+fn square(x: f64, y: f64, size: f64) -> Polygon {
+    Polygon::new(vec![(x, y, 0.0), (x + size, y, 0.0), (x + size, y + size, 0.0), (x, y + size, 0.0)])
+}
+
+fn has_point(points: &[Vector3d], expected: Vector3d) -> bool { points.iter().any(|p| p.is_approx(&expected, Some(1e-9))) }
+
+#[test]
+fn coplanar_overlap_and_crossings() {
+    let (a, b) = (square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0));
+    assert!(a.is_coplanar(&b));
+    assert!(a.intersects(&b));
+    assert_almost_eq!(a.overlap_area(&b), 1.0);
+    let crossings = a.crossing_points(&b);
+    assert_eq!(crossings.len(), 2);
+    assert!(has_point(&crossings, Vector3d::new(2.0, 1.0, 0.0)));
+    assert!(has_point(&crossings, Vector3d::new(1.0, 2.0, 0.0)));
+
+    let far = square(5.0, 0.0, 1.0);
+    assert!(!a.intersects(&far));
+    assert_almost_eq!(a.overlap_area(&far), 0.0);
+    assert!(a.crossing_points(&far).is_empty());
+}
+
+#[test]
+fn coplanar_touching_and_nested_polygons() {
+    // Shared edge: touching without overlap.
+    let (left, right) = (square(0.0, 0.0, 1.0), square(1.0, 0.0, 1.0));
+    assert!(left.intersects(&right));
+    assert_almost_eq!(left.overlap_area(&right), 0.0);
+    let shared = left.crossing_points(&right);
+    assert_eq!(shared.len(), 2);
+    assert!(has_point(&shared, Vector3d::new(1.0, 0.0, 0.0)) && has_point(&shared, Vector3d::new(1.0, 1.0, 0.0)));
+
+    // Nested: no boundary crossings, but the panels overlap.
+    let (outer, inner) = (square(0.0, 0.0, 10.0), square(2.0, 2.0, 1.0));
+    assert!(outer.crossing_points(&inner).is_empty());
+    assert!(outer.intersects(&inner) && inner.intersects(&outer));
+    assert_almost_eq!(outer.overlap_area(&inner), 1.0);
+
+    // Inside a hole: no contact at all.
+    let slab = Polygon::with_holes(
+        vec![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)],
+        vec![vec![(1.0, 1.0, 0.0), (1.0, 5.0, 0.0), (5.0, 5.0, 0.0), (5.0, 1.0, 0.0)]],
+    );
+    assert!(!slab.intersects(&inner));
+    assert_almost_eq!(slab.overlap_area(&inner), 0.0);
+}
+
+#[test]
+fn wall_crossing_slab() {
+    let slab = square(0.0, 0.0, 4.0);
+    let wall = Polygon::new(vec![(1.0, 2.0, -1.0), (3.0, 2.0, -1.0), (3.0, 2.0, 1.0), (1.0, 2.0, 1.0)]);
+    assert!(!slab.is_coplanar(&wall));
+    assert!(slab.intersects(&wall));
+    assert_almost_eq!(slab.overlap_area(&wall), 0.0);
+    let crossings = slab.crossing_points(&wall);
+    assert_eq!(crossings.len(), 2);
+    assert!(has_point(&crossings, Vector3d::new(1.0, 2.0, 0.0)));
+    assert!(has_point(&crossings, Vector3d::new(3.0, 2.0, 0.0)));
+
+    // Wall standing on the slab touches it along its base.
+    let standing = Polygon::new(vec![(1.0, 2.0, 0.0), (3.0, 2.0, 0.0), (3.0, 2.0, 3.0), (1.0, 2.0, 3.0)]);
+    assert!(slab.intersects(&standing));
+
+    let above = Polygon::new(vec![(1.0, 2.0, 1.0), (3.0, 2.0, 1.0), (3.0, 2.0, 3.0), (1.0, 2.0, 3.0)]);
+    assert!(!slab.intersects(&above));
+    assert!(slab.crossing_points(&above).is_empty());
+}
+
+#[test]
+fn rotated_and_translated_polygons_keep_their_frame() {
+    let mut slab = Polygon::with_holes(
+        [(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (0.0, 2.0, 0.0)],
+        [vec![(1.0, 0.5, 0.0), (2.0, 0.5, 0.0), (2.0, 1.5, 0.0), (1.0, 1.5, 0.0)]],
+    );
+    slab.reverse();
+    let hinge = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0));
+
+    let wall = slab.rotated_about(&hinge, std::f64::consts::FRAC_PI_2);
+    assert_almost_eq!(wall.area(), slab.area());
+    assert_almost_eq!(wall.perimeter(), slab.perimeter());
+    assert_vec3_almost_eq!(wall.centroid(), Vector3d::new(14.5 / 7.0, 0.0, 1.0));
+    assert_vec3_almost_eq!(wall.vertices()[1], Vector3d::new(4.0, 0.0, 2.0));
+    assert_vec3_almost_eq!(wall.holes()[0][0], Vector3d::new(1.0, 0.0, 0.5));
+    // The reversed frame (normal -Z, local x along +X) is rotated, not rebuilt.
+    assert_vec3_almost_eq!(wall.axis(Axis::AxisX), Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(wall.axis(Axis::AxisZ), Vector3d::new(0.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(wall.axis(Axis::AxisY), Vector3d::new(0.0, 0.0, -1.0));
+    assert!(wall.contains(&Vector3d::new(3.0, 0.0, 1.0), None));
+    assert!(!wall.contains(&Vector3d::new(1.5, 0.0, 1.0), None));
+    let pin = Line::new(Vector3d::new(1.0, 1.0, 0.0), Vector3d::new(1.0, 1.0, 0.0));
+    assert_eq!(slab.rotated_about(&pin, 1.0), slab);
+
+    let moved = wall.translated((1.0, 2.0, 3.0));
+    assert_vec3_almost_eq!(moved.centroid(), Vector3d(wall.centroid().0 + Vector3d::new(1.0, 2.0, 3.0).0));
+    assert_vec3_almost_eq!(moved.axis(Axis::AxisY), wall.axis(Axis::AxisY));
+    assert_almost_eq!(moved.area(), wall.area());
+
+    let plan = Polygon::new([Vector2d::new(0.0, 0.0), Vector2d::new(2.0, 0.0), Vector2d::new(2.0, 1.0)]);
+    let shifted = plan.translated(Vector2d::new(1.0, 1.0));
+    assert_almost_eq!(shifted.vertices()[2].x(), 3.0);
+    assert_almost_eq!(shifted.vertices()[2].y(), 2.0);
+}
+
+#[test]
+fn polygons_move_rotate_and_scale_in_place() {
+    let rebuilt = |p: &Polygon| Polygon::with_holes(p.vertices().clone(), p.holes().to_vec());
+    let mut slab = Polygon::with_holes(
+        [(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (0.0, 2.0, 0.0)],
+        [vec![(1.0, 0.5, 0.0), (2.0, 0.5, 0.0), (2.0, 1.5, 0.0), (1.0, 1.5, 0.0)]],
+    );
+
+    slab.translate((1.0, 0.0, 2.0));
+    assert_vec3_almost_eq!(slab.vertices()[0], Vector3d::new(1.0, 0.0, 2.0));
+    slab.rotate(std::f64::consts::FRAC_PI_2, (1.0, 0.0, 0.0), (1.0, 0.0, 2.0));
+    assert_vec3_almost_eq!(slab.vertices()[2], Vector3d::new(5.0, 0.0, 4.0));
+    assert_vec3_almost_eq!(slab.axis(Axis::AxisZ), Vector3d::new(0.0, -1.0, 0.0));
+    slab.scale(2.0, (1.0, 0.0, 2.0));
+    assert_vec3_almost_eq!(slab.vertices()[2], Vector3d::new(9.0, 0.0, 6.0));
+
+    // The cached properties match a polygon built from the moved rings.
+    let fresh = rebuilt(&slab);
+    assert_almost_eq!(slab.area(), 4.0 * 7.0);
+    assert_almost_eq!(slab.area(), fresh.area());
+    assert_almost_eq!(slab.perimeter(), fresh.perimeter());
+    assert_vec3_almost_eq!(slab.centroid(), fresh.centroid());
+    assert_vec3_almost_eq!(slab.axis(Axis::AxisZ), fresh.axis(Axis::AxisZ));
+    assert!((slab.about_centroid_local() - fresh.about_centroid_local()).norm() < 1e-9);
+}
+
+#[test]
+fn inertia_about_centroid_point_and_axis() {
+    // 2 × 1 rectangle in the plane x = 1, centroid (1, 1, 0.5).
+    let p = Polygon::new([(1.0, 0.0, 0.0), (1.0, 2.0, 0.0), (1.0, 2.0, 1.0), (1.0, 0.0, 1.0)]);
+    let local = p.about_centroid_local();
+    assert_almost_eq!(local[(0, 0)], 2.0 / 12.0);
+    assert_almost_eq!(local[(1, 1)], 8.0 / 12.0);
+    assert_almost_eq!(local[(0, 1)], 0.0);
+    assert_eq!(p.centroidal_local_second_moment_of_area(), local);
+
+    let global = p.about_centroid_global();
+    assert_eq!(p.centroidal_second_moment_of_area(), global);
+    assert_almost_eq!(global[(0, 0)], 10.0 / 12.0);
+    assert_almost_eq!(global[(1, 1)], 2.0 / 12.0);
+    assert_almost_eq!(global[(2, 2)], 8.0 / 12.0);
+
+    // About the global origin: d = (1, 1, 0.5), A = 2.
+    let origin = p.about_point(&Vector3d::new(0.0, 0.0, 0.0));
+    assert_almost_eq!(origin[(0, 0)], 10.0 / 12.0 + 2.0 * 1.25);
+    assert_almost_eq!(origin[(1, 1)], 2.0 / 12.0 + 2.0 * 1.25);
+    assert_almost_eq!(origin[(2, 2)], 8.0 / 12.0 + 2.0 * 2.0);
+    assert_almost_eq!(origin[(0, 1)], -2.0);
+    assert_almost_eq!(origin[(1, 2)], -1.0);
+    #[allow(deprecated)]
+    let at_center = p.second_moment_of_area_at_center();
+    assert!((p.about_point(&p.center()) - at_center).norm() < 1e-12);
+
+    // Axis along global Y through (0, 0, 0.5): in-plane offset is zero, normal offset 1.
+    let axis = Line::new(Vector3d::new(0.0, 0.0, 0.5), Vector3d::new(0.0, 3.0, 0.5));
+    assert_almost_eq!(p.about_axis(&axis), 2.0 / 12.0 + 2.0);
+}
+
+#[test]
+fn plane_coordinates_follow_the_local_frame() {
+    // Wall in the XZ plane, 4 m long and 3 m high.
+    let wall = Polygon::new([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 0.0, 3.0), (0.0, 0.0, 3.0)]);
+    let point = Vector3d::new(3.0, 0.25, 1.0);
+    let (u, v, offset) = wall.plane_coordinates(&point);
+    let from_centroid = point - wall.centroid();
+    assert_almost_eq!(u, from_centroid.dot(&wall.axis(Axis::AxisX)));
+    assert_almost_eq!(v, from_centroid.dot(&wall.axis(Axis::AxisY)));
+    assert_almost_eq!(offset.abs(), 0.25);
+    assert_almost_eq!(u.hypot(v), 1.0f64.hypot(0.5));
+
+    let on_plane = wall.point_at_plane_coordinates(u, v);
+    assert_vec3_almost_eq!(on_plane, Vector3d::new(3.0, 0.0, 1.0));
+    assert_eq!(wall.plane_coordinates(&point), (u, v, offset));
+}
+
+#[test]
+fn first_and_static_moments_of_a_rectangle() {
+    // 0.2 wide, 0.4 deep; local axes follow global X and Y for this ring.
+    let section = Polygon::new([(0.0, 0.0, 0.0), (0.2, 0.0, 0.0), (0.2, 0.4, 0.0), (0.0, 0.4, 0.0)]);
+    assert_vec3_almost_eq!(section.axis(Axis::AxisY), Vector3d::new(0.0, 1.0, 0.0));
+
+    let base = Line::new((0.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+    assert_almost_eq!(section.first_moment_of_area(&base), 0.08 * 0.2);
+    assert_almost_eq!(section.first_moment_of_area(&base.reversed()), -0.08 * 0.2);
+
+    // Q(y) = b/2 (h²/4 − y²), largest at the neutral axis.
+    for y in [0.0, 0.1, -0.15] {
+        assert_almost_eq!(section.static_moment_above(y), 0.1 * (0.04 - y * y));
+    }
+    assert_eq!(section.static_moment_above(0.2), 0.0);
+    assert!(section.static_moment_above(-0.2).abs() < 1e-15);
+}
+
+#[test]
+fn section_modulus_and_radius_of_gyration() {
+    // Rectangle 0.2 wide (local X) and 0.4 deep (local Y) with a centered hole.
+    let section = Polygon::with_holes(
+        vec![(0.0, 0.0, 0.0), (0.2, 0.0, 0.0), (0.2, 0.4, 0.0), (0.0, 0.4, 0.0)],
+        vec![vec![(0.05, 0.1, 0.0), (0.05, 0.3, 0.0), (0.15, 0.3, 0.0), (0.15, 0.1, 0.0)]],
+    );
+    let ixx = (0.2 * 0.4f64.powi(3) - 0.1 * 0.2f64.powi(3)) / 12.0;
+    let iyy = (0.4 * 0.2f64.powi(3) - 0.2 * 0.1f64.powi(3)) / 12.0;
+    let modulus = section.elastic_section_modulus();
+    assert_almost_eq!(modulus.x(), ixx / 0.2);
+    assert_almost_eq!(modulus.y(), iyy / 0.1);
+    let radius = section.radius_of_gyration();
+    assert_almost_eq!(radius.x(), (ixx / 0.06).sqrt());
+    assert_almost_eq!(radius.y(), (iyy / 0.06).sqrt());
+}
+
+#[test]
+fn coplanar_lines_are_clipped_to_the_polygon() {
+    // Slab at z = 1 with an opening, cut by grid lines in its plane.
+    let slab = Polygon::with_holes(
+        vec![(0.0, 0.0, 1.0), (6.0, 0.0, 1.0), (6.0, 4.0, 1.0), (0.0, 4.0, 1.0)],
+        vec![vec![(2.0, 1.0, 1.0), (4.0, 1.0, 1.0), (4.0, 3.0, 1.0), (2.0, 3.0, 1.0)]],
+    );
+    let grid = Line::new(Vector3d::new(-1.0, 2.0, 1.0), Vector3d::new(0.0, 2.0, 1.0));
+    let segments = slab.clip_line(&grid, None);
+    assert_eq!(segments.len(), 2);
+    assert_vec3_almost_eq!(segments[0].start(), Vector3d::new(0.0, 2.0, 1.0));
+    assert_vec3_almost_eq!(segments[0].end(), Vector3d::new(2.0, 2.0, 1.0));
+    assert_vec3_almost_eq!(segments[1].start(), Vector3d::new(4.0, 2.0, 1.0));
+    assert_vec3_almost_eq!(segments[1].end(), Vector3d::new(6.0, 2.0, 1.0));
+    assert_eq!(slab.intersection_with_line(&grid, None).len(), 4);
+
+    // Along the hole edge the boundary counts, so the line stays whole.
+    let edge = Line::new(Vector3d::new(0.0, 1.0, 1.0), Vector3d::new(1.0, 1.0, 1.0));
+    let along = slab.clip_line(&edge, None);
+    assert_eq!(along.len(), 1);
+    assert_almost_eq!(along[0].length(), 6.0);
+
+    // A ray starts where it starts; a diagonal skips the corner of the opening.
+    let ray = Line::new(Vector3d::new(5.0, 2.0, 1.0), Vector3d::new(4.5, 2.0, 1.0));
+    let clipped = Ray::from_line(&ray).clip_to_polygon(&slab, None);
+    assert_eq!(clipped.len(), 2);
+    assert_vec3_almost_eq!(clipped[0].start(), Vector3d::new(5.0, 2.0, 1.0));
+    assert_vec3_almost_eq!(clipped[0].end(), Vector3d::new(4.0, 2.0, 1.0));
+    assert_vec3_almost_eq!(clipped[1].end(), Vector3d::new(0.0, 2.0, 1.0));
+    let diagonal = Line::new(Vector3d::new(0.0, 4.0, 1.0), Vector3d::new(1.0, 3.0, 1.0));
+    let pieces = slab.clip_line(&diagonal, None);
+    assert_eq!(pieces.len(), 2);
+    assert_vec3_almost_eq!(pieces[0].end(), Vector3d::new(2.0, 2.0, 1.0));
+    assert_vec3_almost_eq!(pieces[1].start(), Vector3d::new(3.0, 1.0, 1.0));
+    assert_vec3_almost_eq!(pieces[1].end(), Vector3d::new(4.0, 0.0, 1.0));
+
+    // Lines above the slab or crossing it are not clipped.
+    let above = Line::new(Vector3d::new(0.0, 2.0, 1.5), Vector3d::new(1.0, 2.0, 1.5));
+    assert!(slab.clip_line(&above, None).is_empty());
+    assert!(slab.intersection_with_line(&above, None).is_empty());
+    let post = Line::new(Vector3d::new(1.0, 1.0, 0.0), Vector3d::new(1.0, 1.0, 2.0));
+    assert!(slab.clip_line(&post, None).is_empty());
+}
+
+#[test]
+fn polygon_from_a_closed_edge_loop() {
+    // Square with its right side bulging out as a half circle; the top edge
+    // is stored backwards as CAD loops often do.
+    let edges = [
+        Edge::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0)),
+        Edge::from_arc(Arc::new(Vector3d::new(4.0, 2.0, 0.0), Vector3d::new(4.0, 0.0, 0.0), Vector3d::new(4.0, 4.0, 0.0), false)),
+        Edge::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(4.0, 4.0, 0.0)),
+        Edge::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(0.0, 0.0, 0.0)),
+    ];
+    let (polygon, sources) = Polygon::from_edges(&edges, 1e-4, None).unwrap();
+    assert_eq!(sources.len(), polygon.vertices().len());
+    assert_eq!(sources.first(), Some(&0));
+    assert_eq!(sources.last(), Some(&3));
+    let arc_sides = sources.iter().filter(|&&edge| edge == 1).count();
+    assert!(arc_sides > 10);
+    assert_eq!(sources.iter().filter(|&&edge| edge == 2).count(), 1);
+    assert!(sources.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!((polygon.area() - (16.0 + 2.0 * PI)).abs() < 1e-3);
+    assert_vec3_almost_eq!(polygon.vertices()[arc_sides + 1], Vector3d::new(4.0, 4.0, 0.0));
+
+    let mut open = edges;
+    open[3] = Edge::new(Vector3d::new(0.0, 4.0, 0.0), Vector3d::new(0.0, 1.0, 0.0));
+    assert_eq!(Polygon::from_edges(&open, 1e-4, None).unwrap_err(), EdgeLoopError::NotClosed);
+    let shuffled = [edges[0], edges[2], edges[1], edges[3]];
+    assert_eq!(Polygon::from_edges(&shuffled, 1e-4, None).unwrap_err(), EdgeLoopError::Disconnected { index: 1 });
+    assert_eq!(Polygon::from_edges(&edges[..1], 1e-4, None).unwrap_err(), EdgeLoopError::NotClosed);
+
+    // A millimetre loop exported with micrometre gaps at its corners.
+    let gapped = [
+        Edge::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(400.0, 0.0, 0.0)),
+        Edge::new(Vector3d::new(400.0, 1e-3, 0.0), Vector3d::new(400.0, 300.0, 0.0)),
+        Edge::new(Vector3d::new(400.0, 300.0, 0.0), Vector3d::new(0.0, 300.0, 0.0)),
+        Edge::new(Vector3d::new(0.0, 300.0, 0.0), Vector3d::new(1e-3, 0.0, 0.0)),
+    ];
+    assert_eq!(Polygon::from_edges(&gapped, 1e-2, None).unwrap_err(), EdgeLoopError::Disconnected { index: 1 });
+    let (polygon, _) = Polygon::from_edges(&gapped, 1e-2, Some(1e-2)).unwrap();
+    assert_almost_eq!(polygon.area(), 120_000.0, 1e-5);
+}
+
+#[test]
+fn grid_and_gauss_points_integrate_over_the_polygon() {
+    // 4 × 2 panel with a 1 × 1 opening, standing in the XZ plane.
+    let panel = Polygon::with_holes(
+        [(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 0.0, 2.0), (0.0, 0.0, 2.0)],
+        [vec![(1.0, 0.0, 0.5), (2.0, 0.0, 0.5), (2.0, 0.0, 1.5), (1.0, 0.0, 1.5)]],
+    );
+    let inertia = panel.about_centroid_local();
+    let integrate = |points: &[geometry::IntegrationPoint], f: &dyn Fn(f64, f64) -> f64| {
+        points.iter().map(|p| p.weight * f(p.position.x(), p.position.y())).sum::<f64>()
+    };
+
+    for order in 1..=5 {
+        let points = panel.gauss_points(order);
+        assert!(points.iter().all(|p| panel.contains(&panel.to_global(Vector3d::new(p.position.x(), p.position.y(), 0.0)), None)));
+        assert_almost_eq!(integrate(&points, &|_, _| 1.0), 7.0, 1e-9);
+        assert_almost_eq!(integrate(&points, &|x, _| x), 0.0, 1e-9);
+        if order >= 2 {
+            assert_almost_eq!(integrate(&points, &|_, y| y * y), inertia[(0, 0)], 1e-9);
+            assert_almost_eq!(integrate(&points, &|x, _| x * x), inertia[(1, 1)], 1e-9);
+        }
+    }
+
+    // Cells over the opening drop out; cells cut by it shrink.
+    let fibers = panel.sample_grid(8, 4);
+    assert_eq!(fibers.len(), 32 - 4);
+    assert_almost_eq!(integrate(&fibers, &|_, _| 1.0), 7.0, 1e-9);
+    assert_almost_eq!(integrate(&fibers, &|x, y| x + y), 0.0, 1e-9);
+    assert!(fibers.iter().all(|p| (p.weight - 0.25).abs() < 1e-9));
+    let coarse = panel.sample_grid(3, 1);
+    assert_eq!(coarse.len(), 3);
+    assert_almost_eq!(integrate(&coarse, &|_, _| 1.0), 7.0, 1e-9);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn uniform_samples_cover_the_polygon_by_area() {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    // L-shaped panel in the XZ plane: the 4 × 1 leg holds 4/6 of the area.
+    let panel = Polygon::new([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 0.0, 1.0), (1.0, 0.0, 1.0), (1.0, 0.0, 3.0), (0.0, 0.0, 3.0)]);
+    let mut rng = SmallRng::seed_from_u64(1823);
+    let samples = panel.sample_uniform(&mut rng, 20_000);
+    assert_eq!(samples.len(), 20_000);
+    assert_almost_eq!(samples.iter().map(|p| p.weight).sum::<f64>(), 6.0, 1e-9);
+
+    let global: Vec<Vector3d> = samples.iter().map(|p| panel.to_global(Vector3d::new(p.position.x(), p.position.y(), 0.0))).collect();
+    assert!(global.iter().all(|p| panel.contains(p, Some(1e-9))));
+    let in_leg = global.iter().filter(|p| p.z() < 1.0).count() as f64 / global.len() as f64;
+    assert_almost_eq!(in_leg, 4.0 / 6.0, 0.02);
+    let mean_x = global.iter().map(|p| p.x()).sum::<f64>() / global.len() as f64;
+    assert_almost_eq!(mean_x, panel.centroid().x(), 0.03);
+
+    assert!(panel.sample_uniform(&mut rng, 0).is_empty());
+}
+
+#[test]
+fn edge_normals_point_out_of_the_material() {
+    // Clockwise about +Z, with a square opening.
+    let slab = Polygon::with_holes(
+        [(0.0, 0.0, 0.0), (0.0, 2.0, 0.0), (4.0, 2.0, 0.0), (4.0, 0.0, 0.0)],
+        [vec![(1.0, 0.5, 0.0), (2.0, 0.5, 0.0), (2.0, 1.5, 0.0), (1.0, 1.5, 0.0)]],
+    );
+    let lines = slab.boundary_lines();
+    let normals = slab.edge_normals();
+    assert_eq!(normals.len(), 8);
+    for (line, normal) in lines.iter().zip(&normals) {
+        assert_almost_eq!(normal.norm(), 1.0);
+        assert_almost_eq!(normal.dot(&(line.end() - line.start())), 0.0);
+        let midpoint = line.midpoint();
+        let outside = Vector3d(midpoint.0 + normal.0 * 0.1);
+        let inside = Vector3d(midpoint.0 - normal.0 * 0.1);
+        assert!(!slab.contains(&outside, None) && slab.contains(&inside, None));
+    }
+    assert_vec3_almost_eq!(normals[0], Vector3d::new(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn points_along_perimeter_keep_their_spacing() {
+    let slab = Polygon::new([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (0.0, 2.0, 0.0)]);
+    let studs = slab.points_along_perimeter(1.5);
+    assert_eq!(studs.len(), 8);
+    assert_vec3_almost_eq!(studs[0].0, Vector3d::new(0.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(studs[3].0, Vector3d::new(4.0, 0.5, 0.0));
+    assert_eq!(studs[3].1, 1);
+    assert_vec3_almost_eq!(studs[7].0, Vector3d::new(0.0, 1.5, 0.0));
+    assert_eq!(studs[7].1, 3);
+
+    // Corners belong to the edge starting there; the start is not repeated at the end.
+    let corners = slab.points_along_perimeter(2.0);
+    assert_eq!(corners.iter().map(|p| p.1).collect::<Vec<_>>(), vec![0, 0, 1, 2, 2, 3]);
+    assert_vec3_almost_eq!(corners[2].0, Vector3d::new(4.0, 0.0, 0.0));
+
+    // Anchors 0.1 in from the edge.
+    let normals = slab.edge_normals();
+    let anchors: Vec<Vector3d> = studs.iter().map(|(p, edge)| Vector3d(p.0 - normals[*edge].0 * 0.1)).collect();
+    assert_vec3_almost_eq!(anchors[3], Vector3d::new(3.9, 0.5, 0.0));
+}
+
+#[test]
+fn fallible_constructors_report_bad_input() {
+    let square = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)];
+    assert_almost_eq!(Polygon::try_new(square).unwrap().area(), 1.0);
+    let closed_twice = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.0, 0.0)];
+    assert_eq!(Polygon::try_new(closed_twice).unwrap_err(), GeometryError::TooFewPoints { distinct: 2 });
+    let on_a_line = [(0.0, 0.0, 0.0), (1.0, 1.0, 1.0), (3.0, 3.0, 3.0), (2.0, 2.0, 2.0)];
+    assert_eq!(Polygon::try_new(on_a_line).unwrap_err(), GeometryError::Collinear);
+    assert!(Polygon::try_with_holes(square, [[(0.2, 0.2, 0.0), (0.4, 0.2, 0.0), (0.4, 0.4, 0.0)]]).is_ok());
+
+    let hexagon = Polygon::try_regular_ngon(2.0, 6).unwrap();
+    assert_almost_eq!(hexagon.perimeter(), 12.0);
+    assert_eq!(Polygon::try_regular_ngon(1.0, 2).unwrap_err(), GeometryError::TooFewPoints { distinct: 2 });
+    let error = Polygon::try_regular_ngon(f64::NAN, 5).unwrap_err();
+    assert_eq!(error.to_string(), "radius must be positive");
+}
+
+#[test]
+fn self_intersection_has_no_tolerance() {
+    let bowtie = Polygon::new([(0.0, 0.0, 0.0), (2.0, 2.0, 0.0), (2.0, 0.0, 0.0), (0.0, 2.0, 0.0)]);
+    assert!(bowtie.self_intersects(None));
+    // A vertex pushed onto the opposite edge touches it; one just short of it does not.
+    let pinched = |x: f64| Polygon::new([(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (x, 1.0, 0.0), (0.0, 2.0, 0.0), (0.0, 1.0, 0.0)]);
+    assert!(pinched(0.0).self_intersects(None));
+    assert!(!pinched(1e-14).self_intersects(None));
+    assert!(pinched(1e-14).is_valid(None));
+    // Tilted out of the XY plane the test runs on the vertices as given.
+    let tilted = Polygon::new([(0.0, 0.0, 0.0), (2.0, 0.0, 2.0), (2.0, 2.0, 2.0), (0.0, 2.0, 0.0)]);
+    assert!(!tilted.self_intersects(None));
+    assert!(tilted.contains(&Vector3d::new(1.0, 1.0, 1.0), None));
+    assert!(!tilted.contains(&Vector3d::new(-1e-15, 1.0, -1e-15), None));
+}
+
+#[test]
+fn tolerances_follow_the_model_units() {
+    // A 4 m × 2 m panel in millimetres with a notch reaching to 0.5 mm of the opposite edge.
+    let notched = Polygon::new([
+        (0.0, 0.0, 0.0),
+        (4000.0, 0.0, 0.0),
+        (4000.0, 2000.0, 0.0),
+        (0.5, 1000.0, 0.0),
+        (0.0, 2000.0, 0.0),
+        (0.0, 1000.0, 0.0),
+    ]);
+    assert!(notched.is_valid(None));
+    assert!(!notched.is_valid(Some(1.0)));
+    assert!(notched.self_intersects(Some(1.0)));
+
+    let near_edge = Vector3d::new(2000.0, 0.4, 0.3);
+    assert!(!notched.border_contains(&near_edge, None));
+    assert!(notched.border_contains(&near_edge, Some(1.0)));
+    assert!(!notched.contains(&near_edge, None));
+    assert!(notched.contains(&near_edge, Some(1.0)));
+
+    let edge = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4000.0, 0.0, 0.0));
+    assert!(edge.contains(&Vector3d::new(4000.5, 0.0, 0.0), Some(1.0)));
+    assert!(!edge.contains(&Vector3d::new(4000.5, 0.0, 0.0), None));
+    let post = Line::new(Vector3d::new(1000.0, 0.8, 0.0), Vector3d::new(1000.0, 500.0, 0.0));
+    assert!(edge.intersection(&post, None).is_none());
+    assert!(edge.intersection(&post, Some(1.0)).is_some());
+}
+
+#[test]
+fn distances_to_points_segments_and_polygons() {
+    let square = Polygon::new([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0), (0.0, 2.0, 0.0)]);
+    let (on_square, _, distance) = square.distance_to(&Vector3d::new(1.0, 1.0, 3.0));
+    assert_vec3_almost_eq!(on_square, Vector3d::new(1.0, 1.0, 0.0));
+    assert_almost_eq!(distance, 3.0);
+    let (on_square, _, distance) = square.distance_to(&Vector3d::new(5.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(on_square, Vector3d::new(2.0, 1.0, 0.0));
+    assert_almost_eq!(distance, 3.0);
+
+    let piercing = Line::new(Vector3d::new(0.5, 0.5, -1.0), Vector3d::new(0.5, 0.5, 1.0));
+    let (on_square, on_line, distance) = square.distance_to(&piercing);
+    assert_almost_eq!(distance, 0.0);
+    assert_vec3_almost_eq!(on_square, Vector3d::new(0.5, 0.5, 0.0));
+    assert_vec3_almost_eq!(on_line, on_square);
+    let above = Line::new(Vector3d::new(-1.0, 1.0, 0.5), Vector3d::new(3.0, 1.0, 0.5));
+    let (on_line, _, distance) = above.distance_to(&square);
+    assert_almost_eq!(distance, 0.5);
+    assert_almost_eq!(on_line.z(), 0.5);
+
+    let beside = Polygon::new([(3.0, 0.0, 1.0), (5.0, 0.0, 1.0), (5.0, 2.0, 1.0), (3.0, 2.0, 1.0)]);
+    let (on_square, on_beside, distance) = square.distance_to(&beside);
+    assert_almost_eq!(distance, 2.0f64.sqrt());
+    assert_almost_eq!(on_square.x(), 2.0);
+    assert_almost_eq!(on_beside.x(), 3.0);
+    assert_almost_eq!((on_beside.0 - on_square.0).norm(), distance);
+}
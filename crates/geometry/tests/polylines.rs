@@ -0,0 +1,92 @@
+use geometry::{Arc, GeometryError, Line, Polyline, Segment, Vector3d};
+use std::f64::consts::PI;
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+/// Straight run, quarter circle of radius 1 and a straight leg upwards.
+fn hook() -> Polyline {
+    Polyline::new([
+        Segment::Line(Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0))),
+        Segment::Arc(Arc::new(Vector3d::new(2.0, 1.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(3.0, 1.0, 0.0), false)),
+        Segment::Line(Line::new(Vector3d::new(3.0, 1.0, 0.0), Vector3d::new(3.0, 3.0, 0.0))),
+    ])
+}
+
+#[test]
+fn polyline_length_and_stationing() {
+    let polyline = hook();
+    assert_almost_eq!(polyline.length(), 4.0 + PI / 2.0);
+    assert!(!polyline.is_closed());
+    assert_vec3_almost_eq!(polyline.point_at_length(1.0), Vector3d::new(1.0, 0.0, 0.0));
+    let s = 1.0 / 2.0_f64.sqrt();
+    assert_vec3_almost_eq!(polyline.point_at_length(2.0 + PI / 4.0), Vector3d::new(2.0 + s, 1.0 - s, 0.0));
+    assert_vec3_almost_eq!(polyline.point_at_length(3.0 + PI / 2.0), Vector3d::new(3.0, 2.0, 0.0));
+    assert_vec3_almost_eq!(polyline.point_at_length(100.0), polyline.end());
+    assert_vec3_almost_eq!(polyline.point_at_length(-1.0), polyline.start());
+}
+
+#[test]
+fn stations_by_arc_length() {
+    let member = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 2.0));
+    let stations: Vec<Vector3d> = (0..5).map(|i| member.point_at_length(0.5 * i as f64)).collect();
+    assert_vec3_almost_eq!(stations[1], Vector3d::new(0.0, 0.0, 0.5));
+    assert_vec3_almost_eq!(stations[4], member.end());
+    assert_almost_eq!(member.parameter_at_length(1.5), 0.75);
+    assert_almost_eq!(member.parameter_at_length(3.0), 1.0);
+
+    let quarter = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(0.0, 2.0, 0.0), false);
+    assert_vec3_almost_eq!(quarter.point_at_length(PI / 2.0), Vector3d::new(2.0_f64.sqrt(), 2.0_f64.sqrt(), 0.0));
+    assert_almost_eq!(quarter.parameter_at_length(PI / 2.0), 0.5);
+    assert_almost_eq!(quarter.parameter_at_length(-1.0), 0.0);
+
+    let polyline = hook();
+    let t = polyline.parameter_at_length(2.0 + PI / 4.0);
+    assert_almost_eq!(t, (2.0 + PI / 4.0) / (4.0 + PI / 2.0));
+    assert_vec3_almost_eq!(geometry::Curve::point_at(&polyline, t), polyline.point_at_length(2.0 + PI / 4.0));
+}
+
+#[test]
+fn polyline_closest_point() {
+    let polyline = hook();
+    let closest = polyline.closest_point(&Vector3d::new(2.0, 1.0, 0.0));
+    assert_almost_eq!((closest.0 - Vector3d::new(2.0, 1.0, 0.0).0).norm(), 1.0);
+    let closest = polyline.closest_point(&Vector3d::new(4.0, 2.5, 0.0));
+    assert_vec3_almost_eq!(closest, Vector3d::new(3.0, 2.5, 0.0));
+    assert_almost_eq!(polyline.distance(&Vector3d::new(4.0, 2.5, 0.0)), 1.0);
+    assert_almost_eq!(polyline.length_at_point(&Vector3d::new(4.0, 2.5, 0.0)), 3.5 + PI / 2.0);
+}
+
+#[test]
+fn polyline_intersections() {
+    let polyline = hook();
+    // Diagonal through the run and the leg, passing the arc's center.
+    let line = Line::new(Vector3d::new(0.0, -1.0, 0.0), Vector3d::new(4.0, 3.0, 0.0));
+    let points = polyline.intersection_with_line(&line, None);
+    assert_eq!(points.len(), 2);
+    assert_vec3_almost_eq!(points[0], Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(points[1], Vector3d::new(3.0, 2.0, 0.0));
+
+    let line = Line::new(Vector3d::new(0.0, 0.5, 0.0), Vector3d::new(4.0, 0.5, 0.0));
+    let points = polyline.intersection_with_line(&line, None);
+    assert_eq!(points.len(), 1);
+    assert_vec3_almost_eq!(points[0], Vector3d::new(2.0 + 0.75_f64.sqrt(), 0.5, 0.0));
+
+    let other = Polyline::from_points([Vector3d::new(2.5, -1.0, 0.0), Vector3d::new(2.5, 4.0, 0.0)]);
+    let points = polyline.intersection_with_polyline(&other, None);
+    assert_eq!(points.len(), 1);
+    assert_almost_eq!(points[0].x(), 2.5);
+
+    let reversed = polyline.reversed();
+    assert_vec3_almost_eq!(reversed.start(), polyline.end());
+    assert_almost_eq!(reversed.length(), polyline.length());
+}
+
+#[test]
+fn polyline_joints_within_tolerance() {
+    // Millimetre drawing whose second segment starts a micrometre off the first one's end.
+    let first = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1000.0, 0.0, 0.0));
+    let second = Line::new(Vector3d::new(1000.0, 1e-3, 0.0), Vector3d::new(1000.0, 500.0, 0.0));
+    assert_eq!(Polyline::try_new([first, second], None), Err(GeometryError::Disconnected { index: 1 }));
+    let polyline = Polyline::try_new([first, second], Some(1e-2)).unwrap();
+    assert_almost_eq!(polyline.length(), 1500.0, 1e-5);
+    assert!(Polyline::try_new(Vec::<Line>::new(), None).is_err());
+}
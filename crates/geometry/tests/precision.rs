@@ -0,0 +1,60 @@
+use geometry::{Arc3f, Curve, Line3f, Polygon, Polygon3f, Vector3d, Vector3f};
+use utils::assert_almost_eq;
+
+#[test]
+fn single_precision_vectors_cast_and_compute() {
+    let a = Vector3f::new(1.0, 2.0, 2.0);
+    assert_eq!(a.norm(), 3.0f32);
+    assert_eq!((a * 2.0 - a)[2], 2.0f32);
+    let wide: Vector3d = a.cast();
+    assert_eq!(wide, Vector3d::new(1.0, 2.0, 2.0));
+    assert_eq!(Vector3d::new(0.1, 0.0, 0.0).cast::<f32>().x(), 0.1f32);
+}
+
+#[test]
+fn single_precision_lines_compute_in_f32() {
+    let line = Line3f::new(Vector3f::new(0.0, 0.0, 0.0), Vector3f::new(3.0, 4.0, 0.0));
+    let length: f32 = line.length();
+    assert_eq!(length, 5.0);
+    assert_eq!(line.point_at(0.5), Vector3f::new(1.5, 2.0, 0.0));
+    assert_eq!(line.closest_point(&Vector3f::new(3.0, 4.0, 2.0)), Vector3f::new(3.0, 4.0, 0.0));
+
+    let other = Line3f::new(Vector3f::new(0.0, 4.0, 0.0), Vector3f::new(3.0, 0.0, 0.0));
+    let hit = line.intersection(&other, None).expect("the diagonals cross");
+    assert!(hit.is_approx(&Vector3f::new(1.5, 2.0, 0.0), None));
+    // The default tolerance follows the precision of the points.
+    assert!(line.contains(&Vector3f::new(1.5, 2.000001, 0.0), None));
+    assert!(!line.contains(&Vector3f::new(1.5, 2.001, 0.0), None));
+}
+
+#[test]
+fn single_precision_arcs_compute_in_f32() {
+    let arc = Arc3f::new(Vector3f::new(0.0, 0.0, 0.0), Vector3f::new(2.0, 0.0, 0.0), Vector3f::new(0.0, 2.0, 0.0), false);
+    assert_almost_eq!(f64::from(arc.length()), std::f64::consts::PI, 1e-6);
+    let middle = arc.point_at(0.5);
+    assert_almost_eq!(f64::from(middle.x()), 2.0_f64.sqrt(), 1e-6);
+    assert!(arc.contains(&middle, None));
+    let tangent = Curve::tangent_at(&arc, 0.0);
+    assert!(tangent.is_approx(&Vector3f::new(0.0, 1.0, 0.0), None));
+    assert_eq!(arc.linearized(4).len(), 4);
+}
+
+#[test]
+fn single_precision_polygons_match_double_precision() {
+    let corners = [[0.0, 0.0], [4.0, 0.0], [4.0, 3.0], [0.0, 3.0]];
+    let single = Polygon3f::new(corners.map(|[x, y]| Vector3f::new(x, y, 0.0)));
+    let double = Polygon::new(corners.map(|[x, y]| Vector3d::new(f64::from(x), f64::from(y), 0.0)));
+    let area: f32 = single.area();
+    assert_eq!(area, 12.0);
+    assert_eq!(single.perimeter(), 14.0);
+    assert_eq!(single.centroid(), Vector3f::new(2.0, 1.5, 0.0));
+    assert_almost_eq!(f64::from(single.about_centroid_local()[(0, 0)]), double.about_centroid_local()[(0, 0)], 1e-6);
+
+    assert!(single.contains(&Vector3f::new(1.0, 1.0, 0.0), None));
+    assert!(!single.contains(&Vector3f::new(5.0, 1.0, 0.0), None));
+    assert!(single.border_contains(&Vector3f::new(4.0, 1.0, 0.0), None));
+    let across = Line3f::new(Vector3f::new(-1.0, 1.0, 0.0), Vector3f::new(5.0, 1.0, 0.0));
+    let clipped = single.clip_line(&across, None);
+    assert_eq!(clipped.len(), 1);
+    assert_almost_eq!(f64::from(clipped[0].length()), 4.0, 1e-6);
+}
@@ -0,0 +1,46 @@
+use geometry::predicates::{incircle, orient2d, segments_intersect};
+use nalgebra::Vector2;
+
+#[test]
+fn orientation_is_exact_near_a_line() {
+    // Points a few ulps either side of y = x, far from the line's defining points:
+    // a plain determinant gets many of these wrong.
+    let (a, b) = (Vector2::new(12.0, 12.0), Vector2::new(24.0, 24.0));
+    let ulp = f64::EPSILON / 2.0;
+    for i in 0..32_i32 {
+        for j in 0..32 {
+            let c = Vector2::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+            let expected = (j - i).signum() as f64;
+            let sign = orient2d(&a, &b, &c);
+            assert_eq!(if sign == 0.0 { 0.0 } else { sign.signum() }, expected, "i = {i}, j = {j}");
+        }
+    }
+}
+
+#[test]
+fn incircle_is_zero_exactly_on_the_circle() {
+    let (a, b, c) = (Vector2::new(5.0, 0.0), Vector2::new(0.0, 5.0), Vector2::new(-5.0, 0.0));
+    assert_eq!(incircle(&a, &b, &c, &Vector2::new(3.0, -4.0)), 0.0);
+    assert!(incircle(&a, &b, &c, &Vector2::new(3.0, -4.0 + 1e-15)) > 0.0);
+    assert!(incircle(&a, &b, &c, &Vector2::new(3.0, -4.0 - 1e-15)) < 0.0);
+    // Clockwise triangles flip the sign.
+    assert!(incircle(&c, &b, &a, &Vector2::new(0.0, 0.0)) < 0.0);
+}
+
+#[test]
+fn segments_touching_and_overlapping_intersect() {
+    let p = |x: f64, y: f64| Vector2::new(x, y);
+    assert!(segments_intersect(&p(0.0, 0.0), &p(2.0, 2.0), &p(0.0, 2.0), &p(2.0, 0.0)));
+    assert!(segments_intersect(&p(0.0, 0.0), &p(2.0, 0.0), &p(1.0, 0.0), &p(1.0, 3.0)));
+    assert!(segments_intersect(&p(0.0, 0.0), &p(2.0, 0.0), &p(1.0, 0.0), &p(3.0, 0.0)));
+    assert!(!segments_intersect(&p(0.0, 0.0), &p(2.0, 0.0), &p(2.5, 0.0), &p(3.0, 0.0)));
+    assert!(!segments_intersect(&p(0.0, 0.0), &p(2.0, 0.0), &p(1.0, 1e-300), &p(1.0, 3.0)));
+}
+
+#[test]
+fn tiny_crossing_segments_intersect() {
+    // The orientations are about 1e-200, their products underflow to zero.
+    let p = |x: f64, y: f64| Vector2::new(x * 1e-100, y * 1e-100);
+    assert!(segments_intersect(&p(-1.0, -1.0), &p(1.0, 1.0), &p(-1.0, 1.0), &p(1.0, -1.0)));
+    assert!(!segments_intersect(&p(-1.0, -1.0), &p(1.0, 1.0), &p(2.0, 1.0), &p(3.0, -1.0)));
+}
@@ -0,0 +1,102 @@
+use geometry::{Arc, Line, Plane, Polygon, Polyline, Ray, Segment, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+#[test]
+fn ray_meets_segments_beyond_its_origin_only() {
+    let ray = Ray::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(ray.point_at(2.5), Vector3d::new(2.5, 0.0, 0.0));
+
+    let ahead = Line::new(Vector3d::new(5.0, -1.0, 0.0), Vector3d::new(5.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(ray.intersection_with_line(&ahead, None).unwrap(), Vector3d::new(5.0, 0.0, 0.0));
+    let behind = Line::new(Vector3d::new(-5.0, -1.0, 0.0), Vector3d::new(-5.0, 1.0, 0.0));
+    assert!(ray.intersection_with_line(&behind, None).is_none());
+    let beside = Line::new(Vector3d::new(5.0, 1.0, 0.0), Vector3d::new(5.0, 2.0, 0.0));
+    assert!(ray.intersection_with_line(&beside, None).is_none());
+
+    // Collinear segments report the first point reached.
+    let along = Line::new(Vector3d::new(7.0, 0.0, 0.0), Vector3d::new(3.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(ray.intersection_with_line(&along, None).unwrap(), Vector3d::new(3.0, 0.0, 0.0));
+    let across = Line::new(Vector3d::new(-2.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(ray.intersection_with_line(&across, None).unwrap(), ray.origin());
+}
+
+#[test]
+fn ray_meets_infinite_lines_ahead_only() {
+    let ray = Ray::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(5.0, 0.0, 0.0));
+    let behind = Line::new(Vector3d::new(-5.0, -5.0, 0.0), Vector3d::new(-5.0, 5.0, 0.0));
+    assert!(ray.intersection_with_infinite_line(&behind, None).is_none());
+
+    // The segment stops short of the ray, the line through it does not.
+    let short = Line::new(Vector3d::new(3.0, 5.0, 0.0), Vector3d::new(3.0, 1.0, 0.0));
+    assert!(ray.intersection_with_line(&short, None).is_none());
+    assert_vec3_almost_eq!(ray.intersection_with_infinite_line(&short, None).unwrap(), Vector3d::new(3.0, 0.0, 0.0));
+
+    let parallel = Line::new(Vector3d::new(10.0, 0.0, 0.0), Vector3d::new(15.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(ray.intersection_with_infinite_line(&parallel, None).unwrap(), ray.origin());
+    let offset = Line::new(Vector3d::new(10.0, 1.0, 0.0), Vector3d::new(15.0, 1.0, 0.0));
+    assert!(ray.intersection_with_infinite_line(&offset, None).is_none());
+}
+
+#[test]
+fn ray_hits_are_ordered_from_the_origin() {
+    let hook = Polyline::new([
+        Segment::Line(Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0))),
+        Segment::Arc(Arc::new(Vector3d::new(2.0, 1.0, 0.0), Vector3d::new(2.0, 0.0, 0.0), Vector3d::new(3.0, 1.0, 0.0), false)),
+        Segment::Line(Line::new(Vector3d::new(3.0, 1.0, 0.0), Vector3d::new(3.0, 3.0, 0.0))),
+    ]);
+    let ray = Ray::new(Vector3d::new(4.0, 2.0, 0.0), Vector3d::new(-1.0, -1.0, 0.0));
+    let hits = ray.intersection_with_polyline(&hook, None);
+    assert_eq!(hits.len(), 2);
+    assert_vec3_almost_eq!(hits[0], Vector3d::new(3.0, 1.0, 0.0));
+    assert_vec3_almost_eq!(hits[1], Vector3d::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn ray_crosses_polygons_and_planes_ahead() {
+    let square = Polygon::new([
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(2.0, 0.0, 0.0),
+        Vector3d::new(2.0, 2.0, 0.0),
+        Vector3d::new(0.0, 2.0, 0.0),
+    ]);
+    let down = Ray::new(Vector3d::new(1.0, 1.0, 5.0), Vector3d::new(0.0, 0.0, -2.0));
+    let hits = down.intersection_with_polygon(&square, None);
+    assert_eq!(hits.len(), 1);
+    assert_vec3_almost_eq!(hits[0], Vector3d::new(1.0, 1.0, 0.0));
+    let up = Ray::new(Vector3d::new(1.0, 1.0, 5.0), Vector3d::new(0.0, 0.0, 1.0));
+    assert!(up.intersection_with_polygon(&square, None).is_empty());
+
+    // A ray starting inside the polygon's plane region is clipped at its origin.
+    let inside = Ray::new(Vector3d::new(1.0, 1.0, 0.0), Vector3d::new(1.0, 0.0, 0.0));
+    let parts = inside.clip_to_polygon(&square, None);
+    assert_eq!(parts.len(), 1);
+    assert_almost_eq!(parts[0].length(), 1.0);
+
+    let plane = square.plane();
+    assert_almost_eq!(down.parameter(&down.intersection_with_plane(&plane, None).unwrap()), 2.5);
+    assert!(up.intersection_with_plane(&plane, None).is_none());
+    assert!(inside.intersection_with_plane(&Plane::new((0.0, 0.0, 1.0), (0.0, 0.0, 1.0)), None).is_none());
+}
+
+#[test]
+fn ray_hits_within_tolerance() {
+    let square = Polygon::new([
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(2.0, 0.0, 0.0),
+        Vector3d::new(2.0, 2.0, 0.0),
+        Vector3d::new(0.0, 2.0, 0.0),
+    ]);
+    // Grazes the polygon 1e-6 outside its edge.
+    let grazing = Ray::new(Vector3d::new(2.000001, 1.0, 5.0), Vector3d::new(0.0, 0.0, -1.0));
+    assert!(grazing.intersection_with_polygon(&square, None).is_empty());
+    assert_eq!(grazing.intersection_with_polygon(&square, Some(1e-5)).len(), 1);
+
+    // Starts just past a segment that should still count as met.
+    let ray = Ray::new(Vector3d::new(1e-6, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0));
+    let behind = Line::new(Vector3d::new(0.0, -1.0, 0.0), Vector3d::new(0.0, 1.0, 0.0));
+    assert!(ray.intersection_with_line(&behind, None).is_none());
+    assert_vec3_almost_eq!(ray.intersection_with_line(&behind, Some(1e-5)).unwrap(), ray.origin());
+    let plane = Plane::new((0.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+    assert!(ray.intersection_with_plane(&plane, None).is_none());
+    assert!(ray.intersection_with_plane(&plane, Some(1e-5)).is_some());
+}
@@ -0,0 +1,50 @@
+use geometry::{Polygon, Region, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+fn rectangle(x0: f64, y0: f64, width: f64, height: f64) -> Polygon {
+    Polygon::new([(x0, y0, 0.0), (x0 + width, y0, 0.0), (x0 + width, y0 + height, 0.0), (x0, y0 + height, 0.0)])
+}
+
+#[test]
+fn built_up_section_combines_parts() {
+    // 200x20 flange plate on top of a 20x180 web, T-section 200 mm deep.
+    let plate = rectangle(-0.1, 0.18, 0.2, 0.02);
+    let web = rectangle(-0.01, 0.0, 0.02, 0.18);
+    let region = Region::new().with_polygon(plate).with_polygon(web);
+
+    let (a1, a2) = (0.2 * 0.02, 0.02 * 0.18);
+    assert_almost_eq!(region.area(), a1 + a2);
+    let y = (a1 * 0.19 + a2 * 0.09) / (a1 + a2);
+    assert_vec3_almost_eq!(region.centroid().unwrap(), Vector3d::new(0.0, y, 0.0));
+
+    // Parallel axis theorem about the combined centroid.
+    let ixx = 0.2 * 0.02f64.powi(3) / 12.0 + a1 * (0.19 - y).powi(2) + 0.02 * 0.18f64.powi(3) / 12.0 + a2 * (0.09 - y).powi(2);
+    let iyy = 0.02 * 0.2f64.powi(3) / 12.0 + 0.18 * 0.02f64.powi(3) / 12.0;
+    let global = region.about_centroid_global().unwrap();
+    assert_almost_eq!(global[(0, 0)], ixx);
+    assert_almost_eq!(global[(1, 1)], iyy);
+    assert_almost_eq!(global[(2, 2)], ixx + iyy);
+
+    let local = region.about_centroid_local().unwrap();
+    assert_almost_eq!(local[(0, 0)] + local[(1, 1)], ixx + iyy);
+    assert!(local[(0, 1)].abs() < 1e-15);
+}
+
+#[test]
+fn openings_subtract() {
+    let plate = rectangle(0.0, 0.0, 0.4, 0.2);
+    let hole = rectangle(0.25, 0.05, 0.1, 0.1);
+    let region = Region::new().with_polygon(plate.clone()).with_opening(hole.clone());
+    assert_almost_eq!(region.area(), 0.08 - 0.01);
+    let x = (0.08 * 0.2 - 0.01 * 0.3) / 0.07;
+    assert_vec3_almost_eq!(region.centroid().unwrap(), Vector3d::new(x, 0.1, 0.0));
+
+    let origin = Vector3d::new(0.0, 0.0, 0.0);
+    let expected = plate.about_point(&origin) - hole.about_point(&origin);
+    assert_almost_eq!(region.about_point(&origin)[(0, 0)], expected[(0, 0)]);
+
+    assert!(region.contains(&Vector3d::new(0.1, 0.1, 0.0), None));
+    assert!(!region.contains(&Vector3d::new(0.3, 0.1, 0.0), None));
+    assert!(region.contains(&Vector3d::new(0.25, 0.1, 0.0), None));
+    assert!(Region::new().centroid().is_none());
+}
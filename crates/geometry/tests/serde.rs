@@ -0,0 +1,102 @@
+#![cfg(feature = "serde")]
+
+use geometry::{
+    Arc, Axis, CompoundShape, Disk, Edge, GenericShape, Line, LocalAxis, Polygon, Rectangle, Shape, ShapeBox, ShapeC, ShapeDescriptor,
+    ShapeI, ShapeL, ShapeT, ShapeTube, Vector2d, Vector3d,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn curves_and_frames_round_trip() {
+    assert_eq!(round_trip(&Vector2d::new(1.5, -2.0)), Vector2d::new(1.5, -2.0));
+    let line = Line::new((0.0, 0.0, 0.0), (3.0, 4.0, 0.0));
+    assert_eq!(round_trip(&line), line);
+    let arc = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0), false);
+    assert_eq!(round_trip(&arc), arc);
+    let edge = Edge::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(2.0, 0.0, 0.0));
+    assert_eq!(round_trip(&edge), edge);
+    let axis = LocalAxis::new(Vector3d::new(1.0, 2.0, 3.0), nalgebra::Matrix3::identity());
+    assert_eq!(round_trip(&axis), axis);
+    assert_eq!(round_trip(&Axis::AxisZ), Axis::AxisZ);
+}
+
+#[test]
+fn polygons_store_rings_only() {
+    let frame = Polygon::with_holes(
+        vec![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 2.0, 0.0), (0.0, 2.0, 0.0)],
+        vec![vec![(1.0, 0.5, 0.0), (1.0, 1.5, 0.0), (2.0, 1.5, 0.0), (2.0, 0.5, 0.0)]],
+    );
+    let text = serde_json::to_string(&frame).unwrap();
+    assert!(!text.contains("rotation") && !text.contains("area"));
+    let restored: Polygon = serde_json::from_str(&text).unwrap();
+    assert_eq!(restored, frame);
+
+    let solid: Polygon = serde_json::from_str(r#"{"vertices": [[0, 0, 0], [1, 0, 0], [0, 1, 0]]}"#).unwrap();
+    assert_eq!(solid.area(), 0.5);
+    assert!(solid.holes().is_empty());
+
+    let profile = ShapeI::new(0.15, 0.15, 0.3, 0.0107, 0.0107, 0.0071, 0.015, 0.0, 0.0, 0.0, 0.0);
+    let restored = round_trip(&profile);
+    assert_eq!(restored.area(), profile.area());
+    assert_eq!(restored.height, profile.height);
+}
+
+#[test]
+fn shapes_serialize_as_tagged_parameters() {
+    let profile = ShapeI::from_designation("IPE 300").unwrap();
+    let value = serde_json::to_value(&profile).unwrap();
+    assert_eq!(value["type"], "ShapeI");
+    assert_eq!(value["height"], 0.3);
+    assert!(value.get("polygon").is_none());
+
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rectangle::new(0.2, 0.1, 0.1, 0.05)),
+        Box::new(Disk::new(0.1, 0.02)),
+        Box::new(ShapeBox::new(0.2, 0.1, 0.01, 0.02)),
+        Box::new(ShapeTube::new(0.2, 0.01)),
+        Box::new(profile),
+        Box::new(ShapeC::from_designation("UPN 200").unwrap()),
+        Box::new(ShapeL::from_designation("L 100x10").unwrap()),
+        Box::new(ShapeT::new(0.2, 0.2, 0.02, 0.01, 0.01, 0.0, 0.0)),
+        Box::new(GenericShape::from_points([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)]).unwrap()),
+        Box::new(
+            CompoundShape::new()
+                .with_part(Rectangle::new(0.4, 0.02, 0.0, 0.0), Vector2d::new(0.0, 0.21), 0.0)
+                .with_part(Rectangle::new(0.01, 0.4, 0.0, 0.0), Vector2d::new(0.0, 0.0), 0.0),
+        ),
+    ];
+    for shape in &shapes {
+        let descriptor = shape.descriptor().unwrap();
+        let restored: ShapeDescriptor = serde_json::from_str(&serde_json::to_string(&descriptor).unwrap()).unwrap();
+        assert_eq!(restored, descriptor);
+        let rebuilt = restored.build().unwrap();
+        assert_eq!(rebuilt.area(), shape.area());
+        assert_eq!(rebuilt.second_moment_of_area(), shape.second_moment_of_area());
+    }
+}
+
+#[test]
+fn handwritten_shapes_default_and_validate() {
+    let plate: Rectangle = serde_json::from_str(r#"{"type": "Rectangle", "width": 0.2, "height": 0.1}"#).unwrap();
+    assert_eq!(plate.width * plate.height, 0.2 * 0.1);
+    assert_eq!(plate.hole_width, 0.0);
+    let angle: ShapeL = serde_json::from_str(r#"{"type": "ShapeL", "width": 0.1, "height": 0.1, "flange_thickness": 0.01, "web_thickness": 0.01}"#).unwrap();
+    assert_eq!(angle.fillet, 0.0);
+    let girder: CompoundShape = serde_json::from_str(
+        r#"{"type": "CompoundShape", "parts": [
+            {"shape": {"type": "Rectangle", "width": 0.4, "height": 0.02}, "offset": [0.0, 0.21]},
+            {"shape": {"type": "Rectangle", "width": 0.01, "height": 0.4}, "offset": [0.0, 0.0]}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(girder.part_count(), 2);
+
+    let invalid = serde_json::from_str::<Disk>(r#"{"type": "Disk", "radius": 0.1, "hole_radius": 0.2}"#).unwrap_err();
+    assert!(invalid.to_string().contains("hole_radius must be less than the radius"));
+    let mismatched = serde_json::from_str::<Disk>(r#"{"type": "ShapeTube", "diameter": 0.2, "thickness": 0.01}"#).unwrap_err();
+    assert!(mismatched.to_string().contains("type"));
+}
@@ -1,11 +1,19 @@
 use geometry::{
+    ClassifiedShape,
+    CompoundShape,
     Disk,
+    GenericShape,
+    Polygon,
     Rectangle,
     Shape,
+    ShapeBox,
     ShapeC,
+    ShapeError,
     ShapeI,
     ShapeL,
     ShapeT,
+    ShapeTube,
+    Vector2d,
     Vector3d,
 };
 use utils::{assert_almost_eq, assert_vec3_almost_eq};
@@ -34,6 +42,8 @@ fn rectangle_full_matches_reference_snapshot() {
     {
         let rect = Rectangle::new(220.0, 140.0, 100.0, 60.0);
         let poly = rect.to_polygon();
+        assert_eq!(poly.holes().len(), 1);
+        assert_relative_eq("rectangle full perimeter", 2.0 * (220.0 + 140.0 + 100.0 + 60.0), rect.perimeter());
         let area = poly.area();
         let centroid = poly.centroid();
         let local = poly.local_second_moment_of_area();
@@ -42,18 +52,18 @@ fn rectangle_full_matches_reference_snapshot() {
         let global = poly.second_moment_of_area();
         let centroidal_global = poly.centroidal_second_moment_of_area();
 
-        let expected_area = 30800.0;
+        let expected_area = 30800.0 - 6000.0;
         assert_relative_eq("rectangle full area", expected_area, area);
         assert_vec3_almost_eq!(centroid, Vector3d::new(0.0, 0.0, 0.0));
 
-        let expected_i_xx_local = 50306666.66666666;
-        let expected_i_yy_local = 124226666.66666669;
+        let expected_i_xx_local = 48506666.66666666;
+        let expected_i_yy_local = 119226666.66666669;
         assert_relative_eq("rectangle full I_xx local", expected_i_xx_local, local[(0, 0)]);
         assert_relative_eq("rectangle full I_yy local", expected_i_yy_local, local[(1, 1)]);
         assert_almost_eq!(local[(0, 1)], 0.0);
 
-        let expected_i_xx_centroidal_local = 50306666.66666666;
-        let expected_i_yy_centroidal_local = 124226666.66666669;
+        let expected_i_xx_centroidal_local = 48506666.66666666;
+        let expected_i_yy_centroidal_local = 119226666.66666669;
         assert_relative_eq(
             "rectangle full I_xx centroidal local",
             expected_i_xx_centroidal_local,
@@ -71,9 +81,9 @@ fn rectangle_full_matches_reference_snapshot() {
         assert_relative_eq("rectangle full principal (1,0)", 0.0, principal[(1, 0)]);
         assert_relative_eq("rectangle full principal (1,1)", 1.0, principal[(1, 1)]);
 
-        let expected_i_xx_global = 50306666.66666666;
-        let expected_i_yy_global = 124226666.66666669;
-        let expected_i_zz_global = 174533333.33333334;
+        let expected_i_xx_global = 48506666.66666666;
+        let expected_i_yy_global = 119226666.66666669;
+        let expected_i_zz_global = 167733333.33333334;
         assert_relative_eq("rectangle full I_xx global", expected_i_xx_global, global[(0, 0)]);
         assert_relative_eq("rectangle full I_yy global", expected_i_yy_global, global[(1, 1)]);
         assert_relative_eq("rectangle full I_zz global", expected_i_zz_global, global[(2, 2)]);
@@ -81,9 +91,9 @@ fn rectangle_full_matches_reference_snapshot() {
         assert_almost_eq!(global[(0, 2)], 0.0);
         assert_almost_eq!(global[(1, 2)], 0.0);
 
-        let expected_i_xx_centroidal_global = 50306666.66666666;
-        let expected_i_yy_centroidal_global = 124226666.66666669;
-        let expected_i_zz_centroidal_global = 174533333.33333334;
+        let expected_i_xx_centroidal_global = 48506666.66666666;
+        let expected_i_yy_centroidal_global = 119226666.66666669;
+        let expected_i_zz_centroidal_global = 167733333.33333334;
         assert_relative_eq(
             "rectangle full I_xx centroidal global",
             expected_i_xx_centroidal_global,
@@ -143,6 +153,11 @@ fn disk_full_matches_reference_snapshot() {
 
         let expected_i_zz = expected_i_planar * 2.0;
         assert_relative_eq("disk full I_zz", expected_i_zz, i_zz);
+
+        let polygon = disk.linearized(512);
+        assert_eq!(polygon.holes().len(), 1);
+        assert!((polygon.area() - expected_area).abs() < 1e-4 * expected_area);
+        assert!((polygon.about_centroid_local()[(0, 0)] - expected_i_planar).abs() < 1e-4 * expected_i_planar);
     }
 }
 
@@ -168,18 +183,18 @@ fn shape_i_full_matches_reference_snapshot() {
         let local = poly.local_second_moment_of_area();
         let global = poly.second_moment_of_area();
 
-        let expected_area = 8080.0;
+        let expected_area = 8139.586861655672;
         assert_relative_eq("shape I full area", expected_area, area);
-        assert_vec3_almost_eq!(centroid, Vector3d::new(0.0, 0.0, 0.0));
+        assert_vec3_almost_eq!(centroid, Vector3d::new(0.0, 0.2587313765828583, 0.0));
 
-        let expected_local_xx = 171511893.3333333;
-        let expected_local_yy = 9027333.333333336;
+        let expected_local_xx = 172910671.54179677;
+        let expected_local_yy = 8442340.759367771;
         assert_relative_eq("shape I full I_xx local", expected_local_xx, local[(0, 0)]);
         assert_relative_eq("shape I full I_yy local", expected_local_yy, local[(1, 1)]);
 
-        let expected_global_xx = 171511893.3333333;
-        let expected_global_yy = 9027333.333333336;
-        let expected_global_zz = 180539226.66666666;
+        let expected_global_xx = 172910671.54179677;
+        let expected_global_yy = 8442340.759367771;
+        let expected_global_zz = 181353012.30116454;
         assert_relative_eq("shape I full I_xx global", expected_global_xx, global[(0, 0)]);
         assert_relative_eq("shape I full I_yy global", expected_global_yy, global[(1, 1)]);
         assert_relative_eq("shape I full I_zz global", expected_global_zz, global[(2, 2)]);
@@ -241,27 +256,27 @@ fn shape_c_full_matches_reference_snapshot() {
         let local = poly.local_second_moment_of_area();
         let global = poly.second_moment_of_area();
 
-        let expected_area = 4332.0;
+        let expected_area = 4322.3103130953605;
         assert_relative_eq("shape C full area", expected_area, area);
         assert_vec3_almost_eq!(
             centroid,
-            Vector3d::new(32.35180055401662, -11.35457063711911, 0.0),
+            Vector3d::new(31.00218865357605, -11.158757657154595, 0.0),
         );
 
-        let expected_local_xx = 40273327.99999999;
-        let expected_local_yy = 9163856.0;
+        let expected_local_xx = 40114728.56357058;
+        let expected_local_yy = 8510396.182223799;
         assert_relative_eq("shape C full I_xx local", expected_local_xx, local[(0, 0)]);
         assert_relative_eq("shape C full I_yy local", expected_local_yy, local[(1, 1)]);
 
-        let expected_local_xy = -4023852.0000000005;
+        let expected_local_xy = -3751667.322280803;
         assert_relative_eq("shape C full I_xy local", expected_local_xy, local[(0, 1)]);
 
-        let expected_global_xx = 40273327.99999999;
-        let expected_global_yy = 9163856.0;
-        let expected_global_zz = 49437183.99999999;
+        let expected_global_xx = 40114728.56357058;
+        let expected_global_yy = 8510396.182223799;
+        let expected_global_zz = 48625124.74579438;
         assert_relative_eq("shape C full I_xx global", expected_global_xx, global[(0, 0)]);
         assert_relative_eq("shape C full I_yy global", expected_global_yy, global[(1, 1)]);
-        assert_relative_eq("shape C full I_xy global", 4023852.0000000005, global[(0, 1)]);
+        assert_relative_eq("shape C full I_xy global", 3751667.322280803, global[(0, 1)]);
         assert_relative_eq("shape C full I_zz global", expected_global_zz, global[(2, 2)]);
     }
 }
@@ -320,27 +335,27 @@ fn shape_l_full_matches_reference_snapshot() {
         let local = poly.local_second_moment_of_area();
         let global = poly.second_moment_of_area();
 
-        let expected_area = 2320.0;
+        let expected_area = 2317.573349758217;
         assert_relative_eq("shape L full area", expected_area, area);
         assert_vec3_almost_eq!(
             centroid,
-            Vector3d::new(34.13793103448276, -25.03448275862069, 0.0),
+            Vector3d::new(32.03012620606407, -26.098240788884507, 0.0),
         );
 
-        let expected_local_xx = 3404693.3333333335;
-        let expected_local_yy = 6091333.333333333;
+        let expected_local_xx = 3359074.5034039635;
+        let expected_local_yy = 5487636.99168109;
         assert_relative_eq("shape L full I_xx local", expected_local_xx, local[(0, 0)]);
         assert_relative_eq("shape L full I_yy local", expected_local_yy, local[(1, 1)]);
 
-        let expected_local_xy = -3484800.0;
+        let expected_local_xy = -3299362.78867448;
         assert_relative_eq("shape L full I_xy local", expected_local_xy, local[(0, 1)]);
 
-        let expected_global_xx = 3404693.3333333335;
-        let expected_global_yy = 6091333.333333333;
-        let expected_global_zz = 9496026.666666666;
+        let expected_global_xx = 3359074.5034039635;
+        let expected_global_yy = 5487636.99168109;
+        let expected_global_zz = 8846711.495085053;
         assert_relative_eq("shape L full I_xx global", expected_global_xx, global[(0, 0)]);
         assert_relative_eq("shape L full I_yy global", expected_global_yy, global[(1, 1)]);
-        assert_relative_eq("shape L full I_xy global", 3484800.0, global[(0, 1)]);
+        assert_relative_eq("shape L full I_xy global", 3299362.78867448, global[(0, 1)]);
         assert_relative_eq("shape L full I_zz global", expected_global_zz, global[(2, 2)]);
     }
 }
@@ -362,18 +377,18 @@ fn shape_t_full_matches_reference_snapshot() {
         let local = poly.local_second_moment_of_area();
         let global = poly.second_moment_of_area();
 
-        let expected_area = 3800.0;
+        let expected_area = 3834.715215544028;
         assert_relative_eq("shape T full area", expected_area, area);
-        assert_vec3_almost_eq!(centroid, Vector3d::new(0.0, 39.15789473684211, 0.0));
+        assert_vec3_almost_eq!(centroid, Vector3d::new(0.0, 39.24913531751423, 0.0));
 
-        let expected_local_xx = 11563466.66666666;
-        let expected_local_yy = 5471666.666666667;
+        let expected_local_xx = 11647362.882869788;
+        let expected_local_yy = 5276052.987412205;
         assert_relative_eq("shape T full I_xx local", expected_local_xx, local[(0, 0)]);
         assert_relative_eq("shape T full I_yy local", expected_local_yy, local[(1, 1)]);
 
-        let expected_global_xx = 11563466.66666666;
-        let expected_global_yy = 5471666.666666667;
-        let expected_global_zz = 17035133.33333333;
+        let expected_global_xx = 11647362.882869788;
+        let expected_global_yy = 5276052.987412205;
+        let expected_global_zz = 16923415.870281994;
         assert_relative_eq("shape T full I_xx global", expected_global_xx, global[(0, 0)]);
         assert_relative_eq("shape T full I_yy global", expected_global_yy, global[(1, 1)]);
         assert_relative_eq("shape T full I_zz global", expected_global_zz, global[(2, 2)]);
@@ -442,3 +457,356 @@ fn all_shapes_full_have_valid_polygons() {
         );
         assert!(shape_t_full.area() > 0.0, "ShapeT_full should have positive area");
 }
+
+#[test]
+fn rolled_profiles_match_catalog_values() {
+    // (name, outline, A [cm²], Iy [cm⁴], Iz [cm⁴]) from the EN 10365 tables.
+    let profiles = [
+        ("IPE 300", ShapeI::new(0.15, 0.15, 0.3, 0.0107, 0.0107, 0.0071, 0.015, 0.0, 0.0, 0.0, 0.0).to_polygon(), 53.81, 8356.0, 603.8),
+        (
+            "IPN 200",
+            ShapeI::new(0.09, 0.09, 0.2, 0.0113, 0.0113, 0.0075, 0.0075, 0.0045, 0.0045, 0.14f64.atan(), 0.14f64.atan()).to_polygon(),
+            33.4,
+            2140.0,
+            117.0,
+        ),
+        (
+            "UPN 200",
+            ShapeC::new(0.075, 0.075, 0.2, 0.0115, 0.0115, 0.0085, 0.0115, 0.006, 0.006, 0.0, 0.0, 0.08f64.atan(), 0.08f64.atan())
+                .to_polygon(),
+            32.2,
+            1910.0,
+            148.0,
+        ),
+        ("L 100x10", ShapeL::new(0.1, 0.1, 0.01, 0.01, 0.012, 0.006, 0.0, 0.0).to_polygon(), 19.2, 177.0, 177.0),
+    ];
+    for (name, polygon, area, iy, iz) in profiles {
+        let inertia = polygon.about_centroid_global();
+        assert!(relative_error(area * 1e-4, polygon.area()) < 1e-2, "{name} area {}", polygon.area());
+        assert!(relative_error(iy * 1e-8, inertia[(0, 0)]) < 1e-2, "{name} Iy {}", inertia[(0, 0)]);
+        assert!(relative_error(iz * 1e-8, inertia[(1, 1)]) < 1e-2, "{name} Iz {}", inertia[(1, 1)]);
+    }
+
+    // Zero chords per arc give the sharp-cornered outline through the corner points.
+    let ipe = ShapeI::new(0.15, 0.15, 0.3, 0.0107, 0.0107, 0.0071, 0.015, 0.0, 0.0, 0.0, 0.0);
+    assert_eq!(ipe.linearized(0).vertices().len(), 12);
+    assert_eq!(ipe.linearized(4).vertices().len(), 12 + 4 * 4);
+    assert_relative_eq("IPE 300 sharp area", 2.0 * 0.15 * 0.0107 + (0.3 - 2.0 * 0.0107) * 0.0071, ipe.linearized(0).area());
+    // Finer arcs change the stored outline by far less than the catalog rounding.
+    assert!(relative_error(ipe.linearized(64).area(), ipe.area()) < 1e-3);
+}
+
+#[test]
+fn profiles_from_designation() {
+    // (designation, A [cm²]) from the EN 10365 and AISC tables.
+    let beams = [("IPE 300", 53.81), ("ipe300", 53.81), ("HEA 200", 53.83), ("HE 300 B", 149.1), ("HEB 600", 270.0), ("W12x26", 49.4)];
+    for (name, area) in beams {
+        let shape = ShapeI::from_designation(name).unwrap_or_else(|| panic!("{name} missing"));
+        assert!(relative_error(area * 1e-4, shape.area()) < 1e-2, "{name} area {}", shape.area());
+    }
+    let upn = ShapeC::from_designation("UPN 200").unwrap();
+    assert!(relative_error(32.2e-4, upn.area()) < 1e-2, "UPN 200 area {}", upn.area());
+    for name in ["L 100x10", "L100x100x10"] {
+        let angle = ShapeL::from_designation(name).unwrap();
+        assert!(relative_error(19.2e-4, angle.area()) < 1e-2, "{name} area {}", angle.area());
+    }
+
+    assert!(ShapeI::from_designation("IPE 310").is_none());
+    assert!(ShapeI::from_designation("UPN 200").is_none());
+    assert!(ShapeL::from_designation("L 100x90x10").is_none());
+    assert!(geometry::profile_designations().all(|name| {
+        ShapeI::from_designation(name).is_some() || ShapeC::from_designation(name).is_some() || ShapeL::from_designation(name).is_some()
+    }));
+}
+
+#[test]
+fn try_new_names_the_invalid_dimension() {
+    let error = |dimension, reason| Err(ShapeError { dimension, reason });
+    assert_eq!(Rectangle::try_new(0.0, 1.0, 0.0, 0.0).map(|_| ()), error("width", "must be positive"));
+    assert_eq!(Rectangle::try_new(1.0, 1.0, 0.5, 1.5).map(|_| ()), error("hole_height", "must be less than the height"));
+    assert_eq!(Disk::try_new(0.1, 0.2).map(|_| ()), error("hole_radius", "must be less than the radius"));
+    assert_eq!(ShapeTube::try_new(0.1, 0.05).map(|_| ()), error("thickness", "must be less than half the diameter"));
+    assert_eq!(ShapeBox::try_new(0.2, 0.1, 0.01, -0.01).map(|_| ()), error("outer_radius", "must not be negative"));
+    assert_eq!(
+        ShapeI::try_new(0.15, 0.15, 0.02, 0.0107, 0.0107, 0.0071, 0.015, 0.0, 0.0, 0.0, 0.0).map(|_| ()),
+        error("height", "must exceed the flange thicknesses")
+    );
+    assert_eq!(
+        ShapeC::try_new(0.075, 0.075, 0.2, 0.0115, 0.0115, 0.0085, f64::NAN, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0).map(|_| ()),
+        error("fillet", "must not be negative")
+    );
+    // A fillet larger than the web between the flanges cannot be drawn.
+    assert_eq!(
+        ShapeI::try_new(0.15, 0.15, 0.3, 0.0107, 0.0107, 0.0071, 0.2, 0.0, 0.0, 0.0, 0.0).map(|_| ()),
+        error("fillet", "must fit on the edges next to its corner")
+    );
+    assert_eq!(
+        ShapeT::try_new(0.2, 0.2, 0.01, 0.01, 0.0, 0.0, 0.3).map(|_| ()),
+        error("taper_angle", "must leave the flange some thickness at the toe")
+    );
+    assert_eq!(ShapeL::try_new(0.1, 0.1, 0.01, 0.1, 0.0, 0.0, 0.0, 0.0).map(|_| ()), error("width", "must exceed the web thickness"));
+
+    let valid = ShapeI::try_new(0.15, 0.15, 0.3, 0.0107, 0.0107, 0.0071, 0.015, 0.0, 0.0, 0.0, 0.0).unwrap();
+    assert_almost_eq!(valid.area(), ShapeI::from_designation("IPE 300").unwrap().area());
+    assert_eq!(error("web_thickness", "must be positive").unwrap_err().to_string(), "web_thickness must be positive");
+}
+
+#[test]
+#[should_panic(expected = "hole_radius must be less than the radius")]
+fn new_panics_with_the_shape_error() { Disk::new(0.1, 0.1); }
+
+#[test]
+fn linearized_by_tolerance_scales_with_radius() {
+    // A small bar needs far fewer sides than the fixed 256 for the same error.
+    let bar = Disk::new(0.01, 0.0).linearized_by_tolerance(1e-5);
+    let pile = Disk::new(1.0, 0.5).linearized_by_tolerance(1e-5);
+    assert_eq!(bar.vertices().len(), 4 * 18);
+    assert!(pile.vertices().len() > 256);
+    assert!(pile.holes()[0].len() < pile.vertices().len());
+    assert!(relative_error(PI * 0.01f64.powi(2), bar.area()) < 2e-3);
+
+    // Rolled profiles size every arc by its own radius.
+    let ipe = ShapeI::from_designation("IPE 300").unwrap();
+    let coarse = ipe.linearized_by_tolerance(1e-3);
+    let fine = ipe.linearized_by_tolerance(1e-6);
+    assert_eq!(coarse.vertices().len(), 12 + 4 * 3);
+    assert!(fine.vertices().len() > ipe.linearized(8).vertices().len());
+    assert!(relative_error(ipe.linearized(64).area(), fine.area()) < 1e-5);
+
+    let tube = ShapeBox::new(0.2, 0.1, 0.01, 0.02).linearized_by_tolerance(1e-4);
+    assert!(tube.holes()[0].len() < tube.vertices().len());
+}
+
+#[test]
+fn hollow_sections_match_closed_form() {
+    // Sharp corners: a rectangle less its hole.
+    let sharp = ShapeBox::new(0.2, 0.1, 0.01, 0.0);
+    assert_relative_eq("sharp box area", 0.2 * 0.1 - 0.18 * 0.08, sharp.area());
+    let inertia = sharp.second_moment_of_area();
+    assert_relative_eq("sharp box Ix", (0.2 * 0.1f64.powi(3) - 0.18 * 0.08f64.powi(3)) / 12.0, inertia[(0, 0)]);
+    assert_relative_eq("sharp box Iy", (0.1 * 0.2f64.powi(3) - 0.08 * 0.18f64.powi(3)) / 12.0, inertia[(1, 1)]);
+    assert_eq!(sharp.linearized(16).holes().len(), 1);
+
+    // SHS 100x100x5 with the cold-formed radii 2t outside and t inside.
+    let shs = ShapeBox::new(0.1, 0.1, 0.005, 0.01);
+    assert_almost_eq!(shs.inner_radius(), 0.005);
+    assert!(relative_error(18.4e-4, shs.area()) < 1e-2);
+    let rhs = ShapeBox::new(0.2, 0.1, 0.008, 0.016);
+    let polygon = rhs.linearized(256);
+    let (exact, linear) = (rhs.second_moment_of_area(), polygon.about_centroid_global());
+    assert!(relative_error(rhs.area(), polygon.area()) < 1e-5);
+    assert!(relative_error(exact[(0, 0)], linear[(0, 0)]) < 1e-5);
+    assert!(relative_error(exact[(1, 1)], linear[(1, 1)]) < 1e-5);
+    assert!(relative_error(rhs.perimeter(), polygon.lines().iter().map(|line| line.length()).sum()) < 1e-5);
+
+    // CHS 168.3x8.
+    let chs = ShapeTube::new(0.1683, 0.008);
+    let inner: f64 = 0.1683 - 2.0 * 0.008;
+    assert_relative_eq("CHS area", PI * (0.1683f64.powi(2) - inner.powi(2)) / 4.0, chs.area());
+    assert!(relative_error(40.3e-4, chs.area()) < 1e-2);
+    assert!(relative_error(1297e-8, chs.second_moment_of_area()[(0, 0)]) < 1e-2);
+    assert_eq!(chs.linearized(0).holes().len(), 1);
+}
+
+#[test]
+fn compound_plate_girder_combines_parts() {
+    // 800x10 web with a 300x20 top and a 200x20 bottom flange.
+    let girder = CompoundShape::new()
+        .with_part(Rectangle::new(0.01, 0.8, 0.0, 0.0), Vector2d::new(0.0, 0.0), 0.0)
+        .with_part(Rectangle::new(0.3, 0.02, 0.0, 0.0), Vector2d::new(0.0, 0.41), 0.0)
+        .with_part(Rectangle::new(0.02, 0.2, 0.0, 0.0), Vector2d::new(0.0, -0.41), PI / 2.0);
+    let (web, top, bottom) = (0.008, 0.006, 0.004);
+    assert_relative_eq("girder area", web + top + bottom, girder.area());
+    let y = (top * 0.41 - bottom * 0.41) / (web + top + bottom);
+    assert_vec3_almost_eq!(girder.centroid(), Vector3d::new(0.0, y, 0.0));
+
+    let ix = 0.01 * 0.8f64.powi(3) / 12.0 + web * y * y
+        + 0.3 * 0.02f64.powi(3) / 12.0 + top * (0.41 - y).powi(2)
+        + 0.2 * 0.02f64.powi(3) / 12.0 + bottom * (0.41 + y).powi(2);
+    let iy = (0.8 * 0.01f64.powi(3) + 0.02 * 0.3f64.powi(3) + 0.02 * 0.2f64.powi(3)) / 12.0;
+    let inertia = girder.second_moment_of_area();
+    assert_relative_eq("girder Ix", ix, inertia[(0, 0)]);
+    assert_relative_eq("girder Iy", iy, inertia[(1, 1)]);
+    assert!(inertia[(0, 1)].abs() < 1e-15);
+
+    // The parts merge into one I-shaped outline with the same properties.
+    let outline = girder.linearized(0);
+    assert_relative_eq("outline area", girder.area(), outline.area());
+    assert_relative_eq("outline Ix", ix, outline.about_centroid_global()[(0, 0)]);
+    // Flange faces, less the web joints, plus the flange edges and both web faces.
+    assert_relative_eq("outline perimeter", 2.0 * (0.3 + 0.2) - 2.0 * 0.01 + 4.0 * 0.02 + 2.0 * 0.8, girder.perimeter());
+}
+
+#[test]
+fn compound_battened_column_keeps_chords_apart() {
+    // Two UPN 200 toes facing each other, 100 mm apart, with rotated angles in the middle.
+    let channel = || ShapeC::new(0.075, 0.075, 0.2, 0.0115, 0.0115, 0.0085, 0.0115, 0.006, 0.006, 0.0, 0.0, 0.08f64.atan(), 0.08f64.atan());
+    let column = CompoundShape::new()
+        .with_part(channel(), Vector2d::new(-0.125, 0.0), 0.0)
+        .with_part(channel(), Vector2d::new(0.125, 0.0), PI);
+    let single = channel();
+    assert_eq!(column.part_count(), 2);
+    assert_relative_eq("column area", 2.0 * single.area(), column.area());
+    assert_vec3_almost_eq!(column.centroid(), Vector3d::new(0.0, 0.0, 0.0));
+
+    // Chords about the column axes: Ix doubles, Iy adds the Steiner terms.
+    let (own, area) = (single.second_moment_of_area(), single.area());
+    let distance = 0.125 - single.centroid().x();
+    let inertia = column.second_moment_of_area();
+    assert_relative_eq("column Ix", 2.0 * own[(0, 0)], inertia[(0, 0)]);
+    assert_relative_eq("column Iy", 2.0 * (own[(1, 1)] + area * distance * distance), inertia[(1, 1)]);
+
+    let outlines = column.outlines(8);
+    assert_eq!(outlines.len(), 2);
+    assert_relative_eq("outline areas", column.area(), outlines.iter().map(|outline| outline.area()).sum());
+}
+
+/// Rotate the outline about Z, lift it and shift it, as a DXF import would place it.
+fn placed(vertices: &[Vector3d], angle: f64) -> Vec<Vector3d> {
+    let (s, c) = angle.sin_cos();
+    vertices
+        .iter()
+        .map(|v| Vector3d::new(c * v.x() - s * v.y() + 5.0, s * v.x() + c * v.y() - 2.0, 3.0))
+        .collect()
+}
+
+#[test]
+fn classify_recognises_standard_profiles() {
+    let tol = 1e-6;
+    let i = ShapeI::new(0.2, 0.15, 0.4, 0.02, 0.015, 0.01, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let polygon = Polygon::new(placed(i.to_polygon().vertices(), 0.3));
+    let Some(ClassifiedShape::I(fitted)) = <dyn Shape>::classify(&polygon, tol) else { panic!("expected an I") };
+    assert_almost_eq!(fitted.bottom_width, 0.2);
+    assert_almost_eq!(fitted.top_width, 0.15);
+    assert_almost_eq!(fitted.height, 0.4);
+    assert_almost_eq!(fitted.top_thickness, 0.015);
+    assert_almost_eq!(fitted.web_thickness, 0.01);
+
+    let c = ShapeC::new(0.08, 0.08, 0.3, 0.012, 0.012, 0.007, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let mut reversed = placed(c.to_polygon().vertices(), 2.0);
+    reversed.reverse();
+    let Some(ClassifiedShape::C(fitted)) = <dyn Shape>::classify(&Polygon::new(reversed), tol) else { panic!("expected a C") };
+    assert_almost_eq!(fitted.height, 0.3);
+    assert_almost_eq!(fitted.web_thickness, 0.007);
+
+    let l = ShapeL::new(0.1, 0.15, 0.01, 0.012, 0.0, 0.0, 0.0, 0.0);
+    let shape = <dyn Shape>::classify(&Polygon::new(placed(l.to_polygon().vertices(), -1.0)), tol).unwrap();
+    assert!(matches!(shape, ClassifiedShape::L(_)));
+    assert_almost_eq!(shape.area(), l.area());
+
+    let t = ShapeT::new(0.2, 0.25, 0.02, 0.012, 0.0, 0.0, 0.0);
+    let shape = <dyn Shape>::classify(&Polygon::new(placed(t.to_polygon().vertices(), PI)), tol).unwrap();
+    let ClassifiedShape::T(fitted) = shape else { panic!("expected a T") };
+    assert_almost_eq!(fitted.flange_thickness, 0.02);
+
+    let rectangle = Polygon::new(placed(Rectangle::new(0.3, 0.5, 0.0, 0.0).to_polygon().vertices(), 0.7));
+    let Some(ClassifiedShape::Rectangle(fitted)) = <dyn Shape>::classify(&rectangle, tol) else { panic!("expected a rectangle") };
+    assert_almost_eq!(fitted.width * fitted.height, 0.15);
+}
+
+#[test]
+fn classify_fits_radii_and_taper_of_rolled_profiles() {
+    let tol = 1e-6;
+    let ipe = ShapeI::from_designation("IPE 300").unwrap();
+    let polygon = Polygon::new(placed(ipe.to_polygon().vertices(), 0.5));
+    let Some(ClassifiedShape::I(fitted)) = <dyn Shape>::classify(&polygon, tol) else { panic!("expected an I") };
+    assert_almost_eq!(fitted.height, 0.3);
+    assert_almost_eq!(fitted.top_thickness, 0.0107);
+    assert_almost_eq!(fitted.web_thickness, 0.0071);
+    assert_almost_eq!(fitted.fillet, 0.015);
+    assert_almost_eq!(fitted.area(), ipe.area());
+
+    let upn = ShapeC::from_designation("UPN 200").unwrap();
+    let Some(ClassifiedShape::C(fitted)) = <dyn Shape>::classify(&Polygon::new(placed(upn.to_polygon().vertices(), -2.5)), tol) else {
+        panic!("expected a C")
+    };
+    assert_almost_eq!(fitted.bottom_thickness, upn.bottom_thickness);
+    assert_almost_eq!(fitted.fillet, upn.fillet);
+    assert_almost_eq!(fitted.top_toe_radius, upn.top_toe_radius);
+    assert_almost_eq!(fitted.top_taper_angle, upn.top_taper_angle);
+
+    let angle = ShapeL::from_designation("L 100x10").unwrap();
+    let Some(ClassifiedShape::L(fitted)) = <dyn Shape>::classify(&Polygon::new(placed(angle.to_polygon().vertices(), 1.2)), tol) else {
+        panic!("expected an L")
+    };
+    assert_almost_eq!(fitted.flange_thickness, 0.01);
+    assert_almost_eq!(fitted.toe_radius, angle.toe_radius);
+
+    let tee = ShapeT::new(0.2, 0.25, 0.02, 0.012, 0.01, 0.005, 0.05);
+    let Some(ClassifiedShape::T(fitted)) = <dyn Shape>::classify(&Polygon::new(placed(tee.to_polygon().vertices(), 0.1)), tol) else {
+        panic!("expected a T")
+    };
+    assert_almost_eq!(fitted.flange_thickness, 0.02);
+    assert_almost_eq!(fitted.taper_angle, 0.05);
+}
+
+#[test]
+fn classify_recognises_tubes_and_rejects_others() {
+    let tol = 1e-6;
+    let circle = |r: f64| (0..32).map(move |k| {
+        let a = k as f64 * PI / 16.0;
+        Vector3d::new(r * a.cos(), r * a.sin(), 0.0)
+    });
+    let mut hole: Vec<Vector3d> = circle(0.08).collect();
+    hole.reverse();
+    let tube = Polygon::with_holes(circle(0.1), [hole]);
+    let Some(ClassifiedShape::Tube(disk)) = <dyn Shape>::classify(&tube, tol) else { panic!("expected a tube") };
+    assert_almost_eq!(disk.radius, 0.1);
+    assert_almost_eq!(disk.hole_radius, 0.08);
+
+    let outer = Rectangle::new(0.2, 0.1, 0.0, 0.0).to_polygon();
+    let mut inner = Rectangle::new(0.18, 0.08, 0.0, 0.0).to_polygon();
+    inner.reverse();
+    let rhs = Polygon::with_holes(placed(outer.vertices(), 0.4), [placed(inner.vertices(), 0.4)]);
+    let Some(ClassifiedShape::RectangularTube(fitted)) = <dyn Shape>::classify(&rhs, tol) else { panic!("expected a tube") };
+    assert_almost_eq!(fitted.width.max(fitted.height), 0.2);
+    assert_almost_eq!(fitted.hole_width.min(fitted.hole_height), 0.08);
+
+    // An I with a flange shifted off the web axis is no standard profile.
+    let mut vertices = ShapeI::new(0.2, 0.2, 0.4, 0.02, 0.02, 0.01, 0.0, 0.0, 0.0, 0.0, 0.0).to_polygon().vertices().clone();
+    for v in vertices.iter_mut().take(3) {
+        *v = Vector3d::new(v.x() + 0.03, v.y(), 0.0);
+    }
+    vertices[11] = Vector3d::new(vertices[11].x() + 0.03, vertices[11].y(), 0.0);
+    assert!(<dyn Shape>::classify(&Polygon::new(vertices), tol).is_none());
+    let triangle = Polygon::new([Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0)]);
+    assert!(<dyn Shape>::classify(&triangle, tol).is_none());
+}
+
+#[test]
+fn generic_shape_cleans_outline_points() {
+    // Clockwise L outline with a repeated point, a collinear midpoint and a spike.
+    let shape = GenericShape::from_points([
+        (0.0, 0.0, 0.0),
+        (0.0, 0.3, 0.0),
+        (0.1, 0.3, 0.0),
+        (0.1, 0.1, 0.0),
+        (0.1, 0.1, 0.0),
+        (0.2, 0.1, 0.0),
+        (0.25, 0.1, 0.0),
+        (0.2, 0.1, 0.0),
+        (0.2, 0.0, 0.0),
+        (0.1, 0.0, 0.0),
+    ])
+    .unwrap();
+    let polygon = shape.to_polygon();
+    assert_eq!(polygon.vertices().len(), 6);
+    assert_vec3_almost_eq!(polygon.axis(geometry::Axis::AxisZ), Vector3d::new(0.0, 0.0, 1.0));
+    assert_almost_eq!(shape.area(), 0.04);
+    assert_vec3_almost_eq!(shape.centroid(), Vector3d::new(0.075, 0.125, 0.0));
+    let reference = Polygon::new([(0.0, 0.0, 0.0), (0.2, 0.0, 0.0), (0.2, 0.1, 0.0), (0.1, 0.1, 0.0), (0.1, 0.3, 0.0), (0.0, 0.3, 0.0)]);
+    assert_almost_eq!(shape.second_moment_of_area()[(0, 0)], reference.about_centroid_global()[(0, 0)]);
+    assert_almost_eq!(shape.second_moment_of_area()[(1, 1)], reference.about_centroid_global()[(1, 1)]);
+}
+
+#[test]
+fn generic_shape_untangles_crossing_edges() {
+    // Rectangle corners listed in bow-tie order, out of the XY plane.
+    let shape = GenericShape::from_points([(0.0, 0.0, 1.0), (0.4, 0.2, 1.0), (0.4, 0.0, 1.0), (0.0, 0.2, 1.0)]).unwrap();
+    assert_almost_eq!(shape.area(), 0.08);
+    assert_almost_eq!(shape.perimeter(), 1.2);
+    assert_vec3_almost_eq!(shape.centroid(), Vector3d::new(0.2, 0.1, 0.0));
+
+    assert!(GenericShape::from_points([(0.0, 0.0, 0.0), (1.0, 1.0, 0.0), (2.0, 2.0, 0.0), (1.0, 1.0, 0.0)]).is_none());
+    assert!(GenericShape::from_points([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)]).is_none());
+}
@@ -0,0 +1,97 @@
+use geometry::{Polygon, ThinWalledSection, Vector2d, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+const H: f64 = 0.2;
+const B: f64 = 0.08;
+const T: f64 = 0.01;
+
+/// Channel with its web mid-line on the Y axis and the flanges towards +X;
+/// `H` and `B` are mid-line dimensions.
+fn channel() -> Polygon {
+    let (h, b, t) = (H / 2.0, B, T / 2.0);
+    let outline = [(-t, -h - t), (b, -h - t), (b, -h + t), (t, -h + t), (t, h - t), (b, h - t), (b, h + t), (-t, h + t)];
+    Polygon::new(outline.map(|(x, y)| (x, y, 0.0)))
+}
+
+fn thin_channel() -> ThinWalledSection {
+    ThinWalledSection::new()
+        .with_wall(Vector2d::new(B, -H / 2.0), Vector2d::new(0.0, -H / 2.0), T)
+        .with_wall(Vector2d::new(0.0, -H / 2.0), Vector2d::new(0.0, H / 2.0), T)
+        .with_wall(Vector2d::new(0.0, H / 2.0), Vector2d::new(B, H / 2.0), T)
+}
+
+#[test]
+fn rectangle_has_five_sixths_shear_area() {
+    let rectangle = Polygon::new([(0.0, 0.0, 0.0), (0.2, 0.0, 0.0), (0.2, 0.4, 0.0), (0.0, 0.4, 0.0)]);
+    let areas = rectangle.shear_areas();
+    assert_almost_eq!(areas.x(), 0.08 / 1.2, 2e-3);
+    assert_almost_eq!(areas.y(), 0.08 / 1.2, 2e-3);
+    assert_vec3_almost_eq!("center", rectangle.shear_center(), Vector3d::new(0.1, 0.2, 0.0), 1e-4);
+}
+
+#[test]
+fn channel_shear_center_lies_behind_the_web() {
+    // Thin-walled closed form: e = 3 b² / (6 b + h) behind the web mid-line.
+    let e = 3.0 * B * B / (6.0 * B + H);
+    let thin = thin_channel().shear_center().unwrap();
+    assert_almost_eq!(thin.x(), -e, 1e-9);
+    assert!(thin.y().abs() < 1e-12);
+
+    let solid = channel().shear_center();
+    assert_almost_eq!(solid.x(), -e, 2e-2);
+    assert!(solid.y().abs() < 1e-5);
+
+    // Shear along the web is carried mostly by the web; the solid section
+    // adds the corners the mid-line model leaves out.
+    let (thin, solid) = (thin_channel().shear_areas().unwrap(), channel().shear_areas());
+    assert!(thin.y() > 0.8 * H * T && thin.y() < H * T);
+    assert_almost_eq!(solid.x(), thin.x(), 5e-2);
+    assert_almost_eq!(solid.y(), thin.y(), 5e-2);
+}
+
+#[test]
+fn thin_walled_profiles() {
+    // The shear center of an angle is at the junction of its legs.
+    let angle = ThinWalledSection::new()
+        .with_wall(Vector2d::new(0.1, 0.0), Vector2d::new(0.0, 0.0), T)
+        .with_wall(Vector2d::new(0.0, 0.0), Vector2d::new(0.0, 0.15), T);
+    let center = angle.shear_center().unwrap();
+    assert!(center.x().abs() < 1e-12 && center.y().abs() < 1e-12);
+
+    // Strip: parabolic shear flow, A / 1.2.
+    let strip = ThinWalledSection::new().with_wall(Vector2d::new(0.0, 0.0), Vector2d::new(0.0, 0.3), T);
+    assert_almost_eq!(strip.shear_areas().unwrap().y(), 0.3 * T / 1.2);
+
+    // Closed cells are not open profiles.
+    let closed = thin_channel().with_wall(Vector2d::new(B, H / 2.0), Vector2d::new(B, -H / 2.0), T);
+    assert!(closed.shear_center().is_none());
+    assert!(closed.shear_areas().is_none());
+}
+
+#[test]
+fn warping_constant_of_an_i_section() {
+    // Flanges 0.1 × 0.01 at 0.2 between mid-lines, web 0.006 thick.
+    let (b, h, tf, tw): (f64, f64, f64, f64) = (0.1, 0.2, 0.01, 0.006);
+    let expected = b.powi(3) * h * h * tf / 24.0;
+    let thin = ThinWalledSection::new()
+        .with_wall(Vector2d::new(-b / 2.0, 0.0), Vector2d::new(0.0, 0.0), tf)
+        .with_wall(Vector2d::new(0.0, 0.0), Vector2d::new(b / 2.0, 0.0), tf)
+        .with_wall(Vector2d::new(0.0, 0.0), Vector2d::new(0.0, h), tw)
+        .with_wall(Vector2d::new(-b / 2.0, h), Vector2d::new(0.0, h), tf)
+        .with_wall(Vector2d::new(0.0, h), Vector2d::new(b / 2.0, h), tf);
+    assert_almost_eq!(thin.warping_constant().unwrap(), expected, 1e-9);
+
+    let (x, y, f, w) = (b / 2.0, h / 2.0, tf / 2.0, tw / 2.0);
+    let outline = [
+        (-x, -y - f), (x, -y - f), (x, -y + f), (w, -y + f), (w, y - f), (x, y - f),
+        (x, y + f), (-x, y + f), (-x, y - f), (-w, y - f), (-w, -y + f), (-x, -y + f),
+    ];
+    let solid = Polygon::new(outline.map(|(x, y)| (x, y, 0.0)));
+    assert_almost_eq!(solid.warping_constant(), expected, 5e-2);
+
+    // The legs of an angle meet at the shear center: no warping of the mid-line.
+    let angle = ThinWalledSection::new()
+        .with_wall(Vector2d::new(0.1, 0.0), Vector2d::new(0.0, 0.0), T)
+        .with_wall(Vector2d::new(0.0, 0.0), Vector2d::new(0.0, 0.15), T);
+    assert!(angle.warping_constant().unwrap().abs() < 1e-15);
+}
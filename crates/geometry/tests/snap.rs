@@ -0,0 +1,38 @@
+use geometry::snap::VertexWelder;
+use geometry::Vector3d;
+
+#[test]
+fn welder_reuses_vertices_within_tolerance() {
+    let mut welder = VertexWelder::new(1e-3);
+    let a = welder.insert(Vector3d::new(0.0, 0.0, 0.0));
+    let b = welder.insert(Vector3d::new(1.0, 0.0, 0.0));
+    // Across a cell boundary, still within tolerance.
+    assert_eq!(welder.insert(Vector3d::new(-0.0004, 0.0003, 0.0)), a);
+    assert_eq!(welder.insert(Vector3d::new(0.9995, 0.0, 0.0)), b);
+    assert_eq!(welder.insert(Vector3d::new(0.0, 0.002, 0.0)), 2);
+    assert_eq!(welder.len(), 3);
+    assert_eq!(welder.find(&Vector3d::new(1.0, 0.0, 0.0009)), Some(b));
+    assert_eq!(welder.find(&Vector3d::new(0.5, 0.0, 0.0)), None);
+    assert_eq!(welder.vertices()[a], Vector3d::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn batch_welding_does_not_depend_on_order() {
+    // A chain of points each within tolerance of the next forms one group.
+    let points = [
+        Vector3d::new(0.0016, 0.0, 0.0),
+        Vector3d::new(5.0, 5.0, 5.0),
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(0.0008, 0.0, 0.0),
+        Vector3d::new(5.0, 5.0, 5.0005),
+    ];
+    let (vertices, indices) = VertexWelder::weld(&points, 1e-3);
+    assert_eq!(indices, vec![0, 1, 0, 0, 1]);
+    assert_eq!(vertices, vec![Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(5.0, 5.0, 5.0)]);
+
+    let mut reversed = points;
+    reversed.reverse();
+    let (again, indices) = VertexWelder::weld(&reversed, 1e-3);
+    assert_eq!(indices, vec![0, 1, 1, 0, 1]);
+    assert_eq!(again, vec![Vector3d::new(5.0, 5.0, 5.0), Vector3d::new(0.0, 0.0, 0.0)]);
+}
@@ -0,0 +1,85 @@
+use geometry::spatial::{Bounded, Bvh};
+use geometry::{Line, Vector3d};
+use utils::assert_almost_eq;
+
+/// Deterministic scattered points in a 10 × 10 × 10 cube.
+fn scattered(count: usize) -> Vec<Vector3d> {
+    let mut state = 12345_u64;
+    let mut next = || {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 * 10.0
+    };
+    (0..count).map(|_| Vector3d::new(next(), next(), next())).collect()
+}
+
+#[test]
+fn point_queries_match_brute_force() {
+    let points = scattered(500);
+    let bvh = Bvh::new(points.clone());
+    assert_eq!(bvh.len(), 500);
+
+    for query in scattered(20).iter().map(|p| Vector3d::new(p.x() - 1.0, p.y() + 0.5, p.z())) {
+        let (index, distance) = bvh.nearest(&query).unwrap();
+        let brute = points.iter().map(|p| p.distance(&query)).fold(f64::INFINITY, f64::min);
+        assert_almost_eq!(distance, brute);
+        assert_almost_eq!(points[index].distance(&query), brute);
+
+        let within: Vec<usize> = (0..points.len()).filter(|&i| points[i].distance(&query) <= 1.5).collect();
+        assert_eq!(bvh.within(&query, 1.5), within);
+    }
+
+    let (min, max) = (Vector3d::new(2.0, 3.0, 4.0), Vector3d::new(5.0, 6.0, 4.5));
+    let inside: Vec<usize> = (0..points.len())
+        .filter(|&i| (0..3).all(|k| points[i].0[k] >= min.0[k] && points[i].0[k] <= max.0[k]))
+        .collect();
+    assert!(!inside.is_empty());
+    assert_eq!(bvh.query_box(max, min), inside);
+}
+
+#[test]
+fn ray_query_returns_hits_in_order() {
+    // Unit segments along Y, stacked along X.
+    let lines: Vec<Line> = (0..10).map(|i| Line::new((i as f64, -0.5, 0.0), (i as f64, 0.5, 0.0))).collect();
+    let bvh: Bvh<Line> = lines.into_iter().collect();
+    let hits = bvh.query_ray(&Vector3d::new(2.5, 0.0, 0.0), &Vector3d::new(2.0, 0.0, 0.0));
+    assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), vec![3, 4, 5, 6, 7, 8, 9]);
+    assert_almost_eq!(hits[0].1, 0.5);
+    assert!(bvh.query_ray(&Vector3d::new(2.5, 0.0, 1.0), &Vector3d::new(1.0, 0.0, 0.0)).is_empty());
+    assert!(bvh.query_ray(&Vector3d::new(2.5, 0.0, 0.0), &Vector3d::new(0.0, 0.0, 0.0)).is_empty());
+}
+
+#[test]
+fn overlapping_pairs_of_line_grid() {
+    // Four lines along X crossing four lines along Y, each pair meeting once.
+    let mut lines: Vec<Line> = (0..4).map(|i| Line::new((0.0, i as f64, 0.0), (3.0, i as f64, 0.0))).collect();
+    lines.extend((0..4).map(|i| Line::new((i as f64 + 0.5, -1.0, 0.0), (i as f64 + 0.5, 4.0, 0.0))));
+    let bvh = Bvh::new(lines.iter().collect::<Vec<_>>());
+
+    let pairs = bvh.overlapping_pairs();
+    let crossing: Vec<(usize, usize)> = (0..4).flat_map(|i| (4..7).map(move |j| (i, j))).collect();
+    assert_eq!(pairs, crossing);
+    assert_almost_eq!(bvh.get(0).distance(&Vector3d::new(1.0, 0.5, 0.0)), 0.5);
+}
+
+#[test]
+fn empty_tree_answers_nothing() {
+    let bvh: Bvh<Vector3d> = Bvh::new(Vec::new());
+    assert!(bvh.is_empty());
+    assert!(bvh.nearest(&Vector3d::new(0.0, 0.0, 0.0)).is_none());
+    assert!(bvh.overlapping_pairs().is_empty());
+}
+
+#[test]
+fn arcs_are_bounded_by_their_sweep() {
+    let arcs = vec![
+        geometry::Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(-1.0, 0.0, 0.0), false),
+        geometry::Arc::new(Vector3d::new(5.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0), Vector3d::new(6.0, 0.0, 0.0), false),
+    ];
+    let bvh = Bvh::new(arcs);
+    // Only the top of the first arc reaches y = 1; its chord stays at y = 0.
+    assert_eq!(bvh.query_box(Vector3d::new(-0.5, 0.9, -0.1), Vector3d::new(0.5, 1.1, 0.1)), vec![0]);
+    assert_eq!(bvh.query_box(Vector3d::new(4.5, -1.1, -0.1), Vector3d::new(5.5, -0.9, 0.1)), vec![1]);
+    let (nearest, distance) = bvh.nearest(&Vector3d::new(0.0, 3.0, 0.0)).unwrap();
+    assert_eq!(nearest, 0);
+    assert_almost_eq!(distance, 2.0);
+}
@@ -0,0 +1,133 @@
+use geometry::{bounding_sphere, Cylinder, Line, Polygon, Ray, Sphere, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+#[test]
+fn bounding_sphere_of_points_on_a_sphere() {
+    let center = Vector3d::new(1.0, 2.0, 3.0);
+    // Fibonacci lattice on a sphere of radius 3 plus interior points.
+    let golden = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+    let mut points: Vec<Vector3d> = (0..200)
+        .map(|i| {
+            let z = 1.0 - 2.0 * (i as f64 + 0.5) / 200.0;
+            let r = (1.0 - z * z).sqrt();
+            let angle = golden * i as f64;
+            Vector3d::new(center.x() + 3.0 * r * angle.cos(), center.y() + 3.0 * r * angle.sin(), center.z() + 3.0 * z)
+        })
+        .collect();
+    points.extend((0..50).map(|i| Vector3d::new(center.x() + 0.02 * i as f64, center.y(), center.z())));
+
+    let sphere = bounding_sphere(&points).unwrap();
+    assert!(points.iter().all(|p| sphere.contains(p)));
+    assert!(sphere.radius() <= 3.0 + 1e-9);
+    assert!(sphere.radius() > 2.95);
+    assert!((sphere.center().0 - center.0).norm() < 0.05);
+    assert!(bounding_sphere(&[]).is_none());
+}
+
+#[test]
+fn bounding_sphere_of_degenerate_sets() {
+    // Sorted collinear points: the end points define the sphere.
+    let points: Vec<Vector3d> = (0..=1000).map(|i| Vector3d::new(i as f64 * 0.01, 0.0, 0.0)).collect();
+    let sphere = bounding_sphere(&points).unwrap();
+    assert_vec3_almost_eq!(sphere.center(), Vector3d::new(5.0, 0.0, 0.0));
+    assert_almost_eq!(sphere.radius(), 5.0);
+
+    // Coplanar square corners lie on one circle.
+    let square = [
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(2.0, 0.0, 0.0),
+        Vector3d::new(2.0, 2.0, 0.0),
+        Vector3d::new(0.0, 2.0, 0.0),
+        Vector3d::new(1.0, 1.0, 0.0),
+    ];
+    let sphere = bounding_sphere(&square).unwrap();
+    assert_vec3_almost_eq!(sphere.center(), Vector3d::new(1.0, 1.0, 0.0));
+    assert_almost_eq!(sphere.radius(), 2.0_f64.sqrt());
+
+    let single = bounding_sphere(&[Vector3d::new(1.0, 1.0, 1.0)]).unwrap();
+    assert_eq!(single.radius(), 0.0);
+}
+
+#[test]
+fn bounding_spheres_of_lines_and_polygons() {
+    let line = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 6.0, 8.0));
+    let sphere = line.bounding_sphere();
+    assert_vec3_almost_eq!(sphere.center(), Vector3d::new(0.0, 3.0, 4.0));
+    assert_almost_eq!(sphere.radius(), 5.0);
+
+    let triangle = Polygon::new([Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(4.0, 0.0, 0.0), Vector3d::new(0.0, 3.0, 0.0)]);
+    let sphere = triangle.bounding_sphere();
+    assert_vec3_almost_eq!(sphere.center(), Vector3d::new(2.0, 1.5, 0.0));
+    assert_almost_eq!(sphere.radius(), 2.5);
+
+    // An obtuse triangle is bounded by its longest side.
+    let obtuse = Polygon::new([Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(10.0, 0.0, 0.0), Vector3d::new(5.0, 1.0, 0.0)]);
+    assert_almost_eq!(obtuse.bounding_sphere().radius(), 5.0);
+}
+
+#[test]
+fn sphere_queries() {
+    let a = Sphere::new((0.0, 0.0, 0.0), 1.0);
+    let b = Sphere::new((3.0, 0.0, 0.0), 1.0);
+    assert!(!a.intersects(&b));
+    assert!(a.intersects(&Sphere::new((2.0, 0.0, 0.0), 1.0)));
+    let merged = a.merged(&b);
+    assert_vec3_almost_eq!(merged.center(), Vector3d::new(1.5, 0.0, 0.0));
+    assert_almost_eq!(merged.radius(), 2.5);
+    assert_eq!(merged.merged(&a), merged);
+}
+
+#[test]
+fn spheres_meet_lines_and_rays() {
+    let sphere = Sphere::new((1.0, 0.0, 0.0), 2.0);
+    let through = Line::new(Vector3d::new(-4.0, 0.0, 0.0), Vector3d::new(6.0, 0.0, 0.0));
+    let hits = sphere.intersection_with_line(&through);
+    assert_eq!(hits.len(), 2);
+    assert_vec3_almost_eq!(hits[0], Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(hits[1], Vector3d::new(3.0, 0.0, 0.0));
+    // A segment ending inside pierces once; a tangent touches once.
+    assert_eq!(sphere.intersection_with_line(&Line::new(Vector3d::new(-4.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0))).len(), 1);
+    let tangent = Line::new(Vector3d::new(1.0, 2.0, -5.0), Vector3d::new(1.0, 2.0, 5.0));
+    assert_eq!(sphere.intersection_with_line(&tangent).len(), 1);
+    assert!(sphere.intersection_with_line(&Line::new(Vector3d::new(0.0, 3.0, 0.0), Vector3d::new(2.0, 3.0, 0.0))).is_empty());
+
+    let ray = Ray::new(Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 1.0));
+    let exits = ray.intersection_with_sphere(&sphere, None);
+    assert_eq!(exits.len(), 1);
+    assert_vec3_almost_eq!(exits[0], Vector3d::new(1.0, 0.0, 2.0));
+
+    assert_vec3_almost_eq!(sphere.closest_point(&Vector3d::new(1.0, 5.0, 0.0)), Vector3d::new(1.0, 2.0, 0.0));
+    assert_almost_eq!(sphere.signed_distance(&Vector3d::new(1.0, 0.5, 0.0)), -1.5);
+}
+
+#[test]
+fn cylinders_bound_a_member() {
+    let member = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 4.0));
+    let envelope = Cylinder::around(&member, 0.5);
+    assert_almost_eq!(envelope.length(), 4.0);
+    assert!(envelope.contains(&Vector3d::new(0.3, 0.3, 2.0), None));
+    assert!(!envelope.contains(&Vector3d::new(0.4, 0.4, 2.0), None));
+    assert!(!envelope.contains(&Vector3d::new(0.0, 0.0, 4.1), None));
+    assert!(envelope.contains(&Vector3d::new(0.0, 0.0, 4.1), Some(0.2)));
+
+    // Across the side, through a cap and the side, and along the axis.
+    let across = envelope.intersection_with_line(&Line::new(Vector3d::new(-2.0, 0.0, 1.0), Vector3d::new(2.0, 0.0, 1.0)));
+    assert_eq!(across.len(), 2);
+    assert_vec3_almost_eq!(across[0], Vector3d::new(-0.5, 0.0, 1.0));
+    assert_vec3_almost_eq!(across[1], Vector3d::new(0.5, 0.0, 1.0));
+    let slanted = envelope.intersection_with_line(&Line::new(Vector3d::new(0.0, 0.0, 5.0), Vector3d::new(0.5, 0.0, 3.0)));
+    assert_eq!(slanted.len(), 2);
+    assert_vec3_almost_eq!(slanted[0], Vector3d::new(0.25, 0.0, 4.0));
+    assert_vec3_almost_eq!(slanted[1], Vector3d::new(0.5, 0.0, 3.0));
+    let ray = Ray::new(Vector3d::new(0.0, 0.0, -1.0), Vector3d::new(0.0, 0.0, 1.0));
+    let along = ray.intersection_with_cylinder(&envelope, None);
+    assert_eq!(along.len(), 2);
+    assert_vec3_almost_eq!(along[0], Vector3d::new(0.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(along[1], Vector3d::new(0.0, 0.0, 4.0));
+
+    assert_vec3_almost_eq!(envelope.closest_point(&Vector3d::new(3.0, 0.0, 2.0)), Vector3d::new(0.5, 0.0, 2.0));
+    assert_vec3_almost_eq!(envelope.closest_point(&Vector3d::new(0.1, 0.0, 3.9)), Vector3d::new(0.1, 0.0, 4.0));
+    assert_vec3_almost_eq!(envelope.closest_point(&Vector3d::new(3.0, 0.0, 7.0)), Vector3d::new(0.5, 0.0, 4.0));
+    assert_almost_eq!(envelope.signed_distance(&Vector3d::new(0.0, 3.5, 8.0)), 5.0);
+    assert_almost_eq!(envelope.signed_distance(&Vector3d::new(0.2, 0.0, 2.0)), -0.3);
+}
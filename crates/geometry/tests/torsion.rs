@@ -0,0 +1,50 @@
+use std::f64::consts::PI;
+
+use geometry::Polygon;
+use utils::assert_almost_eq;
+
+fn rectangle(width: f64, height: f64) -> Polygon {
+    Polygon::new([(0.0, 0.0, 0.0), (width, 0.0, 0.0), (width, height, 0.0), (0.0, height, 0.0)])
+}
+
+fn circle(radius: f64, segments: usize) -> Vec<(f64, f64, f64)> {
+    (0..segments)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / segments as f64;
+            (radius * angle.cos(), radius * angle.sin(), 0.0)
+        })
+        .collect()
+}
+
+#[test]
+fn solid_sections_match_closed_form_values() {
+    // Square: J = 0.1406 a⁴; 1:4 rectangle: J = 0.281 b t³ (Roark).
+    assert_almost_eq!(rectangle(0.1, 0.1).torsion_constant(), 0.1406 * 1e-4, 5e-3);
+    assert_almost_eq!(rectangle(0.4, 0.1).torsion_constant(), 0.281 * 0.4 * 1e-3, 5e-3);
+
+    // Fine polygon approximating a round bar: J = π r⁴ / 2.
+    let bar = Polygon::new(circle(0.05, 96));
+    assert_almost_eq!(bar.torsion_constant(), PI * 0.05f64.powi(4) / 2.0, 1e-2);
+}
+
+#[test]
+fn holes_carry_circulation() {
+    // Thick tube: J = π (R⁴ − r⁴) / 2, far above the open-section value.
+    let mut hole = circle(0.03, 64);
+    hole.reverse();
+    let tube = Polygon::with_holes(circle(0.05, 64), vec![hole]);
+    assert_almost_eq!(tube.torsion_constant(), PI * (0.05f64.powi(4) - 0.03f64.powi(4)) / 2.0, 2e-2);
+}
+
+#[test]
+fn thin_walled_sections_use_closed_form_fallbacks() {
+    // Strip 1 m by 4 mm: J = b t³ / 3.
+    let strip = rectangle(1.0, 0.004);
+    assert_almost_eq!(strip.torsion_constant(), 0.004f64.powi(3) / 3.0, 1e-2);
+
+    // Square tube 400 mm wide with 2 mm walls: Bredt, J = 4 Aₘ² t / sₘ.
+    let hole = vec![(0.002, 0.002, 0.0), (0.002, 0.398, 0.0), (0.398, 0.398, 0.0), (0.398, 0.002, 0.0)];
+    let tube = Polygon::with_holes(vec![(0.0, 0.0, 0.0), (0.4, 0.0, 0.0), (0.4, 0.4, 0.0), (0.0, 0.4, 0.0)], vec![hole]);
+    let side: f64 = 0.398;
+    assert_almost_eq!(tube.torsion_constant(), 4.0 * side.powi(4) * 0.002 / (4.0 * side), 1e-2);
+}
@@ -0,0 +1,171 @@
+use geometry::{Arc, Axis, Edge, Line, LocalAxis, Plane, Polygon, Transform3d, Transformable, Vector3d};
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
+
+#[test]
+fn transform_points_and_vectors() {
+    let rotation = Transform3d::rotation(FRAC_PI_2, (0.0, 0.0, 1.0));
+    assert_vec3_almost_eq!(rotation.apply_point(&Vector3d::new(1.0, 0.0, 0.0)), Vector3d::new(0.0, 1.0, 0.0));
+
+    let moved = Vector3d::new(1.0, 2.0, 3.0).transformed(&Transform3d::translation((1.0, 1.0, 1.0)));
+    assert_vec3_almost_eq!(moved, Vector3d::new(2.0, 3.0, 4.0));
+    // Vectors ignore the translation.
+    let shift = Transform3d::translation((5.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(shift.apply_vector(&Vector3d::new(0.0, 1.0, 0.0)), Vector3d::new(0.0, 1.0, 0.0));
+
+    let mirror = Transform3d::mirror(&Plane::new((1.0, 0.0, 0.0), (1.0, 0.0, 0.0)));
+    assert!(mirror.is_mirroring());
+    assert_vec3_almost_eq!(mirror.apply_point(&Vector3d::new(3.0, 2.0, 0.0)), Vector3d::new(-1.0, 2.0, 0.0));
+    let twice = mirror.then(&mirror);
+    assert!(!twice.is_mirroring());
+    assert_vec3_almost_eq!(twice.apply_point(&Vector3d::new(3.0, 2.0, 0.0)), Vector3d::new(3.0, 2.0, 0.0));
+
+    let matrix = Transform3d::scaling_about(2.0, (1.0, 1.0, 1.0)).matrix();
+    assert_almost_eq!(matrix[(0, 0)], 2.0);
+    assert_almost_eq!(matrix[(0, 3)], -1.0);
+}
+
+#[test]
+fn transform_lines_edges_and_arcs() {
+    let transform = Transform3d::rotation_about(FRAC_PI_2, (0.0, 0.0, 1.0), (1.0, 0.0, 0.0));
+    let line = Line::new(Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(3.0, 0.0, 0.0)).transformed(&transform);
+    assert_vec3_almost_eq!(line.start(), Vector3d::new(1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(line.end(), Vector3d::new(1.0, 2.0, 0.0));
+
+    let mut edge = Edge::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0));
+    edge.set_start_tangent(Vector3d::new(1.0, 0.0, 0.0));
+    edge.transform(&Transform3d::rotation(FRAC_PI_2, (0.0, 0.0, 1.0)));
+    assert_vec3_almost_eq!(edge.start_tangent().unwrap(), Vector3d::new(0.0, 1.0, 0.0));
+    assert!(edge.end_tangent().is_none());
+
+    let arc = Arc::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 1.0, 0.0), false);
+    let scaled = arc.transformed(&Transform3d::scaling(3.0).then(&Transform3d::translation((0.0, 0.0, 2.0))));
+    assert_almost_eq!(scaled.radius(), 3.0);
+    assert_almost_eq!(scaled.length(), 1.5 * PI);
+    assert_vec3_almost_eq!(scaled.point_at(0.5), Vector3d::new(1.5 * 2.0_f64.sqrt(), 1.5 * 2.0_f64.sqrt(), 2.0));
+
+    // Mirroring keeps the arc on the mirrored points.
+    let mirror = Transform3d::mirror(&Plane::new((0.0, 0.0, 0.0), (1.0, 0.0, 0.0)));
+    let mirrored = arc.transformed(&mirror);
+    assert_vec3_almost_eq!(mirrored.start(), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(mirrored.point_at(0.5), mirror.apply_point(&arc.point_at(0.5)));
+}
+
+#[test]
+fn transform_polygons() {
+    let polygon = Polygon::new([
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(2.0, 0.0, 0.0),
+        Vector3d::new(2.0, 1.0, 0.0),
+        Vector3d::new(0.0, 1.0, 0.0),
+    ]);
+    let transform = Transform3d::rotation(FRAC_PI_2, (1.0, 0.0, 0.0)).then(&Transform3d::scaling(2.0));
+    let transformed = polygon.transformed(&transform);
+    assert_almost_eq!(transformed.area(), 8.0);
+    assert_vec3_almost_eq!(transformed.centroid(), Vector3d::new(2.0, 0.0, 1.0));
+    let back = transformed.transformed(&transform.inverse().unwrap());
+    assert_vec3_almost_eq!(back.centroid(), polygon.centroid());
+
+    // The local frame is carried along rather than rebuilt from the outline.
+    let turn = Transform3d::rotation_about(1.0, (1.0, 1.0, 1.0), (0.5, 0.0, 0.0));
+    let turned = polygon.transformed(&turn);
+    for axis in [Axis::AxisX, Axis::AxisY, Axis::AxisZ] {
+        assert_vec3_almost_eq!(turned.axis(axis), turn.apply_vector(&polygon.axis(axis)));
+    }
+    assert_almost_eq!(turned.area(), polygon.area());
+}
+
+#[test]
+fn mirror_about_plane_keeps_frames_right_handed() {
+    let plane = Plane::new((2.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+    let mut point = Vector3d::new(0.0, 1.0, 1.0);
+    point.mirror_about_plane(&plane);
+    assert_vec3_almost_eq!(point, Vector3d::new(4.0, 1.0, 1.0));
+
+    let mut line = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 1.0, 0.0));
+    line.mirror_about_plane(&plane);
+    assert_vec3_almost_eq!(line.start(), Vector3d::new(4.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(line.end(), Vector3d::new(3.0, 1.0, 0.0));
+    let axes = line.rotation_matrix().unwrap();
+    assert_almost_eq!(axes.determinant(), 1.0);
+
+    // A stored frame is reflected with the line: local x keeps running along it.
+    let mut framed = Line::new(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(1.0, 1.0, 0.0));
+    let (ex, ey) = (nalgebra::Vector3::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), nalgebra::Vector3::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0));
+    framed.set_orientation_matrix(nalgebra::Matrix3::from_columns(&[ex, ey, ex.cross(&ey)]));
+    framed.mirror_about_plane(&plane);
+    assert_vec3_almost_eq!(framed.direction().unwrap(), Vector3d::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0));
+    assert_vec3_almost_eq!(framed.axis(Axis::AxisX).unwrap(), framed.direction().unwrap());
+    assert_vec3_almost_eq!(framed.axis(Axis::AxisY).unwrap(), Vector3d::new(-FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0));
+    assert_vec3_almost_eq!(framed.axis(Axis::AxisZ).unwrap(), Vector3d::new(0.0, 0.0, 1.0));
+    assert_almost_eq!(framed.rotation_matrix().unwrap().determinant(), 1.0);
+
+    let mut square = Polygon::new([
+        Vector3d::new(0.0, 0.0, 0.0),
+        Vector3d::new(1.0, 0.0, 0.0),
+        Vector3d::new(1.0, 1.0, 0.0),
+        Vector3d::new(0.0, 1.0, 0.0),
+    ]);
+    square.mirror_about_plane(&plane);
+    assert_almost_eq!(square.area(), 1.0);
+    assert_vec3_almost_eq!(square.centroid(), Vector3d::new(3.5, 0.5, 0.0));
+    // The mirrored winding turns the normal over; local x follows the outline.
+    assert_vec3_almost_eq!(square.axis(Axis::AxisZ), Vector3d::new(0.0, 0.0, -1.0));
+    assert_vec3_almost_eq!(square.axis(Axis::AxisX), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(square.axis(Axis::AxisY), Vector3d::new(0.0, 1.0, 0.0));
+    assert!(square.contains(&Vector3d::new(3.25, 0.75, 0.0), None));
+}
+
+#[test]
+fn local_axes_compose_and_invert() {
+    let member = LocalAxis::from_z_and_x_hint(Vector3d::new(1.0, 2.0, 0.0), Vector3d::new(0.0, 0.0, 2.0), Vector3d::new(1.0, 1.0, 5.0)).unwrap();
+    assert_vec3_almost_eq!(member.direction(Axis::AxisX), Vector3d::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0));
+    assert!(LocalAxis::from_z_and_x_hint(Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 1.0), Vector3d::new(0.0, 0.0, -3.0)).is_none());
+
+    let section = LocalAxis::from_axes(
+        Vector3d::new(0.0, 0.0, 3.0),
+        Vector3d::new(0.0, 2.0, 0.0),
+        Vector3d::new(-1.0, 0.0, 0.0),
+        Vector3d::new(0.0, 0.0, 1.0),
+    )
+    .unwrap();
+    let left_handed = [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, -1.0)].map(Vector3d::from);
+    assert!(LocalAxis::from_axes(Vector3d::new(0.0, 0.0, 0.0), left_handed[0], left_handed[1], left_handed[2]).is_none());
+
+    // A point given in section coordinates lands in the same place either way.
+    let nested = member.compose(&section);
+    let point = Vector3d::new(0.5, -1.0, 2.0);
+    assert_vec3_almost_eq!(nested.to_global(point), member.to_global(section.to_global(point)));
+    assert_vec3_almost_eq!(member.inverse().to_global(member.to_global(point)), point);
+    let identity = member.compose(&member.inverse());
+    assert_vec3_almost_eq!(identity.origin(), Vector3d::new(0.0, 0.0, 0.0));
+    assert!((identity.rotation() - nalgebra::Matrix3::identity()).norm() < 1e-12);
+
+    let (roll, pitch, yaw) = (0.3, -0.7, 2.1);
+    let frame = LocalAxis::from_euler_angles(Vector3d::new(0.0, 0.0, 0.0), roll, pitch, yaw);
+    let angles = frame.euler_angles();
+    assert_almost_eq!(angles.0, roll);
+    assert_almost_eq!(angles.1, pitch);
+    assert_almost_eq!(angles.2, yaw);
+    let yawed = LocalAxis::from_euler_angles(Vector3d::new(0.0, 0.0, 0.0), 0.0, 0.0, FRAC_PI_2);
+    assert_vec3_almost_eq!(yawed.direction(Axis::AxisX), Vector3d::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn local_axes_interpolate_the_short_way() {
+    let start = LocalAxis::from_euler_angles(Vector3d::new(0.0, 0.0, 0.0), 0.0, 0.0, 0.0);
+    let end = LocalAxis::from_euler_angles(Vector3d::new(4.0, 0.0, 2.0), 0.0, 0.0, 3.0 * FRAC_PI_2);
+    let middle = start.interpolate(&end, 0.5);
+    assert_vec3_almost_eq!(middle.origin(), Vector3d::new(2.0, 0.0, 1.0));
+    // Three quarter turns one way are one quarter turn the other.
+    assert_almost_eq!(middle.euler_angles().2, -PI / 4.0);
+    assert!((start.interpolate(&end, 1.0).rotation() - end.rotation()).norm() < 1e-12);
+    assert!((start.interpolate(&start, 0.3).rotation() - start.rotation()).norm() < 1e-12);
+
+    let rolled = LocalAxis::from_euler_angles(Vector3d::new(0.0, 0.0, 0.0), 0.9, 0.0, 0.0);
+    for t in [0.25, 0.5, 0.75] {
+        let frame = start.interpolate(&rolled, t);
+        assert_almost_eq!(frame.euler_angles().0, 0.9 * t);
+        assert_vec3_almost_eq!(frame.direction(Axis::AxisX), Vector3d::new(1.0, 0.0, 0.0));
+    }
+}
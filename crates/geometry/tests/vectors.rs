@@ -1,5 +1,6 @@
 use geometry::{Vector2d, Vector3d};
-use utils::{assert_almost_eq, assert_vec3_almost_eq, DEFAULT_EPSILON, epsilon};
+use nalgebra::{Matrix2x3, Matrix3, Vector4};
+use utils::{assert_almost_eq, assert_matrix_almost_eq, assert_vec3_almost_eq, DEFAULT_EPSILON, epsilon};
 
 #[test]
 fn vector2d_normalization_preserves_direction() {
@@ -42,3 +43,36 @@ fn vector_is_approx_allows_custom_precision() {
     assert!(!a.is_approx(&b, None));
     assert!(a.is_approx(&b, Some(1e-5)));
 }
+
+#[test]
+fn lerp_and_midpoint() {
+    let a = Vector3d::new(1.0, 2.0, 3.0);
+    let b = Vector3d::new(3.0, -2.0, 7.0);
+    assert_vec3_almost_eq!(a.lerp(&b, 0.0), a);
+    assert_vec3_almost_eq!(a.lerp(&b, 1.0), b);
+    assert_vec3_almost_eq!(a.lerp(&b, 0.25), Vector3d::new(1.5, 1.0, 4.0));
+    assert_vec3_almost_eq!(a.lerp(&b, -1.0), Vector3d::new(-1.0, 6.0, -1.0));
+    assert_vec3_almost_eq!(a.midpoint(&b), Vector3d::new(2.0, 0.0, 5.0));
+
+    let p = Vector2d::new(0.0, 4.0);
+    let q = Vector2d::new(2.0, 0.0);
+    assert_eq!(p.midpoint(&q), Vector2d::new(1.0, 2.0));
+    assert_eq!(p.lerp(&q, 0.75), Vector2d::new(1.5, 1.0));
+}
+
+#[test]
+fn assert_matrix_almost_eq_compares_every_entry() {
+    let inertia = Matrix3::new(2.0, -0.5, 0.0, -0.5, 1.0, 0.0, 0.0, 0.0, 3.0);
+    assert_matrix_almost_eq!(inertia, inertia.transpose());
+    assert_matrix_almost_eq!("scaled", inertia * 1e6, inertia * (1e6 + 1e-7));
+    assert_matrix_almost_eq!("vector", Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(1.0, 2.0, 3.0, 4.0 + 1e-5), 1e-4);
+}
+
+#[test]
+#[should_panic(expected = "(1, 2): actual")]
+fn assert_matrix_almost_eq_reports_mismatched_entries() {
+    let expected = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let mut actual = expected;
+    actual[(1, 2)] += 1e-3;
+    assert_matrix_almost_eq!(actual, expected);
+}
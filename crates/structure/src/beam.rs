@@ -1,5 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
+use geometry::{Transform3d, Transformable};
+
 use crate::{
     linearelement::{Fixity, LinearElement},
     node::Node,
@@ -8,6 +10,7 @@ use crate::{
 
 /// Beam formed by two nodes enriched with section related metadata.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Beam {
     element: LinearElement,
     section: Option<Section>,
@@ -156,6 +159,10 @@ impl DerefMut for Beam {
     fn deref_mut(&mut self) -> &mut Self::Target { &mut self.element }
 }
 
+impl Transformable for Beam {
+    fn transform(&mut self, transform: &Transform3d) { self.element.transform(transform); }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
@@ -0,0 +1,314 @@
+//! Canonical verification problems with closed-form or literature reference answers.
+//!
+//! Every benchmark is a ready-to-solve [`Model`] with a single load case plus the
+//! values a correct analysis must reproduce. They are meant both for this
+//! workspace's solver tests and for downstream code checking its own assembly,
+//! boundary handling and unit conventions against known results.
+//!
+//! Unless noted otherwise, members are modelled as Euler-Bernoulli beams and DOFs
+//! follow the order `[ux, uy, uz, rx, ry, rz]`. Planar problems lie in the global
+//! XZ plane with Z up, so in-plane bending is about the local y axis.
+
+use std::f64::consts::PI;
+
+use geometry::Vector3d;
+
+use crate::{
+    linearelement::Fixity,
+    material::Material,
+    model::{LoadCase, Model, NodeId},
+    section::Section,
+};
+
+/// Result quantity a reference value applies to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantity {
+    /// Displacement or rotation of a node in global DOF `dof` (0..6).
+    Displacement { node: NodeId, dof: usize },
+    /// Support reaction of a node in global DOF `dof` (0..6).
+    Reaction { node: NodeId, dof: usize },
+    /// Circular natural frequency (rad/s) of the `mode`-th lowest mode.
+    NaturalFrequency { mode: usize },
+    /// Linear buckling load factor of the `mode`-th lowest mode for the load case.
+    BucklingFactor { mode: usize },
+    /// Load factor at the first limit point of a geometrically nonlinear analysis.
+    LimitLoadFactor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reference {
+    pub quantity: Quantity,
+    pub value: f64,
+    /// Relative tolerance an analysis of the supplied model should meet.
+    pub tolerance: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Benchmark {
+    name: &'static str,
+    description: &'static str,
+    model: Model,
+    references: Vec<Reference>,
+}
+
+impl Benchmark {
+    pub fn name(&self) -> &'static str { self.name }
+    pub fn description(&self) -> &'static str { self.description }
+    pub fn model(&self) -> &Model { &self.model }
+    pub fn references(&self) -> &[Reference] { &self.references }
+
+    /// The load case the references refer to.
+    pub fn load_case(&self) -> &LoadCase { self.model.load_case(0) }
+}
+
+/// All benchmarks in this module.
+pub fn all() -> Vec<Benchmark> {
+    vec![simply_supported_beam(), portal_frame(), cantilever_eigenvalues(), euler_column(), lees_frame()]
+}
+
+fn steel() -> Material {
+    Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, Some("Steel".into()))
+}
+
+fn frame_section(material: Material, area: f64, iy: f64, iz: f64) -> Section {
+    let mut section = Section::generic(material, None);
+    section.set_area(area);
+    section.set_second_moment_components(iy, iz, 0.0);
+    section.set_torsion_constant(iy + iz);
+    section
+}
+
+/// Add members along a straight line from `start` to `end`, creating intermediate nodes.
+fn add_line(model: &mut Model, start: NodeId, end: NodeId, elements: usize, section: &Section) -> Vec<NodeId> {
+    let a = model.node(start).center().0;
+    let b = model.node(end).center().0;
+    let mut nodes = vec![start];
+    for i in 1..elements {
+        let t = i as f64 / elements as f64;
+        nodes.push(model.add_node(Vector3d(a + (b - a) * t)));
+    }
+    nodes.push(end);
+    for pair in nodes.windows(2) {
+        model.add_member(pair[0], pair[1], section.clone());
+    }
+    nodes
+}
+
+/// Simply supported beam (L = 6 m) under a uniform load w = 10 kN/m, meshed with two
+/// elements. References: midspan deflection `5wL⁴/384EI`, end rotation `wL³/24EI`
+/// and support reactions `wL/2`.
+pub fn simply_supported_beam() -> Benchmark {
+    let (length, w, inertia) = (6.0, 10e3, 8.356e-5);
+    let material = steel();
+    let e = material.young_modulus();
+    let section = frame_section(material, 5.381e-3, inertia, inertia);
+
+    let mut model = Model::new();
+    let start = model.add_node((0.0, 0.0, 0.0));
+    let end = model.add_node((length, 0.0, 0.0));
+    let nodes = add_line(&mut model, start, end, 2, &section);
+    model.set_support(start, Fixity::new([true; 3], [true, false, false]));
+    model.set_support(end, Fixity::new([false, true, true], [false; 3]));
+    let mut case = LoadCase::new("uniform");
+    for member in 0..model.members().len() {
+        case.add_member_load(member, Vector3d::new(0.0, 0.0, -w));
+    }
+    model.add_load_case(case);
+
+    let references = vec![
+        Reference {
+            quantity: Quantity::Displacement { node: nodes[1], dof: 2 },
+            value: -5.0 * w * length.powi(4) / (384.0 * e * inertia),
+            tolerance: 1e-9,
+        },
+        Reference {
+            quantity: Quantity::Displacement { node: start, dof: 4 },
+            value: w * length.powi(3) / (24.0 * e * inertia),
+            tolerance: 1e-9,
+        },
+        Reference { quantity: Quantity::Reaction { node: start, dof: 2 }, value: w * length / 2.0, tolerance: 1e-9 },
+        Reference { quantity: Quantity::Reaction { node: end, dof: 2 }, value: w * length / 2.0, tolerance: 1e-9 },
+    ];
+    Benchmark {
+        name: "simply_supported_beam",
+        description: "Simply supported beam under uniform load",
+        model,
+        references,
+    }
+}
+
+/// Fixed-base portal frame (h = 4 m, span 6 m) with a horizontal load H = 10 kN at
+/// the beam level. Members are practically axially rigid, so the sway is
+/// `H h³ (3k + 2) / (12 E Ic (6k + 1))` with `k = Ib h / (Ic L)`, and each base
+/// takes half of the shear.
+pub fn portal_frame() -> Benchmark {
+    let (height, span, h) = (4.0, 6.0, 10e3);
+    let (ic, ib) = (8.356e-5, 1.5 * 8.356e-5);
+    let material = steel();
+    let e = material.young_modulus();
+    let column = frame_section(material.clone(), 1.0, ic, ic);
+    let beam = frame_section(material, 1.0, ib, ib);
+
+    let mut model = Model::new();
+    let base_left = model.add_node((0.0, 0.0, 0.0));
+    let top_left = model.add_node((0.0, 0.0, height));
+    let top_right = model.add_node((span, 0.0, height));
+    let base_right = model.add_node((span, 0.0, 0.0));
+    model.add_member(base_left, top_left, column.clone());
+    model.add_member(top_left, top_right, beam);
+    model.add_member(top_right, base_right, column);
+    model.set_support(base_left, Fixity::fixed());
+    model.set_support(base_right, Fixity::fixed());
+    let mut case = LoadCase::new("wind");
+    case.add_nodal_force(top_left, Vector3d::new(h, 0.0, 0.0));
+    model.add_load_case(case);
+
+    let k = ib * height / (ic * span);
+    let sway = h * height.powi(3) * (3.0 * k + 2.0) / (12.0 * e * ic * (6.0 * k + 1.0));
+    let references = vec![
+        Reference { quantity: Quantity::Displacement { node: top_left, dof: 0 }, value: sway, tolerance: 1e-4 },
+        Reference { quantity: Quantity::Displacement { node: top_right, dof: 0 }, value: sway, tolerance: 1e-4 },
+        Reference { quantity: Quantity::Reaction { node: base_left, dof: 0 }, value: -h / 2.0, tolerance: 1e-4 },
+        Reference { quantity: Quantity::Reaction { node: base_right, dof: 0 }, value: -h / 2.0, tolerance: 1e-4 },
+    ];
+    Benchmark {
+        name: "portal_frame",
+        description: "Fixed-base portal frame under horizontal load",
+        model,
+        references,
+    }
+}
+
+/// Cantilever (L = 2 m) meshed with ten elements and a consistent mass. The two
+/// lowest modes are the first bending modes about the weak and strong axis,
+/// `ω = (1.8751)² √(EI / (ρ A L⁴))`.
+pub fn cantilever_eigenvalues() -> Benchmark {
+    let (length, area, iy, iz) = (2.0, 5.381e-3, 8.356e-5, 8.356e-5 / 4.0);
+    let material = steel();
+    let (e, rho) = (material.young_modulus(), material.density());
+    let section = frame_section(material, area, iy, iz);
+
+    let mut model = Model::new();
+    let root = model.add_node((0.0, 0.0, 0.0));
+    let tip = model.add_node((length, 0.0, 0.0));
+    add_line(&mut model, root, tip, 10, &section);
+    model.set_support(root, Fixity::fixed());
+    model.add_load_case(LoadCase::new("modal"));
+
+    let beta: f64 = 1.875_104_068_711_961;
+    let omega = |inertia: f64| beta * beta * (e * inertia / (rho * area * length.powi(4))).sqrt();
+    let references = vec![
+        Reference { quantity: Quantity::NaturalFrequency { mode: 0 }, value: omega(iz), tolerance: 1e-5 },
+        Reference { quantity: Quantity::NaturalFrequency { mode: 1 }, value: omega(iy), tolerance: 1e-5 },
+    ];
+    Benchmark {
+        name: "cantilever_eigenvalues",
+        description: "Natural frequencies of a cantilever beam",
+        model,
+        references,
+    }
+}
+
+/// Pinned-pinned column (L = 5 m) under a unit axial compression, meshed with eight
+/// elements. The first buckling factor is the Euler load `π² E I / L²`.
+pub fn euler_column() -> Benchmark {
+    let (length, inertia, load) = (5.0, 8.356e-5, 1e3);
+    let material = steel();
+    let e = material.young_modulus();
+    let section = frame_section(material, 5.381e-3, inertia, inertia);
+
+    let mut model = Model::new();
+    let base = model.add_node((0.0, 0.0, 0.0));
+    let top = model.add_node((0.0, 0.0, length));
+    add_line(&mut model, base, top, 8, &section);
+    model.set_support(base, Fixity::new([true; 3], [false, false, true]));
+    model.set_support(top, Fixity::new([true, true, false], [false; 3]));
+    let mut case = LoadCase::new("axial");
+    case.add_nodal_force(top, Vector3d::new(0.0, 0.0, -load));
+    model.add_load_case(case);
+
+    let references = vec![Reference {
+        quantity: Quantity::BucklingFactor { mode: 0 },
+        value: PI * PI * e * inertia / (length * length) / load,
+        tolerance: 1e-4,
+    }];
+    Benchmark {
+        name: "euler_column",
+        description: "Euler buckling of a pinned-pinned column",
+        model,
+        references,
+    }
+}
+
+/// Lee's frame: a pinned L-shaped frame (L = 120, E = 720, A = 6, I = 2 in kip and
+/// inch units) with a unit load 24 from the corner, ten elements per leg. The
+/// first limit point of the large-displacement response is at `P ≈ 18.55 EI / L²`
+/// (the path then snaps back, so displacement or arc-length control is needed to
+/// pass it).
+pub fn lees_frame() -> Benchmark {
+    let (length, inertia) = (120.0, 2.0);
+    let material = Material::new(720.0, 0.3, 0.0, 0.0, 0.0, 0.0, Some("Lee".into()));
+    let e = material.young_modulus();
+    let section = frame_section(material, 6.0, inertia, inertia);
+
+    let mut model = Model::new();
+    let base = model.add_node((0.0, 0.0, 0.0));
+    let corner = model.add_node((0.0, 0.0, length));
+    let end = model.add_node((length, 0.0, length));
+    let column = add_line(&mut model, base, corner, 10, &section);
+    let beam = add_line(&mut model, corner, end, 10, &section);
+    // Keep the frame in its plane.
+    let out_of_plane = |translations: [bool; 2]| Fixity::new([translations[0], true, translations[1]], [true, false, true]);
+    for &node in column.iter().chain(beam.iter()) {
+        model.set_support(node, out_of_plane([false, false]));
+    }
+    model.set_support(base, out_of_plane([true, true]));
+    model.set_support(end, out_of_plane([true, true]));
+    let mut case = LoadCase::new("point");
+    case.add_nodal_force(beam[2], Vector3d::new(0.0, 0.0, -1.0));
+    model.add_load_case(case);
+
+    let references = vec![Reference {
+        quantity: Quantity::LimitLoadFactor,
+        value: 18.55 * e * inertia / (length * length),
+        tolerance: 1e-2,
+    }];
+    Benchmark {
+        name: "lees_frame",
+        description: "Large-displacement limit load of Lee's frame",
+        model,
+        references,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benchmarks_are_consistent() {
+        for benchmark in all() {
+            let model = benchmark.model();
+            assert!(!benchmark.references().is_empty(), "{}", benchmark.name());
+            assert!(model.supports().count() > 0);
+            assert_eq!(model.instabilities(), vec![], "{}", benchmark.name());
+            for reference in benchmark.references() {
+                match reference.quantity {
+                    Quantity::Displacement { node, dof } | Quantity::Reaction { node, dof } => {
+                        assert!(node < model.nodes().len() && dof < 6);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lees_frame_load_sits_on_the_beam() {
+        let benchmark = lees_frame();
+        let load = &benchmark.load_case().loads()[0];
+        let crate::model::Load::Nodal { node, .. } = load else { panic!("expected a nodal load") };
+        let center = benchmark.model().node(*node).center();
+        assert!((center.x() - 24.0).abs() < 1e-9 && (center.z() - 120.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,68 @@
+
+use crate::{erection::Capacity, model::MemberId};
+
+/// Members that must share a section, e.g. all columns of a story. Design
+/// checks envelope the demands over the whole group, so resizing the group
+/// for its governing member keeps every member adequate.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DesignGroup {
+    name: String,
+    members: Vec<MemberId>,
+    /// Capacity of the shared section; groups without one are enveloped but
+    /// not checked.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    capacity: Option<Capacity>,
+}
+
+impl DesignGroup {
+    pub fn new<S: Into<String>>(name: S) -> Self { Self { name: name.into(), members: Vec::new(), capacity: None } }
+
+    /// Group of the given members.
+    pub fn of<S: Into<String>, I: IntoIterator<Item = MemberId>>(name: S, members: I) -> Self {
+        let mut group = Self::new(name);
+        for member in members {
+            group.add_member(member);
+        }
+        group
+    }
+
+    pub fn with_member(mut self, member: MemberId) -> Self {
+        self.add_member(member);
+        self
+    }
+
+    pub fn with_capacity(mut self, capacity: Capacity) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Members already in the group are not added twice.
+    pub fn add_member(&mut self, member: MemberId) {
+        if !self.members.contains(&member) {
+            self.members.push(member);
+        }
+    }
+
+    pub fn set_capacity(&mut self, capacity: Capacity) {
+        self.capacity = Some(capacity);
+    }
+
+    pub fn name(&self) -> &str { &self.name }
+    pub fn members(&self) -> &[MemberId] { &self.members }
+    pub fn capacity(&self) -> Option<&Capacity> { self.capacity.as_ref() }
+    pub fn contains(&self, member: MemberId) -> bool { self.members.contains(&member) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn members_are_not_repeated() {
+        let group = DesignGroup::of("columns", [0, 2, 2]).with_member(0).with_capacity(Capacity::new(1e6, 1e5));
+        assert_eq!(group.members(), &[0, 2]);
+        assert!(group.contains(2) && !group.contains(1));
+        assert_eq!(group.capacity(), Some(&Capacity::new(1e6, 1e5)));
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+
+use crate::{
+    linearelement::Fixity,
+    model::{LoadCase, MemberId, Model, NodeId},
+};
+
+/// Limits on member forces, e.g. temporary ones while the structure is
+/// incomplete or those of a design group's section.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capacity {
+    /// Largest axial force magnitude.
+    pub axial: f64,
+    /// Largest resultant bending moment magnitude.
+    pub moment: f64,
+}
+
+impl Capacity {
+    pub fn new(axial: f64, moment: f64) -> Self { Self { axial, moment } }
+
+    /// Ratio of the governing force to its limit; above one means overstressed.
+    pub fn utilization(&self, axial: f64, moment: f64) -> f64 { (axial.abs() / self.axial).max(moment.abs() / self.moment) }
+}
+
+/// One erection step: the members it adds, the temporary supports standing
+/// during it, and the loads acting (construction loads, self-weight).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stage {
+    name: String,
+    members: Vec<MemberId>,
+    temporary_supports: Vec<(NodeId, Fixity)>,
+    load_case: LoadCase,
+}
+
+impl Stage {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        let name = name.into();
+        Self { load_case: LoadCase::new(name.clone()), name, members: Vec::new(), temporary_supports: Vec::new() }
+    }
+
+    pub fn name(&self) -> &str { &self.name }
+    pub fn members(&self) -> &[MemberId] { &self.members }
+    pub fn temporary_supports(&self) -> &[(NodeId, Fixity)] { &self.temporary_supports }
+    pub fn load_case(&self) -> &LoadCase { &self.load_case }
+    pub fn load_case_mut(&mut self) -> &mut LoadCase { &mut self.load_case }
+
+    pub fn add_member(&mut self, member: MemberId) {
+        self.members.push(member);
+    }
+
+    /// Support added for this stage only, on top of any permanent support.
+    pub fn add_temporary_support(&mut self, node: NodeId, fixity: Fixity) {
+        self.temporary_supports.push((node, fixity));
+    }
+}
+
+/// Structure of one stage, renumbered; `nodes` and `members` give the original
+/// ids of its nodes and members.
+#[derive(Debug, Clone)]
+pub struct StageModel {
+    pub model: Model,
+    pub nodes: Vec<NodeId>,
+    pub members: Vec<MemberId>,
+}
+
+/// Staged construction of a model: members are erected stage by stage and stay
+/// in place for all later stages. Ids refer to the complete model.
+#[derive(Debug, Clone, Default)]
+pub struct ErectionSequence {
+    stages: Vec<Stage>,
+    capacities: BTreeMap<MemberId, Capacity>,
+}
+
+impl ErectionSequence {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn add_stage(&mut self, stage: Stage) -> usize {
+        self.stages.push(stage);
+        self.stages.len() - 1
+    }
+
+    pub fn stage(&self, index: usize) -> &Stage { &self.stages[index] }
+    pub fn stages(&self) -> &[Stage] { &self.stages }
+
+    /// Temporary capacity of a member; members without one are not checked.
+    pub fn set_capacity(&mut self, member: MemberId, capacity: Capacity) {
+        self.capacities.insert(member, capacity);
+    }
+
+    pub fn capacity(&self, member: MemberId) -> Option<&Capacity> { self.capacities.get(&member) }
+
+    /// Members standing in `stage`, in erection order.
+    pub fn active_members(&self, stage: usize) -> Vec<MemberId> {
+        let mut members: Vec<MemberId> = Vec::new();
+        for member in self.stages[..=stage].iter().flat_map(|s| s.members.iter().copied()) {
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+        members
+    }
+
+    /// The structure as it stands in `stage`: erected members, the permanent
+    /// supports of their nodes combined with the stage's temporary supports, and
+    /// the stage loads as its only load case. Temporary supports and loads on
+    /// nodes or members not yet erected are dropped.
+    pub fn stage_model(&self, model: &Model, stage: usize) -> StageModel {
+        let members = self.active_members(stage);
+        let (mut sub, nodes) = model.sub_model(&members);
+        let node_index = |node: NodeId| nodes.binary_search(&node).ok();
+        let member_index = |member: MemberId| members.iter().position(|&m| m == member);
+
+        let current = &self.stages[stage];
+        for (node, fixity) in &current.temporary_supports {
+            let Some(index) = node_index(*node) else { continue };
+            let existing = sub.support(index).map_or([false; 6], Fixity::dofs);
+            let dofs: [bool; 6] = std::array::from_fn(|i| existing[i] || fixity.dofs()[i]);
+            sub.set_support(index, Fixity::new([dofs[0], dofs[1], dofs[2]], [dofs[3], dofs[4], dofs[5]]));
+        }
+
+        let mut case = LoadCase::new(current.load_case.name());
+        for load in current.load_case.loads().iter().filter_map(|load| load.renumbered(node_index, member_index)) {
+            case.add_load(load);
+        }
+        sub.add_load_case(case);
+        StageModel { model: sub, nodes, members }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{material::Material, section::Section};
+
+    #[test]
+    fn stage_model_renumbers_and_drops_inactive_loads() {
+        let mut model = Model::new();
+        let nodes: Vec<_> = (0..4).map(|i| model.add_node((i as f64, 0.0, 0.0))).collect();
+        let section = Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None);
+        for i in 0..3 {
+            model.add_member(nodes[i], nodes[i + 1], section.clone());
+        }
+        model.set_support(nodes[3], Fixity::fixed());
+
+        let mut sequence = ErectionSequence::new();
+        let mut stage = Stage::new("first");
+        stage.add_member(2);
+        stage.add_temporary_support(2, Fixity::pinned());
+        stage.add_temporary_support(0, Fixity::fixed());
+        stage.load_case_mut().add_member_load(2, geometry::Vector3d::new(0.0, 0.0, -1.0));
+        stage.load_case_mut().add_member_load(0, geometry::Vector3d::new(0.0, 0.0, -1.0));
+        sequence.add_stage(stage);
+
+        let staged = sequence.stage_model(&model, 0);
+        assert_eq!(staged.nodes, vec![2, 3]);
+        assert_eq!(staged.members, vec![2]);
+        assert_eq!(staged.model.member_nodes(0), [0, 1]);
+        assert_eq!(staged.model.support(0), Some(&Fixity::pinned()));
+        assert_eq!(staged.model.support(1), Some(&Fixity::fixed()));
+        assert_eq!(staged.model.load_case(0).loads().len(), 1);
+    }
+}
@@ -0,0 +1,9 @@
+use std::collections::BTreeMap;
+
+pub use serde_json::Value;
+
+/// Free-form user data attached to nodes, members, sections and load cases, for
+/// IDs, costs or fabrication data. It is serialized with its owner and ignored by
+/// the analysis. Keys are kept sorted so serialized models are reproducible.
+/// Needs the `serde` feature.
+pub type Extras = BTreeMap<String, Value>;
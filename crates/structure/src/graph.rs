@@ -0,0 +1,220 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::model::{MemberId, Model, NodeId};
+
+/// Topology of a [`Model`]: nodes are vertices, members are edges.
+///
+/// The graph is a snapshot; rebuild it with [`Model::graph`] after editing the
+/// model. Parallel members between the same pair of nodes are kept as separate
+/// edges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelGraph {
+    /// Per node: `(member, other node)` for every member attached to it.
+    adjacency: Vec<Vec<(MemberId, NodeId)>>,
+    ends: Vec<[NodeId; 2]>,
+    lengths: Vec<f64>,
+}
+
+impl ModelGraph {
+    pub fn new(model: &Model) -> Self {
+        let mut adjacency = vec![Vec::new(); model.nodes().len()];
+        let mut ends = Vec::with_capacity(model.members().len());
+        let mut lengths = Vec::with_capacity(model.members().len());
+        for (member, m) in model.members().iter().enumerate() {
+            let [a, b] = model.member_nodes(member);
+            adjacency[a].push((member, b));
+            adjacency[b].push((member, a));
+            ends.push([a, b]);
+            lengths.push(m.length());
+        }
+        Self { adjacency, ends, lengths }
+    }
+
+    pub fn node_count(&self) -> usize { self.adjacency.len() }
+    pub fn member_count(&self) -> usize { self.ends.len() }
+
+    /// Members attached to a node with the node at their other end.
+    pub fn incident(&self, node: NodeId) -> &[(MemberId, NodeId)] { &self.adjacency[node] }
+
+    pub fn degree(&self, node: NodeId) -> usize { self.adjacency[node].len() }
+
+    pub fn member_nodes(&self, member: MemberId) -> [NodeId; 2] { self.ends[member] }
+
+    /// Distinct nodes connected to `node` by at least one member, ascending.
+    pub fn neighbors(&self, node: NodeId) -> Vec<NodeId> {
+        let mut neighbors: Vec<NodeId> = self.adjacency[node].iter().map(|&(_, n)| n).collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Connected components as ascending node lists, ordered by their smallest
+    /// node. Unconnected nodes form components of their own.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut label = vec![usize::MAX; self.node_count()];
+        let mut components = Vec::new();
+        for root in 0..self.node_count() {
+            if label[root] != usize::MAX {
+                continue;
+            }
+            let id = components.len();
+            let mut nodes = vec![root];
+            label[root] = id;
+            let mut queue = VecDeque::from([root]);
+            while let Some(node) = queue.pop_front() {
+                for &(_, next) in &self.adjacency[node] {
+                    if label[next] == usize::MAX {
+                        label[next] = id;
+                        nodes.push(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            nodes.sort_unstable();
+            components.push(nodes);
+        }
+        components
+    }
+
+    /// Members of each component returned by [`Self::components`], ascending.
+    pub fn component_members(&self) -> Vec<Vec<MemberId>> {
+        let components = self.components();
+        let mut owner = vec![0; self.node_count()];
+        for (id, nodes) in components.iter().enumerate() {
+            for &node in nodes {
+                owner[node] = id;
+            }
+        }
+        let mut members = vec![Vec::new(); components.len()];
+        for (member, ends) in self.ends.iter().enumerate() {
+            members[owner[ends[0]]].push(member);
+        }
+        members
+    }
+
+    pub fn is_connected(&self) -> bool { self.components().len() <= 1 }
+
+    /// Members along the shortest path (by total member length) from `from` to
+    /// `to`, in order. Empty when `from == to`, `None` when unreachable.
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<Vec<MemberId>> {
+        #[derive(PartialEq)]
+        struct Entry(f64, NodeId);
+        impl Eq for Entry {}
+        impl PartialOrd for Entry {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+        }
+        impl Ord for Entry {
+            fn cmp(&self, other: &Self) -> Ordering { other.0.total_cmp(&self.0).then(other.1.cmp(&self.1)) }
+        }
+
+        let mut distance = vec![f64::INFINITY; self.node_count()];
+        let mut via: Vec<Option<(MemberId, NodeId)>> = vec![None; self.node_count()];
+        let mut heap = BinaryHeap::from([Entry(0.0, from)]);
+        distance[from] = 0.0;
+        while let Some(Entry(d, node)) = heap.pop() {
+            if node == to {
+                break;
+            }
+            if d > distance[node] {
+                continue;
+            }
+            for &(member, next) in &self.adjacency[node] {
+                let candidate = d + self.lengths[member];
+                if candidate < distance[next] {
+                    distance[next] = candidate;
+                    via[next] = Some((member, node));
+                    heap.push(Entry(candidate, next));
+                }
+            }
+        }
+        if distance[to].is_infinite() {
+            return None;
+        }
+        let mut path = Vec::new();
+        let mut node = to;
+        while let Some((member, previous)) = via[node] {
+            path.push(member);
+            node = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn has_cycle(&self) -> bool { !self.cycles().is_empty() }
+
+    /// A cycle basis: one closed member loop per member that is not part of a
+    /// spanning forest. Each loop lists its members in order around the loop.
+    pub fn cycles(&self) -> Vec<Vec<MemberId>> {
+        let n = self.node_count();
+        let mut parent: Vec<Option<(MemberId, NodeId)>> = vec![None; n];
+        let mut depth = vec![usize::MAX; n];
+        let mut tree = vec![false; self.member_count()];
+        for root in 0..n {
+            if depth[root] != usize::MAX {
+                continue;
+            }
+            depth[root] = 0;
+            let mut queue = VecDeque::from([root]);
+            while let Some(node) = queue.pop_front() {
+                for &(member, next) in &self.adjacency[node] {
+                    if depth[next] == usize::MAX {
+                        depth[next] = depth[node] + 1;
+                        parent[next] = Some((member, node));
+                        tree[member] = true;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        let mut cycles = Vec::new();
+        for (member, &[a, b]) in self.ends.iter().enumerate() {
+            if tree[member] {
+                continue;
+            }
+            // Walk both ends up the tree to their common ancestor.
+            let (mut x, mut y) = (a, b);
+            let (mut from_a, mut from_b) = (Vec::new(), Vec::new());
+            while x != y {
+                if depth[x] >= depth[y] {
+                    let (m, p) = parent[x].expect("non-root node has a parent");
+                    from_a.push(m);
+                    x = p;
+                } else {
+                    let (m, p) = parent[y].expect("non-root node has a parent");
+                    from_b.push(m);
+                    y = p;
+                }
+            }
+            let mut cycle = vec![member];
+            cycle.extend(from_b);
+            cycle.extend(from_a.into_iter().rev());
+            cycles.push(cycle);
+        }
+        cycles
+    }
+}
+
+impl Model {
+    /// Topology graph of the current model.
+    pub fn graph(&self) -> ModelGraph { ModelGraph::new(self) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{material::Material, section::Section};
+
+    #[test]
+    fn parallel_members_form_a_cycle() {
+        let mut model = Model::new();
+        let a = model.add_node((0.0, 0.0, 0.0));
+        let b = model.add_node((1.0, 0.0, 0.0));
+        let section = Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None);
+        model.add_member(a, b, section.clone());
+        assert!(!model.graph().has_cycle());
+        model.add_member(b, a, section);
+        assert_eq!(model.graph().cycles(), vec![vec![1, 0]]);
+    }
+}
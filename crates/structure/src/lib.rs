@@ -1,15 +1,37 @@
 pub mod beam;
+pub mod benchmarks;
+pub mod design;
+pub mod erection;
+#[cfg(feature = "serde")]
+pub mod extras;
+pub mod graph;
+pub mod lifting;
 pub mod linearelement;
 pub mod material;
 pub mod member;
+pub mod model;
 pub mod node;
+pub mod patch;
 pub mod section;
 pub mod spring;
+pub mod stability;
+pub mod takeoff;
 
 pub use beam::Beam;
+pub use design::DesignGroup;
+pub use erection::{Capacity, ErectionSequence, Stage, StageModel};
+#[cfg(feature = "serde")]
+pub use extras::Extras;
+pub use graph::ModelGraph;
+pub use lifting::Lift;
 pub use linearelement::{Fixity, IntoVec3, LinearElement};
 pub use material::Material;
 pub use member::Member;
-pub use node::{BoundingBox3d, Node};
-pub use section::Section;
+pub use model::{Load, LoadCase, MemberId, Model, NodeId};
+pub use geometry::BoundingBox3d;
+pub use node::Node;
+pub use patch::SupportPatch;
+pub use section::{CatalogValues, PropertyDeviation, Section, SectionProperty};
 pub use spring::Spring;
+pub use stability::Instability;
+pub use takeoff::{GroupBy, Takeoff, TakeoffRow, TakeoffTable, UnitCost};
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use geometry::Vector3d;
+
+use crate::{
+    erection::{Capacity, StageModel},
+    linearelement::Fixity,
+    model::{LoadCase, MemberId, Model, NodeId},
+};
+
+/// Lift of a sub-assembly by crane, e.g. a prefabricated truss hung from its
+/// lifting lugs. The assembly carries its self-weight, amplified by a dynamic
+/// factor, and is held only at the lifting points. Ids refer to the complete
+/// model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lift {
+    members: Vec<MemberId>,
+    lifting_points: Vec<(NodeId, Fixity)>,
+    dynamic_factor: f64,
+    hook: Option<Vector3d>,
+    capacities: BTreeMap<MemberId, Capacity>,
+}
+
+impl Lift {
+    pub fn new<I: IntoIterator<Item = MemberId>>(members: I) -> Self {
+        Self {
+            members: members.into_iter().collect(),
+            lifting_points: Vec::new(),
+            dynamic_factor: 1.0,
+            hook: None,
+            capacities: BTreeMap::new(),
+        }
+    }
+
+    /// Lifting point held in all three translations, the usual sling lug.
+    pub fn with_lifting_point(mut self, node: NodeId) -> Self {
+        self.add_lifting_point(node, Fixity::pinned());
+        self
+    }
+
+    /// Factor on the self-weight for hoisting and handling effects. Panics
+    /// below one.
+    pub fn with_dynamic_factor(mut self, factor: f64) -> Self {
+        assert!(factor >= 1.0, "dynamic amplification factor must be at least one");
+        self.dynamic_factor = factor;
+        self
+    }
+
+    /// Crane hook position, from which the sling angles follow; without one the
+    /// slings are taken as vertical.
+    pub fn with_hook(mut self, hook: Vector3d) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+
+    /// Lifting point with an explicit restraint, e.g. to suppress the rotation
+    /// about the line through two lugs.
+    pub fn add_lifting_point(&mut self, node: NodeId, fixity: Fixity) {
+        self.lifting_points.push((node, fixity));
+    }
+
+    /// Capacity of a member during the lift; members without one are not checked.
+    pub fn set_capacity(&mut self, member: MemberId, capacity: Capacity) {
+        self.capacities.insert(member, capacity);
+    }
+
+    pub fn members(&self) -> &[MemberId] { &self.members }
+    pub fn lifting_points(&self) -> &[(NodeId, Fixity)] { &self.lifting_points }
+    pub fn dynamic_factor(&self) -> f64 { self.dynamic_factor }
+    pub fn hook(&self) -> Option<Vector3d> { self.hook }
+    pub fn capacity(&self, member: MemberId) -> Option<&Capacity> { self.capacities.get(&member) }
+
+    /// Self-weight of the assembly from section areas and material unit
+    /// weights, without the dynamic factor.
+    pub fn weight(&self, model: &Model) -> f64 {
+        self.members.iter().map(|&member| unit_weight(model, member) * model.member(member).length()).sum()
+    }
+
+    /// The assembly as lifted: its members renumbered, their permanent supports
+    /// replaced by the lifting points, and the amplified self-weight (along −Z)
+    /// as the only load case. Panics if a lifting point is not a node of the
+    /// assembly.
+    pub fn lift_model(&self, model: &Model) -> StageModel {
+        let (mut sub, nodes) = model.sub_model(&self.members);
+        for node in 0..nodes.len() {
+            sub.clear_support(node);
+        }
+        for (node, fixity) in &self.lifting_points {
+            let index = nodes.binary_search(node).expect("lifting point must be a node of the lifted assembly");
+            sub.set_support(index, fixity.clone());
+        }
+        let mut case = LoadCase::new("lift");
+        for (index, &member) in self.members.iter().enumerate() {
+            let weight = unit_weight(model, member) * self.dynamic_factor;
+            if weight > 0.0 {
+                case.add_member_load(index, Vector3d::new(0.0, 0.0, -weight));
+            }
+        }
+        sub.add_load_case(case);
+        StageModel { model: sub, nodes, members: self.members.clone() }
+    }
+}
+
+/// Weight per unit length of a member; zero without a section.
+fn unit_weight(model: &Model, member: MemberId) -> f64 {
+    model.member(member).get_section().map_or(0.0, |section| section.area() * section.material().unit_weight())
+}
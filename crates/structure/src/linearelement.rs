@@ -1,18 +1,23 @@
 use std::ops::{Deref, DerefMut};
 
-use geometry::{Axis, Line3d, Vector3d};
-use nalgebra::{Matrix3, Matrix4, Rotation3, Unit};
+use geometry::{BoundingBox3d, Line3d, LocalAxis, SignedAxis, Transform3d, Transformable, TriadLine, Vector3d};
+use nalgebra::{Matrix3, Matrix4, Rotation3};
 use utils::epsilon;
 
-use crate::node::{BoundingBox3d, Node};
+use crate::node::Node;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fixity {
     translations: [bool; 3],
     rotations: [bool; 3],
 }
 
 impl Fixity {
+    pub fn new(translations: [bool; 3], rotations: [bool; 3]) -> Self {
+        Self { translations, rotations }
+    }
+
     pub fn fixed() -> Self {
         Self { translations: [true; 3], rotations: [true; 3] }
     }
@@ -24,6 +29,20 @@ impl Fixity {
     pub fn free() -> Self {
         Self { translations: [false; 3], rotations: [false; 3] }
     }
+
+    pub fn translations(&self) -> [bool; 3] { self.translations }
+    pub fn rotations(&self) -> [bool; 3] { self.rotations }
+
+    /// Restraint flags in DOF order `[ux, uy, uz, rx, ry, rz]`.
+    pub fn dofs(&self) -> [bool; 6] {
+        let [tx, ty, tz] = self.translations;
+        let [rx, ry, rz] = self.rotations;
+        [tx, ty, tz, rx, ry, rz]
+    }
+
+    pub fn is_restrained(&self, dof: usize) -> bool { self.dofs()[dof] }
+
+    pub fn is_free(&self) -> bool { self.dofs().iter().all(|r| !r) }
 }
 
 impl Default for Fixity {
@@ -48,6 +67,7 @@ impl IntoVec3 for (f64, f64, f64) {
 
 /// Minimal straight element described by two nodes.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearElement {
     name: Option<String>,
     start_node: Node,
@@ -97,8 +117,8 @@ impl LinearElement {
             .unwrap_or_else(Rotation3::identity)
     }
 
-    pub fn direction(&self, axis: Axis) -> Vector3d {
-        let rotated = self.orientation() * axis.to_vector3d().0;
+    pub fn direction<A: Into<SignedAxis>>(&self, axis: A) -> Vector3d {
+        let rotated = self.orientation() * axis.into().to_vector3d().0;
         Vector3d(rotated)
     }
 
@@ -118,29 +138,21 @@ impl LinearElement {
 
     pub fn to_line(&self) -> Line3d { self.line }
 
+    /// Orientation triad of the local axes at the element center.
+    pub fn triad_geometry(&self, scale: f64) -> [TriadLine; 3] {
+        LocalAxis::new(self.center(), self.rotation_matrix()).triad_geometry(scale)
+    }
+
     pub fn bounding_box(&self) -> BoundingBox3d {
-        let mut bbox = BoundingBox3d::from_point(self.start_node.center());
-        bbox.expand_with_point(self.end_node.center());
-        bbox
+        BoundingBox3d::new(self.start_node.center(), self.end_node.center())
     }
 
+    /// Rotate about an axis through the element center.
     pub fn rotate<A: IntoVec3>(&mut self, angle: f64, axis: A) {
-        let axis_vec = axis.into_vec3().0;
-        let unit_axis = match Unit::try_new(axis_vec, epsilon()) {
-            Some(axis) => axis,
-            None => return,
-        };
-        let incremental = Rotation3::from_axis_angle(&unit_axis, angle);
-
-        self.line.rotate(angle, [axis_vec.x, axis_vec.y, axis_vec.z]);
-
-        let center = self.center().0;
-        for node in [&mut self.start_node, &mut self.end_node] {
-            let relative = node.center().0 - center;
-            node.set_center(Vector3d(incremental * relative + center));
-            node.apply_rotation(&incremental);
+        let axis = axis.into_vec3();
+        if axis.norm() > epsilon() {
+            self.transform(&Transform3d::rotation_about(angle, axis, self.center()));
         }
-        self.refresh_line();
     }
 
     pub fn r#move<T: IntoVec3>(&mut self, offset: T) {
@@ -187,6 +199,24 @@ impl DerefMut for LinearElement {
     fn deref_mut(&mut self) -> &mut Self::Target { &mut self.line }
 }
 
+impl Transformable for LinearElement {
+    /// Transforms both nodes and the axis line. The local axes are carried
+    /// along rather than rebuilt from the new direction: local x follows the
+    /// line and the frame stays right-handed under mirroring, so a section
+    /// mirrored in a vertical plane keeps its z-axis up.
+    fn transform(&mut self, transform: &Transform3d) {
+        let frame = self.line.rotation_matrix();
+        self.line.transform(transform);
+        if let Some(frame) = frame {
+            self.line.set_orientation_matrix(transform.apply_frame(&frame));
+        }
+        for node in [&mut self.start_node, &mut self.end_node] {
+            node.transform(transform);
+        }
+        self.refresh_line();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use utils::{assert_almost_eq, assert_vec3_almost_eq};
@@ -1,5 +1,7 @@
+
 /// Simple isotropic material definition mirroring the Python demo.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Material {
     name: Option<String>,
     young_modulus: f64,
@@ -66,7 +68,7 @@ mod tests {
     fn shear_modulus_matches_isotropic_formula() {
         let material = Material::new(210e9, 0.3, 7850.0, 78.5, 1.2e-5, 0.2, Some("S355".into()));
         let shear = material.shear_modulus();
-        assert_almost_eq!(shear, 80_769_230_769.23077);
+        assert_almost_eq!(shear, 80_769_230_769.230_77);
     }
 
     #[test]
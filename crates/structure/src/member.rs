@@ -1,17 +1,30 @@
 use std::ops::{Deref, DerefMut};
 
+use geometry::{spatial::Bounded, BoundingBox3d, Transform3d, Transformable, Vector3d};
+
+#[cfg(feature = "serde")]
+use crate::extras::Extras;
 use crate::{beam::Beam, node::Node, section::Section};
 
 /// Highest level linear element enriched with a list of child beams forming a mesh.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Member {
     beam: Beam,
     mesh: Vec<Beam>,
+    #[cfg(feature = "serde")]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    extras: Extras,
 }
 
 impl Member {
     pub fn new(start_node: Node, end_node: Node) -> Self {
-        Self { beam: Beam::new(start_node, end_node), mesh: Vec::new() }
+        Self {
+            beam: Beam::new(start_node, end_node),
+            mesh: Vec::new(),
+            #[cfg(feature = "serde")]
+            extras: Extras::new(),
+        }
     }
 
     pub fn mesh(&self) -> &[Beam] {
@@ -29,6 +42,11 @@ impl Member {
     pub fn clear_mesh(&mut self) {
         self.mesh.clear();
     }
+
+    #[cfg(feature = "serde")]
+    pub fn extras(&self) -> &Extras { &self.extras }
+    #[cfg(feature = "serde")]
+    pub fn extras_mut(&mut self) -> &mut Extras { &mut self.extras }
 }
 
 impl From<(Node, Node)> for Member {
@@ -65,6 +83,22 @@ impl DerefMut for Member {
     fn deref_mut(&mut self) -> &mut Self::Target { &mut self.beam }
 }
 
+impl Transformable for Member {
+    /// Transforms the member and every beam of its mesh.
+    fn transform(&mut self, transform: &Transform3d) {
+        self.beam.transform(transform);
+        for beam in &mut self.mesh {
+            beam.transform(transform);
+        }
+    }
+}
+
+impl Bounded for Member {
+    fn bounds(&self) -> BoundingBox3d { self.to_line().bounding_box() }
+
+    fn distance(&self, point: &Vector3d) -> f64 { self.to_line().distance(point) }
+}
+
 #[cfg(test)]
 mod tests {
     use utils::assert_almost_eq;
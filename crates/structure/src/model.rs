@@ -0,0 +1,344 @@
+use geometry::Vector3d;
+
+use crate::{
+    design::DesignGroup,
+    erection::{Capacity, StageModel},
+    linearelement::Fixity, material::Material, member::Member, node::Node, patch::SupportPatch,
+    section::Section,
+};
+#[cfg(feature = "serde")]
+use crate::extras::Extras;
+
+/// Index of a node in a [`Model`].
+pub type NodeId = usize;
+/// Index of a member in a [`Model`].
+pub type MemberId = usize;
+
+/// Action applied in a load case. Vectors are in global coordinates.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Load {
+    Nodal { node: NodeId, force: Vector3d, moment: Vector3d },
+    /// Uniform line load along the whole member, per unit length.
+    MemberUniform { member: MemberId, load: Vector3d },
+}
+
+impl Load {
+    /// The load with its node or member renumbered by `node` or `member`;
+    /// `None` when the map drops it.
+    pub fn renumbered<N, M>(&self, node: N, member: M) -> Option<Load>
+    where
+        N: Fn(NodeId) -> Option<NodeId>,
+        M: Fn(MemberId) -> Option<MemberId>,
+    {
+        match *self {
+            Load::Nodal { node: id, force, moment } => node(id).map(|node| Load::Nodal { node, force, moment }),
+            Load::MemberUniform { member: id, load } => member(id).map(|member| Load::MemberUniform { member, load }),
+        }
+    }
+
+    /// The load multiplied by `factor`.
+    pub fn scaled(&self, factor: f64) -> Load {
+        match *self {
+            Load::Nodal { node, force, moment } => {
+                Load::Nodal { node, force: Vector3d(force.0 * factor), moment: Vector3d(moment.0 * factor) }
+            }
+            Load::MemberUniform { member, load } => Load::MemberUniform { member, load: Vector3d(load.0 * factor) },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadCase {
+    name: String,
+    loads: Vec<Load>,
+    #[cfg(feature = "serde")]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    extras: Extras,
+}
+
+impl LoadCase {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            loads: Vec::new(),
+            #[cfg(feature = "serde")]
+            extras: Extras::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str { &self.name }
+    pub fn loads(&self) -> &[Load] { &self.loads }
+    pub fn loads_mut(&mut self) -> &mut [Load] { &mut self.loads }
+    #[cfg(feature = "serde")]
+    pub fn extras(&self) -> &Extras { &self.extras }
+    #[cfg(feature = "serde")]
+    pub fn extras_mut(&mut self) -> &mut Extras { &mut self.extras }
+
+    pub fn add_load(&mut self, load: Load) {
+        self.loads.push(load);
+    }
+
+    pub fn add_nodal_force(&mut self, node: NodeId, force: Vector3d) {
+        self.add_load(Load::Nodal { node, force, moment: Vector3d::new(0.0, 0.0, 0.0) });
+    }
+
+    pub fn add_nodal_moment(&mut self, node: NodeId, moment: Vector3d) {
+        self.add_load(Load::Nodal { node, force: Vector3d::new(0.0, 0.0, 0.0), moment });
+    }
+
+    pub fn add_member_load(&mut self, member: MemberId, load: Vector3d) {
+        self.add_load(Load::MemberUniform { member, load });
+    }
+}
+
+/// Structural model: nodes connected by members, supports and load cases.
+///
+/// Members keep copies of their end nodes (as [`Member`] always did); the model
+/// additionally records which model nodes they connect so the structure can be
+/// assembled. Nodes and members are addressed by their insertion index.
+///
+/// Every collection iterates in a stable order: nodes, members, load cases and
+/// design groups by index, supports by node, and the section and material
+/// catalogues by first use. Exports, reports and assembled matrices are
+/// therefore the same from run to run.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Model {
+    nodes: Vec<Node>,
+    members: Vec<Member>,
+    connectivity: Vec<[NodeId; 2]>,
+    supports: Vec<Option<Fixity>>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    support_patches: Vec<SupportPatch>,
+    load_cases: Vec<LoadCase>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    design_groups: Vec<DesignGroup>,
+}
+
+impl Model {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn add_node<N: Into<Node>>(&mut self, node: N) -> NodeId {
+        self.nodes.push(node.into());
+        self.supports.push(None);
+        self.nodes.len() - 1
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node { &self.nodes[id] }
+    pub fn nodes(&self) -> &[Node] { &self.nodes }
+
+    /// Connect two existing nodes with a member carrying `section`.
+    pub fn add_member(&mut self, start: NodeId, end: NodeId, section: Section) -> MemberId {
+        assert!(start < self.nodes.len() && end < self.nodes.len(), "member references an unknown node");
+        assert_ne!(start, end, "member must connect two different nodes");
+        let member: Member = (self.nodes[start].clone(), self.nodes[end].clone(), section).into();
+        self.members.push(member);
+        self.connectivity.push([start, end]);
+        self.members.len() - 1
+    }
+
+    pub fn member(&self, id: MemberId) -> &Member { &self.members[id] }
+    pub fn member_mut(&mut self, id: MemberId) -> &mut Member { &mut self.members[id] }
+    pub fn members(&self) -> &[Member] { &self.members }
+
+    /// Distinct member sections in the order members first use them.
+    pub fn sections(&self) -> Vec<&Section> {
+        let mut sections: Vec<&Section> = Vec::new();
+        for section in self.members.iter().filter_map(|member| member.get_section()) {
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        sections
+    }
+
+    /// Distinct materials of the member sections in the order of [`Model::sections`].
+    pub fn materials(&self) -> Vec<&Material> {
+        let mut materials: Vec<&Material> = Vec::new();
+        for material in self.sections().into_iter().map(Section::material) {
+            if !materials.contains(&material) {
+                materials.push(material);
+            }
+        }
+        materials
+    }
+
+    /// Start and end node of a member.
+    pub fn member_nodes(&self, id: MemberId) -> [NodeId; 2] { self.connectivity[id] }
+
+    pub fn set_support(&mut self, node: NodeId, fixity: Fixity) {
+        self.supports[node] = if fixity.is_free() { None } else { Some(fixity) };
+    }
+
+    pub fn clear_support(&mut self, node: NodeId) {
+        self.supports[node] = None;
+    }
+
+    pub fn support(&self, node: NodeId) -> Option<&Fixity> { self.supports[node].as_ref() }
+
+    /// Supported nodes in ascending node order.
+    pub fn supports(&self) -> impl Iterator<Item = (NodeId, &Fixity)> {
+        self.supports.iter().enumerate().filter_map(|(id, s)| s.as_ref().map(|f| (id, f)))
+    }
+
+    /// Add an elastic support over several nodes. Panics on unknown nodes.
+    pub fn add_support_patch(&mut self, patch: SupportPatch) -> usize {
+        assert!(patch.nodes().iter().all(|&(node, _)| node < self.nodes.len()), "patch references an unknown node");
+        self.support_patches.push(patch);
+        self.support_patches.len() - 1
+    }
+
+    pub fn support_patch(&self, index: usize) -> &SupportPatch { &self.support_patches[index] }
+    pub fn support_patches(&self) -> &[SupportPatch] { &self.support_patches }
+
+    /// Add a group of members sharing a section. Panics on unknown members.
+    pub fn add_design_group(&mut self, group: DesignGroup) -> usize {
+        assert!(group.members().iter().all(|&m| m < self.members.len()), "design group references an unknown member");
+        self.design_groups.push(group);
+        self.design_groups.len() - 1
+    }
+
+    pub fn design_group(&self, index: usize) -> &DesignGroup { &self.design_groups[index] }
+    pub fn design_groups(&self) -> &[DesignGroup] { &self.design_groups }
+
+    /// Group containing `member`, if any.
+    pub fn design_group_of(&self, member: MemberId) -> Option<usize> {
+        self.design_groups.iter().position(|group| group.contains(member))
+    }
+
+    /// Give every member of a design group `section` and record its capacity,
+    /// one step of an auto-sizing loop.
+    pub fn resize_design_group(&mut self, index: usize, section: Section, capacity: Capacity) {
+        for &member in self.design_groups[index].members() {
+            self.members[member].set_section(section.clone());
+        }
+        self.design_groups[index].set_capacity(capacity);
+    }
+
+    pub fn add_load_case(&mut self, load_case: LoadCase) -> usize {
+        self.load_cases.push(load_case);
+        self.load_cases.len() - 1
+    }
+
+    pub fn load_case(&self, index: usize) -> &LoadCase { &self.load_cases[index] }
+    pub fn load_case_mut(&mut self, index: usize) -> &mut LoadCase { &mut self.load_cases[index] }
+    pub fn load_cases(&self) -> &[LoadCase] { &self.load_cases }
+
+    pub fn load_case_by_name(&self, name: &str) -> Option<&LoadCase> {
+        self.load_cases.iter().find(|case| case.name() == name)
+    }
+
+    /// Model made of the given members (in that order) and the nodes they
+    /// connect, keeping node supports but no load cases. Support patches keep the
+    /// nodes that remain, with their share of the stiffness. Also returns the
+    /// original id of every node of the new model, ascending.
+    pub fn sub_model(&self, members: &[MemberId]) -> (Model, Vec<NodeId>) {
+        let mut nodes: Vec<NodeId> = members.iter().flat_map(|&m| self.connectivity[m]).collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        let index = |node: NodeId| nodes.binary_search(&node).expect("node of a selected member");
+        let mut sub = Model::new();
+        for &node in &nodes {
+            sub.add_node(self.nodes[node].clone());
+            sub.supports[index(node)] = self.supports[node].clone();
+        }
+        for &member in members {
+            sub.members.push(self.members[member].clone());
+            sub.connectivity.push(self.connectivity[member].map(index));
+        }
+        for patch in &self.support_patches {
+            let kept: Vec<(NodeId, f64)> = patch
+                .nodes()
+                .iter()
+                .filter_map(|&(node, weight)| nodes.binary_search(&node).ok().map(|i| (i, weight)))
+                .collect();
+            if kept.is_empty() {
+                continue;
+            }
+            let share = kept.iter().map(|(_, w)| w).sum::<f64>() / patch.nodes().iter().map(|(_, w)| w).sum::<f64>();
+            let mut sub_patch = SupportPatch::new(patch.stiffness().map(|k| k * share));
+            for (node, weight) in kept {
+                sub_patch.add_node(node, weight);
+            }
+            sub.support_patches.push(sub_patch);
+        }
+        (sub, nodes)
+    }
+
+    /// The model with `removed` members taken out, e.g. for alternate load path
+    /// studies. Nodes left without members are dropped with their loads; load
+    /// cases and design groups are renumbered, groups keeping their place even
+    /// when emptied. `nodes` and `members` of the result give the original ids.
+    pub fn without_members(&self, removed: &[MemberId]) -> StageModel {
+        let members: Vec<MemberId> = (0..self.members.len()).filter(|m| !removed.contains(m)).collect();
+        let (mut sub, nodes) = self.sub_model(&members);
+        let node_index = |node: NodeId| nodes.binary_search(&node).ok();
+        let member_index = |member: MemberId| members.binary_search(&member).ok();
+        for case in &self.load_cases {
+            let mut renumbered = LoadCase::new(case.name());
+            #[cfg(feature = "serde")]
+            renumbered.extras_mut().clone_from(case.extras());
+            for load in case.loads().iter().filter_map(|load| load.renumbered(node_index, member_index)) {
+                renumbered.add_load(load);
+            }
+            sub.add_load_case(renumbered);
+        }
+        for group in &self.design_groups {
+            let mut kept = DesignGroup::of(group.name(), group.members().iter().filter_map(|&m| member_index(m)));
+            if let Some(capacity) = group.capacity() {
+                kept.set_capacity(*capacity);
+            }
+            sub.design_groups.push(kept);
+        }
+        StageModel { model: sub, nodes, members }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material::Material;
+
+    #[test]
+    fn members_reference_model_nodes() {
+        let mut model = Model::new();
+        let a = model.add_node((0.0, 0.0, 0.0));
+        let b = model.add_node((3.0, 0.0, 0.0));
+        let material = Material::new(210e9, 0.3, 7850.0, 78.5, 1.2e-5, 0.2, None);
+        let m = model.add_member(a, b, Section::generic(material, None));
+        assert_eq!(model.member_nodes(m), [a, b]);
+        assert_eq!(model.member(m).end_node(), model.node(b));
+
+        model.set_support(a, Fixity::pinned());
+        model.set_support(b, Fixity::free());
+        assert_eq!(model.supports().count(), 1);
+        assert!(model.support(b).is_none());
+    }
+
+    #[test]
+    fn removing_members_renumbers_loads_and_groups() {
+        let mut model = Model::new();
+        let nodes: Vec<_> = (0..4).map(|i| model.add_node((i as f64, 0.0, 0.0))).collect();
+        let material = Material::new(210e9, 0.3, 7850.0, 78.5, 1.2e-5, 0.2, None);
+        for i in 0..3 {
+            model.add_member(nodes[i], nodes[i + 1], Section::generic(material.clone(), None));
+        }
+        model.set_support(nodes[3], Fixity::fixed());
+        let mut case = LoadCase::new("live");
+        case.add_nodal_force(nodes[0], Vector3d::new(0.0, 0.0, -1.0));
+        case.add_member_load(2, Vector3d::new(0.0, 0.0, -2.0));
+        model.add_load_case(case);
+        model.add_design_group(DesignGroup::of("first", [0]));
+        model.add_design_group(DesignGroup::of("rest", [1, 2]));
+
+        let stage = model.without_members(&[0]);
+        assert_eq!(stage.members, vec![1, 2]);
+        assert_eq!(stage.nodes, vec![1, 2, 3]);
+        assert!(stage.model.support(2).is_some());
+        assert_eq!(stage.model.load_case(0).loads(), &[Load::MemberUniform { member: 1, load: Vector3d::new(0.0, 0.0, -2.0) }]);
+        assert!(stage.model.design_group(0).members().is_empty());
+        assert_eq!(stage.model.design_group(1).members(), &[0, 1]);
+    }
+}
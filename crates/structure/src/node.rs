@@ -1,62 +1,31 @@
-use geometry::{Axis, Vector3d};
+use geometry::{spatial::Bounded, BoundingBox3d, LocalAxis, SignedAxis, Transform3d, Transformable, TriadLine, Vector3d};
 use utils::epsilon;
-use nalgebra::{Matrix3, Matrix4, Rotation3, Unit, Vector3};
+use nalgebra::{Matrix3, Matrix4, Rotation3, Vector3};
 
-/// Axis-aligned bounding box helper used by structural entities.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct BoundingBox3d {
-    min: Vector3d,
-    max: Vector3d,
-}
-
-impl BoundingBox3d {
-    pub fn new(min: Vector3d, max: Vector3d) -> Self {
-        let min_vec = Vector3::new(
-            min.x().min(max.x()),
-            min.y().min(max.y()),
-            min.z().min(max.z()),
-        );
-        let max_vec = Vector3::new(
-            min.x().max(max.x()),
-            min.y().max(max.y()),
-            min.z().max(max.z()),
-        );
-
-        Self { min: Vector3d(min_vec), max: Vector3d(max_vec) }
-    }
-
-    pub fn from_point(point: Vector3d) -> Self {
-        Self { min: point, max: point }
-    }
-
-    pub fn expand_with_point(&mut self, point: Vector3d) {
-        self.min = Vector3d(Vector3::new(
-            self.min.x().min(point.x()),
-            self.min.y().min(point.y()),
-            self.min.z().min(point.z()),
-        ));
-        self.max = Vector3d(Vector3::new(
-            self.max.x().max(point.x()),
-            self.max.y().max(point.y()),
-            self.max.z().max(point.z()),
-        ));
-    }
-
-    pub fn min(&self) -> Vector3d { self.min }
-    pub fn max(&self) -> Vector3d { self.max }
-}
+#[cfg(feature = "serde")]
+use crate::extras::Extras;
 
 /// 3D node combining a position and orientation.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Node {
     name: Option<String>,
     center: Vector3d,
     rotation: Rotation3<f64>,
+    #[cfg(feature = "serde")]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    extras: Extras,
 }
 
 impl Node {
     fn from_parts(center: Vector3d, name: Option<String>) -> Self {
-        Self { name, center, rotation: Rotation3::identity() }
+        Self {
+            name,
+            center,
+            rotation: Rotation3::identity(),
+            #[cfg(feature = "serde")]
+            extras: Extras::new(),
+        }
     }
 
     pub fn new<C: Into<Vector3d>>(center: C) -> Self {
@@ -67,6 +36,11 @@ impl Node {
 
     pub fn center(&self) -> Vector3d { self.center }
 
+    #[cfg(feature = "serde")]
+    pub fn extras(&self) -> &Extras { &self.extras }
+    #[cfg(feature = "serde")]
+    pub fn extras_mut(&mut self) -> &mut Extras { &mut self.extras }
+
     pub fn coord(&self, index: usize) -> f64 {
         match index {
             0 => self.center.x(),
@@ -97,17 +71,16 @@ impl Node {
         self.rotate_about_axis(angle, [0.0, 0.0, 1.0]);
     }
 
-    /// Accumulate a rotation around an arbitrary axis expressed in global coordinates.
+    /// Accumulate a rotation around an arbitrary axis expressed in global
+    /// coordinates through the node center.
     pub fn rotate_about_axis(&mut self, angle: f64, axis: [f64; 3]) {
-        let axis_vec = Vector3::new(axis[0], axis[1], axis[2]);
-        if let Some(unit_axis) = Unit::try_new(axis_vec, epsilon()) {
-            let rotation = Rotation3::from_axis_angle(&unit_axis, angle);
-            self.apply_rotation(&rotation);
+        if Vector3::from(axis).norm() > epsilon() {
+            self.transform(&Transform3d::rotation_about(angle, axis, self.center));
         }
     }
 
     pub fn apply_rotation(&mut self, rotation: &Rotation3<f64>) {
-        self.rotation = self.rotation * rotation;
+        self.rotation *= rotation;
     }
 
     /// Translate the node by a vector expressed in the node local coordinates.
@@ -125,6 +98,11 @@ impl Node {
         BoundingBox3d::from_point(self.center)
     }
 
+    /// Orientation triad of the node axes at its center.
+    pub fn triad_geometry(&self, scale: f64) -> [TriadLine; 3] {
+        LocalAxis::new(self.center, self.rotation_matrix()).triad_geometry(scale)
+    }
+
     pub fn rotation_matrix(&self) -> Matrix3<f64> {
         *self.rotation.matrix()
     }
@@ -138,8 +116,8 @@ impl Node {
         matrix
     }
 
-    pub fn direction(&self, axis: Axis) -> Vector3d {
-        let rotated = self.rotation * axis.to_vector3d().0;
+    pub fn direction<A: Into<SignedAxis>>(&self, axis: A) -> Vector3d {
+        let rotated = self.rotation * axis.into().to_vector3d().0;
         Vector3d(rotated)
     }
 
@@ -214,12 +192,26 @@ where
 }
 
 
+impl Transformable for Node {
+    /// Moves the center; the node frame is carried along with
+    /// [`Transform3d::apply_frame`] and stays right-handed under mirroring.
+    fn transform(&mut self, transform: &Transform3d) {
+        self.center = transform.apply_point(&self.center);
+        self.rotation = Rotation3::from_matrix_unchecked(transform.apply_frame(self.rotation.matrix()));
+    }
+}
+
+impl Bounded for Node {
+    fn bounds(&self) -> BoundingBox3d { self.bounding_box() }
+    fn distance(&self, point: &Vector3d) -> f64 { (self.center.0 - point.0).norm() }
+}
+
 #[cfg(test)]
 mod tests {
-    use geometry::Vector3d;
+    use geometry::{Axis, Vector3d};
     use utils::{assert_almost_eq, assert_vec3_almost_eq};
 
-    use super::{Axis, Node};
+    use super::Node;
 
     #[test]
     fn coordinate_access_and_update() {
@@ -0,0 +1,69 @@
+
+use crate::model::NodeId;
+
+/// Elastic support spread over a footprint of nodes, e.g. a column head under a
+/// plate or the piles under a cap. The total translational stiffness of the
+/// patch is shared among its nodes in proportion to their relative stiffness,
+/// so the reaction is distributed over the area instead of concentrating in a
+/// single node; the rotational stiffness of the patch follows from the spread.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupportPatch {
+    /// Total stiffness along the global X, Y and Z axes; zero leaves the
+    /// direction free.
+    stiffness: [f64; 3],
+    nodes: Vec<(NodeId, f64)>,
+}
+
+impl SupportPatch {
+    /// Panics on negative stiffness.
+    pub fn new(stiffness: [f64; 3]) -> Self {
+        assert!(stiffness.iter().all(|&k| k >= 0.0), "patch stiffness must not be negative");
+        Self { stiffness, nodes: Vec::new() }
+    }
+
+    /// Patch sharing its stiffness equally among `nodes`.
+    pub fn uniform<I: IntoIterator<Item = NodeId>>(stiffness: [f64; 3], nodes: I) -> Self {
+        let mut patch = Self::new(stiffness);
+        for node in nodes {
+            patch.add_node(node, 1.0);
+        }
+        patch
+    }
+
+    pub fn with_node(mut self, node: NodeId, relative_stiffness: f64) -> Self {
+        self.add_node(node, relative_stiffness);
+        self
+    }
+
+    /// Add a node with its share of the stiffness relative to the other nodes,
+    /// e.g. its tributary area or a pile stiffness. Panics unless positive.
+    pub fn add_node(&mut self, node: NodeId, relative_stiffness: f64) {
+        assert!(relative_stiffness > 0.0, "relative stiffness must be positive");
+        self.nodes.push((node, relative_stiffness));
+    }
+
+    pub fn stiffness(&self) -> [f64; 3] { self.stiffness }
+    pub fn nodes(&self) -> &[(NodeId, f64)] { &self.nodes }
+
+    /// Spring stiffness of each node along the global axes, in node order.
+    pub fn node_stiffness(&self) -> Vec<(NodeId, [f64; 3])> {
+        let total: f64 = self.nodes.iter().map(|(_, weight)| weight).sum();
+        self.nodes.iter().map(|&(node, weight)| (node, self.stiffness.map(|k| k * weight / total))).collect()
+    }
+
+    /// Global axes the patch restrains.
+    pub fn restrained(&self) -> [bool; 3] { self.stiffness.map(|k| k > 0.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stiffness_is_shared_by_weight() {
+        let patch = SupportPatch::new([0.0, 0.0, 100.0]).with_node(3, 1.0).with_node(5, 3.0);
+        assert_eq!(patch.node_stiffness(), vec![(3, [0.0, 0.0, 25.0]), (5, [0.0, 0.0, 75.0])]);
+        assert_eq!(patch.restrained(), [false, false, true]);
+    }
+}
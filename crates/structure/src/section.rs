@@ -1,14 +1,18 @@
 use geometry::Vector3d;
 
+#[cfg(feature = "serde")]
+use crate::extras::Extras;
 use crate::material::Material;
 
 /// Simplified cross-section entity capturing the metadata listed in the Python dump.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Section {
     name: Option<String>,
     material: Material,
     area: f64,
     mass: f64,
+    perimeter: f64,
     centroid: Vector3d,
     elastic_modulus: Vector3d,
     is_generic: bool,
@@ -30,6 +34,9 @@ pub struct Section {
     rotation_principal_axes: Option<f64>,
     parts: Vec<String>,
     section_values: Vec<f64>,
+    #[cfg(feature = "serde")]
+    #[serde(default, skip_serializing_if = "Extras::is_empty")]
+    extras: Extras,
 }
 
 impl Section {
@@ -39,6 +46,7 @@ impl Section {
             material,
             area: 0.0,
             mass: 0.0,
+            perimeter: 0.0,
             centroid: Vector3d::new(0.0, 0.0, 0.0),
             elastic_modulus: Vector3d::new(0.0, 0.0, 0.0),
             is_generic: true,
@@ -60,14 +68,22 @@ impl Section {
             rotation_principal_axes: None,
             parts: Vec::new(),
             section_values: Vec::new(),
+            #[cfg(feature = "serde")]
+            extras: Extras::new(),
         }
     }
 
     pub fn name(&self) -> Option<&str> { self.name.as_deref() }
     pub fn material(&self) -> &Material { &self.material }
+    #[cfg(feature = "serde")]
+    pub fn extras(&self) -> &Extras { &self.extras }
+    #[cfg(feature = "serde")]
+    pub fn extras_mut(&mut self) -> &mut Extras { &mut self.extras }
 
     pub fn area(&self) -> f64 { self.area }
     pub fn mass(&self) -> f64 { self.mass }
+    /// Outline length exposed for coating, i.e. painted surface per unit length.
+    pub fn perimeter(&self) -> f64 { self.perimeter }
     pub fn centroid(&self) -> Vector3d { self.centroid }
     pub fn elastic_modulus(&self) -> Vector3d { self.elastic_modulus }
     pub fn is_generic(&self) -> bool { self.is_generic }
@@ -100,6 +116,7 @@ impl Section {
 
     pub fn set_area(&mut self, area: f64) { self.area = area; }
     pub fn set_mass(&mut self, mass: f64) { self.mass = mass; }
+    pub fn set_perimeter(&mut self, perimeter: f64) { self.perimeter = perimeter; }
     pub fn set_centroid(&mut self, centroid: Vector3d) { self.centroid = centroid; }
 
     pub fn set_elastic_modulus(&mut self, modulus: Vector3d) {
@@ -112,6 +129,10 @@ impl Section {
         self.second_moment_yz = iyz;
     }
 
+    pub fn set_torsion_constant(&mut self, torsion_constant: f64) {
+        self.torsion_constant = torsion_constant;
+    }
+
     pub fn set_radius_of_gyration(&mut self, radius: Vector3d) {
         self.radius_of_gyration = radius;
     }
@@ -119,6 +140,77 @@ impl Section {
     pub fn simplified(&self) -> Vec<String> {
         Vec::new()
     }
+
+    pub fn property(&self, property: SectionProperty) -> f64 {
+        match property {
+            SectionProperty::Area => self.area,
+            SectionProperty::Perimeter => self.perimeter,
+            SectionProperty::SecondMomentY => self.second_moment_y,
+            SectionProperty::SecondMomentZ => self.second_moment_z,
+            SectionProperty::TorsionConstant => self.torsion_constant,
+            SectionProperty::WarpingConstant => self.warping_constant,
+        }
+    }
+
+    /// Deviation of every property listed in `catalog` from its catalogue
+    /// value, in catalogue order.
+    pub fn compare_with_catalog(&self, catalog: &CatalogValues) -> Vec<PropertyDeviation> {
+        catalog
+            .values
+            .iter()
+            .map(|&(property, expected)| PropertyDeviation { property, computed: self.property(property), catalog: expected })
+            .collect()
+    }
+}
+
+/// Cross-section property that can be checked against a catalogue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionProperty {
+    Area,
+    Perimeter,
+    SecondMomentY,
+    SecondMomentZ,
+    TorsionConstant,
+    WarpingConstant,
+}
+
+/// Published values of a profile, e.g. copied from steel tables, to validate
+/// computed section properties against. Only listed properties are compared.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalogValues {
+    values: Vec<(SectionProperty, f64)>,
+}
+
+impl CatalogValues {
+    pub fn new() -> Self { Self::default() }
+
+    /// Catalogue value of a property, replacing an earlier one.
+    pub fn with(mut self, property: SectionProperty, value: f64) -> Self {
+        self.values.retain(|(listed, _)| *listed != property);
+        self.values.push((property, value));
+        self
+    }
+
+    pub fn values(&self) -> &[(SectionProperty, f64)] { &self.values }
+}
+
+/// A computed property next to its catalogue value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropertyDeviation {
+    pub property: SectionProperty,
+    pub computed: f64,
+    pub catalog: f64,
+}
+
+impl PropertyDeviation {
+    /// `(computed - catalog) / catalog`; infinite for a zero catalogue value
+    /// unless the computed value is zero too.
+    pub fn relative(&self) -> f64 {
+        let difference = self.computed - self.catalog;
+        if difference == 0.0 { 0.0 } else { difference / self.catalog.abs() }
+    }
+
+    pub fn within(&self, tolerance: f64) -> bool { self.relative().abs() <= tolerance }
 }
 
 #[cfg(test)]
@@ -152,4 +244,26 @@ mod tests {
         assert!(section.simplified().is_empty());
         assert_vec3_almost_eq!(section.centroid(), Vector3d::new(0.0, 0.0, 0.0));
     }
+
+    #[test]
+    fn catalog_deviations_are_relative_to_catalog() {
+        let material = Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None);
+        let mut section = Section::generic(material, Some("IPE 300".into()));
+        // Corner fillets ignored: area and inertia come out slightly low.
+        section.set_area(51.4e-4);
+        section.set_second_moment_components(8240e-8, 600e-8, 0.0);
+        let catalog = CatalogValues::new()
+            .with(SectionProperty::Area, 53.8e-4)
+            .with(SectionProperty::SecondMomentY, 8356e-8)
+            .with(SectionProperty::TorsionConstant, 20.1e-8);
+
+        let deviations = section.compare_with_catalog(&catalog);
+        assert_eq!(deviations.len(), 3);
+        assert_eq!(deviations[0].property, SectionProperty::Area);
+        assert_almost_eq!(deviations[0].relative(), (51.4 - 53.8) / 53.8);
+        assert!(!deviations[0].within(0.02) && deviations[0].within(0.05));
+        assert!(deviations[1].within(0.02));
+        // Torsion constant was never computed.
+        assert_almost_eq!(deviations[2].relative(), -1.0);
+    }
 }
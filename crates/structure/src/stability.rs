@@ -0,0 +1,148 @@
+use nalgebra::{DMatrix, Matrix3, Vector3};
+
+use crate::{
+    linearelement::Fixity,
+    model::{MemberId, Model, NodeId},
+};
+
+/// Rank threshold relative to the largest singular value.
+const RANK_TOLERANCE: f64 = 1e-9;
+
+/// A mechanism found by [`Model::instabilities`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instability {
+    /// A connected sub-assembly whose supports leave `modes` of its six rigid-body
+    /// motions free (`modes == 6` when it is not supported at all).
+    RigidBodyMotion { nodes: Vec<NodeId>, members: Vec<MemberId>, modes: usize },
+    /// A member released about its own axis at both ends, free to spin.
+    TorsionalMechanism { member: MemberId },
+    /// A node whose attached member ends and support leave some directions
+    /// unrestrained, e.g. a node where every member is pinned and no support
+    /// fixes the rotation.
+    UnrestrainedNode { node: NodeId, translations: usize, rotations: usize },
+}
+
+impl Model {
+    /// Mechanisms that would make the stiffness matrix singular, found from the
+    /// topology, supports and member end fixities alone.
+    ///
+    /// Member end fixities flag the DOFs connected to the node (released where
+    /// `false`); ends without a fixity are rigid. Each connected sub-assembly is
+    /// treated as a rigid body when checking its supports, so mechanisms formed by
+    /// internal hinges are only found where they free a single member or node.
+    pub fn instabilities(&self) -> Vec<Instability> {
+        let graph = self.graph();
+        let mut found = Vec::new();
+
+        for (nodes, members) in graph.components().into_iter().zip(graph.component_members()) {
+            let modes = 6 - self.rigid_body_restraint(&nodes);
+            if modes > 0 {
+                found.push(Instability::RigidBodyMotion { nodes, members, modes });
+            }
+        }
+
+        for member in 0..self.members().len() {
+            let released = |fixity: Option<&Fixity>| fixity.is_some_and(|f| !f.rotations()[0]);
+            if released(self.member(member).get_start_fixity()) && released(self.member(member).get_end_fixity()) {
+                found.push(Instability::TorsionalMechanism { member });
+            }
+        }
+
+        // Unconnected nodes are already reported as rigid bodies of their own.
+        for node in (0..self.nodes().len()).filter(|&n| graph.degree(n) > 0) {
+            let mut translations = Vec::new();
+            let mut rotations = Vec::new();
+            let [tx, ty, tz, rx, ry, rz] = self.support_dofs(node);
+            translations.extend(axes(&Matrix3::identity(), [tx, ty, tz]));
+            rotations.extend(axes(&Matrix3::identity(), [rx, ry, rz]));
+            for &(member, _) in graph.incident(node) {
+                let m = self.member(member);
+                let fixity = if self.member_nodes(member)[0] == node { m.get_start_fixity() } else { m.get_end_fixity() };
+                let dofs = fixity.map_or([true; 6], Fixity::dofs);
+                let rotation = m.rotation_matrix();
+                translations.extend(axes(&rotation, [dofs[0], dofs[1], dofs[2]]));
+                rotations.extend(axes(&rotation, [dofs[3], dofs[4], dofs[5]]));
+            }
+            let (translations, rotations) = (3 - rank(&translations), 3 - rank(&rotations));
+            if translations + rotations > 0 {
+                found.push(Instability::UnrestrainedNode { node, translations, rotations });
+            }
+        }
+        found
+    }
+
+    /// Number of independent rigid-body motions of `nodes` fixed by their supports.
+    fn rigid_body_restraint(&self, nodes: &[NodeId]) -> usize {
+        let center = nodes.iter().map(|&n| self.node(n).center().0).sum::<Vector3<f64>>() / nodes.len() as f64;
+        // Rows map a rigid motion (translation t, rotation ω about the center) to
+        // each restrained support DOF.
+        let mut rows = Vec::new();
+        for &node in nodes {
+            let arm = self.node(node).center().0 - center;
+            for (dof, restrained) in self.support_dofs(node).into_iter().enumerate() {
+                if !restrained {
+                    continue;
+                }
+                let mut row = [0.0; 6];
+                row[dof] = 1.0;
+                if dof < 3 {
+                    // e · (ω × r) = ω · (r × e)
+                    row[3..].copy_from_slice(arm.cross(&Vector3::ith(dof, 1.0)).as_slice());
+                }
+                rows.push(row);
+            }
+        }
+        if rows.is_empty() {
+            return 0;
+        }
+        let matrix = DMatrix::from_fn(rows.len(), 6, |i, j| rows[i][j]);
+        matrix_rank(matrix)
+    }
+
+    /// DOFs of a node held by its support or by a support patch containing it.
+    fn support_dofs(&self, node: NodeId) -> [bool; 6] {
+        let mut dofs = self.support(node).map_or([false; 6], Fixity::dofs);
+        for patch in self.support_patches().iter().filter(|p| p.nodes().iter().any(|&(n, _)| n == node)) {
+            for (dof, restrained) in patch.restrained().into_iter().enumerate() {
+                dofs[dof] |= restrained;
+            }
+        }
+        dofs
+    }
+}
+
+/// Global directions of the flagged local axes (columns of `rotation`).
+fn axes(rotation: &Matrix3<f64>, flags: [bool; 3]) -> Vec<Vector3<f64>> {
+    (0..3).filter(|&i| flags[i]).map(|i| rotation.column(i).into_owned()).collect()
+}
+
+fn rank(directions: &[Vector3<f64>]) -> usize {
+    if directions.is_empty() {
+        return 0;
+    }
+    matrix_rank(DMatrix::from_fn(directions.len(), 3, |i, j| directions[i][j]))
+}
+
+fn matrix_rank(matrix: DMatrix<f64>) -> usize {
+    let singular = matrix.singular_values();
+    let largest = singular.max();
+    singular.iter().filter(|&&s| s > largest * RANK_TOLERANCE).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{material::Material, section::Section};
+
+    #[test]
+    fn unsupported_model_has_six_free_modes() {
+        let mut model = Model::new();
+        let a = model.add_node((0.0, 0.0, 0.0));
+        let b = model.add_node((2.0, 0.0, 0.0));
+        model.add_member(a, b, Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None));
+        assert_eq!(
+            model.instabilities(),
+            vec![Instability::RigidBodyMotion { nodes: vec![0, 1], members: vec![0], modes: 6 }]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use crate::extras::Value;
+use crate::{member::Member, model::Model};
+
+/// How members are grouped in a [`Takeoff`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupBy {
+    /// Section name.
+    Section,
+    /// Material name of the section.
+    Material,
+    /// Value of a member extra, e.g. a fabrication group or assembly mark.
+    #[cfg(feature = "serde")]
+    Extra(String),
+}
+
+/// Prices applied to the quantities of one group; unused rates stay zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UnitCost {
+    pub per_length: f64,
+    pub per_surface: f64,
+    pub per_mass: f64,
+}
+
+/// Quantities of one group of members, in the model units (m, m², kg).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TakeoffRow {
+    pub group: String,
+    pub count: usize,
+    pub length: f64,
+    /// Coating surface from the section perimeter.
+    pub surface: f64,
+    pub mass: f64,
+    /// `None` when no unit cost was given for the group.
+    pub cost: Option<f64>,
+}
+
+/// Quantity takeoff of the members of a model: length, coating surface and mass
+/// per group, priced with optional unit costs.
+#[derive(Debug, Clone)]
+pub struct Takeoff {
+    group_by: GroupBy,
+    unit_costs: BTreeMap<String, UnitCost>,
+}
+
+impl Takeoff {
+    pub fn new(group_by: GroupBy) -> Self {
+        Self { group_by, unit_costs: BTreeMap::new() }
+    }
+
+    pub fn with_unit_cost(mut self, group: impl Into<String>, cost: UnitCost) -> Self {
+        self.unit_costs.insert(group.into(), cost);
+        self
+    }
+
+    pub fn group_by(&self) -> &GroupBy { &self.group_by }
+
+    /// Group name of a member. Members without a section, or whose section,
+    /// material or extra is unnamed, fall into the group `""`.
+    pub fn group_of(&self, member: &Member) -> String {
+        let section = member.get_section();
+        match &self.group_by {
+            GroupBy::Section => section.and_then(|s| s.name()).unwrap_or_default().to_string(),
+            GroupBy::Material => section.and_then(|s| s.material().name()).unwrap_or_default().to_string(),
+            #[cfg(feature = "serde")]
+            GroupBy::Extra(key) => match member.extras().get(key) {
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => String::new(),
+            },
+        }
+    }
+
+    /// Rows sorted by group name. The mass per unit length is the section mass,
+    /// or area times material density where the section has no mass set.
+    pub fn compute(&self, model: &Model) -> TakeoffTable {
+        let mut groups: BTreeMap<String, TakeoffRow> = BTreeMap::new();
+        for member in model.members() {
+            let group = self.group_of(member);
+            let row = groups.entry(group.clone()).or_insert_with(|| TakeoffRow {
+                group,
+                count: 0,
+                length: 0.0,
+                surface: 0.0,
+                mass: 0.0,
+                cost: None,
+            });
+            let length = member.length();
+            let (perimeter, mass) = member.get_section().map_or((0.0, 0.0), |section| {
+                let mass = if section.mass() > 0.0 { section.mass() } else { section.area() * section.material().density() };
+                (section.perimeter(), mass)
+            });
+            row.count += 1;
+            row.length += length;
+            row.surface += perimeter * length;
+            row.mass += mass * length;
+        }
+        let rows = groups
+            .into_values()
+            .map(|mut row| {
+                row.cost = self
+                    .unit_costs
+                    .get(&row.group)
+                    .map(|c| c.per_length * row.length + c.per_surface * row.surface + c.per_mass * row.mass);
+                row
+            })
+            .collect();
+        TakeoffTable { rows }
+    }
+}
+
+/// Result of [`Takeoff::compute`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TakeoffTable {
+    rows: Vec<TakeoffRow>,
+}
+
+impl TakeoffTable {
+    pub fn rows(&self) -> &[TakeoffRow] { &self.rows }
+
+    pub fn row(&self, group: &str) -> Option<&TakeoffRow> { self.rows.iter().find(|row| row.group == group) }
+
+    /// Sum over all groups, named `"Total"`; the cost sums the priced groups only.
+    pub fn total(&self) -> TakeoffRow {
+        let priced: Vec<f64> = self.rows.iter().filter_map(|row| row.cost).collect();
+        TakeoffRow {
+            group: "Total".to_string(),
+            count: self.rows.iter().map(|row| row.count).sum(),
+            length: self.rows.iter().map(|row| row.length).sum(),
+            surface: self.rows.iter().map(|row| row.surface).sum(),
+            mass: self.rows.iter().map(|row| row.mass).sum(),
+            cost: (!priced.is_empty()).then(|| priced.iter().sum()),
+        }
+    }
+
+    /// Comma-separated table with a header, one line per group and a total line.
+    /// Unpriced costs are left empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("group,count,length,surface,mass,cost\n");
+        for row in self.rows.iter().chain(std::iter::once(&self.total())) {
+            let cost = row.cost.map(|c| c.to_string()).unwrap_or_default();
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                csv_field(&row.group),
+                row.count,
+                row.length,
+                row.surface,
+                row.mass,
+                cost
+            );
+        }
+        csv
+    }
+}
+
+/// Quote a field containing separators, quotes or line breaks.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("IPE 300"), "IPE 300");
+        assert_eq!(csv_field("HEA 200, S355"), "\"HEA 200, S355\"");
+        assert_eq!(csv_field("2\" pipe"), "\"2\"\" pipe\"");
+    }
+}
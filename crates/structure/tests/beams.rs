@@ -189,3 +189,21 @@ fn linear_element_and_line_reflect_beam_geometry() {
     assert_vec3_almost_eq!(element_line.start(), beam_line.start());
     assert_vec3_almost_eq!(element_line.end(), beam_line.end());
 }
+
+#[test]
+fn triads_follow_node_and_beam_axes() {
+    let beam = beam_from_coords((0.0, 0.0, 0.0), (0.0, 0.0, 3.0));
+    let triad = beam.triad_geometry(0.2);
+    assert_vec3_almost_eq!(triad[0].line.start(), Vector3d::new(0.0, 0.0, 1.5));
+    for arm in &triad {
+        assert_vec3_almost_eq!(arm.line.end(), Vector3d(arm.line.start().0 + beam.direction(arm.axis).0 * 0.2));
+    }
+    assert_vec3_almost_eq!(triad[0].line.end(), Vector3d::new(0.0, 0.0, 1.7));
+
+    let mut node = Node::new((1.0, 2.0, 3.0));
+    node.rotate(FRAC_PI_2);
+    let triad = node.triad_geometry(1.0);
+    assert_vec3_almost_eq!(triad[0].line.end(), Vector3d::new(1.0, 3.0, 3.0));
+    assert_vec3_almost_eq!(triad[1].line.end(), Vector3d::new(0.0, 2.0, 3.0));
+    assert_eq!(triad[2].color, Axis::AxisZ.color());
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use structure::{Fixity, LoadCase, Material, Model, Node, Section};
+
+#[test]
+fn extras_round_trip_through_json() {
+    let mut model = Model::new();
+    let mut start = Node::new((0.0, 0.0, 0.0));
+    start.extras_mut().insert("tag".into(), json!("N-101"));
+    let a = model.add_node(start);
+    let b = model.add_node((5.0, 0.0, 0.0));
+
+    let mut section = Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None);
+    section.extras_mut().insert("catalogue".into(), json!({ "series": "IPE", "size": 300 }));
+    let member = model.add_member(a, b, section);
+    model.member_mut(member).extras_mut().insert("cost".into(), json!(1250.5));
+    model.set_support(a, Fixity::fixed());
+
+    let mut case = LoadCase::new("dead");
+    case.extras_mut().insert("factor".into(), json!(1.35));
+    model.add_load_case(case);
+
+    let text = serde_json::to_string(&model).unwrap();
+    let restored: Model = serde_json::from_str(&text).unwrap();
+
+    assert_eq!(restored.node(a).extras()["tag"], json!("N-101"));
+    assert!(restored.node(b).extras().is_empty());
+    let member = restored.member(member);
+    assert_eq!(member.extras()["cost"], json!(1250.5));
+    assert_eq!(member.get_section().unwrap().extras()["catalogue"]["size"], json!(300));
+    assert_eq!(restored.load_case(0).extras()["factor"], json!(1.35));
+    assert_eq!(restored.support(a), Some(&Fixity::fixed()));
+    assert_eq!(serde_json::to_string(&restored).unwrap(), text);
+}
+
+#[test]
+fn empty_extras_are_not_written() {
+    let text = serde_json::to_string(&Node::new((1.0, 2.0, 3.0))).unwrap();
+    assert!(!text.contains("extras"));
+    let node: Node = serde_json::from_str(&text).unwrap();
+    assert_eq!(node, Node::new((1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn extras_serialize_in_key_order() {
+    let mut first = Node::new((0.0, 0.0, 0.0));
+    let mut second = first.clone();
+    for key in ["zone", "id", "phase"] {
+        first.extras_mut().insert(key.into(), json!(key));
+    }
+    for key in ["phase", "zone", "id"] {
+        second.extras_mut().insert(key.into(), json!(key));
+    }
+    let text = serde_json::to_string(&first).unwrap();
+    assert_eq!(text, serde_json::to_string(&second).unwrap());
+    assert!(text.find("\"id\"").unwrap() < text.find("\"phase\"").unwrap());
+    assert!(text.find("\"phase\"").unwrap() < text.find("\"zone\"").unwrap());
+}
+
+#[test]
+fn catalogues_follow_first_use() {
+    let steel = Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, Some("S355".into()));
+    let timber = Material::new(11e9, 0.3, 420.0, 4.2e3, 5e-6, 0.0, Some("GL24h".into()));
+    let beam = Section::generic(steel.clone(), Some("IPE 300".into()));
+    let column = Section::generic(steel.clone(), Some("HEB 200".into()));
+    let purlin = Section::generic(timber.clone(), Some("120x240".into()));
+
+    let mut model = Model::new();
+    let nodes: Vec<_> = (0..5).map(|i| model.add_node((i as f64, 0.0, 0.0))).collect();
+    for (i, section) in [&purlin, &beam, &purlin, &column].into_iter().enumerate() {
+        model.add_member(nodes[i], nodes[i + 1], section.clone());
+    }
+    assert_eq!(model.sections(), vec![&purlin, &beam, &column]);
+    assert_eq!(model.materials(), vec![&timber, &steel]);
+}
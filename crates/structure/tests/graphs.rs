@@ -0,0 +1,64 @@
+use structure::{Material, Model, Section};
+
+fn section() -> Section {
+    Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None)
+}
+
+/// A portal frame (0-1-2-3) with a diagonal brace 0-2 and a detached bar 4-5.
+fn braced_portal() -> Model {
+    let mut model = Model::new();
+    let points = [
+        (0.0, 0.0, 0.0),
+        (0.0, 0.0, 3.0),
+        (4.0, 0.0, 3.0),
+        (4.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+    ];
+    let nodes: Vec<_> = points.iter().map(|p| model.add_node(*p)).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 3), (0, 2), (4, 5)] {
+        model.add_member(nodes[a], nodes[b], section());
+    }
+    model
+}
+
+#[test]
+fn components_split_detached_parts() {
+    let model = braced_portal();
+    let graph = model.graph();
+    assert_eq!(graph.components(), vec![vec![0, 1, 2, 3], vec![4, 5]]);
+    assert_eq!(graph.component_members(), vec![vec![0, 1, 2, 3], vec![4]]);
+    assert!(!graph.is_connected());
+    assert_eq!(graph.neighbors(2), vec![0, 1, 3]);
+    assert_eq!(graph.degree(0), 2);
+}
+
+#[test]
+fn shortest_path_follows_member_lengths() {
+    let model = braced_portal();
+    let graph = model.graph();
+    // Brace (5 m) beats column plus beam (7 m).
+    assert_eq!(graph.shortest_path(0, 2), Some(vec![3]));
+    assert_eq!(graph.shortest_path(1, 3), Some(vec![1, 2]));
+    assert_eq!(graph.shortest_path(3, 3), Some(vec![]));
+    assert_eq!(graph.shortest_path(0, 5), None);
+}
+
+#[test]
+fn cycles_are_closed_loops() {
+    let model = braced_portal();
+    let graph = model.graph();
+    let cycles = graph.cycles();
+    assert_eq!(cycles.len(), 1);
+    let mut cycle = cycles[0].clone();
+    cycle.sort_unstable();
+    assert_eq!(cycle, vec![0, 1, 3]);
+
+    let mut open = Model::new();
+    let a = open.add_node((0.0, 0.0, 0.0));
+    let b = open.add_node((1.0, 0.0, 0.0));
+    let c = open.add_node((2.0, 0.0, 0.0));
+    open.add_member(a, b, section());
+    open.add_member(b, c, section());
+    assert!(!open.graph().has_cycle());
+}
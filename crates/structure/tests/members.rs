@@ -1,5 +1,5 @@
-use geometry::Vector3d;
-use utils::assert_almost_eq;
+use geometry::{Axis, Plane, Transformable, Vector3d};
+use utils::{assert_almost_eq, assert_vec3_almost_eq};
 
 use structure::{Beam, Member, Node};
 
@@ -31,3 +31,44 @@ fn member_mesh_can_be_populated_later() {
     assert_eq!(member.mesh().len(), 1);
     assert_almost_eq!(member.mesh()[0].length(), 1.0);
 }
+
+#[test]
+fn mirrored_member_keeps_right_handed_axes() {
+    let mut member = Member::new(Node::new(Vector3d::new(0.0, 0.0, 0.0)), Node::new(Vector3d::new(2.0, 0.0, 3.0)));
+    member.add_mesh_beam(Beam::new(Node::new(Vector3d::new(0.0, 0.0, 0.0)), Node::new(Vector3d::new(1.0, 0.0, 1.5))));
+    member.mirror_about_plane(&Plane::new((4.0, 0.0, 0.0), (1.0, 0.0, 0.0)));
+
+    assert_vec3_almost_eq!(member.start_node().center(), Vector3d::new(8.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(member.end_node().center(), Vector3d::new(6.0, 0.0, 3.0));
+    assert_vec3_almost_eq!(member.mesh()[0].end_node().center(), Vector3d::new(7.0, 0.0, 1.5));
+    let along = 13.0_f64.sqrt();
+    assert_vec3_almost_eq!(member.direction(Axis::AxisX), Vector3d::new(-2.0 / along, 0.0, 3.0 / along));
+    assert_vec3_almost_eq!(member.direction(Axis::AxisY), Vector3d::new(0.0, -1.0, 0.0));
+    assert_vec3_almost_eq!(member.direction(Axis::AxisZ), Vector3d::new(3.0 / along, 0.0, 2.0 / along));
+    assert_almost_eq!(member.rotation_matrix().determinant(), 1.0);
+    let node = member.start_node();
+    assert_vec3_almost_eq!(node.direction(Axis::AxisX), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_vec3_almost_eq!(node.direction(Axis::AxisY), Vector3d::new(0.0, -1.0, 0.0));
+    assert_vec3_almost_eq!(node.direction(Axis::AxisZ), Vector3d::new(0.0, 0.0, 1.0));
+    assert_almost_eq!(node.rotation_matrix().determinant(), 1.0);
+    assert_almost_eq!(member.length(), 13.0_f64.sqrt());
+}
+
+#[test]
+fn members_and_nodes_in_spatial_index() {
+    use geometry::spatial::Bvh;
+
+    let nodes: Vec<Node> = (0..5).map(|i| Node::new(Vector3d::new(i as f64, 0.0, 0.0))).collect();
+    let members: Vec<Member> = nodes.windows(2).map(|w| Member::new(w[0].clone(), w[1].clone())).collect();
+
+    let node_index = Bvh::new(nodes.iter().collect());
+    assert_eq!(node_index.nearest(&Vector3d::new(2.2, 1.0, 0.0)).map(|hit| hit.0), Some(2));
+    assert_eq!(node_index.within(&Vector3d::new(2.0, 0.0, 0.0), 1e-9), vec![2]);
+
+    let member_index = Bvh::new(members.iter().collect());
+    let (closest, distance) = member_index.nearest(&Vector3d::new(2.5, 0.5, 0.0)).unwrap();
+    assert_eq!(closest, 2);
+    assert_almost_eq!(distance, 0.5);
+    // Consecutive members share an end node.
+    assert_eq!(member_index.overlapping_pairs(), vec![(0, 1), (1, 2), (2, 3)]);
+}
@@ -0,0 +1,62 @@
+use structure::{Fixity, Instability, Material, Model, Section};
+
+fn section() -> Section {
+    Section::generic(Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, None), None)
+}
+
+#[test]
+fn pin_ended_beam_spins_about_its_axis() {
+    let mut model = Model::new();
+    let a = model.add_node((0.0, 0.0, 0.0));
+    let b = model.add_node((4.0, 0.0, 0.0));
+    let member = model.add_member(a, b, section());
+    model.set_support(a, Fixity::fixed());
+    model.set_support(b, Fixity::fixed());
+    assert!(model.instabilities().is_empty());
+
+    model.member_mut(member).set_start_fixity(Fixity::pinned());
+    model.member_mut(member).set_end_fixity(Fixity::pinned());
+    assert_eq!(model.instabilities(), vec![Instability::TorsionalMechanism { member }]);
+}
+
+#[test]
+fn pinned_truss_node_needs_rotational_restraint() {
+    let mut model = Model::new();
+    let left = model.add_node((0.0, 0.0, 0.0));
+    let apex = model.add_node((2.0, 0.0, 2.0));
+    let right = model.add_node((4.0, 0.0, 0.0));
+    for (a, b) in [(left, apex), (apex, right)] {
+        let member = model.add_member(a, b, section());
+        model.member_mut(member).set_start_fixity(Fixity::new([true; 3], [true, false, false]));
+        model.member_mut(member).set_end_fixity(Fixity::new([true; 3], [true, false, false]));
+    }
+    model.set_support(left, Fixity::fixed());
+    model.set_support(right, Fixity::fixed());
+    // Both bars lie in the XZ plane, so their torsion axes only span two
+    // rotation directions at the apex.
+    assert_eq!(model.instabilities(), vec![Instability::UnrestrainedNode { node: apex, translations: 0, rotations: 1 }]);
+
+    model.set_support(apex, Fixity::new([false; 3], [false, true, false]));
+    assert!(model.instabilities().is_empty());
+}
+
+#[test]
+fn collinear_pins_leave_rotation_about_their_line() {
+    let mut model = Model::new();
+    let a = model.add_node((0.0, 0.0, 0.0));
+    let b = model.add_node((3.0, 0.0, 0.0));
+    let c = model.add_node((6.0, 0.0, 0.0));
+    model.add_member(a, b, section());
+    model.add_member(b, c, section());
+    model.set_support(a, Fixity::pinned());
+    model.set_support(c, Fixity::pinned());
+    assert_eq!(
+        model.instabilities(),
+        vec![Instability::RigidBodyMotion { nodes: vec![a, b, c], members: vec![0, 1], modes: 1 }]
+    );
+
+    let detached = model.add_node((0.0, 5.0, 0.0));
+    model.set_support(detached, Fixity::pinned());
+    let found = model.instabilities();
+    assert!(found.contains(&Instability::RigidBodyMotion { nodes: vec![detached], members: vec![], modes: 3 }));
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "serde")]
+use serde_json::json;
+use structure::{benchmarks, GroupBy, Material, Model, Section, Takeoff, UnitCost};
+use utils::assert_almost_eq;
+
+fn section(name: &str, material: &str, area: f64, perimeter: f64) -> Section {
+    let material = Material::new(210e9, 0.3, 7850.0, 78.5e3, 1.2e-5, 0.2, Some(material.into()));
+    let mut section = Section::generic(material, Some(name.into()));
+    section.set_area(area);
+    section.set_perimeter(perimeter);
+    section
+}
+
+/// The benchmark portal frame with two 4 m HEA 200 columns and a 6 m IPE 300 beam.
+fn portal() -> Model {
+    let mut model = benchmarks::portal_frame().model().clone();
+    let column = section("HEA 200", "S355", 53.8e-4, 1.136);
+    let mut beam = section("IPE 300", "S235", 53.8e-4, 1.160);
+    beam.set_mass(42.2);
+    for (member, section) in [(0, column.clone()), (1, beam), (2, column)] {
+        model.member_mut(member).set_section(section);
+    }
+    #[cfg(feature = "serde")]
+    for (member, mark) in [(0, "columns"), (1, "roof"), (2, "columns")] {
+        model.member_mut(member).extras_mut().insert("group".into(), json!(mark));
+    }
+    model
+}
+
+#[test]
+fn takeoff_groups_by_section() {
+    let table = Takeoff::new(GroupBy::Section)
+        .with_unit_cost("HEA 200", UnitCost { per_mass: 2.5, ..UnitCost::default() })
+        .compute(&portal());
+    assert_eq!(table.rows().len(), 2);
+
+    let columns = table.row("HEA 200").unwrap();
+    assert_eq!(columns.count, 2);
+    assert_almost_eq!(columns.length, 8.0);
+    assert_almost_eq!(columns.surface, 8.0 * 1.136);
+    assert_almost_eq!(columns.mass, 8.0 * 53.8e-4 * 7850.0);
+    assert_almost_eq!(columns.cost.unwrap(), 2.5 * columns.mass);
+
+    // The explicit section mass wins over area times density.
+    let beam = table.row("IPE 300").unwrap();
+    assert_almost_eq!(beam.mass, 6.0 * 42.2);
+    assert_eq!(beam.cost, None);
+
+    let total = table.total();
+    assert_eq!(total.count, 3);
+    assert_almost_eq!(total.length, 14.0);
+    assert_eq!(total.cost, columns.cost);
+}
+
+#[test]
+fn takeoff_groups_by_material() {
+    let by_material = Takeoff::new(GroupBy::Material).compute(&portal());
+    let groups: Vec<&str> = by_material.rows().iter().map(|row| row.group.as_str()).collect();
+    assert_eq!(groups, ["S235", "S355"]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn takeoff_groups_by_extras() {
+    let by_mark = Takeoff::new(GroupBy::Extra("group".into())).compute(&portal());
+    assert_eq!(by_mark.row("columns").unwrap().count, 2);
+    assert_almost_eq!(by_mark.row("roof").unwrap().length, 6.0);
+}
+
+#[test]
+fn takeoff_exports_csv() {
+    let table = Takeoff::new(GroupBy::Section)
+        .with_unit_cost("IPE 300", UnitCost { per_length: 100.0, ..UnitCost::default() })
+        .compute(&portal());
+    let csv = table.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "group,count,length,surface,mass,cost");
+    assert!(lines[1].starts_with("HEA 200,2,8,") && lines[1].ends_with(','));
+    assert!(lines[2].starts_with("IPE 300,1,6,") && lines[2].ends_with(",600"));
+    assert!(lines[3].starts_with("Total,3,14,") && lines[3].ends_with(",600"));
+}
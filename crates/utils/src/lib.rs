@@ -61,25 +61,25 @@ macro_rules! assert_vec3_almost_eq {
         let a_val = $a;
         let b_val = $b;
         let tol = $epsilon;
-        let dx = (a_val.x() - b_val.x()).abs();
-        let dy = (a_val.y() - b_val.y()).abs();
-        let dz = (a_val.z() - b_val.z()).abs();
-        if !$crate::approx_eq(a_val.x(), b_val.x(), tol)
-            || !$crate::approx_eq(a_val.y(), b_val.y(), tol)
-            || !$crate::approx_eq(a_val.z(), b_val.z(), tol)
-        {
+        // Typed components also pin down vectors generic over their scalar.
+        let (ax, ay, az): (f64, f64, f64) = (a_val.x(), a_val.y(), a_val.z());
+        let (bx, by, bz): (f64, f64, f64) = (b_val.x(), b_val.y(), b_val.z());
+        let dx = (ax - bx).abs();
+        let dy = (ay - by).abs();
+        let dz = (az - bz).abs();
+        if !$crate::approx_eq(ax, bx, tol) || !$crate::approx_eq(ay, by, tol) || !$crate::approx_eq(az, bz, tol) {
             eprintln!(
                 "[DEBUG] {} mismatch:\n  actual   = ({:.12}, {:.12}, {:.12})\n  expected = ({:.12}, {:.12}, {:.12})\n  diff     = ({:.12}, {:.12}, {:.12})\n  tol      = {:.12}",
                 label_val,
-                a_val.x(), a_val.y(), a_val.z(),
-                b_val.x(), b_val.y(), b_val.z(),
+                ax, ay, az,
+                bx, by, bz,
                 dx, dy, dz, tol
             );
             panic!(
                 "{} mismatch: actual=({:.12}, {:.12}, {:.12}), expected=({:.12}, {:.12}, {:.12}), diff=({:.12}, {:.12}, {:.12}), tol={:.12}",
                 label_val,
-                a_val.x(), a_val.y(), a_val.z(),
-                b_val.x(), b_val.y(), b_val.z(),
+                ax, ay, az,
+                bx, by, bz,
                 dx, dy, dz, tol
             );
         }
@@ -97,3 +97,52 @@ macro_rules! assert_vec3_almost_eq {
         $crate::assert_vec3_almost_eq!(@check $epsilon, "approx mismatch", $a, $b);
     }};
 }
+
+/// Assert that two nalgebra matrices or vectors of any dimension have the same
+/// shape and are entry-wise within the current epsilon. Lists every mismatched
+/// entry before panicking and supports the label and tolerance forms of
+/// [`assert_vec3_almost_eq!`].
+#[macro_export]
+macro_rules! assert_matrix_almost_eq {
+    (@check $epsilon:expr, $label:expr, $a:expr, $b:expr) => {{
+        let label_val = $label;
+        let a_val = &$a;
+        let b_val = &$b;
+        let tol = $epsilon;
+        let a_shape = (a_val.nrows(), a_val.ncols());
+        let b_shape = (b_val.nrows(), b_val.ncols());
+        if a_shape != b_shape {
+            panic!(
+                "{} shape mismatch: actual is {}x{}, expected is {}x{}",
+                label_val, a_shape.0, a_shape.1, b_shape.0, b_shape.1
+            );
+        }
+        let mut mismatches = ::std::string::String::new();
+        for i in 0..a_shape.0 {
+            for j in 0..a_shape.1 {
+                let (a, b): (f64, f64) = (a_val[(i, j)], b_val[(i, j)]);
+                if !$crate::approx_eq(a, b, tol) {
+                    mismatches.push_str(&format!(
+                        "\n  ({}, {}): actual = {:.12}, expected = {:.12}, diff = {:.12}",
+                        i, j, a, b, (a - b).abs()
+                    ));
+                }
+            }
+        }
+        if !mismatches.is_empty() {
+            panic!("{} mismatch, tol = {:.12}:{}", label_val, tol, mismatches);
+        }
+    }};
+    ($label:expr, $a:expr, $b:expr $(,)?) => {{
+        $crate::assert_matrix_almost_eq!(@check $crate::epsilon(), $label, $a, $b);
+    }};
+    ($label:expr, $a:expr, $b:expr, $epsilon:expr $(,)?) => {{
+        $crate::assert_matrix_almost_eq!(@check $epsilon, $label, $a, $b);
+    }};
+    ($a:expr, $b:expr $(,)?) => {{
+        $crate::assert_matrix_almost_eq!(@check $crate::epsilon(), "approx mismatch", $a, $b);
+    }};
+    ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
+        $crate::assert_matrix_almost_eq!(@check $epsilon, "approx mismatch", $a, $b);
+    }};
+}